@@ -1 +1,236 @@
+use std::{any::Any, collections::BTreeMap, sync::Arc};
+
+pub use async_trait;
 pub use waynest;
+
+use waynest::{Message, MessageInfo, ObjectId, ProtocolError};
+
+pub use waynest_macros::EventDispatcher;
+
+pub struct Store<C: waynest::Connection, E: From<ProtocolError>, S = ()> {
+    objects: BTreeMap<ObjectId, Object<C, E, S>>,
+}
+
+impl<C: waynest::Connection, E: From<ProtocolError>, S> Default for Store<C, E, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: waynest::Connection, E: From<ProtocolError>, S> Store<C, E, S> {
+    pub fn new() -> Self {
+        Self {
+            objects: BTreeMap::new(),
+        }
+    }
+}
+
+impl<C: waynest::Connection + 'static, E: From<ProtocolError> + 'static, S: Send + 'static>
+    ObjectStore for Store<C, E, S>
+{
+    type Error = E;
+    type Connection = C;
+    type State = S;
+
+    fn insert(
+        &mut self,
+        id: ObjectId,
+        object: StoreObject<Self>,
+    ) -> Result<(), StoreError<StoreObject<Self>>> {
+        if self.objects.contains_key(&id) {
+            return Err(StoreError(object));
+        }
+
+        self.objects.insert(id, object);
+
+        Ok(())
+    }
+
+    fn get(&self, id: ObjectId) -> Option<StoreObject<Self>> {
+        self.objects.get(&id).cloned()
+    }
+
+    fn remove(&mut self, id: ObjectId) {
+        self.objects.remove(&id);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StoreError<T: Clone>(pub T);
+
+/// An object as kept by an [`ObjectStore`], shared with the handlers of its messages.
+pub type Object<C, E, S = ()> = Arc<dyn EventDispatcher<Error = E, Connection = C, State = S>>;
+
+/// The [`Object`] type kept by the store `T`.
+pub type StoreObject<T> =
+    Object<<T as ObjectStore>::Connection, <T as ObjectStore>::Error, <T as ObjectStore>::State>;
+
+/// Keeps track of the proxies created by a client, routing incoming events to them.
+pub trait ObjectStore {
+    type Connection: waynest::Connection + 'static;
+    type Error: From<ProtocolError> + 'static;
+    type State: Send + 'static;
+
+    fn insert(
+        &mut self,
+        id: ObjectId,
+        object: StoreObject<Self>,
+    ) -> Result<(), StoreError<StoreObject<Self>>>;
+
+    fn get(&self, id: ObjectId) -> Option<StoreObject<Self>>;
+
+    fn remove(&mut self, id: ObjectId);
+
+    /// Inserts a dispatcher, returning it for the caller to keep.
+    fn insert_dispatcher<
+        D: EventDispatcher<Error = Self::Error, Connection = Self::Connection, State = Self::State>,
+    >(
+        &mut self,
+        id: ObjectId,
+        object: D,
+    ) -> Result<Arc<D>, StoreError<StoreObject<Self>>> {
+        let dispatcher = Arc::new(object);
+
+        self.insert(id, dispatcher.clone())?;
+
+        Ok(dispatcher)
+    }
+
+    /// The dispatcher of an object, if it is a `D`.
+    fn get_dispatcher<D: EventDispatcher>(&self, id: ObjectId) -> Option<Arc<D>> {
+        let dispatcher = EventDispatcher::as_any(self.get(id)?);
+        Arc::downcast(dispatcher).ok()
+    }
+
+    /// Routes an event to the object it was sent to.
+    fn dispatch(
+        &self,
+        connection: &mut Self::Connection,
+        state: &mut Self::State,
+        message: &mut Message,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let sender_id = message.object_id();
+
+            let object = self
+                .get(sender_id)
+                .ok_or(ProtocolError::UnknownObject(sender_id))?;
+
+            object
+                .dispatch_event(connection, state, sender_id, message)
+                .await
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait EventDispatcher: Any + Send + Sync + 'static {
+    type Connection: waynest::Connection;
+    type Error: From<ProtocolError>;
+    /// State shared by every object of the client, passed to [`ObjectStore::dispatch`]
+    type State: Send;
+
+    fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static>;
+
+    /// The name of the interface implemented by this object.
+    fn interface(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The signatures of the events of this object by opcode.
+    fn events(&self) -> &'static [MessageInfo] {
+        &[]
+    }
+
+    async fn dispatch_event(
+        &self,
+        connection: &mut Self::Connection,
+        state: &mut Self::State,
+        sender_id: ObjectId,
+        message: &mut Message,
+    ) -> Result<(), Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tokio::net::UnixStream;
+    use waynest::{Message, ObjectId, PayloadBuilder, ProtocolError, Socket};
+
+    use crate::{EventDispatcher, ObjectStore, Store};
+
+    /// An object recording the opcodes of its events in the store state.
+    struct Recording;
+
+    #[async_trait::async_trait]
+    impl EventDispatcher for Recording {
+        type Connection = Socket;
+        type Error = ProtocolError;
+        type State = Vec<(ObjectId, u16, u32)>;
+
+        fn as_any(self: Arc<Self>) -> Arc<dyn std::any::Any + Send + Sync + 'static> {
+            self
+        }
+
+        async fn dispatch_event(
+            &self,
+            _connection: &mut Self::Connection,
+            state: &mut Self::State,
+            sender_id: ObjectId,
+            message: &mut Message,
+        ) -> Result<(), Self::Error> {
+            state.push((sender_id, message.opcode(), message.uint()?));
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn dispatch_routes_events_by_object() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let (stream, _peer) = UnixStream::pair().unwrap();
+            let mut socket = Socket::new(stream).unwrap();
+
+            let mut store = Store::<Socket, ProtocolError, _>::new();
+            let mut state = Vec::new();
+
+            let callback = ObjectId::new(3).unwrap();
+            let unknown = ObjectId::new(4).unwrap();
+
+            let Ok(recording) = store.insert_dispatcher(callback, Recording) else {
+                panic!("Failed to insert the callback");
+            };
+            assert!(Arc::ptr_eq(
+                &recording,
+                &store.get_dispatcher::<Recording>(callback).unwrap()
+            ));
+            assert!(store.insert_dispatcher(callback, Recording).is_err());
+
+            let mut done = Message::new(callback, 0, PayloadBuilder::new().put_uint(42).build());
+            store
+                .dispatch(&mut socket, &mut state, &mut done)
+                .await
+                .unwrap();
+
+            assert_eq!(state, [(callback, 0, 42)]);
+
+            let mut stray = Message::new(unknown, 0, PayloadBuilder::new().put_uint(7).build());
+            let error = store.dispatch(&mut socket, &mut state, &mut stray).await;
+
+            assert!(matches!(error, Err(ProtocolError::UnknownObject(id)) if id == unknown));
+            assert_eq!(state.len(), 1);
+
+            store.remove(callback);
+            assert!(store.get(callback).is_none());
+        });
+    }
+}
@@ -0,0 +1,336 @@
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    LogLevel, ProtocolGenerator,
+    error::Error,
+    file_header, format_code,
+    lint::{self, Severity},
+    parser::Protocol,
+};
+
+/// Which side of the protocol to generate bindings for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Side {
+    Client,
+    #[default]
+    Server,
+    /// Generates both sides, in a `client` and a `server` module respectively
+    Both,
+}
+
+/// Generates bindings for custom protocol XML files, meant to be used from a build script.
+///
+/// ```no_run
+/// waynest_gen::Builder::new()
+///     .protocol("protocols/my-protocol-v1.xml")
+///     .side(waynest_gen::Side::Server)
+///     .reference("core", ["/usr/share/wayland/wayland.xml"])
+///     .write("protocols.rs")
+///     .expect("Failed to generate protocols");
+/// ```
+///
+/// The generated file can then be included with
+/// `include!(concat!(env!("OUT_DIR"), "/protocols.rs"));` at the crate root, or in the module
+/// passed to [`Builder::mount`]. It expects the `waynest` and
+/// `bitflags` crates to be available, along with `waynest-protocols` when
+/// references are used. Messages are logged through `tracing` or `log`, gated behind features
+/// of the same name in the including crate.
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    paths: Vec<PathBuf>,
+    side: Side,
+    references: Vec<(&'static str, Vec<PathBuf>)>,
+    mount: Option<String>,
+    log_level: LogLevel,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a protocol XML file to generate bindings for.
+    pub fn protocol<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.paths.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds several protocol XML files to generate bindings for.
+    pub fn protocols<I, P>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.paths
+            .extend(paths.into_iter().map(|path| path.as_ref().to_path_buf()));
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the module the generated file is included in, e.g. `crate::protocols`, which enums
+    /// referenced across the generated protocols are resolved through. Defaults to `crate`.
+    pub fn mount(mut self, path: &str) -> Self {
+        self.mount = Some(path.to_owned());
+        self
+    }
+
+    /// Sets the level messages are logged at, [`LogLevel::Debug`] by default.
+    pub fn log_level(mut self, level: LogLevel) -> Self {
+        self.log_level = level;
+        self
+    }
+
+    /// Declares that the given XML files are already generated by `waynest-protocols` under
+    /// `family`, e.g. `core` or `stable`. Enums referenced from them resolve to the types in
+    /// `waynest_protocols` instead of being generated again.
+    pub fn reference<I, P>(mut self, family: &'static str, paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.references.push((
+            family,
+            paths
+                .into_iter()
+                .map(|path| path.as_ref().to_path_buf())
+                .collect(),
+        ));
+        self
+    }
+
+    /// Generates the formatted bindings.
+    pub fn generate(&self) -> Result<String, Error> {
+        let protocols = self
+            .paths
+            .iter()
+            .map(Protocol::from_path)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut references: HashMap<&'static str, Vec<Protocol>> = HashMap::new();
+
+        for (family, paths) in &self.references {
+            for path in paths {
+                references
+                    .entry(family)
+                    .or_default()
+                    .push(Protocol::from_path(path)?);
+            }
+        }
+
+        let known: Vec<&Protocol> = references.values().flatten().collect();
+        let mut diagnostics = Vec::new();
+
+        for protocol in &protocols {
+            diagnostics.extend(
+                lint::lint(protocol, &known)
+                    .into_iter()
+                    .filter(|diagnostic| diagnostic.severity == Severity::Error),
+            );
+        }
+
+        if !diagnostics.is_empty() {
+            return Err(Error::Lint(diagnostics));
+        }
+
+        let mount: TokenStream = syn::parse_str(self.mount.as_deref().unwrap_or("crate"))?;
+        let (server_root, client_root) = match self.side {
+            Side::Both => (quote! { #mount::server }, quote! { #mount::client }),
+            _ => (mount.clone(), mount),
+        };
+
+        let server = || -> Result<TokenStream, Error> {
+            let modules = ProtocolGenerator::new(&protocols, &references)
+                .with_root(quote! { waynest_protocols::server })
+                .with_local_root(server_root.clone())
+                .with_log_level(self.log_level)
+                .generate_protocols(false, true)?;
+
+            Ok(quote! { #(#modules)* })
+        };
+
+        let client = || -> Result<TokenStream, Error> {
+            let modules = ProtocolGenerator::new(&protocols, &references)
+                .with_root(quote! { waynest_protocols::client })
+                .with_local_root(client_root.clone())
+                .with_log_level(self.log_level)
+                .generate_protocols(true, false)?;
+
+            Ok(quote! { #(#modules)* })
+        };
+
+        let tokens = match self.side {
+            Side::Server => server()?,
+            Side::Client => client()?,
+            Side::Both => {
+                let server = server()?;
+                let client = client()?;
+
+                quote! {
+                    pub mod server {
+                        #server
+                    }
+
+                    pub mod client {
+                        #client
+                    }
+                }
+            }
+        };
+
+        Ok(file_header(&protocols) + &format_code(tokens)?)
+    }
+
+    /// Generates the bindings into `file_name` inside `OUT_DIR`, returning the full path.
+    ///
+    /// This also instructs cargo to rerun the build script when any of the XML files change.
+    pub fn write<P: AsRef<Path>>(&self, file_name: P) -> Result<PathBuf, Error> {
+        let out_dir = env::var_os("OUT_DIR")
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "OUT_DIR is not set"))?;
+
+        self.write_to(Path::new(&out_dir), file_name)
+    }
+
+    fn write_to<P: AsRef<Path>>(&self, out_dir: &Path, file_name: P) -> Result<PathBuf, Error> {
+        for path in self
+            .paths
+            .iter()
+            .chain(self.references.iter().flat_map(|(_, paths)| paths))
+        {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        let path = out_dir.join(file_name);
+
+        fs::write(&path, self.generate()?)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::{Builder, Side};
+    use crate::error::Error;
+
+    const CORE: &str = r#"<protocol name="wayland">
+        <interface name="wl_output" version="4">
+            <enum name="transform">
+                <entry name="normal" value="0"/>
+            </enum>
+        </interface>
+    </protocol>"#;
+
+    const PROTOCOL: &str = r#"<protocol name="test_protocol">
+        <interface name="test_thing" version="1">
+            <request name="destroy" type="destructor"/>
+            <request name="set_transform">
+                <arg name="transform" type="int" enum="wl_output.transform"/>
+            </request>
+            <event name="done"/>
+        </interface>
+    </protocol>"#;
+
+    /// A scratch directory holding the given files, removed when dropped.
+    struct Scratch(PathBuf);
+
+    impl Scratch {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let dir = env::temp_dir().join(format!("waynest-gen-{name}-{}", process::id()));
+            fs::create_dir_all(&dir).expect("Failed to create scratch directory");
+
+            for (file, content) in files {
+                fs::write(dir.join(file), content).expect("Failed to write protocol");
+            }
+
+            Self(dir)
+        }
+    }
+
+    impl Drop for Scratch {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn items<'a>(items: &'a [syn::Item], name: &str) -> &'a [syn::Item] {
+        items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Mod(module) if module.ident == name => Some(&module.content.as_ref()?.1),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("Missing module {name}"))
+    }
+
+    fn names(items: &[syn::Item]) -> Vec<String> {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Mod(module) => Some(module.ident.to_string()),
+                syn::Item::Trait(item) => Some(item.ident.to_string()),
+                syn::Item::Struct(item) => Some(item.ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn writes_both_sides() {
+        let scratch = Scratch::new(
+            "builder",
+            &[("wayland.xml", CORE), ("test-protocol.xml", PROTOCOL)],
+        );
+
+        let path = Builder::new()
+            .protocol(scratch.0.join("test-protocol.xml"))
+            .side(Side::Both)
+            .reference("core", [scratch.0.join("wayland.xml")])
+            .write_to(&scratch.0, "protocols.rs")
+            .expect("Failed to generate protocols");
+
+        assert_eq!(path, scratch.0.join("protocols.rs"));
+
+        let source = fs::read_to_string(&path).expect("Failed to read generated file");
+
+        assert!(source.starts_with("// This file was generated by waynest-gen"));
+
+        let file: syn::File = syn::parse_str(&source).expect("Generated code does not parse");
+
+        assert_eq!(names(&file.items), ["server", "client"]);
+
+        for (side, senders) in [("server", "Events"), ("client", "Requests")] {
+            let thing = items(
+                items(items(&file.items, side), "test_protocol"),
+                "test_thing",
+            );
+
+            assert_eq!(names(thing), [senders, "TestThing"]);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_protocols() {
+        let scratch = Scratch::new("builder-invalid", &[("test-protocol.xml", PROTOCOL)]);
+
+        // wl_output.transform can't be resolved without the core protocol
+        let result = Builder::new()
+            .protocol(scratch.0.join("test-protocol.xml"))
+            .write_to(&scratch.0, "protocols.rs");
+
+        assert!(matches!(result, Err(Error::Lint(diagnostics)) if diagnostics.len() == 1));
+        assert!(!scratch.0.join("protocols.rs").exists());
+    }
+}
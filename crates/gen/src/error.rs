@@ -2,19 +2,56 @@ use std::{error, fmt, io};
 
 use quick_xml::DeError;
 
+use crate::{lint::Diagnostic, parser::Location};
+
 #[derive(Debug)]
 pub enum Error {
-    NotFound(String),
+    /// A reference to another interface or enum could not be resolved. When `candidates` is not
+    /// empty the reference was ambiguous and matched all of them.
+    NotFound {
+        name: String,
+        candidates: Vec<String>,
+        location: Location,
+    },
+    /// The protocol failed validation
+    Lint(Vec<Diagnostic>),
     IoError(io::Error),
     Decode(DeError),
+    /// The generated code could not be parsed back for formatting
+    Syntax(syn::Error),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::NotFound(interface) => write!(f, "Could not find interface {interface}"),
+            Self::NotFound {
+                name,
+                candidates,
+                location,
+            } if candidates.is_empty() => write!(f, "{location}: Could not find {name}"),
+            Self::NotFound {
+                name,
+                candidates,
+                location,
+            } => write!(
+                f,
+                "{location}: Ambiguous reference to {name}, found in {}",
+                candidates.join(", ")
+            ),
+            Self::Lint(diagnostics) => {
+                for (index, diagnostic) in diagnostics.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+
+                    diagnostic.fmt(f)?;
+                }
+
+                Ok(())
+            }
             Self::IoError(err) => err.fmt(f),
             Self::Decode(err) => err.fmt(f),
+            Self::Syntax(err) => err.fmt(f),
         }
     }
 }
@@ -24,6 +61,7 @@ impl error::Error for Error {
         match self {
             Error::IoError(err) => Some(err),
             Error::Decode(err) => Some(err),
+            Error::Syntax(err) => Some(err),
             _ => None,
         }
     }
@@ -40,3 +78,9 @@ impl From<DeError> for Error {
         Error::Decode(err)
     }
 }
+
+impl From<syn::Error> for Error {
+    fn from(err: syn::Error) -> Self {
+        Error::Syntax(err)
+    }
+}
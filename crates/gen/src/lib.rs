@@ -1,23 +1,98 @@
 use std::collections::HashMap;
 
-use heck::{ToSnekCase, ToUpperCamelCase};
+use heck::{ToShoutySnakeCase, ToSnekCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, format_ident, quote};
 
 use crate::{
     error::Error,
+    metadata::write_messages,
     parser::{Arg, ArgType, Interface, Message, Protocol},
-    utils::{description_to_docs, make_ident, write_enums},
+    utils::{
+        deprecated_attr, deprecated_since_to_token, description_to_docs, description_to_lines,
+        make_arg_ident, make_ident, since_to_docs, since_to_token, write_enums,
+    },
 };
 
+mod builder;
+mod metadata;
 mod utils;
 
 pub mod error;
+pub mod lint;
 pub mod parser;
 
+pub use builder::{Builder, Side};
+
+/// Pretty prints generated code.
+pub fn format_code(tokens: TokenStream) -> Result<String, Error> {
+    Ok(prettyplease::unparse(&syn::parse2(tokens)?))
+}
+
+/// Builds the comment placed at the top of generated files, naming the source XML of every
+/// protocol along with its copyright notice.
+pub fn file_header(protocols: &[Protocol]) -> String {
+    let mut header =
+        "// This file was generated by waynest-gen, do not edit it manually.\n".to_string();
+
+    for protocol in protocols {
+        header.push_str("//\n");
+
+        match &protocol.path {
+            Some(path) => header.push_str(&format!(
+                "// Generated from the {} protocol ({})\n",
+                protocol.name,
+                path.display()
+            )),
+            None => header.push_str(&format!(
+                "// Generated from the {} protocol\n",
+                protocol.name
+            )),
+        }
+
+        let copyright = description_to_lines(protocol.copyright.as_ref());
+
+        if !copyright.is_empty() {
+            header.push_str("//\n");
+        }
+
+        for line in copyright {
+            if line.is_empty() {
+                header.push_str("//\n");
+            } else {
+                header.push_str(&format!("//   {line}\n"));
+            }
+        }
+    }
+
+    header.push('\n');
+    header
+}
+
+/// Name of the cargo feature gating `protocol` in `waynest-protocols`, e.g. `xdg-shell` for
+/// `xdg_shell`.
+pub fn protocol_feature(protocol: &Protocol) -> String {
+    protocol.name.replace('_', "-")
+}
+
+/// The level generated code logs messages at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    #[default]
+    Debug,
+    Trace,
+}
+
 pub struct ProtocolGenerator<'a> {
     xml: &'a Vec<Protocol>,
     protocols: &'a HashMap<&'static str, Vec<Protocol>>,
+    root: Option<TokenStream>,
+    local_root: Option<TokenStream>,
+    features: bool,
+    log_level: LogLevel,
 }
 
 impl<'a> ProtocolGenerator<'a> {
@@ -25,7 +100,115 @@ impl<'a> ProtocolGenerator<'a> {
         xml: &'a Vec<Protocol>,
         protocols: &'a HashMap<&'static str, Vec<Protocol>>,
     ) -> ProtocolGenerator<'a> {
-        Self { xml, protocols }
+        Self {
+            xml,
+            protocols,
+            root: None,
+            local_root: None,
+            features: false,
+            log_level: LogLevel::default(),
+        }
+    }
+
+    /// Sets the path the other protocol families are mounted under, used to reference enums
+    /// across families. Defaults to `crate::server` or `crate::client` depending on the side
+    /// being generated, matching the layout of `waynest-protocols`.
+    pub fn with_root(mut self, root: TokenStream) -> Self {
+        self.root = Some(root);
+        self
+    }
+
+    /// Sets the path the protocols being generated are mounted under when they are not part of
+    /// a family, used to reference enums across them. Defaults to `crate`.
+    pub fn with_local_root(mut self, root: TokenStream) -> Self {
+        self.local_root = Some(root);
+        self
+    }
+
+    /// Sets the level messages are logged at, [`LogLevel::Debug`] by default.
+    pub fn with_log_level(mut self, level: LogLevel) -> Self {
+        self.log_level = level;
+        self
+    }
+
+    /// Gates every protocol module behind a cargo feature named after the protocol, see
+    /// [`protocol_feature`].
+    pub fn with_features(mut self) -> Self {
+        self.features = true;
+        self
+    }
+
+    /// Returns the protocols `protocol` depends on, either through enums or through interfaces
+    /// used as arguments. Protocols of the same family take precedence, references that can't
+    /// be resolved to a single protocol are ignored.
+    pub fn dependencies(&self, protocol: &Protocol) -> Vec<&'a Protocol> {
+        let mut references = Vec::new();
+
+        for interface in &protocol.interfaces {
+            for message in interface.requests.iter().chain(&interface.events) {
+                for arg in &message.args {
+                    if let Some(name) = &arg.interface {
+                        references.push((name.as_str(), None));
+                    }
+
+                    if let Some((name, r#enum)) = arg
+                        .r#enum
+                        .as_ref()
+                        .and_then(|r#enum| r#enum.split_once('.'))
+                    {
+                        references.push((name, Some(r#enum)));
+                    }
+                }
+            }
+        }
+
+        let mut dependencies: Vec<&'a Protocol> = Vec::new();
+
+        for (interface_name, enum_name) in references {
+            if protocol.interfaces.iter().any(|i| i.name == interface_name) {
+                continue;
+            }
+
+            let defines = |protocol: &&Protocol| {
+                protocol.interfaces.iter().any(|interface| {
+                    interface.name == interface_name
+                        && enum_name
+                            .is_none_or(|name| interface.enums.iter().any(|e| e.name == name))
+                })
+            };
+
+            let candidates: Vec<&'a Protocol> = self.xml.iter().filter(defines).collect();
+
+            let candidates = if candidates.is_empty() {
+                self.protocols.values().flatten().filter(defines).collect()
+            } else {
+                candidates
+            };
+
+            if let [dependency] = candidates.as_slice()
+                && !dependencies
+                    .iter()
+                    .any(|protocol| protocol.name == dependency.name)
+            {
+                dependencies.push(dependency);
+            }
+        }
+
+        dependencies.sort_by(|a, b| a.name.cmp(&b.name));
+        dependencies
+    }
+
+    fn feature_attrs(&self, protocol: &Protocol) -> TokenStream {
+        if !self.features {
+            return quote! {};
+        }
+
+        let feature = protocol_feature(protocol);
+
+        quote! {
+            #[cfg(feature = #feature)]
+            #[cfg_attr(docsrs, doc(cfg(feature = #feature)))]
+        }
     }
 
     pub fn generate_protocols(
@@ -35,6 +218,14 @@ impl<'a> ProtocolGenerator<'a> {
     ) -> Result<Vec<TokenStream>, Error> {
         let mut generate_modules = Vec::new();
 
+        let root = self.root.clone().unwrap_or_else(|| {
+            if events_body {
+                quote! { crate::server }
+            } else {
+                quote! { crate::client }
+            }
+        });
+
         for protocol in self.xml {
             let mut inner_modules = Vec::new();
 
@@ -50,34 +241,50 @@ impl<'a> ProtocolGenerator<'a> {
                     "Trait to implement the {} interface. See the module level documentation for more info",
                     interface.name
                 );
+                let unimplemented = format!(
+                    "`{{Self}}` does not implement the `{}` interface",
+                    interface.name
+                );
+                let unimplemented_label =
+                    format!("`{trait_name}` is not implemented for `{{Self}}`");
 
                 let requests = self.generate_functions(
+                    &root,
+                    protocol,
                     interface,
                     &interface.requests,
-                    &interface.events,
                     requests_body,
                 )?;
-                let events =
-                    self.generate_functions(interface, &interface.events, &[], events_body)?;
+                let events = self.generate_functions(
+                    &root,
+                    protocol,
+                    interface,
+                    &interface.events,
+                    events_body,
+                )?;
 
-                let enums = write_enums(interface);
+                let enums = write_enums(protocol, interface)?;
 
                 let request_handler = if events_body {
-                    let dispatchers = self.write_dispatchers(interface, &interface.requests);
+                    let dispatchers =
+                        self.write_dispatchers(&root, protocol, interface, &interface.requests)?;
 
                     let args = if dispatchers.is_empty() {
                         quote! {
                             _connection: &mut Self::Connection,
+                            _state: &mut Self::State,
                             _sender_id: waynest::ObjectId,
                         }
                     } else {
                         quote! {
                             connection: &mut Self::Connection,
+                            state: &mut Self::State,
                             sender_id: waynest::ObjectId,
                         }
                     };
 
                     quote! {
+                        #[allow(deprecated)]
                         fn handle_request(
                             &self,
                             #args
@@ -97,21 +304,25 @@ impl<'a> ProtocolGenerator<'a> {
                 };
 
                 let event_handler = if requests_body {
-                    let dispatchers = self.write_dispatchers(interface, &interface.events);
+                    let dispatchers =
+                        self.write_dispatchers(&root, protocol, interface, &interface.events)?;
 
                     let args = if dispatchers.is_empty() {
                         quote! {
                             _connection: &mut Self::Connection,
+                            _state: &mut Self::State,
                             _sender_id: waynest::ObjectId,
                         }
                     } else {
                         quote! {
                             connection: &mut Self::Connection,
+                            state: &mut Self::State,
                             sender_id: waynest::ObjectId,
                         }
                     };
 
                     quote! {
+                        #[allow(deprecated)]
                         fn handle_event(
                             &self,
                             #args
@@ -130,21 +341,56 @@ impl<'a> ProtocolGenerator<'a> {
                     quote! {}
                 };
 
+                let (table, messages) = if events_body {
+                    (quote! { REQUESTS }, &interface.requests)
+                } else {
+                    (quote! { EVENTS }, &interface.events)
+                };
+
+                let infos = write_messages(messages);
+
+                let (handlers, senders, senders_name) = if events_body {
+                    (requests, events, quote! { Events })
+                } else {
+                    (events, requests, quote! { Requests })
+                };
+
+                let senders_docs = format!(
+                    "Sends the {} of the {} interface through any [`waynest::MessageSender`]",
+                    if events_body { "events" } else { "requests" },
+                    interface.name
+                );
+
                 inner_modules.push(quote! {
                     #(#docs)*
                     #[allow(clippy::too_many_arguments)]
                     pub mod #module_name {
                         #(#enums)*
 
+                        static #table: &[waynest::MessageInfo] = &[#(#infos),*];
+
+                        #[doc = #senders_docs]
+                        #[derive(Debug, Clone, Copy)]
+                        pub struct #senders_name;
+
+                        impl #senders_name {
+                            #(#senders)*
+                        }
+
                         #[doc = #trait_docs]
+                        #[diagnostic::on_unimplemented(message = #unimplemented, label = #unimplemented_label)]
                         pub trait #trait_name where Self: std::marker::Sync {
                             type Connection: waynest::Connection;
+                            /// State shared with every other object, such as the whole
+                            /// compositor, which handlers get exclusive access to
+                            type State: Send;
 
                             const INTERFACE: &'static str = #name;
                             const VERSION: u32 = #version;
+                            /// The signatures of the messages handled by this trait, by opcode
+                            const #table: &'static [waynest::MessageInfo] = #table;
 
-                            #(#requests)*
-                            #(#events)*
+                            #(#handlers)*
 
                             #request_handler
                             #event_handler
@@ -155,10 +401,12 @@ impl<'a> ProtocolGenerator<'a> {
 
             let ident = make_ident(&protocol.name);
             let docs = description_to_docs(protocol.description.as_ref());
+            let features = self.feature_attrs(protocol);
 
             generate_modules.push(quote! {
                 #(#docs)*
-                #[allow(clippy::module_inception)]
+                #features
+                #[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
                 pub mod #ident {
                     #(#inner_modules)*
                 }
@@ -170,46 +418,83 @@ impl<'a> ProtocolGenerator<'a> {
 
     fn generate_functions(
         &self,
+        root: &TokenStream,
+        protocol: &Protocol,
         interface: &Interface,
         messages: &[Message],
-        skip: &[Message],
         generate_body: bool,
     ) -> Result<Vec<TokenStream>, Error> {
         let mut functions = Vec::new();
 
         for (opcode, message) in messages.iter().enumerate() {
             let docs = description_to_docs(message.description.as_ref());
-            let name = if skip.iter().any(|m| m.name == message.name) {
-                make_ident(format!("{}_", message.name.to_snek_case()))
-            } else {
-                make_ident(message.name.to_snek_case())
-            };
+            let since_docs = since_to_docs(message.since, message.deprecated_since);
+            let deprecated = deprecated_attr(message.deprecated_since);
+            let name = make_ident(message.name.to_snek_case());
+
+            let const_prefix = message.name.to_shouty_snake_case();
+            let since_const = format_ident!("{const_prefix}_SINCE");
+            let deprecated_since_const = format_ident!("{const_prefix}_DEPRECATED_SINCE");
+            let since_const_docs = format!(
+                "The interface version the {} message was introduced in",
+                message.name
+            );
+            let deprecated_since_const_docs = format!(
+                "The interface version the {} message was deprecated in, if any",
+                message.name
+            );
+            let since = since_to_token(message.since);
+            let deprecated_since = deprecated_since_to_token(message.deprecated_since);
 
             let mut args = Vec::new();
 
             for arg in &message.args {
-                let mut ty = self.arg_to_rust_type_token(arg, generate_body)?;
+                let mut ty = self.arg_to_rust_type_token(
+                    root,
+                    protocol,
+                    interface,
+                    message,
+                    arg,
+                    generate_body,
+                )?;
 
                 if arg.allow_null {
                     ty = quote! {Option<#ty>};
                 }
 
-                let name = make_ident(arg.name.to_snek_case());
+                let name = make_arg_ident(&arg.name);
 
                 args.push(quote! {#name: #ty})
             }
 
-            let body = if generate_body {
-                self.generate_function_body(interface, message, opcode as u16)
+            if generate_body {
+                let body = self.generate_function_body(interface, message, opcode as u16);
+
+                functions.push(quote! {
+                    #[doc = #since_const_docs]
+                    pub const #since_const: u32 = #since;
+                    #[doc = #deprecated_since_const_docs]
+                    pub const #deprecated_since_const: Option<u32> = #deprecated_since;
+
+                    #(#docs)*
+                    #(#since_docs)*
+                    #deprecated
+                    pub fn #name<S: waynest::MessageSender + ?Sized>(connection: &S, sender_id: waynest::ObjectId, #(#args),*) -> Result<(), S::Error>
+                    #body
+                });
             } else {
-                quote! {;}
-            };
+                functions.push(quote! {
+                    #[doc = #since_const_docs]
+                    const #since_const: u32 = #since;
+                    #[doc = #deprecated_since_const_docs]
+                    const #deprecated_since_const: Option<u32> = #deprecated_since;
 
-            functions.push(quote! {
-                #(#docs)*
-                fn #name(&self, connection: &mut Self::Connection, sender_id: waynest::ObjectId, #(#args),*) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-                #body
-            });
+                    #(#docs)*
+                    #(#since_docs)*
+                    #deprecated
+                    fn #name(&self, connection: &mut Self::Connection, state: &mut Self::State, sender_id: waynest::ObjectId, #(#args),*) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+                });
+            }
         }
 
         Ok(functions)
@@ -222,15 +507,13 @@ impl<'a> ProtocolGenerator<'a> {
         opcode: u16,
     ) -> TokenStream {
         let mut build_args = Vec::new();
-        let mut fd_pushes = Vec::new();
+        let mut fds = Vec::new();
 
         for arg in &message.args {
-            let name = make_ident(arg.name.to_snek_case());
+            let name = make_arg_ident(&arg.name);
 
             if matches!(arg.ty, ArgType::Fd) {
-                fd_pushes.push(quote! {
-                    waynest::Connection::push_fd(connection, #name);
-                });
+                fds.push(quote! { #name });
                 continue;
             }
 
@@ -250,91 +533,103 @@ impl<'a> ProtocolGenerator<'a> {
             });
         }
 
-        let (tracing_inner, tracing_args) = self.generate_tracing(interface, message, true);
-
-        let tracing = quote! {
-            #[cfg(feature = "tracing")]
-            tracing::debug!(#tracing_inner, sender_id, #(#tracing_args),*);
-        };
+        let logging = self.generate_logging(interface, message, opcode, true);
 
         quote! {
             {
-                async move {
-                    #tracing
-
-                    #(#fd_pushes)*
+                #logging
 
-                    let payload = waynest::PayloadBuilder::new()
-                        #(#build_args)*
-                        .build();
+                let payload = waynest::PayloadBuilder::new()
+                    #(#build_args)*
+                    .build();
 
-                    futures_util::SinkExt::send(
-                        connection, waynest::Message::new(sender_id, #opcode, payload)
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
+                connection.send_message(
+                    waynest::Message::new(sender_id, #opcode, payload),
+                    vec![#(#fds),*],
+                )
             }
         }
     }
 
-    pub fn generate_tracing(
+    /// Emits a structured event for a message, through `tracing` or, when only the `log`
+    /// feature is enabled, through `log`. Every argument is recorded as an `args.{name}` field.
+    pub fn generate_logging(
         &self,
         interface: &Interface,
         message: &Message,
+        opcode: u16,
         outgoing: bool,
-    ) -> (String, Vec<TokenStream>) {
-        let mut tracing_fmt = Vec::new();
-        let mut tracing_args = Vec::new();
-
-        for arg in &message.args {
-            let mut map_display = quote! {};
+    ) -> TokenStream {
+        let interface_name = &interface.name;
+        let message_name = &message.name;
+        let direction = if outgoing { "out" } else { "in" };
+        let text = format!("{interface_name}.{message_name}");
 
-            if arg.allow_null {
-                map_display = quote! {.as_ref().map_or("null".to_string(), |v| v.to_string())}
-            }
+        let mut keys = Vec::new();
+        let mut values = Vec::new();
 
-            let name = make_ident(arg.name.to_snek_case());
+        for arg in &message.args {
+            let name = make_arg_ident(&arg.name);
 
-            match arg.ty {
-                ArgType::Array => {
-                    tracing_fmt.push("array[{}]");
-                    tracing_args.push(quote! { #name .len() });
-                }
-                ArgType::String => {
-                    tracing_fmt.push("\"{}\"");
-                    tracing_args.push(quote! { #name #map_display });
-                }
-                ArgType::Fd => {
-                    tracing_fmt.push("{}");
-                    tracing_args
-                        .push(quote! { std::os::fd::AsRawFd::as_raw_fd(&#name) #map_display });
+            let value = match arg.ty {
+                ArgType::Array => quote! { #name.len() },
+                ArgType::Fd => quote! { std::os::fd::AsRawFd::as_raw_fd(&#name) },
+                _ if arg.allow_null => {
+                    quote! { #name.as_ref().map_or("null".to_string(), |v| v.to_string()) }
                 }
-                _ => {
-                    tracing_fmt.push("{}");
-                    tracing_args.push(quote! { #name #map_display });
-                }
-            }
-        }
-
-        let tracing_fmt = tracing_fmt.join(", ");
+                _ => quote! { #name },
+            };
 
-        let outgoing = if outgoing { "-> " } else { "" };
+            keys.push(format!("args.{}", arg.name));
+            values.push(value);
+        }
 
-        let tracing_inner = format!(
-            "{outgoing}{interface}#{{}}.{request}({tracing_fmt})",
-            interface = interface.name,
-            request = message.name.to_snek_case()
-        );
+        let (tracing_level, log_level) = match self.log_level {
+            LogLevel::Error => (quote! { ERROR }, quote! { Error }),
+            LogLevel::Warn => (quote! { WARN }, quote! { Warn }),
+            LogLevel::Info => (quote! { INFO }, quote! { Info }),
+            LogLevel::Debug => (quote! { DEBUG }, quote! { Debug }),
+            LogLevel::Trace => (quote! { TRACE }, quote! { Trace }),
+        };
 
-        (tracing_inner, tracing_args)
+        quote! {
+            #[cfg(feature = "tracing")]
+            tracing::event!(
+                target: "waynest",
+                tracing::Level::#tracing_level,
+                interface = #interface_name,
+                object_id = %sender_id,
+                opcode = #opcode,
+                message = #message_name,
+                direction = #direction,
+                #(#keys = %#values,)*
+            );
+            #[cfg(all(feature = "log", not(feature = "tracing")))]
+            log::log!(
+                target: "waynest",
+                log::Level::#log_level,
+                interface = #interface_name,
+                object_id:% = sender_id,
+                opcode = #opcode,
+                message = #message_name,
+                direction = #direction
+                #(, #keys:% = #values)*;
+                #text
+            );
+        }
     }
 
+    /// Generates the match arms decoding and dispatching every message.
+    ///
+    /// Enum arguments are converted before the message is logged, so logs show entry names
+    /// rather than raw values.
     pub fn write_dispatchers(
         &self,
+        root: &TokenStream,
+        protocol: &Protocol,
         interface: &Interface,
         messages: &[Message],
-    ) -> Vec<TokenStream> {
+    ) -> Result<Vec<TokenStream>, Error> {
         let mut dispatchers = Vec::new();
 
         for (opcode, request) in messages.iter().enumerate() {
@@ -342,26 +637,32 @@ impl<'a> ProtocolGenerator<'a> {
             let name = make_ident(request.name.to_snek_case());
 
             let mut setters = Vec::new();
-            let mut args = vec![quote! { connection }, quote! { sender_id }];
+            let mut args = vec![
+                quote! { connection },
+                quote! { state },
+                quote! { sender_id },
+            ];
 
             for arg in &request.args {
                 let mut optional = quote! {};
-                let mut tryinto = quote! {};
+                let mut binding = quote! {};
 
                 if !arg.allow_null && arg.is_return_option() {
                     optional = quote! {.ok_or(waynest::ProtocolError::MalformedPayload)?};
                 }
 
                 if arg.r#enum.is_some() {
-                    tryinto = quote! {.try_into()?}
+                    let ty = self
+                        .arg_to_rust_type_token(root, protocol, interface, request, arg, false)?;
+
+                    binding = quote! { : #ty };
+                    optional = quote! { .try_into()? };
                 }
 
                 let caller = make_ident(arg.to_caller());
-                let name = make_ident(arg.name.to_snek_case());
+                let name = make_arg_ident(&arg.name);
 
-                args.push(quote! {
-                    #name #tryinto
-                });
+                args.push(quote! { #name });
 
                 if matches!(arg.ty, ArgType::Fd) {
                     setters.push(quote! {
@@ -369,22 +670,17 @@ impl<'a> ProtocolGenerator<'a> {
                     });
                 } else {
                     setters.push(quote! {
-                       let #name = message.#caller()? #optional;
+                       let #name #binding = message.#caller()? #optional;
                     });
                 }
             }
 
-            let (tracing_inner, tracing_args) = self.generate_tracing(interface, request, false);
-
-            let tracing = quote! {
-                #[cfg(feature = "tracing")]
-                tracing::debug!(#tracing_inner, sender_id, #(#tracing_args),*);
-            };
+            let logging = self.generate_logging(interface, request, opcode, false);
 
             let inner = quote! {
                 #opcode => {
                     #(#setters)*
-                    #tracing
+                    #logging
                     self.#name(#(#args),*).await
                 }
             };
@@ -392,37 +688,261 @@ impl<'a> ProtocolGenerator<'a> {
             dispatchers.push(inner);
         }
 
-        dispatchers
+        Ok(dispatchers)
     }
 
     pub fn arg_to_rust_type_token(
         &self,
+        root: &TokenStream,
+        protocol: &Protocol,
+        interface: &Interface,
+        message: &Message,
         arg: &Arg,
         generate_body: bool,
     ) -> Result<TokenStream, Error> {
         if let Some(e) = &arg.r#enum {
-            if let Some((interface, name)) = e.split_once('.') {
-                for (module, protocols) in self.protocols {
-                    for protocol in protocols {
-                        if let Some(interface) =
-                            protocol.interfaces.iter().find(|i| i.name == interface)
-                        {
-                            let module = make_ident(module);
-                            let protocol_module = make_ident(&protocol.name);
-                            let protocol_name = make_ident(&interface.name);
-                            let name = make_ident(name.to_upper_camel_case());
-
-                            return Ok(
-                                quote! {super::super::super::#module::#protocol_module::#protocol_name::#name},
-                            );
-                        }
+            return self.resolve_enum(root, protocol, interface, message, arg, e);
+        }
+
+        Ok(arg.to_underlying_type_token(generate_body))
+    }
+
+    /// The family the protocols being generated belong to, if they are one of the families
+    /// passed to [`ProtocolGenerator::new`].
+    fn family(&self) -> Option<&'static str> {
+        self.protocols
+            .iter()
+            .find(|(_, protocols)| std::ptr::eq(*protocols, self.xml))
+            .map(|(family, _)| *family)
+    }
+
+    /// Resolves the `enum` attribute of an argument to the path of the generated type.
+    ///
+    /// Unqualified names refer to the current interface. Qualified `interface.enum` names are
+    /// looked up in the current protocol first, then in the other protocols of the same family
+    /// and finally in every other family, failing if a step yields more than one match.
+    ///
+    /// Enums of other protocols are referenced through the root, unless the protocols being
+    /// generated are not part of a family, in which case they are referenced through the local
+    /// root.
+    fn resolve_enum(
+        &self,
+        root: &TokenStream,
+        protocol: &Protocol,
+        interface: &Interface,
+        message: &Message,
+        arg: &Arg,
+        reference: &str,
+    ) -> Result<TokenStream, Error> {
+        let kind = if interface
+            .requests
+            .iter()
+            .any(|request| std::ptr::eq(request, message))
+        {
+            "request"
+        } else {
+            "event"
+        };
+
+        let not_found = |candidates: Vec<String>| Error::NotFound {
+            name: format!("enum {reference}"),
+            candidates,
+            location: protocol.locate(&[
+                ("interface", &interface.name),
+                (kind, &message.name),
+                ("arg", &arg.name),
+            ]),
+        };
+
+        let Some((interface_name, enum_name)) = reference.split_once('.') else {
+            if interface.enums.iter().any(|e| e.name == reference) {
+                return Ok(make_ident(reference.to_upper_camel_case()).to_token_stream());
+            }
+
+            return Err(not_found(Vec::new()));
+        };
+
+        let defines = |interface: &Interface| {
+            interface.name == interface_name && interface.enums.iter().any(|e| e.name == enum_name)
+        };
+
+        let interface_module = make_ident(interface_name);
+        let enum_ident = make_ident(enum_name.to_upper_camel_case());
+
+        if protocol.interfaces.iter().any(defines) {
+            return Ok(quote! { super::#interface_module::#enum_ident });
+        }
+
+        let candidates: Vec<&Protocol> = self
+            .xml
+            .iter()
+            .filter(|protocol| protocol.interfaces.iter().any(defines))
+            .collect();
+
+        match candidates.as_slice() {
+            [] => {}
+            [candidate] => {
+                let protocol_module = make_ident(&candidate.name);
+
+                return Ok(match self.family() {
+                    Some(family) => {
+                        let family_module = make_ident(family);
+
+                        quote! { #root::#family_module::#protocol_module::#interface_module::#enum_ident }
                     }
-                }
+                    None => {
+                        let local_root =
+                            self.local_root.clone().unwrap_or_else(|| quote! { crate });
+
+                        quote! { #local_root::#protocol_module::#interface_module::#enum_ident }
+                    }
+                });
+            }
+            candidates => {
+                return Err(not_found(
+                    candidates.iter().map(|p| p.name.clone()).collect(),
+                ));
             }
+        }
+
+        let mut candidates: Vec<(&str, &Protocol)> = self
+            .protocols
+            .iter()
+            .flat_map(|(family, protocols)| {
+                protocols
+                    .iter()
+                    .filter(|protocol| protocol.interfaces.iter().any(defines))
+                    .map(|protocol| (*family, protocol))
+            })
+            .collect();
 
-            return Ok(make_ident(e.to_upper_camel_case()).to_token_stream());
+        candidates.sort_by_key(|(family, protocol)| (*family, protocol.name.as_str()));
+
+        match candidates.as_slice() {
+            [] => Err(not_found(Vec::new())),
+            [(family, candidate)] => {
+                let family_module = make_ident(family);
+                let protocol_module = make_ident(&candidate.name);
+
+                Ok(
+                    quote! { #root::#family_module::#protocol_module::#interface_module::#enum_ident },
+                )
+            }
+            candidates => Err(not_found(
+                candidates
+                    .iter()
+                    .map(|(family, p)| format!("{family}::{}", p.name))
+                    .collect(),
+            )),
         }
+    }
+}
 
-        Ok(arg.to_underlying_type_token(generate_body))
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use quote::quote;
+
+    use super::ProtocolGenerator;
+    use crate::parser::Protocol;
+
+    const DEFINES: &str = r#"<protocol name="defines">
+        <interface name="defines_thing" version="1">
+            <enum name="mode">
+                <entry name="on" value="0"/>
+            </enum>
+        </interface>
+    </protocol>"#;
+
+    const USES: &str = r#"<protocol name="uses">
+        <interface name="uses_thing" version="1">
+            <request name="set">
+                <arg name="mode" type="uint" enum="defines_thing.mode"/>
+            </request>
+        </interface>
+    </protocol>"#;
+
+    fn module<'a>(items: &'a [syn::Item], name: &str) -> &'a [syn::Item] {
+        items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Mod(module) if module.ident == name => Some(&module.content.as_ref()?.1),
+                _ => None,
+            })
+            .expect("Missing module")
+    }
+
+    /// The path of the type of the `mode` argument of `uses_thing.set`, as generated by `generator`.
+    fn mode_type(generator: ProtocolGenerator) -> Vec<String> {
+        let modules = generator
+            .generate_protocols(false, true)
+            .expect("Failed to generate protocols");
+        let file: syn::File = syn::parse2(quote! { #(#modules)* }).expect("Invalid code");
+
+        let set = module(module(&file.items, "uses"), "uses_thing")
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Trait(handler) => handler.items.iter().find_map(|item| match item {
+                    syn::TraitItem::Fn(function) if function.sig.ident == "set" => {
+                        Some(&function.sig)
+                    }
+                    _ => None,
+                }),
+                _ => None,
+            })
+            .expect("Missing set handler");
+
+        let Some(syn::FnArg::Typed(mode)) = set.inputs.last() else {
+            panic!("Missing mode argument");
+        };
+        let syn::Type::Path(path) = &*mode.ty else {
+            panic!("Unexpected mode type");
+        };
+
+        path.path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_enums_across_protocols() {
+        let protocols: Vec<Protocol> = [DEFINES, USES]
+            .iter()
+            .map(|xml| xml.parse().expect("Failed to parse protocol"))
+            .collect();
+
+        // Protocols of a family go through the root
+        let families = HashMap::from([("extra", protocols.clone())]);
+
+        assert_eq!(
+            mode_type(ProtocolGenerator::new(&families["extra"], &families)),
+            [
+                "crate",
+                "server",
+                "extra",
+                "defines",
+                "defines_thing",
+                "Mode"
+            ]
+        );
+
+        // Standalone protocols go through the local root, the crate root by default
+        let families = HashMap::new();
+
+        assert_eq!(
+            mode_type(ProtocolGenerator::new(&protocols, &families)),
+            ["crate", "defines", "defines_thing", "Mode"]
+        );
+
+        assert_eq!(
+            mode_type(
+                ProtocolGenerator::new(&protocols, &families)
+                    .with_local_root(quote! { crate::protocols })
+            ),
+            ["crate", "protocols", "defines", "defines_thing", "Mode"]
+        );
     }
 }
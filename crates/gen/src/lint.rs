@@ -0,0 +1,532 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    parser::{ArgType, Enum, Interface, Location, Message, MessageType, Protocol},
+    utils::{value_to_i64, value_to_u32},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem found while validating a protocol.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub location: Location,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.location, self.severity, self.message)
+    }
+}
+
+/// Validates a protocol, returning every problem found.
+///
+/// `protocols` is the set of other protocols qualified enum references such as
+/// `wl_output.transform` may point into.
+pub fn lint(protocol: &Protocol, protocols: &[&Protocol]) -> Vec<Diagnostic> {
+    let mut linter = Linter {
+        protocol,
+        protocols,
+        diagnostics: Vec::new(),
+    };
+
+    linter.lint_protocol();
+
+    linter.diagnostics
+}
+
+/// Returns true if any of the diagnostics is an error.
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity == Severity::Error)
+}
+
+struct Linter<'a> {
+    protocol: &'a Protocol,
+    protocols: &'a [&'a Protocol],
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Linter<'_> {
+    fn report(&mut self, severity: Severity, path: &[(&str, &str)], message: String) {
+        let location = self.protocol.locate(path);
+
+        self.diagnostics.push(Diagnostic {
+            severity,
+            message,
+            location,
+        });
+    }
+
+    fn lint_protocol(&mut self) {
+        let mut names = HashSet::new();
+
+        for interface in &self.protocol.interfaces {
+            if !names.insert(&interface.name) {
+                self.report(
+                    Severity::Error,
+                    &[("interface", &interface.name)],
+                    format!("duplicate interface {}", interface.name),
+                );
+            }
+
+            self.lint_interface(interface);
+        }
+    }
+
+    fn lint_interface(&mut self, interface: &Interface) {
+        let path = ("interface", interface.name.as_str());
+
+        self.lint_messages(interface, &interface.requests, "request");
+        self.lint_messages(interface, &interface.events, "event");
+
+        if !interface.requests.is_empty()
+            && !interface
+                .requests
+                .iter()
+                .any(|request| request.ty == Some(MessageType::Destructor))
+        {
+            self.report(
+                Severity::Warning,
+                &[path],
+                format!("interface {} has no destructor request", interface.name),
+            );
+        }
+
+        let mut names = HashSet::new();
+
+        for e in &interface.enums {
+            if !names.insert(&e.name) {
+                self.report(
+                    Severity::Error,
+                    &[path, ("enum", &e.name)],
+                    format!("duplicate enum {}.{}", interface.name, e.name),
+                );
+            }
+
+            self.lint_enum(interface, e);
+        }
+    }
+
+    fn lint_messages(&mut self, interface: &Interface, messages: &[Message], kind: &str) {
+        let mut names = HashSet::new();
+        let mut previous_since = 1;
+
+        for message in messages {
+            let path = [
+                ("interface", interface.name.as_str()),
+                (kind, &message.name),
+            ];
+            let name = format!("{}.{}", interface.name, message.name);
+
+            if !names.insert(&message.name) {
+                self.report(Severity::Error, &path, format!("duplicate {kind} {name}"));
+            }
+
+            let since = message.since.unwrap_or(1) as u32;
+
+            if since > interface.version {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!(
+                        "{kind} {name} is available since version {since} but the interface is at version {}",
+                        interface.version
+                    ),
+                );
+            }
+
+            if since < previous_since {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!(
+                        "{kind} {name} is available since version {since} but follows a message available since version {previous_since}"
+                    ),
+                );
+            }
+
+            previous_since = previous_since.max(since);
+
+            if let Some(deprecated_since) = message.deprecated_since
+                && (deprecated_since as u32) < since
+            {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!(
+                        "{kind} {name} is deprecated since version {deprecated_since} before being introduced"
+                    ),
+                );
+            }
+
+            self.lint_args(interface, message, kind);
+        }
+    }
+
+    fn lint_args(&mut self, interface: &Interface, message: &Message, kind: &str) {
+        let interface_path = ("interface", interface.name.as_str());
+        let message_path = (kind, message.name.as_str());
+
+        let mut names = HashSet::new();
+        let mut generic_new_ids = 0;
+
+        for arg in &message.args {
+            let path = [interface_path, message_path, ("arg", &arg.name)];
+            let name = format!("{}.{}.{}", interface.name, message.name, arg.name);
+
+            if !names.insert(&arg.name) {
+                self.report(Severity::Error, &path, format!("duplicate argument {name}"));
+            }
+
+            if arg.interface.is_some() && !matches!(arg.ty, ArgType::Object | ArgType::NewId) {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!("argument {name} sets an interface but is not an object or new_id"),
+                );
+            }
+
+            if arg.ty == ArgType::NewId {
+                if arg.interface.is_none() {
+                    generic_new_ids += 1;
+
+                    if kind == "event" {
+                        self.report(
+                            Severity::Error,
+                            &path,
+                            format!("new_id argument {name} in an event must set an interface"),
+                        );
+                    }
+                }
+
+                if arg.allow_null {
+                    self.report(
+                        Severity::Error,
+                        &path,
+                        format!("new_id argument {name} cannot be null"),
+                    );
+                }
+            }
+
+            if arg.allow_null
+                && !matches!(arg.ty, ArgType::String | ArgType::Object | ArgType::NewId)
+            {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!("argument {name} allows null but is not a string or object"),
+                );
+            }
+
+            if let Some(reference) = &arg.r#enum {
+                if !matches!(arg.ty, ArgType::Int | ArgType::Uint) {
+                    self.report(
+                        Severity::Error,
+                        &path,
+                        format!("argument {name} references an enum but is not an int or uint"),
+                    );
+                }
+
+                if !self.enum_exists(interface, reference) {
+                    self.report(
+                        Severity::Error,
+                        &path,
+                        format!("argument {name} references missing enum {reference}"),
+                    );
+                }
+            }
+        }
+
+        if generic_new_ids > 1 {
+            self.report(
+                Severity::Error,
+                &[interface_path, message_path],
+                format!(
+                    "{kind} {}.{} has more than one new_id argument without an interface",
+                    interface.name, message.name
+                ),
+            );
+        }
+    }
+
+    fn lint_enum(&mut self, interface: &Interface, e: &Enum) {
+        let interface_path = ("interface", interface.name.as_str());
+        let enum_path = ("enum", e.name.as_str());
+
+        let mut names = HashSet::new();
+        let mut values: Vec<(&str, i64)> = Vec::new();
+
+        for entry in &e.entries {
+            let path = [interface_path, enum_path, ("entry", &entry.name)];
+            let name = format!("{}.{}.{}", interface.name, e.name, entry.name);
+
+            if !names.insert(&entry.name) {
+                self.report(Severity::Error, &path, format!("duplicate entry {name}"));
+            }
+
+            if entry.since.unwrap_or(1) as u32 > interface.version {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!(
+                        "entry {name} is available since version {} but the interface is at version {}",
+                        entry.since.unwrap_or(1),
+                        interface.version
+                    ),
+                );
+            }
+
+            // Flags are always unsigned, regular enums may use negative values for `int` arguments.
+            let value = if e.bitfield {
+                value_to_u32(&entry.value).map(i64::from)
+            } else {
+                value_to_i64(&entry.value)
+            };
+
+            let Some(value) = value else {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!("entry {name} has invalid value {}", entry.value),
+                );
+
+                continue;
+            };
+
+            if !e.bitfield
+                && let Some((other, _)) = values.iter().find(|(_, other)| *other == value)
+            {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!("entry {name} has the same value as {other}"),
+                );
+            }
+
+            values.push((&entry.name, value));
+        }
+
+        if !e.bitfield
+            && values.iter().any(|(_, value)| *value < 0)
+            && values.iter().any(|(_, value)| *value > i32::MAX.into())
+        {
+            self.report(
+                Severity::Error,
+                &[interface_path, enum_path],
+                format!(
+                    "enum {}.{} mixes negative values with values that do not fit an int",
+                    interface.name, e.name
+                ),
+            );
+        }
+
+        if e.bitfield {
+            let flags: Vec<_> = values
+                .iter()
+                .map(|(name, value)| (*name, *value as u32))
+                .collect();
+
+            self.lint_flags(interface, e, &flags);
+        }
+    }
+
+    /// Reports flags sharing bits with each other, unless both are just combinations of
+    /// other flags, such as `top_left` being `top | left`.
+    fn lint_flags(&mut self, interface: &Interface, e: &Enum, values: &[(&str, u32)]) {
+        let is_combination = |value: u32| {
+            let parts = values
+                .iter()
+                .map(|(_, other)| *other)
+                .filter(|other| *other != 0 && *other != value && other & !value == 0)
+                .fold(0, |acc, other| acc | other);
+
+            parts == value
+        };
+
+        for (index, (name, value)) in values.iter().enumerate() {
+            for (other, other_value) in &values[..index] {
+                let shared = value & other_value;
+
+                if shared == 0 || shared == *value || shared == *other_value {
+                    continue;
+                }
+
+                if is_combination(*value) && is_combination(*other_value) {
+                    continue;
+                }
+
+                self.report(
+                    Severity::Warning,
+                    &[
+                        ("interface", &interface.name),
+                        ("enum", &e.name),
+                        ("entry", name),
+                    ],
+                    format!(
+                        "flag {}.{}.{name} overlaps with {other}",
+                        interface.name, e.name
+                    ),
+                );
+            }
+        }
+    }
+
+    fn enum_exists(&self, interface: &Interface, reference: &str) -> bool {
+        let Some((interface_name, enum_name)) = reference.split_once('.') else {
+            return interface.enums.iter().any(|e| e.name == reference);
+        };
+
+        std::iter::once(self.protocol)
+            .chain(self.protocols.iter().copied())
+            .flat_map(|protocol| &protocol.interfaces)
+            .filter(|interface| interface.name == interface_name)
+            .any(|interface| interface.enums.iter().any(|e| e.name == enum_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Severity, lint};
+    use crate::parser::Protocol;
+
+    fn messages(xml: &str) -> Vec<(Severity, String)> {
+        let protocol: Protocol = xml.parse().expect("Failed to parse protocol");
+
+        lint(&protocol, &[])
+            .into_iter()
+            .map(|diagnostic| (diagnostic.severity, diagnostic.message))
+            .collect()
+    }
+
+    #[test]
+    fn valid_protocol() {
+        let diagnostics = messages(
+            r#"<protocol name="test">
+                <interface name="test_thing" version="2">
+                    <request name="destroy" type="destructor"/>
+                    <request name="set_mode" since="2">
+                        <arg name="mode" type="uint" enum="mode"/>
+                    </request>
+                    <request name="set_offset" since="2">
+                        <arg name="offset" type="int" enum="offset"/>
+                    </request>
+                    <enum name="mode">
+                        <entry name="on" value="0"/>
+                        <entry name="off" value="0x1"/>
+                    </enum>
+                    <enum name="offset">
+                        <entry name="before" value="-1"/>
+                        <entry name="after" value="1"/>
+                    </enum>
+                    <enum name="edge" bitfield="true">
+                        <entry name="top" value="1"/>
+                        <entry name="left" value="2"/>
+                        <entry name="top_left" value="3"/>
+                    </enum>
+                </interface>
+            </protocol>"#,
+        );
+
+        assert_eq!(diagnostics, Vec::new());
+    }
+
+    #[test]
+    fn invalid_protocol() {
+        let diagnostics = messages(
+            r#"<protocol name="test">
+                <interface name="test_thing" version="1">
+                    <request name="set" since="2">
+                        <arg name="mode" type="uint" enum="wl_output.missing"/>
+                        <arg name="mode" type="new_id"/>
+                        <arg name="other" type="new_id"/>
+                    </request>
+                    <request name="set"/>
+                    <enum name="mode">
+                        <entry name="on" value="zero"/>
+                        <entry name="off" value="1"/>
+                        <entry name="disabled" value="1"/>
+                    </enum>
+                    <enum name="edge" bitfield="true">
+                        <entry name="top" value="3"/>
+                        <entry name="left" value="6"/>
+                        <entry name="right" value="-8"/>
+                    </enum>
+                    <enum name="offset">
+                        <entry name="before" value="-1"/>
+                        <entry name="after" value="0xffffffff"/>
+                    </enum>
+                </interface>
+            </protocol>"#,
+        );
+
+        let expected = [
+            (
+                Severity::Error,
+                "request test_thing.set is available since version 2 but the interface is at version 1",
+            ),
+            (
+                Severity::Error,
+                "argument test_thing.set.mode references missing enum wl_output.missing",
+            ),
+            (Severity::Error, "duplicate argument test_thing.set.mode"),
+            (
+                Severity::Error,
+                "request test_thing.set has more than one new_id argument without an interface",
+            ),
+            (Severity::Error, "duplicate request test_thing.set"),
+            (
+                Severity::Error,
+                "request test_thing.set is available since version 1 but follows a message available since version 2",
+            ),
+            (
+                Severity::Warning,
+                "interface test_thing has no destructor request",
+            ),
+            (
+                Severity::Error,
+                "entry test_thing.mode.on has invalid value zero",
+            ),
+            (
+                Severity::Error,
+                "entry test_thing.mode.disabled has the same value as off",
+            ),
+            (
+                Severity::Error,
+                "entry test_thing.edge.right has invalid value -8",
+            ),
+            (
+                Severity::Warning,
+                "flag test_thing.edge.left overlaps with top",
+            ),
+            (
+                Severity::Error,
+                "enum test_thing.offset mixes negative values with values that do not fit an int",
+            ),
+        ];
+
+        assert_eq!(
+            diagnostics,
+            expected
+                .iter()
+                .map(|(severity, message)| (*severity, message.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use heck::ToShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    ProtocolGenerator,
+    error::Error,
+    parser::{ArgType, Message, MessageType},
+    protocol_feature,
+    utils::{deprecated_since_to_token, make_ident, since_to_token},
+};
+
+impl ProtocolGenerator<'_> {
+    /// Generates static [`waynest::InterfaceInfo`] tables for every interface, grouped by protocol,
+    /// along with an `INTERFACES` slice listing all of them.
+    pub fn generate_metadata(&self, family: &str) -> Result<TokenStream, Error> {
+        let mut modules = Vec::new();
+        let mut interfaces = Vec::new();
+
+        for protocol in self.xml {
+            let protocol_name = &protocol.name;
+            let protocol_module = make_ident(&protocol.name);
+
+            let features = self.feature_attrs(protocol);
+            let cfg = self.features.then(|| {
+                let feature = protocol_feature(protocol);
+                quote! { #[cfg(feature = #feature)] }
+            });
+
+            let mut statics = Vec::new();
+
+            for interface in &protocol.interfaces {
+                let name = &interface.name;
+                let version = interface.version;
+                let static_name = make_ident(interface.name.to_shouty_snake_case());
+
+                let requests = write_messages(&interface.requests);
+                let events = write_messages(&interface.events);
+                let enums = interface.enums.iter().map(|e| &e.name);
+
+                statics.push(quote! {
+                    pub static #static_name: waynest::InterfaceInfo = waynest::InterfaceInfo {
+                        name: #name,
+                        version: #version,
+                        protocol: #protocol_name,
+                        family: #family,
+                        requests: &[#(#requests),*],
+                        events: &[#(#events),*],
+                        enums: &[#(#enums),*],
+                    };
+                });
+
+                interfaces.push(quote! { #cfg &#protocol_module::#static_name });
+            }
+
+            modules.push(quote! {
+                #features
+                pub mod #protocol_module {
+                    #(#statics)*
+                }
+            });
+        }
+
+        Ok(quote! {
+            #(#modules)*
+
+            pub static INTERFACES: &[&waynest::InterfaceInfo] = &[#(#interfaces),*];
+        })
+    }
+}
+
+pub(crate) fn write_messages(messages: &[Message]) -> Vec<TokenStream> {
+    let mut infos = Vec::new();
+
+    for message in messages {
+        let name = &message.name;
+        let since = since_to_token(message.since);
+        let deprecated_since = deprecated_since_to_token(message.deprecated_since);
+        let destructor = message.ty == Some(MessageType::Destructor);
+
+        let mut signature = String::new();
+        let mut args = Vec::new();
+
+        if since > 1 {
+            signature.push_str(&since.to_string());
+        }
+
+        for arg in &message.args {
+            if arg.allow_null {
+                signature.push('?');
+            }
+
+            if arg.ty == ArgType::NewId && arg.interface.is_none() {
+                signature.push_str("sun");
+            } else {
+                signature.push(arg.ty.signature_char());
+            }
+
+            let name = &arg.name;
+            let kind = match arg.ty {
+                ArgType::Int => quote! { waynest::ArgKind::Int },
+                ArgType::Uint => quote! { waynest::ArgKind::Uint },
+                ArgType::Fixed => quote! { waynest::ArgKind::Fixed },
+                ArgType::String => quote! { waynest::ArgKind::String },
+                ArgType::Object => quote! { waynest::ArgKind::Object },
+                ArgType::NewId => quote! { waynest::ArgKind::NewId },
+                ArgType::Array => quote! { waynest::ArgKind::Array },
+                ArgType::Fd => quote! { waynest::ArgKind::Fd },
+            };
+            let interface = option_to_token(arg.interface.as_deref());
+            let r#enum = option_to_token(arg.r#enum.as_deref());
+            let allow_null = arg.allow_null;
+
+            args.push(quote! {
+                waynest::ArgInfo {
+                    name: #name,
+                    kind: #kind,
+                    interface: #interface,
+                    r#enum: #r#enum,
+                    allow_null: #allow_null,
+                }
+            });
+        }
+
+        infos.push(quote! {
+            waynest::MessageInfo {
+                name: #name,
+                since: #since,
+                deprecated_since: #deprecated_since,
+                destructor: #destructor,
+                signature: #signature,
+                args: &[#(#args),*],
+            }
+        });
+    }
+
+    infos
+}
+
+fn option_to_token(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
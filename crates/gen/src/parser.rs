@@ -1,6 +1,12 @@
-use std::{fs, path::Path};
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
 
 use proc_macro2::TokenStream;
+use quick_xml::{Reader, events::Event};
 use quote::quote;
 use serde::{Deserialize, Serialize};
 
@@ -15,11 +21,124 @@ pub struct Protocol {
     pub description: Option<String>,
     #[serde(default, rename(deserialize = "interface"))]
     pub interfaces: Vec<Interface>,
+    /// The file this protocol was loaded from, used to report locations
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+    /// The XML this protocol was parsed from, used to report locations
+    #[serde(skip)]
+    source: Option<Arc<str>>,
+}
+
+impl FromStr for Protocol {
+    type Err = Error;
+
+    fn from_str(source: &str) -> Result<Self, Error> {
+        let mut protocol: Self = quick_xml::de::from_str(source)?;
+        protocol.source = Some(source.into());
+
+        Ok(protocol)
+    }
 }
 
 impl Protocol {
+    /// Creates a protocol that was not parsed from XML, such as one rebuilt from the metadata
+    /// of `waynest-protocols`.
+    pub fn new(name: impl Into<String>, interfaces: Vec<Interface>) -> Self {
+        Self {
+            name: name.into(),
+            copyright: None,
+            description: None,
+            interfaces,
+            path: None,
+            source: None,
+        }
+    }
+
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
-        Ok(quick_xml::de::from_str(&fs::read_to_string(path)?)?)
+        let mut protocol: Self = fs::read_to_string(&path)?.parse()?;
+        protocol.path = Some(path.as_ref().to_path_buf());
+
+        Ok(protocol)
+    }
+
+    /// Finds the line of the element reached by following `path` from the protocol element,
+    /// given as pairs of element and `name` attribute, e.g.
+    /// `[("interface", "wl_surface"), ("request", "attach")]`. Falls back to the deepest
+    /// element of the path that could be found.
+    pub fn locate(&self, path: &[(&str, &str)]) -> Location {
+        let line = self.source.as_deref().and_then(|source| {
+            let mut reader = Reader::from_str(source);
+            // Whether each open element is part of the path, and how many of them are
+            let mut open: Vec<bool> = Vec::new();
+            let mut matched = 0;
+            let mut found = (0, None);
+
+            while found.0 < path.len() {
+                let position = reader.buffer_position() as usize;
+
+                let (element, empty) = match reader.read_event() {
+                    Ok(Event::Start(element)) => (element, false),
+                    Ok(Event::Empty(element)) => (element, true),
+                    Ok(Event::End(_)) => {
+                        if open.pop() == Some(true) {
+                            matched -= 1;
+                        }
+
+                        continue;
+                    }
+                    Ok(Event::Eof) | Err(_) => break,
+                    Ok(_) => continue,
+                };
+
+                // The protocol element itself is not part of the path
+                let is_match = !open.is_empty()
+                    && open.len() - 1 == matched
+                    && path.get(matched).is_some_and(|(kind, name)| {
+                        element.name().as_ref() == kind.as_bytes()
+                            && element
+                                .try_get_attribute("name")
+                                .ok()
+                                .flatten()
+                                .is_some_and(|attribute| {
+                                    attribute.value.as_ref() == name.as_bytes()
+                                })
+                    });
+
+                if is_match {
+                    let line = source[..position].matches('\n').count() + 1;
+                    found = (matched + 1, Some(line));
+                }
+
+                if !empty {
+                    open.push(is_match);
+                    matched += usize::from(is_match);
+                }
+            }
+
+            found.1
+        });
+
+        Location {
+            path: self.path.clone(),
+            line,
+        }
+    }
+}
+
+/// A position in a protocol XML file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.line) {
+            (Some(path), Some(line)) => write!(f, "{}:{line}", path.display()),
+            (Some(path), None) => path.display().fmt(f),
+            (None, _) => write!(f, "<unknown>"),
+        }
     }
 }
 
@@ -111,6 +230,19 @@ impl ArgType {
     pub const fn is_array(&self) -> bool {
         matches!(self, Self::Array)
     }
+
+    pub const fn signature_char(&self) -> char {
+        match self {
+            Self::Int => 'i',
+            Self::Uint => 'u',
+            Self::Fixed => 'f',
+            Self::String => 's',
+            Self::Object => 'o',
+            Self::NewId => 'n',
+            Self::Array => 'a',
+            Self::Fd => 'h',
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -176,10 +308,6 @@ impl Arg {
     }
 
     pub fn to_caller(&self) -> &str {
-        if self.r#enum.is_some() {
-            return "uint";
-        }
-
         match self.ty {
             ArgType::Int => "int",
             ArgType::Uint => "uint",
@@ -198,3 +326,69 @@ impl Arg {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgType, Protocol};
+
+    const PROTOCOL: &str = r#"<protocol name="test">
+  <interface name="test_thing" version="1">
+    <request name="set">
+      <arg name="mode" type="uint" enum="mode"/>
+    </request>
+    <event name="set"/>
+    <enum name="mode">
+      <entry name="on" value="0"/>
+    </enum>
+  </interface>
+  <interface name="test_thing_manager" version="1"/>
+</protocol>"#;
+
+    #[test]
+    fn locates_elements_by_path() {
+        let protocol: Protocol = PROTOCOL.parse().expect("Failed to parse protocol");
+        let line = |path: &[(&str, &str)]| protocol.locate(path).line;
+
+        assert_eq!(line(&[("interface", "test_thing_manager")]), Some(11));
+        assert_eq!(
+            line(&[("interface", "test_thing"), ("event", "set")]),
+            Some(6)
+        );
+        assert_eq!(
+            line(&[
+                ("interface", "test_thing"),
+                ("enum", "mode"),
+                ("entry", "on")
+            ]),
+            Some(8)
+        );
+
+        // Paths that can't be followed to the end fall back to the deepest element found
+        assert_eq!(
+            line(&[("interface", "test_thing"), ("enum", "missing")]),
+            Some(2)
+        );
+        assert_eq!(line(&[("interface", "missing")]), None);
+    }
+
+    #[test]
+    fn enum_arguments_keep_their_wire_type() {
+        let protocol: Protocol = r#"<protocol name="test">
+            <interface name="test_thing" version="1">
+                <request name="set">
+                    <arg name="mode" type="uint" enum="mode"/>
+                    <arg name="offset" type="int" enum="offset"/>
+                </request>
+            </interface>
+        </protocol>"#
+            .parse()
+            .expect("Failed to parse protocol");
+
+        let args = &protocol.interfaces[0].requests[0].args;
+
+        assert_eq!(args[0].ty, ArgType::Uint);
+        assert_eq!(args[1].ty, ArgType::Int);
+        assert_eq!(args[0].to_caller(), "uint");
+        assert_eq!(args[1].to_caller(), "int");
+    }
+}
@@ -1,9 +1,13 @@
-use heck::ToUpperCamelCase;
+use heck::{ToSnekCase, ToUpperCamelCase};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::{collections::VecDeque, fmt::Display};
 
-use crate::parser::Interface;
+use crate::{
+    error::Error,
+    lint::{Diagnostic, Severity},
+    parser::{Enum, Interface, Protocol},
+};
 
 const KEYWORDS: [&str; 52] = [
     "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
@@ -13,7 +17,18 @@ const KEYWORDS: [&str; 52] = [
     "override", "priv", "typeof", "unsized", "virtual", "yield", "try", "gen",
 ];
 
+/// Parameters every handler takes ahead of the message arguments.
+const HANDLER_PARAMS: [&str; 3] = ["connection", "state", "sender_id"];
+
 pub fn description_to_docs(description: Option<&String>) -> Vec<TokenStream> {
+    description_to_lines(description)
+        .iter()
+        .map(|doc| quote! { #[doc = #doc]})
+        .collect()
+}
+
+/// Splits a description into trimmed lines, dropping the empty first and last ones.
+pub fn description_to_lines(description: Option<&String>) -> VecDeque<&str> {
     let mut docs = VecDeque::new();
 
     if let Some(description) = description {
@@ -35,17 +50,74 @@ pub fn description_to_docs(description: Option<&String>) -> Vec<TokenStream> {
         docs.pop_back();
     }
 
-    docs.iter().map(|doc| quote! { #[doc = #doc]}).collect()
+    docs
+}
+
+pub fn since_to_docs(since: Option<usize>, deprecated_since: Option<usize>) -> Vec<TokenStream> {
+    let mut docs = Vec::new();
+
+    if let Some(since) = since {
+        let doc = format!("Available since version {since}");
+        docs.push(quote! { #[doc = ""] });
+        docs.push(quote! { #[doc = #doc] });
+    }
+
+    if let Some(deprecated_since) = deprecated_since {
+        let doc = format!("Deprecated since version {deprecated_since}");
+        docs.push(quote! { #[doc = ""] });
+        docs.push(quote! { #[doc = #doc] });
+    }
+
+    docs
+}
+
+pub fn deprecated_attr(deprecated_since: Option<usize>) -> TokenStream {
+    match deprecated_since {
+        Some(version) => {
+            let note = format!("deprecated since version {version} of the interface");
+            quote! { #[deprecated(note = #note)] }
+        }
+        None => quote! {},
+    }
+}
+
+pub fn since_to_token(since: Option<usize>) -> u32 {
+    since.map_or(1, |since| since as u32)
+}
+
+pub fn deprecated_since_to_token(deprecated_since: Option<usize>) -> TokenStream {
+    match deprecated_since {
+        Some(version) => {
+            let version = version as u32;
+            quote! { Some(#version) }
+        }
+        None => quote! { None },
+    }
 }
 
-pub fn value_to_u32(value: &str) -> u32 {
+pub fn value_to_u32(value: &str) -> Option<u32> {
     if let Some(s) = value.strip_prefix("0x") {
-        u32::from_str_radix(s, 16).expect("Invalid enum value")
+        u32::from_str_radix(s, 16).ok()
     } else {
-        value.parse().expect("Invalid enum value")
+        value.parse().ok()
+    }
+}
+
+/// Parses the value of a regular enum entry, which unlike flags may be negative.
+pub fn value_to_i64(value: &str) -> Option<i64> {
+    match value.strip_prefix('-') {
+        Some(value) => Some(-i64::from(value_to_u32(value)?)).filter(|v| *v >= i32::MIN.into()),
+        None => value_to_u32(value).map(i64::from),
     }
 }
 
+/// Whether the entries of a regular enum need a signed representation.
+pub fn is_signed(e: &Enum) -> bool {
+    e.entries
+        .iter()
+        .any(|entry| value_to_i64(&entry.value).is_some_and(|value| value < 0))
+}
+
 pub fn make_ident<D: Display>(ident: D) -> Ident {
     let mut prefix = "";
 
@@ -62,49 +134,160 @@ pub fn make_ident<D: Display>(ident: D) -> Ident {
     format_ident!("{raw}{prefix}{ident}")
 }
 
-pub fn write_enums(interface: &Interface) -> Vec<TokenStream> {
+/// The identifier of a message argument, suffixed with `_arg` when it would clash with one of
+/// the parameters every handler takes.
+pub fn make_arg_ident(name: &str) -> Ident {
+    let name = name.to_snek_case();
+
+    if HANDLER_PARAMS.contains(&name.as_str()) {
+        return format_ident!("{name}_arg");
+    }
+
+    make_ident(name)
+}
+
+pub fn write_enums(protocol: &Protocol, interface: &Interface) -> Result<Vec<TokenStream>, Error> {
     let mut enums = Vec::new();
 
     for e in &interface.enums {
         let docs = description_to_docs(e.description.as_ref());
+        let since_docs = since_to_docs(e.since, e.deprecated_since);
         let name = make_ident(e.name.to_upper_camel_case());
 
+        let since = since_to_token(e.since);
+        let deprecated_since = deprecated_since_to_token(e.deprecated_since);
+
         if !e.bitfield {
             let mut variants = Vec::new();
             let mut match_variants = Vec::new();
+            let mut since_variants = Vec::new();
+            let mut deprecated_since_variants = Vec::new();
+            let mut str_variants = Vec::new();
+            let mut entries = Vec::new();
+            let signed = is_signed(e);
+            let (repr, other) = if signed {
+                (quote! { i32 }, quote! { u32 })
+            } else {
+                (quote! { u32 }, quote! { i32 })
+            };
 
             for entry in &e.entries {
                 let docs = description_to_docs(entry.summary.as_ref());
+                let since_docs = since_to_docs(entry.since, entry.deprecated_since);
+                let deprecated = deprecated_attr(entry.deprecated_since);
                 let name = make_ident(entry.name.to_upper_camel_case());
-                let value = value_to_u32(&entry.value);
+                let value = value_to_i64(&entry.value)
+                    .filter(|value| !signed || *value <= i32::MAX.into())
+                    .ok_or_else(|| {
+                        Error::Lint(vec![Diagnostic {
+                            severity: Severity::Error,
+                            message: format!(
+                                "entry {}.{}.{} has invalid value {}",
+                                interface.name, e.name, entry.name, entry.value
+                            ),
+                            location: protocol.locate(&[
+                                ("interface", &interface.name),
+                                ("enum", &e.name),
+                                ("entry", &entry.name),
+                            ]),
+                        }])
+                    })?;
+                let value = if signed {
+                    let value = value as i32;
+                    quote! { #value }
+                } else {
+                    let value = value as u32;
+                    quote! { #value }
+                };
 
                 variants.push(quote! {
                     #(#docs)*
+                    #(#since_docs)*
+                    #deprecated
                     #name = #value
                 });
 
                 match_variants.push(quote! { #value => { Ok(Self::#name) } });
+
+                let entry_since = since_to_token(entry.since);
+                let entry_deprecated_since = deprecated_since_to_token(entry.deprecated_since);
+
+                since_variants.push(quote! { Self::#name => #entry_since });
+                deprecated_since_variants.push(quote! { Self::#name => #entry_deprecated_since });
+
+                let entry_name = &entry.name;
+
+                str_variants.push(quote! { Self::#name => #entry_name });
+                entries.push(quote! { (#entry_name, Self::#name) });
             }
 
             enums.push(quote! {
                 #(#docs)*
-                #[repr(u32)]
+                #(#since_docs)*
+                #[repr(#repr)]
                 #[non_exhaustive]
                 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
                 pub enum #name {
                     #(#variants),*
                 }
 
+                #[allow(deprecated)]
+                impl #name {
+                    /// The interface version this enum was introduced in
+                    pub const SINCE: u32 = #since;
+                    /// The interface version this enum was deprecated in, if any
+                    pub const DEPRECATED_SINCE: Option<u32> = #deprecated_since;
+
+                    /// The interface version this entry was introduced in
+                    pub const fn since(&self) -> u32 {
+                        match self {
+                            #(#since_variants),*
+                        }
+                    }
+
+                    /// The interface version this entry was deprecated in, if any
+                    pub const fn deprecated_since(&self) -> Option<u32> {
+                        match self {
+                            #(#deprecated_since_variants),*
+                        }
+                    }
+
+                    /// Every entry of this enum along with its name in the protocol XML
+                    pub const ENTRIES: &'static [(&'static str, Self)] = &[#(#entries),*];
+
+                    /// The name of this entry in the protocol XML
+                    pub const fn as_str(&self) -> &'static str {
+                        match self {
+                            #(#str_variants),*
+                        }
+                    }
+
+                    /// Looks up an entry by its name in the protocol XML
+                    pub fn from_protocol_name(name: &str) -> Option<Self> {
+                        Self::ENTRIES
+                            .iter()
+                            .find(|(entry, _)| *entry == name)
+                            .map(|(_, value)| *value)
+                    }
+                }
+
                 impl From<#name> for u32 {
                     fn from(value: #name) -> Self {
                         value as u32
                     }
                 }
 
-                impl TryFrom<u32> for #name {
+                impl From<#name> for i32 {
+                    fn from(value: #name) -> Self {
+                        value as i32
+                    }
+                }
+
+                #[allow(deprecated)]
+                impl TryFrom<#repr> for #name {
                     type Error = waynest::ProtocolError;
 
-                    fn try_from(v: u32) -> Result<Self, Self::Error> {
+                    fn try_from(v: #repr) -> Result<Self, Self::Error> {
                         match v {
                             #(#match_variants),*
                             _ => Err(waynest::ProtocolError::MalformedPayload)
@@ -112,24 +295,53 @@ pub fn write_enums(interface: &Interface) -> Vec<TokenStream> {
                     }
                 }
 
+                impl TryFrom<#other> for #name {
+                    type Error = waynest::ProtocolError;
+
+                    fn try_from(v: #other) -> Result<Self, Self::Error> {
+                        Self::try_from(v as #repr)
+                    }
+                }
+
                 impl std::fmt::Display for #name {
                     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        (*self as u32).fmt(f)
+                        f.write_str(self.as_str())
                     }
                 }
             })
         } else {
             let mut variants = Vec::new();
+            let mut entries = Vec::new();
 
             for entry in &e.entries {
                 let name = make_ident(entry.name.to_upper_camel_case());
+                let entry_name = &entry.name;
 
-                let docs = description_to_docs(entry.summary.as_ref());
+                entries.push(quote! { (#entry_name, Self::#name) });
 
-                let value = value_to_u32(&entry.value);
+                let docs = description_to_docs(entry.summary.as_ref());
+                let since_docs = since_to_docs(entry.since, entry.deprecated_since);
+                let deprecated = deprecated_attr(entry.deprecated_since);
+
+                let value = value_to_u32(&entry.value).ok_or_else(|| {
+                    Error::Lint(vec![Diagnostic {
+                        severity: Severity::Error,
+                        message: format!(
+                            "entry {}.{}.{} has invalid value {}",
+                            interface.name, e.name, entry.name, entry.value
+                        ),
+                        location: protocol.locate(&[
+                            ("interface", &interface.name),
+                            ("enum", &e.name),
+                            ("entry", &entry.name),
+                        ]),
+                    }])
+                })?;
 
                 variants.push(quote! {
                     #(#docs)*
+                    #(#since_docs)*
+                    #deprecated
                     const #name = #value;
                 });
             }
@@ -137,12 +349,32 @@ pub fn write_enums(interface: &Interface) -> Vec<TokenStream> {
             enums.push(quote! {
                 bitflags::bitflags! {
                     #(#docs)*
+                    #(#since_docs)*
                     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
                     pub struct #name: u32 {
                         #(#variants)*
                     }
                 }
 
+                #[allow(deprecated)]
+                impl #name {
+                    /// The interface version this enum was introduced in
+                    pub const SINCE: u32 = #since;
+                    /// The interface version this enum was deprecated in, if any
+                    pub const DEPRECATED_SINCE: Option<u32> = #deprecated_since;
+
+                    /// Every flag of this enum along with its name in the protocol XML
+                    pub const ENTRIES: &'static [(&'static str, Self)] = &[#(#entries),*];
+
+                    /// Looks up a single flag by its name in the protocol XML
+                    pub fn from_protocol_name(name: &str) -> Option<Self> {
+                        Self::ENTRIES
+                            .iter()
+                            .find(|(entry, _)| *entry == name)
+                            .map(|(_, value)| *value)
+                    }
+                }
+
                 impl From<#name> for u32 {
                     fn from(value: #name) -> Self {
                         value.bits()
@@ -159,12 +391,210 @@ pub fn write_enums(interface: &Interface) -> Vec<TokenStream> {
 
                 impl std::fmt::Display for #name {
                     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        self.bits().fmt(f)
+                        if self.is_empty() {
+                            return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty()) {
+                                Some((name, _)) => f.write_str(name),
+                                None => f.write_str("0"),
+                            };
+                        }
+
+                        let mut remaining = *self;
+                        let mut first = true;
+
+                        for (name, value) in Self::ENTRIES {
+                            if value.is_empty() || !self.contains(*value) || !remaining.intersects(*value) {
+                                continue;
+                            }
+
+                            if !first {
+                                f.write_str("|")?;
+                            }
+
+                            f.write_str(name)?;
+                            remaining.remove(*value);
+                            first = false;
+                        }
+
+                        if !remaining.is_empty() {
+                            if !first {
+                                f.write_str("|")?;
+                            }
+
+                            write!(f, "{:#x}", remaining.bits())?;
+                        }
+
+                        Ok(())
                     }
                 }
             })
         }
     }
 
-    enums
+    Ok(enums)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{make_arg_ident, write_enums};
+    use crate::parser::Protocol;
+
+    const PROTOCOL: &str = r#"<protocol name="test">
+        <interface name="test_thing" version="1">
+            <request name="set">
+                <arg name="mode" type="uint" enum="mode"/>
+                <arg name="transform" type="int" enum="transform"/>
+                <arg name="offset" type="int" enum="offset"/>
+            </request>
+            <enum name="mode">
+                <entry name="on" value="0"/>
+                <entry name="off" value="1"/>
+            </enum>
+            <enum name="transform">
+                <entry name="normal" value="0"/>
+                <entry name="flipped" value="4"/>
+            </enum>
+            <enum name="offset">
+                <entry name="before" value="-1"/>
+                <entry name="after" value="1"/>
+            </enum>
+        </interface>
+    </protocol>"#;
+
+    /// The parts of a generated enum that depend on the wire type of its entries.
+    struct GeneratedEnum {
+        repr: String,
+        variants: Vec<(String, i64)>,
+        /// The integer types the enum can be converted from
+        try_from: Vec<String>,
+    }
+
+    /// Parses back every enum generated for the first interface of `protocol`.
+    fn generated_enums(protocol: &Protocol) -> Vec<GeneratedEnum> {
+        let int_type = |ty: &syn::Type| match ty {
+            syn::Type::Path(path) => path.path.get_ident().map(ToString::to_string),
+            _ => None,
+        };
+
+        let value = |expr: &syn::Expr| match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(int),
+                ..
+            }) => int.base10_parse::<i64>().ok(),
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => match &**expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(int),
+                    ..
+                }) => int.base10_parse::<i64>().ok().map(|value| -value),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        write_enums(protocol, &protocol.interfaces[0])
+            .expect("Failed to generate enums")
+            .into_iter()
+            .map(|tokens| {
+                let file: syn::File = syn::parse2(tokens).expect("Invalid code");
+
+                let item = file
+                    .items
+                    .iter()
+                    .find_map(|item| match item {
+                        syn::Item::Enum(item) => Some(item),
+                        _ => None,
+                    })
+                    .expect("No enum was generated");
+
+                let repr = item
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path().is_ident("repr"))
+                    .and_then(|attr| attr.parse_args::<syn::Ident>().ok())
+                    .expect("The enum has no repr")
+                    .to_string();
+
+                let variants = item
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let (_, discriminant) =
+                            variant.discriminant.as_ref().expect("No discriminant");
+
+                        (
+                            variant.ident.to_string(),
+                            value(discriminant).expect("Invalid discriminant"),
+                        )
+                    })
+                    .collect();
+
+                let try_from = file
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        syn::Item::Impl(item) => item.trait_.as_ref(),
+                        _ => None,
+                    })
+                    .filter_map(|(_, path, _)| {
+                        let segment = path.segments.last()?;
+
+                        if segment.ident != "TryFrom" {
+                            return None;
+                        }
+
+                        match &segment.arguments {
+                            syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+                                syn::GenericArgument::Type(ty) => int_type(ty),
+                                _ => None,
+                            },
+                            _ => None,
+                        }
+                    })
+                    .collect();
+
+                GeneratedEnum {
+                    repr,
+                    variants,
+                    try_from,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn signed_enums() {
+        let protocol: Protocol = PROTOCOL.parse().expect("Failed to parse protocol");
+        let enums = generated_enums(&protocol);
+
+        let owned = |names: &[(&str, i64)]| -> Vec<(String, i64)> {
+            names
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect()
+        };
+
+        assert_eq!(enums.len(), 3);
+
+        for unsigned in &enums[..2] {
+            assert_eq!(unsigned.repr, "u32");
+            assert_eq!(unsigned.try_from, ["u32", "i32"]);
+        }
+
+        assert_eq!(enums[0].variants, owned(&[("On", 0), ("Off", 1)]));
+        assert_eq!(enums[1].variants, owned(&[("Normal", 0), ("Flipped", 4)]));
+
+        assert_eq!(enums[2].repr, "i32");
+        assert_eq!(enums[2].variants, owned(&[("Before", -1), ("After", 1)]));
+        assert_eq!(enums[2].try_from, ["i32", "u32"]);
+    }
+
+    #[test]
+    fn renames_clashing_args() {
+        assert_eq!(make_arg_ident("state"), "state_arg");
+        assert_eq!(make_arg_ident("serial"), "serial");
+        assert_eq!(make_arg_ident("type"), "r#type");
+    }
 }
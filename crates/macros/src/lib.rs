@@ -1,62 +1,384 @@
 use darling::FromDeriveInput;
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{DeriveInput, parse_macro_input};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{DeriveInput, parse_macro_input, parse_quote, spanned::Spanned};
 
 #[derive(FromDeriveInput)]
 #[darling(attributes(waynest))]
 struct Opts {
-    error: syn::Path,
-    connection: syn::Path,
+    /// The generated interface trait messages are dispatched to
+    interface: Option<syn::Path>,
+    error: Option<syn::Path>,
+    connection: Option<syn::Path>,
+    state: Option<syn::Path>,
 }
 
-#[proc_macro_derive(RequestDispatcher, attributes(waynest, connection))]
-pub fn derive_dispatcher(input: TokenStream) -> TokenStream {
+/// Implements `waynest_server::RequestDispatcher` by forwarding requests to the
+/// `handle_request` method of a generated interface trait.
+///
+/// ```ignore
+/// #[derive(RequestDispatcher)]
+/// #[waynest(interface = WlSurface)]
+/// struct Surface;
+/// ```
+///
+/// The connection and state types default to the ones of the interface implementation and the
+/// error type to the error of the connection. They can be overridden with `connection = Path`,
+/// `state = Path` and `error = Path`, in which case the error must implement `From` for the
+/// connection error. Without `interface` the `handle_request` method is resolved from the trait
+/// in scope, `connection` must be given and the state defaults to `()`.
+#[proc_macro_derive(RequestDispatcher, attributes(waynest))]
+pub fn derive_request_dispatcher(input: TokenStream) -> TokenStream {
+    derive_dispatcher(
+        input,
+        "waynest_server",
+        "RequestDispatcher",
+        "dispatch_request",
+        "handle_request",
+        "requests",
+        "REQUESTS",
+    )
+}
+
+/// Implements `waynest_client::EventDispatcher` by forwarding events to the `handle_event`
+/// method of a generated interface trait.
+///
+/// ```ignore
+/// #[derive(EventDispatcher)]
+/// #[waynest(interface = WlSurface)]
+/// struct Surface;
+/// ```
+///
+/// Accepts the same options as [`RequestDispatcher`](derive@RequestDispatcher).
+#[proc_macro_derive(EventDispatcher, attributes(waynest))]
+pub fn derive_event_dispatcher(input: TokenStream) -> TokenStream {
+    derive_dispatcher(
+        input,
+        "waynest_client",
+        "EventDispatcher",
+        "dispatch_event",
+        "handle_event",
+        "events",
+        "EVENTS",
+    )
+}
+
+fn derive_dispatcher(
+    input: TokenStream,
+    krate: &str,
+    dispatcher: &str,
+    dispatch: &str,
+    handle: &str,
+    messages: &str,
+    table: &str,
+) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let krate = format_ident!("{krate}");
+    let dispatcher = format_ident!("{dispatcher}");
+    let dispatch = format_ident!("{dispatch}");
+    let handle = format_ident!("{handle}");
+    let messages = format_ident!("{messages}");
+    let table = format_ident!("{table}");
     let ident = &input.ident;
 
-    let Opts { error, connection } = match Opts::from_derive_input(&input) {
+    let Opts {
+        interface,
+        error,
+        connection,
+        state,
+    } = match Opts::from_derive_input(&input) {
         Ok(v) => v,
         Err(err) => return err.write_errors().into(),
     };
 
+    let connection = match (connection, &interface) {
+        (Some(connection), _) => quote! { #connection },
+        (None, Some(interface)) => {
+            quote_spanned! { interface.span()=> <Self as #interface>::Connection }
+        }
+        (None, None) => {
+            return syn::Error::new(
+                ident.span(),
+                "either `#[waynest(interface = ...)]` or `#[waynest(connection = ...)]` is required",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let state = match (state, &interface) {
+        (Some(state), _) => quote! { #state },
+        (None, Some(interface)) => {
+            quote_spanned! { interface.span()=> <Self as #interface>::State }
+        }
+        (None, None) => quote! { () },
+    };
+
+    let error = match error {
+        Some(error) => quote! { #error },
+        None => quote! { <#connection as waynest::Connection>::Error },
+    };
+
+    let handle = match &interface {
+        Some(interface) => quote_spanned! { interface.span()=>
+            <Self as #interface>::#handle(self, connection, state, sender_id, message)
+        },
+        None => quote! { self.#handle(connection, state, sender_id, message) },
+    };
+
+    // Interface names and signatures are only known for generated traits, others keep the
+    // defaults
+    let messages = interface.as_ref().map(|interface| {
+        quote_spanned! { interface.span()=>
+            fn interface(&self) -> Option<&'static str> {
+                Some(<Self as #interface>::INTERFACE)
+            }
+
+            fn #messages(&self) -> &'static [waynest::MessageInfo] {
+                <Self as #interface>::#table
+            }
+        }
+    });
+
+    let mut generics = input.generics.clone();
+
+    // The dispatcher is stored as a trait object shared across tasks
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(Send));
+        param.bounds.push(parse_quote!(Sync));
+        param.bounds.push(parse_quote!('static));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     quote! {
-        #[waynest_server::async_trait::async_trait]
-        impl waynest_server::RequestDispatcher for #ident {
+        #[#krate::async_trait::async_trait]
+        impl #impl_generics #krate::#dispatcher for #ident #ty_generics #where_clause {
             type Error = #error;
             type Connection = #connection;
+            type State = #state;
 
             fn as_any(self: std::sync::Arc<Self>) -> std::sync::Arc<dyn std::any::Any + Send + Sync + 'static> {
                 self
             }
 
-            async fn dispatch_request(
+            #messages
+
+            async fn #dispatch(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
             ) -> Result<(), Self::Error> {
-                self.handle_request(connection, sender_id, message).await
+                #handle.await.map_err(Into::into)
             }
         }
     }
     .into()
 }
 
+/// Generates the server side bindings of a protocol XML file, with a path relative to the
+/// crate's `CARGO_MANIFEST_DIR`. Several comma separated files can be passed, in which case
+/// enums can be referenced across them. Enums of the protocols bundled with
+/// `waynest-protocols` are referenced through `waynest_protocols::server`.
+///
+/// Enums referenced across the passed files are resolved from the crate root, unless the
+/// module the macro is invoked in is given first, e.g.
+/// `server_protocol!(root = crate::protocols; "a.xml", "b.xml")`.
 #[proc_macro]
 #[cfg(feature = "gen")]
 pub fn server_protocol(input: TokenStream) -> TokenStream {
-    use syn::LitStr;
-
-    let _xml = parse_macro_input!(input as LitStr);
-    quote! {}.into()
+    generate_protocol(input, false, true)
 }
 
+/// Generates the client side bindings of a protocol XML file, with a path relative to the
+/// crate's `CARGO_MANIFEST_DIR`. Several comma separated files can be passed, in which case
+/// enums can be referenced across them. Enums of the protocols bundled with
+/// `waynest-protocols` are referenced through `waynest_protocols::client`.
+///
+/// Enums referenced across the passed files are resolved from the crate root, unless the
+/// module the macro is invoked in is given first, e.g.
+/// `client_protocol!(root = crate::protocols; "a.xml", "b.xml")`.
 #[proc_macro]
 #[cfg(feature = "gen")]
 pub fn client_protocol(input: TokenStream) -> TokenStream {
-    use syn::LitStr;
+    generate_protocol(input, true, false)
+}
+
+#[cfg(feature = "gen")]
+fn generate_protocol(input: TokenStream, requests_body: bool, events_body: bool) -> TokenStream {
+    use std::path::PathBuf;
+
+    use syn::{
+        LitStr, Token,
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+    };
+    use waynest_gen::{
+        ProtocolGenerator,
+        lint::{self, Severity},
+        parser::Protocol,
+    };
+
+    struct Input {
+        root: Option<syn::Path>,
+        paths: Punctuated<LitStr, Token![,]>,
+    }
+
+    impl Parse for Input {
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            let root = if input.peek(syn::Ident) && input.peek2(Token![=]) {
+                let ident: syn::Ident = input.parse()?;
+
+                if ident != "root" {
+                    return Err(syn::Error::new(ident.span(), "expected `root`"));
+                }
+
+                input.parse::<Token![=]>()?;
+                let root = input.parse()?;
+                input.parse::<Token![;]>()?;
+
+                Some(root)
+            } else {
+                None
+            };
+
+            Ok(Self {
+                root,
+                paths: Punctuated::parse_terminated(input)?,
+            })
+        }
+    }
+
+    let Input {
+        root: local_root,
+        paths,
+    } = parse_macro_input!(input as Input);
+
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+
+    let mut protocols = Vec::new();
+    let mut includes = Vec::new();
+
+    for lit in &paths {
+        let path = manifest_dir.join(lit.value());
+
+        let protocol = match Protocol::from_path(&path) {
+            Ok(protocol) => protocol,
+            Err(err) => {
+                return syn::Error::new(
+                    lit.span(),
+                    format!("Failed to load {}: {err}", path.display()),
+                )
+                .to_compile_error()
+                .into();
+            }
+        };
+
+        let path = path.to_string_lossy().into_owned();
+        includes.push(quote! { const _: &[u8] = include_bytes!(#path); });
+
+        protocols.push((lit, protocol));
+    }
+
+    let families = bundled_families();
+    let known: Vec<&Protocol> = protocols
+        .iter()
+        .map(|(_, protocol)| protocol)
+        .chain(families.values().flatten())
+        .collect();
+    let mut errors = Vec::new();
+
+    for (lit, protocol) in &protocols {
+        for diagnostic in lint::lint(protocol, &known) {
+            if diagnostic.severity == Severity::Error {
+                errors.push(
+                    syn::Error::new(
+                        lit.span(),
+                        format!("{}: {}", diagnostic.location, diagnostic.message),
+                    )
+                    .to_compile_error(),
+                );
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return quote! { #(#errors)* }.into();
+    }
+
+    let span = paths.span();
+    let xml = protocols
+        .into_iter()
+        .map(|(_, protocol)| protocol)
+        .collect();
+    let root = if requests_body {
+        quote! { ::waynest_protocols::client }
+    } else {
+        quote! { ::waynest_protocols::server }
+    };
+
+    let local_root = local_root.map_or_else(|| quote! { crate }, |root| quote! { #root });
+
+    match ProtocolGenerator::new(&xml, &families)
+        .with_root(root)
+        .with_local_root(local_root)
+        .generate_protocols(requests_body, events_body)
+    {
+        Ok(modules) => quote! {
+            #(#includes)*
+            #(#modules)*
+        }
+        .into(),
+        Err(err) => syn::Error::new(span, err).to_compile_error().into(),
+    }
+}
+
+/// Rebuilds the protocols bundled with `waynest-protocols` from its metadata, grouped by family.
+/// Only their interfaces and the names of their enums are known, which is all that is needed to
+/// resolve enum references.
+#[cfg(feature = "gen")]
+fn bundled_families() -> std::collections::HashMap<&'static str, Vec<waynest_gen::parser::Protocol>>
+{
+    use std::collections::HashMap;
+
+    use waynest_gen::parser::{Enum, Interface, Protocol};
+
+    let mut families: HashMap<&'static str, Vec<Protocol>> = HashMap::new();
+
+    for info in waynest_protocols::metadata::interfaces() {
+        let protocols = families.entry(info.family).or_default();
+
+        let index = match protocols.iter().position(|p| p.name == info.protocol) {
+            Some(index) => index,
+            None => {
+                protocols.push(Protocol::new(info.protocol, Vec::new()));
+                protocols.len() - 1
+            }
+        };
+
+        protocols[index].interfaces.push(Interface {
+            name: info.name.to_string(),
+            version: info.version,
+            frozen: None,
+            description: None,
+            requests: Vec::new(),
+            events: Vec::new(),
+            enums: info
+                .enums
+                .iter()
+                .map(|name| Enum {
+                    name: name.to_string(),
+                    bitfield: false,
+                    since: None,
+                    deprecated_since: None,
+                    description: None,
+                    entries: Vec::new(),
+                })
+                .collect(),
+        });
+    }
 
-    let _xml = parse_macro_input!(input as LitStr);
-    quote! {}.into()
+    families
 }
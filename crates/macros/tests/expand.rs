@@ -0,0 +1,63 @@
+use std::{cell::RefCell, os::fd::OwnedFd};
+
+use waynest::{Message, MessageSender, ObjectId, ProtocolError};
+use waynest_protocols::{client, server};
+
+mod server_side {
+    waynest_macros::server_protocol!("tests/protocols/transformed.xml");
+}
+
+mod client_side {
+    waynest_macros::client_protocol!("tests/protocols/transformed.xml");
+}
+
+#[derive(Default)]
+struct Recorder(RefCell<Vec<Message>>);
+
+impl MessageSender for Recorder {
+    type Error = ProtocolError;
+
+    fn send_message(&self, message: Message, _fds: Vec<OwnedFd>) -> Result<(), Self::Error> {
+        self.0.borrow_mut().push(message);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn bundled_enums_resolve_through_waynest_protocols() {
+    use client::core::wayland::wl_output::Transform as ClientTransform;
+    use server::core::wayland::wl_output::Transform as ServerTransform;
+
+    let recorder = Recorder::default();
+    let surface = ObjectId::new(3).unwrap();
+
+    server_side::transformed::transformed_surface::Events::transform(
+        &recorder,
+        surface,
+        ServerTransform::Flipped90,
+    )
+    .unwrap();
+
+    client_side::transformed::transformed_surface::Requests::set_transform(
+        &recorder,
+        surface,
+        ClientTransform::Flipped180,
+    )
+    .unwrap();
+
+    let mut messages = recorder.0.into_inner();
+    assert_eq!(messages.len(), 2);
+
+    assert_eq!(messages[0].object_id(), surface);
+    assert_eq!(
+        messages[0].int().unwrap(),
+        ServerTransform::Flipped90 as i32
+    );
+
+    assert_eq!(messages[1].object_id(), surface);
+    assert_eq!(
+        messages[1].int().unwrap(),
+        ClientTransform::Flipped180 as i32
+    );
+}
@@ -1,46 +1,40 @@
+// This file was generated by waynest-gen, do not edit it manually.
+
 pub mod core;
-#[cfg(feature = "cosmic")]
-#[cfg_attr(docsrs, doc(cfg(feature = "cosmic")))]
-pub mod cosmic;
+pub mod stable;
+pub mod staging;
+pub mod unstable;
 #[cfg(feature = "experimental")]
 #[cfg_attr(docsrs, doc(cfg(feature = "experimental")))]
 pub mod experimental;
+pub mod wlr;
+#[cfg(feature = "plasma")]
+#[cfg_attr(docsrs, doc(cfg(feature = "plasma")))]
+pub mod plasma;
+#[cfg(feature = "weston")]
+#[cfg_attr(docsrs, doc(cfg(feature = "weston")))]
+pub mod weston;
+#[cfg(feature = "cosmic")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cosmic")))]
+pub mod cosmic;
 #[cfg(feature = "frog")]
 #[cfg_attr(docsrs, doc(cfg(feature = "frog")))]
 pub mod frog;
-#[cfg(feature = "hyprland")]
-#[cfg_attr(docsrs, doc(cfg(feature = "hyprland")))]
-pub mod hyprland;
 #[cfg(feature = "ivi")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ivi")))]
 pub mod ivi;
+#[cfg(feature = "hyprland")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hyprland")))]
+pub mod hyprland;
 #[cfg(feature = "mesa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mesa")))]
 pub mod mesa;
+#[cfg(feature = "treeland")]
+#[cfg_attr(docsrs, doc(cfg(feature = "treeland")))]
+pub mod treeland;
 #[cfg(feature = "mutter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mutter")))]
 pub mod mutter;
-#[cfg(feature = "plasma")]
-#[cfg_attr(docsrs, doc(cfg(feature = "plasma")))]
-pub mod plasma;
 #[cfg(feature = "river")]
 #[cfg_attr(docsrs, doc(cfg(feature = "river")))]
 pub mod river;
-#[cfg(feature = "stable")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stable")))]
-pub mod stable;
-#[cfg(feature = "staging")]
-#[cfg_attr(docsrs, doc(cfg(feature = "staging")))]
-pub mod staging;
-#[cfg(feature = "treeland")]
-#[cfg_attr(docsrs, doc(cfg(feature = "treeland")))]
-pub mod treeland;
-#[cfg(feature = "unstable")]
-#[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
-pub mod unstable;
-#[cfg(feature = "weston")]
-#[cfg_attr(docsrs, doc(cfg(feature = "weston")))]
-pub mod weston;
-#[cfg(feature = "wlr")]
-#[cfg_attr(docsrs, doc(cfg(feature = "wlr")))]
-pub mod wlr;
@@ -1,4 +1,8 @@
 #[cfg(feature = "client")]
+#[rustfmt::skip]
 pub mod client;
+#[rustfmt::skip]
+pub mod metadata;
 #[cfg(feature = "server")]
+#[rustfmt::skip]
 pub mod server;
@@ -0,0 +1,22 @@
+// This file was generated by waynest-gen, do not edit it manually.
+
+pub mod core;
+pub mod stable;
+pub mod staging;
+pub mod unstable;
+pub mod wlr;
+static FAMILIES: &[&[&waynest::InterfaceInfo]] = &[
+    core::INTERFACES,
+    stable::INTERFACES,
+    staging::INTERFACES,
+    unstable::INTERFACES,
+    wlr::INTERFACES,
+];
+/// Iterates over the metadata of every interface in the enabled protocol families
+pub fn interfaces() -> impl Iterator<Item = &'static waynest::InterfaceInfo> {
+    FAMILIES.iter().flat_map(|family| family.iter().copied())
+}
+/// Looks up the metadata of an interface by name across all the enabled protocol families
+pub fn lookup_interface(name: &str) -> Option<&'static waynest::InterfaceInfo> {
+    interfaces().find(|interface| interface.name == name)
+}
@@ -0,0 +1,2951 @@
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the wayland protocol (external/protocols/wayland/protocol/wayland.xml)
+//
+//   Copyright © 2008-2011 Kristian Høgsberg
+//   Copyright © 2010-2011 Intel Corporation
+//   Copyright © 2012-2013 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person
+//   obtaining a copy of this software and associated documentation files
+//   (the "Software"), to deal in the Software without restriction,
+//   including without limitation the rights to use, copy, modify, merge,
+//   publish, distribute, sublicense, and/or sell copies of the Software,
+//   and to permit persons to whom the Software is furnished to do so,
+//   subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the
+//   next paragraph) shall be included in all copies or substantial
+//   portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//   EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+//   MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+//   NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+//   BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+//   ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+//   CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+//   SOFTWARE.
+
+pub mod wayland {
+    pub static WL_DISPLAY: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_display",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "sync",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_callback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_registry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "registry",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_registry"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "error",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ous",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "object_id",
+                        kind: waynest::ArgKind::Object,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "code",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "message",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "delete_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WL_REGISTRY: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_registry",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "bind",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "usun",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "global",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "usu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "interface",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "version",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "global_remove",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WL_CALLBACK: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_callback",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[],
+        events: &[
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback_data",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WL_COMPOSITOR: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_compositor",
+        version: 7u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 7u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "7",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static WL_SHM_POOL: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_shm_pool",
+        version: 2u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "niiiiu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_shm.format"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static WL_SHM: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_shm",
+        version: 2u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_pool",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nhi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_shm_pool"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "2",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "format",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("format"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error", "format"],
+    };
+    pub static WL_BUFFER: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_buffer",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WL_DATA_OFFER: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_data_offer",
+        version: 4u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "accept",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "receive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "finish",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_actions",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dnd_actions",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "preferred_action",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "source_actions",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source_actions",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "action",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dnd_action",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WL_DATA_SOURCE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_data_source",
+        version: 4u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_actions",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dnd_actions",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "target",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "send",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cancelled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "dnd_drop_performed",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "dnd_finished",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "action",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dnd_action",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WL_DATA_DEVICE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_data_device",
+        version: 4u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "start_drag",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?oo?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "origin",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "icon",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "2",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "data_offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_data_offer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoff?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_offer"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "drop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_offer"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WL_DATA_DEVICE_MANAGER: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_data_device_manager",
+        version: 4u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_data_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_data_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_data_device"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "4",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["dnd_action"],
+    };
+    pub static WL_SHELL: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_shell",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_shell_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_shell_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WL_SHELL_SURFACE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_shell_surface",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "pong",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "move",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("resize"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_transient",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oiiu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("transient"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "method",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("fullscreen_method"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "framerate",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouoiiu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("transient"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_class",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "class_",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "ping",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("resize"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "popup_done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["resize", "transient", "fullscreen_method"],
+    };
+    pub static WL_SURFACE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_surface",
+        version: 7u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "attach",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "damage",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_callback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_opaque_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_input_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_buffer_transform",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_buffer_scale",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "damage_buffer",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "offset",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_release",
+                since: 7u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "7n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_callback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preferred_buffer_scale",
+                since: 6u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "6i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "factor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preferred_buffer_transform",
+                since: 6u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "6u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WL_SEAT: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_seat",
+        version: 10u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_keyboard",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_keyboard"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_touch",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_touch"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "5",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "capabilities",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capabilities",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("capability"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "name",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["capability", "error"],
+    };
+    pub static WL_POINTER: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_pointer",
+        version: 10u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface_x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface_y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface_x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface_y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuf",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "value",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "axis_source",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "axis_source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis_source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_stop",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_discrete",
+                since: 5u32,
+                deprecated_since: Some(8u32),
+                destructor: false,
+                signature: "5ui",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "discrete",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_value120",
+                since: 8u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "8ui",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "value120",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_relative_direction",
+                since: 9u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "9uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "direction",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("axis_relative_direction"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            "error",
+            "button_state",
+            "axis",
+            "axis_source",
+            "axis_relative_direction",
+        ],
+    };
+    pub static WL_KEYBOARD: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_keyboard",
+        version: 10u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "keymap",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uhu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("keymap_format"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoa",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "keys",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "key",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "key",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("key_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modifiers",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mods_depressed",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mods_latched",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mods_locked",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "group",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "repeat_info",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "rate",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "delay",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["keymap_format", "key_state"],
+    };
+    pub static WL_TOUCH: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_touch",
+        version: 10u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "down",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuoiff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "up",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uui",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uiff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "cancel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "shape",
+                since: 6u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "6iff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "major",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "minor",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "orientation",
+                since: 6u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "6if",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "orientation",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WL_OUTPUT: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_output",
+        version: 4u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "geometry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiiiissi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "physical_width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "physical_height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "subpixel",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("subpixel"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "make",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "model",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("mode"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "refresh",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "scale",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "factor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "name",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "description",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["subpixel", "transform", "mode"],
+    };
+    pub static WL_REGION: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_region",
+        version: 7u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "add",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "subtract",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static WL_SUBCOMPOSITOR: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_subcompositor",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_subsurface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_subsurface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WL_SUBSURFACE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_subsurface",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "place_above",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "sibling",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "place_below",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "sibling",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_sync",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_desync",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WL_FIXES: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wl_fixes",
+        version: 1u32,
+        protocol: "wayland",
+        family: "core",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy_registry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "registry",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_registry"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+pub static INTERFACES: &[&waynest::InterfaceInfo] = &[
+    &wayland::WL_DISPLAY,
+    &wayland::WL_REGISTRY,
+    &wayland::WL_CALLBACK,
+    &wayland::WL_COMPOSITOR,
+    &wayland::WL_SHM_POOL,
+    &wayland::WL_SHM,
+    &wayland::WL_BUFFER,
+    &wayland::WL_DATA_OFFER,
+    &wayland::WL_DATA_SOURCE,
+    &wayland::WL_DATA_DEVICE,
+    &wayland::WL_DATA_DEVICE_MANAGER,
+    &wayland::WL_SHELL,
+    &wayland::WL_SHELL_SURFACE,
+    &wayland::WL_SURFACE,
+    &wayland::WL_SEAT,
+    &wayland::WL_POINTER,
+    &wayland::WL_KEYBOARD,
+    &wayland::WL_TOUCH,
+    &wayland::WL_OUTPUT,
+    &wayland::WL_REGION,
+    &wayland::WL_SUBCOMPOSITOR,
+    &wayland::WL_SUBSURFACE,
+    &wayland::WL_FIXES,
+];
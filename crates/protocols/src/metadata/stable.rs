@@ -0,0 +1,2973 @@
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the linux_dmabuf_v1 protocol (external/protocols/wayland-protocols/stable/linux-dmabuf/linux-dmabuf-v1.xml)
+//
+//   Copyright © 2014, 2015 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the presentation_time protocol (external/protocols/wayland-protocols/stable/presentation-time/presentation-time.xml)
+//
+//   Copyright © 2013-2014 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the tablet_v2 protocol (external/protocols/wayland-protocols/stable/tablet/tablet-v2.xml)
+//
+//   Copyright 2014 © Stephen "Lyude" Chandler Paul
+//   Copyright 2015-2024 © Red Hat, Inc.
+//
+//   Permission is hereby granted, free of charge, to any person
+//   obtaining a copy of this software and associated documentation files
+//   (the "Software"), to deal in the Software without restriction,
+//   including without limitation the rights to use, copy, modify, merge,
+//   publish, distribute, sublicense, and/or sell copies of the Software,
+//   and to permit persons to whom the Software is furnished to do so,
+//   subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the
+//   next paragraph) shall be included in all copies or substantial
+//   portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//   EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+//   MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+//   NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+//   BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+//   ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+//   CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+//   SOFTWARE.
+//
+// Generated from the viewporter protocol (external/protocols/wayland-protocols/stable/viewporter/viewporter.xml)
+//
+//   Copyright © 2013-2016 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_shell protocol (external/protocols/wayland-protocols/stable/xdg-shell/xdg-shell.xml)
+//
+//   Copyright © 2008-2013 Kristian Høgsberg
+//   Copyright © 2013      Rafael Antognolli
+//   Copyright © 2013      Jasper St. Pierre
+//   Copyright © 2010-2013 Intel Corporation
+//   Copyright © 2015-2017 Samsung Electronics Co., Ltd
+//   Copyright © 2015-2017 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "linux-dmabuf-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "linux-dmabuf-v1")))]
+pub mod linux_dmabuf_v1 {
+    pub static ZWP_LINUX_DMABUF_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_dmabuf_v1",
+        version: 6u32,
+        protocol: "linux_dmabuf_v1",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_params",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "params_id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_buffer_params_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_default_feedback",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_surface_feedback",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "format",
+                since: 1u32,
+                deprecated_since: Some(4u32),
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modifier",
+                since: 3u32,
+                deprecated_since: Some(4u32),
+                destructor: false,
+                signature: "3uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_LINUX_BUFFER_PARAMS_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_buffer_params_v1",
+        version: 6u32,
+        protocol: "linux_dmabuf_v1",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "add",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "huuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "plane_idx",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_immed",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2niiuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer_id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_sampling_device",
+                since: 6u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "6a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "created",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error", "flags"],
+    };
+    pub static ZWP_LINUX_DMABUF_FEEDBACK_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_dmabuf_feedback_v1",
+        version: 6u32,
+        protocol: "linux_dmabuf_v1",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "format_table",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "hu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "main_device",
+                since: 1u32,
+                deprecated_since: Some(6u32),
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tranche_done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "tranche_target_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tranche_formats",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "indices",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tranche_flags",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("tranche_flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["tranche_flags"],
+    };
+}
+#[cfg(feature = "presentation-time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "presentation-time")))]
+pub mod presentation_time {
+    pub static WP_PRESENTATION: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_presentation",
+        version: 2u32,
+        protocol: "presentation_time",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "on",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_presentation_feedback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "clock_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "clk_id",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WP_PRESENTATION_FEEDBACK: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_presentation_feedback",
+        version: 2u32,
+        protocol: "presentation_time",
+        family: "stable",
+        requests: &[],
+        events: &[
+            waynest::MessageInfo {
+                name: "sync_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "presented",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "uuuuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tv_sec_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_sec_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_nsec",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "refresh",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seq_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seq_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("kind"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "discarded",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["kind"],
+    };
+}
+#[cfg(feature = "tablet-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tablet-v2")))]
+pub mod tablet_v2 {
+    pub static ZWP_TABLET_MANAGER_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_manager_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_tablet_seat",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tablet_seat",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_seat_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_SEAT_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_seat_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "tablet_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tool_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_tool_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pad_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_TOOL_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_tool_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tool_type",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("type"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hardware_serial",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hardware_serial_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hardware_serial_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hardware_id_wacom",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hardware_id_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hardware_id_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capability",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capability",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("capability"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "proximity_in",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tablet",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_tablet_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "proximity_out",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "down",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "up",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pressure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "pressure",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "distance",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "distance",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tilt",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tilt_x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tilt_y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "rotation",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "slider",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "position",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "wheel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "fi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "clicks",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["type", "capability", "button_state", "error"],
+    };
+    pub static ZWP_TABLET_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "vid",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pid",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "path",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "path",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "bustype",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "bustype",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("bustype"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["bustype"],
+    };
+    pub static ZWP_TABLET_PAD_RING_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_ring_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "angle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["source"],
+    };
+    pub static ZWP_TABLET_PAD_STRIP_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_strip_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "position",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["source"],
+    };
+    pub static ZWP_TABLET_PAD_GROUP_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_group_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "buttons",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buttons",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ring",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "ring",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_ring_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "strip",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "strip",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_strip_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modes",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "modes",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "mode_switch",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "dial",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dial",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_dial_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_PAD_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "usu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "group",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "pad_group",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_group_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "path",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "path",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "buttons",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buttons",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tablet",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_tablet_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["button_state"],
+    };
+    pub static ZWP_TABLET_PAD_DIAL_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_dial_v2",
+        version: 2u32,
+        protocol: "tablet_v2",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "delta",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "value120",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "viewporter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "viewporter")))]
+pub mod viewporter {
+    pub static WP_VIEWPORTER: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_viewporter",
+        version: 1u32,
+        protocol: "viewporter",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_viewport",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_viewport"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_VIEWPORT: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_viewport",
+        version: 1u32,
+        protocol: "viewporter",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ffff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_destination",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "xdg-shell")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-shell")))]
+pub mod xdg_shell {
+    pub static XDG_WM_BASE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_wm_base",
+        version: 7u32,
+        protocol: "xdg_shell",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_positioner",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_positioner"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pong",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "ping",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static XDG_POSITIONER: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_positioner",
+        version: 7u32,
+        protocol: "xdg_shell",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor_rect",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_gravity",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "gravity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("gravity"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_constraint_adjustment",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "constraint_adjustment",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("constraint_adjustment"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_offset",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_reactive",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent_size",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent_width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent_height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_parent_configure",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error", "anchor", "gravity", "constraint_adjustment"],
+    };
+    pub static XDG_SURFACE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_surface",
+        version: 7u32,
+        protocol: "xdg_shell",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n?oo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_popup"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "positioner",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_positioner"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_window_geometry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static XDG_TOPLEVEL: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel",
+        version: 7u32,
+        protocol: "xdg_shell",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "show_window_menu",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "move",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("resize_edge"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_max_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_min_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iia",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "states",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "close",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "configure_bounds",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "wm_capabilities",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capabilities",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error", "resize_edge", "state", "wm_capabilities"],
+    };
+    pub static XDG_POPUP: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_popup",
+        version: 7u32,
+        protocol: "xdg_shell",
+        family: "stable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "grab",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "reposition",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "positioner",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_positioner"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "token",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "popup_done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "repositioned",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "token",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+pub static INTERFACES: &[&waynest::InterfaceInfo] = &[
+    #[cfg(feature = "linux-dmabuf-v1")]
+    &linux_dmabuf_v1::ZWP_LINUX_DMABUF_V1,
+    #[cfg(feature = "linux-dmabuf-v1")]
+    &linux_dmabuf_v1::ZWP_LINUX_BUFFER_PARAMS_V1,
+    #[cfg(feature = "linux-dmabuf-v1")]
+    &linux_dmabuf_v1::ZWP_LINUX_DMABUF_FEEDBACK_V1,
+    #[cfg(feature = "presentation-time")]
+    &presentation_time::WP_PRESENTATION,
+    #[cfg(feature = "presentation-time")]
+    &presentation_time::WP_PRESENTATION_FEEDBACK,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_MANAGER_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_SEAT_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_TOOL_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_PAD_RING_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_PAD_STRIP_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_PAD_GROUP_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_PAD_V2,
+    #[cfg(feature = "tablet-v2")]
+    &tablet_v2::ZWP_TABLET_PAD_DIAL_V2,
+    #[cfg(feature = "viewporter")]
+    &viewporter::WP_VIEWPORTER,
+    #[cfg(feature = "viewporter")]
+    &viewporter::WP_VIEWPORT,
+    #[cfg(feature = "xdg-shell")]
+    &xdg_shell::XDG_WM_BASE,
+    #[cfg(feature = "xdg-shell")]
+    &xdg_shell::XDG_POSITIONER,
+    #[cfg(feature = "xdg-shell")]
+    &xdg_shell::XDG_SURFACE,
+    #[cfg(feature = "xdg-shell")]
+    &xdg_shell::XDG_TOPLEVEL,
+    #[cfg(feature = "xdg-shell")]
+    &xdg_shell::XDG_POPUP,
+];
@@ -0,0 +1,6287 @@
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the alpha_modifier_v1 protocol (external/protocols/wayland-protocols/staging/alpha-modifier/alpha-modifier-v1.xml)
+//
+//   Copyright © 2024 Xaver Hugl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the color_management_v1 protocol (external/protocols/wayland-protocols/staging/color-management/color-management-v1.xml)
+//
+//   Copyright 2019 Sebastian Wick
+//   Copyright 2019 Erwin Burema
+//   Copyright 2020 AMD
+//   Copyright 2020-2024 Collabora, Ltd.
+//   Copyright 2024 Xaver Hugl
+//   Copyright 2022-2025 Red Hat, Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the color_representation_v1 protocol (external/protocols/wayland-protocols/staging/color-representation/color-representation-v1.xml)
+//
+//   Copyright 2022 Simon Ser
+//   Copyright 2022 Red Hat, Inc.
+//   Copyright 2022 Collabora, Ltd.
+//   Copyright 2022-2025 Red Hat, Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the commit_timing_v1 protocol (external/protocols/wayland-protocols/staging/commit-timing/commit-timing-v1.xml)
+//
+//   Copyright © 2023 Valve Corporation
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the content_type_v1 protocol (external/protocols/wayland-protocols/staging/content-type/content-type-v1.xml)
+//
+//   Copyright © 2021 Emmanuel Gil Peyrot
+//   Copyright © 2022 Xaver Hugl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the cursor_shape_v1 protocol (external/protocols/wayland-protocols/staging/cursor-shape/cursor-shape-v1.xml)
+//
+//   Copyright 2018 The Chromium Authors
+//   Copyright 2023 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the drm_lease_v1 protocol (external/protocols/wayland-protocols/staging/drm-lease/drm-lease-v1.xml)
+//
+//   Copyright © 2018 NXP
+//   Copyright © 2019 Status Research & Development GmbH.
+//   Copyright © 2021 Xaver Hugl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the ext_background_effect_v1 protocol (external/protocols/wayland-protocols/staging/ext-background-effect/ext-background-effect-v1.xml)
+//
+//   Copyright (C) 2015 Martin Gräßlin
+//   Copyright (C) 2015 Marco Martin
+//   Copyright (C) 2020 Vlad Zahorodnii
+//   Copyright (C) 2024 Xaver Hugl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the ext_data_control_v1 protocol (external/protocols/wayland-protocols/staging/ext-data-control/ext-data-control-v1.xml)
+//
+//   Copyright © 2018 Simon Ser
+//   Copyright © 2019 Ivan Molodetskikh
+//   Copyright © 2024 Neal Gompa
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the ext_foreign_toplevel_list_v1 protocol (external/protocols/wayland-protocols/staging/ext-foreign-toplevel-list/ext-foreign-toplevel-list-v1.xml)
+//
+//   Copyright © 2018 Ilia Bozhinov
+//   Copyright © 2020 Isaac Freund
+//   Copyright © 2022 wb9688
+//   Copyright © 2023 i509VCB
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the ext_idle_notify_v1 protocol (external/protocols/wayland-protocols/staging/ext-idle-notify/ext-idle-notify-v1.xml)
+//
+//   Copyright © 2015 Martin Gräßlin
+//   Copyright © 2022 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the ext_image_capture_source_v1 protocol (external/protocols/wayland-protocols/staging/ext-image-capture-source/ext-image-capture-source-v1.xml)
+//
+//   Copyright © 2022 Andri Yngvason
+//   Copyright © 2024 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the ext_image_copy_capture_v1 protocol (external/protocols/wayland-protocols/staging/ext-image-copy-capture/ext-image-copy-capture-v1.xml)
+//
+//   Copyright © 2021-2023 Andri Yngvason
+//   Copyright © 2024 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the ext_session_lock_v1 protocol (external/protocols/wayland-protocols/staging/ext-session-lock/ext-session-lock-v1.xml)
+//
+//   Copyright 2021 Isaac Freund
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice shall be included in
+//   all copies or substantial portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+//   OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+//   THE SOFTWARE.
+//
+// Generated from the ext_transient_seat_v1 protocol (external/protocols/wayland-protocols/staging/ext-transient-seat/ext-transient-seat-v1.xml)
+//
+//   Copyright © 2020 - 2023 Andri Yngvason
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the ext_workspace_v1 protocol (external/protocols/wayland-protocols/staging/ext-workspace/ext-workspace-v1.xml)
+//
+//   Copyright © 2019 Christopher Billington
+//   Copyright © 2020 Ilia Bozhinov
+//   Copyright © 2022 Victoria Brekenfeld
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the fifo_v1 protocol (external/protocols/wayland-protocols/staging/fifo/fifo-v1.xml)
+//
+//   Copyright © 2023 Valve Corporation
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the fractional_scale_v1 protocol (external/protocols/wayland-protocols/staging/fractional-scale/fractional-scale-v1.xml)
+//
+//   Copyright © 2022 Kenny Levinsen
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the linux_drm_syncobj_v1 protocol (external/protocols/wayland-protocols/staging/linux-drm-syncobj/linux-drm-syncobj-v1.xml)
+//
+//   Copyright 2016 The Chromium Authors.
+//   Copyright 2017 Intel Corporation
+//   Copyright 2018 Collabora, Ltd
+//   Copyright 2021 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the pointer_warp_v1 protocol (external/protocols/wayland-protocols/staging/pointer-warp/pointer-warp-v1.xml)
+//
+//   Copyright © 2024 Neal Gompa
+//   Copyright © 2024 Xaver Hugl
+//   Copyright © 2024 Matthias Klumpp
+//   Copyright © 2024 Vlad Zahorodnii
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the security_context_v1 protocol (external/protocols/wayland-protocols/staging/security-context/security-context-v1.xml)
+//
+//   Copyright © 2021 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the single_pixel_buffer_v1 protocol (external/protocols/wayland-protocols/staging/single-pixel-buffer/single-pixel-buffer-v1.xml)
+//
+//   Copyright © 2022 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the tearing_control_v1 protocol (external/protocols/wayland-protocols/staging/tearing-control/tearing-control-v1.xml)
+//
+//   Copyright © 2021 Xaver Hugl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_activation_v1 protocol (external/protocols/wayland-protocols/staging/xdg-activation/xdg-activation-v1.xml)
+//
+//   Copyright © 2020 Aleix Pol Gonzalez <aleixpol@kde.org>
+//   Copyright © 2020 Carlos Garnacho <carlosg@gnome.org>
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_dialog_v1 protocol (external/protocols/wayland-protocols/staging/xdg-dialog/xdg-dialog-v1.xml)
+//
+//   Copyright © 2023 Carlos Garnacho
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_session_management_v1 protocol (external/protocols/wayland-protocols/staging/xdg-session-management/xdg-session-management-v1.xml)
+//
+//   Copyright 2018 Mike Blumenkrantz
+//   Copyright 2018 Samsung Electronics Co., Ltd
+//   Copyright 2018 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_system_bell_v1 protocol (external/protocols/wayland-protocols/staging/xdg-system-bell/xdg-system-bell-v1.xml)
+//
+//   Copyright © 2016, 2023 Red Hat
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_toplevel_drag_v1 protocol (external/protocols/wayland-protocols/staging/xdg-toplevel-drag/xdg-toplevel-drag-v1.xml)
+//
+//   Copyright 2023 David Redondo
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_toplevel_icon_v1 protocol (external/protocols/wayland-protocols/staging/xdg-toplevel-icon/xdg-toplevel-icon-v1.xml)
+//
+//   Copyright © 2023-2024 Matthias Klumpp
+//   Copyright ©      2024 David Edmundson
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_toplevel_tag_v1 protocol (external/protocols/wayland-protocols/staging/xdg-toplevel-tag/xdg-toplevel-tag-v1.xml)
+//
+//   Copyright © 2024 Xaver Hugl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xwayland_shell_v1 protocol (external/protocols/wayland-protocols/staging/xwayland-shell/xwayland-shell-v1.xml)
+//
+//   Copyright © 2022 Joshua Ashton
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "alpha-modifier-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alpha-modifier-v1")))]
+pub mod alpha_modifier_v1 {
+    pub static WP_ALPHA_MODIFIER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_alpha_modifier_v1",
+        version: 1u32,
+        protocol: "alpha_modifier_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_alpha_modifier_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_ALPHA_MODIFIER_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_alpha_modifier_surface_v1",
+        version: 1u32,
+        protocol: "alpha_modifier_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_multiplier",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "factor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "color-management-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color-management-v1")))]
+pub mod color_management_v1 {
+    pub static WP_COLOR_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_color_manager_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_color_management_output_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_color_management_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_surface_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_color_management_surface_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_icc_creator",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "obj",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_creator_icc_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_parametric_creator",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "obj",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_creator_params_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_windows_scrgb",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_image_description",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "reference",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wp_image_description_reference_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_windows_bt2100",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "supported_intent",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "render_intent",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("render_intent"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "supported_feature",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "feature",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("feature"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "supported_tf_named",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tf",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("transfer_function"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "supported_primaries_named",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "primaries",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("primaries"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error", "render_intent", "feature", "primaries", "transfer_function"],
+    };
+    pub static WP_COLOR_MANAGEMENT_OUTPUT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_color_management_output_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_image_description",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "image_description_changed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WP_COLOR_MANAGEMENT_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_color_management_surface_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_image_description",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "render_intent",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_manager_v1.render_intent"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_image_description",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_COLOR_MANAGEMENT_SURFACE_FEEDBACK_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_color_management_surface_feedback_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_preferred",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_preferred_parametric",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "preferred_changed",
+                since: 1u32,
+                deprecated_since: Some(2u32),
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "identity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preferred_changed2",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "identity_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "identity_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WP_IMAGE_DESCRIPTION_CREATOR_ICC_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_image_description_creator_icc_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_icc_file",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "huu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "icc_profile",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_IMAGE_DESCRIPTION_CREATOR_PARAMS_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_image_description_creator_params_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "image_description",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_tf_named",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tf",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_manager_v1.transfer_function"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_tf_power",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "eexp",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_primaries_named",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "primaries",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_manager_v1.primaries"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_primaries",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiiiiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "r_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "r_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_luminances",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "min_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "max_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "reference_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_mastering_display_primaries",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiiiiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "r_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "r_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_mastering_luminance",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "min_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "max_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_max_cll",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "max_cll",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_max_fall",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "max_fall",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_IMAGE_DESCRIPTION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_image_description_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_information",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "information",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_image_description_info_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "us",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "cause",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("cause"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "msg",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ready",
+                since: 1u32,
+                deprecated_since: Some(2u32),
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "identity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ready2",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "identity_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "identity_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error", "cause"],
+    };
+    pub static WP_IMAGE_DESCRIPTION_INFO_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_image_description_info_v1",
+        version: 3u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[],
+        events: &[
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "icc_file",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "hu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "icc",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "icc_size",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "primaries",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiiiiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "r_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "r_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "primaries_named",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "primaries",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_manager_v1.primaries"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tf_power",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "eexp",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tf_named",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tf",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_manager_v1.transfer_function"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "luminances",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "min_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "max_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "reference_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "target_primaries",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiiiiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "r_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "r_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "w_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "target_luminance",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "min_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "max_lum",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "target_max_cll",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "max_cll",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "target_max_fall",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "max_fall",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WP_IMAGE_DESCRIPTION_REFERENCE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_image_description_reference_v1",
+        version: 1u32,
+        protocol: "color_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "color-representation-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color-representation-v1")))]
+pub mod color_representation_v1 {
+    pub static WP_COLOR_REPRESENTATION_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_color_representation_manager_v1",
+        version: 1u32,
+        protocol: "color_representation_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_color_representation_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "supported_alpha_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "alpha_mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_representation_surface_v1.alpha_mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "supported_coefficients_and_ranges",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "coefficients",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_representation_surface_v1.coefficients"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "range",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wp_color_representation_surface_v1.range"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static WP_COLOR_REPRESENTATION_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_color_representation_surface_v1",
+        version: 1u32,
+        protocol: "color_representation_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_alpha_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "alpha_mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("alpha_mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_coefficients_and_range",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "coefficients",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("coefficients"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "range",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("range"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_chroma_location",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "chroma_location",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("chroma_location"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error", "alpha_mode", "coefficients", "range", "chroma_location"],
+    };
+}
+#[cfg(feature = "commit-timing-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "commit-timing-v1")))]
+pub mod commit_timing_v1 {
+    pub static WP_COMMIT_TIMING_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_commit_timing_manager_v1",
+        version: 1u32,
+        protocol: "commit_timing_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_timer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_commit_timer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_COMMIT_TIMER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_commit_timer_v1",
+        version: 1u32,
+        protocol: "commit_timing_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_timestamp",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tv_sec_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_sec_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_nsec",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "content-type-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "content-type-v1")))]
+pub mod content_type_v1 {
+    pub static WP_CONTENT_TYPE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_content_type_manager_v1",
+        version: 1u32,
+        protocol: "content_type_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_surface_content_type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_content_type_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_CONTENT_TYPE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_content_type_v1",
+        version: 1u32,
+        protocol: "content_type_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_content_type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "content_type",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("type"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["type"],
+    };
+}
+#[cfg(feature = "cursor-shape-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cursor-shape-v1")))]
+pub mod cursor_shape_v1 {
+    pub static WP_CURSOR_SHAPE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_cursor_shape_manager_v1",
+        version: 2u32,
+        protocol: "cursor_shape_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "cursor_shape_device",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_cursor_shape_device_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_tablet_tool_v2",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "cursor_shape_device",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_cursor_shape_device_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tablet_tool",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_tablet_tool_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static WP_CURSOR_SHAPE_DEVICE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_cursor_shape_device_v1",
+        version: 2u32,
+        protocol: "cursor_shape_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_shape",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "shape",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("shape"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["shape", "error"],
+    };
+}
+#[cfg(feature = "drm-lease-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "drm-lease-v1")))]
+pub mod drm_lease_v1 {
+    pub static WP_DRM_LEASE_DEVICE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_drm_lease_device_v1",
+        version: 1u32,
+        protocol: "drm_lease_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_lease_request",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_drm_lease_request_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "drm_fd",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "h",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "connector",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_drm_lease_connector_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "released",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WP_DRM_LEASE_CONNECTOR_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_drm_lease_connector_v1",
+        version: 1u32,
+        protocol: "drm_lease_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "description",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "connector_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "connector_id",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "withdrawn",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static WP_DRM_LEASE_REQUEST_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_drm_lease_request_v1",
+        version: 1u32,
+        protocol: "drm_lease_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "request_connector",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "connector",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wp_drm_lease_connector_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "submit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_drm_lease_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_DRM_LEASE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_drm_lease_v1",
+        version: 1u32,
+        protocol: "drm_lease_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "lease_fd",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "h",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "leased_fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "ext-background-effect-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-background-effect-v1")))]
+pub mod ext_background_effect_v1 {
+    pub static EXT_BACKGROUND_EFFECT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_background_effect_manager_v1",
+        version: 1u32,
+        protocol: "ext_background_effect_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_background_effect",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_background_effect_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "capabilities",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("capability"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error", "capability"],
+    };
+    pub static EXT_BACKGROUND_EFFECT_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_background_effect_surface_v1",
+        version: 1u32,
+        protocol: "ext_background_effect_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_blur_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "ext-data-control-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-data-control-v1")))]
+pub mod ext_data_control_v1 {
+    pub static EXT_DATA_CONTROL_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_data_control_manager_v1",
+        version: 1u32,
+        protocol: "ext_data_control_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_data_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_data_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_data_control_device_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static EXT_DATA_CONTROL_DEVICE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_data_control_device_v1",
+        version: 1u32,
+        protocol: "ext_data_control_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_primary_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "data_offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_data_control_offer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_data_control_offer_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "primary_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_data_control_offer_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static EXT_DATA_CONTROL_SOURCE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_data_control_source_v1",
+        version: 1u32,
+        protocol: "ext_data_control_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "send",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cancelled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static EXT_DATA_CONTROL_OFFER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_data_control_offer_v1",
+        version: 1u32,
+        protocol: "ext_data_control_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "receive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "ext-foreign-toplevel-list-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-foreign-toplevel-list-v1")))]
+pub mod ext_foreign_toplevel_list_v1 {
+    pub static EXT_FOREIGN_TOPLEVEL_LIST_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_foreign_toplevel_list_v1",
+        version: 1u32,
+        protocol: "ext_foreign_toplevel_list_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_foreign_toplevel_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static EXT_FOREIGN_TOPLEVEL_HANDLE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_foreign_toplevel_handle_v1",
+        version: 1u32,
+        protocol: "ext_foreign_toplevel_list_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "closed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "identifier",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "identifier",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "ext-idle-notify-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-idle-notify-v1")))]
+pub mod ext_idle_notify_v1 {
+    pub static EXT_IDLE_NOTIFIER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_idle_notifier_v1",
+        version: 2u32,
+        protocol: "ext_idle_notify_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_idle_notification",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nuo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_idle_notification_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "timeout",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_input_idle_notification",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2nuo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_idle_notification_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "timeout",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static EXT_IDLE_NOTIFICATION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_idle_notification_v1",
+        version: 2u32,
+        protocol: "ext_idle_notify_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "idled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "resumed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "ext-image-capture-source-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-image-capture-source-v1")))]
+pub mod ext_image_capture_source_v1 {
+    pub static EXT_IMAGE_CAPTURE_SOURCE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_image_capture_source_v1",
+        version: 1u32,
+        protocol: "ext_image_capture_source_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static EXT_OUTPUT_IMAGE_CAPTURE_SOURCE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_output_image_capture_source_manager_v1",
+        version: 1u32,
+        protocol: "ext_image_capture_source_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_image_capture_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static EXT_FOREIGN_TOPLEVEL_IMAGE_CAPTURE_SOURCE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_foreign_toplevel_image_capture_source_manager_v1",
+        version: 1u32,
+        protocol: "ext_image_capture_source_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_image_capture_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "toplevel_handle",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_foreign_toplevel_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "ext-image-copy-capture-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-image-copy-capture-v1")))]
+pub mod ext_image_copy_capture_v1 {
+    pub static EXT_IMAGE_COPY_CAPTURE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_image_copy_capture_manager_v1",
+        version: 1u32,
+        protocol: "ext_image_copy_capture_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_session",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "session",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_image_copy_capture_session_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_image_capture_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "options",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("options"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_pointer_cursor_session",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "session",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_image_copy_capture_cursor_session_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_image_capture_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error", "options"],
+    };
+    pub static EXT_IMAGE_COPY_CAPTURE_SESSION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_image_copy_capture_session_v1",
+        version: 1u32,
+        protocol: "ext_image_copy_capture_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_image_copy_capture_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "buffer_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "shm_format",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_shm.format"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "dmabuf_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "dmabuf_format",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ua",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifiers",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "stopped",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static EXT_IMAGE_COPY_CAPTURE_FRAME_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_image_copy_capture_frame_v1",
+        version: 1u32,
+        protocol: "ext_image_copy_capture_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "attach_buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "damage_buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capture",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "transform",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "damage",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "presentation_time",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tv_sec_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_sec_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_nsec",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ready",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "reason",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("failure_reason"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error", "failure_reason"],
+    };
+    pub static EXT_IMAGE_COPY_CAPTURE_CURSOR_SESSION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_image_copy_capture_cursor_session_v1",
+        version: 1u32,
+        protocol: "ext_image_copy_capture_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_capture_session",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "session",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_image_copy_capture_session_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hotspot",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "ext-session-lock-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-session-lock-v1")))]
+pub mod ext_session_lock_v1 {
+    pub static EXT_SESSION_LOCK_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_session_lock_manager_v1",
+        version: 1u32,
+        protocol: "ext_session_lock_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "lock",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_session_lock_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static EXT_SESSION_LOCK_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_session_lock_v1",
+        version: 1u32,
+        protocol: "ext_session_lock_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_lock_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_session_lock_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unlock_and_destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "locked",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static EXT_SESSION_LOCK_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_session_lock_surface_v1",
+        version: 1u32,
+        protocol: "ext_session_lock_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "ext-transient-seat-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-transient-seat-v1")))]
+pub mod ext_transient_seat_v1 {
+    pub static EXT_TRANSIENT_SEAT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_transient_seat_manager_v1",
+        version: 1u32,
+        protocol: "ext_transient_seat_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_transient_seat_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static EXT_TRANSIENT_SEAT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_transient_seat_v1",
+        version: 1u32,
+        protocol: "ext_transient_seat_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "ready",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "global_name",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "denied",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "ext-workspace-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ext-workspace-v1")))]
+pub mod ext_workspace_v1 {
+    pub static EXT_WORKSPACE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_workspace_manager_v1",
+        version: 1u32,
+        protocol: "ext_workspace_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "commit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "workspace_group",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "workspace_group",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_workspace_group_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "workspace",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "workspace",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("ext_workspace_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static EXT_WORKSPACE_GROUP_HANDLE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_workspace_group_handle_v1",
+        version: 1u32,
+        protocol: "ext_workspace_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_workspace",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "workspace",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "capabilities",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capabilities",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("group_capabilities"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "output_enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "output_leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "workspace_enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "workspace",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_workspace_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "workspace_leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "workspace",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_workspace_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["group_capabilities"],
+    };
+    pub static EXT_WORKSPACE_HANDLE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "ext_workspace_handle_v1",
+        version: 1u32,
+        protocol: "ext_workspace_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "activate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "deactivate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "assign",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "workspace_group",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("ext_workspace_group_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "remove",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "coordinates",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "coordinates",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "state",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capabilities",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capabilities",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("workspace_capabilities"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["state", "workspace_capabilities"],
+    };
+}
+#[cfg(feature = "fifo-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fifo-v1")))]
+pub mod fifo_v1 {
+    pub static WP_FIFO_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_fifo_manager_v1",
+        version: 1u32,
+        protocol: "fifo_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_fifo",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_fifo_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_FIFO_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_fifo_v1",
+        version: 1u32,
+        protocol: "fifo_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_barrier",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "wait_barrier",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "fractional-scale-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fractional-scale-v1")))]
+pub mod fractional_scale_v1 {
+    pub static WP_FRACTIONAL_SCALE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_fractional_scale_manager_v1",
+        version: 1u32,
+        protocol: "fractional_scale_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_fractional_scale",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_fractional_scale_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_FRACTIONAL_SCALE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_fractional_scale_v1",
+        version: 1u32,
+        protocol: "fractional_scale_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "preferred_scale",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "linux-drm-syncobj-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "linux-drm-syncobj-v1")))]
+pub mod linux_drm_syncobj_v1 {
+    pub static WP_LINUX_DRM_SYNCOBJ_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_linux_drm_syncobj_manager_v1",
+        version: 1u32,
+        protocol: "linux_drm_syncobj_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_linux_drm_syncobj_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "import_timeline",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_linux_drm_syncobj_timeline_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_LINUX_DRM_SYNCOBJ_TIMELINE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_linux_drm_syncobj_timeline_v1",
+        version: 1u32,
+        protocol: "linux_drm_syncobj_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static WP_LINUX_DRM_SYNCOBJ_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_linux_drm_syncobj_surface_v1",
+        version: 1u32,
+        protocol: "linux_drm_syncobj_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_acquire_point",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "timeline",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wp_linux_drm_syncobj_timeline_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "point_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "point_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_release_point",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "timeline",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wp_linux_drm_syncobj_timeline_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "point_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "point_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "pointer-warp-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pointer-warp-v1")))]
+pub mod pointer_warp_v1 {
+    pub static WP_POINTER_WARP_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_pointer_warp_v1",
+        version: 1u32,
+        protocol: "pointer_warp_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "warp_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ooffu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "security-context-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "security-context-v1")))]
+pub mod security_context_v1 {
+    pub static WP_SECURITY_CONTEXT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_security_context_manager_v1",
+        version: 1u32,
+        protocol: "security_context_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_listener",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nhh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_security_context_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "listen_fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "close_fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_SECURITY_CONTEXT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_security_context_v1",
+        version: 1u32,
+        protocol: "security_context_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_sandbox_engine",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_instance_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "instance_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "single-pixel-buffer-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "single-pixel-buffer-v1")))]
+pub mod single_pixel_buffer_v1 {
+    pub static WP_SINGLE_PIXEL_BUFFER_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_single_pixel_buffer_manager_v1",
+        version: 1u32,
+        protocol: "single_pixel_buffer_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_u32_rgba_buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "r",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "g",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "b",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "a",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "tearing-control-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tearing-control-v1")))]
+pub mod tearing_control_v1 {
+    pub static WP_TEARING_CONTROL_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_tearing_control_manager_v1",
+        version: 1u32,
+        protocol: "tearing_control_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_tearing_control",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_tearing_control_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static WP_TEARING_CONTROL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "wp_tearing_control_v1",
+        version: 1u32,
+        protocol: "tearing_control_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_presentation_hint",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hint",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("presentation_hint"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["presentation_hint"],
+    };
+}
+#[cfg(feature = "xdg-activation-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-activation-v1")))]
+pub mod xdg_activation_v1 {
+    pub static XDG_ACTIVATION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_activation_v1",
+        version: 1u32,
+        protocol: "xdg_activation_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_activation_token",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_activation_token_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "activate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "so",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "token",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static XDG_ACTIVATION_TOKEN_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_activation_token_v1",
+        version: 1u32,
+        protocol: "xdg_activation_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_serial",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "token",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "xdg-dialog-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-dialog-v1")))]
+pub mod xdg_dialog_v1 {
+    pub static XDG_WM_DIALOG_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_wm_dialog_v1",
+        version: 1u32,
+        protocol: "xdg_dialog_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_dialog",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_dialog_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static XDG_DIALOG_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_dialog_v1",
+        version: 1u32,
+        protocol: "xdg_dialog_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_modal",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_modal",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-session-management-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-session-management-v1")))]
+pub mod xdg_session_management_v1 {
+    pub static XDG_SESSION_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_session_manager_v1",
+        version: 1u32,
+        protocol: "xdg_session_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_session",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nu?s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_session_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "reason",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("reason"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "session_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error", "reason"],
+    };
+    pub static XDG_SESSION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_session_v1",
+        version: 1u32,
+        protocol: "xdg_session_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "remove",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "add_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nos",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_toplevel_session_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "restore_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nos",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_toplevel_session_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "remove_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "created",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "session_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "restored",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "replaced",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static XDG_TOPLEVEL_SESSION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel_session_v1",
+        version: 1u32,
+        protocol: "xdg_session_management_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "rename",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "restored",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-system-bell-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-system-bell-v1")))]
+pub mod xdg_system_bell_v1 {
+    pub static XDG_SYSTEM_BELL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_system_bell_v1",
+        version: 1u32,
+        protocol: "xdg_system_bell_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "ring",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-toplevel-drag-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-toplevel-drag-v1")))]
+pub mod xdg_toplevel_drag_v1 {
+    pub static XDG_TOPLEVEL_DRAG_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel_drag_manager_v1",
+        version: 1u32,
+        protocol: "xdg_toplevel_drag_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_toplevel_drag",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_toplevel_drag_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "data_source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static XDG_TOPLEVEL_DRAG_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel_drag_v1",
+        version: 1u32,
+        protocol: "xdg_toplevel_drag_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "attach",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x_offset",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y_offset",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "xdg-toplevel-icon-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-toplevel-icon-v1")))]
+pub mod xdg_toplevel_icon_v1 {
+    pub static XDG_TOPLEVEL_ICON_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel_icon_manager_v1",
+        version: 1u32,
+        protocol: "xdg_toplevel_icon_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_icon",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_toplevel_icon_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_icon",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "icon",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel_icon_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "icon_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static XDG_TOPLEVEL_ICON_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel_icon_v1",
+        version: 1u32,
+        protocol: "xdg_toplevel_icon_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "icon_name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "add_buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "xdg-toplevel-tag-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-toplevel-tag-v1")))]
+pub mod xdg_toplevel_tag_v1 {
+    pub static XDG_TOPLEVEL_TAG_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_toplevel_tag_manager_v1",
+        version: 1u32,
+        protocol: "xdg_toplevel_tag_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_toplevel_tag",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "os",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tag",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_toplevel_description",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "os",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xwayland-shell-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xwayland-shell-v1")))]
+pub mod xwayland_shell_v1 {
+    pub static XWAYLAND_SHELL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xwayland_shell_v1",
+        version: 1u32,
+        protocol: "xwayland_shell_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_xwayland_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xwayland_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static XWAYLAND_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xwayland_surface_v1",
+        version: 1u32,
+        protocol: "xwayland_shell_v1",
+        family: "staging",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_serial",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+pub static INTERFACES: &[&waynest::InterfaceInfo] = &[
+    #[cfg(feature = "alpha-modifier-v1")]
+    &alpha_modifier_v1::WP_ALPHA_MODIFIER_V1,
+    #[cfg(feature = "alpha-modifier-v1")]
+    &alpha_modifier_v1::WP_ALPHA_MODIFIER_SURFACE_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_COLOR_MANAGER_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_COLOR_MANAGEMENT_OUTPUT_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_COLOR_MANAGEMENT_SURFACE_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_COLOR_MANAGEMENT_SURFACE_FEEDBACK_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_IMAGE_DESCRIPTION_CREATOR_ICC_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_IMAGE_DESCRIPTION_CREATOR_PARAMS_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_IMAGE_DESCRIPTION_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_IMAGE_DESCRIPTION_INFO_V1,
+    #[cfg(feature = "color-management-v1")]
+    &color_management_v1::WP_IMAGE_DESCRIPTION_REFERENCE_V1,
+    #[cfg(feature = "color-representation-v1")]
+    &color_representation_v1::WP_COLOR_REPRESENTATION_MANAGER_V1,
+    #[cfg(feature = "color-representation-v1")]
+    &color_representation_v1::WP_COLOR_REPRESENTATION_SURFACE_V1,
+    #[cfg(feature = "commit-timing-v1")]
+    &commit_timing_v1::WP_COMMIT_TIMING_MANAGER_V1,
+    #[cfg(feature = "commit-timing-v1")]
+    &commit_timing_v1::WP_COMMIT_TIMER_V1,
+    #[cfg(feature = "content-type-v1")]
+    &content_type_v1::WP_CONTENT_TYPE_MANAGER_V1,
+    #[cfg(feature = "content-type-v1")]
+    &content_type_v1::WP_CONTENT_TYPE_V1,
+    #[cfg(feature = "cursor-shape-v1")]
+    &cursor_shape_v1::WP_CURSOR_SHAPE_MANAGER_V1,
+    #[cfg(feature = "cursor-shape-v1")]
+    &cursor_shape_v1::WP_CURSOR_SHAPE_DEVICE_V1,
+    #[cfg(feature = "drm-lease-v1")]
+    &drm_lease_v1::WP_DRM_LEASE_DEVICE_V1,
+    #[cfg(feature = "drm-lease-v1")]
+    &drm_lease_v1::WP_DRM_LEASE_CONNECTOR_V1,
+    #[cfg(feature = "drm-lease-v1")]
+    &drm_lease_v1::WP_DRM_LEASE_REQUEST_V1,
+    #[cfg(feature = "drm-lease-v1")]
+    &drm_lease_v1::WP_DRM_LEASE_V1,
+    #[cfg(feature = "ext-background-effect-v1")]
+    &ext_background_effect_v1::EXT_BACKGROUND_EFFECT_MANAGER_V1,
+    #[cfg(feature = "ext-background-effect-v1")]
+    &ext_background_effect_v1::EXT_BACKGROUND_EFFECT_SURFACE_V1,
+    #[cfg(feature = "ext-data-control-v1")]
+    &ext_data_control_v1::EXT_DATA_CONTROL_MANAGER_V1,
+    #[cfg(feature = "ext-data-control-v1")]
+    &ext_data_control_v1::EXT_DATA_CONTROL_DEVICE_V1,
+    #[cfg(feature = "ext-data-control-v1")]
+    &ext_data_control_v1::EXT_DATA_CONTROL_SOURCE_V1,
+    #[cfg(feature = "ext-data-control-v1")]
+    &ext_data_control_v1::EXT_DATA_CONTROL_OFFER_V1,
+    #[cfg(feature = "ext-foreign-toplevel-list-v1")]
+    &ext_foreign_toplevel_list_v1::EXT_FOREIGN_TOPLEVEL_LIST_V1,
+    #[cfg(feature = "ext-foreign-toplevel-list-v1")]
+    &ext_foreign_toplevel_list_v1::EXT_FOREIGN_TOPLEVEL_HANDLE_V1,
+    #[cfg(feature = "ext-idle-notify-v1")]
+    &ext_idle_notify_v1::EXT_IDLE_NOTIFIER_V1,
+    #[cfg(feature = "ext-idle-notify-v1")]
+    &ext_idle_notify_v1::EXT_IDLE_NOTIFICATION_V1,
+    #[cfg(feature = "ext-image-capture-source-v1")]
+    &ext_image_capture_source_v1::EXT_IMAGE_CAPTURE_SOURCE_V1,
+    #[cfg(feature = "ext-image-capture-source-v1")]
+    &ext_image_capture_source_v1::EXT_OUTPUT_IMAGE_CAPTURE_SOURCE_MANAGER_V1,
+    #[cfg(feature = "ext-image-capture-source-v1")]
+    &ext_image_capture_source_v1::EXT_FOREIGN_TOPLEVEL_IMAGE_CAPTURE_SOURCE_MANAGER_V1,
+    #[cfg(feature = "ext-image-copy-capture-v1")]
+    &ext_image_copy_capture_v1::EXT_IMAGE_COPY_CAPTURE_MANAGER_V1,
+    #[cfg(feature = "ext-image-copy-capture-v1")]
+    &ext_image_copy_capture_v1::EXT_IMAGE_COPY_CAPTURE_SESSION_V1,
+    #[cfg(feature = "ext-image-copy-capture-v1")]
+    &ext_image_copy_capture_v1::EXT_IMAGE_COPY_CAPTURE_FRAME_V1,
+    #[cfg(feature = "ext-image-copy-capture-v1")]
+    &ext_image_copy_capture_v1::EXT_IMAGE_COPY_CAPTURE_CURSOR_SESSION_V1,
+    #[cfg(feature = "ext-session-lock-v1")]
+    &ext_session_lock_v1::EXT_SESSION_LOCK_MANAGER_V1,
+    #[cfg(feature = "ext-session-lock-v1")]
+    &ext_session_lock_v1::EXT_SESSION_LOCK_V1,
+    #[cfg(feature = "ext-session-lock-v1")]
+    &ext_session_lock_v1::EXT_SESSION_LOCK_SURFACE_V1,
+    #[cfg(feature = "ext-transient-seat-v1")]
+    &ext_transient_seat_v1::EXT_TRANSIENT_SEAT_MANAGER_V1,
+    #[cfg(feature = "ext-transient-seat-v1")]
+    &ext_transient_seat_v1::EXT_TRANSIENT_SEAT_V1,
+    #[cfg(feature = "ext-workspace-v1")]
+    &ext_workspace_v1::EXT_WORKSPACE_MANAGER_V1,
+    #[cfg(feature = "ext-workspace-v1")]
+    &ext_workspace_v1::EXT_WORKSPACE_GROUP_HANDLE_V1,
+    #[cfg(feature = "ext-workspace-v1")]
+    &ext_workspace_v1::EXT_WORKSPACE_HANDLE_V1,
+    #[cfg(feature = "fifo-v1")]
+    &fifo_v1::WP_FIFO_MANAGER_V1,
+    #[cfg(feature = "fifo-v1")]
+    &fifo_v1::WP_FIFO_V1,
+    #[cfg(feature = "fractional-scale-v1")]
+    &fractional_scale_v1::WP_FRACTIONAL_SCALE_MANAGER_V1,
+    #[cfg(feature = "fractional-scale-v1")]
+    &fractional_scale_v1::WP_FRACTIONAL_SCALE_V1,
+    #[cfg(feature = "linux-drm-syncobj-v1")]
+    &linux_drm_syncobj_v1::WP_LINUX_DRM_SYNCOBJ_MANAGER_V1,
+    #[cfg(feature = "linux-drm-syncobj-v1")]
+    &linux_drm_syncobj_v1::WP_LINUX_DRM_SYNCOBJ_TIMELINE_V1,
+    #[cfg(feature = "linux-drm-syncobj-v1")]
+    &linux_drm_syncobj_v1::WP_LINUX_DRM_SYNCOBJ_SURFACE_V1,
+    #[cfg(feature = "pointer-warp-v1")]
+    &pointer_warp_v1::WP_POINTER_WARP_V1,
+    #[cfg(feature = "security-context-v1")]
+    &security_context_v1::WP_SECURITY_CONTEXT_MANAGER_V1,
+    #[cfg(feature = "security-context-v1")]
+    &security_context_v1::WP_SECURITY_CONTEXT_V1,
+    #[cfg(feature = "single-pixel-buffer-v1")]
+    &single_pixel_buffer_v1::WP_SINGLE_PIXEL_BUFFER_MANAGER_V1,
+    #[cfg(feature = "tearing-control-v1")]
+    &tearing_control_v1::WP_TEARING_CONTROL_MANAGER_V1,
+    #[cfg(feature = "tearing-control-v1")]
+    &tearing_control_v1::WP_TEARING_CONTROL_V1,
+    #[cfg(feature = "xdg-activation-v1")]
+    &xdg_activation_v1::XDG_ACTIVATION_V1,
+    #[cfg(feature = "xdg-activation-v1")]
+    &xdg_activation_v1::XDG_ACTIVATION_TOKEN_V1,
+    #[cfg(feature = "xdg-dialog-v1")]
+    &xdg_dialog_v1::XDG_WM_DIALOG_V1,
+    #[cfg(feature = "xdg-dialog-v1")]
+    &xdg_dialog_v1::XDG_DIALOG_V1,
+    #[cfg(feature = "xdg-session-management-v1")]
+    &xdg_session_management_v1::XDG_SESSION_MANAGER_V1,
+    #[cfg(feature = "xdg-session-management-v1")]
+    &xdg_session_management_v1::XDG_SESSION_V1,
+    #[cfg(feature = "xdg-session-management-v1")]
+    &xdg_session_management_v1::XDG_TOPLEVEL_SESSION_V1,
+    #[cfg(feature = "xdg-system-bell-v1")]
+    &xdg_system_bell_v1::XDG_SYSTEM_BELL_V1,
+    #[cfg(feature = "xdg-toplevel-drag-v1")]
+    &xdg_toplevel_drag_v1::XDG_TOPLEVEL_DRAG_MANAGER_V1,
+    #[cfg(feature = "xdg-toplevel-drag-v1")]
+    &xdg_toplevel_drag_v1::XDG_TOPLEVEL_DRAG_V1,
+    #[cfg(feature = "xdg-toplevel-icon-v1")]
+    &xdg_toplevel_icon_v1::XDG_TOPLEVEL_ICON_MANAGER_V1,
+    #[cfg(feature = "xdg-toplevel-icon-v1")]
+    &xdg_toplevel_icon_v1::XDG_TOPLEVEL_ICON_V1,
+    #[cfg(feature = "xdg-toplevel-tag-v1")]
+    &xdg_toplevel_tag_v1::XDG_TOPLEVEL_TAG_MANAGER_V1,
+    #[cfg(feature = "xwayland-shell-v1")]
+    &xwayland_shell_v1::XWAYLAND_SHELL_V1,
+    #[cfg(feature = "xwayland-shell-v1")]
+    &xwayland_shell_v1::XWAYLAND_SURFACE_V1,
+];
@@ -0,0 +1,7817 @@
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the fullscreen_shell_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/fullscreen-shell/fullscreen-shell-unstable-v1.xml)
+//
+//   Copyright © 2016 Yong Bakos
+//   Copyright © 2015 Jason Ekstrand
+//   Copyright © 2015 Jonas Ådahl
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the idle_inhibit_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/idle-inhibit/idle-inhibit-unstable-v1.xml)
+//
+//   Copyright © 2015 Samsung Electronics Co., Ltd
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the input_method_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/input-method/input-method-unstable-v1.xml)
+//
+//   Copyright © 2012, 2013 Intel Corporation
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the input_timestamps_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/input-timestamps/input-timestamps-unstable-v1.xml)
+//
+//   Copyright © 2017 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the keyboard_shortcuts_inhibit_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/keyboard-shortcuts-inhibit/keyboard-shortcuts-inhibit-unstable-v1.xml)
+//
+//   Copyright © 2017 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the linux_dmabuf_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/linux-dmabuf/linux-dmabuf-unstable-v1.xml)
+//
+//   Copyright © 2014, 2015 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the zwp_linux_explicit_synchronization_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/linux-explicit-synchronization/linux-explicit-synchronization-unstable-v1.xml)
+//
+//   Copyright 2016 The Chromium Authors.
+//   Copyright 2017 Intel Corporation
+//   Copyright 2018 Collabora, Ltd
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the pointer_constraints_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/pointer-constraints/pointer-constraints-unstable-v1.xml)
+//
+//   Copyright © 2014      Jonas Ådahl
+//   Copyright © 2015      Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the pointer_gestures_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/pointer-gestures/pointer-gestures-unstable-v1.xml)
+//
+//   Copyright © 2015, 2021 Red Hat Inc.
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wp_primary_selection_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/primary-selection/primary-selection-unstable-v1.xml)
+//
+//   Copyright © 2015, 2016 Red Hat
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the relative_pointer_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/relative-pointer/relative-pointer-unstable-v1.xml)
+//
+//   Copyright © 2014      Jonas Ådahl
+//   Copyright © 2015      Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the tablet_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/tablet/tablet-unstable-v1.xml)
+//
+//   Copyright 2014 © Stephen "Lyude" Chandler Paul
+//   Copyright 2015-2016 © Red Hat, Inc.
+//
+//   Permission is hereby granted, free of charge, to any person
+//   obtaining a copy of this software and associated documentation files
+//   (the "Software"), to deal in the Software without restriction,
+//   including without limitation the rights to use, copy, modify, merge,
+//   publish, distribute, sublicense, and/or sell copies of the Software,
+//   and to permit persons to whom the Software is furnished to do so,
+//   subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the
+//   next paragraph) shall be included in all copies or substantial
+//   portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//   EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+//   MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+//   NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+//   BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+//   ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+//   CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+//   SOFTWARE.
+//
+// Generated from the tablet_unstable_v2 protocol (external/protocols/wayland-protocols/unstable/tablet/tablet-unstable-v2.xml)
+//
+//   Copyright 2014 © Stephen "Lyude" Chandler Paul
+//   Copyright 2015-2016 © Red Hat, Inc.
+//
+//   Permission is hereby granted, free of charge, to any person
+//   obtaining a copy of this software and associated documentation files
+//   (the "Software"), to deal in the Software without restriction,
+//   including without limitation the rights to use, copy, modify, merge,
+//   publish, distribute, sublicense, and/or sell copies of the Software,
+//   and to permit persons to whom the Software is furnished to do so,
+//   subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the
+//   next paragraph) shall be included in all copies or substantial
+//   portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//   EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+//   MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+//   NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+//   BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+//   ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+//   CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+//   SOFTWARE.
+//
+// Generated from the text_input_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/text-input/text-input-unstable-v1.xml)
+//
+//   Copyright © 2012, 2013 Intel Corporation
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the text_input_unstable_v3 protocol (external/protocols/wayland-protocols/unstable/text-input/text-input-unstable-v3.xml)
+//
+//   Copyright © 2012, 2013 Intel Corporation
+//   Copyright © 2015, 2016 Jan Arne Petersen
+//   Copyright © 2017, 2018 Red Hat, Inc.
+//   Copyright © 2018       Purism SPC
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the xdg_decoration_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/xdg-decoration/xdg-decoration-unstable-v1.xml)
+//
+//   Copyright © 2018 Simon Ser
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_foreign_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/xdg-foreign/xdg-foreign-unstable-v1.xml)
+//
+//   Copyright © 2015-2016 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_foreign_unstable_v2 protocol (external/protocols/wayland-protocols/unstable/xdg-foreign/xdg-foreign-unstable-v2.xml)
+//
+//   Copyright © 2015-2016 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_output_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/xdg-output/xdg-output-unstable-v1.xml)
+//
+//   Copyright © 2017 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_shell_unstable_v5 protocol (external/protocols/wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v5.xml)
+//
+//   Copyright © 2008-2013 Kristian Høgsberg
+//   Copyright © 2013      Rafael Antognolli
+//   Copyright © 2013      Jasper St. Pierre
+//   Copyright © 2010-2013 Intel Corporation
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_shell_unstable_v6 protocol (external/protocols/wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v6.xml)
+//
+//   Copyright © 2008-2013 Kristian Høgsberg
+//   Copyright © 2013      Rafael Antognolli
+//   Copyright © 2013      Jasper St. Pierre
+//   Copyright © 2010-2013 Intel Corporation
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xwayland_keyboard_grab_unstable_v1 protocol (external/protocols/wayland-protocols/unstable/xwayland-keyboard-grab/xwayland-keyboard-grab-unstable-v1.xml)
+//
+//   Copyright © 2017 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "fullscreen-shell-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fullscreen-shell-unstable-v1")))]
+pub mod fullscreen_shell_unstable_v1 {
+    pub static ZWP_FULLSCREEN_SHELL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_fullscreen_shell_v1",
+        version: 1u32,
+        protocol: "fullscreen_shell_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "present_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?ou?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "method",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("present_method"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "present_surface_for_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ooin",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "framerate",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "feedback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_fullscreen_shell_mode_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "capability",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capability",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("capability"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["capability", "present_method", "error"],
+    };
+    pub static ZWP_FULLSCREEN_SHELL_MODE_FEEDBACK_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_fullscreen_shell_mode_feedback_v1",
+        version: 1u32,
+        protocol: "fullscreen_shell_unstable_v1",
+        family: "unstable",
+        requests: &[],
+        events: &[
+            waynest::MessageInfo {
+                name: "mode_successful",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "mode_failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "present_cancelled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "idle-inhibit-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "idle-inhibit-unstable-v1")))]
+pub mod idle_inhibit_unstable_v1 {
+    pub static ZWP_IDLE_INHIBIT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_idle_inhibit_manager_v1",
+        version: 1u32,
+        protocol: "idle_inhibit_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_inhibitor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_idle_inhibitor_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_IDLE_INHIBITOR_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_idle_inhibitor_v1",
+        version: 1u32,
+        protocol: "idle_inhibit_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "input-method-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input-method-unstable-v1")))]
+pub mod input_method_unstable_v1 {
+    pub static ZWP_INPUT_METHOD_CONTEXT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_input_method_context_v1",
+        version: 1u32,
+        protocol: "input_method_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "commit_string",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "us",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_string",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uss",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "commit",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_styling",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "style",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "delete_surrounding_text",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cursor_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modifiers_map",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "map",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "keysym",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "sym",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifiers",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "grab_keyboard",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "keyboard",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_keyboard"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "key",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "key",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modifiers",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mods_depressed",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mods_latched",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mods_locked",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "group",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "language",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "us",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "language",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "text_direction",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "direction",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "surrounding_text",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "suu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cursor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "reset",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "content_type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hint",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "purpose",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "invoke_action",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit_state",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preferred_language",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "language",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_INPUT_METHOD_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_input_method_v1",
+        version: 1u32,
+        protocol: "input_method_unstable_v1",
+        family: "unstable",
+        requests: &[],
+        events: &[
+            waynest::MessageInfo {
+                name: "activate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_input_method_context_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "deactivate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "context",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_input_method_context_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_INPUT_PANEL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_input_panel_v1",
+        version: 1u32,
+        protocol: "input_method_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_input_panel_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_input_panel_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_INPUT_PANEL_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_input_panel_surface_v1",
+        version: 1u32,
+        protocol: "input_method_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "position",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_overlay_panel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["position"],
+    };
+}
+#[cfg(feature = "input-timestamps-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input-timestamps-unstable-v1")))]
+pub mod input_timestamps_unstable_v1 {
+    pub static ZWP_INPUT_TIMESTAMPS_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_input_timestamps_manager_v1",
+        version: 1u32,
+        protocol: "input_timestamps_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_keyboard_timestamps",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_input_timestamps_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "keyboard",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_keyboard"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_pointer_timestamps",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_input_timestamps_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_touch_timestamps",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_input_timestamps_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "touch",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_touch"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_INPUT_TIMESTAMPS_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_input_timestamps_v1",
+        version: 1u32,
+        protocol: "input_timestamps_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "timestamp",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tv_sec_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_sec_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_nsec",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "keyboard-shortcuts-inhibit-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keyboard-shortcuts-inhibit-unstable-v1")))]
+pub mod keyboard_shortcuts_inhibit_unstable_v1 {
+    pub static ZWP_KEYBOARD_SHORTCUTS_INHIBIT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_keyboard_shortcuts_inhibit_manager_v1",
+        version: 1u32,
+        protocol: "keyboard_shortcuts_inhibit_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "inhibit_shortcuts",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_keyboard_shortcuts_inhibitor_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static ZWP_KEYBOARD_SHORTCUTS_INHIBITOR_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_keyboard_shortcuts_inhibitor_v1",
+        version: 1u32,
+        protocol: "keyboard_shortcuts_inhibit_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "active",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "inactive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "linux-dmabuf-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "linux-dmabuf-unstable-v1")))]
+pub mod linux_dmabuf_unstable_v1 {
+    pub static ZWP_LINUX_DMABUF_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_dmabuf_v1",
+        version: 5u32,
+        protocol: "linux_dmabuf_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_params",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "params_id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_buffer_params_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_default_feedback",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_surface_feedback",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "format",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modifier",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_LINUX_BUFFER_PARAMS_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_buffer_params_v1",
+        version: 5u32,
+        protocol: "linux_dmabuf_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "add",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "huuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "plane_idx",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_immed",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2niiuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer_id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "created",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error", "flags"],
+    };
+    pub static ZWP_LINUX_DMABUF_FEEDBACK_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_dmabuf_feedback_v1",
+        version: 5u32,
+        protocol: "linux_dmabuf_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "format_table",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "hu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "main_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tranche_done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "tranche_target_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tranche_formats",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "indices",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tranche_flags",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("tranche_flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["tranche_flags"],
+    };
+}
+#[cfg(feature = "zwp-linux-explicit-synchronization-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zwp-linux-explicit-synchronization-unstable-v1")))]
+pub mod zwp_linux_explicit_synchronization_unstable_v1 {
+    pub static ZWP_LINUX_EXPLICIT_SYNCHRONIZATION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_explicit_synchronization_v1",
+        version: 2u32,
+        protocol: "zwp_linux_explicit_synchronization_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_synchronization",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_surface_synchronization_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static ZWP_LINUX_SURFACE_SYNCHRONIZATION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_surface_synchronization_v1",
+        version: 2u32,
+        protocol: "zwp_linux_explicit_synchronization_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_acquire_fence",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "h",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_release",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "release",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_buffer_release_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static ZWP_LINUX_BUFFER_RELEASE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_linux_buffer_release_v1",
+        version: 1u32,
+        protocol: "zwp_linux_explicit_synchronization_unstable_v1",
+        family: "unstable",
+        requests: &[],
+        events: &[
+            waynest::MessageInfo {
+                name: "fenced_release",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "h",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fence",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "immediate_release",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "pointer-constraints-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pointer-constraints-unstable-v1")))]
+pub mod pointer_constraints_unstable_v1 {
+    pub static ZWP_POINTER_CONSTRAINTS_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_pointer_constraints_v1",
+        version: 1u32,
+        protocol: "pointer_constraints_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "lock_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_locked_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "lifetime",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("lifetime"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "confine_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_confined_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "lifetime",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("lifetime"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error", "lifetime"],
+    };
+    pub static ZWP_LOCKED_POINTER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_locked_pointer_v1",
+        version: 1u32,
+        protocol: "pointer_constraints_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_cursor_position_hint",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface_x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface_y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "locked",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unlocked",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_CONFINED_POINTER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_confined_pointer_v1",
+        version: 1u32,
+        protocol: "pointer_constraints_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "confined",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unconfined",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "pointer-gestures-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pointer-gestures-unstable-v1")))]
+pub mod pointer_gestures_unstable_v1 {
+    pub static ZWP_POINTER_GESTURES_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_pointer_gestures_v1",
+        version: 3u32,
+        protocol: "pointer_gestures_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_swipe_gesture",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_pointer_gesture_swipe_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_pinch_gesture",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_pointer_gesture_pinch_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "2",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_hold_gesture",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_pointer_gesture_hold_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_POINTER_GESTURE_SWIPE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_pointer_gesture_swipe_v1",
+        version: 3u32,
+        protocol: "pointer_gestures_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "begin",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fingers",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "update",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dx",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dy",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "end",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uui",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cancelled",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_POINTER_GESTURE_PINCH_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_pointer_gesture_pinch_v1",
+        version: 3u32,
+        protocol: "pointer_gestures_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "begin",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fingers",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "update",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uffff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dx",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dy",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "rotation",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "end",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uui",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cancelled",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_POINTER_GESTURE_HOLD_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_pointer_gesture_hold_v1",
+        version: 3u32,
+        protocol: "pointer_gestures_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "begin",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3uuou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fingers",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "end",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3uui",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cancelled",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "wp-primary-selection-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wp-primary-selection-unstable-v1")))]
+pub mod wp_primary_selection_unstable_v1 {
+    pub static ZWP_PRIMARY_SELECTION_DEVICE_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_primary_selection_device_manager_v1",
+        version: 1u32,
+        protocol: "wp_primary_selection_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_primary_selection_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_primary_selection_device_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_PRIMARY_SELECTION_DEVICE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_primary_selection_device_v1",
+        version: 1u32,
+        protocol: "wp_primary_selection_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_primary_selection_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "data_offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "offer",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_primary_selection_offer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_primary_selection_offer_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_PRIMARY_SELECTION_OFFER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_primary_selection_offer_v1",
+        version: 1u32,
+        protocol: "wp_primary_selection_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "receive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_PRIMARY_SELECTION_SOURCE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_primary_selection_source_v1",
+        version: 1u32,
+        protocol: "wp_primary_selection_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "send",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cancelled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "relative-pointer-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "relative-pointer-unstable-v1")))]
+pub mod relative_pointer_unstable_v1 {
+    pub static ZWP_RELATIVE_POINTER_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_relative_pointer_manager_v1",
+        version: 1u32,
+        protocol: "relative_pointer_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_relative_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_relative_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pointer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_RELATIVE_POINTER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_relative_pointer_v1",
+        version: 1u32,
+        protocol: "relative_pointer_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "relative_motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuffff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "utime_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "utime_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dx",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dy",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dx_unaccel",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dy_unaccel",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "tablet-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tablet-unstable-v1")))]
+pub mod tablet_unstable_v1 {
+    pub static ZWP_TABLET_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_manager_v1",
+        version: 1u32,
+        protocol: "tablet_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_tablet_seat",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tablet_seat",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_seat_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_SEAT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_seat_v1",
+        version: 1u32,
+        protocol: "tablet_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "tablet_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tool_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_tool_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_TOOL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_tool_v1",
+        version: 1u32,
+        protocol: "tablet_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tool_type",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("type"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hardware_serial",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hardware_serial_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hardware_serial_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hardware_id_wacom",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hardware_id_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hardware_id_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capability",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capability",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("capability"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "proximity_in",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tablet",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_tablet_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "proximity_out",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "down",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "up",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pressure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "pressure",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "distance",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "distance",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tilt",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tilt_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tilt_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "rotation",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "slider",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "position",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "wheel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "clicks",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["type", "capability", "button_state", "error"],
+    };
+    pub static ZWP_TABLET_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_v1",
+        version: 1u32,
+        protocol: "tablet_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "vid",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pid",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "path",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "path",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "tablet-unstable-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tablet-unstable-v2")))]
+pub mod tablet_unstable_v2 {
+    pub static ZWP_TABLET_MANAGER_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_manager_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_tablet_seat",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tablet_seat",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_seat_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_SEAT_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_seat_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "tablet_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tool_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_tool_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pad_added",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_TOOL_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_tool_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tool_type",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("type"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hardware_serial",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hardware_serial_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hardware_serial_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "hardware_id_wacom",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hardware_id_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hardware_id_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capability",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "capability",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("capability"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "proximity_in",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tablet",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_tablet_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "proximity_out",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "down",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "up",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pressure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "pressure",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "distance",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "distance",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "tilt",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tilt_x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tilt_y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "rotation",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "slider",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "position",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "wheel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "fi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "clicks",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["type", "capability", "button_state", "error"],
+    };
+    pub static ZWP_TABLET_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "vid",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "pid",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "path",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "path",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_PAD_RING_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_ring_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "angle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "degrees",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["source"],
+    };
+    pub static ZWP_TABLET_PAD_STRIP_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_strip_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "position",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["source"],
+    };
+    pub static ZWP_TABLET_PAD_GROUP_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_group_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "buttons",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buttons",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ring",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "ring",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_ring_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "strip",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "strip",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_strip_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "modes",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "modes",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "mode_switch",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWP_TABLET_PAD_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_tablet_pad_v2",
+        version: 1u32,
+        protocol: "tablet_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "usu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "group",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "pad_group",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_pad_group_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "path",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "path",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "buttons",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buttons",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uoo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tablet",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwp_tablet_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "removed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["button_state"],
+    };
+}
+#[cfg(feature = "text-input-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "text-input-unstable-v1")))]
+pub mod text_input_unstable_v1 {
+    pub static ZWP_TEXT_INPUT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_text_input_v1",
+        version: 1u32,
+        protocol: "text_input_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "activate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "deactivate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "show_input_panel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "hide_input_panel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "reset",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_surrounding_text",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "suu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cursor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_content_type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hint",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("content_hint"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "purpose",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("content_purpose"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_cursor_rectangle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_preferred_language",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "language",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit_state",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "invoke_action",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "modifiers_map",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "map",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "input_panel_state",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_string",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uss",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "commit",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_styling",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "style",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("preedit_style"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit_string",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "us",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cursor_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "delete_surrounding_text",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "keysym",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "sym",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifiers",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "language",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "us",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "language",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "text_direction",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "direction",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("text_direction"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["content_hint", "content_purpose", "preedit_style", "text_direction"],
+    };
+    pub static ZWP_TEXT_INPUT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_text_input_manager_v1",
+        version: 1u32,
+        protocol: "text_input_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_text_input",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_text_input_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "text-input-unstable-v3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "text-input-unstable-v3")))]
+pub mod text_input_unstable_v3 {
+    pub static ZWP_TEXT_INPUT_V3: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_text_input_v3",
+        version: 2u32,
+        protocol: "text_input_unstable_v3",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "enable",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "disable",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_surrounding_text",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_text_change_cause",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "cause",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("change_cause"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_content_type",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "hint",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("content_hint"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "purpose",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("content_purpose"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_cursor_rectangle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_available_actions",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "available_actions",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "show_input_panel",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "hide_input_panel",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_string",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?sii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "cursor_begin",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "cursor_end",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit_string",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "text",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "delete_surrounding_text",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "before_length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "after_length",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "action",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "action",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("action"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "language",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "language",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preedit_hint",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "start",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "end",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hint",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("preedit_hint"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[
+            "change_cause",
+            "content_hint",
+            "content_purpose",
+            "error",
+            "action",
+            "preedit_hint",
+        ],
+    };
+    pub static ZWP_TEXT_INPUT_MANAGER_V3: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_text_input_manager_v3",
+        version: 2u32,
+        protocol: "text_input_unstable_v3",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_text_input",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_text_input_v3"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-decoration-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-decoration-unstable-v1")))]
+pub mod xdg_decoration_unstable_v1 {
+    pub static ZXDG_DECORATION_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_decoration_manager_v1",
+        version: 2u32,
+        protocol: "xdg_decoration_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_toplevel_decoration",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_toplevel_decoration_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZXDG_TOPLEVEL_DECORATION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_toplevel_decoration_v1",
+        version: 2u32,
+        protocol: "xdg_decoration_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error", "mode"],
+    };
+}
+#[cfg(feature = "xdg-foreign-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-foreign-unstable-v1")))]
+pub mod xdg_foreign_unstable_v1 {
+    pub static ZXDG_EXPORTER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_exporter_v1",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "export",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_exported_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZXDG_IMPORTER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_importer_v1",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "import",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ns",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_imported_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "handle",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZXDG_EXPORTED_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_exported_v1",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "handle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "handle",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZXDG_IMPORTED_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_imported_v1",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent_of",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "destroyed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-foreign-unstable-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-foreign-unstable-v2")))]
+pub mod xdg_foreign_unstable_v2 {
+    pub static ZXDG_EXPORTER_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_exporter_v2",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "export_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_exported_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static ZXDG_IMPORTER_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_importer_v2",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "import_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ns",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_imported_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "handle",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZXDG_EXPORTED_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_exported_v2",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "handle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "handle",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZXDG_IMPORTED_V2: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_imported_v2",
+        version: 1u32,
+        protocol: "xdg_foreign_unstable_v2",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent_of",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "destroyed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "xdg-output-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-output-unstable-v1")))]
+pub mod xdg_output_unstable_v1 {
+    pub static ZXDG_OUTPUT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_output_manager_v1",
+        version: 3u32,
+        protocol: "xdg_output_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_output_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZXDG_OUTPUT_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_output_v1",
+        version: 3u32,
+        protocol: "xdg_output_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "logical_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "logical_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: Some(3u32),
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "name",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "description",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-shell-unstable-v5")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-shell-unstable-v5")))]
+pub mod xdg_shell_unstable_v5 {
+    pub static XDG_SHELL: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_shell",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v5",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "use_unstable_version",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "version",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nooouii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_popup"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pong",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "ping",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["version", "error"],
+    };
+    pub static XDG_SURFACE: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_surface",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v5",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "show_window_menu",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "move",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_window_geometry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiau",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "states",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "close",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["resize_edge", "state"],
+    };
+    pub static XDG_POPUP: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "xdg_popup",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v5",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "popup_done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "xdg-shell-unstable-v6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-shell-unstable-v6")))]
+pub mod xdg_shell_unstable_v6 {
+    pub static ZXDG_SHELL_V6: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_shell_v6",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v6",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_positioner",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_positioner_v6"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_surface_v6"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pong",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "ping",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static ZXDG_POSITIONER_V6: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_positioner_v6",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v6",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor_rect",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_gravity",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "gravity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("gravity"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_constraint_adjustment",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "constraint_adjustment",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_offset",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error", "anchor", "gravity", "constraint_adjustment"],
+    };
+    pub static ZXDG_SURFACE_V6: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_surface_v6",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v6",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_toplevel_v6"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zxdg_popup_v6"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zxdg_surface_v6"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "positioner",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zxdg_positioner_v6"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_window_geometry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static ZXDG_TOPLEVEL_V6: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_toplevel_v6",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v6",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zxdg_toplevel_v6"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "show_window_menu",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "move",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_max_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_min_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iia",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "states",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "close",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["resize_edge", "state"],
+    };
+    pub static ZXDG_POPUP_V6: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zxdg_popup_v6",
+        version: 1u32,
+        protocol: "xdg_shell_unstable_v6",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "grab",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "popup_done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "xwayland-keyboard-grab-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xwayland-keyboard-grab-unstable-v1")))]
+pub mod xwayland_keyboard_grab_unstable_v1 {
+    pub static ZWP_XWAYLAND_KEYBOARD_GRAB_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_xwayland_keyboard_grab_manager_v1",
+        version: 1u32,
+        protocol: "xwayland_keyboard_grab_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "grab_keyboard",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_xwayland_keyboard_grab_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWP_XWAYLAND_KEYBOARD_GRAB_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwp_xwayland_keyboard_grab_v1",
+        version: 1u32,
+        protocol: "xwayland_keyboard_grab_unstable_v1",
+        family: "unstable",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+pub static INTERFACES: &[&waynest::InterfaceInfo] = &[
+    #[cfg(feature = "fullscreen-shell-unstable-v1")]
+    &fullscreen_shell_unstable_v1::ZWP_FULLSCREEN_SHELL_V1,
+    #[cfg(feature = "fullscreen-shell-unstable-v1")]
+    &fullscreen_shell_unstable_v1::ZWP_FULLSCREEN_SHELL_MODE_FEEDBACK_V1,
+    #[cfg(feature = "idle-inhibit-unstable-v1")]
+    &idle_inhibit_unstable_v1::ZWP_IDLE_INHIBIT_MANAGER_V1,
+    #[cfg(feature = "idle-inhibit-unstable-v1")]
+    &idle_inhibit_unstable_v1::ZWP_IDLE_INHIBITOR_V1,
+    #[cfg(feature = "input-method-unstable-v1")]
+    &input_method_unstable_v1::ZWP_INPUT_METHOD_CONTEXT_V1,
+    #[cfg(feature = "input-method-unstable-v1")]
+    &input_method_unstable_v1::ZWP_INPUT_METHOD_V1,
+    #[cfg(feature = "input-method-unstable-v1")]
+    &input_method_unstable_v1::ZWP_INPUT_PANEL_V1,
+    #[cfg(feature = "input-method-unstable-v1")]
+    &input_method_unstable_v1::ZWP_INPUT_PANEL_SURFACE_V1,
+    #[cfg(feature = "input-timestamps-unstable-v1")]
+    &input_timestamps_unstable_v1::ZWP_INPUT_TIMESTAMPS_MANAGER_V1,
+    #[cfg(feature = "input-timestamps-unstable-v1")]
+    &input_timestamps_unstable_v1::ZWP_INPUT_TIMESTAMPS_V1,
+    #[cfg(feature = "keyboard-shortcuts-inhibit-unstable-v1")]
+    &keyboard_shortcuts_inhibit_unstable_v1::ZWP_KEYBOARD_SHORTCUTS_INHIBIT_MANAGER_V1,
+    #[cfg(feature = "keyboard-shortcuts-inhibit-unstable-v1")]
+    &keyboard_shortcuts_inhibit_unstable_v1::ZWP_KEYBOARD_SHORTCUTS_INHIBITOR_V1,
+    #[cfg(feature = "linux-dmabuf-unstable-v1")]
+    &linux_dmabuf_unstable_v1::ZWP_LINUX_DMABUF_V1,
+    #[cfg(feature = "linux-dmabuf-unstable-v1")]
+    &linux_dmabuf_unstable_v1::ZWP_LINUX_BUFFER_PARAMS_V1,
+    #[cfg(feature = "linux-dmabuf-unstable-v1")]
+    &linux_dmabuf_unstable_v1::ZWP_LINUX_DMABUF_FEEDBACK_V1,
+    #[cfg(feature = "zwp-linux-explicit-synchronization-unstable-v1")]
+    &zwp_linux_explicit_synchronization_unstable_v1::ZWP_LINUX_EXPLICIT_SYNCHRONIZATION_V1,
+    #[cfg(feature = "zwp-linux-explicit-synchronization-unstable-v1")]
+    &zwp_linux_explicit_synchronization_unstable_v1::ZWP_LINUX_SURFACE_SYNCHRONIZATION_V1,
+    #[cfg(feature = "zwp-linux-explicit-synchronization-unstable-v1")]
+    &zwp_linux_explicit_synchronization_unstable_v1::ZWP_LINUX_BUFFER_RELEASE_V1,
+    #[cfg(feature = "pointer-constraints-unstable-v1")]
+    &pointer_constraints_unstable_v1::ZWP_POINTER_CONSTRAINTS_V1,
+    #[cfg(feature = "pointer-constraints-unstable-v1")]
+    &pointer_constraints_unstable_v1::ZWP_LOCKED_POINTER_V1,
+    #[cfg(feature = "pointer-constraints-unstable-v1")]
+    &pointer_constraints_unstable_v1::ZWP_CONFINED_POINTER_V1,
+    #[cfg(feature = "pointer-gestures-unstable-v1")]
+    &pointer_gestures_unstable_v1::ZWP_POINTER_GESTURES_V1,
+    #[cfg(feature = "pointer-gestures-unstable-v1")]
+    &pointer_gestures_unstable_v1::ZWP_POINTER_GESTURE_SWIPE_V1,
+    #[cfg(feature = "pointer-gestures-unstable-v1")]
+    &pointer_gestures_unstable_v1::ZWP_POINTER_GESTURE_PINCH_V1,
+    #[cfg(feature = "pointer-gestures-unstable-v1")]
+    &pointer_gestures_unstable_v1::ZWP_POINTER_GESTURE_HOLD_V1,
+    #[cfg(feature = "wp-primary-selection-unstable-v1")]
+    &wp_primary_selection_unstable_v1::ZWP_PRIMARY_SELECTION_DEVICE_MANAGER_V1,
+    #[cfg(feature = "wp-primary-selection-unstable-v1")]
+    &wp_primary_selection_unstable_v1::ZWP_PRIMARY_SELECTION_DEVICE_V1,
+    #[cfg(feature = "wp-primary-selection-unstable-v1")]
+    &wp_primary_selection_unstable_v1::ZWP_PRIMARY_SELECTION_OFFER_V1,
+    #[cfg(feature = "wp-primary-selection-unstable-v1")]
+    &wp_primary_selection_unstable_v1::ZWP_PRIMARY_SELECTION_SOURCE_V1,
+    #[cfg(feature = "relative-pointer-unstable-v1")]
+    &relative_pointer_unstable_v1::ZWP_RELATIVE_POINTER_MANAGER_V1,
+    #[cfg(feature = "relative-pointer-unstable-v1")]
+    &relative_pointer_unstable_v1::ZWP_RELATIVE_POINTER_V1,
+    #[cfg(feature = "tablet-unstable-v1")]
+    &tablet_unstable_v1::ZWP_TABLET_MANAGER_V1,
+    #[cfg(feature = "tablet-unstable-v1")]
+    &tablet_unstable_v1::ZWP_TABLET_SEAT_V1,
+    #[cfg(feature = "tablet-unstable-v1")]
+    &tablet_unstable_v1::ZWP_TABLET_TOOL_V1,
+    #[cfg(feature = "tablet-unstable-v1")]
+    &tablet_unstable_v1::ZWP_TABLET_V1,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_MANAGER_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_SEAT_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_TOOL_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_PAD_RING_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_PAD_STRIP_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_PAD_GROUP_V2,
+    #[cfg(feature = "tablet-unstable-v2")]
+    &tablet_unstable_v2::ZWP_TABLET_PAD_V2,
+    #[cfg(feature = "text-input-unstable-v1")]
+    &text_input_unstable_v1::ZWP_TEXT_INPUT_V1,
+    #[cfg(feature = "text-input-unstable-v1")]
+    &text_input_unstable_v1::ZWP_TEXT_INPUT_MANAGER_V1,
+    #[cfg(feature = "text-input-unstable-v3")]
+    &text_input_unstable_v3::ZWP_TEXT_INPUT_V3,
+    #[cfg(feature = "text-input-unstable-v3")]
+    &text_input_unstable_v3::ZWP_TEXT_INPUT_MANAGER_V3,
+    #[cfg(feature = "xdg-decoration-unstable-v1")]
+    &xdg_decoration_unstable_v1::ZXDG_DECORATION_MANAGER_V1,
+    #[cfg(feature = "xdg-decoration-unstable-v1")]
+    &xdg_decoration_unstable_v1::ZXDG_TOPLEVEL_DECORATION_V1,
+    #[cfg(feature = "xdg-foreign-unstable-v1")]
+    &xdg_foreign_unstable_v1::ZXDG_EXPORTER_V1,
+    #[cfg(feature = "xdg-foreign-unstable-v1")]
+    &xdg_foreign_unstable_v1::ZXDG_IMPORTER_V1,
+    #[cfg(feature = "xdg-foreign-unstable-v1")]
+    &xdg_foreign_unstable_v1::ZXDG_EXPORTED_V1,
+    #[cfg(feature = "xdg-foreign-unstable-v1")]
+    &xdg_foreign_unstable_v1::ZXDG_IMPORTED_V1,
+    #[cfg(feature = "xdg-foreign-unstable-v2")]
+    &xdg_foreign_unstable_v2::ZXDG_EXPORTER_V2,
+    #[cfg(feature = "xdg-foreign-unstable-v2")]
+    &xdg_foreign_unstable_v2::ZXDG_IMPORTER_V2,
+    #[cfg(feature = "xdg-foreign-unstable-v2")]
+    &xdg_foreign_unstable_v2::ZXDG_EXPORTED_V2,
+    #[cfg(feature = "xdg-foreign-unstable-v2")]
+    &xdg_foreign_unstable_v2::ZXDG_IMPORTED_V2,
+    #[cfg(feature = "xdg-output-unstable-v1")]
+    &xdg_output_unstable_v1::ZXDG_OUTPUT_MANAGER_V1,
+    #[cfg(feature = "xdg-output-unstable-v1")]
+    &xdg_output_unstable_v1::ZXDG_OUTPUT_V1,
+    #[cfg(feature = "xdg-shell-unstable-v5")]
+    &xdg_shell_unstable_v5::XDG_SHELL,
+    #[cfg(feature = "xdg-shell-unstable-v5")]
+    &xdg_shell_unstable_v5::XDG_SURFACE,
+    #[cfg(feature = "xdg-shell-unstable-v5")]
+    &xdg_shell_unstable_v5::XDG_POPUP,
+    #[cfg(feature = "xdg-shell-unstable-v6")]
+    &xdg_shell_unstable_v6::ZXDG_SHELL_V6,
+    #[cfg(feature = "xdg-shell-unstable-v6")]
+    &xdg_shell_unstable_v6::ZXDG_POSITIONER_V6,
+    #[cfg(feature = "xdg-shell-unstable-v6")]
+    &xdg_shell_unstable_v6::ZXDG_SURFACE_V6,
+    #[cfg(feature = "xdg-shell-unstable-v6")]
+    &xdg_shell_unstable_v6::ZXDG_TOPLEVEL_V6,
+    #[cfg(feature = "xdg-shell-unstable-v6")]
+    &xdg_shell_unstable_v6::ZXDG_POPUP_V6,
+    #[cfg(feature = "xwayland-keyboard-grab-unstable-v1")]
+    &xwayland_keyboard_grab_unstable_v1::ZWP_XWAYLAND_KEYBOARD_GRAB_MANAGER_V1,
+    #[cfg(feature = "xwayland-keyboard-grab-unstable-v1")]
+    &xwayland_keyboard_grab_unstable_v1::ZWP_XWAYLAND_KEYBOARD_GRAB_V1,
+];
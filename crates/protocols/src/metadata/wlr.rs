@@ -0,0 +1,2963 @@
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the wlr_data_control_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-data-control-unstable-v1.xml)
+//
+//   Copyright © 2018 Simon Ser
+//   Copyright © 2019 Ivan Molodetskikh
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_export_dmabuf_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-export-dmabuf-unstable-v1.xml)
+//
+//   Copyright © 2018 Rostislav Pehlivanov
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the wlr_foreign_toplevel_management_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-foreign-toplevel-management-unstable-v1.xml)
+//
+//   Copyright © 2018 Ilia Bozhinov
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_gamma_control_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-gamma-control-unstable-v1.xml)
+//
+//   Copyright © 2015 Giulio camuffo
+//   Copyright © 2018 Simon Ser
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_input_inhibit_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-input-inhibitor-unstable-v1.xml)
+//
+//   Copyright © 2018 Drew DeVault
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_layer_shell_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-layer-shell-unstable-v1.xml)
+//
+//   Copyright © 2017 Drew DeVault
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_output_management_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-output-management-unstable-v1.xml)
+//
+//   Copyright © 2019 Purism SPC
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_output_power_management_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-output-power-management-unstable-v1.xml)
+//
+//   Copyright © 2019 Purism SPC
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the wlr_screencopy_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-screencopy-unstable-v1.xml)
+//
+//   Copyright © 2018 Simon Ser
+//   Copyright © 2019 Andri Yngvason
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the wlr_virtual_pointer_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-virtual-pointer-unstable-v1.xml)
+//
+//   Copyright © 2019 Josef Gajdusek
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "wlr-data-control-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-data-control-unstable-v1")))]
+pub mod wlr_data_control_unstable_v1 {
+    pub static ZWLR_DATA_CONTROL_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_data_control_manager_v1",
+        version: 2u32,
+        protocol: "wlr_data_control_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_data_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_data_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_data_control_device_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWLR_DATA_CONTROL_DEVICE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_data_control_device_v1",
+        version: 2u32,
+        protocol: "wlr_data_control_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_primary_selection",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "data_offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_data_control_offer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_data_control_offer_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "primary_selection",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_data_control_offer_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static ZWLR_DATA_CONTROL_SOURCE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_data_control_source_v1",
+        version: 1u32,
+        protocol: "wlr_data_control_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "send",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cancelled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static ZWLR_DATA_CONTROL_OFFER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_data_control_offer_v1",
+        version: 1u32,
+        protocol: "wlr_data_control_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "receive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &[],
+    };
+}
+#[cfg(feature = "wlr-export-dmabuf-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-export-dmabuf-unstable-v1")))]
+pub mod wlr_export_dmabuf_unstable_v1 {
+    pub static ZWLR_EXPORT_DMABUF_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_export_dmabuf_manager_v1",
+        version: 1u32,
+        protocol: "wlr_export_dmabuf_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "capture_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nio",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_export_dmabuf_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "overlay_cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWLR_EXPORT_DMABUF_FRAME_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_export_dmabuf_frame_v1",
+        version: 1u32,
+        protocol: "wlr_export_dmabuf_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuuuuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset_x",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset_y",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "buffer_flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mod_high",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mod_low",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "num_objects",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "object",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uhuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "index",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "plane_index",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ready",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tv_sec_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_sec_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_nsec",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "cancel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "reason",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("cancel_reason"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["flags", "cancel_reason"],
+    };
+}
+#[cfg(feature = "wlr-foreign-toplevel-management-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-foreign-toplevel-management-unstable-v1")))]
+pub mod wlr_foreign_toplevel_management_unstable_v1 {
+    pub static ZWLR_FOREIGN_TOPLEVEL_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_foreign_toplevel_manager_v1",
+        version: 3u32,
+        protocol: "wlr_foreign_toplevel_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "toplevel",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_foreign_toplevel_handle_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWLR_FOREIGN_TOPLEVEL_HANDLE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_foreign_toplevel_handle_v1",
+        version: 3u32,
+        protocol: "wlr_foreign_toplevel_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "activate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "close",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_rectangle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_fullscreen",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "output_enter",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "output_leave",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "state",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "closed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "parent",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_foreign_toplevel_handle_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ],
+        enums: &["state", "error"],
+    };
+}
+#[cfg(feature = "wlr-gamma-control-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-gamma-control-unstable-v1")))]
+pub mod wlr_gamma_control_unstable_v1 {
+    pub static ZWLR_GAMMA_CONTROL_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_gamma_control_manager_v1",
+        version: 1u32,
+        protocol: "wlr_gamma_control_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_gamma_control",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_gamma_control_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWLR_GAMMA_CONTROL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_gamma_control_v1",
+        version: 1u32,
+        protocol: "wlr_gamma_control_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_gamma",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "h",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "gamma_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "wlr-input-inhibit-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-input-inhibit-unstable-v1")))]
+pub mod wlr_input_inhibit_unstable_v1 {
+    pub static ZWLR_INPUT_INHIBIT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_input_inhibit_manager_v1",
+        version: 1u32,
+        protocol: "wlr_input_inhibit_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_inhibitor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_input_inhibitor_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static ZWLR_INPUT_INHIBITOR_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_input_inhibitor_v1",
+        version: 1u32,
+        protocol: "wlr_input_inhibit_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+#[cfg(feature = "wlr-layer-shell-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-layer-shell-unstable-v1")))]
+pub mod wlr_layer_shell_unstable_v1 {
+    pub static ZWLR_LAYER_SHELL_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_layer_shell_v1",
+        version: 5u32,
+        protocol: "wlr_layer_shell_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_layer_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no?ous",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_layer_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "layer",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("layer"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "namespace",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error", "layer"],
+    };
+    pub static ZWLR_LAYER_SURFACE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_layer_surface_v1",
+        version: 5u32,
+        protocol: "wlr_layer_shell_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_exclusive_zone",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "zone",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_margin",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "top",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "right",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "bottom",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "left",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_keyboard_interactivity",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "keyboard_interactivity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("keyboard_interactivity"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "popup",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_popup"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_layer",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "layer",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("zwlr_layer_shell_v1.layer"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_exclusive_edge",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "edge",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "closed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["keyboard_interactivity", "error", "anchor"],
+    };
+}
+#[cfg(feature = "wlr-output-management-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-output-management-unstable-v1")))]
+pub mod wlr_output_management_unstable_v1 {
+    pub static ZWLR_OUTPUT_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_manager_v1",
+        version: 4u32,
+        protocol: "wlr_output_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_configuration",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_configuration_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "head",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "head",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "done",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWLR_OUTPUT_HEAD_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_head_v1",
+        version: 4u32,
+        protocol: "wlr_output_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "name",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "description",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "physical_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_mode_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "enabled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "enabled",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "current_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_mode_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "transform",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "scale",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "make",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "make",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "model",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "model",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "serial_number",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial_number",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "adaptive_sync",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("adaptive_sync_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        enums: &["adaptive_sync_state"],
+    };
+    pub static ZWLR_OUTPUT_MODE_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_mode_v1",
+        version: 3u32,
+        protocol: "wlr_output_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "refresh",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "refresh",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "preferred",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "finished",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &[],
+    };
+    pub static ZWLR_OUTPUT_CONFIGURATION_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_configuration_v1",
+        version: 4u32,
+        protocol: "wlr_output_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "enable_head",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_configuration_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "head",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "disable_head",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "head",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "apply",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "test",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "succeeded",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "cancelled",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["error"],
+    };
+    pub static ZWLR_OUTPUT_CONFIGURATION_HEAD_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_configuration_head_v1",
+        version: 4u32,
+        protocol: "wlr_output_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_mode_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_custom_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "refresh",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_transform",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_scale",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_adaptive_sync",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("zwlr_output_head_v1.adaptive_sync_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+}
+#[cfg(feature = "wlr-output-power-management-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-output-power-management-unstable-v1")))]
+pub mod wlr_output_power_management_unstable_v1 {
+    pub static ZWLR_OUTPUT_POWER_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_power_manager_v1",
+        version: 1u32,
+        protocol: "wlr_output_power_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "get_output_power",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_power_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWLR_OUTPUT_POWER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_output_power_v1",
+        version: 1u32,
+        protocol: "wlr_output_power_management_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "set_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ],
+        enums: &["mode", "error"],
+    };
+}
+#[cfg(feature = "wlr-screencopy-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-screencopy-unstable-v1")))]
+pub mod wlr_screencopy_unstable_v1 {
+    pub static ZWLR_SCREENCOPY_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_screencopy_manager_v1",
+        version: 3u32,
+        protocol: "wlr_screencopy_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "capture_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nio",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_screencopy_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "overlay_cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capture_output_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nioiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_screencopy_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "overlay_cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+    pub static ZWLR_SCREENCOPY_FRAME_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_screencopy_frame_v1",
+        version: 3u32,
+        protocol: "wlr_screencopy_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "copy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "copy_with_damage",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[
+            waynest::MessageInfo {
+                name: "buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_shm.format"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "flags",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ready",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tv_sec_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_sec_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "tv_nsec",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "failed",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "damage",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2uuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "linux_dmabuf",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "buffer_done",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+        ],
+        enums: &["error", "flags"],
+    };
+}
+#[cfg(feature = "wlr-virtual-pointer-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-virtual-pointer-unstable-v1")))]
+pub mod wlr_virtual_pointer_unstable_v1 {
+    pub static ZWLR_VIRTUAL_POINTER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_virtual_pointer_v1",
+        version: 2u32,
+        protocol: "wlr_virtual_pointer_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dx",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dy",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "motion_absolute",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x_extent",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y_extent",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuf",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "value",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "axis_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "axis_source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis_source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_discrete",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uufi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "value",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "discrete",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ],
+        events: &[],
+        enums: &["error"],
+    };
+    pub static ZWLR_VIRTUAL_POINTER_MANAGER_V1: waynest::InterfaceInfo = waynest::InterfaceInfo {
+        name: "zwlr_virtual_pointer_manager_v1",
+        version: 2u32,
+        protocol: "wlr_virtual_pointer_unstable_v1",
+        family: "wlr",
+        requests: &[
+            waynest::MessageInfo {
+                name: "create_virtual_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?on",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_virtual_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_virtual_pointer_with_output",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o?on",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_virtual_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ],
+        events: &[],
+        enums: &[],
+    };
+}
+pub static INTERFACES: &[&waynest::InterfaceInfo] = &[
+    #[cfg(feature = "wlr-data-control-unstable-v1")]
+    &wlr_data_control_unstable_v1::ZWLR_DATA_CONTROL_MANAGER_V1,
+    #[cfg(feature = "wlr-data-control-unstable-v1")]
+    &wlr_data_control_unstable_v1::ZWLR_DATA_CONTROL_DEVICE_V1,
+    #[cfg(feature = "wlr-data-control-unstable-v1")]
+    &wlr_data_control_unstable_v1::ZWLR_DATA_CONTROL_SOURCE_V1,
+    #[cfg(feature = "wlr-data-control-unstable-v1")]
+    &wlr_data_control_unstable_v1::ZWLR_DATA_CONTROL_OFFER_V1,
+    #[cfg(feature = "wlr-export-dmabuf-unstable-v1")]
+    &wlr_export_dmabuf_unstable_v1::ZWLR_EXPORT_DMABUF_MANAGER_V1,
+    #[cfg(feature = "wlr-export-dmabuf-unstable-v1")]
+    &wlr_export_dmabuf_unstable_v1::ZWLR_EXPORT_DMABUF_FRAME_V1,
+    #[cfg(feature = "wlr-foreign-toplevel-management-unstable-v1")]
+    &wlr_foreign_toplevel_management_unstable_v1::ZWLR_FOREIGN_TOPLEVEL_MANAGER_V1,
+    #[cfg(feature = "wlr-foreign-toplevel-management-unstable-v1")]
+    &wlr_foreign_toplevel_management_unstable_v1::ZWLR_FOREIGN_TOPLEVEL_HANDLE_V1,
+    #[cfg(feature = "wlr-gamma-control-unstable-v1")]
+    &wlr_gamma_control_unstable_v1::ZWLR_GAMMA_CONTROL_MANAGER_V1,
+    #[cfg(feature = "wlr-gamma-control-unstable-v1")]
+    &wlr_gamma_control_unstable_v1::ZWLR_GAMMA_CONTROL_V1,
+    #[cfg(feature = "wlr-input-inhibit-unstable-v1")]
+    &wlr_input_inhibit_unstable_v1::ZWLR_INPUT_INHIBIT_MANAGER_V1,
+    #[cfg(feature = "wlr-input-inhibit-unstable-v1")]
+    &wlr_input_inhibit_unstable_v1::ZWLR_INPUT_INHIBITOR_V1,
+    #[cfg(feature = "wlr-layer-shell-unstable-v1")]
+    &wlr_layer_shell_unstable_v1::ZWLR_LAYER_SHELL_V1,
+    #[cfg(feature = "wlr-layer-shell-unstable-v1")]
+    &wlr_layer_shell_unstable_v1::ZWLR_LAYER_SURFACE_V1,
+    #[cfg(feature = "wlr-output-management-unstable-v1")]
+    &wlr_output_management_unstable_v1::ZWLR_OUTPUT_MANAGER_V1,
+    #[cfg(feature = "wlr-output-management-unstable-v1")]
+    &wlr_output_management_unstable_v1::ZWLR_OUTPUT_HEAD_V1,
+    #[cfg(feature = "wlr-output-management-unstable-v1")]
+    &wlr_output_management_unstable_v1::ZWLR_OUTPUT_MODE_V1,
+    #[cfg(feature = "wlr-output-management-unstable-v1")]
+    &wlr_output_management_unstable_v1::ZWLR_OUTPUT_CONFIGURATION_V1,
+    #[cfg(feature = "wlr-output-management-unstable-v1")]
+    &wlr_output_management_unstable_v1::ZWLR_OUTPUT_CONFIGURATION_HEAD_V1,
+    #[cfg(feature = "wlr-output-power-management-unstable-v1")]
+    &wlr_output_power_management_unstable_v1::ZWLR_OUTPUT_POWER_MANAGER_V1,
+    #[cfg(feature = "wlr-output-power-management-unstable-v1")]
+    &wlr_output_power_management_unstable_v1::ZWLR_OUTPUT_POWER_V1,
+    #[cfg(feature = "wlr-screencopy-unstable-v1")]
+    &wlr_screencopy_unstable_v1::ZWLR_SCREENCOPY_MANAGER_V1,
+    #[cfg(feature = "wlr-screencopy-unstable-v1")]
+    &wlr_screencopy_unstable_v1::ZWLR_SCREENCOPY_FRAME_V1,
+    #[cfg(feature = "wlr-virtual-pointer-unstable-v1")]
+    &wlr_virtual_pointer_unstable_v1::ZWLR_VIRTUAL_POINTER_V1,
+    #[cfg(feature = "wlr-virtual-pointer-unstable-v1")]
+    &wlr_virtual_pointer_unstable_v1::ZWLR_VIRTUAL_POINTER_MANAGER_V1,
+];
@@ -1,29 +1,112 @@
-#[allow(clippy::module_inception)]
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the wayland protocol (external/protocols/wayland/protocol/wayland.xml)
+//
+//   Copyright © 2008-2011 Kristian Høgsberg
+//   Copyright © 2010-2011 Intel Corporation
+//   Copyright © 2012-2013 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person
+//   obtaining a copy of this software and associated documentation files
+//   (the "Software"), to deal in the Software without restriction,
+//   including without limitation the rights to use, copy, modify, merge,
+//   publish, distribute, sublicense, and/or sell copies of the Software,
+//   and to permit persons to whom the Software is furnished to do so,
+//   subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the
+//   next paragraph) shall be included in all copies or substantial
+//   portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//   EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+//   MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+//   NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+//   BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+//   ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+//   CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+//   SOFTWARE.
+
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wayland {
-    #[doc = "The core global object.  This is a special singleton object.  It"]
-    #[doc = "is used for internal Wayland protocol features."]
+    ///The core global object.  This is a special singleton object.  It
+    ///is used for internal Wayland protocol features.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_display {
-        #[doc = "These errors are global and can be emitted in response to any"]
-        #[doc = "server request."]
+        ///These errors are global and can be emitted in response to any
+        ///server request.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "server couldn't find object"]
+            ///server couldn't find object
             InvalidObject = 0u32,
-            #[doc = "method doesn't exist on the specified interface or malformed request"]
+            ///method doesn't exist on the specified interface or malformed request
             InvalidMethod = 1u32,
-            #[doc = "server is out of memory"]
+            ///server is out of memory
             NoMemory = 2u32,
-            #[doc = "implementation error in compositor"]
+            ///implementation error in compositor
             Implementation = 3u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidObject => 1u32,
+                    Self::InvalidMethod => 1u32,
+                    Self::NoMemory => 1u32,
+                    Self::Implementation => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidObject => None,
+                    Self::InvalidMethod => None,
+                    Self::NoMemory => None,
+                    Self::Implementation => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_object", Self::InvalidObject),
+                ("invalid_method", Self::InvalidMethod),
+                ("no_memory", Self::NoMemory),
+                ("implementation", Self::Implementation),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidObject => "invalid_object",
+                    Self::InvalidMethod => "invalid_method",
+                    Self::NoMemory => "no_memory",
+                    Self::Implementation => "implementation",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -36,120 +119,205 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "sync",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_callback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_registry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "registry",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_registry"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_display interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the error message was introduced in
+            pub const ERROR_SINCE: u32 = 1u32;
+            ///The interface version the error message was deprecated in, if any
+            pub const ERROR_DEPRECATED_SINCE: Option<u32> = None;
+            ///The error event is sent out when a fatal (non-recoverable)
+            ///error has occurred.  The object_id argument is the object
+            ///where the error occurred, most often in response to a request
+            ///to that object.  The code identifies the error and is defined
+            ///by the object interface.  As such, each interface defines its
+            ///own set of error codes.  The message is a brief description
+            ///of the error, for (debugging) convenience.
+            pub fn error<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                object_id: waynest::ObjectId,
+                code: u32,
+                message: String,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_display",
+                    object_id = % sender_id, opcode = 0u16, message = "error", direction
+                    = "out", "args.object_id" = % object_id, "args.code" = % code,
+                    "args.message" = % message,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_display",
+                    object_id : % = sender_id, opcode = 0u16, message = "error",
+                    direction = "out", "args.object_id" : % = object_id, "args.code" : %
+                    = code, "args.message" : % = message; "wl_display.error"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(object_id))
+                    .put_uint(code)
+                    .put_string(Some(message))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the delete_id message was introduced in
+            pub const DELETE_ID_SINCE: u32 = 1u32;
+            ///The interface version the delete_id message was deprecated in, if any
+            pub const DELETE_ID_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is used internally by the object ID management
+            ///logic. When a client deletes an object that it had created,
+            ///the server will send this event to acknowledge that it has
+            ///seen the delete request. When the client receives this event,
+            ///it will know that it can safely reuse the object ID.
+            pub fn delete_id<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                id: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_display",
+                    object_id = % sender_id, opcode = 1u16, message = "delete_id",
+                    direction = "out", "args.id" = % id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_display",
+                    object_id : % = sender_id, opcode = 1u16, message = "delete_id",
+                    direction = "out", "args.id" : % = id; "wl_display.delete_id"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(id).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the wl_display interface. See the module level documentation for more info"]
+        ///Trait to implement the wl_display interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_display` interface",
+            label = "`WlDisplay` is not implemented for `{Self}`"
+        )]
         pub trait WlDisplay
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_display";
             const VERSION: u32 = 1u32;
-            #[doc = "The sync request asks the server to emit the 'done' event"]
-            #[doc = "on the returned wl_callback object.  Since requests are"]
-            #[doc = "handled in-order and events are delivered in-order, this can"]
-            #[doc = "be used as a barrier to ensure all previous requests and the"]
-            #[doc = "resulting events have been handled."]
-            #[doc = ""]
-            #[doc = "The object returned by this request will be destroyed by the"]
-            #[doc = "compositor after the callback is fired and as such the client must not"]
-            #[doc = "attempt to use it after that point."]
-            #[doc = ""]
-            #[doc = "The callback_data passed in the callback is undefined and should be ignored."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the sync message was introduced in
+            const SYNC_SINCE: u32 = 1u32;
+            ///The interface version the sync message was deprecated in, if any
+            const SYNC_DEPRECATED_SINCE: Option<u32> = None;
+            ///The sync request asks the server to emit the 'done' event
+            ///on the returned wl_callback object.  Since requests are
+            ///handled in-order and events are delivered in-order, this can
+            ///be used as a barrier to ensure all previous requests and the
+            ///resulting events have been handled.
+            ///
+            ///The object returned by this request will be destroyed by the
+            ///compositor after the callback is fired and as such the client must not
+            ///attempt to use it after that point.
+            ///
+            ///The callback_data passed in the callback is undefined and should be ignored.
             fn sync(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 callback: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request creates a registry object that allows the client"]
-            #[doc = "to list and bind the global objects available from the"]
-            #[doc = "compositor."]
-            #[doc = ""]
-            #[doc = "It should be noted that the server side resources consumed in"]
-            #[doc = "response to a get_registry request can only be released when the"]
-            #[doc = "client disconnects, not when the client side proxy is destroyed."]
-            #[doc = "Therefore, clients should invoke get_registry as infrequently as"]
-            #[doc = "possible to avoid wasting memory."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_registry message was introduced in
+            const GET_REGISTRY_SINCE: u32 = 1u32;
+            ///The interface version the get_registry message was deprecated in, if any
+            const GET_REGISTRY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request creates a registry object that allows the client
+            ///to list and bind the global objects available from the
+            ///compositor.
+            ///
+            ///It should be noted that the server side resources consumed in
+            ///response to a get_registry request can only be released when the
+            ///client disconnects, not when the client side proxy is destroyed.
+            ///Therefore, clients should invoke get_registry as infrequently as
+            ///possible to avoid wasting memory.
             fn get_registry(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 registry: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The error event is sent out when a fatal (non-recoverable)"]
-            #[doc = "error has occurred.  The object_id argument is the object"]
-            #[doc = "where the error occurred, most often in response to a request"]
-            #[doc = "to that object.  The code identifies the error and is defined"]
-            #[doc = "by the object interface.  As such, each interface defines its"]
-            #[doc = "own set of error codes.  The message is a brief description"]
-            #[doc = "of the error, for (debugging) convenience."]
-            fn error(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                object_id: waynest::ObjectId,
-                code: u32,
-                message: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_display#{}.error({}, {}, \"{}\")",
-                        sender_id,
-                        object_id,
-                        code,
-                        message
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(object_id))
-                        .put_uint(code)
-                        .put_string(Some(message))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is used internally by the object ID management"]
-            #[doc = "logic. When a client deletes an object that it had created,"]
-            #[doc = "the server will send this event to acknowledge that it has"]
-            #[doc = "seen the delete request. When the client receives this event,"]
-            #[doc = "it will know that it can safely reuse the object ID."]
-            fn delete_id(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                id: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_display#{}.delete_id({})", sender_id, id);
-                    let payload = waynest::PayloadBuilder::new().put_uint(id).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -158,134 +326,225 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_display#{}.sync({})", sender_id, callback);
-                            self.sync(connection, sender_id, callback).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_display", object_id = % sender_id, opcode = 0u16,
+                                message = "sync", direction = "in", "args.callback" = %
+                                callback,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_display", object_id : % = sender_id, opcode = 0u16,
+                                message = "sync", direction = "in", "args.callback" : % =
+                                callback; "wl_display.sync"
+                            );
+                            self.sync(connection, state, sender_id, callback).await
                         }
                         1u16 => {
                             let registry = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_display#{}.get_registry({})", sender_id, registry);
-                            self.get_registry(connection, sender_id, registry).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_display", object_id = % sender_id, opcode = 1u16,
+                                message = "get_registry", direction = "in", "args.registry"
+                                = % registry,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_display", object_id : % = sender_id, opcode = 1u16,
+                                message = "get_registry", direction = "in", "args.registry"
+                                : % = registry; "wl_display.get_registry"
+                            );
+                            self.get_registry(connection, state, sender_id, registry)
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The singleton global registry object.  The server has a number of"]
-    #[doc = "global objects that are available to all clients.  These objects"]
-    #[doc = "typically represent an actual object in the server (for example,"]
-    #[doc = "an input device) or they are singleton objects that provide"]
-    #[doc = "extension functionality."]
-    #[doc = ""]
-    #[doc = "When a client creates a registry object, the registry object"]
-    #[doc = "will emit a global event for each global currently in the"]
-    #[doc = "registry.  Globals come and go as a result of device or"]
-    #[doc = "monitor hotplugs, reconfiguration or other events, and the"]
-    #[doc = "registry will send out global and global_remove events to"]
-    #[doc = "keep the client up to date with the changes.  To mark the end"]
-    #[doc = "of the initial burst of events, the client can use the"]
-    #[doc = "wl_display.sync request immediately after calling"]
-    #[doc = "wl_display.get_registry."]
-    #[doc = ""]
-    #[doc = "A client can bind to a global object by using the bind"]
-    #[doc = "request.  This creates a client-side handle that lets the object"]
-    #[doc = "emit events to the client and lets the client invoke requests on"]
-    #[doc = "the object."]
+    ///The singleton global registry object.  The server has a number of
+    ///global objects that are available to all clients.  These objects
+    ///typically represent an actual object in the server (for example,
+    ///an input device) or they are singleton objects that provide
+    ///extension functionality.
+    ///
+    ///When a client creates a registry object, the registry object
+    ///will emit a global event for each global currently in the
+    ///registry.  Globals come and go as a result of device or
+    ///monitor hotplugs, reconfiguration or other events, and the
+    ///registry will send out global and global_remove events to
+    ///keep the client up to date with the changes.  To mark the end
+    ///of the initial burst of events, the client can use the
+    ///wl_display.sync request immediately after calling
+    ///wl_display.get_registry.
+    ///
+    ///A client can bind to a global object by using the bind
+    ///request.  This creates a client-side handle that lets the object
+    ///emit events to the client and lets the client invoke requests on
+    ///the object.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_registry {
-        #[doc = "Trait to implement the wl_registry interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "bind",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "usun",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "name",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_registry interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the global message was introduced in
+            pub const GLOBAL_SINCE: u32 = 1u32;
+            ///The interface version the global message was deprecated in, if any
+            pub const GLOBAL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notify the client of global objects.
+            ///
+            ///The event notifies the client that a global object with
+            ///the given name is now available, and it implements the
+            ///given version of the given interface.
+            pub fn global<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                name: u32,
+                interface: String,
+                version: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_registry",
+                    object_id = % sender_id, opcode = 0u16, message = "global", direction
+                    = "out", "args.name" = % name, "args.interface" = % interface,
+                    "args.version" = % version,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_registry",
+                    object_id : % = sender_id, opcode = 0u16, message = "global",
+                    direction = "out", "args.name" : % = name, "args.interface" : % =
+                    interface, "args.version" : % = version; "wl_registry.global"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(name)
+                    .put_string(Some(interface))
+                    .put_uint(version)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the global_remove message was introduced in
+            pub const GLOBAL_REMOVE_SINCE: u32 = 1u32;
+            ///The interface version the global_remove message was deprecated in, if any
+            pub const GLOBAL_REMOVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notify the client of removed global objects.
+            ///
+            ///This event notifies the client that the global identified
+            ///by name is no longer available.  If the client bound to
+            ///the global using the bind request, the client should now
+            ///destroy that object.
+            ///
+            ///The object remains valid and requests to the object will be
+            ///ignored until the client destroys it, to avoid races between
+            ///the global going away and a client sending a request to it.
+            pub fn global_remove<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                name: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_registry",
+                    object_id = % sender_id, opcode = 1u16, message = "global_remove",
+                    direction = "out", "args.name" = % name,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_registry",
+                    object_id : % = sender_id, opcode = 1u16, message = "global_remove",
+                    direction = "out", "args.name" : % = name;
+                    "wl_registry.global_remove"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(name).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the wl_registry interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_registry` interface",
+            label = "`WlRegistry` is not implemented for `{Self}`"
+        )]
         pub trait WlRegistry
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_registry";
             const VERSION: u32 = 1u32;
-            #[doc = "Binds a new, client-created object to the server using the"]
-            #[doc = "specified name as the identifier."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the bind message was introduced in
+            const BIND_SINCE: u32 = 1u32;
+            ///The interface version the bind message was deprecated in, if any
+            const BIND_DEPRECATED_SINCE: Option<u32> = None;
+            ///Binds a new, client-created object to the server using the
+            ///specified name as the identifier.
             fn bind(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 name: u32,
                 id: waynest::NewId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Notify the client of global objects."]
-            #[doc = ""]
-            #[doc = "The event notifies the client that a global object with"]
-            #[doc = "the given name is now available, and it implements the"]
-            #[doc = "given version of the given interface."]
-            fn global(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                name: u32,
-                interface: String,
-                version: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_registry#{}.global({}, \"{}\", {})",
-                        sender_id,
-                        name,
-                        interface,
-                        version
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(name)
-                        .put_string(Some(interface))
-                        .put_uint(version)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "Notify the client of removed global objects."]
-            #[doc = ""]
-            #[doc = "This event notifies the client that the global identified"]
-            #[doc = "by name is no longer available.  If the client bound to"]
-            #[doc = "the global using the bind request, the client should now"]
-            #[doc = "destroy that object."]
-            #[doc = ""]
-            #[doc = "The object remains valid and requests to the object will be"]
-            #[doc = "ignored until the client destroys it, to avoid races between"]
-            #[doc = "the global going away and a client sending a request to it."]
-            fn global_remove(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                name: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_registry#{}.global_remove({})", sender_id, name);
-                    let payload = waynest::PayloadBuilder::new().put_uint(name).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -293,108 +552,232 @@ pub mod wayland {
                             let name = message.uint()?;
                             let id = message.new_id()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_registry#{}.bind({}, {})", sender_id, name, id);
-                            self.bind(connection, sender_id, name, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_registry", object_id = % sender_id, opcode = 0u16,
+                                message = "bind", direction = "in", "args.name" = % name,
+                                "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_registry", object_id : % = sender_id, opcode = 0u16,
+                                message = "bind", direction = "in", "args.name" : % = name,
+                                "args.id" : % = id; "wl_registry.bind"
+                            );
+                            self.bind(connection, state, sender_id, name, id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "Clients can handle the 'done' event to get notified when"]
-    #[doc = "the related request is done."]
-    #[doc = ""]
-    #[doc = "Note, because wl_callback objects are created from multiple independent"]
-    #[doc = "factory interfaces, the wl_callback interface is frozen at version 1."]
+    ///Clients can handle the 'done' event to get notified when
+    ///the related request is done.
+    ///
+    ///Note, because wl_callback objects are created from multiple independent
+    ///factory interfaces, the wl_callback interface is frozen at version 1.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_callback {
-        #[doc = "Trait to implement the wl_callback interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[];
+        ///Sends the events of the wl_callback interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notify the client when the related request is done.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                callback_data: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_callback",
+                    object_id = % sender_id, opcode = 0u16, message = "done", direction =
+                    "out", "args.callback_data" = % callback_data,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_callback",
+                    object_id : % = sender_id, opcode = 0u16, message = "done", direction
+                    = "out", "args.callback_data" : % = callback_data; "wl_callback.done"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(callback_data)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the wl_callback interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_callback` interface",
+            label = "`WlCallback` is not implemented for `{Self}`"
+        )]
         pub trait WlCallback
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_callback";
             const VERSION: u32 = 1u32;
-            #[doc = "Notify the client when the related request is done."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                callback_data: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_callback#{}.done({})", sender_id, callback_data);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(callback_data)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 _connection: &mut Self::Connection,
+                _state: &mut Self::State,
                 _sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "A compositor.  This object is a singleton global.  The"]
-    #[doc = "compositor is in charge of combining the contents of multiple"]
-    #[doc = "surfaces into one displayable output."]
+    ///A compositor.  This object is a singleton global.  The
+    ///compositor is in charge of combining the contents of multiple
+    ///surfaces into one displayable output.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_compositor {
-        #[doc = "Trait to implement the wl_compositor interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 7u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "7",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_compositor interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_compositor interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_compositor` interface",
+            label = "`WlCompositor` is not implemented for `{Self}`"
+        )]
         pub trait WlCompositor
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_compositor";
             const VERSION: u32 = 7u32;
-            #[doc = "Ask the compositor to create a new surface."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_surface message was introduced in
+            const CREATE_SURFACE_SINCE: u32 = 1u32;
+            ///The interface version the create_surface message was deprecated in, if any
+            const CREATE_SURFACE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Ask the compositor to create a new surface.
             fn create_surface(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Ask the compositor to create a new region."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the create_region message was introduced in
+            const CREATE_REGION_SINCE: u32 = 1u32;
+            ///The interface version the create_region message was deprecated in, if any
+            const CREATE_REGION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Ask the compositor to create a new region.
             fn create_region(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request destroys the wl_compositor. This has no effect on any other objects."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 7u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request destroys the wl_compositor. This has no effect on any other objects.
+            ///
+            ///Available since version 7
             fn release(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -403,100 +786,251 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_compositor#{}.create_surface({})", sender_id, id);
-                            self.create_surface(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_compositor", object_id = % sender_id, opcode = 0u16,
+                                message = "create_surface", direction = "in", "args.id" = %
+                                id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_compositor", object_id : % = sender_id, opcode = 0u16,
+                                message = "create_surface", direction = "in", "args.id" : %
+                                = id; "wl_compositor.create_surface"
+                            );
+                            self.create_surface(connection, state, sender_id, id).await
                         }
                         1u16 => {
                             let id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_compositor#{}.create_region({})", sender_id, id);
-                            self.create_region(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_compositor", object_id = % sender_id, opcode = 1u16,
+                                message = "create_region", direction = "in", "args.id" = %
+                                id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_compositor", object_id : % = sender_id, opcode = 1u16,
+                                message = "create_region", direction = "in", "args.id" : % =
+                                id; "wl_compositor.create_region"
+                            );
+                            self.create_region(connection, state, sender_id, id).await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_compositor#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_compositor", object_id = % sender_id, opcode = 2u16,
+                                message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_compositor", object_id : % = sender_id, opcode = 2u16,
+                                message = "release", direction = "in";
+                                "wl_compositor.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The wl_shm_pool object encapsulates a piece of memory shared"]
-    #[doc = "between the compositor and client.  Through the wl_shm_pool"]
-    #[doc = "object, the client can allocate shared memory wl_buffer objects."]
-    #[doc = "All objects created through the same pool share the same"]
-    #[doc = "underlying mapped memory. Reusing the mapped memory avoids the"]
-    #[doc = "setup/teardown overhead and is useful when interactively resizing"]
-    #[doc = "a surface or for many small buffers."]
+    ///The wl_shm_pool object encapsulates a piece of memory shared
+    ///between the compositor and client.  Through the wl_shm_pool
+    ///object, the client can allocate shared memory wl_buffer objects.
+    ///All objects created through the same pool share the same
+    ///underlying mapped memory. Reusing the mapped memory avoids the
+    ///setup/teardown overhead and is useful when interactively resizing
+    ///a surface or for many small buffers.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_shm_pool {
-        #[doc = "Trait to implement the wl_shm_pool interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_buffer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "niiiiu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_shm.format"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_shm_pool interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_shm_pool interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_shm_pool` interface",
+            label = "`WlShmPool` is not implemented for `{Self}`"
+        )]
         pub trait WlShmPool
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_shm_pool";
             const VERSION: u32 = 2u32;
-            #[doc = "Create a wl_buffer object from the pool."]
-            #[doc = ""]
-            #[doc = "The buffer is created offset bytes into the pool and has"]
-            #[doc = "width and height as specified.  The stride argument specifies"]
-            #[doc = "the number of bytes from the beginning of one row to the beginning"]
-            #[doc = "of the next.  The format is the pixel format of the buffer and"]
-            #[doc = "must be one of those advertised through the wl_shm.format event."]
-            #[doc = ""]
-            #[doc = "A buffer will keep a reference to the pool it was created from"]
-            #[doc = "so it is valid to destroy the pool immediately after creating"]
-            #[doc = "a buffer from it."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_buffer message was introduced in
+            const CREATE_BUFFER_SINCE: u32 = 1u32;
+            ///The interface version the create_buffer message was deprecated in, if any
+            const CREATE_BUFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a wl_buffer object from the pool.
+            ///
+            ///The buffer is created offset bytes into the pool and has
+            ///width and height as specified.  The stride argument specifies
+            ///the number of bytes from the beginning of one row to the beginning
+            ///of the next.  The format is the pixel format of the buffer and
+            ///must be one of those advertised through the wl_shm.format event.
+            ///
+            ///A buffer will keep a reference to the pool it was created from
+            ///so it is valid to destroy the pool immediately after creating
+            ///a buffer from it.
             fn create_buffer(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 offset: i32,
                 width: i32,
                 height: i32,
                 stride: i32,
-                format: super::super::super::core::wayland::wl_shm::Format,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroy the shared memory pool."]
-            #[doc = ""]
-            #[doc = "The mmapped memory will be released when all"]
-            #[doc = "buffers that have been created from this pool"]
-            #[doc = "are gone."]
+                format: super::wl_shm::Format,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the shared memory pool.
+            ///
+            ///The mmapped memory will be released when all
+            ///buffers that have been created from this pool
+            ///are gone.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request will cause the server to remap the backing memory"]
-            #[doc = "for the pool from the file descriptor passed when the pool was"]
-            #[doc = "created, but using the new size.  This request can only be"]
-            #[doc = "used to make the pool bigger."]
-            #[doc = ""]
-            #[doc = "This request only changes the amount of bytes that are mmapped"]
-            #[doc = "by the server and does not touch the file corresponding to the"]
-            #[doc = "file descriptor passed at creation time. It is the client's"]
-            #[doc = "responsibility to ensure that the file is at least as big as"]
-            #[doc = "the new pool size."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the resize message was introduced in
+            const RESIZE_SINCE: u32 = 1u32;
+            ///The interface version the resize message was deprecated in, if any
+            const RESIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request will cause the server to remap the backing memory
+            ///for the pool from the file descriptor passed when the pool was
+            ///created, but using the new size.  This request can only be
+            ///used to make the pool bigger.
+            ///
+            ///This request only changes the amount of bytes that are mmapped
+            ///by the server and does not touch the file corresponding to the
+            ///file descriptor passed at creation time. It is the client's
+            ///responsibility to ensure that the file is at least as big as
+            ///the new pool size.
             fn resize(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 size: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -508,75 +1042,158 @@ pub mod wayland {
                             let width = message.int()?;
                             let height = message.int()?;
                             let stride = message.int()?;
-                            let format = message.uint()?;
+                            let format: super::wl_shm::Format = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shm_pool#{}.create_buffer({}, {}, {}, {}, {}, {})",
-                                sender_id,
-                                id,
-                                offset,
-                                width,
-                                height,
-                                stride,
-                                format
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shm_pool", object_id = % sender_id, opcode = 0u16,
+                                message = "create_buffer", direction = "in", "args.id" = %
+                                id, "args.offset" = % offset, "args.width" = % width,
+                                "args.height" = % height, "args.stride" = % stride,
+                                "args.format" = % format,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shm_pool", object_id : % = sender_id, opcode = 0u16,
+                                message = "create_buffer", direction = "in", "args.id" : % =
+                                id, "args.offset" : % = offset, "args.width" : % = width,
+                                "args.height" : % = height, "args.stride" : % = stride,
+                                "args.format" : % = format; "wl_shm_pool.create_buffer"
                             );
                             self.create_buffer(
-                                connection,
-                                sender_id,
-                                id,
-                                offset,
-                                width,
-                                height,
-                                stride,
-                                format.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    offset,
+                                    width,
+                                    height,
+                                    stride,
+                                    format,
+                                )
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_shm_pool#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shm_pool", object_id = % sender_id, opcode = 1u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shm_pool", object_id : % = sender_id, opcode = 1u16,
+                                message = "destroy", direction = "in"; "wl_shm_pool.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         2u16 => {
                             let size = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_shm_pool#{}.resize({})", sender_id, size);
-                            self.resize(connection, sender_id, size).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shm_pool", object_id = % sender_id, opcode = 2u16,
+                                message = "resize", direction = "in", "args.size" = % size,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shm_pool", object_id : % = sender_id, opcode = 2u16,
+                                message = "resize", direction = "in", "args.size" : % =
+                                size; "wl_shm_pool.resize"
+                            );
+                            self.resize(connection, state, sender_id, size).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A singleton global object that provides support for shared"]
-    #[doc = "memory."]
-    #[doc = ""]
-    #[doc = "Clients can create wl_shm_pool objects using the create_pool"]
-    #[doc = "request."]
-    #[doc = ""]
-    #[doc = "On binding the wl_shm object one or more format events"]
-    #[doc = "are emitted to inform clients about the valid pixel formats"]
-    #[doc = "that can be used for buffers."]
+    ///A singleton global object that provides support for shared
+    ///memory.
+    ///
+    ///Clients can create wl_shm_pool objects using the create_pool
+    ///request.
+    ///
+    ///On binding the wl_shm object one or more format events
+    ///are emitted to inform clients about the valid pixel formats
+    ///that can be used for buffers.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_shm {
-        #[doc = "These errors can be emitted in response to wl_shm requests."]
+        ///These errors can be emitted in response to wl_shm requests.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "buffer format is not known"]
+            ///buffer format is not known
             InvalidFormat = 0u32,
-            #[doc = "invalid size or stride during pool or buffer creation"]
+            ///invalid size or stride during pool or buffer creation
             InvalidStride = 1u32,
-            #[doc = "mmapping the file descriptor failed"]
+            ///mmapping the file descriptor failed
             InvalidFd = 2u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidFormat => 1u32,
+                    Self::InvalidStride => 1u32,
+                    Self::InvalidFd => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidFormat => None,
+                    Self::InvalidStride => None,
+                    Self::InvalidFd => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_format", Self::InvalidFormat),
+                ("invalid_stride", Self::InvalidStride),
+                ("invalid_fd", Self::InvalidFd),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidFormat => "invalid_format",
+                    Self::InvalidStride => "invalid_stride",
+                    Self::InvalidFd => "invalid_fd",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -588,195 +1205,201 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
-            }
-        }
-        #[doc = "This describes the memory layout of an individual pixel."]
-        #[doc = ""]
-        #[doc = "All renderers should support argb8888 and xrgb8888 but any other"]
-        #[doc = "formats are optional and may not be supported by the particular"]
-        #[doc = "renderer in use."]
-        #[doc = ""]
-        #[doc = "The drm format codes match the macros defined in drm_fourcc.h, except"]
-        #[doc = "argb8888 and xrgb8888. The formats actually supported by the compositor"]
-        #[doc = "will be reported by the format event. See drm_fourcc.h for more detailed"]
-        #[doc = "format descriptions."]
-        #[doc = ""]
-        #[doc = "For all wl_shm formats and unless specified in another protocol"]
-        #[doc = "extension, pre-multiplied alpha is used for pixel values."]
+                f.write_str(self.as_str())
+            }
+        }
+        ///This describes the memory layout of an individual pixel.
+        ///
+        ///All renderers should support argb8888 and xrgb8888 but any other
+        ///formats are optional and may not be supported by the particular
+        ///renderer in use.
+        ///
+        ///The drm format codes match the macros defined in drm_fourcc.h, except
+        ///argb8888 and xrgb8888. The formats actually supported by the compositor
+        ///will be reported by the format event. See drm_fourcc.h for more detailed
+        ///format descriptions.
+        ///
+        ///For all wl_shm formats and unless specified in another protocol
+        ///extension, pre-multiplied alpha is used for pixel values.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Format {
-            #[doc = "32-bit ARGB format, [31:0] A:R:G:B 8:8:8:8 little endian"]
+            ///32-bit ARGB format, [31:0] A:R:G:B 8:8:8:8 little endian
             Argb8888 = 0u32,
-            #[doc = "32-bit RGB format, [31:0] x:R:G:B 8:8:8:8 little endian"]
+            ///32-bit RGB format, [31:0] x:R:G:B 8:8:8:8 little endian
             Xrgb8888 = 1u32,
-            #[doc = "8-bit color index format, [7:0] C"]
+            ///8-bit color index format, [7:0] C
             C8 = 538982467u32,
-            #[doc = "8-bit RGB format, [7:0] R:G:B 3:3:2"]
+            ///8-bit RGB format, [7:0] R:G:B 3:3:2
             Rgb332 = 943867730u32,
-            #[doc = "8-bit BGR format, [7:0] B:G:R 2:3:3"]
+            ///8-bit BGR format, [7:0] B:G:R 2:3:3
             Bgr233 = 944916290u32,
-            #[doc = "16-bit xRGB format, [15:0] x:R:G:B 4:4:4:4 little endian"]
+            ///16-bit xRGB format, [15:0] x:R:G:B 4:4:4:4 little endian
             Xrgb4444 = 842093144u32,
-            #[doc = "16-bit xBGR format, [15:0] x:B:G:R 4:4:4:4 little endian"]
+            ///16-bit xBGR format, [15:0] x:B:G:R 4:4:4:4 little endian
             Xbgr4444 = 842089048u32,
-            #[doc = "16-bit RGBx format, [15:0] R:G:B:x 4:4:4:4 little endian"]
+            ///16-bit RGBx format, [15:0] R:G:B:x 4:4:4:4 little endian
             Rgbx4444 = 842094674u32,
-            #[doc = "16-bit BGRx format, [15:0] B:G:R:x 4:4:4:4 little endian"]
+            ///16-bit BGRx format, [15:0] B:G:R:x 4:4:4:4 little endian
             Bgrx4444 = 842094658u32,
-            #[doc = "16-bit ARGB format, [15:0] A:R:G:B 4:4:4:4 little endian"]
+            ///16-bit ARGB format, [15:0] A:R:G:B 4:4:4:4 little endian
             Argb4444 = 842093121u32,
-            #[doc = "16-bit ABGR format, [15:0] A:B:G:R 4:4:4:4 little endian"]
+            ///16-bit ABGR format, [15:0] A:B:G:R 4:4:4:4 little endian
             Abgr4444 = 842089025u32,
-            #[doc = "16-bit RBGA format, [15:0] R:G:B:A 4:4:4:4 little endian"]
+            ///16-bit RBGA format, [15:0] R:G:B:A 4:4:4:4 little endian
             Rgba4444 = 842088786u32,
-            #[doc = "16-bit BGRA format, [15:0] B:G:R:A 4:4:4:4 little endian"]
+            ///16-bit BGRA format, [15:0] B:G:R:A 4:4:4:4 little endian
             Bgra4444 = 842088770u32,
-            #[doc = "16-bit xRGB format, [15:0] x:R:G:B 1:5:5:5 little endian"]
+            ///16-bit xRGB format, [15:0] x:R:G:B 1:5:5:5 little endian
             Xrgb1555 = 892424792u32,
-            #[doc = "16-bit xBGR 1555 format, [15:0] x:B:G:R 1:5:5:5 little endian"]
+            ///16-bit xBGR 1555 format, [15:0] x:B:G:R 1:5:5:5 little endian
             Xbgr1555 = 892420696u32,
-            #[doc = "16-bit RGBx 5551 format, [15:0] R:G:B:x 5:5:5:1 little endian"]
+            ///16-bit RGBx 5551 format, [15:0] R:G:B:x 5:5:5:1 little endian
             Rgbx5551 = 892426322u32,
-            #[doc = "16-bit BGRx 5551 format, [15:0] B:G:R:x 5:5:5:1 little endian"]
+            ///16-bit BGRx 5551 format, [15:0] B:G:R:x 5:5:5:1 little endian
             Bgrx5551 = 892426306u32,
-            #[doc = "16-bit ARGB 1555 format, [15:0] A:R:G:B 1:5:5:5 little endian"]
+            ///16-bit ARGB 1555 format, [15:0] A:R:G:B 1:5:5:5 little endian
             Argb1555 = 892424769u32,
-            #[doc = "16-bit ABGR 1555 format, [15:0] A:B:G:R 1:5:5:5 little endian"]
+            ///16-bit ABGR 1555 format, [15:0] A:B:G:R 1:5:5:5 little endian
             Abgr1555 = 892420673u32,
-            #[doc = "16-bit RGBA 5551 format, [15:0] R:G:B:A 5:5:5:1 little endian"]
+            ///16-bit RGBA 5551 format, [15:0] R:G:B:A 5:5:5:1 little endian
             Rgba5551 = 892420434u32,
-            #[doc = "16-bit BGRA 5551 format, [15:0] B:G:R:A 5:5:5:1 little endian"]
+            ///16-bit BGRA 5551 format, [15:0] B:G:R:A 5:5:5:1 little endian
             Bgra5551 = 892420418u32,
-            #[doc = "16-bit RGB 565 format, [15:0] R:G:B 5:6:5 little endian"]
+            ///16-bit RGB 565 format, [15:0] R:G:B 5:6:5 little endian
             Rgb565 = 909199186u32,
-            #[doc = "16-bit BGR 565 format, [15:0] B:G:R 5:6:5 little endian"]
+            ///16-bit BGR 565 format, [15:0] B:G:R 5:6:5 little endian
             Bgr565 = 909199170u32,
-            #[doc = "24-bit RGB format, [23:0] R:G:B little endian"]
+            ///24-bit RGB format, [23:0] R:G:B little endian
             Rgb888 = 875710290u32,
-            #[doc = "24-bit BGR format, [23:0] B:G:R little endian"]
+            ///24-bit BGR format, [23:0] B:G:R little endian
             Bgr888 = 875710274u32,
-            #[doc = "32-bit xBGR format, [31:0] x:B:G:R 8:8:8:8 little endian"]
+            ///32-bit xBGR format, [31:0] x:B:G:R 8:8:8:8 little endian
             Xbgr8888 = 875709016u32,
-            #[doc = "32-bit RGBx format, [31:0] R:G:B:x 8:8:8:8 little endian"]
+            ///32-bit RGBx format, [31:0] R:G:B:x 8:8:8:8 little endian
             Rgbx8888 = 875714642u32,
-            #[doc = "32-bit BGRx format, [31:0] B:G:R:x 8:8:8:8 little endian"]
+            ///32-bit BGRx format, [31:0] B:G:R:x 8:8:8:8 little endian
             Bgrx8888 = 875714626u32,
-            #[doc = "32-bit ABGR format, [31:0] A:B:G:R 8:8:8:8 little endian"]
+            ///32-bit ABGR format, [31:0] A:B:G:R 8:8:8:8 little endian
             Abgr8888 = 875708993u32,
-            #[doc = "32-bit RGBA format, [31:0] R:G:B:A 8:8:8:8 little endian"]
+            ///32-bit RGBA format, [31:0] R:G:B:A 8:8:8:8 little endian
             Rgba8888 = 875708754u32,
-            #[doc = "32-bit BGRA format, [31:0] B:G:R:A 8:8:8:8 little endian"]
+            ///32-bit BGRA format, [31:0] B:G:R:A 8:8:8:8 little endian
             Bgra8888 = 875708738u32,
-            #[doc = "32-bit xRGB format, [31:0] x:R:G:B 2:10:10:10 little endian"]
+            ///32-bit xRGB format, [31:0] x:R:G:B 2:10:10:10 little endian
             Xrgb2101010 = 808669784u32,
-            #[doc = "32-bit xBGR format, [31:0] x:B:G:R 2:10:10:10 little endian"]
+            ///32-bit xBGR format, [31:0] x:B:G:R 2:10:10:10 little endian
             Xbgr2101010 = 808665688u32,
-            #[doc = "32-bit RGBx format, [31:0] R:G:B:x 10:10:10:2 little endian"]
+            ///32-bit RGBx format, [31:0] R:G:B:x 10:10:10:2 little endian
             Rgbx1010102 = 808671314u32,
-            #[doc = "32-bit BGRx format, [31:0] B:G:R:x 10:10:10:2 little endian"]
+            ///32-bit BGRx format, [31:0] B:G:R:x 10:10:10:2 little endian
             Bgrx1010102 = 808671298u32,
-            #[doc = "32-bit ARGB format, [31:0] A:R:G:B 2:10:10:10 little endian"]
+            ///32-bit ARGB format, [31:0] A:R:G:B 2:10:10:10 little endian
             Argb2101010 = 808669761u32,
-            #[doc = "32-bit ABGR format, [31:0] A:B:G:R 2:10:10:10 little endian"]
+            ///32-bit ABGR format, [31:0] A:B:G:R 2:10:10:10 little endian
             Abgr2101010 = 808665665u32,
-            #[doc = "32-bit RGBA format, [31:0] R:G:B:A 10:10:10:2 little endian"]
+            ///32-bit RGBA format, [31:0] R:G:B:A 10:10:10:2 little endian
             Rgba1010102 = 808665426u32,
-            #[doc = "32-bit BGRA format, [31:0] B:G:R:A 10:10:10:2 little endian"]
+            ///32-bit BGRA format, [31:0] B:G:R:A 10:10:10:2 little endian
             Bgra1010102 = 808665410u32,
-            #[doc = "packed YCbCr format, [31:0] Cr0:Y1:Cb0:Y0 8:8:8:8 little endian"]
+            ///packed YCbCr format, [31:0] Cr0:Y1:Cb0:Y0 8:8:8:8 little endian
             Yuyv = 1448695129u32,
-            #[doc = "packed YCbCr format, [31:0] Cb0:Y1:Cr0:Y0 8:8:8:8 little endian"]
+            ///packed YCbCr format, [31:0] Cb0:Y1:Cr0:Y0 8:8:8:8 little endian
             Yvyu = 1431918169u32,
-            #[doc = "packed YCbCr format, [31:0] Y1:Cr0:Y0:Cb0 8:8:8:8 little endian"]
+            ///packed YCbCr format, [31:0] Y1:Cr0:Y0:Cb0 8:8:8:8 little endian
             Uyvy = 1498831189u32,
-            #[doc = "packed YCbCr format, [31:0] Y1:Cb0:Y0:Cr0 8:8:8:8 little endian"]
+            ///packed YCbCr format, [31:0] Y1:Cb0:Y0:Cr0 8:8:8:8 little endian
             Vyuy = 1498765654u32,
-            #[doc = "packed AYCbCr format, [31:0] A:Y:Cb:Cr 8:8:8:8 little endian"]
+            ///packed AYCbCr format, [31:0] A:Y:Cb:Cr 8:8:8:8 little endian
             Ayuv = 1448433985u32,
-            #[doc = "2 plane YCbCr Cr:Cb format, 2x2 subsampled Cr:Cb plane"]
+            ///2 plane YCbCr Cr:Cb format, 2x2 subsampled Cr:Cb plane
             Nv12 = 842094158u32,
-            #[doc = "2 plane YCbCr Cb:Cr format, 2x2 subsampled Cb:Cr plane"]
+            ///2 plane YCbCr Cb:Cr format, 2x2 subsampled Cb:Cr plane
             Nv21 = 825382478u32,
-            #[doc = "2 plane YCbCr Cr:Cb format, 2x1 subsampled Cr:Cb plane"]
+            ///2 plane YCbCr Cr:Cb format, 2x1 subsampled Cr:Cb plane
             Nv16 = 909203022u32,
-            #[doc = "2 plane YCbCr Cb:Cr format, 2x1 subsampled Cb:Cr plane"]
+            ///2 plane YCbCr Cb:Cr format, 2x1 subsampled Cb:Cr plane
             Nv61 = 825644622u32,
-            #[doc = "3 plane YCbCr format, 4x4 subsampled Cb (1) and Cr (2) planes"]
+            ///3 plane YCbCr format, 4x4 subsampled Cb (1) and Cr (2) planes
             Yuv410 = 961959257u32,
-            #[doc = "3 plane YCbCr format, 4x4 subsampled Cr (1) and Cb (2) planes"]
+            ///3 plane YCbCr format, 4x4 subsampled Cr (1) and Cb (2) planes
             Yvu410 = 961893977u32,
-            #[doc = "3 plane YCbCr format, 4x1 subsampled Cb (1) and Cr (2) planes"]
+            ///3 plane YCbCr format, 4x1 subsampled Cb (1) and Cr (2) planes
             Yuv411 = 825316697u32,
-            #[doc = "3 plane YCbCr format, 4x1 subsampled Cr (1) and Cb (2) planes"]
+            ///3 plane YCbCr format, 4x1 subsampled Cr (1) and Cb (2) planes
             Yvu411 = 825316953u32,
-            #[doc = "3 plane YCbCr format, 2x2 subsampled Cb (1) and Cr (2) planes"]
+            ///3 plane YCbCr format, 2x2 subsampled Cb (1) and Cr (2) planes
             Yuv420 = 842093913u32,
-            #[doc = "3 plane YCbCr format, 2x2 subsampled Cr (1) and Cb (2) planes"]
+            ///3 plane YCbCr format, 2x2 subsampled Cr (1) and Cb (2) planes
             Yvu420 = 842094169u32,
-            #[doc = "3 plane YCbCr format, 2x1 subsampled Cb (1) and Cr (2) planes"]
+            ///3 plane YCbCr format, 2x1 subsampled Cb (1) and Cr (2) planes
             Yuv422 = 909202777u32,
-            #[doc = "3 plane YCbCr format, 2x1 subsampled Cr (1) and Cb (2) planes"]
+            ///3 plane YCbCr format, 2x1 subsampled Cr (1) and Cb (2) planes
             Yvu422 = 909203033u32,
-            #[doc = "3 plane YCbCr format, non-subsampled Cb (1) and Cr (2) planes"]
+            ///3 plane YCbCr format, non-subsampled Cb (1) and Cr (2) planes
             Yuv444 = 875713881u32,
-            #[doc = "3 plane YCbCr format, non-subsampled Cr (1) and Cb (2) planes"]
+            ///3 plane YCbCr format, non-subsampled Cr (1) and Cb (2) planes
             Yvu444 = 875714137u32,
-            #[doc = "[7:0] R"]
+            ///[7:0] R
             R8 = 538982482u32,
-            #[doc = "[15:0] R little endian"]
+            ///[15:0] R little endian
             R16 = 540422482u32,
-            #[doc = "[15:0] R:G 8:8 little endian"]
+            ///[15:0] R:G 8:8 little endian
             Rg88 = 943212370u32,
-            #[doc = "[15:0] G:R 8:8 little endian"]
+            ///[15:0] G:R 8:8 little endian
             Gr88 = 943215175u32,
-            #[doc = "[31:0] R:G 16:16 little endian"]
+            ///[31:0] R:G 16:16 little endian
             Rg1616 = 842221394u32,
-            #[doc = "[31:0] G:R 16:16 little endian"]
+            ///[31:0] G:R 16:16 little endian
             Gr1616 = 842224199u32,
-            #[doc = "[63:0] x:R:G:B 16:16:16:16 little endian"]
+            ///[63:0] x:R:G:B 16:16:16:16 little endian
             Xrgb16161616f = 1211388504u32,
-            #[doc = "[63:0] x:B:G:R 16:16:16:16 little endian"]
+            ///[63:0] x:B:G:R 16:16:16:16 little endian
             Xbgr16161616f = 1211384408u32,
-            #[doc = "[63:0] A:R:G:B 16:16:16:16 little endian"]
+            ///[63:0] A:R:G:B 16:16:16:16 little endian
             Argb16161616f = 1211388481u32,
-            #[doc = "[63:0] A:B:G:R 16:16:16:16 little endian"]
+            ///[63:0] A:B:G:R 16:16:16:16 little endian
             Abgr16161616f = 1211384385u32,
-            #[doc = "[31:0] X:Y:Cb:Cr 8:8:8:8 little endian"]
+            ///[31:0] X:Y:Cb:Cr 8:8:8:8 little endian
             Xyuv8888 = 1448434008u32,
-            #[doc = "[23:0] Cr:Cb:Y 8:8:8 little endian"]
+            ///[23:0] Cr:Cb:Y 8:8:8 little endian
             Vuy888 = 875713878u32,
-            #[doc = "Y followed by U then V, 10:10:10. Non-linear modifier only"]
+            ///Y followed by U then V, 10:10:10. Non-linear modifier only
             Vuy101010 = 808670550u32,
-            #[doc = "[63:0] Cr0:0:Y1:0:Cb0:0:Y0:0 10:6:10:6:10:6:10:6 little endian per 2 Y pixels"]
+            ///[63:0] Cr0:0:Y1:0:Cb0:0:Y0:0 10:6:10:6:10:6:10:6 little endian per 2 Y pixels
             Y210 = 808530521u32,
-            #[doc = "[63:0] Cr0:0:Y1:0:Cb0:0:Y0:0 12:4:12:4:12:4:12:4 little endian per 2 Y pixels"]
+            ///[63:0] Cr0:0:Y1:0:Cb0:0:Y0:0 12:4:12:4:12:4:12:4 little endian per 2 Y pixels
             Y212 = 842084953u32,
-            #[doc = "[63:0] Cr0:Y1:Cb0:Y0 16:16:16:16 little endian per 2 Y pixels"]
+            ///[63:0] Cr0:Y1:Cb0:Y0 16:16:16:16 little endian per 2 Y pixels
             Y216 = 909193817u32,
-            #[doc = "[31:0] A:Cr:Y:Cb 2:10:10:10 little endian"]
+            ///[31:0] A:Cr:Y:Cb 2:10:10:10 little endian
             Y410 = 808531033u32,
-            #[doc = "[63:0] A:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian"]
+            ///[63:0] A:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian
             Y412 = 842085465u32,
-            #[doc = "[63:0] A:Cr:Y:Cb 16:16:16:16 little endian"]
+            ///[63:0] A:Cr:Y:Cb 16:16:16:16 little endian
             Y416 = 909194329u32,
-            #[doc = "[31:0] X:Cr:Y:Cb 2:10:10:10 little endian"]
+            ///[31:0] X:Cr:Y:Cb 2:10:10:10 little endian
             Xvyu2101010 = 808670808u32,
-            #[doc = "[63:0] X:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian"]
+            ///[63:0] X:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian
             Xvyu1216161616 = 909334104u32,
-            #[doc = "[63:0] X:Cr:Y:Cb 16:16:16:16 little endian"]
+            ///[63:0] X:Cr:Y:Cb 16:16:16:16 little endian
             Xvyu16161616 = 942954072u32,
-            #[doc = "[63:0]   A3:A2:Y3:0:Cr0:0:Y2:0:A1:A0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian"]
+            ///[63:0]   A3:A2:Y3:0:Cr0:0:Y2:0:A1:A0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian
             Y0l0 = 810299481u32,
-            #[doc = "[63:0]   X3:X2:Y3:0:Cr0:0:Y2:0:X1:X0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian"]
+            ///[63:0]   X3:X2:Y3:0:Cr0:0:Y2:0:X1:X0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian
             X0l0 = 810299480u32,
-            #[doc = "[63:0]   A3:A2:Y3:Cr0:Y2:A1:A0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian"]
+            ///[63:0]   A3:A2:Y3:Cr0:Y2:A1:A0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian
             Y0l2 = 843853913u32,
-            #[doc = "[63:0]   X3:X2:Y3:Cr0:Y2:X1:X0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian"]
+            ///[63:0]   X3:X2:Y3:Cr0:Y2:X1:X0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian
             X0l2 = 843853912u32,
             Yuv4208bit = 942691673u32,
             Yuv42010bit = 808539481u32,
@@ -788,108 +1411,718 @@ pub mod wayland {
             Bgr888A8 = 943798338u32,
             Rgb565A8 = 943797586u32,
             Bgr565A8 = 943797570u32,
-            #[doc = "non-subsampled Cr:Cb plane"]
+            ///non-subsampled Cr:Cb plane
             Nv24 = 875714126u32,
-            #[doc = "non-subsampled Cb:Cr plane"]
+            ///non-subsampled Cb:Cr plane
             Nv42 = 842290766u32,
-            #[doc = "2x1 subsampled Cr:Cb plane, 10 bit per channel"]
+            ///2x1 subsampled Cr:Cb plane, 10 bit per channel
             P210 = 808530512u32,
-            #[doc = "2x2 subsampled Cr:Cb plane 10 bits per channel"]
+            ///2x2 subsampled Cr:Cb plane 10 bits per channel
             P010 = 808530000u32,
-            #[doc = "2x2 subsampled Cr:Cb plane 12 bits per channel"]
+            ///2x2 subsampled Cr:Cb plane 12 bits per channel
             P012 = 842084432u32,
-            #[doc = "2x2 subsampled Cr:Cb plane 16 bits per channel"]
+            ///2x2 subsampled Cr:Cb plane 16 bits per channel
             P016 = 909193296u32,
-            #[doc = "[63:0] A:x:B:x:G:x:R:x 10:6:10:6:10:6:10:6 little endian"]
+            ///[63:0] A:x:B:x:G:x:R:x 10:6:10:6:10:6:10:6 little endian
             Axbxgxrx106106106106 = 808534593u32,
-            #[doc = "2x2 subsampled Cr:Cb plane"]
+            ///2x2 subsampled Cr:Cb plane
             Nv15 = 892425806u32,
             Q410 = 808531025u32,
             Q401 = 825242705u32,
-            #[doc = "[63:0] x:R:G:B 16:16:16:16 little endian"]
+            ///[63:0] x:R:G:B 16:16:16:16 little endian
             Xrgb16161616 = 942953048u32,
-            #[doc = "[63:0] x:B:G:R 16:16:16:16 little endian"]
+            ///[63:0] x:B:G:R 16:16:16:16 little endian
             Xbgr16161616 = 942948952u32,
-            #[doc = "[63:0] A:R:G:B 16:16:16:16 little endian"]
+            ///[63:0] A:R:G:B 16:16:16:16 little endian
             Argb16161616 = 942953025u32,
-            #[doc = "[63:0] A:B:G:R 16:16:16:16 little endian"]
+            ///[63:0] A:B:G:R 16:16:16:16 little endian
             Abgr16161616 = 942948929u32,
-            #[doc = "[7:0] C0:C1:C2:C3:C4:C5:C6:C7 1:1:1:1:1:1:1:1 eight pixels/byte"]
+            ///[7:0] C0:C1:C2:C3:C4:C5:C6:C7 1:1:1:1:1:1:1:1 eight pixels/byte
             C1 = 538980675u32,
-            #[doc = "[7:0] C0:C1:C2:C3 2:2:2:2 four pixels/byte"]
+            ///[7:0] C0:C1:C2:C3 2:2:2:2 four pixels/byte
             C2 = 538980931u32,
-            #[doc = "[7:0] C0:C1 4:4 two pixels/byte"]
+            ///[7:0] C0:C1 4:4 two pixels/byte
             C4 = 538981443u32,
-            #[doc = "[7:0] D0:D1:D2:D3:D4:D5:D6:D7 1:1:1:1:1:1:1:1 eight pixels/byte"]
+            ///[7:0] D0:D1:D2:D3:D4:D5:D6:D7 1:1:1:1:1:1:1:1 eight pixels/byte
             D1 = 538980676u32,
-            #[doc = "[7:0] D0:D1:D2:D3 2:2:2:2 four pixels/byte"]
+            ///[7:0] D0:D1:D2:D3 2:2:2:2 four pixels/byte
             D2 = 538980932u32,
-            #[doc = "[7:0] D0:D1 4:4 two pixels/byte"]
+            ///[7:0] D0:D1 4:4 two pixels/byte
             D4 = 538981444u32,
-            #[doc = "[7:0] D"]
+            ///[7:0] D
             D8 = 538982468u32,
-            #[doc = "[7:0] R0:R1:R2:R3:R4:R5:R6:R7 1:1:1:1:1:1:1:1 eight pixels/byte"]
+            ///[7:0] R0:R1:R2:R3:R4:R5:R6:R7 1:1:1:1:1:1:1:1 eight pixels/byte
             R1 = 538980690u32,
-            #[doc = "[7:0] R0:R1:R2:R3 2:2:2:2 four pixels/byte"]
+            ///[7:0] R0:R1:R2:R3 2:2:2:2 four pixels/byte
             R2 = 538980946u32,
-            #[doc = "[7:0] R0:R1 4:4 two pixels/byte"]
+            ///[7:0] R0:R1 4:4 two pixels/byte
             R4 = 538981458u32,
-            #[doc = "[15:0] x:R 6:10 little endian"]
+            ///[15:0] x:R 6:10 little endian
             R10 = 540029266u32,
-            #[doc = "[15:0] x:R 4:12 little endian"]
+            ///[15:0] x:R 4:12 little endian
             R12 = 540160338u32,
-            #[doc = "[31:0] A:Cr:Cb:Y 8:8:8:8 little endian"]
+            ///[31:0] A:Cr:Cb:Y 8:8:8:8 little endian
             Avuy8888 = 1498764865u32,
-            #[doc = "[31:0] X:Cr:Cb:Y 8:8:8:8 little endian"]
+            ///[31:0] X:Cr:Cb:Y 8:8:8:8 little endian
             Xvuy8888 = 1498764888u32,
-            #[doc = "2x2 subsampled Cr:Cb plane 10 bits per channel packed"]
+            ///2x2 subsampled Cr:Cb plane 10 bits per channel packed
             P030 = 808661072u32,
-            #[doc = "[47:0] R:G:B 16:16:16 little endian"]
+            ///[47:0] R:G:B 16:16:16 little endian
             Rgb161616 = 942950226u32,
-            #[doc = "[47:0] B:G:R 16:16:16 little endian"]
+            ///[47:0] B:G:R 16:16:16 little endian
             Bgr161616 = 942950210u32,
-            #[doc = "[15:0] R 16 little endian"]
+            ///[15:0] R 16 little endian
             R16f = 1210064978u32,
-            #[doc = "[31:0] G:R 16:16 little endian"]
+            ///[31:0] G:R 16:16 little endian
             Gr1616f = 1210077767u32,
-            #[doc = "[47:0] B:G:R 16:16:16 little endian"]
+            ///[47:0] B:G:R 16:16:16 little endian
             Bgr161616f = 1213351746u32,
-            #[doc = "[31:0] R 32 little endian"]
+            ///[31:0] R 32 little endian
             R32f = 1176510546u32,
-            #[doc = "[63:0] R:G 32:32 little endian"]
+            ///[63:0] R:G 32:32 little endian
             Gr3232f = 1176523335u32,
-            #[doc = "[95:0] R:G:B 32:32:32 little endian"]
+            ///[95:0] R:G:B 32:32:32 little endian
             Bgr323232f = 1179797314u32,
-            #[doc = "[127:0] R:G:B:A 32:32:32:32 little endian"]
+            ///[127:0] R:G:B:A 32:32:32:32 little endian
             Abgr32323232f = 1178092097u32,
-            #[doc = "2x1 subsampled Cr:Cb plane"]
+            ///2x1 subsampled Cr:Cb plane
             Nv20 = 808605262u32,
-            #[doc = "non-subsampled Cr:Cb plane"]
+            ///non-subsampled Cr:Cb plane
             Nv30 = 808670798u32,
-            #[doc = "2x2 subsampled Cb (1) and Cr (2) planes 10 bits per channel"]
+            ///2x2 subsampled Cb (1) and Cr (2) planes 10 bits per channel
             S010 = 808530003u32,
-            #[doc = "2x1 subsampled Cb (1) and Cr (2) planes 10 bits per channel"]
+            ///2x1 subsampled Cb (1) and Cr (2) planes 10 bits per channel
             S210 = 808530515u32,
-            #[doc = "non-subsampled Cb (1) and Cr (2) planes 10 bits per channel"]
+            ///non-subsampled Cb (1) and Cr (2) planes 10 bits per channel
             S410 = 808531027u32,
-            #[doc = "2x2 subsampled Cb (1) and Cr (2) planes 12 bits per channel"]
+            ///2x2 subsampled Cb (1) and Cr (2) planes 12 bits per channel
             S012 = 842084435u32,
-            #[doc = "2x1 subsampled Cb (1) and Cr (2) planes 12 bits per channel"]
+            ///2x1 subsampled Cb (1) and Cr (2) planes 12 bits per channel
             S212 = 842084947u32,
-            #[doc = "non-subsampled Cb (1) and Cr (2) planes 12 bits per channel"]
+            ///non-subsampled Cb (1) and Cr (2) planes 12 bits per channel
             S412 = 842085459u32,
-            #[doc = "2x2 subsampled Cb (1) and Cr (2) planes 16 bits per channel"]
+            ///2x2 subsampled Cb (1) and Cr (2) planes 16 bits per channel
             S016 = 909193299u32,
-            #[doc = "2x1 subsampled Cb (1) and Cr (2) planes 16 bits per channel"]
+            ///2x1 subsampled Cb (1) and Cr (2) planes 16 bits per channel
             S216 = 909193811u32,
-            #[doc = "non-subsampled Cb (1) and Cr (2) planes 16 bits per channel"]
+            ///non-subsampled Cb (1) and Cr (2) planes 16 bits per channel
             S416 = 909194323u32,
         }
+        #[allow(deprecated)]
+        impl Format {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Argb8888 => 1u32,
+                    Self::Xrgb8888 => 1u32,
+                    Self::C8 => 1u32,
+                    Self::Rgb332 => 1u32,
+                    Self::Bgr233 => 1u32,
+                    Self::Xrgb4444 => 1u32,
+                    Self::Xbgr4444 => 1u32,
+                    Self::Rgbx4444 => 1u32,
+                    Self::Bgrx4444 => 1u32,
+                    Self::Argb4444 => 1u32,
+                    Self::Abgr4444 => 1u32,
+                    Self::Rgba4444 => 1u32,
+                    Self::Bgra4444 => 1u32,
+                    Self::Xrgb1555 => 1u32,
+                    Self::Xbgr1555 => 1u32,
+                    Self::Rgbx5551 => 1u32,
+                    Self::Bgrx5551 => 1u32,
+                    Self::Argb1555 => 1u32,
+                    Self::Abgr1555 => 1u32,
+                    Self::Rgba5551 => 1u32,
+                    Self::Bgra5551 => 1u32,
+                    Self::Rgb565 => 1u32,
+                    Self::Bgr565 => 1u32,
+                    Self::Rgb888 => 1u32,
+                    Self::Bgr888 => 1u32,
+                    Self::Xbgr8888 => 1u32,
+                    Self::Rgbx8888 => 1u32,
+                    Self::Bgrx8888 => 1u32,
+                    Self::Abgr8888 => 1u32,
+                    Self::Rgba8888 => 1u32,
+                    Self::Bgra8888 => 1u32,
+                    Self::Xrgb2101010 => 1u32,
+                    Self::Xbgr2101010 => 1u32,
+                    Self::Rgbx1010102 => 1u32,
+                    Self::Bgrx1010102 => 1u32,
+                    Self::Argb2101010 => 1u32,
+                    Self::Abgr2101010 => 1u32,
+                    Self::Rgba1010102 => 1u32,
+                    Self::Bgra1010102 => 1u32,
+                    Self::Yuyv => 1u32,
+                    Self::Yvyu => 1u32,
+                    Self::Uyvy => 1u32,
+                    Self::Vyuy => 1u32,
+                    Self::Ayuv => 1u32,
+                    Self::Nv12 => 1u32,
+                    Self::Nv21 => 1u32,
+                    Self::Nv16 => 1u32,
+                    Self::Nv61 => 1u32,
+                    Self::Yuv410 => 1u32,
+                    Self::Yvu410 => 1u32,
+                    Self::Yuv411 => 1u32,
+                    Self::Yvu411 => 1u32,
+                    Self::Yuv420 => 1u32,
+                    Self::Yvu420 => 1u32,
+                    Self::Yuv422 => 1u32,
+                    Self::Yvu422 => 1u32,
+                    Self::Yuv444 => 1u32,
+                    Self::Yvu444 => 1u32,
+                    Self::R8 => 1u32,
+                    Self::R16 => 1u32,
+                    Self::Rg88 => 1u32,
+                    Self::Gr88 => 1u32,
+                    Self::Rg1616 => 1u32,
+                    Self::Gr1616 => 1u32,
+                    Self::Xrgb16161616f => 1u32,
+                    Self::Xbgr16161616f => 1u32,
+                    Self::Argb16161616f => 1u32,
+                    Self::Abgr16161616f => 1u32,
+                    Self::Xyuv8888 => 1u32,
+                    Self::Vuy888 => 1u32,
+                    Self::Vuy101010 => 1u32,
+                    Self::Y210 => 1u32,
+                    Self::Y212 => 1u32,
+                    Self::Y216 => 1u32,
+                    Self::Y410 => 1u32,
+                    Self::Y412 => 1u32,
+                    Self::Y416 => 1u32,
+                    Self::Xvyu2101010 => 1u32,
+                    Self::Xvyu1216161616 => 1u32,
+                    Self::Xvyu16161616 => 1u32,
+                    Self::Y0l0 => 1u32,
+                    Self::X0l0 => 1u32,
+                    Self::Y0l2 => 1u32,
+                    Self::X0l2 => 1u32,
+                    Self::Yuv4208bit => 1u32,
+                    Self::Yuv42010bit => 1u32,
+                    Self::Xrgb8888A8 => 1u32,
+                    Self::Xbgr8888A8 => 1u32,
+                    Self::Rgbx8888A8 => 1u32,
+                    Self::Bgrx8888A8 => 1u32,
+                    Self::Rgb888A8 => 1u32,
+                    Self::Bgr888A8 => 1u32,
+                    Self::Rgb565A8 => 1u32,
+                    Self::Bgr565A8 => 1u32,
+                    Self::Nv24 => 1u32,
+                    Self::Nv42 => 1u32,
+                    Self::P210 => 1u32,
+                    Self::P010 => 1u32,
+                    Self::P012 => 1u32,
+                    Self::P016 => 1u32,
+                    Self::Axbxgxrx106106106106 => 1u32,
+                    Self::Nv15 => 1u32,
+                    Self::Q410 => 1u32,
+                    Self::Q401 => 1u32,
+                    Self::Xrgb16161616 => 1u32,
+                    Self::Xbgr16161616 => 1u32,
+                    Self::Argb16161616 => 1u32,
+                    Self::Abgr16161616 => 1u32,
+                    Self::C1 => 1u32,
+                    Self::C2 => 1u32,
+                    Self::C4 => 1u32,
+                    Self::D1 => 1u32,
+                    Self::D2 => 1u32,
+                    Self::D4 => 1u32,
+                    Self::D8 => 1u32,
+                    Self::R1 => 1u32,
+                    Self::R2 => 1u32,
+                    Self::R4 => 1u32,
+                    Self::R10 => 1u32,
+                    Self::R12 => 1u32,
+                    Self::Avuy8888 => 1u32,
+                    Self::Xvuy8888 => 1u32,
+                    Self::P030 => 1u32,
+                    Self::Rgb161616 => 1u32,
+                    Self::Bgr161616 => 1u32,
+                    Self::R16f => 1u32,
+                    Self::Gr1616f => 1u32,
+                    Self::Bgr161616f => 1u32,
+                    Self::R32f => 1u32,
+                    Self::Gr3232f => 1u32,
+                    Self::Bgr323232f => 1u32,
+                    Self::Abgr32323232f => 1u32,
+                    Self::Nv20 => 1u32,
+                    Self::Nv30 => 1u32,
+                    Self::S010 => 1u32,
+                    Self::S210 => 1u32,
+                    Self::S410 => 1u32,
+                    Self::S012 => 1u32,
+                    Self::S212 => 1u32,
+                    Self::S412 => 1u32,
+                    Self::S016 => 1u32,
+                    Self::S216 => 1u32,
+                    Self::S416 => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Argb8888 => None,
+                    Self::Xrgb8888 => None,
+                    Self::C8 => None,
+                    Self::Rgb332 => None,
+                    Self::Bgr233 => None,
+                    Self::Xrgb4444 => None,
+                    Self::Xbgr4444 => None,
+                    Self::Rgbx4444 => None,
+                    Self::Bgrx4444 => None,
+                    Self::Argb4444 => None,
+                    Self::Abgr4444 => None,
+                    Self::Rgba4444 => None,
+                    Self::Bgra4444 => None,
+                    Self::Xrgb1555 => None,
+                    Self::Xbgr1555 => None,
+                    Self::Rgbx5551 => None,
+                    Self::Bgrx5551 => None,
+                    Self::Argb1555 => None,
+                    Self::Abgr1555 => None,
+                    Self::Rgba5551 => None,
+                    Self::Bgra5551 => None,
+                    Self::Rgb565 => None,
+                    Self::Bgr565 => None,
+                    Self::Rgb888 => None,
+                    Self::Bgr888 => None,
+                    Self::Xbgr8888 => None,
+                    Self::Rgbx8888 => None,
+                    Self::Bgrx8888 => None,
+                    Self::Abgr8888 => None,
+                    Self::Rgba8888 => None,
+                    Self::Bgra8888 => None,
+                    Self::Xrgb2101010 => None,
+                    Self::Xbgr2101010 => None,
+                    Self::Rgbx1010102 => None,
+                    Self::Bgrx1010102 => None,
+                    Self::Argb2101010 => None,
+                    Self::Abgr2101010 => None,
+                    Self::Rgba1010102 => None,
+                    Self::Bgra1010102 => None,
+                    Self::Yuyv => None,
+                    Self::Yvyu => None,
+                    Self::Uyvy => None,
+                    Self::Vyuy => None,
+                    Self::Ayuv => None,
+                    Self::Nv12 => None,
+                    Self::Nv21 => None,
+                    Self::Nv16 => None,
+                    Self::Nv61 => None,
+                    Self::Yuv410 => None,
+                    Self::Yvu410 => None,
+                    Self::Yuv411 => None,
+                    Self::Yvu411 => None,
+                    Self::Yuv420 => None,
+                    Self::Yvu420 => None,
+                    Self::Yuv422 => None,
+                    Self::Yvu422 => None,
+                    Self::Yuv444 => None,
+                    Self::Yvu444 => None,
+                    Self::R8 => None,
+                    Self::R16 => None,
+                    Self::Rg88 => None,
+                    Self::Gr88 => None,
+                    Self::Rg1616 => None,
+                    Self::Gr1616 => None,
+                    Self::Xrgb16161616f => None,
+                    Self::Xbgr16161616f => None,
+                    Self::Argb16161616f => None,
+                    Self::Abgr16161616f => None,
+                    Self::Xyuv8888 => None,
+                    Self::Vuy888 => None,
+                    Self::Vuy101010 => None,
+                    Self::Y210 => None,
+                    Self::Y212 => None,
+                    Self::Y216 => None,
+                    Self::Y410 => None,
+                    Self::Y412 => None,
+                    Self::Y416 => None,
+                    Self::Xvyu2101010 => None,
+                    Self::Xvyu1216161616 => None,
+                    Self::Xvyu16161616 => None,
+                    Self::Y0l0 => None,
+                    Self::X0l0 => None,
+                    Self::Y0l2 => None,
+                    Self::X0l2 => None,
+                    Self::Yuv4208bit => None,
+                    Self::Yuv42010bit => None,
+                    Self::Xrgb8888A8 => None,
+                    Self::Xbgr8888A8 => None,
+                    Self::Rgbx8888A8 => None,
+                    Self::Bgrx8888A8 => None,
+                    Self::Rgb888A8 => None,
+                    Self::Bgr888A8 => None,
+                    Self::Rgb565A8 => None,
+                    Self::Bgr565A8 => None,
+                    Self::Nv24 => None,
+                    Self::Nv42 => None,
+                    Self::P210 => None,
+                    Self::P010 => None,
+                    Self::P012 => None,
+                    Self::P016 => None,
+                    Self::Axbxgxrx106106106106 => None,
+                    Self::Nv15 => None,
+                    Self::Q410 => None,
+                    Self::Q401 => None,
+                    Self::Xrgb16161616 => None,
+                    Self::Xbgr16161616 => None,
+                    Self::Argb16161616 => None,
+                    Self::Abgr16161616 => None,
+                    Self::C1 => None,
+                    Self::C2 => None,
+                    Self::C4 => None,
+                    Self::D1 => None,
+                    Self::D2 => None,
+                    Self::D4 => None,
+                    Self::D8 => None,
+                    Self::R1 => None,
+                    Self::R2 => None,
+                    Self::R4 => None,
+                    Self::R10 => None,
+                    Self::R12 => None,
+                    Self::Avuy8888 => None,
+                    Self::Xvuy8888 => None,
+                    Self::P030 => None,
+                    Self::Rgb161616 => None,
+                    Self::Bgr161616 => None,
+                    Self::R16f => None,
+                    Self::Gr1616f => None,
+                    Self::Bgr161616f => None,
+                    Self::R32f => None,
+                    Self::Gr3232f => None,
+                    Self::Bgr323232f => None,
+                    Self::Abgr32323232f => None,
+                    Self::Nv20 => None,
+                    Self::Nv30 => None,
+                    Self::S010 => None,
+                    Self::S210 => None,
+                    Self::S410 => None,
+                    Self::S012 => None,
+                    Self::S212 => None,
+                    Self::S412 => None,
+                    Self::S016 => None,
+                    Self::S216 => None,
+                    Self::S416 => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("argb8888", Self::Argb8888),
+                ("xrgb8888", Self::Xrgb8888),
+                ("c8", Self::C8),
+                ("rgb332", Self::Rgb332),
+                ("bgr233", Self::Bgr233),
+                ("xrgb4444", Self::Xrgb4444),
+                ("xbgr4444", Self::Xbgr4444),
+                ("rgbx4444", Self::Rgbx4444),
+                ("bgrx4444", Self::Bgrx4444),
+                ("argb4444", Self::Argb4444),
+                ("abgr4444", Self::Abgr4444),
+                ("rgba4444", Self::Rgba4444),
+                ("bgra4444", Self::Bgra4444),
+                ("xrgb1555", Self::Xrgb1555),
+                ("xbgr1555", Self::Xbgr1555),
+                ("rgbx5551", Self::Rgbx5551),
+                ("bgrx5551", Self::Bgrx5551),
+                ("argb1555", Self::Argb1555),
+                ("abgr1555", Self::Abgr1555),
+                ("rgba5551", Self::Rgba5551),
+                ("bgra5551", Self::Bgra5551),
+                ("rgb565", Self::Rgb565),
+                ("bgr565", Self::Bgr565),
+                ("rgb888", Self::Rgb888),
+                ("bgr888", Self::Bgr888),
+                ("xbgr8888", Self::Xbgr8888),
+                ("rgbx8888", Self::Rgbx8888),
+                ("bgrx8888", Self::Bgrx8888),
+                ("abgr8888", Self::Abgr8888),
+                ("rgba8888", Self::Rgba8888),
+                ("bgra8888", Self::Bgra8888),
+                ("xrgb2101010", Self::Xrgb2101010),
+                ("xbgr2101010", Self::Xbgr2101010),
+                ("rgbx1010102", Self::Rgbx1010102),
+                ("bgrx1010102", Self::Bgrx1010102),
+                ("argb2101010", Self::Argb2101010),
+                ("abgr2101010", Self::Abgr2101010),
+                ("rgba1010102", Self::Rgba1010102),
+                ("bgra1010102", Self::Bgra1010102),
+                ("yuyv", Self::Yuyv),
+                ("yvyu", Self::Yvyu),
+                ("uyvy", Self::Uyvy),
+                ("vyuy", Self::Vyuy),
+                ("ayuv", Self::Ayuv),
+                ("nv12", Self::Nv12),
+                ("nv21", Self::Nv21),
+                ("nv16", Self::Nv16),
+                ("nv61", Self::Nv61),
+                ("yuv410", Self::Yuv410),
+                ("yvu410", Self::Yvu410),
+                ("yuv411", Self::Yuv411),
+                ("yvu411", Self::Yvu411),
+                ("yuv420", Self::Yuv420),
+                ("yvu420", Self::Yvu420),
+                ("yuv422", Self::Yuv422),
+                ("yvu422", Self::Yvu422),
+                ("yuv444", Self::Yuv444),
+                ("yvu444", Self::Yvu444),
+                ("r8", Self::R8),
+                ("r16", Self::R16),
+                ("rg88", Self::Rg88),
+                ("gr88", Self::Gr88),
+                ("rg1616", Self::Rg1616),
+                ("gr1616", Self::Gr1616),
+                ("xrgb16161616f", Self::Xrgb16161616f),
+                ("xbgr16161616f", Self::Xbgr16161616f),
+                ("argb16161616f", Self::Argb16161616f),
+                ("abgr16161616f", Self::Abgr16161616f),
+                ("xyuv8888", Self::Xyuv8888),
+                ("vuy888", Self::Vuy888),
+                ("vuy101010", Self::Vuy101010),
+                ("y210", Self::Y210),
+                ("y212", Self::Y212),
+                ("y216", Self::Y216),
+                ("y410", Self::Y410),
+                ("y412", Self::Y412),
+                ("y416", Self::Y416),
+                ("xvyu2101010", Self::Xvyu2101010),
+                ("xvyu12_16161616", Self::Xvyu1216161616),
+                ("xvyu16161616", Self::Xvyu16161616),
+                ("y0l0", Self::Y0l0),
+                ("x0l0", Self::X0l0),
+                ("y0l2", Self::Y0l2),
+                ("x0l2", Self::X0l2),
+                ("yuv420_8bit", Self::Yuv4208bit),
+                ("yuv420_10bit", Self::Yuv42010bit),
+                ("xrgb8888_a8", Self::Xrgb8888A8),
+                ("xbgr8888_a8", Self::Xbgr8888A8),
+                ("rgbx8888_a8", Self::Rgbx8888A8),
+                ("bgrx8888_a8", Self::Bgrx8888A8),
+                ("rgb888_a8", Self::Rgb888A8),
+                ("bgr888_a8", Self::Bgr888A8),
+                ("rgb565_a8", Self::Rgb565A8),
+                ("bgr565_a8", Self::Bgr565A8),
+                ("nv24", Self::Nv24),
+                ("nv42", Self::Nv42),
+                ("p210", Self::P210),
+                ("p010", Self::P010),
+                ("p012", Self::P012),
+                ("p016", Self::P016),
+                ("axbxgxrx106106106106", Self::Axbxgxrx106106106106),
+                ("nv15", Self::Nv15),
+                ("q410", Self::Q410),
+                ("q401", Self::Q401),
+                ("xrgb16161616", Self::Xrgb16161616),
+                ("xbgr16161616", Self::Xbgr16161616),
+                ("argb16161616", Self::Argb16161616),
+                ("abgr16161616", Self::Abgr16161616),
+                ("c1", Self::C1),
+                ("c2", Self::C2),
+                ("c4", Self::C4),
+                ("d1", Self::D1),
+                ("d2", Self::D2),
+                ("d4", Self::D4),
+                ("d8", Self::D8),
+                ("r1", Self::R1),
+                ("r2", Self::R2),
+                ("r4", Self::R4),
+                ("r10", Self::R10),
+                ("r12", Self::R12),
+                ("avuy8888", Self::Avuy8888),
+                ("xvuy8888", Self::Xvuy8888),
+                ("p030", Self::P030),
+                ("rgb161616", Self::Rgb161616),
+                ("bgr161616", Self::Bgr161616),
+                ("r16f", Self::R16f),
+                ("gr1616f", Self::Gr1616f),
+                ("bgr161616f", Self::Bgr161616f),
+                ("r32f", Self::R32f),
+                ("gr3232f", Self::Gr3232f),
+                ("bgr323232f", Self::Bgr323232f),
+                ("abgr32323232f", Self::Abgr32323232f),
+                ("nv20", Self::Nv20),
+                ("nv30", Self::Nv30),
+                ("s010", Self::S010),
+                ("s210", Self::S210),
+                ("s410", Self::S410),
+                ("s012", Self::S012),
+                ("s212", Self::S212),
+                ("s412", Self::S412),
+                ("s016", Self::S016),
+                ("s216", Self::S216),
+                ("s416", Self::S416),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Argb8888 => "argb8888",
+                    Self::Xrgb8888 => "xrgb8888",
+                    Self::C8 => "c8",
+                    Self::Rgb332 => "rgb332",
+                    Self::Bgr233 => "bgr233",
+                    Self::Xrgb4444 => "xrgb4444",
+                    Self::Xbgr4444 => "xbgr4444",
+                    Self::Rgbx4444 => "rgbx4444",
+                    Self::Bgrx4444 => "bgrx4444",
+                    Self::Argb4444 => "argb4444",
+                    Self::Abgr4444 => "abgr4444",
+                    Self::Rgba4444 => "rgba4444",
+                    Self::Bgra4444 => "bgra4444",
+                    Self::Xrgb1555 => "xrgb1555",
+                    Self::Xbgr1555 => "xbgr1555",
+                    Self::Rgbx5551 => "rgbx5551",
+                    Self::Bgrx5551 => "bgrx5551",
+                    Self::Argb1555 => "argb1555",
+                    Self::Abgr1555 => "abgr1555",
+                    Self::Rgba5551 => "rgba5551",
+                    Self::Bgra5551 => "bgra5551",
+                    Self::Rgb565 => "rgb565",
+                    Self::Bgr565 => "bgr565",
+                    Self::Rgb888 => "rgb888",
+                    Self::Bgr888 => "bgr888",
+                    Self::Xbgr8888 => "xbgr8888",
+                    Self::Rgbx8888 => "rgbx8888",
+                    Self::Bgrx8888 => "bgrx8888",
+                    Self::Abgr8888 => "abgr8888",
+                    Self::Rgba8888 => "rgba8888",
+                    Self::Bgra8888 => "bgra8888",
+                    Self::Xrgb2101010 => "xrgb2101010",
+                    Self::Xbgr2101010 => "xbgr2101010",
+                    Self::Rgbx1010102 => "rgbx1010102",
+                    Self::Bgrx1010102 => "bgrx1010102",
+                    Self::Argb2101010 => "argb2101010",
+                    Self::Abgr2101010 => "abgr2101010",
+                    Self::Rgba1010102 => "rgba1010102",
+                    Self::Bgra1010102 => "bgra1010102",
+                    Self::Yuyv => "yuyv",
+                    Self::Yvyu => "yvyu",
+                    Self::Uyvy => "uyvy",
+                    Self::Vyuy => "vyuy",
+                    Self::Ayuv => "ayuv",
+                    Self::Nv12 => "nv12",
+                    Self::Nv21 => "nv21",
+                    Self::Nv16 => "nv16",
+                    Self::Nv61 => "nv61",
+                    Self::Yuv410 => "yuv410",
+                    Self::Yvu410 => "yvu410",
+                    Self::Yuv411 => "yuv411",
+                    Self::Yvu411 => "yvu411",
+                    Self::Yuv420 => "yuv420",
+                    Self::Yvu420 => "yvu420",
+                    Self::Yuv422 => "yuv422",
+                    Self::Yvu422 => "yvu422",
+                    Self::Yuv444 => "yuv444",
+                    Self::Yvu444 => "yvu444",
+                    Self::R8 => "r8",
+                    Self::R16 => "r16",
+                    Self::Rg88 => "rg88",
+                    Self::Gr88 => "gr88",
+                    Self::Rg1616 => "rg1616",
+                    Self::Gr1616 => "gr1616",
+                    Self::Xrgb16161616f => "xrgb16161616f",
+                    Self::Xbgr16161616f => "xbgr16161616f",
+                    Self::Argb16161616f => "argb16161616f",
+                    Self::Abgr16161616f => "abgr16161616f",
+                    Self::Xyuv8888 => "xyuv8888",
+                    Self::Vuy888 => "vuy888",
+                    Self::Vuy101010 => "vuy101010",
+                    Self::Y210 => "y210",
+                    Self::Y212 => "y212",
+                    Self::Y216 => "y216",
+                    Self::Y410 => "y410",
+                    Self::Y412 => "y412",
+                    Self::Y416 => "y416",
+                    Self::Xvyu2101010 => "xvyu2101010",
+                    Self::Xvyu1216161616 => "xvyu12_16161616",
+                    Self::Xvyu16161616 => "xvyu16161616",
+                    Self::Y0l0 => "y0l0",
+                    Self::X0l0 => "x0l0",
+                    Self::Y0l2 => "y0l2",
+                    Self::X0l2 => "x0l2",
+                    Self::Yuv4208bit => "yuv420_8bit",
+                    Self::Yuv42010bit => "yuv420_10bit",
+                    Self::Xrgb8888A8 => "xrgb8888_a8",
+                    Self::Xbgr8888A8 => "xbgr8888_a8",
+                    Self::Rgbx8888A8 => "rgbx8888_a8",
+                    Self::Bgrx8888A8 => "bgrx8888_a8",
+                    Self::Rgb888A8 => "rgb888_a8",
+                    Self::Bgr888A8 => "bgr888_a8",
+                    Self::Rgb565A8 => "rgb565_a8",
+                    Self::Bgr565A8 => "bgr565_a8",
+                    Self::Nv24 => "nv24",
+                    Self::Nv42 => "nv42",
+                    Self::P210 => "p210",
+                    Self::P010 => "p010",
+                    Self::P012 => "p012",
+                    Self::P016 => "p016",
+                    Self::Axbxgxrx106106106106 => "axbxgxrx106106106106",
+                    Self::Nv15 => "nv15",
+                    Self::Q410 => "q410",
+                    Self::Q401 => "q401",
+                    Self::Xrgb16161616 => "xrgb16161616",
+                    Self::Xbgr16161616 => "xbgr16161616",
+                    Self::Argb16161616 => "argb16161616",
+                    Self::Abgr16161616 => "abgr16161616",
+                    Self::C1 => "c1",
+                    Self::C2 => "c2",
+                    Self::C4 => "c4",
+                    Self::D1 => "d1",
+                    Self::D2 => "d2",
+                    Self::D4 => "d4",
+                    Self::D8 => "d8",
+                    Self::R1 => "r1",
+                    Self::R2 => "r2",
+                    Self::R4 => "r4",
+                    Self::R10 => "r10",
+                    Self::R12 => "r12",
+                    Self::Avuy8888 => "avuy8888",
+                    Self::Xvuy8888 => "xvuy8888",
+                    Self::P030 => "p030",
+                    Self::Rgb161616 => "rgb161616",
+                    Self::Bgr161616 => "bgr161616",
+                    Self::R16f => "r16f",
+                    Self::Gr1616f => "gr1616f",
+                    Self::Bgr161616f => "bgr161616f",
+                    Self::R32f => "r32f",
+                    Self::Gr3232f => "gr3232f",
+                    Self::Bgr323232f => "bgr323232f",
+                    Self::Abgr32323232f => "abgr32323232f",
+                    Self::Nv20 => "nv20",
+                    Self::Nv30 => "nv30",
+                    Self::S010 => "s010",
+                    Self::S210 => "s210",
+                    Self::S410 => "s410",
+                    Self::S012 => "s012",
+                    Self::S212 => "s212",
+                    Self::S412 => "s412",
+                    Self::S016 => "s016",
+                    Self::S216 => "s216",
+                    Self::S416 => "s416",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Format> for u32 {
             fn from(value: Format) -> Self {
                 value as u32
             }
         }
+        impl From<Format> for i32 {
+            fn from(value: Format) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Format {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1041,76 +2274,164 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Format {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Format {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_pool",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nhi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_shm_pool"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "size",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "2",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_shm interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the format message was introduced in
+            pub const FORMAT_SINCE: u32 = 1u32;
+            ///The interface version the format message was deprecated in, if any
+            pub const FORMAT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Informs the client about a valid pixel format that
+            ///can be used for buffers. Known formats include
+            ///argb8888 and xrgb8888.
+            ///
+            ///Extensions to drm_fourcc.h (or the format enum) do not require
+            ///increasing the wl_shm version; as a result, clients may receive format
+            ///codes which were not in the list at the time the client was made.
+            pub fn format<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                format: Format,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_shm",
+                    object_id = % sender_id, opcode = 0u16, message = "format", direction
+                    = "out", "args.format" = % format,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_shm",
+                    object_id : % = sender_id, opcode = 0u16, message = "format",
+                    direction = "out", "args.format" : % = format; "wl_shm.format"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(format.into())
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the wl_shm interface. See the module level documentation for more info"]
+        ///Trait to implement the wl_shm interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_shm` interface",
+            label = "`WlShm` is not implemented for `{Self}`"
+        )]
         pub trait WlShm
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_shm";
             const VERSION: u32 = 2u32;
-            #[doc = "Create a new wl_shm_pool object."]
-            #[doc = ""]
-            #[doc = "The pool can be used to create shared memory based buffer"]
-            #[doc = "objects.  The server will mmap size bytes of the passed file"]
-            #[doc = "descriptor, to use as backing memory for the pool."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_pool message was introduced in
+            const CREATE_POOL_SINCE: u32 = 1u32;
+            ///The interface version the create_pool message was deprecated in, if any
+            const CREATE_POOL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a new wl_shm_pool object.
+            ///
+            ///The pool can be used to create shared memory based buffer
+            ///objects.  The server will mmap size bytes of the passed file
+            ///descriptor, to use as backing memory for the pool.
             fn create_pool(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 fd: std::os::fd::OwnedFd,
                 size: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Using this request a client can tell the server that it is not going to"]
-            #[doc = "use the shm object anymore."]
-            #[doc = ""]
-            #[doc = "Objects created via this interface remain unaffected."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 2u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Using this request a client can tell the server that it is not going to
+            ///use the shm object anymore.
+            ///
+            ///Objects created via this interface remain unaffected.
+            ///
+            ///Available since version 2
             fn release(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Informs the client about a valid pixel format that"]
-            #[doc = "can be used for buffers. Known formats include"]
-            #[doc = "argb8888 and xrgb8888."]
-            #[doc = ""]
-            #[doc = "Extensions to drm_fourcc.h (or the format enum) do not require"]
-            #[doc = "increasing the wl_shm version; as a result, clients may receive format"]
-            #[doc = "codes which were not in the list at the time the client was made."]
-            fn format(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                format: Format,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_shm#{}.format({})", sender_id, format);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(format.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -1121,139 +2442,269 @@ pub mod wayland {
                             let fd = waynest::Connection::fd(connection)?;
                             let size = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shm#{}.create_pool({}, {}, {})",
-                                sender_id,
-                                id,
-                                std::os::fd::AsRawFd::as_raw_fd(&fd),
-                                size
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shm", object_id = % sender_id, opcode = 0u16, message =
+                                "create_pool", direction = "in", "args.id" = % id, "args.fd"
+                                = % std::os::fd::AsRawFd::as_raw_fd(& fd), "args.size" = %
+                                size,
                             );
-                            self.create_pool(connection, sender_id, id, fd, size).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface = "wl_shm",
+                                object_id : % = sender_id, opcode = 0u16, message =
+                                "create_pool", direction = "in", "args.id" : % = id,
+                                "args.fd" : % = std::os::fd::AsRawFd::as_raw_fd(& fd),
+                                "args.size" : % = size; "wl_shm.create_pool"
+                            );
+                            self.create_pool(connection, state, sender_id, id, fd, size)
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_shm#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shm", object_id = % sender_id, opcode = 1u16, message =
+                                "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface = "wl_shm",
+                                object_id : % = sender_id, opcode = 1u16, message =
+                                "release", direction = "in"; "wl_shm.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A buffer provides the content for a wl_surface. Buffers are"]
-    #[doc = "created through factory interfaces such as wl_shm, wp_linux_buffer_params"]
-    #[doc = "(from the linux-dmabuf protocol extension) or similar. It has a width and"]
-    #[doc = "a height and can be attached to a wl_surface, but the mechanism by which a"]
-    #[doc = "client provides and updates the contents is defined by the buffer factory"]
-    #[doc = "interface."]
-    #[doc = ""]
-    #[doc = "Color channels are assumed to be electrical rather than optical (in other"]
-    #[doc = "words, encoded with a transfer function) unless otherwise specified. If"]
-    #[doc = "the buffer uses a format that has an alpha channel, the alpha channel is"]
-    #[doc = "assumed to be premultiplied into the electrical color channel values"]
-    #[doc = "(after transfer function encoding) unless otherwise specified."]
-    #[doc = ""]
-    #[doc = "Note, because wl_buffer objects are created from multiple independent"]
-    #[doc = "factory interfaces, the wl_buffer interface is frozen at version 1."]
+    ///A buffer provides the content for a wl_surface. Buffers are
+    ///created through factory interfaces such as wl_shm, wp_linux_buffer_params
+    ///(from the linux-dmabuf protocol extension) or similar. It has a width and
+    ///a height and can be attached to a wl_surface, but the mechanism by which a
+    ///client provides and updates the contents is defined by the buffer factory
+    ///interface.
+    ///
+    ///Color channels are assumed to be electrical rather than optical (in other
+    ///words, encoded with a transfer function) unless otherwise specified. If
+    ///the buffer uses a format that has an alpha channel, the alpha channel is
+    ///assumed to be premultiplied into the electrical color channel values
+    ///(after transfer function encoding) unless otherwise specified.
+    ///
+    ///Note, because wl_buffer objects are created from multiple independent
+    ///factory interfaces, the wl_buffer interface is frozen at version 1.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_buffer {
-        #[doc = "Trait to implement the wl_buffer interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_buffer interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the release message was introduced in
+            pub const RELEASE_SINCE: u32 = 1u32;
+            ///The interface version the release message was deprecated in, if any
+            pub const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent when this wl_buffer is no longer used by the compositor.
+            ///
+            ///For more information on when release events may or may not be sent,
+            ///and what consequences it has, please see the description of
+            ///wl_surface.attach.
+            ///
+            ///If a client receives a release event before the frame callback
+            ///requested in the same wl_surface.commit that attaches this
+            ///wl_buffer to a surface, then the client is immediately free to
+            ///reuse the buffer and its backing storage, and does not need a
+            ///second buffer for the next surface content update. Typically
+            ///this is possible, when the compositor maintains a copy of the
+            ///wl_surface contents, e.g. as a GL texture. This is an important
+            ///optimization for GL(ES) compositors with wl_shm clients.
+            pub fn release<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_buffer",
+                    object_id = % sender_id, opcode = 0u16, message = "release",
+                    direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_buffer",
+                    object_id : % = sender_id, opcode = 0u16, message = "release",
+                    direction = "out"; "wl_buffer.release"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the wl_buffer interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_buffer` interface",
+            label = "`WlBuffer` is not implemented for `{Self}`"
+        )]
         pub trait WlBuffer
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_buffer";
             const VERSION: u32 = 1u32;
-            #[doc = "Destroy a buffer. If and how you need to release the backing"]
-            #[doc = "storage is defined by the buffer factory interface."]
-            #[doc = ""]
-            #[doc = "For possible side-effects to a surface, see wl_surface.attach."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy a buffer. If and how you need to release the backing
+            ///storage is defined by the buffer factory interface.
+            ///
+            ///For possible side-effects to a surface, see wl_surface.attach.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent when this wl_buffer is no longer used by the compositor."]
-            #[doc = ""]
-            #[doc = "For more information on when release events may or may not be sent,"]
-            #[doc = "and what consequences it has, please see the description of"]
-            #[doc = "wl_surface.attach."]
-            #[doc = ""]
-            #[doc = "If a client receives a release event before the frame callback"]
-            #[doc = "requested in the same wl_surface.commit that attaches this"]
-            #[doc = "wl_buffer to a surface, then the client is immediately free to"]
-            #[doc = "reuse the buffer and its backing storage, and does not need a"]
-            #[doc = "second buffer for the next surface content update. Typically"]
-            #[doc = "this is possible, when the compositor maintains a copy of the"]
-            #[doc = "wl_surface contents, e.g. as a GL texture. This is an important"]
-            #[doc = "optimization for GL(ES) compositors with wl_shm clients."]
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_buffer#{}.release()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_buffer#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_buffer", object_id = % sender_id, opcode = 0u16, message
+                                = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_buffer", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "wl_buffer.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A wl_data_offer represents a piece of data offered for transfer"]
-    #[doc = "by another client (the source client).  It is used by the"]
-    #[doc = "copy-and-paste and drag-and-drop mechanisms.  The offer"]
-    #[doc = "describes the different mime types that the data can be"]
-    #[doc = "converted to and provides the mechanism for transferring the"]
-    #[doc = "data directly from the source client."]
+    ///A wl_data_offer represents a piece of data offered for transfer
+    ///by another client (the source client).  It is used by the
+    ///copy-and-paste and drag-and-drop mechanisms.  The offer
+    ///describes the different mime types that the data can be
+    ///converted to and provides the mechanism for transferring the
+    ///data directly from the source client.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_data_offer {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "finish request was called untimely"]
+            ///finish request was called untimely
             InvalidFinish = 0u32,
-            #[doc = "action mask contains invalid values"]
+            ///action mask contains invalid values
             InvalidActionMask = 1u32,
-            #[doc = "action argument has an invalid value"]
+            ///action argument has an invalid value
             InvalidAction = 2u32,
-            #[doc = "offer doesn't accept this request"]
+            ///offer doesn't accept this request
             InvalidOffer = 3u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidFinish => 1u32,
+                    Self::InvalidActionMask => 1u32,
+                    Self::InvalidAction => 1u32,
+                    Self::InvalidOffer => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidFinish => None,
+                    Self::InvalidActionMask => None,
+                    Self::InvalidAction => None,
+                    Self::InvalidOffer => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_finish", Self::InvalidFinish),
+                ("invalid_action_mask", Self::InvalidActionMask),
+                ("invalid_action", Self::InvalidAction),
+                ("invalid_offer", Self::InvalidOffer),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidFinish => "invalid_finish",
+                    Self::InvalidActionMask => "invalid_action_mask",
+                    Self::InvalidAction => "invalid_action",
+                    Self::InvalidOffer => "invalid_offer",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1266,240 +2717,420 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "accept",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "receive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "finish",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_actions",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dnd_actions",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "preferred_action",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_data_offer interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the offer message was introduced in
+            pub const OFFER_SINCE: u32 = 1u32;
+            ///The interface version the offer message was deprecated in, if any
+            pub const OFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent immediately after creating the wl_data_offer object.  One
+            ///event per offered mime type.
+            pub fn offer<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                mime_type: String,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_offer", object_id = % sender_id, opcode = 0u16, message =
+                    "offer", direction = "out", "args.mime_type" = % mime_type,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_offer",
+                    object_id : % = sender_id, opcode = 0u16, message = "offer",
+                    direction = "out", "args.mime_type" : % = mime_type;
+                    "wl_data_offer.offer"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(mime_type))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the source_actions message was introduced in
+            pub const SOURCE_ACTIONS_SINCE: u32 = 3u32;
+            ///The interface version the source_actions message was deprecated in, if any
+            pub const SOURCE_ACTIONS_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates the actions offered by the data source. It
+            ///will be sent immediately after creating the wl_data_offer object,
+            ///or anytime the source side changes its offered actions through
+            ///wl_data_source.set_actions.
+            ///
+            ///Available since version 3
+            pub fn source_actions<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                source_actions: super::wl_data_device_manager::DndAction,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_offer", object_id = % sender_id, opcode = 1u16, message =
+                    "source_actions", direction = "out", "args.source_actions" = %
+                    source_actions,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_offer",
+                    object_id : % = sender_id, opcode = 1u16, message = "source_actions",
+                    direction = "out", "args.source_actions" : % = source_actions;
+                    "wl_data_offer.source_actions"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(source_actions.into())
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the action message was introduced in
+            pub const ACTION_SINCE: u32 = 3u32;
+            ///The interface version the action message was deprecated in, if any
+            pub const ACTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates the action selected by the compositor after
+            ///matching the source/destination side actions. Only one action (or
+            ///none) will be offered here.
+            ///
+            ///This event can be emitted multiple times during the drag-and-drop
+            ///operation in response to destination side action changes through
+            ///wl_data_offer.set_actions.
+            ///
+            ///This event will no longer be emitted after wl_data_device.drop
+            ///happened on the drag-and-drop destination, the client must
+            ///honor the last action received, or the last preferred one set
+            ///through wl_data_offer.set_actions when handling an "ask" action.
+            ///
+            ///Compositors may also change the selected action on the fly, mainly
+            ///in response to keyboard modifier changes during the drag-and-drop
+            ///operation.
+            ///
+            ///The most recent action received is always the valid one. Prior to
+            ///receiving wl_data_device.drop, the chosen action may change (e.g.
+            ///due to keyboard modifiers being pressed). At the time of receiving
+            ///wl_data_device.drop the drag-and-drop destination must honor the
+            ///last action received.
+            ///
+            ///Action changes may still happen after wl_data_device.drop,
+            ///especially on "ask" actions, where the drag-and-drop destination
+            ///may choose another action afterwards. Action changes happening
+            ///at this stage are always the result of inter-client negotiation, the
+            ///compositor shall no longer be able to induce a different action.
+            ///
+            ///Upon "ask" actions, it is expected that the drag-and-drop destination
+            ///may potentially choose a different action and/or mime type,
+            ///based on wl_data_offer.source_actions and finally chosen by the
+            ///user (e.g. popping up a menu with the available options). The
+            ///final wl_data_offer.set_actions and wl_data_offer.accept requests
+            ///must happen before the call to wl_data_offer.finish.
+            ///
+            ///Available since version 3
+            pub fn action<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                dnd_action: super::wl_data_device_manager::DndAction,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_offer", object_id = % sender_id, opcode = 2u16, message =
+                    "action", direction = "out", "args.dnd_action" = % dnd_action,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_offer",
+                    object_id : % = sender_id, opcode = 2u16, message = "action",
+                    direction = "out", "args.dnd_action" : % = dnd_action;
+                    "wl_data_offer.action"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(dnd_action.into())
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the wl_data_offer interface. See the module level documentation for more info"]
+        ///Trait to implement the wl_data_offer interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_data_offer` interface",
+            label = "`WlDataOffer` is not implemented for `{Self}`"
+        )]
         pub trait WlDataOffer
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_data_offer";
             const VERSION: u32 = 4u32;
-            #[doc = "Indicate that the client can accept the given mime type, or"]
-            #[doc = "NULL for not accepted."]
-            #[doc = ""]
-            #[doc = "For objects of version 2 or older, this request is used by the"]
-            #[doc = "client to give feedback whether the client can receive the given"]
-            #[doc = "mime type, or NULL if none is accepted; the feedback does not"]
-            #[doc = "determine whether the drag-and-drop operation succeeds or not."]
-            #[doc = ""]
-            #[doc = "For objects of version 3 or newer, this request determines the"]
-            #[doc = "final result of the drag-and-drop operation. If the end result"]
-            #[doc = "is that no mime types were accepted, the drag-and-drop operation"]
-            #[doc = "will be cancelled and the corresponding drag source will receive"]
-            #[doc = "wl_data_source.cancelled. Clients may still use this event in"]
-            #[doc = "conjunction with wl_data_source.action for feedback."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the accept message was introduced in
+            const ACCEPT_SINCE: u32 = 1u32;
+            ///The interface version the accept message was deprecated in, if any
+            const ACCEPT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicate that the client can accept the given mime type, or
+            ///NULL for not accepted.
+            ///
+            ///For objects of version 2 or older, this request is used by the
+            ///client to give feedback whether the client can receive the given
+            ///mime type, or NULL if none is accepted; the feedback does not
+            ///determine whether the drag-and-drop operation succeeds or not.
+            ///
+            ///For objects of version 3 or newer, this request determines the
+            ///final result of the drag-and-drop operation. If the end result
+            ///is that no mime types were accepted, the drag-and-drop operation
+            ///will be cancelled and the corresponding drag source will receive
+            ///wl_data_source.cancelled. Clients may still use this event in
+            ///conjunction with wl_data_source.action for feedback.
             fn accept(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 mime_type: Option<String>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "To transfer the offered data, the client issues this request"]
-            #[doc = "and indicates the mime type it wants to receive.  The transfer"]
-            #[doc = "happens through the passed file descriptor (typically created"]
-            #[doc = "with the pipe system call).  The source client writes the data"]
-            #[doc = "in the mime type representation requested and then closes the"]
-            #[doc = "file descriptor."]
-            #[doc = ""]
-            #[doc = "The receiving client reads from the read end of the pipe until"]
-            #[doc = "EOF and then closes its end, at which point the transfer is"]
-            #[doc = "complete."]
-            #[doc = ""]
-            #[doc = "This request may happen multiple times for different mime types,"]
-            #[doc = "both before and after wl_data_device.drop. Drag-and-drop destination"]
-            #[doc = "clients may preemptively fetch data or examine it more closely to"]
-            #[doc = "determine acceptance."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the receive message was introduced in
+            const RECEIVE_SINCE: u32 = 1u32;
+            ///The interface version the receive message was deprecated in, if any
+            const RECEIVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///To transfer the offered data, the client issues this request
+            ///and indicates the mime type it wants to receive.  The transfer
+            ///happens through the passed file descriptor (typically created
+            ///with the pipe system call).  The source client writes the data
+            ///in the mime type representation requested and then closes the
+            ///file descriptor.
+            ///
+            ///The receiving client reads from the read end of the pipe until
+            ///EOF and then closes its end, at which point the transfer is
+            ///complete.
+            ///
+            ///This request may happen multiple times for different mime types,
+            ///both before and after wl_data_device.drop. Drag-and-drop destination
+            ///clients may preemptively fetch data or examine it more closely to
+            ///determine acceptance.
             fn receive(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 mime_type: String,
                 fd: std::os::fd::OwnedFd,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroy the data offer."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the data offer.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Notifies the compositor that the drag destination successfully"]
-            #[doc = "finished the drag-and-drop operation."]
-            #[doc = ""]
-            #[doc = "Upon receiving this request, the compositor will emit"]
-            #[doc = "wl_data_source.dnd_finished on the drag source client."]
-            #[doc = ""]
-            #[doc = "It is a client error to perform other requests than"]
-            #[doc = "wl_data_offer.destroy after this one. It is also an error to perform"]
-            #[doc = "this request after a NULL mime type has been set in"]
-            #[doc = "wl_data_offer.accept or no action was received through"]
-            #[doc = "wl_data_offer.action."]
-            #[doc = ""]
-            #[doc = "If wl_data_offer.finish request is received for a non drag and drop"]
-            #[doc = "operation, the invalid_finish protocol error is raised."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the finish message was introduced in
+            const FINISH_SINCE: u32 = 3u32;
+            ///The interface version the finish message was deprecated in, if any
+            const FINISH_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notifies the compositor that the drag destination successfully
+            ///finished the drag-and-drop operation.
+            ///
+            ///Upon receiving this request, the compositor will emit
+            ///wl_data_source.dnd_finished on the drag source client.
+            ///
+            ///It is a client error to perform other requests than
+            ///wl_data_offer.destroy after this one. It is also an error to perform
+            ///this request after a NULL mime type has been set in
+            ///wl_data_offer.accept or no action was received through
+            ///wl_data_offer.action.
+            ///
+            ///If wl_data_offer.finish request is received for a non drag and drop
+            ///operation, the invalid_finish protocol error is raised.
+            ///
+            ///Available since version 3
             fn finish(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sets the actions that the destination side client supports for"]
-            #[doc = "this operation. This request may trigger the emission of"]
-            #[doc = "wl_data_source.action and wl_data_offer.action events if the compositor"]
-            #[doc = "needs to change the selected action."]
-            #[doc = ""]
-            #[doc = "This request can be called multiple times throughout the"]
-            #[doc = "drag-and-drop operation, typically in response to wl_data_device.enter"]
-            #[doc = "or wl_data_device.motion events."]
-            #[doc = ""]
-            #[doc = "This request determines the final result of the drag-and-drop"]
-            #[doc = "operation. If the end result is that no action is accepted,"]
-            #[doc = "the drag source will receive wl_data_source.cancelled."]
-            #[doc = ""]
-            #[doc = "The dnd_actions argument must contain only values expressed in the"]
-            #[doc = "wl_data_device_manager.dnd_actions enum, and the preferred_action"]
-            #[doc = "argument must only contain one of those values set, otherwise it"]
-            #[doc = "will result in a protocol error."]
-            #[doc = ""]
-            #[doc = "While managing an \"ask\" action, the destination drag-and-drop client"]
-            #[doc = "may perform further wl_data_offer.receive requests, and is expected"]
-            #[doc = "to perform one last wl_data_offer.set_actions request with a preferred"]
-            #[doc = "action other than \"ask\" (and optionally wl_data_offer.accept) before"]
-            #[doc = "requesting wl_data_offer.finish, in order to convey the action selected"]
-            #[doc = "by the user. If the preferred action is not in the"]
-            #[doc = "wl_data_offer.source_actions mask, an error will be raised."]
-            #[doc = ""]
-            #[doc = "If the \"ask\" action is dismissed (e.g. user cancellation), the client"]
-            #[doc = "is expected to perform wl_data_offer.destroy right away."]
-            #[doc = ""]
-            #[doc = "This request can only be made on drag-and-drop offers, a protocol error"]
-            #[doc = "will be raised otherwise."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_actions message was introduced in
+            const SET_ACTIONS_SINCE: u32 = 3u32;
+            ///The interface version the set_actions message was deprecated in, if any
+            const SET_ACTIONS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sets the actions that the destination side client supports for
+            ///this operation. This request may trigger the emission of
+            ///wl_data_source.action and wl_data_offer.action events if the compositor
+            ///needs to change the selected action.
+            ///
+            ///This request can be called multiple times throughout the
+            ///drag-and-drop operation, typically in response to wl_data_device.enter
+            ///or wl_data_device.motion events.
+            ///
+            ///This request determines the final result of the drag-and-drop
+            ///operation. If the end result is that no action is accepted,
+            ///the drag source will receive wl_data_source.cancelled.
+            ///
+            ///The dnd_actions argument must contain only values expressed in the
+            ///wl_data_device_manager.dnd_actions enum, and the preferred_action
+            ///argument must only contain one of those values set, otherwise it
+            ///will result in a protocol error.
+            ///
+            ///While managing an "ask" action, the destination drag-and-drop client
+            ///may perform further wl_data_offer.receive requests, and is expected
+            ///to perform one last wl_data_offer.set_actions request with a preferred
+            ///action other than "ask" (and optionally wl_data_offer.accept) before
+            ///requesting wl_data_offer.finish, in order to convey the action selected
+            ///by the user. If the preferred action is not in the
+            ///wl_data_offer.source_actions mask, an error will be raised.
+            ///
+            ///If the "ask" action is dismissed (e.g. user cancellation), the client
+            ///is expected to perform wl_data_offer.destroy right away.
+            ///
+            ///This request can only be made on drag-and-drop offers, a protocol error
+            ///will be raised otherwise.
+            ///
+            ///Available since version 3
             fn set_actions(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-                dnd_actions: super::super::super::core::wayland::wl_data_device_manager::DndAction,
-                preferred_action : super :: super :: super :: core :: wayland :: wl_data_device_manager :: DndAction,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent immediately after creating the wl_data_offer object.  One"]
-            #[doc = "event per offered mime type."]
-            fn offer(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                mime_type: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_offer#{}.offer(\"{}\")", sender_id, mime_type);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(mime_type))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates the actions offered by the data source. It"]
-            #[doc = "will be sent immediately after creating the wl_data_offer object,"]
-            #[doc = "or anytime the source side changes its offered actions through"]
-            #[doc = "wl_data_source.set_actions."]
-            fn source_actions(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                source_actions : super :: super :: super :: core :: wayland :: wl_data_device_manager :: DndAction,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_data_offer#{}.source_actions({})",
-                        sender_id,
-                        source_actions
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(source_actions.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates the action selected by the compositor after"]
-            #[doc = "matching the source/destination side actions. Only one action (or"]
-            #[doc = "none) will be offered here."]
-            #[doc = ""]
-            #[doc = "This event can be emitted multiple times during the drag-and-drop"]
-            #[doc = "operation in response to destination side action changes through"]
-            #[doc = "wl_data_offer.set_actions."]
-            #[doc = ""]
-            #[doc = "This event will no longer be emitted after wl_data_device.drop"]
-            #[doc = "happened on the drag-and-drop destination, the client must"]
-            #[doc = "honor the last action received, or the last preferred one set"]
-            #[doc = "through wl_data_offer.set_actions when handling an \"ask\" action."]
-            #[doc = ""]
-            #[doc = "Compositors may also change the selected action on the fly, mainly"]
-            #[doc = "in response to keyboard modifier changes during the drag-and-drop"]
-            #[doc = "operation."]
-            #[doc = ""]
-            #[doc = "The most recent action received is always the valid one. Prior to"]
-            #[doc = "receiving wl_data_device.drop, the chosen action may change (e.g."]
-            #[doc = "due to keyboard modifiers being pressed). At the time of receiving"]
-            #[doc = "wl_data_device.drop the drag-and-drop destination must honor the"]
-            #[doc = "last action received."]
-            #[doc = ""]
-            #[doc = "Action changes may still happen after wl_data_device.drop,"]
-            #[doc = "especially on \"ask\" actions, where the drag-and-drop destination"]
-            #[doc = "may choose another action afterwards. Action changes happening"]
-            #[doc = "at this stage are always the result of inter-client negotiation, the"]
-            #[doc = "compositor shall no longer be able to induce a different action."]
-            #[doc = ""]
-            #[doc = "Upon \"ask\" actions, it is expected that the drag-and-drop destination"]
-            #[doc = "may potentially choose a different action and/or mime type,"]
-            #[doc = "based on wl_data_offer.source_actions and finally chosen by the"]
-            #[doc = "user (e.g. popping up a menu with the available options). The"]
-            #[doc = "final wl_data_offer.set_actions and wl_data_offer.accept requests"]
-            #[doc = "must happen before the call to wl_data_offer.finish."]
-            fn action(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                dnd_action: super::super::super::core::wayland::wl_data_device_manager::DndAction,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_offer#{}.action({})", sender_id, dnd_action);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(dnd_action.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+                dnd_actions: super::wl_data_device_manager::DndAction,
+                preferred_action: super::wl_data_device_manager::DndAction,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -1507,15 +3138,24 @@ pub mod wayland {
                             let serial = message.uint()?;
                             let mime_type = message.string()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_offer#{}.accept({}, \"{}\")",
-                                sender_id,
-                                serial,
-                                mime_type
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_offer", object_id = % sender_id, opcode = 0u16,
+                                message = "accept", direction = "in", "args.serial" = %
+                                serial, "args.mime_type" = % mime_type.as_ref()
+                                .map_or("null".to_string(), | v | v.to_string()),
                             );
-                            self.accept(connection, sender_id, serial, mime_type).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_offer", object_id : % = sender_id, opcode = 0u16,
+                                message = "accept", direction = "in", "args.serial" : % =
+                                serial, "args.mime_type" : % = mime_type.as_ref()
+                                .map_or("null".to_string(), | v | v.to_string());
+                                "wl_data_offer.accept"
+                            );
+                            self.accept(connection, state, sender_id, serial, mime_type)
+                                .await
                         }
                         1u16 => {
                             let mime_type = message
@@ -1523,68 +3163,163 @@ pub mod wayland {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let fd = waynest::Connection::fd(connection)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_offer#{}.receive(\"{}\", {})",
-                                sender_id,
-                                mime_type,
-                                std::os::fd::AsRawFd::as_raw_fd(&fd)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_offer", object_id = % sender_id, opcode = 1u16,
+                                message = "receive", direction = "in", "args.mime_type" = %
+                                mime_type, "args.fd" = % std::os::fd::AsRawFd::as_raw_fd(&
+                                fd),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_offer", object_id : % = sender_id, opcode = 1u16,
+                                message = "receive", direction = "in", "args.mime_type" : %
+                                = mime_type, "args.fd" : % =
+                                std::os::fd::AsRawFd::as_raw_fd(& fd);
+                                "wl_data_offer.receive"
                             );
-                            self.receive(connection, sender_id, mime_type, fd).await
+                            self.receive(connection, state, sender_id, mime_type, fd)
+                                .await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_data_offer#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_offer", object_id = % sender_id, opcode = 2u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_offer", object_id : % = sender_id, opcode = 2u16,
+                                message = "destroy", direction = "in";
+                                "wl_data_offer.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         3u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_data_offer#{}.finish()", sender_id,);
-                            self.finish(connection, sender_id).await
-                        }
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_offer", object_id = % sender_id, opcode = 3u16,
+                                message = "finish", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_offer", object_id : % = sender_id, opcode = 3u16,
+                                message = "finish", direction = "in"; "wl_data_offer.finish"
+                            );
+                            self.finish(connection, state, sender_id).await
+                        }
                         4u16 => {
-                            let dnd_actions = message.uint()?;
-                            let preferred_action = message.uint()?;
+                            let dnd_actions: super::wl_data_device_manager::DndAction = message
+                                .uint()?
+                                .try_into()?;
+                            let preferred_action: super::wl_data_device_manager::DndAction = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_offer#{}.set_actions({}, {})",
-                                sender_id,
-                                dnd_actions,
-                                preferred_action
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_offer", object_id = % sender_id, opcode = 4u16,
+                                message = "set_actions", direction = "in",
+                                "args.dnd_actions" = % dnd_actions, "args.preferred_action"
+                                = % preferred_action,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_offer", object_id : % = sender_id, opcode = 4u16,
+                                message = "set_actions", direction = "in",
+                                "args.dnd_actions" : % = dnd_actions,
+                                "args.preferred_action" : % = preferred_action;
+                                "wl_data_offer.set_actions"
                             );
                             self.set_actions(
-                                connection,
-                                sender_id,
-                                dnd_actions.try_into()?,
-                                preferred_action.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    dnd_actions,
+                                    preferred_action,
+                                )
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The wl_data_source object is the source side of a wl_data_offer."]
-    #[doc = "It is created by the source client in a data transfer and"]
-    #[doc = "provides a way to describe the offered data and a way to respond"]
-    #[doc = "to requests to transfer the data."]
+    ///The wl_data_source object is the source side of a wl_data_offer.
+    ///It is created by the source client in a data transfer and
+    ///provides a way to describe the offered data and a way to respond
+    ///to requests to transfer the data.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_data_source {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "action mask contains invalid values"]
+            ///action mask contains invalid values
             InvalidActionMask = 0u32,
-            #[doc = "source doesn't accept this request"]
+            ///source doesn't accept this request
             InvalidSource = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidActionMask => 1u32,
+                    Self::InvalidSource => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidActionMask => None,
+                    Self::InvalidSource => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_action_mask", Self::InvalidActionMask),
+                ("invalid_source", Self::InvalidSource),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidActionMask => "invalid_action_mask",
+                    Self::InvalidSource => "invalid_source",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1595,255 +3330,400 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_data_source interface. See the module level documentation for more info"]
-        pub trait WlDataSource
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wl_data_source";
-            const VERSION: u32 = 4u32;
-            #[doc = "This request adds a mime type to the set of mime types"]
-            #[doc = "advertised to targets.  Can be called several times to offer"]
-            #[doc = "multiple types."]
-            fn offer(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                mime_type: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroy the data source."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sets the actions that the source side client supports for this"]
-            #[doc = "operation. This request may trigger wl_data_source.action and"]
-            #[doc = "wl_data_offer.action events if the compositor needs to change the"]
-            #[doc = "selected action."]
-            #[doc = ""]
-            #[doc = "The dnd_actions argument must contain only values expressed in the"]
-            #[doc = "wl_data_device_manager.dnd_actions enum, otherwise it will result"]
-            #[doc = "in a protocol error."]
-            #[doc = ""]
-            #[doc = "This request must be made once only, and can only be made on sources"]
-            #[doc = "used in drag-and-drop, so it must be performed before"]
-            #[doc = "wl_data_device.start_drag. Attempting to use the source other than"]
-            #[doc = "for drag-and-drop will raise a protocol error."]
-            fn set_actions(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                dnd_actions: super::super::super::core::wayland::wl_data_device_manager::DndAction,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent when a target accepts pointer_focus or motion events.  If"]
-            #[doc = "a target does not accept any of the offered types, type is NULL."]
-            #[doc = ""]
-            #[doc = "Used for feedback during drag-and-drop."]
-            fn target(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_actions",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "dnd_actions",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_data_device_manager.dnd_action"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_data_source interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the target message was introduced in
+            pub const TARGET_SINCE: u32 = 1u32;
+            ///The interface version the target message was deprecated in, if any
+            pub const TARGET_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent when a target accepts pointer_focus or motion events.  If
+            ///a target does not accept any of the offered types, type is NULL.
+            ///
+            ///Used for feedback during drag-and-drop.
+            pub fn target<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 mime_type: Option<String>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_data_source#{}.target(\"{}\")",
-                        sender_id,
-                        mime_type
-                            .as_ref()
-                            .map_or("null".to_string(), |v| v.to_string())
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_string(mime_type).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_source", object_id = % sender_id, opcode = 0u16, message =
+                    "target", direction = "out", "args.mime_type" = % mime_type.as_ref()
+                    .map_or("null".to_string(), | v | v.to_string()),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_source",
+                    object_id : % = sender_id, opcode = 0u16, message = "target",
+                    direction = "out", "args.mime_type" : % = mime_type.as_ref()
+                    .map_or("null".to_string(), | v | v.to_string());
+                    "wl_data_source.target"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(mime_type)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Request for data from the client.  Send the data as the"]
-            #[doc = "specified mime type over the passed file descriptor, then"]
-            #[doc = "close it."]
-            fn send(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the send message was introduced in
+            pub const SEND_SINCE: u32 = 1u32;
+            ///The interface version the send message was deprecated in, if any
+            pub const SEND_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request for data from the client.  Send the data as the
+            ///specified mime type over the passed file descriptor, then
+            ///close it.
+            pub fn send<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 mime_type: String,
                 fd: std::os::fd::OwnedFd,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_data_source#{}.send(\"{}\", {})",
-                        sender_id,
-                        mime_type,
-                        std::os::fd::AsRawFd::as_raw_fd(&fd)
-                    );
-                    waynest::Connection::push_fd(connection, fd);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(mime_type))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_source", object_id = % sender_id, opcode = 1u16, message =
+                    "send", direction = "out", "args.mime_type" = % mime_type, "args.fd"
+                    = % std::os::fd::AsRawFd::as_raw_fd(& fd),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_source",
+                    object_id : % = sender_id, opcode = 1u16, message = "send", direction
+                    = "out", "args.mime_type" : % = mime_type, "args.fd" : % =
+                    std::os::fd::AsRawFd::as_raw_fd(& fd); "wl_data_source.send"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(mime_type))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![fd],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This data source is no longer valid. There are several reasons why"]
-            #[doc = "this could happen:"]
-            #[doc = ""]
-            #[doc = "- The data source has been replaced by another data source."]
-            #[doc = "- The drag-and-drop operation was performed, but the drop destination"]
-            #[doc = "did not accept any of the mime types offered through"]
-            #[doc = "wl_data_source.target."]
-            #[doc = "- The drag-and-drop operation was performed, but the drop destination"]
-            #[doc = "did not select any of the actions present in the mask offered through"]
-            #[doc = "wl_data_source.action."]
-            #[doc = "- The drag-and-drop operation was performed but didn't happen over a"]
-            #[doc = "surface."]
-            #[doc = "- The compositor cancelled the drag-and-drop operation (e.g. compositor"]
-            #[doc = "dependent timeouts to avoid stale drag-and-drop transfers)."]
-            #[doc = ""]
-            #[doc = "The client should clean up and destroy this data source."]
-            #[doc = ""]
-            #[doc = "For objects of version 2 or older, wl_data_source.cancelled will"]
-            #[doc = "only be emitted if the data source was replaced by another data"]
-            #[doc = "source."]
-            fn cancelled(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_source#{}.cancelled()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the cancelled message was introduced in
+            pub const CANCELLED_SINCE: u32 = 1u32;
+            ///The interface version the cancelled message was deprecated in, if any
+            pub const CANCELLED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This data source is no longer valid. There are several reasons why
+            ///this could happen:
+            ///
+            ///- The data source has been replaced by another data source.
+            ///- The drag-and-drop operation was performed, but the drop destination
+            ///did not accept any of the mime types offered through
+            ///wl_data_source.target.
+            ///- The drag-and-drop operation was performed, but the drop destination
+            ///did not select any of the actions present in the mask offered through
+            ///wl_data_source.action.
+            ///- The drag-and-drop operation was performed but didn't happen over a
+            ///surface.
+            ///- The compositor cancelled the drag-and-drop operation (e.g. compositor
+            ///dependent timeouts to avoid stale drag-and-drop transfers).
+            ///
+            ///The client should clean up and destroy this data source.
+            ///
+            ///For objects of version 2 or older, wl_data_source.cancelled will
+            ///only be emitted if the data source was replaced by another data
+            ///source.
+            pub fn cancelled<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_source", object_id = % sender_id, opcode = 2u16, message =
+                    "cancelled", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_source",
+                    object_id : % = sender_id, opcode = 2u16, message = "cancelled",
+                    direction = "out"; "wl_data_source.cancelled"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The user performed the drop action. This event does not indicate"]
-            #[doc = "acceptance, wl_data_source.cancelled may still be emitted afterwards"]
-            #[doc = "if the drop destination does not accept any mime type."]
-            #[doc = ""]
-            #[doc = "However, this event might however not be received if the compositor"]
-            #[doc = "cancelled the drag-and-drop operation before this event could happen."]
-            #[doc = ""]
-            #[doc = "Note that the data_source may still be used in the future and should"]
-            #[doc = "not be destroyed here."]
-            fn dnd_drop_performed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_source#{}.dnd_drop_performed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the dnd_drop_performed message was introduced in
+            pub const DND_DROP_PERFORMED_SINCE: u32 = 3u32;
+            ///The interface version the dnd_drop_performed message was deprecated in, if any
+            pub const DND_DROP_PERFORMED_DEPRECATED_SINCE: Option<u32> = None;
+            ///The user performed the drop action. This event does not indicate
+            ///acceptance, wl_data_source.cancelled may still be emitted afterwards
+            ///if the drop destination does not accept any mime type.
+            ///
+            ///However, this event might however not be received if the compositor
+            ///cancelled the drag-and-drop operation before this event could happen.
+            ///
+            ///Note that the data_source may still be used in the future and should
+            ///not be destroyed here.
+            ///
+            ///Available since version 3
+            pub fn dnd_drop_performed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_source", object_id = % sender_id, opcode = 3u16, message =
+                    "dnd_drop_performed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_source",
+                    object_id : % = sender_id, opcode = 3u16, message =
+                    "dnd_drop_performed", direction = "out";
+                    "wl_data_source.dnd_drop_performed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The drop destination finished interoperating with this data"]
-            #[doc = "source, so the client is now free to destroy this data source and"]
-            #[doc = "free all associated data."]
-            #[doc = ""]
-            #[doc = "If the action used to perform the operation was \"move\", the"]
-            #[doc = "source can now delete the transferred data."]
-            fn dnd_finished(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_source#{}.dnd_finished()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the dnd_finished message was introduced in
+            pub const DND_FINISHED_SINCE: u32 = 3u32;
+            ///The interface version the dnd_finished message was deprecated in, if any
+            pub const DND_FINISHED_DEPRECATED_SINCE: Option<u32> = None;
+            ///The drop destination finished interoperating with this data
+            ///source, so the client is now free to destroy this data source and
+            ///free all associated data.
+            ///
+            ///If the action used to perform the operation was "move", the
+            ///source can now delete the transferred data.
+            ///
+            ///Available since version 3
+            pub fn dnd_finished<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_source", object_id = % sender_id, opcode = 4u16, message =
+                    "dnd_finished", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_source",
+                    object_id : % = sender_id, opcode = 4u16, message = "dnd_finished",
+                    direction = "out"; "wl_data_source.dnd_finished"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event indicates the action selected by the compositor after"]
-            #[doc = "matching the source/destination side actions. Only one action (or"]
-            #[doc = "none) will be offered here."]
-            #[doc = ""]
-            #[doc = "This event can be emitted multiple times during the drag-and-drop"]
-            #[doc = "operation, mainly in response to destination side changes through"]
-            #[doc = "wl_data_offer.set_actions, and as the data device enters/leaves"]
-            #[doc = "surfaces."]
-            #[doc = ""]
-            #[doc = "It is only possible to receive this event after"]
-            #[doc = "wl_data_source.dnd_drop_performed if the drag-and-drop operation"]
-            #[doc = "ended in an \"ask\" action, in which case the final wl_data_source.action"]
-            #[doc = "event will happen immediately before wl_data_source.dnd_finished."]
-            #[doc = ""]
-            #[doc = "Compositors may also change the selected action on the fly, mainly"]
-            #[doc = "in response to keyboard modifier changes during the drag-and-drop"]
-            #[doc = "operation."]
-            #[doc = ""]
-            #[doc = "The most recent action received is always the valid one. The chosen"]
-            #[doc = "action may change alongside negotiation (e.g. an \"ask\" action can turn"]
-            #[doc = "into a \"move\" operation), so the effects of the final action must"]
-            #[doc = "always be applied in wl_data_offer.dnd_finished."]
-            #[doc = ""]
-            #[doc = "Clients can trigger cursor surface changes from this point, so"]
-            #[doc = "they reflect the current action."]
-            fn action(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                dnd_action: super::super::super::core::wayland::wl_data_device_manager::DndAction,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_source#{}.action({})", sender_id, dnd_action);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(dnd_action.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the action message was introduced in
+            pub const ACTION_SINCE: u32 = 3u32;
+            ///The interface version the action message was deprecated in, if any
+            pub const ACTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates the action selected by the compositor after
+            ///matching the source/destination side actions. Only one action (or
+            ///none) will be offered here.
+            ///
+            ///This event can be emitted multiple times during the drag-and-drop
+            ///operation, mainly in response to destination side changes through
+            ///wl_data_offer.set_actions, and as the data device enters/leaves
+            ///surfaces.
+            ///
+            ///It is only possible to receive this event after
+            ///wl_data_source.dnd_drop_performed if the drag-and-drop operation
+            ///ended in an "ask" action, in which case the final wl_data_source.action
+            ///event will happen immediately before wl_data_source.dnd_finished.
+            ///
+            ///Compositors may also change the selected action on the fly, mainly
+            ///in response to keyboard modifier changes during the drag-and-drop
+            ///operation.
+            ///
+            ///The most recent action received is always the valid one. The chosen
+            ///action may change alongside negotiation (e.g. an "ask" action can turn
+            ///into a "move" operation), so the effects of the final action must
+            ///always be applied in wl_data_offer.dnd_finished.
+            ///
+            ///Clients can trigger cursor surface changes from this point, so
+            ///they reflect the current action.
+            ///
+            ///Available since version 3
+            pub fn action<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                dnd_action: super::wl_data_device_manager::DndAction,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_source", object_id = % sender_id, opcode = 5u16, message =
+                    "action", direction = "out", "args.dnd_action" = % dnd_action,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_source",
+                    object_id : % = sender_id, opcode = 5u16, message = "action",
+                    direction = "out", "args.dnd_action" : % = dnd_action;
+                    "wl_data_source.action"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(dnd_action.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wl_data_source interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_data_source` interface",
+            label = "`WlDataSource` is not implemented for `{Self}`"
+        )]
+        pub trait WlDataSource
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wl_data_source";
+            const VERSION: u32 = 4u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the offer message was introduced in
+            const OFFER_SINCE: u32 = 1u32;
+            ///The interface version the offer message was deprecated in, if any
+            const OFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request adds a mime type to the set of mime types
+            ///advertised to targets.  Can be called several times to offer
+            ///multiple types.
+            fn offer(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                mime_type: String,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the data source.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_actions message was introduced in
+            const SET_ACTIONS_SINCE: u32 = 3u32;
+            ///The interface version the set_actions message was deprecated in, if any
+            const SET_ACTIONS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sets the actions that the source side client supports for this
+            ///operation. This request may trigger wl_data_source.action and
+            ///wl_data_offer.action events if the compositor needs to change the
+            ///selected action.
+            ///
+            ///The dnd_actions argument must contain only values expressed in the
+            ///wl_data_device_manager.dnd_actions enum, otherwise it will result
+            ///in a protocol error.
+            ///
+            ///This request must be made once only, and can only be made on sources
+            ///used in drag-and-drop, so it must be performed before
+            ///wl_data_device.start_drag. Attempting to use the source other than
+            ///for drag-and-drop will raise a protocol error.
+            ///
+            ///Available since version 3
+            fn set_actions(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                dnd_actions: super::wl_data_device_manager::DndAction,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -1852,56 +3732,134 @@ pub mod wayland {
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_source#{}.offer(\"{}\")",
-                                sender_id,
-                                mime_type
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_source", object_id = % sender_id, opcode = 0u16,
+                                message = "offer", direction = "in", "args.mime_type" = %
+                                mime_type,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_source", object_id : % = sender_id, opcode = 0u16,
+                                message = "offer", direction = "in", "args.mime_type" : % =
+                                mime_type; "wl_data_source.offer"
                             );
-                            self.offer(connection, sender_id, mime_type).await
+                            self.offer(connection, state, sender_id, mime_type).await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_data_source#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_source", object_id = % sender_id, opcode = 1u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_source", object_id : % = sender_id, opcode = 1u16,
+                                message = "destroy", direction = "in";
+                                "wl_data_source.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         2u16 => {
-                            let dnd_actions = message.uint()?;
+                            let dnd_actions: super::wl_data_device_manager::DndAction = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_source#{}.set_actions({})",
-                                sender_id,
-                                dnd_actions
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_source", object_id = % sender_id, opcode = 2u16,
+                                message = "set_actions", direction = "in",
+                                "args.dnd_actions" = % dnd_actions,
                             );
-                            self.set_actions(connection, sender_id, dnd_actions.try_into()?)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_source", object_id : % = sender_id, opcode = 2u16,
+                                message = "set_actions", direction = "in",
+                                "args.dnd_actions" : % = dnd_actions;
+                                "wl_data_source.set_actions"
+                            );
+                            self.set_actions(connection, state, sender_id, dnd_actions)
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "There is one wl_data_device per seat which can be obtained"]
-    #[doc = "from the global wl_data_device_manager singleton."]
-    #[doc = ""]
-    #[doc = "A wl_data_device provides access to inter-client data transfer"]
-    #[doc = "mechanisms such as copy-and-paste and drag-and-drop."]
+    ///There is one wl_data_device per seat which can be obtained
+    ///from the global wl_data_device_manager singleton.
+    ///
+    ///A wl_data_device provides access to inter-client data transfer
+    ///mechanisms such as copy-and-paste and drag-and-drop.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_data_device {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "given wl_surface has another role"]
+            ///given wl_surface has another role
             Role = 0u32,
-            #[doc = "source has already been used"]
+            ///source has already been used
             UsedSource = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Role => 1u32,
+                    Self::UsedSource => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Role => None,
+                    Self::UsedSource => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("role", Self::Role),
+                ("used_source", Self::UsedSource),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Role => "role",
+                    Self::UsedSource => "used_source",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1912,274 +3870,437 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_data_device interface. See the module level documentation for more info"]
-        pub trait WlDataDevice
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wl_data_device";
-            const VERSION: u32 = 4u32;
-            #[doc = "This request asks the compositor to start a drag-and-drop"]
-            #[doc = "operation on behalf of the client."]
-            #[doc = ""]
-            #[doc = "The source argument is the data source that provides the data"]
-            #[doc = "for the eventual data transfer. If source is NULL, enter, leave"]
-            #[doc = "and motion events are sent only to the client that initiated the"]
-            #[doc = "drag and the client is expected to handle the data passing"]
-            #[doc = "internally. If source is destroyed, the drag-and-drop session will be"]
-            #[doc = "cancelled."]
-            #[doc = ""]
-            #[doc = "The origin surface is the surface where the drag originates and"]
-            #[doc = "the client must have an active implicit grab that matches the"]
-            #[doc = "serial."]
-            #[doc = ""]
-            #[doc = "The icon surface is an optional (can be NULL) surface that"]
-            #[doc = "provides an icon to be moved around with the cursor.  Initially,"]
-            #[doc = "the top-left corner of the icon surface is placed at the cursor"]
-            #[doc = "hotspot, but subsequent wl_surface.offset requests can move the"]
-            #[doc = "relative position. Attach requests must be confirmed with"]
-            #[doc = "wl_surface.commit as usual. The icon surface is given the role of"]
-            #[doc = "a drag-and-drop icon. If the icon surface already has another role,"]
-            #[doc = "it raises a protocol error."]
-            #[doc = ""]
-            #[doc = "The input region is ignored for wl_surfaces with the role of a"]
-            #[doc = "drag-and-drop icon."]
-            #[doc = ""]
-            #[doc = "The given source may not be used in any further set_selection or"]
-            #[doc = "start_drag requests. Attempting to reuse a previously-used source"]
-            #[doc = "may send a used_source error."]
-            fn start_drag(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                source: Option<waynest::ObjectId>,
-                origin: waynest::ObjectId,
-                icon: Option<waynest::ObjectId>,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request asks the compositor to set the selection"]
-            #[doc = "to the data from the source on behalf of the client."]
-            #[doc = ""]
-            #[doc = "To unset the selection, set the source to NULL."]
-            #[doc = ""]
-            #[doc = "The given source may not be used in any further set_selection or"]
-            #[doc = "start_drag requests. Attempting to reuse a previously-used source"]
-            #[doc = "may send a used_source error."]
-            fn set_selection(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                source: Option<waynest::ObjectId>,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request destroys the data device."]
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The data_offer event introduces a new wl_data_offer object,"]
-            #[doc = "which will subsequently be used in either the"]
-            #[doc = "data_device.enter event (for drag-and-drop) or the"]
-            #[doc = "data_device.selection event (for selections).  Immediately"]
-            #[doc = "following the data_device.data_offer event, the new data_offer"]
-            #[doc = "object will send out data_offer.offer events to describe the"]
-            #[doc = "mime types it offers."]
-            fn data_offer(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "start_drag",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?oo?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "origin",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "icon",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "2",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_data_device interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the data_offer message was introduced in
+            pub const DATA_OFFER_SINCE: u32 = 1u32;
+            ///The interface version the data_offer message was deprecated in, if any
+            pub const DATA_OFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///The data_offer event introduces a new wl_data_offer object,
+            ///which will subsequently be used in either the
+            ///data_device.enter event (for drag-and-drop) or the
+            ///data_device.selection event (for selections).  Immediately
+            ///following the data_device.data_offer event, the new data_offer
+            ///object will send out data_offer.offer events to describe the
+            ///mime types it offers.
+            pub fn data_offer<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_device#{}.data_offer({})", sender_id, id);
-                    let payload = waynest::PayloadBuilder::new().put_object(Some(id)).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_device", object_id = % sender_id, opcode = 0u16, message =
+                    "data_offer", direction = "out", "args.id" = % id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_device",
+                    object_id : % = sender_id, opcode = 0u16, message = "data_offer",
+                    direction = "out", "args.id" : % = id; "wl_data_device.data_offer"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(id))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent when an active drag-and-drop pointer enters"]
-            #[doc = "a surface owned by the client.  The position of the pointer at"]
-            #[doc = "enter time is provided by the x and y arguments, in surface-local"]
-            #[doc = "coordinates."]
-            fn enter(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the enter message was introduced in
+            pub const ENTER_SINCE: u32 = 1u32;
+            ///The interface version the enter message was deprecated in, if any
+            pub const ENTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent when an active drag-and-drop pointer enters
+            ///a surface owned by the client.  The position of the pointer at
+            ///enter time is provided by the x and y arguments, in surface-local
+            ///coordinates.
+            pub fn enter<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 surface: waynest::ObjectId,
                 x: waynest::Fixed,
                 y: waynest::Fixed,
                 id: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_data_device#{}.enter({}, {}, {}, {}, {})",
-                        sender_id,
-                        serial,
-                        surface,
-                        x,
-                        y,
-                        id.as_ref().map_or("null".to_string(), |v| v.to_string())
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(surface))
-                        .put_fixed(x)
-                        .put_fixed(y)
-                        .put_object(id)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_device", object_id = % sender_id, opcode = 1u16, message =
+                    "enter", direction = "out", "args.serial" = % serial, "args.surface"
+                    = % surface, "args.x" = % x, "args.y" = % y, "args.id" = % id
+                    .as_ref().map_or("null".to_string(), | v | v.to_string()),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_device",
+                    object_id : % = sender_id, opcode = 1u16, message = "enter",
+                    direction = "out", "args.serial" : % = serial, "args.surface" : % =
+                    surface, "args.x" : % = x, "args.y" : % = y, "args.id" : % = id
+                    .as_ref().map_or("null".to_string(), | v | v.to_string());
+                    "wl_data_device.enter"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(surface))
+                    .put_fixed(x)
+                    .put_fixed(y)
+                    .put_object(id)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent when the drag-and-drop pointer leaves the"]
-            #[doc = "surface and the session ends.  The client must destroy the"]
-            #[doc = "wl_data_offer introduced at enter time at this point."]
-            fn leave(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_device#{}.leave()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the leave message was introduced in
+            pub const LEAVE_SINCE: u32 = 1u32;
+            ///The interface version the leave message was deprecated in, if any
+            pub const LEAVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent when the drag-and-drop pointer leaves the
+            ///surface and the session ends.  The client must destroy the
+            ///wl_data_offer introduced at enter time at this point.
+            pub fn leave<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_device", object_id = % sender_id, opcode = 2u16, message =
+                    "leave", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_device",
+                    object_id : % = sender_id, opcode = 2u16, message = "leave",
+                    direction = "out"; "wl_data_device.leave"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent when the drag-and-drop pointer moves within"]
-            #[doc = "the currently focused surface. The new position of the pointer"]
-            #[doc = "is provided by the x and y arguments, in surface-local"]
-            #[doc = "coordinates."]
-            fn motion(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the motion message was introduced in
+            pub const MOTION_SINCE: u32 = 1u32;
+            ///The interface version the motion message was deprecated in, if any
+            pub const MOTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent when the drag-and-drop pointer moves within
+            ///the currently focused surface. The new position of the pointer
+            ///is provided by the x and y arguments, in surface-local
+            ///coordinates.
+            pub fn motion<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 x: waynest::Fixed,
                 y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_data_device#{}.motion({}, {}, {})",
-                        sender_id,
-                        time,
-                        x,
-                        y
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_fixed(x)
-                        .put_fixed(y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_device", object_id = % sender_id, opcode = 3u16, message =
+                    "motion", direction = "out", "args.time" = % time, "args.x" = % x,
+                    "args.y" = % y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_device",
+                    object_id : % = sender_id, opcode = 3u16, message = "motion",
+                    direction = "out", "args.time" : % = time, "args.x" : % = x, "args.y"
+                    : % = y; "wl_data_device.motion"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_fixed(x)
+                    .put_fixed(y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The event is sent when a drag-and-drop operation is ended"]
-            #[doc = "because the implicit grab is removed."]
-            #[doc = ""]
-            #[doc = "The drag-and-drop destination is expected to honor the last action"]
-            #[doc = "received through wl_data_offer.action, if the resulting action is"]
-            #[doc = "\"copy\" or \"move\", the destination can still perform"]
-            #[doc = "wl_data_offer.receive requests, and is expected to end all"]
-            #[doc = "transfers with a wl_data_offer.finish request."]
-            #[doc = ""]
-            #[doc = "If the resulting action is \"ask\", the action will not be considered"]
-            #[doc = "final. The drag-and-drop destination is expected to perform one last"]
-            #[doc = "wl_data_offer.set_actions request, or wl_data_offer.destroy in order"]
-            #[doc = "to cancel the operation."]
-            fn drop(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_data_device#{}.drop()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the drop message was introduced in
+            pub const DROP_SINCE: u32 = 1u32;
+            ///The interface version the drop message was deprecated in, if any
+            pub const DROP_DEPRECATED_SINCE: Option<u32> = None;
+            ///The event is sent when a drag-and-drop operation is ended
+            ///because the implicit grab is removed.
+            ///
+            ///The drag-and-drop destination is expected to honor the last action
+            ///received through wl_data_offer.action, if the resulting action is
+            ///"copy" or "move", the destination can still perform
+            ///wl_data_offer.receive requests, and is expected to end all
+            ///transfers with a wl_data_offer.finish request.
+            ///
+            ///If the resulting action is "ask", the action will not be considered
+            ///final. The drag-and-drop destination is expected to perform one last
+            ///wl_data_offer.set_actions request, or wl_data_offer.destroy in order
+            ///to cancel the operation.
+            pub fn drop<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_device", object_id = % sender_id, opcode = 4u16, message =
+                    "drop", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_device",
+                    object_id : % = sender_id, opcode = 4u16, message = "drop", direction
+                    = "out"; "wl_data_device.drop"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The selection event is sent out to notify the client of a new"]
-            #[doc = "wl_data_offer for the selection for this device.  The"]
-            #[doc = "data_device.data_offer and the data_offer.offer events are"]
-            #[doc = "sent out immediately before this event to introduce the data"]
-            #[doc = "offer object.  The selection event is sent to a client"]
-            #[doc = "immediately before receiving keyboard focus and when a new"]
-            #[doc = "selection is set while the client has keyboard focus.  The"]
-            #[doc = "data_offer is valid until a new data_offer or NULL is received"]
-            #[doc = "or until the client loses keyboard focus.  Switching surface with"]
-            #[doc = "keyboard focus within the same client doesn't mean a new selection"]
-            #[doc = "will be sent.  The client must destroy the previous selection"]
-            #[doc = "data_offer, if any, upon receiving this event."]
-            fn selection(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the selection message was introduced in
+            pub const SELECTION_SINCE: u32 = 1u32;
+            ///The interface version the selection message was deprecated in, if any
+            pub const SELECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///The selection event is sent out to notify the client of a new
+            ///wl_data_offer for the selection for this device.  The
+            ///data_device.data_offer and the data_offer.offer events are
+            ///sent out immediately before this event to introduce the data
+            ///offer object.  The selection event is sent to a client
+            ///immediately before receiving keyboard focus and when a new
+            ///selection is set while the client has keyboard focus.  The
+            ///data_offer is valid until a new data_offer or NULL is received
+            ///or until the client loses keyboard focus.  Switching surface with
+            ///keyboard focus within the same client doesn't mean a new selection
+            ///will be sent.  The client must destroy the previous selection
+            ///data_offer, if any, upon receiving this event.
+            pub fn selection<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_data_device#{}.selection({})",
-                        sender_id,
-                        id.as_ref().map_or("null".to_string(), |v| v.to_string())
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_object(id).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_data_device", object_id = % sender_id, opcode = 5u16, message =
+                    "selection", direction = "out", "args.id" = % id.as_ref()
+                    .map_or("null".to_string(), | v | v.to_string()),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_data_device",
+                    object_id : % = sender_id, opcode = 5u16, message = "selection",
+                    direction = "out", "args.id" : % = id.as_ref().map_or("null"
+                    .to_string(), | v | v.to_string()); "wl_data_device.selection"
+                );
+                let payload = waynest::PayloadBuilder::new().put_object(id).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wl_data_device interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_data_device` interface",
+            label = "`WlDataDevice` is not implemented for `{Self}`"
+        )]
+        pub trait WlDataDevice
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wl_data_device";
+            const VERSION: u32 = 4u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the start_drag message was introduced in
+            const START_DRAG_SINCE: u32 = 1u32;
+            ///The interface version the start_drag message was deprecated in, if any
+            const START_DRAG_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request asks the compositor to start a drag-and-drop
+            ///operation on behalf of the client.
+            ///
+            ///The source argument is the data source that provides the data
+            ///for the eventual data transfer. If source is NULL, enter, leave
+            ///and motion events are sent only to the client that initiated the
+            ///drag and the client is expected to handle the data passing
+            ///internally. If source is destroyed, the drag-and-drop session will be
+            ///cancelled.
+            ///
+            ///The origin surface is the surface where the drag originates and
+            ///the client must have an active implicit grab that matches the
+            ///serial.
+            ///
+            ///The icon surface is an optional (can be NULL) surface that
+            ///provides an icon to be moved around with the cursor.  Initially,
+            ///the top-left corner of the icon surface is placed at the cursor
+            ///hotspot, but subsequent wl_surface.offset requests can move the
+            ///relative position. Attach requests must be confirmed with
+            ///wl_surface.commit as usual. The icon surface is given the role of
+            ///a drag-and-drop icon. If the icon surface already has another role,
+            ///it raises a protocol error.
+            ///
+            ///The input region is ignored for wl_surfaces with the role of a
+            ///drag-and-drop icon.
+            ///
+            ///The given source may not be used in any further set_selection or
+            ///start_drag requests. Attempting to reuse a previously-used source
+            ///may send a used_source error.
+            fn start_drag(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                source: Option<waynest::ObjectId>,
+                origin: waynest::ObjectId,
+                icon: Option<waynest::ObjectId>,
+                serial: u32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_selection message was introduced in
+            const SET_SELECTION_SINCE: u32 = 1u32;
+            ///The interface version the set_selection message was deprecated in, if any
+            const SET_SELECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request asks the compositor to set the selection
+            ///to the data from the source on behalf of the client.
+            ///
+            ///To unset the selection, set the source to NULL.
+            ///
+            ///The given source may not be used in any further set_selection or
+            ///start_drag requests. Attempting to reuse a previously-used source
+            ///may send a used_source error.
+            fn set_selection(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                source: Option<waynest::ObjectId>,
+                serial: u32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 2u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request destroys the data device.
+            ///
+            ///Available since version 2
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2191,58 +4312,149 @@ pub mod wayland {
                             let icon = message.object()?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_device#{}.start_drag({}, {}, {}, {})",
-                                sender_id,
-                                source
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                origin,
-                                icon.as_ref().map_or("null".to_string(), |v| v.to_string()),
-                                serial
-                            );
-                            self.start_drag(connection, sender_id, source, origin, icon, serial)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_device", object_id = % sender_id, opcode = 0u16,
+                                message = "start_drag", direction = "in", "args.source" = %
+                                source.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.origin" = % origin, "args.icon" = %
+                                icon.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.serial" = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_device", object_id : % = sender_id, opcode = 0u16,
+                                message = "start_drag", direction = "in", "args.source" : %
+                                = source.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.origin" : % = origin, "args.icon" : % =
+                                icon.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.serial" : % = serial;
+                                "wl_data_device.start_drag"
+                            );
+                            self.start_drag(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    source,
+                                    origin,
+                                    icon,
+                                    serial,
+                                )
                                 .await
                         }
                         1u16 => {
                             let source = message.object()?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_device#{}.set_selection({}, {})",
-                                sender_id,
-                                source
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                serial
-                            );
-                            self.set_selection(connection, sender_id, source, serial)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_device", object_id = % sender_id, opcode = 1u16,
+                                message = "set_selection", direction = "in", "args.source" =
+                                % source.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.serial" = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_device", object_id : % = sender_id, opcode = 1u16,
+                                message = "set_selection", direction = "in", "args.source" :
+                                % = source.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.serial" : % = serial;
+                                "wl_data_device.set_selection"
+                            );
+                            self.set_selection(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    source,
+                                    serial,
+                                )
                                 .await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_data_device#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_device", object_id = % sender_id, opcode = 2u16,
+                                message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_device", object_id : % = sender_id, opcode = 2u16,
+                                message = "release", direction = "in";
+                                "wl_data_device.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The wl_data_device_manager is a singleton global object that"]
-    #[doc = "provides access to inter-client data transfer mechanisms such as"]
-    #[doc = "copy-and-paste and drag-and-drop.  These mechanisms are tied to"]
-    #[doc = "a wl_seat and this interface lets a client get a wl_data_device"]
-    #[doc = "corresponding to a wl_seat."]
-    #[doc = ""]
-    #[doc = "Depending on the version bound, the objects created from the bound"]
-    #[doc = "wl_data_device_manager object will have different requirements for"]
-    #[doc = "functioning properly. See wl_data_source.set_actions,"]
-    #[doc = "wl_data_offer.accept and wl_data_offer.finish for details."]
+    ///The wl_data_device_manager is a singleton global object that
+    ///provides access to inter-client data transfer mechanisms such as
+    ///copy-and-paste and drag-and-drop.  These mechanisms are tied to
+    ///a wl_seat and this interface lets a client get a wl_data_device
+    ///corresponding to a wl_seat.
+    ///
+    ///Depending on the version bound, the objects created from the bound
+    ///wl_data_device_manager object will have different requirements for
+    ///functioning properly. See wl_data_source.set_actions,
+    ///wl_data_offer.accept and wl_data_offer.finish for details.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_data_device_manager {
-        bitflags::bitflags! { # [doc = "This is a bitmask of the available/preferred actions in a"] # [doc = "drag-and-drop operation."] # [doc = ""] # [doc = "In the compositor, the selected action is a result of matching the"] # [doc = "actions offered by the source and destination sides.  \"action\" events"] # [doc = "with a \"none\" action will be sent to both source and destination if"] # [doc = "there is no match. All further checks will effectively happen on"] # [doc = "(source actions ∩ destination actions)."] # [doc = ""] # [doc = "In addition, compositors may also pick different actions in"] # [doc = "reaction to key modifiers being pressed. One common design that"] # [doc = "is used in major toolkits (and the behavior recommended for"] # [doc = "compositors) is:"] # [doc = ""] # [doc = "- If no modifiers are pressed, the first match (in bit order)"] # [doc = "will be used."] # [doc = "- Pressing Shift selects \"move\", if enabled in the mask."] # [doc = "- Pressing Control selects \"copy\", if enabled in the mask."] # [doc = ""] # [doc = "Behavior beyond that is considered implementation-dependent."] # [doc = "Compositors may for example bind other modifiers (like Alt/Meta)"] # [doc = "or drags initiated with other buttons than BTN_LEFT to specific"] # [doc = "actions (e.g. \"ask\")."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct DndAction : u32 { # [doc = "no action"] const None = 0u32 ; # [doc = "copy action"] const Copy = 1u32 ; # [doc = "move action"] const Move = 2u32 ; # [doc = "ask action"] const Ask = 4u32 ; } }
+        bitflags::bitflags! {
+            #[doc = "This is a bitmask of the available/preferred actions in a"] #[doc =
+            "drag-and-drop operation."] #[doc = ""] #[doc =
+            "In the compositor, the selected action is a result of matching the"] #[doc =
+            "actions offered by the source and destination sides.  \"action\" events"]
+            #[doc =
+            "with a \"none\" action will be sent to both source and destination if"]
+            #[doc = "there is no match. All further checks will effectively happen on"]
+            #[doc = "(source actions ∩ destination actions)."] #[doc = ""] #[doc =
+            "In addition, compositors may also pick different actions in"] #[doc =
+            "reaction to key modifiers being pressed. One common design that"] #[doc =
+            "is used in major toolkits (and the behavior recommended for"] #[doc =
+            "compositors) is:"] #[doc = ""] #[doc =
+            "- If no modifiers are pressed, the first match (in bit order)"] #[doc =
+            "will be used."] #[doc =
+            "- Pressing Shift selects \"move\", if enabled in the mask."] #[doc =
+            "- Pressing Control selects \"copy\", if enabled in the mask."] #[doc = ""]
+            #[doc = "Behavior beyond that is considered implementation-dependent."] #[doc
+            = "Compositors may for example bind other modifiers (like Alt/Meta)"] #[doc =
+            "or drags initiated with other buttons than BTN_LEFT to specific"] #[doc =
+            "actions (e.g. \"ask\")."] #[doc = ""] #[doc = "Available since version 3"]
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub
+            struct DndAction : u32 { #[doc = "no action"] const None = 0u32; #[doc =
+            "copy action"] const Copy = 1u32; #[doc = "move action"] const Move = 2u32;
+            #[doc = "ask action"] const Ask = 4u32; }
+        }
+        #[allow(deprecated)]
+        impl DndAction {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 3u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("copy", Self::Copy),
+                ("move", Self::Move),
+                ("ask", Self::Ask),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<DndAction> for u32 {
             fn from(value: DndAction) -> Self {
                 value.bits()
@@ -2256,46 +4468,162 @@ pub mod wayland {
         }
         impl std::fmt::Display for DndAction {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Trait to implement the wl_data_device_manager interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_data_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_data_source"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_data_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_data_device"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "4",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_data_device_manager interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_data_device_manager interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_data_device_manager` interface",
+            label = "`WlDataDeviceManager` is not implemented for `{Self}`"
+        )]
         pub trait WlDataDeviceManager
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_data_device_manager";
             const VERSION: u32 = 4u32;
-            #[doc = "Create a new data source."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_data_source message was introduced in
+            const CREATE_DATA_SOURCE_SINCE: u32 = 1u32;
+            ///The interface version the create_data_source message was deprecated in, if any
+            const CREATE_DATA_SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a new data source.
             fn create_data_source(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Create a new data device for a given seat."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_data_device message was introduced in
+            const GET_DATA_DEVICE_SINCE: u32 = 1u32;
+            ///The interface version the get_data_device message was deprecated in, if any
+            const GET_DATA_DEVICE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a new data device for a given seat.
             fn get_data_device(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 seat: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request destroys the wl_data_device_manager. This has no effect on any other"]
-            #[doc = "objects."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 4u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request destroys the wl_data_device_manager. This has no effect on any other
+            ///objects.
+            ///
+            ///Available since version 4
             fn release(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2304,12 +4632,22 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_device_manager#{}.create_data_source({})",
-                                sender_id,
-                                id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_device_manager", object_id = % sender_id, opcode =
+                                0u16, message = "create_data_source", direction = "in",
+                                "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_device_manager", object_id : % = sender_id, opcode
+                                = 0u16, message = "create_data_source", direction = "in",
+                                "args.id" : % = id;
+                                "wl_data_device_manager.create_data_source"
                             );
-                            self.create_data_source(connection, sender_id, id).await
+                            self.create_data_source(connection, state, sender_id, id)
+                                .await
                         }
                         1u16 => {
                             let id = message
@@ -2319,48 +4657,110 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_data_device_manager#{}.get_data_device({}, {})",
-                                sender_id,
-                                id,
-                                seat
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_device_manager", object_id = % sender_id, opcode =
+                                1u16, message = "get_data_device", direction = "in",
+                                "args.id" = % id, "args.seat" = % seat,
                             );
-                            self.get_data_device(connection, sender_id, id, seat).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_device_manager", object_id : % = sender_id, opcode
+                                = 1u16, message = "get_data_device", direction = "in",
+                                "args.id" : % = id, "args.seat" : % = seat;
+                                "wl_data_device_manager.get_data_device"
+                            );
+                            self.get_data_device(connection, state, sender_id, id, seat)
+                                .await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_data_device_manager#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_data_device_manager", object_id = % sender_id, opcode =
+                                2u16, message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_data_device_manager", object_id : % = sender_id, opcode
+                                = 2u16, message = "release", direction = "in";
+                                "wl_data_device_manager.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This interface is implemented by servers that provide"]
-    #[doc = "desktop-style user interfaces."]
-    #[doc = ""]
-    #[doc = "It allows clients to associate a wl_shell_surface with"]
-    #[doc = "a basic surface."]
-    #[doc = ""]
-    #[doc = "Note! This protocol is deprecated and not intended for production use."]
-    #[doc = "For desktop-style user interfaces, use xdg_shell. Compositors and clients"]
-    #[doc = "should not implement this interface."]
+    ///This interface is implemented by servers that provide
+    ///desktop-style user interfaces.
+    ///
+    ///It allows clients to associate a wl_shell_surface with
+    ///a basic surface.
+    ///
+    ///Note! This protocol is deprecated and not intended for production use.
+    ///For desktop-style user interfaces, use xdg_shell. Compositors and clients
+    ///should not implement this interface.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_shell {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "given wl_surface has another role"]
+            ///given wl_surface has another role
             Role = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Role => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Role => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[("role", Self::Role)];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Role => "role",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2370,38 +4770,92 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_shell interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_shell_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_shell_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_shell interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_shell interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_shell` interface",
+            label = "`WlShell` is not implemented for `{Self}`"
+        )]
         pub trait WlShell
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_shell";
             const VERSION: u32 = 1u32;
-            #[doc = "Create a shell surface for an existing surface. This gives"]
-            #[doc = "the wl_surface the role of a shell surface. If the wl_surface"]
-            #[doc = "already has another role, it raises a protocol error."]
-            #[doc = ""]
-            #[doc = "Only one shell surface can be associated with a given surface."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_shell_surface message was introduced in
+            const GET_SHELL_SURFACE_SINCE: u32 = 1u32;
+            ///The interface version the get_shell_surface message was deprecated in, if any
+            const GET_SHELL_SURFACE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a shell surface for an existing surface. This gives
+            ///the wl_surface the role of a shell surface. If the wl_surface
+            ///already has another role, it raises a protocol error.
+            ///
+            ///Only one shell surface can be associated with a given surface.
             fn get_shell_surface(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2413,35 +4867,89 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell#{}.get_shell_surface({}, {})",
-                                sender_id,
-                                id,
-                                surface
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell", object_id = % sender_id, opcode = 0u16, message
+                                = "get_shell_surface", direction = "in", "args.id" = % id,
+                                "args.surface" = % surface,
                             );
-                            self.get_shell_surface(connection, sender_id, id, surface)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell", object_id : % = sender_id, opcode = 0u16,
+                                message = "get_shell_surface", direction = "in", "args.id" :
+                                % = id, "args.surface" : % = surface;
+                                "wl_shell.get_shell_surface"
+                            );
+                            self.get_shell_surface(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    surface,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "An interface that may be implemented by a wl_surface, for"]
-    #[doc = "implementations that provide a desktop-style user interface."]
-    #[doc = ""]
-    #[doc = "It provides requests to treat surfaces like toplevel, fullscreen"]
-    #[doc = "or popup windows, move, resize or maximize them, associate"]
-    #[doc = "metadata like title and class, etc."]
-    #[doc = ""]
-    #[doc = "On the server side the object is automatically destroyed when"]
-    #[doc = "the related wl_surface is destroyed. On the client side,"]
-    #[doc = "wl_shell_surface_destroy() must be called before destroying"]
-    #[doc = "the wl_surface object."]
+    ///An interface that may be implemented by a wl_surface, for
+    ///implementations that provide a desktop-style user interface.
+    ///
+    ///It provides requests to treat surfaces like toplevel, fullscreen
+    ///or popup windows, move, resize or maximize them, associate
+    ///metadata like title and class, etc.
+    ///
+    ///On the server side the object is automatically destroyed when
+    ///the related wl_surface is destroyed. On the client side,
+    ///wl_shell_surface_destroy() must be called before destroying
+    ///the wl_surface object.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_shell_surface {
-        bitflags::bitflags! { # [doc = "These values are used to indicate which edge of a surface"] # [doc = "is being dragged in a resize operation. The server may"] # [doc = "use this information to adapt its behavior, e.g. choose"] # [doc = "an appropriate cursor image."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Resize : u32 { # [doc = "no edge"] const None = 0u32 ; # [doc = "top edge"] const Top = 1u32 ; # [doc = "bottom edge"] const Bottom = 2u32 ; # [doc = "left edge"] const Left = 4u32 ; # [doc = "top and left edges"] const TopLeft = 5u32 ; # [doc = "bottom and left edges"] const BottomLeft = 6u32 ; # [doc = "right edge"] const Right = 8u32 ; # [doc = "top and right edges"] const TopRight = 9u32 ; # [doc = "bottom and right edges"] const BottomRight = 10u32 ; } }
+        bitflags::bitflags! {
+            #[doc = "These values are used to indicate which edge of a surface"] #[doc =
+            "is being dragged in a resize operation. The server may"] #[doc =
+            "use this information to adapt its behavior, e.g. choose"] #[doc =
+            "an appropriate cursor image."] #[derive(Debug, PartialEq, Eq, PartialOrd,
+            Ord, Hash, Clone, Copy)] pub struct Resize : u32 { #[doc = "no edge"] const
+            None = 0u32; #[doc = "top edge"] const Top = 1u32; #[doc = "bottom edge"]
+            const Bottom = 2u32; #[doc = "left edge"] const Left = 4u32; #[doc =
+            "top and left edges"] const TopLeft = 5u32; #[doc = "bottom and left edges"]
+            const BottomLeft = 6u32; #[doc = "right edge"] const Right = 8u32; #[doc =
+            "top and right edges"] const TopRight = 9u32; #[doc =
+            "bottom and right edges"] const BottomRight = 10u32; }
+        }
+        #[allow(deprecated)]
+        impl Resize {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("top", Self::Top),
+                ("bottom", Self::Bottom),
+                ("left", Self::Left),
+                ("top_left", Self::TopLeft),
+                ("bottom_left", Self::BottomLeft),
+                ("right", Self::Right),
+                ("top_right", Self::TopRight),
+                ("bottom_right", Self::BottomRight),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Resize> for u32 {
             fn from(value: Resize) -> Self {
                 value.bits()
@@ -2455,10 +4963,61 @@ pub mod wayland {
         }
         impl std::fmt::Display for Resize {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
+            }
+        }
+        bitflags::bitflags! {
+            #[doc = "These flags specify details of the expected behaviour"] #[doc =
+            "of transient surfaces. Used in the set_transient request."] #[derive(Debug,
+            PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub struct Transient :
+            u32 { #[doc = "do not set keyboard focus"] const Inactive = 1u32; }
+        }
+        #[allow(deprecated)]
+        impl Transient {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("inactive", Self::Inactive),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
             }
         }
-        bitflags::bitflags! { # [doc = "These flags specify details of the expected behaviour"] # [doc = "of transient surfaces. Used in the set_transient request."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Transient : u32 { # [doc = "do not set keyboard focus"] const Inactive = 1u32 ; } }
         impl From<Transient> for u32 {
             fn from(value: Transient) -> Self {
                 value.bits()
@@ -2472,30 +5031,112 @@ pub mod wayland {
         }
         impl std::fmt::Display for Transient {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Hints to indicate to the compositor how to deal with a conflict"]
-        #[doc = "between the dimensions of the surface and the dimensions of the"]
-        #[doc = "output. The compositor is free to ignore this parameter."]
+        ///Hints to indicate to the compositor how to deal with a conflict
+        ///between the dimensions of the surface and the dimensions of the
+        ///output. The compositor is free to ignore this parameter.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum FullscreenMethod {
-            #[doc = "no preference, apply default policy"]
+            ///no preference, apply default policy
             Default = 0u32,
-            #[doc = "scale, preserve the surface's aspect ratio and center on output"]
+            ///scale, preserve the surface's aspect ratio and center on output
             Scale = 1u32,
-            #[doc = "switch output mode to the smallest mode that can fit the surface, add black borders to compensate size mismatch"]
+            ///switch output mode to the smallest mode that can fit the surface, add black borders to compensate size mismatch
             Driver = 2u32,
-            #[doc = "no upscaling, center on output and add black borders to compensate size mismatch"]
+            ///no upscaling, center on output and add black borders to compensate size mismatch
             Fill = 3u32,
         }
+        #[allow(deprecated)]
+        impl FullscreenMethod {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Default => 1u32,
+                    Self::Scale => 1u32,
+                    Self::Driver => 1u32,
+                    Self::Fill => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Default => None,
+                    Self::Scale => None,
+                    Self::Driver => None,
+                    Self::Fill => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("default", Self::Default),
+                ("scale", Self::Scale),
+                ("driver", Self::Driver),
+                ("fill", Self::Fill),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Default => "default",
+                    Self::Scale => "scale",
+                    Self::Driver => "driver",
+                    Self::Fill => "fill",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<FullscreenMethod> for u32 {
             fn from(value: FullscreenMethod) -> Self {
                 value as u32
             }
         }
+        impl From<FullscreenMethod> for i32 {
+            fn from(value: FullscreenMethod) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for FullscreenMethod {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2508,139 +5149,566 @@ pub mod wayland {
                 }
             }
         }
-        impl std::fmt::Display for FullscreenMethod {
+        impl TryFrom<i32> for FullscreenMethod {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
+        impl std::fmt::Display for FullscreenMethod {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "pong",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "move",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("resize"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_transient",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oiiu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("transient"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "method",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("fullscreen_method"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "framerate",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouoiiu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("transient"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_class",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "class_",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_shell_surface interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the ping message was introduced in
+            pub const PING_SINCE: u32 = 1u32;
+            ///The interface version the ping message was deprecated in, if any
+            pub const PING_DEPRECATED_SINCE: Option<u32> = None;
+            ///Ping a client to check if it is receiving events and sending
+            ///requests. A client is expected to reply with a pong request.
+            pub fn ping<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_shell_surface", object_id = % sender_id, opcode = 0u16, message =
+                    "ping", direction = "out", "args.serial" = % serial,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "wl_shell_surface", object_id : % = sender_id, opcode = 0u16, message
+                    = "ping", direction = "out", "args.serial" : % = serial;
+                    "wl_shell_surface.ping"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the configure message was introduced in
+            pub const CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the configure message was deprecated in, if any
+            pub const CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The configure event asks the client to resize its surface.
+            ///
+            ///The size is a hint, in the sense that the client is free to
+            ///ignore it if it doesn't resize, pick a smaller size (to
+            ///satisfy aspect ratio or resize in steps of NxM pixels).
+            ///
+            ///The edges parameter provides a hint about how the surface
+            ///was resized. The client may use this information to decide
+            ///how to adjust its content to the new size (e.g. a scrolling
+            ///area might adjust its content position to leave the viewable
+            ///content unmoved).
+            ///
+            ///The client is free to dismiss all but the last configure
+            ///event it received.
+            ///
+            ///The width and height arguments specify the size of the window
+            ///in surface-local coordinates.
+            pub fn configure<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                edges: Resize,
+                width: i32,
+                height: i32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_shell_surface", object_id = % sender_id, opcode = 1u16, message =
+                    "configure", direction = "out", "args.edges" = % edges, "args.width"
+                    = % width, "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "wl_shell_surface", object_id : % = sender_id, opcode = 1u16, message
+                    = "configure", direction = "out", "args.edges" : % = edges,
+                    "args.width" : % = width, "args.height" : % = height;
+                    "wl_shell_surface.configure"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(edges.into())
+                    .put_int(width)
+                    .put_int(height)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the popup_done message was introduced in
+            pub const POPUP_DONE_SINCE: u32 = 1u32;
+            ///The interface version the popup_done message was deprecated in, if any
+            pub const POPUP_DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The popup_done event is sent out when a popup grab is broken,
+            ///that is, when the user clicks a surface that doesn't belong
+            ///to the client owning the popup surface.
+            pub fn popup_done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wl_shell_surface", object_id = % sender_id, opcode = 2u16, message =
+                    "popup_done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "wl_shell_surface", object_id : % = sender_id, opcode = 2u16, message
+                    = "popup_done", direction = "out"; "wl_shell_surface.popup_done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the wl_shell_surface interface. See the module level documentation for more info"]
+        ///Trait to implement the wl_shell_surface interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_shell_surface` interface",
+            label = "`WlShellSurface` is not implemented for `{Self}`"
+        )]
         pub trait WlShellSurface
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_shell_surface";
             const VERSION: u32 = 1u32;
-            #[doc = "A client must respond to a ping event with a pong request or"]
-            #[doc = "the client may be deemed unresponsive."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the pong message was introduced in
+            const PONG_SINCE: u32 = 1u32;
+            ///The interface version the pong message was deprecated in, if any
+            const PONG_DEPRECATED_SINCE: Option<u32> = None;
+            ///A client must respond to a ping event with a pong request or
+            ///the client may be deemed unresponsive.
             fn pong(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Start a pointer-driven move of the surface."]
-            #[doc = ""]
-            #[doc = "This request must be used in response to a button press event."]
-            #[doc = "The server may ignore move requests depending on the state of"]
-            #[doc = "the surface (e.g. fullscreen or maximized)."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the move message was introduced in
+            const MOVE_SINCE: u32 = 1u32;
+            ///The interface version the move message was deprecated in, if any
+            const MOVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Start a pointer-driven move of the surface.
+            ///
+            ///This request must be used in response to a button press event.
+            ///The server may ignore move requests depending on the state of
+            ///the surface (e.g. fullscreen or maximized).
             fn r#move(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Start a pointer-driven resizing of the surface."]
-            #[doc = ""]
-            #[doc = "This request must be used in response to a button press event."]
-            #[doc = "The server may ignore resize requests depending on the state of"]
-            #[doc = "the surface (e.g. fullscreen or maximized)."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the resize message was introduced in
+            const RESIZE_SINCE: u32 = 1u32;
+            ///The interface version the resize message was deprecated in, if any
+            const RESIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Start a pointer-driven resizing of the surface.
+            ///
+            ///This request must be used in response to a button press event.
+            ///The server may ignore resize requests depending on the state of
+            ///the surface (e.g. fullscreen or maximized).
             fn resize(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
                 edges: Resize,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Map the surface as a toplevel surface."]
-            #[doc = ""]
-            #[doc = "A toplevel surface is not fullscreen, maximized or transient."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_toplevel message was introduced in
+            const SET_TOPLEVEL_SINCE: u32 = 1u32;
+            ///The interface version the set_toplevel message was deprecated in, if any
+            const SET_TOPLEVEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Map the surface as a toplevel surface.
+            ///
+            ///A toplevel surface is not fullscreen, maximized or transient.
             fn set_toplevel(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Map the surface relative to an existing surface."]
-            #[doc = ""]
-            #[doc = "The x and y arguments specify the location of the upper left"]
-            #[doc = "corner of the surface relative to the upper left corner of the"]
-            #[doc = "parent surface, in surface-local coordinates."]
-            #[doc = ""]
-            #[doc = "The flags argument controls details of the transient behaviour."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_transient message was introduced in
+            const SET_TRANSIENT_SINCE: u32 = 1u32;
+            ///The interface version the set_transient message was deprecated in, if any
+            const SET_TRANSIENT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Map the surface relative to an existing surface.
+            ///
+            ///The x and y arguments specify the location of the upper left
+            ///corner of the surface relative to the upper left corner of the
+            ///parent surface, in surface-local coordinates.
+            ///
+            ///The flags argument controls details of the transient behaviour.
             fn set_transient(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 parent: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 flags: Transient,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Map the surface as a fullscreen surface."]
-            #[doc = ""]
-            #[doc = "If an output parameter is given then the surface will be made"]
-            #[doc = "fullscreen on that output. If the client does not specify the"]
-            #[doc = "output then the compositor will apply its policy - usually"]
-            #[doc = "choosing the output on which the surface has the biggest surface"]
-            #[doc = "area."]
-            #[doc = ""]
-            #[doc = "The client may specify a method to resolve a size conflict"]
-            #[doc = "between the output size and the surface size - this is provided"]
-            #[doc = "through the method parameter."]
-            #[doc = ""]
-            #[doc = "The framerate parameter is used only when the method is set"]
-            #[doc = "to \"driver\", to indicate the preferred framerate. A value of 0"]
-            #[doc = "indicates that the client does not care about framerate.  The"]
-            #[doc = "framerate is specified in mHz, that is framerate of 60000 is 60Hz."]
-            #[doc = ""]
-            #[doc = "A method of \"scale\" or \"driver\" implies a scaling operation of"]
-            #[doc = "the surface, either via a direct scaling operation or a change of"]
-            #[doc = "the output mode. This will override any kind of output scaling, so"]
-            #[doc = "that mapping a surface with a buffer size equal to the mode can"]
-            #[doc = "fill the screen independent of buffer_scale."]
-            #[doc = ""]
-            #[doc = "A method of \"fill\" means we don't scale up the buffer, however"]
-            #[doc = "any output scale is applied. This means that you may run into"]
-            #[doc = "an edge case where the application maps a buffer with the same"]
-            #[doc = "size of the output mode but buffer_scale 1 (thus making a"]
-            #[doc = "surface larger than the output). In this case it is allowed to"]
-            #[doc = "downscale the results to fit the screen."]
-            #[doc = ""]
-            #[doc = "The compositor must reply to this request with a configure event"]
-            #[doc = "with the dimensions for the output on which the surface will"]
-            #[doc = "be made fullscreen."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_fullscreen message was introduced in
+            const SET_FULLSCREEN_SINCE: u32 = 1u32;
+            ///The interface version the set_fullscreen message was deprecated in, if any
+            const SET_FULLSCREEN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Map the surface as a fullscreen surface.
+            ///
+            ///If an output parameter is given then the surface will be made
+            ///fullscreen on that output. If the client does not specify the
+            ///output then the compositor will apply its policy - usually
+            ///choosing the output on which the surface has the biggest surface
+            ///area.
+            ///
+            ///The client may specify a method to resolve a size conflict
+            ///between the output size and the surface size - this is provided
+            ///through the method parameter.
+            ///
+            ///The framerate parameter is used only when the method is set
+            ///to "driver", to indicate the preferred framerate. A value of 0
+            ///indicates that the client does not care about framerate.  The
+            ///framerate is specified in mHz, that is framerate of 60000 is 60Hz.
+            ///
+            ///A method of "scale" or "driver" implies a scaling operation of
+            ///the surface, either via a direct scaling operation or a change of
+            ///the output mode. This will override any kind of output scaling, so
+            ///that mapping a surface with a buffer size equal to the mode can
+            ///fill the screen independent of buffer_scale.
+            ///
+            ///A method of "fill" means we don't scale up the buffer, however
+            ///any output scale is applied. This means that you may run into
+            ///an edge case where the application maps a buffer with the same
+            ///size of the output mode but buffer_scale 1 (thus making a
+            ///surface larger than the output). In this case it is allowed to
+            ///downscale the results to fit the screen.
+            ///
+            ///The compositor must reply to this request with a configure event
+            ///with the dimensions for the output on which the surface will
+            ///be made fullscreen.
             fn set_fullscreen(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 method: FullscreenMethod,
                 framerate: u32,
                 output: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Map the surface as a popup."]
-            #[doc = ""]
-            #[doc = "A popup surface is a transient surface with an added pointer"]
-            #[doc = "grab."]
-            #[doc = ""]
-            #[doc = "An existing implicit grab will be changed to owner-events mode,"]
-            #[doc = "and the popup grab will continue after the implicit grab ends"]
-            #[doc = "(i.e. releasing the mouse button does not cause the popup to"]
-            #[doc = "be unmapped)."]
-            #[doc = ""]
-            #[doc = "The popup grab continues until the window is destroyed or a"]
-            #[doc = "mouse button is pressed in any other client's window. A click"]
-            #[doc = "in any of the client's surfaces is reported as normal, however,"]
-            #[doc = "clicks in other clients' surfaces will be discarded and trigger"]
-            #[doc = "the callback."]
-            #[doc = ""]
-            #[doc = "The x and y arguments specify the location of the upper left"]
-            #[doc = "corner of the surface relative to the upper left corner of the"]
-            #[doc = "parent surface, in surface-local coordinates."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_popup message was introduced in
+            const SET_POPUP_SINCE: u32 = 1u32;
+            ///The interface version the set_popup message was deprecated in, if any
+            const SET_POPUP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Map the surface as a popup.
+            ///
+            ///A popup surface is a transient surface with an added pointer
+            ///grab.
+            ///
+            ///An existing implicit grab will be changed to owner-events mode,
+            ///and the popup grab will continue after the implicit grab ends
+            ///(i.e. releasing the mouse button does not cause the popup to
+            ///be unmapped).
+            ///
+            ///The popup grab continues until the window is destroyed or a
+            ///mouse button is pressed in any other client's window. A click
+            ///in any of the client's surfaces is reported as normal, however,
+            ///clicks in other clients' surfaces will be discarded and trigger
+            ///the callback.
+            ///
+            ///The x and y arguments specify the location of the upper left
+            ///corner of the surface relative to the upper left corner of the
+            ///parent surface, in surface-local coordinates.
             fn set_popup(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
@@ -2648,161 +5716,109 @@ pub mod wayland {
                 x: i32,
                 y: i32,
                 flags: Transient,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Map the surface as a maximized surface."]
-            #[doc = ""]
-            #[doc = "If an output parameter is given then the surface will be"]
-            #[doc = "maximized on that output. If the client does not specify the"]
-            #[doc = "output then the compositor will apply its policy - usually"]
-            #[doc = "choosing the output on which the surface has the biggest surface"]
-            #[doc = "area."]
-            #[doc = ""]
-            #[doc = "The compositor will reply with a configure event telling"]
-            #[doc = "the expected new surface size. The operation is completed"]
-            #[doc = "on the next buffer attach to this surface."]
-            #[doc = ""]
-            #[doc = "A maximized surface typically fills the entire output it is"]
-            #[doc = "bound to, except for desktop elements such as panels. This is"]
-            #[doc = "the main difference between a maximized shell surface and a"]
-            #[doc = "fullscreen shell surface."]
-            #[doc = ""]
-            #[doc = "The details depend on the compositor implementation."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_maximized message was introduced in
+            const SET_MAXIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the set_maximized message was deprecated in, if any
+            const SET_MAXIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Map the surface as a maximized surface.
+            ///
+            ///If an output parameter is given then the surface will be
+            ///maximized on that output. If the client does not specify the
+            ///output then the compositor will apply its policy - usually
+            ///choosing the output on which the surface has the biggest surface
+            ///area.
+            ///
+            ///The compositor will reply with a configure event telling
+            ///the expected new surface size. The operation is completed
+            ///on the next buffer attach to this surface.
+            ///
+            ///A maximized surface typically fills the entire output it is
+            ///bound to, except for desktop elements such as panels. This is
+            ///the main difference between a maximized shell surface and a
+            ///fullscreen shell surface.
+            ///
+            ///The details depend on the compositor implementation.
             fn set_maximized(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 output: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set a short title for the surface."]
-            #[doc = ""]
-            #[doc = "This string may be used to identify the surface in a task bar,"]
-            #[doc = "window list, or other user interface elements provided by the"]
-            #[doc = "compositor."]
-            #[doc = ""]
-            #[doc = "The string must be encoded in UTF-8."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_title message was introduced in
+            const SET_TITLE_SINCE: u32 = 1u32;
+            ///The interface version the set_title message was deprecated in, if any
+            const SET_TITLE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set a short title for the surface.
+            ///
+            ///This string may be used to identify the surface in a task bar,
+            ///window list, or other user interface elements provided by the
+            ///compositor.
+            ///
+            ///The string must be encoded in UTF-8.
             fn set_title(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 title: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set a class for the surface."]
-            #[doc = ""]
-            #[doc = "The surface class identifies the general class of applications"]
-            #[doc = "to which the surface belongs. A common convention is to use the"]
-            #[doc = "file name (or the full path if it is a non-standard location) of"]
-            #[doc = "the application's .desktop file as the class."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_class message was introduced in
+            const SET_CLASS_SINCE: u32 = 1u32;
+            ///The interface version the set_class message was deprecated in, if any
+            const SET_CLASS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set a class for the surface.
+            ///
+            ///The surface class identifies the general class of applications
+            ///to which the surface belongs. A common convention is to use the
+            ///file name (or the full path if it is a non-standard location) of
+            ///the application's .desktop file as the class.
             fn set_class(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 class: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Ping a client to check if it is receiving events and sending"]
-            #[doc = "requests. A client is expected to reply with a pong request."]
-            fn ping(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_shell_surface#{}.ping({})", sender_id, serial);
-                    let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The configure event asks the client to resize its surface."]
-            #[doc = ""]
-            #[doc = "The size is a hint, in the sense that the client is free to"]
-            #[doc = "ignore it if it doesn't resize, pick a smaller size (to"]
-            #[doc = "satisfy aspect ratio or resize in steps of NxM pixels)."]
-            #[doc = ""]
-            #[doc = "The edges parameter provides a hint about how the surface"]
-            #[doc = "was resized. The client may use this information to decide"]
-            #[doc = "how to adjust its content to the new size (e.g. a scrolling"]
-            #[doc = "area might adjust its content position to leave the viewable"]
-            #[doc = "content unmoved)."]
-            #[doc = ""]
-            #[doc = "The client is free to dismiss all but the last configure"]
-            #[doc = "event it received."]
-            #[doc = ""]
-            #[doc = "The width and height arguments specify the size of the window"]
-            #[doc = "in surface-local coordinates."]
-            fn configure(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                edges: Resize,
-                width: i32,
-                height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_shell_surface#{}.configure({}, {}, {})",
-                        sender_id,
-                        edges,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(edges.into())
-                        .put_int(width)
-                        .put_int(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The popup_done event is sent out when a popup grab is broken,"]
-            #[doc = "that is, when the user clicks a surface that doesn't belong"]
-            #[doc = "to the client owning the popup surface."]
-            fn popup_done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_shell_surface#{}.popup_done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_shell_surface#{}.pong({})", sender_id, serial);
-                            self.pong(connection, sender_id, serial).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 0u16,
+                                message = "pong", direction = "in", "args.serial" = %
+                                serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                0u16, message = "pong", direction = "in", "args.serial" : %
+                                = serial; "wl_shell_surface.pong"
+                            );
+                            self.pong(connection, state, sender_id, serial).await
                         }
                         1u16 => {
                             let seat = message
@@ -2810,35 +5826,67 @@ pub mod wayland {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.move({}, {})",
-                                sender_id,
-                                seat,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 1u16,
+                                message = "move", direction = "in", "args.seat" = % seat,
+                                "args.serial" = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                1u16, message = "move", direction = "in", "args.seat" : % =
+                                seat, "args.serial" : % = serial; "wl_shell_surface.move"
                             );
-                            self.r#move(connection, sender_id, seat, serial).await
+                            self.r#move(connection, state, sender_id, seat, serial).await
                         }
                         2u16 => {
                             let seat = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
-                            let edges = message.uint()?;
+                            let edges: Resize = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.resize({}, {}, {})",
-                                sender_id,
-                                seat,
-                                serial,
-                                edges
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 2u16,
+                                message = "resize", direction = "in", "args.seat" = % seat,
+                                "args.serial" = % serial, "args.edges" = % edges,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                2u16, message = "resize", direction = "in", "args.seat" : %
+                                = seat, "args.serial" : % = serial, "args.edges" : % =
+                                edges; "wl_shell_surface.resize"
                             );
-                            self.resize(connection, sender_id, seat, serial, edges.try_into()?)
+                            self.resize(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    seat,
+                                    serial,
+                                    edges,
+                                )
                                 .await
                         }
                         3u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_shell_surface#{}.set_toplevel()", sender_id,);
-                            self.set_toplevel(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 3u16,
+                                message = "set_toplevel", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                3u16, message = "set_toplevel", direction = "in";
+                                "wl_shell_surface.set_toplevel"
+                            );
+                            self.set_toplevel(connection, state, sender_id).await
                         }
                         4u16 => {
                             let parent = message
@@ -2846,48 +5894,67 @@ pub mod wayland {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let x = message.int()?;
                             let y = message.int()?;
-                            let flags = message.uint()?;
+                            let flags: Transient = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.set_transient({}, {}, {}, {})",
-                                sender_id,
-                                parent,
-                                x,
-                                y,
-                                flags
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 4u16,
+                                message = "set_transient", direction = "in", "args.parent" =
+                                % parent, "args.x" = % x, "args.y" = % y, "args.flags" = %
+                                flags,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                4u16, message = "set_transient", direction = "in",
+                                "args.parent" : % = parent, "args.x" : % = x, "args.y" : % =
+                                y, "args.flags" : % = flags;
+                                "wl_shell_surface.set_transient"
                             );
                             self.set_transient(
-                                connection,
-                                sender_id,
-                                parent,
-                                x,
-                                y,
-                                flags.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    parent,
+                                    x,
+                                    y,
+                                    flags,
+                                )
+                                .await
                         }
                         5u16 => {
-                            let method = message.uint()?;
+                            let method: FullscreenMethod = message.uint()?.try_into()?;
                             let framerate = message.uint()?;
                             let output = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.set_fullscreen({}, {}, {})",
-                                sender_id,
-                                method,
-                                framerate,
-                                output
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 5u16,
+                                message = "set_fullscreen", direction = "in", "args.method"
+                                = % method, "args.framerate" = % framerate, "args.output" =
+                                % output.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                5u16, message = "set_fullscreen", direction = "in",
+                                "args.method" : % = method, "args.framerate" : % =
+                                framerate, "args.output" : % = output.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string());
+                                "wl_shell_surface.set_fullscreen"
                             );
                             self.set_fullscreen(
-                                connection,
-                                sender_id,
-                                method.try_into()?,
-                                framerate,
-                                output,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    method,
+                                    framerate,
+                                    output,
+                                )
+                                .await
                         }
                         6u16 => {
                             let seat = message
@@ -2899,139 +5966,236 @@ pub mod wayland {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let x = message.int()?;
                             let y = message.int()?;
-                            let flags = message.uint()?;
+                            let flags: Transient = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.set_popup({}, {}, {}, {}, {}, {})",
-                                sender_id,
-                                seat,
-                                serial,
-                                parent,
-                                x,
-                                y,
-                                flags
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 6u16,
+                                message = "set_popup", direction = "in", "args.seat" = %
+                                seat, "args.serial" = % serial, "args.parent" = % parent,
+                                "args.x" = % x, "args.y" = % y, "args.flags" = % flags,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                6u16, message = "set_popup", direction = "in", "args.seat" :
+                                % = seat, "args.serial" : % = serial, "args.parent" : % =
+                                parent, "args.x" : % = x, "args.y" : % = y, "args.flags" : %
+                                = flags; "wl_shell_surface.set_popup"
                             );
                             self.set_popup(
-                                connection,
-                                sender_id,
-                                seat,
-                                serial,
-                                parent,
-                                x,
-                                y,
-                                flags.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    seat,
+                                    serial,
+                                    parent,
+                                    x,
+                                    y,
+                                    flags,
+                                )
+                                .await
                         }
                         7u16 => {
                             let output = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.set_maximized({})",
-                                sender_id,
-                                output
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_maximized(connection, sender_id, output).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 7u16,
+                                message = "set_maximized", direction = "in", "args.output" =
+                                % output.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                7u16, message = "set_maximized", direction = "in",
+                                "args.output" : % = output.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string());
+                                "wl_shell_surface.set_maximized"
+                            );
+                            self.set_maximized(connection, state, sender_id, output)
+                                .await
                         }
                         8u16 => {
                             let title = message
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.set_title(\"{}\")",
-                                sender_id,
-                                title
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 8u16,
+                                message = "set_title", direction = "in", "args.title" = %
+                                title,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                8u16, message = "set_title", direction = "in", "args.title"
+                                : % = title; "wl_shell_surface.set_title"
                             );
-                            self.set_title(connection, sender_id, title).await
+                            self.set_title(connection, state, sender_id, title).await
                         }
                         9u16 => {
                             let class = message
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_shell_surface#{}.set_class(\"{}\")",
-                                sender_id,
-                                class
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_shell_surface", object_id = % sender_id, opcode = 9u16,
+                                message = "set_class", direction = "in", "args.class_" = %
+                                class,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_shell_surface", object_id : % = sender_id, opcode =
+                                9u16, message = "set_class", direction = "in", "args.class_"
+                                : % = class; "wl_shell_surface.set_class"
                             );
-                            self.set_class(connection, sender_id, class).await
+                            self.set_class(connection, state, sender_id, class).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A surface is a rectangular area that may be displayed on zero"]
-    #[doc = "or more outputs, and shown any number of times at the compositor's"]
-    #[doc = "discretion. They can present wl_buffers, receive user input, and"]
-    #[doc = "define a local coordinate system."]
-    #[doc = ""]
-    #[doc = "The size of a surface (and relative positions on it) is described"]
-    #[doc = "in surface-local coordinates, which may differ from the buffer"]
-    #[doc = "coordinates of the pixel content, in case a buffer_transform"]
-    #[doc = "or a buffer_scale is used."]
-    #[doc = ""]
-    #[doc = "A surface without a \"role\" is fairly useless: a compositor does"]
-    #[doc = "not know where, when or how to present it. The role is the"]
-    #[doc = "purpose of a wl_surface. Examples of roles are a cursor for a"]
-    #[doc = "pointer (as set by wl_pointer.set_cursor), a drag icon"]
-    #[doc = "(wl_data_device.start_drag), a sub-surface"]
-    #[doc = "(wl_subcompositor.get_subsurface), and a window as defined by a"]
-    #[doc = "shell protocol (e.g. wl_shell.get_shell_surface)."]
-    #[doc = ""]
-    #[doc = "A surface can have only one role at a time. Initially a"]
-    #[doc = "wl_surface does not have a role. Once a wl_surface is given a"]
-    #[doc = "role, it is set permanently for the whole lifetime of the"]
-    #[doc = "wl_surface object. Giving the current role again is allowed,"]
-    #[doc = "unless explicitly forbidden by the relevant interface"]
-    #[doc = "specification."]
-    #[doc = ""]
-    #[doc = "Surface roles are given by requests in other interfaces such as"]
-    #[doc = "wl_pointer.set_cursor. The request should explicitly mention"]
-    #[doc = "that this request gives a role to a wl_surface. Often, this"]
-    #[doc = "request also creates a new protocol object that represents the"]
-    #[doc = "role and adds additional functionality to wl_surface. When a"]
-    #[doc = "client wants to destroy a wl_surface, they must destroy this role"]
-    #[doc = "object before the wl_surface, otherwise a defunct_role_object error is"]
-    #[doc = "sent."]
-    #[doc = ""]
-    #[doc = "Destroying the role object does not remove the role from the"]
-    #[doc = "wl_surface, but it may stop the wl_surface from \"playing the role\"."]
-    #[doc = "For instance, if a wl_subsurface object is destroyed, the wl_surface"]
-    #[doc = "it was created for will be unmapped and forget its position and"]
-    #[doc = "z-order. It is allowed to create a wl_subsurface for the same"]
-    #[doc = "wl_surface again, but it is not allowed to use the wl_surface as"]
-    #[doc = "a cursor (cursor is a different role than sub-surface, and role"]
-    #[doc = "switching is not allowed)."]
+    ///A surface is a rectangular area that may be displayed on zero
+    ///or more outputs, and shown any number of times at the compositor's
+    ///discretion. They can present wl_buffers, receive user input, and
+    ///define a local coordinate system.
+    ///
+    ///The size of a surface (and relative positions on it) is described
+    ///in surface-local coordinates, which may differ from the buffer
+    ///coordinates of the pixel content, in case a buffer_transform
+    ///or a buffer_scale is used.
+    ///
+    ///A surface without a "role" is fairly useless: a compositor does
+    ///not know where, when or how to present it. The role is the
+    ///purpose of a wl_surface. Examples of roles are a cursor for a
+    ///pointer (as set by wl_pointer.set_cursor), a drag icon
+    ///(wl_data_device.start_drag), a sub-surface
+    ///(wl_subcompositor.get_subsurface), and a window as defined by a
+    ///shell protocol (e.g. wl_shell.get_shell_surface).
+    ///
+    ///A surface can have only one role at a time. Initially a
+    ///wl_surface does not have a role. Once a wl_surface is given a
+    ///role, it is set permanently for the whole lifetime of the
+    ///wl_surface object. Giving the current role again is allowed,
+    ///unless explicitly forbidden by the relevant interface
+    ///specification.
+    ///
+    ///Surface roles are given by requests in other interfaces such as
+    ///wl_pointer.set_cursor. The request should explicitly mention
+    ///that this request gives a role to a wl_surface. Often, this
+    ///request also creates a new protocol object that represents the
+    ///role and adds additional functionality to wl_surface. When a
+    ///client wants to destroy a wl_surface, they must destroy this role
+    ///object before the wl_surface, otherwise a defunct_role_object error is
+    ///sent.
+    ///
+    ///Destroying the role object does not remove the role from the
+    ///wl_surface, but it may stop the wl_surface from "playing the role".
+    ///For instance, if a wl_subsurface object is destroyed, the wl_surface
+    ///it was created for will be unmapped and forget its position and
+    ///z-order. It is allowed to create a wl_subsurface for the same
+    ///wl_surface again, but it is not allowed to use the wl_surface as
+    ///a cursor (cursor is a different role than sub-surface, and role
+    ///switching is not allowed).
     #[allow(clippy::too_many_arguments)]
     pub mod wl_surface {
-        #[doc = "These errors can be emitted in response to wl_surface requests."]
+        ///These errors can be emitted in response to wl_surface requests.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "buffer scale value is invalid"]
+            ///buffer scale value is invalid
             InvalidScale = 0u32,
-            #[doc = "buffer transform value is invalid"]
+            ///buffer transform value is invalid
             InvalidTransform = 1u32,
-            #[doc = "buffer size is invalid"]
+            ///buffer size is invalid
             InvalidSize = 2u32,
-            #[doc = "buffer offset is invalid"]
+            ///buffer offset is invalid
             InvalidOffset = 3u32,
-            #[doc = "surface was destroyed before its role object"]
+            ///surface was destroyed before its role object
             DefunctRoleObject = 4u32,
-            #[doc = "no buffer was attached"]
+            ///no buffer was attached
             NoBuffer = 5u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidScale => 1u32,
+                    Self::InvalidTransform => 1u32,
+                    Self::InvalidSize => 1u32,
+                    Self::InvalidOffset => 1u32,
+                    Self::DefunctRoleObject => 1u32,
+                    Self::NoBuffer => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidScale => None,
+                    Self::InvalidTransform => None,
+                    Self::InvalidSize => None,
+                    Self::InvalidOffset => None,
+                    Self::DefunctRoleObject => None,
+                    Self::NoBuffer => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_scale", Self::InvalidScale),
+                ("invalid_transform", Self::InvalidTransform),
+                ("invalid_size", Self::InvalidSize),
+                ("invalid_offset", Self::InvalidOffset),
+                ("defunct_role_object", Self::DefunctRoleObject),
+                ("no_buffer", Self::NoBuffer),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidScale => "invalid_scale",
+                    Self::InvalidTransform => "invalid_transform",
+                    Self::InvalidSize => "invalid_size",
+                    Self::InvalidOffset => "invalid_offset",
+                    Self::DefunctRoleObject => "defunct_role_object",
+                    Self::NoBuffer => "no_buffer",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -3046,585 +6210,996 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_surface interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "attach",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "damage",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_callback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_opaque_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_input_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "region",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_region"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "commit",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_buffer_transform",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_buffer_scale",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "damage_buffer",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "offset",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_release",
+                since: 7u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "7n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_callback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_surface interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the enter message was introduced in
+            pub const ENTER_SINCE: u32 = 1u32;
+            ///The interface version the enter message was deprecated in, if any
+            pub const ENTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This is emitted whenever a surface's creation, movement, or resizing
+            ///results in some part of it being within the scanout region of an
+            ///output.
+            ///
+            ///Note that a surface may be overlapping with zero or more outputs.
+            pub fn enter<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                output: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_surface",
+                    object_id = % sender_id, opcode = 0u16, message = "enter", direction
+                    = "out", "args.output" = % output,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_surface",
+                    object_id : % = sender_id, opcode = 0u16, message = "enter",
+                    direction = "out", "args.output" : % = output; "wl_surface.enter"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(output))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the leave message was introduced in
+            pub const LEAVE_SINCE: u32 = 1u32;
+            ///The interface version the leave message was deprecated in, if any
+            pub const LEAVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This is emitted whenever a surface's creation, movement, or resizing
+            ///results in it no longer having any part of it within the scanout region
+            ///of an output.
+            ///
+            ///Clients should not use the number of outputs the surface is on for frame
+            ///throttling purposes. The surface might be hidden even if no leave event
+            ///has been sent, and the compositor might expect new surface content
+            ///updates even if no enter event has been sent. The frame event should be
+            ///used instead.
+            pub fn leave<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                output: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_surface",
+                    object_id = % sender_id, opcode = 1u16, message = "leave", direction
+                    = "out", "args.output" = % output,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_surface",
+                    object_id : % = sender_id, opcode = 1u16, message = "leave",
+                    direction = "out", "args.output" : % = output; "wl_surface.leave"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(output))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the preferred_buffer_scale message was introduced in
+            pub const PREFERRED_BUFFER_SCALE_SINCE: u32 = 6u32;
+            ///The interface version the preferred_buffer_scale message was deprecated in, if any
+            pub const PREFERRED_BUFFER_SCALE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates the preferred buffer scale for this surface. It is
+            ///sent whenever the compositor's preference changes.
+            ///
+            ///Before receiving this event the preferred buffer scale for this surface
+            ///is 1.
+            ///
+            ///It is intended that scaling aware clients use this event to scale their
+            ///content and use wl_surface.set_buffer_scale to indicate the scale they
+            ///have rendered with. This allows clients to supply a higher detail
+            ///buffer.
+            ///
+            ///The compositor shall emit a scale value greater than 0.
+            ///
+            ///Available since version 6
+            pub fn preferred_buffer_scale<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                factor: i32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_surface",
+                    object_id = % sender_id, opcode = 2u16, message =
+                    "preferred_buffer_scale", direction = "out", "args.factor" = %
+                    factor,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_surface",
+                    object_id : % = sender_id, opcode = 2u16, message =
+                    "preferred_buffer_scale", direction = "out", "args.factor" : % =
+                    factor; "wl_surface.preferred_buffer_scale"
+                );
+                let payload = waynest::PayloadBuilder::new().put_int(factor).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the preferred_buffer_transform message was introduced in
+            pub const PREFERRED_BUFFER_TRANSFORM_SINCE: u32 = 6u32;
+            ///The interface version the preferred_buffer_transform message was deprecated in, if any
+            pub const PREFERRED_BUFFER_TRANSFORM_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates the preferred buffer transform for this surface.
+            ///It is sent whenever the compositor's preference changes.
+            ///
+            ///Before receiving this event the preferred buffer transform for this
+            ///surface is normal.
+            ///
+            ///Applying this transformation to the surface buffer contents and using
+            ///wl_surface.set_buffer_transform might allow the compositor to use the
+            ///surface buffer more efficiently.
+            ///
+            ///Available since version 6
+            pub fn preferred_buffer_transform<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                transform: super::wl_output::Transform,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_surface",
+                    object_id = % sender_id, opcode = 3u16, message =
+                    "preferred_buffer_transform", direction = "out", "args.transform" = %
+                    transform,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_surface",
+                    object_id : % = sender_id, opcode = 3u16, message =
+                    "preferred_buffer_transform", direction = "out", "args.transform" : %
+                    = transform; "wl_surface.preferred_buffer_transform"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(transform.into())
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the wl_surface interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_surface` interface",
+            label = "`WlSurface` is not implemented for `{Self}`"
+        )]
         pub trait WlSurface
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_surface";
             const VERSION: u32 = 7u32;
-            #[doc = "Deletes the surface and invalidates its object ID."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Deletes the surface and invalidates its object ID.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set a buffer as the content of this surface."]
-            #[doc = ""]
-            #[doc = "The new size of the surface is calculated based on the buffer"]
-            #[doc = "size transformed by the inverse buffer_transform and the"]
-            #[doc = "inverse buffer_scale. This means that at commit time the supplied"]
-            #[doc = "buffer size must be an integer multiple of the buffer_scale. If"]
-            #[doc = "that's not the case, an invalid_size error is sent."]
-            #[doc = ""]
-            #[doc = "The x and y arguments specify the location of the new pending"]
-            #[doc = "buffer's upper left corner, relative to the current buffer's upper"]
-            #[doc = "left corner, in surface-local coordinates. In other words, the"]
-            #[doc = "x and y, combined with the new surface size define in which"]
-            #[doc = "directions the surface's size changes. Setting anything other than 0"]
-            #[doc = "as x and y arguments is discouraged, and should instead be replaced"]
-            #[doc = "with using the separate wl_surface.offset request."]
-            #[doc = ""]
-            #[doc = "When the bound wl_surface version is 5 or higher, passing any"]
-            #[doc = "non-zero x or y is a protocol violation, and will result in an"]
-            #[doc = "'invalid_offset' error being raised. The x and y arguments are ignored"]
-            #[doc = "and do not change the pending state. To achieve equivalent semantics,"]
-            #[doc = "use wl_surface.offset."]
-            #[doc = ""]
-            #[doc = "Surface contents are double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "The initial surface contents are void; there is no content."]
-            #[doc = "wl_surface.attach assigns the given wl_buffer as the pending"]
-            #[doc = "wl_buffer. wl_surface.commit makes the pending wl_buffer the new"]
-            #[doc = "surface contents, and the size of the surface becomes the size"]
-            #[doc = "calculated from the wl_buffer, as described above. After commit,"]
-            #[doc = "there is no pending buffer until the next attach."]
-            #[doc = ""]
-            #[doc = "Committing a pending wl_buffer allows the compositor to read the"]
-            #[doc = "pixels in the wl_buffer. The compositor may access the pixels at"]
-            #[doc = "any time after the wl_surface.commit request. When the compositor"]
-            #[doc = "will not access the pixels anymore, it will send the"]
-            #[doc = "wl_buffer.release event. Only after receiving wl_buffer.release,"]
-            #[doc = "the client may reuse the wl_buffer. A wl_buffer that has been"]
-            #[doc = "attached and then replaced by another attach instead of committed"]
-            #[doc = "will not receive a release event, and is not used by the"]
-            #[doc = "compositor."]
-            #[doc = ""]
-            #[doc = "If a pending wl_buffer has been committed to more than one wl_surface,"]
-            #[doc = "the delivery of wl_buffer.release events becomes undefined. A well"]
-            #[doc = "behaved client should not rely on wl_buffer.release events in this"]
-            #[doc = "case. Instead, clients hitting this case should use"]
-            #[doc = "wl_surface.get_release or use a protocol extension providing per-commit"]
-            #[doc = "release notifications (if none of these options are available, a"]
-            #[doc = "fallback can be implemented by creating multiple wl_buffer objects from"]
-            #[doc = "the same backing storage)."]
-            #[doc = ""]
-            #[doc = "Destroying the wl_buffer after wl_buffer.release does not change"]
-            #[doc = "the surface contents. Destroying the wl_buffer before wl_buffer.release"]
-            #[doc = "is allowed as long as the underlying buffer storage isn't re-used (this"]
-            #[doc = "can happen e.g. on client process termination). However, if the client"]
-            #[doc = "destroys the wl_buffer before receiving the wl_buffer.release event and"]
-            #[doc = "mutates the underlying buffer storage, the surface contents become"]
-            #[doc = "undefined immediately."]
-            #[doc = ""]
-            #[doc = "If wl_surface.attach is sent with a NULL wl_buffer, the"]
-            #[doc = "following wl_surface.commit will remove the surface content."]
-            #[doc = ""]
-            #[doc = "If a pending wl_buffer has been destroyed, the result is not specified."]
-            #[doc = "Many compositors are known to remove the surface content on the following"]
-            #[doc = "wl_surface.commit, but this behaviour is not universal. Clients seeking to"]
-            #[doc = "maximise compatibility should not destroy pending buffers and should"]
-            #[doc = "ensure that they explicitly remove content from surfaces, even after"]
-            #[doc = "destroying buffers."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the attach message was introduced in
+            const ATTACH_SINCE: u32 = 1u32;
+            ///The interface version the attach message was deprecated in, if any
+            const ATTACH_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set a buffer as the content of this surface.
+            ///
+            ///The new size of the surface is calculated based on the buffer
+            ///size transformed by the inverse buffer_transform and the
+            ///inverse buffer_scale. This means that at commit time the supplied
+            ///buffer size must be an integer multiple of the buffer_scale. If
+            ///that's not the case, an invalid_size error is sent.
+            ///
+            ///The x and y arguments specify the location of the new pending
+            ///buffer's upper left corner, relative to the current buffer's upper
+            ///left corner, in surface-local coordinates. In other words, the
+            ///x and y, combined with the new surface size define in which
+            ///directions the surface's size changes. Setting anything other than 0
+            ///as x and y arguments is discouraged, and should instead be replaced
+            ///with using the separate wl_surface.offset request.
+            ///
+            ///When the bound wl_surface version is 5 or higher, passing any
+            ///non-zero x or y is a protocol violation, and will result in an
+            ///'invalid_offset' error being raised. The x and y arguments are ignored
+            ///and do not change the pending state. To achieve equivalent semantics,
+            ///use wl_surface.offset.
+            ///
+            ///Surface contents are double-buffered state, see wl_surface.commit.
+            ///
+            ///The initial surface contents are void; there is no content.
+            ///wl_surface.attach assigns the given wl_buffer as the pending
+            ///wl_buffer. wl_surface.commit makes the pending wl_buffer the new
+            ///surface contents, and the size of the surface becomes the size
+            ///calculated from the wl_buffer, as described above. After commit,
+            ///there is no pending buffer until the next attach.
+            ///
+            ///Committing a pending wl_buffer allows the compositor to read the
+            ///pixels in the wl_buffer. The compositor may access the pixels at
+            ///any time after the wl_surface.commit request. When the compositor
+            ///will not access the pixels anymore, it will send the
+            ///wl_buffer.release event. Only after receiving wl_buffer.release,
+            ///the client may reuse the wl_buffer. A wl_buffer that has been
+            ///attached and then replaced by another attach instead of committed
+            ///will not receive a release event, and is not used by the
+            ///compositor.
+            ///
+            ///If a pending wl_buffer has been committed to more than one wl_surface,
+            ///the delivery of wl_buffer.release events becomes undefined. A well
+            ///behaved client should not rely on wl_buffer.release events in this
+            ///case. Instead, clients hitting this case should use
+            ///wl_surface.get_release or use a protocol extension providing per-commit
+            ///release notifications (if none of these options are available, a
+            ///fallback can be implemented by creating multiple wl_buffer objects from
+            ///the same backing storage).
+            ///
+            ///Destroying the wl_buffer after wl_buffer.release does not change
+            ///the surface contents. Destroying the wl_buffer before wl_buffer.release
+            ///is allowed as long as the underlying buffer storage isn't re-used (this
+            ///can happen e.g. on client process termination). However, if the client
+            ///destroys the wl_buffer before receiving the wl_buffer.release event and
+            ///mutates the underlying buffer storage, the surface contents become
+            ///undefined immediately.
+            ///
+            ///If wl_surface.attach is sent with a NULL wl_buffer, the
+            ///following wl_surface.commit will remove the surface content.
+            ///
+            ///If a pending wl_buffer has been destroyed, the result is not specified.
+            ///Many compositors are known to remove the surface content on the following
+            ///wl_surface.commit, but this behaviour is not universal. Clients seeking to
+            ///maximise compatibility should not destroy pending buffers and should
+            ///ensure that they explicitly remove content from surfaces, even after
+            ///destroying buffers.
             fn attach(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 buffer: Option<waynest::ObjectId>,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request is used to describe the regions where the pending"]
-            #[doc = "buffer is different from the current surface contents, and where"]
-            #[doc = "the surface therefore needs to be repainted. The compositor"]
-            #[doc = "ignores the parts of the damage that fall outside of the surface."]
-            #[doc = ""]
-            #[doc = "Damage is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "The damage rectangle is specified in surface-local coordinates,"]
-            #[doc = "where x and y specify the upper left corner of the damage rectangle."]
-            #[doc = ""]
-            #[doc = "The initial value for pending damage is empty: no damage."]
-            #[doc = "wl_surface.damage adds pending damage: the new pending damage"]
-            #[doc = "is the union of old pending damage and the given rectangle."]
-            #[doc = ""]
-            #[doc = "wl_surface.commit assigns pending damage as the current damage,"]
-            #[doc = "and clears pending damage. The server will clear the current"]
-            #[doc = "damage as it repaints the surface."]
-            #[doc = ""]
-            #[doc = "Note! New clients should not use this request. Instead damage can be"]
-            #[doc = "posted with wl_surface.damage_buffer which uses buffer coordinates"]
-            #[doc = "instead of surface coordinates."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the damage message was introduced in
+            const DAMAGE_SINCE: u32 = 1u32;
+            ///The interface version the damage message was deprecated in, if any
+            const DAMAGE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request is used to describe the regions where the pending
+            ///buffer is different from the current surface contents, and where
+            ///the surface therefore needs to be repainted. The compositor
+            ///ignores the parts of the damage that fall outside of the surface.
+            ///
+            ///Damage is double-buffered state, see wl_surface.commit.
+            ///
+            ///The damage rectangle is specified in surface-local coordinates,
+            ///where x and y specify the upper left corner of the damage rectangle.
+            ///
+            ///The initial value for pending damage is empty: no damage.
+            ///wl_surface.damage adds pending damage: the new pending damage
+            ///is the union of old pending damage and the given rectangle.
+            ///
+            ///wl_surface.commit assigns pending damage as the current damage,
+            ///and clears pending damage. The server will clear the current
+            ///damage as it repaints the surface.
+            ///
+            ///Note! New clients should not use this request. Instead damage can be
+            ///posted with wl_surface.damage_buffer which uses buffer coordinates
+            ///instead of surface coordinates.
             fn damage(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Request a notification when it is a good time to start drawing a new"]
-            #[doc = "frame, by creating a frame callback. This is useful for throttling"]
-            #[doc = "redrawing operations, and driving animations."]
-            #[doc = ""]
-            #[doc = "When a client is animating on a wl_surface, it can use the 'frame'"]
-            #[doc = "request to get notified when it is a good time to draw and commit the"]
-            #[doc = "next frame of animation. If the client commits an update earlier than"]
-            #[doc = "that, it is likely that some updates will not make it to the display,"]
-            #[doc = "and the client is wasting resources by drawing too often."]
-            #[doc = ""]
-            #[doc = "The frame request will take effect on the next wl_surface.commit."]
-            #[doc = "The notification will only be posted for one frame unless"]
-            #[doc = "requested again. For a wl_surface, the notifications are posted in"]
-            #[doc = "the order the frame requests were committed."]
-            #[doc = ""]
-            #[doc = "The server must send the notifications so that a client"]
-            #[doc = "will not send excessive updates, while still allowing"]
-            #[doc = "the highest possible update rate for clients that wait for the reply"]
-            #[doc = "before drawing again. The server should give some time for the client"]
-            #[doc = "to draw and commit after sending the frame callback events to let it"]
-            #[doc = "hit the next output refresh."]
-            #[doc = ""]
-            #[doc = "A server should avoid signaling the frame callbacks if the"]
-            #[doc = "surface is not visible in any way, e.g. the surface is off-screen,"]
-            #[doc = "or completely obscured by other opaque surfaces."]
-            #[doc = ""]
-            #[doc = "The object returned by this request will be destroyed by the"]
-            #[doc = "compositor after the callback is fired and as such the client must not"]
-            #[doc = "attempt to use it after that point."]
-            #[doc = ""]
-            #[doc = "The callback_data passed in the callback is the current time, in"]
-            #[doc = "milliseconds, with an undefined base."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the frame message was introduced in
+            const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request a notification when it is a good time to start drawing a new
+            ///frame, by creating a frame callback. This is useful for throttling
+            ///redrawing operations, and driving animations.
+            ///
+            ///When a client is animating on a wl_surface, it can use the 'frame'
+            ///request to get notified when it is a good time to draw and commit the
+            ///next frame of animation. If the client commits an update earlier than
+            ///that, it is likely that some updates will not make it to the display,
+            ///and the client is wasting resources by drawing too often.
+            ///
+            ///The frame request will take effect on the next wl_surface.commit.
+            ///The notification will only be posted for one frame unless
+            ///requested again. For a wl_surface, the notifications are posted in
+            ///the order the frame requests were committed.
+            ///
+            ///The server must send the notifications so that a client
+            ///will not send excessive updates, while still allowing
+            ///the highest possible update rate for clients that wait for the reply
+            ///before drawing again. The server should give some time for the client
+            ///to draw and commit after sending the frame callback events to let it
+            ///hit the next output refresh.
+            ///
+            ///A server should avoid signaling the frame callbacks if the
+            ///surface is not visible in any way, e.g. the surface is off-screen,
+            ///or completely obscured by other opaque surfaces.
+            ///
+            ///The object returned by this request will be destroyed by the
+            ///compositor after the callback is fired and as such the client must not
+            ///attempt to use it after that point.
+            ///
+            ///The callback_data passed in the callback is the current time, in
+            ///milliseconds, with an undefined base.
             fn frame(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 callback: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets the region of the surface that contains"]
-            #[doc = "opaque content."]
-            #[doc = ""]
-            #[doc = "The opaque region is an optimization hint for the compositor"]
-            #[doc = "that lets it optimize the redrawing of content behind opaque"]
-            #[doc = "regions.  Setting an opaque region is not required for correct"]
-            #[doc = "behaviour, but marking transparent content as opaque will result"]
-            #[doc = "in repaint artifacts."]
-            #[doc = ""]
-            #[doc = "The opaque region is specified in surface-local coordinates."]
-            #[doc = ""]
-            #[doc = "The compositor ignores the parts of the opaque region that fall"]
-            #[doc = "outside of the surface."]
-            #[doc = ""]
-            #[doc = "Opaque region is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "wl_surface.set_opaque_region changes the pending opaque region."]
-            #[doc = "wl_surface.commit copies the pending region to the current region."]
-            #[doc = "Otherwise, the pending and current regions are never changed."]
-            #[doc = ""]
-            #[doc = "The initial value for an opaque region is empty. Setting the pending"]
-            #[doc = "opaque region has copy semantics, and the wl_region object can be"]
-            #[doc = "destroyed immediately. A NULL wl_region causes the pending opaque"]
-            #[doc = "region to be set to empty."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_opaque_region message was introduced in
+            const SET_OPAQUE_REGION_SINCE: u32 = 1u32;
+            ///The interface version the set_opaque_region message was deprecated in, if any
+            const SET_OPAQUE_REGION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the region of the surface that contains
+            ///opaque content.
+            ///
+            ///The opaque region is an optimization hint for the compositor
+            ///that lets it optimize the redrawing of content behind opaque
+            ///regions.  Setting an opaque region is not required for correct
+            ///behaviour, but marking transparent content as opaque will result
+            ///in repaint artifacts.
+            ///
+            ///The opaque region is specified in surface-local coordinates.
+            ///
+            ///The compositor ignores the parts of the opaque region that fall
+            ///outside of the surface.
+            ///
+            ///Opaque region is double-buffered state, see wl_surface.commit.
+            ///
+            ///wl_surface.set_opaque_region changes the pending opaque region.
+            ///wl_surface.commit copies the pending region to the current region.
+            ///Otherwise, the pending and current regions are never changed.
+            ///
+            ///The initial value for an opaque region is empty. Setting the pending
+            ///opaque region has copy semantics, and the wl_region object can be
+            ///destroyed immediately. A NULL wl_region causes the pending opaque
+            ///region to be set to empty.
             fn set_opaque_region(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 region: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets the region of the surface that can receive"]
-            #[doc = "pointer and touch events."]
-            #[doc = ""]
-            #[doc = "Input events happening outside of this region will try the next"]
-            #[doc = "surface in the server surface stack. The compositor ignores the"]
-            #[doc = "parts of the input region that fall outside of the surface."]
-            #[doc = ""]
-            #[doc = "The input region is specified in surface-local coordinates."]
-            #[doc = ""]
-            #[doc = "Input region is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "wl_surface.set_input_region changes the pending input region."]
-            #[doc = "wl_surface.commit copies the pending region to the current region."]
-            #[doc = "Otherwise the pending and current regions are never changed,"]
-            #[doc = "except cursor and icon surfaces are special cases, see"]
-            #[doc = "wl_pointer.set_cursor and wl_data_device.start_drag."]
-            #[doc = ""]
-            #[doc = "The initial value for an input region is infinite. That means the"]
-            #[doc = "whole surface will accept input. Setting the pending input region"]
-            #[doc = "has copy semantics, and the wl_region object can be destroyed"]
-            #[doc = "immediately. A NULL wl_region causes the input region to be set"]
-            #[doc = "to infinite."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_input_region message was introduced in
+            const SET_INPUT_REGION_SINCE: u32 = 1u32;
+            ///The interface version the set_input_region message was deprecated in, if any
+            const SET_INPUT_REGION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the region of the surface that can receive
+            ///pointer and touch events.
+            ///
+            ///Input events happening outside of this region will try the next
+            ///surface in the server surface stack. The compositor ignores the
+            ///parts of the input region that fall outside of the surface.
+            ///
+            ///The input region is specified in surface-local coordinates.
+            ///
+            ///Input region is double-buffered state, see wl_surface.commit.
+            ///
+            ///wl_surface.set_input_region changes the pending input region.
+            ///wl_surface.commit copies the pending region to the current region.
+            ///Otherwise the pending and current regions are never changed,
+            ///except cursor and icon surfaces are special cases, see
+            ///wl_pointer.set_cursor and wl_data_device.start_drag.
+            ///
+            ///The initial value for an input region is infinite. That means the
+            ///whole surface will accept input. Setting the pending input region
+            ///has copy semantics, and the wl_region object can be destroyed
+            ///immediately. A NULL wl_region causes the input region to be set
+            ///to infinite.
             fn set_input_region(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 region: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Surface state (input, opaque, and damage regions, attached buffers,"]
-            #[doc = "etc.) is double-buffered. Protocol requests modify the pending state,"]
-            #[doc = "as opposed to the active state in use by the compositor."]
-            #[doc = ""]
-            #[doc = "All requests that need a commit to become effective are documented"]
-            #[doc = "to affect double-buffered state."]
-            #[doc = ""]
-            #[doc = "Other interfaces may add further double-buffered surface state."]
-            #[doc = ""]
-            #[doc = "A commit request atomically creates a Content Update (CU) from the"]
-            #[doc = "pending state, even if the pending state has not been touched. The"]
-            #[doc = "content update is placed at the end of a per-surface queue until it"]
-            #[doc = "becomes active. After commit, the new pending state is as documented for"]
-            #[doc = "each related request."]
-            #[doc = ""]
-            #[doc = "A CU is either a Desync Content Update (DCU) or a Sync Content Update"]
-            #[doc = "(SCU). If the surface is effectively synchronized at the commit request,"]
-            #[doc = "it is a SCU, otherwise a DCU."]
-            #[doc = ""]
-            #[doc = "When a surface transitions from effectively synchronized to effectively"]
-            #[doc = "desynchronized, all SCUs in its queue which are not reachable by any"]
-            #[doc = "DCU become DCUs and dependency edges from outside the queue to these CUs"]
-            #[doc = "are removed."]
-            #[doc = ""]
-            #[doc = "See wl_subsurface for the definition of 'effectively synchronized' and"]
-            #[doc = "'effectively desynchronized'."]
-            #[doc = ""]
-            #[doc = "When a CU is placed in the queue, the CU has a dependency on the CU in"]
-            #[doc = "front of it and to the SCU at end of the queue of every direct child"]
-            #[doc = "surface if that SCU exists and does not have another dependent. This can"]
-            #[doc = "form a directed acyclic graph of CUs with dependencies as edges."]
-            #[doc = ""]
-            #[doc = "In addition to surface state, the CU can have constraints that must be"]
-            #[doc = "satisfied before it can be applied. Other interfaces may add CU"]
-            #[doc = "constraints."]
-            #[doc = ""]
-            #[doc = "All DCUs which do not have a SCU in front of themselves in their queue,"]
-            #[doc = "are candidates. If the graph that's reachable by a candidate does not"]
-            #[doc = "have any unsatisfied constraints, the entire graph must be applied"]
-            #[doc = "atomically."]
-            #[doc = ""]
-            #[doc = "When a CU is applied, the wl_buffer is applied before all other state."]
-            #[doc = "This means that all coordinates in double-buffered state are relative to"]
-            #[doc = "the newly attached wl_buffers, except for wl_surface.attach itself. If"]
-            #[doc = "there is no newly attached wl_buffer, the coordinates are relative to"]
-            #[doc = "the previous content update."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the commit message was introduced in
+            const COMMIT_SINCE: u32 = 1u32;
+            ///The interface version the commit message was deprecated in, if any
+            const COMMIT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Surface state (input, opaque, and damage regions, attached buffers,
+            ///etc.) is double-buffered. Protocol requests modify the pending state,
+            ///as opposed to the active state in use by the compositor.
+            ///
+            ///All requests that need a commit to become effective are documented
+            ///to affect double-buffered state.
+            ///
+            ///Other interfaces may add further double-buffered surface state.
+            ///
+            ///A commit request atomically creates a Content Update (CU) from the
+            ///pending state, even if the pending state has not been touched. The
+            ///content update is placed at the end of a per-surface queue until it
+            ///becomes active. After commit, the new pending state is as documented for
+            ///each related request.
+            ///
+            ///A CU is either a Desync Content Update (DCU) or a Sync Content Update
+            ///(SCU). If the surface is effectively synchronized at the commit request,
+            ///it is a SCU, otherwise a DCU.
+            ///
+            ///When a surface transitions from effectively synchronized to effectively
+            ///desynchronized, all SCUs in its queue which are not reachable by any
+            ///DCU become DCUs and dependency edges from outside the queue to these CUs
+            ///are removed.
+            ///
+            ///See wl_subsurface for the definition of 'effectively synchronized' and
+            ///'effectively desynchronized'.
+            ///
+            ///When a CU is placed in the queue, the CU has a dependency on the CU in
+            ///front of it and to the SCU at end of the queue of every direct child
+            ///surface if that SCU exists and does not have another dependent. This can
+            ///form a directed acyclic graph of CUs with dependencies as edges.
+            ///
+            ///In addition to surface state, the CU can have constraints that must be
+            ///satisfied before it can be applied. Other interfaces may add CU
+            ///constraints.
+            ///
+            ///All DCUs which do not have a SCU in front of themselves in their queue,
+            ///are candidates. If the graph that's reachable by a candidate does not
+            ///have any unsatisfied constraints, the entire graph must be applied
+            ///atomically.
+            ///
+            ///When a CU is applied, the wl_buffer is applied before all other state.
+            ///This means that all coordinates in double-buffered state are relative to
+            ///the newly attached wl_buffers, except for wl_surface.attach itself. If
+            ///there is no newly attached wl_buffer, the coordinates are relative to
+            ///the previous content update.
             fn commit(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets the transformation that the client has already applied"]
-            #[doc = "to the content of the buffer. The accepted values for the transform"]
-            #[doc = "parameter are the values for wl_output.transform."]
-            #[doc = ""]
-            #[doc = "The compositor applies the inverse of this transformation whenever it"]
-            #[doc = "uses the buffer contents."]
-            #[doc = ""]
-            #[doc = "Buffer transform is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "A newly created surface has its buffer transformation set to normal."]
-            #[doc = ""]
-            #[doc = "wl_surface.set_buffer_transform changes the pending buffer"]
-            #[doc = "transformation. wl_surface.commit copies the pending buffer"]
-            #[doc = "transformation to the current one. Otherwise, the pending and current"]
-            #[doc = "values are never changed."]
-            #[doc = ""]
-            #[doc = "The purpose of this request is to allow clients to render content"]
-            #[doc = "according to the output transform, thus permitting the compositor to"]
-            #[doc = "use certain optimizations even if the display is rotated. Using"]
-            #[doc = "hardware overlays and scanning out a client buffer for fullscreen"]
-            #[doc = "surfaces are examples of such optimizations. Those optimizations are"]
-            #[doc = "highly dependent on the compositor implementation, so the use of this"]
-            #[doc = "request should be considered on a case-by-case basis."]
-            #[doc = ""]
-            #[doc = "Note that if the transform value includes 90 or 270 degree rotation,"]
-            #[doc = "the width of the buffer will become the surface height and the height"]
-            #[doc = "of the buffer will become the surface width."]
-            #[doc = ""]
-            #[doc = "If transform is not one of the values from the"]
-            #[doc = "wl_output.transform enum the invalid_transform protocol error"]
-            #[doc = "is raised."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_buffer_transform message was introduced in
+            const SET_BUFFER_TRANSFORM_SINCE: u32 = 2u32;
+            ///The interface version the set_buffer_transform message was deprecated in, if any
+            const SET_BUFFER_TRANSFORM_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the transformation that the client has already applied
+            ///to the content of the buffer. The accepted values for the transform
+            ///parameter are the values for wl_output.transform.
+            ///
+            ///The compositor applies the inverse of this transformation whenever it
+            ///uses the buffer contents.
+            ///
+            ///Buffer transform is double-buffered state, see wl_surface.commit.
+            ///
+            ///A newly created surface has its buffer transformation set to normal.
+            ///
+            ///wl_surface.set_buffer_transform changes the pending buffer
+            ///transformation. wl_surface.commit copies the pending buffer
+            ///transformation to the current one. Otherwise, the pending and current
+            ///values are never changed.
+            ///
+            ///The purpose of this request is to allow clients to render content
+            ///according to the output transform, thus permitting the compositor to
+            ///use certain optimizations even if the display is rotated. Using
+            ///hardware overlays and scanning out a client buffer for fullscreen
+            ///surfaces are examples of such optimizations. Those optimizations are
+            ///highly dependent on the compositor implementation, so the use of this
+            ///request should be considered on a case-by-case basis.
+            ///
+            ///Note that if the transform value includes 90 or 270 degree rotation,
+            ///the width of the buffer will become the surface height and the height
+            ///of the buffer will become the surface width.
+            ///
+            ///If transform is not one of the values from the
+            ///wl_output.transform enum the invalid_transform protocol error
+            ///is raised.
+            ///
+            ///Available since version 2
             fn set_buffer_transform(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                transform: super::super::super::core::wayland::wl_output::Transform,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets an optional scaling factor on how the compositor"]
-            #[doc = "interprets the contents of the buffer attached to the window."]
-            #[doc = ""]
-            #[doc = "Buffer scale is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "A newly created surface has its buffer scale set to 1."]
-            #[doc = ""]
-            #[doc = "wl_surface.set_buffer_scale changes the pending buffer scale."]
-            #[doc = "wl_surface.commit copies the pending buffer scale to the current one."]
-            #[doc = "Otherwise, the pending and current values are never changed."]
-            #[doc = ""]
-            #[doc = "The purpose of this request is to allow clients to supply higher"]
-            #[doc = "resolution buffer data for use on high resolution outputs. It is"]
-            #[doc = "intended that you pick the same buffer scale as the scale of the"]
-            #[doc = "output that the surface is displayed on. This means the compositor"]
-            #[doc = "can avoid scaling when rendering the surface on that output."]
-            #[doc = ""]
-            #[doc = "Note that if the scale is larger than 1, then you have to attach"]
-            #[doc = "a buffer that is larger (by a factor of scale in each dimension)"]
-            #[doc = "than the desired surface size."]
-            #[doc = ""]
-            #[doc = "If scale is not greater than 0 the invalid_scale protocol error is"]
-            #[doc = "raised."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                transform: super::wl_output::Transform,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_buffer_scale message was introduced in
+            const SET_BUFFER_SCALE_SINCE: u32 = 3u32;
+            ///The interface version the set_buffer_scale message was deprecated in, if any
+            const SET_BUFFER_SCALE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets an optional scaling factor on how the compositor
+            ///interprets the contents of the buffer attached to the window.
+            ///
+            ///Buffer scale is double-buffered state, see wl_surface.commit.
+            ///
+            ///A newly created surface has its buffer scale set to 1.
+            ///
+            ///wl_surface.set_buffer_scale changes the pending buffer scale.
+            ///wl_surface.commit copies the pending buffer scale to the current one.
+            ///Otherwise, the pending and current values are never changed.
+            ///
+            ///The purpose of this request is to allow clients to supply higher
+            ///resolution buffer data for use on high resolution outputs. It is
+            ///intended that you pick the same buffer scale as the scale of the
+            ///output that the surface is displayed on. This means the compositor
+            ///can avoid scaling when rendering the surface on that output.
+            ///
+            ///Note that if the scale is larger than 1, then you have to attach
+            ///a buffer that is larger (by a factor of scale in each dimension)
+            ///than the desired surface size.
+            ///
+            ///If scale is not greater than 0 the invalid_scale protocol error is
+            ///raised.
+            ///
+            ///Available since version 3
             fn set_buffer_scale(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 scale: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request is used to describe the regions where the pending"]
-            #[doc = "buffer is different from the current surface contents, and where"]
-            #[doc = "the surface therefore needs to be repainted. The compositor"]
-            #[doc = "ignores the parts of the damage that fall outside of the surface."]
-            #[doc = ""]
-            #[doc = "Damage is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "The damage rectangle is specified in buffer coordinates,"]
-            #[doc = "where x and y specify the upper left corner of the damage rectangle."]
-            #[doc = ""]
-            #[doc = "The initial value for pending damage is empty: no damage."]
-            #[doc = "wl_surface.damage_buffer adds pending damage: the new pending"]
-            #[doc = "damage is the union of old pending damage and the given rectangle."]
-            #[doc = ""]
-            #[doc = "wl_surface.commit assigns pending damage as the current damage,"]
-            #[doc = "and clears pending damage. The server will clear the current"]
-            #[doc = "damage as it repaints the surface."]
-            #[doc = ""]
-            #[doc = "This request differs from wl_surface.damage in only one way - it"]
-            #[doc = "takes damage in buffer coordinates instead of surface-local"]
-            #[doc = "coordinates. While this generally is more intuitive than surface"]
-            #[doc = "coordinates, it is especially desirable when using wp_viewport"]
-            #[doc = "or when a drawing library (like EGL) is unaware of buffer scale"]
-            #[doc = "and buffer transform."]
-            #[doc = ""]
-            #[doc = "Note: Because buffer transformation changes and damage requests may"]
-            #[doc = "be interleaved in the protocol stream, it is impossible to determine"]
-            #[doc = "the actual mapping between surface and buffer damage until"]
-            #[doc = "wl_surface.commit time. Therefore, compositors wishing to take both"]
-            #[doc = "kinds of damage into account will have to accumulate damage from the"]
-            #[doc = "two requests separately and only transform from one to the other"]
-            #[doc = "after receiving the wl_surface.commit."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the damage_buffer message was introduced in
+            const DAMAGE_BUFFER_SINCE: u32 = 4u32;
+            ///The interface version the damage_buffer message was deprecated in, if any
+            const DAMAGE_BUFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request is used to describe the regions where the pending
+            ///buffer is different from the current surface contents, and where
+            ///the surface therefore needs to be repainted. The compositor
+            ///ignores the parts of the damage that fall outside of the surface.
+            ///
+            ///Damage is double-buffered state, see wl_surface.commit.
+            ///
+            ///The damage rectangle is specified in buffer coordinates,
+            ///where x and y specify the upper left corner of the damage rectangle.
+            ///
+            ///The initial value for pending damage is empty: no damage.
+            ///wl_surface.damage_buffer adds pending damage: the new pending
+            ///damage is the union of old pending damage and the given rectangle.
+            ///
+            ///wl_surface.commit assigns pending damage as the current damage,
+            ///and clears pending damage. The server will clear the current
+            ///damage as it repaints the surface.
+            ///
+            ///This request differs from wl_surface.damage in only one way - it
+            ///takes damage in buffer coordinates instead of surface-local
+            ///coordinates. While this generally is more intuitive than surface
+            ///coordinates, it is especially desirable when using wp_viewport
+            ///or when a drawing library (like EGL) is unaware of buffer scale
+            ///and buffer transform.
+            ///
+            ///Note: Because buffer transformation changes and damage requests may
+            ///be interleaved in the protocol stream, it is impossible to determine
+            ///the actual mapping between surface and buffer damage until
+            ///wl_surface.commit time. Therefore, compositors wishing to take both
+            ///kinds of damage into account will have to accumulate damage from the
+            ///two requests separately and only transform from one to the other
+            ///after receiving the wl_surface.commit.
+            ///
+            ///Available since version 4
             fn damage_buffer(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The x and y arguments specify the location of the new pending"]
-            #[doc = "buffer's upper left corner, relative to the current buffer's upper"]
-            #[doc = "left corner, in surface-local coordinates. In other words, the"]
-            #[doc = "x and y, combined with the new surface size define in which"]
-            #[doc = "directions the surface's size changes."]
-            #[doc = ""]
-            #[doc = "The exact semantics of wl_surface.offset are role-specific. Refer to"]
-            #[doc = "the documentation of specific roles for more information."]
-            #[doc = ""]
-            #[doc = "Surface location offset is double-buffered state, see"]
-            #[doc = "wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "This request is semantically equivalent to and the replaces the x and y"]
-            #[doc = "arguments in the wl_surface.attach request in wl_surface versions prior"]
-            #[doc = "to 5. See wl_surface.attach for details."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the offset message was introduced in
+            const OFFSET_SINCE: u32 = 5u32;
+            ///The interface version the offset message was deprecated in, if any
+            const OFFSET_DEPRECATED_SINCE: Option<u32> = None;
+            ///The x and y arguments specify the location of the new pending
+            ///buffer's upper left corner, relative to the current buffer's upper
+            ///left corner, in surface-local coordinates. In other words, the
+            ///x and y, combined with the new surface size define in which
+            ///directions the surface's size changes.
+            ///
+            ///The exact semantics of wl_surface.offset are role-specific. Refer to
+            ///the documentation of specific roles for more information.
+            ///
+            ///Surface location offset is double-buffered state, see
+            ///wl_surface.commit.
+            ///
+            ///This request is semantically equivalent to and the replaces the x and y
+            ///arguments in the wl_surface.attach request in wl_surface versions prior
+            ///to 5. See wl_surface.attach for details.
+            ///
+            ///Available since version 5
             fn offset(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Create a callback for the release of the buffer attached by the client"]
-            #[doc = "with wl_surface.attach."]
-            #[doc = ""]
-            #[doc = "The compositor will release the buffer when it has finished its usage of"]
-            #[doc = "the underlying storage for the relevant commit. Once the client receives"]
-            #[doc = "this event, and assuming the associated buffer is not pending release"]
-            #[doc = "from other wl_surface.commit requests, the client can safely re-use the"]
-            #[doc = "buffer."]
-            #[doc = ""]
-            #[doc = "Release callbacks are double-buffered state, and will be associated"]
-            #[doc = "with the pending buffer at wl_surface.commit time."]
-            #[doc = ""]
-            #[doc = "The callback_data passed in the wl_callback.done event is unused and"]
-            #[doc = "is always zero."]
-            #[doc = ""]
-            #[doc = "Sending this request without attaching a non-null buffer in the same"]
-            #[doc = "content update is a protocol error. The compositor will send the"]
-            #[doc = "no_buffer error in this case."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_release message was introduced in
+            const GET_RELEASE_SINCE: u32 = 7u32;
+            ///The interface version the get_release message was deprecated in, if any
+            const GET_RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a callback for the release of the buffer attached by the client
+            ///with wl_surface.attach.
+            ///
+            ///The compositor will release the buffer when it has finished its usage of
+            ///the underlying storage for the relevant commit. Once the client receives
+            ///this event, and assuming the associated buffer is not pending release
+            ///from other wl_surface.commit requests, the client can safely re-use the
+            ///buffer.
+            ///
+            ///Release callbacks are double-buffered state, and will be associated
+            ///with the pending buffer at wl_surface.commit time.
+            ///
+            ///The callback_data passed in the wl_callback.done event is unused and
+            ///is always zero.
+            ///
+            ///Sending this request without attaching a non-null buffer in the same
+            ///content update is a protocol error. The compositor will send the
+            ///no_buffer error in this case.
+            ///
+            ///Available since version 7
             fn get_release(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 callback: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This is emitted whenever a surface's creation, movement, or resizing"]
-            #[doc = "results in some part of it being within the scanout region of an"]
-            #[doc = "output."]
-            #[doc = ""]
-            #[doc = "Note that a surface may be overlapping with zero or more outputs."]
-            fn enter(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_surface#{}.enter({})", sender_id, output);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(output))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This is emitted whenever a surface's creation, movement, or resizing"]
-            #[doc = "results in it no longer having any part of it within the scanout region"]
-            #[doc = "of an output."]
-            #[doc = ""]
-            #[doc = "Clients should not use the number of outputs the surface is on for frame"]
-            #[doc = "throttling purposes. The surface might be hidden even if no leave event"]
-            #[doc = "has been sent, and the compositor might expect new surface content"]
-            #[doc = "updates even if no enter event has been sent. The frame event should be"]
-            #[doc = "used instead."]
-            fn leave(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_surface#{}.leave({})", sender_id, output);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(output))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates the preferred buffer scale for this surface. It is"]
-            #[doc = "sent whenever the compositor's preference changes."]
-            #[doc = ""]
-            #[doc = "Before receiving this event the preferred buffer scale for this surface"]
-            #[doc = "is 1."]
-            #[doc = ""]
-            #[doc = "It is intended that scaling aware clients use this event to scale their"]
-            #[doc = "content and use wl_surface.set_buffer_scale to indicate the scale they"]
-            #[doc = "have rendered with. This allows clients to supply a higher detail"]
-            #[doc = "buffer."]
-            #[doc = ""]
-            #[doc = "The compositor shall emit a scale value greater than 0."]
-            fn preferred_buffer_scale(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                factor: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_surface#{}.preferred_buffer_scale({})",
-                        sender_id,
-                        factor
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_int(factor).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates the preferred buffer transform for this surface."]
-            #[doc = "It is sent whenever the compositor's preference changes."]
-            #[doc = ""]
-            #[doc = "Before receiving this event the preferred buffer transform for this"]
-            #[doc = "surface is normal."]
-            #[doc = ""]
-            #[doc = "Applying this transformation to the surface buffer contents and using"]
-            #[doc = "wl_surface.set_buffer_transform might allow the compositor to use the"]
-            #[doc = "surface buffer more efficiently."]
-            fn preferred_buffer_transform(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                transform: super::super::super::core::wayland::wl_output::Transform,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_surface#{}.preferred_buffer_transform({})",
-                        sender_id,
-                        transform
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(transform.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 3u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_surface#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "wl_surface.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let buffer = message.object()?;
                             let x = message.int()?;
                             let y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_surface#{}.attach({}, {}, {})",
-                                sender_id,
-                                buffer
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                x,
-                                y
-                            );
-                            self.attach(connection, sender_id, buffer, x, y).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 1u16,
+                                message = "attach", direction = "in", "args.buffer" = %
+                                buffer.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.x" = % x, "args.y" = % y,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 1u16,
+                                message = "attach", direction = "in", "args.buffer" : % =
+                                buffer.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.x" : % = x, "args.y" : % = y;
+                                "wl_surface.attach"
+                            );
+                            self.attach(connection, state, sender_id, buffer, x, y).await
                         }
                         2u16 => {
                             let x = message.int()?;
@@ -3632,15 +7207,30 @@ pub mod wayland {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_surface#{}.damage({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.damage(connection, sender_id, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 2u16,
+                                message = "damage", direction = "in", "args.x" = % x,
+                                "args.y" = % y, "args.width" = % width, "args.height" = %
+                                height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 2u16,
+                                message = "damage", direction = "in", "args.x" : % = x,
+                                "args.y" : % = y, "args.width" : % = width, "args.height" :
+                                % = height; "wl_surface.damage"
+                            );
+                            self.damage(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         3u16 => {
@@ -3648,54 +7238,125 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_surface#{}.frame({})", sender_id, callback);
-                            self.frame(connection, sender_id, callback).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 3u16,
+                                message = "frame", direction = "in", "args.callback" = %
+                                callback,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 3u16,
+                                message = "frame", direction = "in", "args.callback" : % =
+                                callback; "wl_surface.frame"
+                            );
+                            self.frame(connection, state, sender_id, callback).await
                         }
                         4u16 => {
                             let region = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_surface#{}.set_opaque_region({})",
-                                sender_id,
-                                region
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_opaque_region(connection, sender_id, region).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 4u16,
+                                message = "set_opaque_region", direction = "in",
+                                "args.region" = % region.as_ref().map_or("null".to_string(),
+                                | v | v.to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 4u16,
+                                message = "set_opaque_region", direction = "in",
+                                "args.region" : % = region.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string());
+                                "wl_surface.set_opaque_region"
+                            );
+                            self.set_opaque_region(connection, state, sender_id, region)
+                                .await
                         }
                         5u16 => {
                             let region = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_surface#{}.set_input_region({})",
-                                sender_id,
-                                region
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_input_region(connection, sender_id, region).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 5u16,
+                                message = "set_input_region", direction = "in",
+                                "args.region" = % region.as_ref().map_or("null".to_string(),
+                                | v | v.to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 5u16,
+                                message = "set_input_region", direction = "in",
+                                "args.region" : % = region.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string());
+                                "wl_surface.set_input_region"
+                            );
+                            self.set_input_region(connection, state, sender_id, region)
+                                .await
                         }
                         6u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_surface#{}.commit()", sender_id,);
-                            self.commit(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 6u16,
+                                message = "commit", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 6u16,
+                                message = "commit", direction = "in"; "wl_surface.commit"
+                            );
+                            self.commit(connection, state, sender_id).await
                         }
                         7u16 => {
-                            let transform = message.uint()?;
+                            let transform: super::wl_output::Transform = message
+                                .int()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_surface#{}.set_buffer_transform({})",
-                                sender_id,
-                                transform
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 7u16,
+                                message = "set_buffer_transform", direction = "in",
+                                "args.transform" = % transform,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 7u16,
+                                message = "set_buffer_transform", direction = "in",
+                                "args.transform" : % = transform;
+                                "wl_surface.set_buffer_transform"
                             );
-                            self.set_buffer_transform(connection, sender_id, transform.try_into()?)
+                            self.set_buffer_transform(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    transform,
+                                )
                                 .await
                         }
                         8u16 => {
                             let scale = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_surface#{}.set_buffer_scale({})", sender_id, scale);
-                            self.set_buffer_scale(connection, sender_id, scale).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 8u16,
+                                message = "set_buffer_scale", direction = "in", "args.scale"
+                                = % scale,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 8u16,
+                                message = "set_buffer_scale", direction = "in", "args.scale"
+                                : % = scale; "wl_surface.set_buffer_scale"
+                            );
+                            self.set_buffer_scale(connection, state, sender_id, scale)
+                                .await
                         }
                         9u16 => {
                             let x = message.int()?;
@@ -3703,45 +7364,114 @@ pub mod wayland {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_surface#{}.damage_buffer({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.damage_buffer(connection, sender_id, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 9u16,
+                                message = "damage_buffer", direction = "in", "args.x" = % x,
+                                "args.y" = % y, "args.width" = % width, "args.height" = %
+                                height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 9u16,
+                                message = "damage_buffer", direction = "in", "args.x" : % =
+                                x, "args.y" : % = y, "args.width" : % = width, "args.height"
+                                : % = height; "wl_surface.damage_buffer"
+                            );
+                            self.damage_buffer(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         10u16 => {
                             let x = message.int()?;
                             let y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_surface#{}.offset({}, {})", sender_id, x, y);
-                            self.offset(connection, sender_id, x, y).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 10u16,
+                                message = "offset", direction = "in", "args.x" = % x,
+                                "args.y" = % y,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 10u16,
+                                message = "offset", direction = "in", "args.x" : % = x,
+                                "args.y" : % = y; "wl_surface.offset"
+                            );
+                            self.offset(connection, state, sender_id, x, y).await
                         }
                         11u16 => {
                             let callback = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_surface#{}.get_release({})", sender_id, callback);
-                            self.get_release(connection, sender_id, callback).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_surface", object_id = % sender_id, opcode = 11u16,
+                                message = "get_release", direction = "in", "args.callback" =
+                                % callback,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_surface", object_id : % = sender_id, opcode = 11u16,
+                                message = "get_release", direction = "in", "args.callback" :
+                                % = callback; "wl_surface.get_release"
+                            );
+                            self.get_release(connection, state, sender_id, callback)
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A seat is a group of keyboards, pointer and touch devices. This"]
-    #[doc = "object is published as a global during start up, or when such a"]
-    #[doc = "device is hot plugged.  A seat typically has a pointer and"]
-    #[doc = "maintains a keyboard focus and a pointer focus."]
+    ///A seat is a group of keyboards, pointer and touch devices. This
+    ///object is published as a global during start up, or when such a
+    ///device is hot plugged.  A seat typically has a pointer and
+    ///maintains a keyboard focus and a pointer focus.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_seat {
-        bitflags::bitflags! { # [doc = "This is a bitmask of capabilities this seat has; if a member is"] # [doc = "set, then it is present on the seat."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Capability : u32 { # [doc = "the seat has pointer devices"] const Pointer = 1u32 ; # [doc = "the seat has one or more keyboards"] const Keyboard = 2u32 ; # [doc = "the seat has touch devices"] const Touch = 4u32 ; } }
+        bitflags::bitflags! {
+            #[doc = "This is a bitmask of capabilities this seat has; if a member is"]
+            #[doc = "set, then it is present on the seat."] #[derive(Debug, PartialEq,
+            Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub struct Capability : u32 { #[doc
+            = "the seat has pointer devices"] const Pointer = 1u32; #[doc =
+            "the seat has one or more keyboards"] const Keyboard = 2u32; #[doc =
+            "the seat has touch devices"] const Touch = 4u32; }
+        }
+        #[allow(deprecated)]
+        impl Capability {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("pointer", Self::Pointer),
+                ("keyboard", Self::Keyboard),
+                ("touch", Self::Touch),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Capability> for u32 {
             fn from(value: Capability) -> Self {
                 value.bits()
@@ -3755,22 +7485,92 @@ pub mod wayland {
         }
         impl std::fmt::Display for Capability {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "These errors can be emitted in response to wl_seat requests."]
+        ///These errors can be emitted in response to wl_seat requests.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "get_pointer, get_keyboard or get_touch called on seat without the matching capability"]
+            ///get_pointer, get_keyboard or get_touch called on seat without the matching capability
             MissingCapability = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::MissingCapability => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::MissingCapability => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("missing_capability", Self::MissingCapability),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::MissingCapability => "missing_capability",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -3780,158 +7580,290 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_seat interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_pointer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_keyboard",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_keyboard"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_touch",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_touch"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "5",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_seat interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the capabilities message was introduced in
+            pub const CAPABILITIES_SINCE: u32 = 1u32;
+            ///The interface version the capabilities message was deprecated in, if any
+            pub const CAPABILITIES_DEPRECATED_SINCE: Option<u32> = None;
+            ///This is sent on binding to the seat global or whenever a seat gains
+            ///or loses the pointer, keyboard or touch capabilities.
+            ///The argument is a capability enum containing the complete set of
+            ///capabilities this seat has.
+            ///
+            ///When the pointer capability is added, a client may create a
+            ///wl_pointer object using the wl_seat.get_pointer request. This object
+            ///will receive pointer events until the capability is removed in the
+            ///future.
+            ///
+            ///When the pointer capability is removed, a client should destroy the
+            ///wl_pointer objects associated with the seat where the capability was
+            ///removed, using the wl_pointer.release request. No further pointer
+            ///events will be received on these objects.
+            ///
+            ///In some compositors, if a seat regains the pointer capability and a
+            ///client has a previously obtained wl_pointer object of version 4 or
+            ///less, that object may start sending pointer events again. This
+            ///behavior is considered a misinterpretation of the intended behavior
+            ///and must not be relied upon by the client. wl_pointer objects of
+            ///version 5 or later must not send events if created before the most
+            ///recent event notifying the client of an added pointer capability.
+            ///
+            ///The above behavior also applies to wl_keyboard and wl_touch with the
+            ///keyboard and touch capabilities, respectively.
+            pub fn capabilities<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                capabilities: Capability,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_seat",
+                    object_id = % sender_id, opcode = 0u16, message = "capabilities",
+                    direction = "out", "args.capabilities" = % capabilities,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_seat",
+                    object_id : % = sender_id, opcode = 0u16, message = "capabilities",
+                    direction = "out", "args.capabilities" : % = capabilities;
+                    "wl_seat.capabilities"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(capabilities.into())
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the name message was introduced in
+            pub const NAME_SINCE: u32 = 2u32;
+            ///The interface version the name message was deprecated in, if any
+            pub const NAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///In a multi-seat configuration the seat name can be used by clients to
+            ///help identify which physical devices the seat represents.
+            ///
+            ///The seat name is a UTF-8 string with no convention defined for its
+            ///contents. Each name is unique among all wl_seat globals. The name is
+            ///only guaranteed to be unique for the current compositor instance.
+            ///
+            ///The same seat names are used for all clients. Thus, the name can be
+            ///shared across processes to refer to a specific wl_seat global.
+            ///
+            ///The name event is sent after binding to the seat global, and should be sent
+            ///before announcing capabilities. This event only sent once per seat object,
+            ///and the name does not change over the lifetime of the wl_seat global.
+            ///
+            ///Compositors may re-use the same seat name if the wl_seat global is
+            ///destroyed and re-created later.
+            ///
+            ///Available since version 2
+            pub fn name<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                name: String,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_seat",
+                    object_id = % sender_id, opcode = 1u16, message = "name", direction =
+                    "out", "args.name" = % name,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_seat",
+                    object_id : % = sender_id, opcode = 1u16, message = "name", direction
+                    = "out", "args.name" : % = name; "wl_seat.name"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(name))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the wl_seat interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_seat` interface",
+            label = "`WlSeat` is not implemented for `{Self}`"
+        )]
         pub trait WlSeat
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_seat";
             const VERSION: u32 = 10u32;
-            #[doc = "The ID provided will be initialized to the wl_pointer interface"]
-            #[doc = "for this seat."]
-            #[doc = ""]
-            #[doc = "This request only takes effect if the seat has the pointer"]
-            #[doc = "capability, or has had the pointer capability in the past."]
-            #[doc = "It is a protocol violation to issue this request on a seat that has"]
-            #[doc = "never had the pointer capability. The missing_capability error will"]
-            #[doc = "be sent in this case."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_pointer message was introduced in
+            const GET_POINTER_SINCE: u32 = 1u32;
+            ///The interface version the get_pointer message was deprecated in, if any
+            const GET_POINTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///The ID provided will be initialized to the wl_pointer interface
+            ///for this seat.
+            ///
+            ///This request only takes effect if the seat has the pointer
+            ///capability, or has had the pointer capability in the past.
+            ///It is a protocol violation to issue this request on a seat that has
+            ///never had the pointer capability. The missing_capability error will
+            ///be sent in this case.
             fn get_pointer(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The ID provided will be initialized to the wl_keyboard interface"]
-            #[doc = "for this seat."]
-            #[doc = ""]
-            #[doc = "This request only takes effect if the seat has the keyboard"]
-            #[doc = "capability, or has had the keyboard capability in the past."]
-            #[doc = "It is a protocol violation to issue this request on a seat that has"]
-            #[doc = "never had the keyboard capability. The missing_capability error will"]
-            #[doc = "be sent in this case."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_keyboard message was introduced in
+            const GET_KEYBOARD_SINCE: u32 = 1u32;
+            ///The interface version the get_keyboard message was deprecated in, if any
+            const GET_KEYBOARD_DEPRECATED_SINCE: Option<u32> = None;
+            ///The ID provided will be initialized to the wl_keyboard interface
+            ///for this seat.
+            ///
+            ///This request only takes effect if the seat has the keyboard
+            ///capability, or has had the keyboard capability in the past.
+            ///It is a protocol violation to issue this request on a seat that has
+            ///never had the keyboard capability. The missing_capability error will
+            ///be sent in this case.
             fn get_keyboard(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The ID provided will be initialized to the wl_touch interface"]
-            #[doc = "for this seat."]
-            #[doc = ""]
-            #[doc = "This request only takes effect if the seat has the touch"]
-            #[doc = "capability, or has had the touch capability in the past."]
-            #[doc = "It is a protocol violation to issue this request on a seat that has"]
-            #[doc = "never had the touch capability. The missing_capability error will"]
-            #[doc = "be sent in this case."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_touch message was introduced in
+            const GET_TOUCH_SINCE: u32 = 1u32;
+            ///The interface version the get_touch message was deprecated in, if any
+            const GET_TOUCH_DEPRECATED_SINCE: Option<u32> = None;
+            ///The ID provided will be initialized to the wl_touch interface
+            ///for this seat.
+            ///
+            ///This request only takes effect if the seat has the touch
+            ///capability, or has had the touch capability in the past.
+            ///It is a protocol violation to issue this request on a seat that has
+            ///never had the touch capability. The missing_capability error will
+            ///be sent in this case.
             fn get_touch(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Using this request a client can tell the server that it is not going to"]
-            #[doc = "use the seat object anymore."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 5u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Using this request a client can tell the server that it is not going to
+            ///use the seat object anymore.
+            ///
+            ///Available since version 5
             fn release(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This is sent on binding to the seat global or whenever a seat gains"]
-            #[doc = "or loses the pointer, keyboard or touch capabilities."]
-            #[doc = "The argument is a capability enum containing the complete set of"]
-            #[doc = "capabilities this seat has."]
-            #[doc = ""]
-            #[doc = "When the pointer capability is added, a client may create a"]
-            #[doc = "wl_pointer object using the wl_seat.get_pointer request. This object"]
-            #[doc = "will receive pointer events until the capability is removed in the"]
-            #[doc = "future."]
-            #[doc = ""]
-            #[doc = "When the pointer capability is removed, a client should destroy the"]
-            #[doc = "wl_pointer objects associated with the seat where the capability was"]
-            #[doc = "removed, using the wl_pointer.release request. No further pointer"]
-            #[doc = "events will be received on these objects."]
-            #[doc = ""]
-            #[doc = "In some compositors, if a seat regains the pointer capability and a"]
-            #[doc = "client has a previously obtained wl_pointer object of version 4 or"]
-            #[doc = "less, that object may start sending pointer events again. This"]
-            #[doc = "behavior is considered a misinterpretation of the intended behavior"]
-            #[doc = "and must not be relied upon by the client. wl_pointer objects of"]
-            #[doc = "version 5 or later must not send events if created before the most"]
-            #[doc = "recent event notifying the client of an added pointer capability."]
-            #[doc = ""]
-            #[doc = "The above behavior also applies to wl_keyboard and wl_touch with the"]
-            #[doc = "keyboard and touch capabilities, respectively."]
-            fn capabilities(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                capabilities: Capability,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_seat#{}.capabilities({})", sender_id, capabilities);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(capabilities.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "In a multi-seat configuration the seat name can be used by clients to"]
-            #[doc = "help identify which physical devices the seat represents."]
-            #[doc = ""]
-            #[doc = "The seat name is a UTF-8 string with no convention defined for its"]
-            #[doc = "contents. Each name is unique among all wl_seat globals. The name is"]
-            #[doc = "only guaranteed to be unique for the current compositor instance."]
-            #[doc = ""]
-            #[doc = "The same seat names are used for all clients. Thus, the name can be"]
-            #[doc = "shared across processes to refer to a specific wl_seat global."]
-            #[doc = ""]
-            #[doc = "The name event is sent after binding to the seat global, and should be sent"]
-            #[doc = "before announcing capabilities. This event only sent once per seat object,"]
-            #[doc = "and the name does not change over the lifetime of the wl_seat global."]
-            #[doc = ""]
-            #[doc = "Compositors may re-use the same seat name if the wl_seat global is"]
-            #[doc = "destroyed and re-created later."]
-            fn name(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                name: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_seat#{}.name(\"{}\")", sender_id, name);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(name))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3940,58 +7872,143 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_seat#{}.get_pointer({})", sender_id, id);
-                            self.get_pointer(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_seat", object_id = % sender_id, opcode = 0u16, message =
+                                "get_pointer", direction = "in", "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_seat", object_id : % = sender_id, opcode = 0u16, message
+                                = "get_pointer", direction = "in", "args.id" : % = id;
+                                "wl_seat.get_pointer"
+                            );
+                            self.get_pointer(connection, state, sender_id, id).await
                         }
                         1u16 => {
                             let id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_seat#{}.get_keyboard({})", sender_id, id);
-                            self.get_keyboard(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_seat", object_id = % sender_id, opcode = 1u16, message =
+                                "get_keyboard", direction = "in", "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_seat", object_id : % = sender_id, opcode = 1u16, message
+                                = "get_keyboard", direction = "in", "args.id" : % = id;
+                                "wl_seat.get_keyboard"
+                            );
+                            self.get_keyboard(connection, state, sender_id, id).await
                         }
                         2u16 => {
                             let id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_seat#{}.get_touch({})", sender_id, id);
-                            self.get_touch(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_seat", object_id = % sender_id, opcode = 2u16, message =
+                                "get_touch", direction = "in", "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_seat", object_id : % = sender_id, opcode = 2u16, message
+                                = "get_touch", direction = "in", "args.id" : % = id;
+                                "wl_seat.get_touch"
+                            );
+                            self.get_touch(connection, state, sender_id, id).await
                         }
                         3u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_seat#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_seat", object_id = % sender_id, opcode = 3u16, message =
+                                "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_seat", object_id : % = sender_id, opcode = 3u16, message
+                                = "release", direction = "in"; "wl_seat.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The wl_pointer interface represents one or more input devices,"]
-    #[doc = "such as mice, which control the pointer location and pointer_focus"]
-    #[doc = "of a seat."]
-    #[doc = ""]
-    #[doc = "The wl_pointer interface generates motion, enter and leave"]
-    #[doc = "events for the surfaces that the pointer is located over,"]
-    #[doc = "and button and axis events for button presses, button releases"]
-    #[doc = "and scrolling."]
+    ///The wl_pointer interface represents one or more input devices,
+    ///such as mice, which control the pointer location and pointer_focus
+    ///of a seat.
+    ///
+    ///The wl_pointer interface generates motion, enter and leave
+    ///events for the surfaces that the pointer is located over,
+    ///and button and axis events for button presses, button releases
+    ///and scrolling.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_pointer {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "given wl_surface has another role"]
+            ///given wl_surface has another role
             Role = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Role => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Role => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[("role", Self::Role)];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Role => "role",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4001,27 +8018,79 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Describes the physical state of a button that produced the button"]
-        #[doc = "event."]
+        ///Describes the physical state of a button that produced the button
+        ///event.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum ButtonState {
-            #[doc = "the button is not pressed"]
+            ///the button is not pressed
             Released = 0u32,
-            #[doc = "the button is pressed"]
+            ///the button is pressed
             Pressed = 1u32,
         }
+        #[allow(deprecated)]
+        impl ButtonState {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Released => 1u32,
+                    Self::Pressed => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Released => None,
+                    Self::Pressed => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("released", Self::Released),
+                ("pressed", Self::Pressed),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Released => "released",
+                    Self::Pressed => "pressed",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<ButtonState> for u32 {
             fn from(value: ButtonState) -> Self {
                 value as u32
             }
         }
+        impl From<ButtonState> for i32 {
+            fn from(value: ButtonState) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for ButtonState {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4032,26 +8101,78 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for ButtonState {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for ButtonState {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Describes the axis types of scroll events."]
+        ///Describes the axis types of scroll events.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Axis {
-            #[doc = "vertical axis"]
+            ///vertical axis
             VerticalScroll = 0u32,
-            #[doc = "horizontal axis"]
+            ///horizontal axis
             HorizontalScroll = 1u32,
         }
+        #[allow(deprecated)]
+        impl Axis {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::VerticalScroll => 1u32,
+                    Self::HorizontalScroll => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::VerticalScroll => None,
+                    Self::HorizontalScroll => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("vertical_scroll", Self::VerticalScroll),
+                ("horizontal_scroll", Self::HorizontalScroll),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::VerticalScroll => "vertical_scroll",
+                    Self::HorizontalScroll => "horizontal_scroll",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Axis> for u32 {
             fn from(value: Axis) -> Self {
                 value as u32
             }
         }
+        impl From<Axis> for i32 {
+            fn from(value: Axis) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Axis {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4062,45 +8183,107 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Axis {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Axis {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
-            }
-        }
-        #[doc = "Describes the source types for axis events. This indicates to the"]
-        #[doc = "client how an axis event was physically generated; a client may"]
-        #[doc = "adjust the user interface accordingly. For example, scroll events"]
-        #[doc = "from a \"finger\" source may be in a smooth coordinate space with"]
-        #[doc = "kinetic scrolling whereas a \"wheel\" source may be in discrete steps"]
-        #[doc = "of a number of lines."]
-        #[doc = ""]
-        #[doc = "The \"continuous\" axis source is a device generating events in a"]
-        #[doc = "continuous coordinate space, but using something other than a"]
-        #[doc = "finger. One example for this source is button-based scrolling where"]
-        #[doc = "the vertical motion of a device is converted to scroll events while"]
-        #[doc = "a button is held down."]
-        #[doc = ""]
-        #[doc = "The \"wheel tilt\" axis source indicates that the actual device is a"]
-        #[doc = "wheel but the scroll event is not caused by a rotation but a"]
-        #[doc = "(usually sideways) tilt of the wheel."]
+                f.write_str(self.as_str())
+            }
+        }
+        ///Describes the source types for axis events. This indicates to the
+        ///client how an axis event was physically generated; a client may
+        ///adjust the user interface accordingly. For example, scroll events
+        ///from a "finger" source may be in a smooth coordinate space with
+        ///kinetic scrolling whereas a "wheel" source may be in discrete steps
+        ///of a number of lines.
+        ///
+        ///The "continuous" axis source is a device generating events in a
+        ///continuous coordinate space, but using something other than a
+        ///finger. One example for this source is button-based scrolling where
+        ///the vertical motion of a device is converted to scroll events while
+        ///a button is held down.
+        ///
+        ///The "wheel tilt" axis source indicates that the actual device is a
+        ///wheel but the scroll event is not caused by a rotation but a
+        ///(usually sideways) tilt of the wheel.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum AxisSource {
-            #[doc = "a physical wheel rotation"]
+            ///a physical wheel rotation
             Wheel = 0u32,
-            #[doc = "finger on a touch surface"]
+            ///finger on a touch surface
             Finger = 1u32,
-            #[doc = "continuous coordinate space"]
+            ///continuous coordinate space
             Continuous = 2u32,
-            #[doc = "a physical wheel tilt"]
+            ///a physical wheel tilt
+            ///
+            ///Available since version 6
             WheelTilt = 3u32,
         }
+        #[allow(deprecated)]
+        impl AxisSource {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Wheel => 1u32,
+                    Self::Finger => 1u32,
+                    Self::Continuous => 1u32,
+                    Self::WheelTilt => 6u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Wheel => None,
+                    Self::Finger => None,
+                    Self::Continuous => None,
+                    Self::WheelTilt => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("wheel", Self::Wheel),
+                ("finger", Self::Finger),
+                ("continuous", Self::Continuous),
+                ("wheel_tilt", Self::WheelTilt),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Wheel => "wheel",
+                    Self::Finger => "finger",
+                    Self::Continuous => "continuous",
+                    Self::WheelTilt => "wheel_tilt",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<AxisSource> for u32 {
             fn from(value: AxisSource) -> Self {
                 value as u32
             }
         }
+        impl From<AxisSource> for i32 {
+            fn from(value: AxisSource) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for AxisSource {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4113,27 +8296,79 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for AxisSource {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for AxisSource {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "This specifies the direction of the physical motion that caused a"]
-        #[doc = "wl_pointer.axis event, relative to the wl_pointer.axis direction."]
+        ///This specifies the direction of the physical motion that caused a
+        ///wl_pointer.axis event, relative to the wl_pointer.axis direction.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum AxisRelativeDirection {
-            #[doc = "physical motion matches axis direction"]
+            ///physical motion matches axis direction
             Identical = 0u32,
-            #[doc = "physical motion is the inverse of the axis direction"]
+            ///physical motion is the inverse of the axis direction
             Inverted = 1u32,
         }
+        #[allow(deprecated)]
+        impl AxisRelativeDirection {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Identical => 1u32,
+                    Self::Inverted => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Identical => None,
+                    Self::Inverted => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("identical", Self::Identical),
+                ("inverted", Self::Inverted),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Identical => "identical",
+                    Self::Inverted => "inverted",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<AxisRelativeDirection> for u32 {
             fn from(value: AxisRelativeDirection) -> Self {
                 value as u32
             }
         }
+        impl From<AxisRelativeDirection> for i32 {
+            fn from(value: AxisRelativeDirection) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for AxisRelativeDirection {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4144,580 +8379,759 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for AxisRelativeDirection {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for AxisRelativeDirection {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_pointer interface. See the module level documentation for more info"]
-        pub trait WlPointer
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wl_pointer";
-            const VERSION: u32 = 10u32;
-            #[doc = "Set the pointer surface, i.e., the surface that contains the"]
-            #[doc = "pointer image (cursor). This request gives the surface the role"]
-            #[doc = "of a cursor. If the surface already has another role, it raises"]
-            #[doc = "a protocol error."]
-            #[doc = ""]
-            #[doc = "The cursor actually changes only if the pointer"]
-            #[doc = "focus for this device is one of the requesting client's surfaces"]
-            #[doc = "or the surface parameter is the current pointer surface. If"]
-            #[doc = "there was a previous surface set with this request it is"]
-            #[doc = "replaced. If surface is NULL, the pointer image is hidden."]
-            #[doc = ""]
-            #[doc = "The parameters hotspot_x and hotspot_y define the position of"]
-            #[doc = "the pointer surface relative to the pointer location. Its"]
-            #[doc = "top-left corner is always at (x, y) - (hotspot_x, hotspot_y),"]
-            #[doc = "where (x, y) are the coordinates of the pointer location, in"]
-            #[doc = "surface-local coordinates."]
-            #[doc = ""]
-            #[doc = "On wl_surface.offset requests to the pointer surface, hotspot_x"]
-            #[doc = "and hotspot_y are decremented by the x and y parameters"]
-            #[doc = "passed to the request. The offset must be applied by"]
-            #[doc = "wl_surface.commit as usual."]
-            #[doc = ""]
-            #[doc = "The hotspot can also be updated by passing the currently set"]
-            #[doc = "pointer surface to this request with new values for hotspot_x"]
-            #[doc = "and hotspot_y."]
-            #[doc = ""]
-            #[doc = "The input region is ignored for wl_surfaces with the role of"]
-            #[doc = "a cursor. When the use as a cursor ends, the wl_surface is"]
-            #[doc = "unmapped."]
-            #[doc = ""]
-            #[doc = "The serial parameter must match the latest wl_pointer.enter"]
-            #[doc = "serial number sent to the client. Otherwise the request will be"]
-            #[doc = "ignored."]
-            fn set_cursor(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-                surface: Option<waynest::ObjectId>,
-                hotspot_x: i32,
-                hotspot_y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Using this request a client can tell the server that it is not going to"]
-            #[doc = "use the pointer object anymore."]
-            #[doc = ""]
-            #[doc = "This request destroys the pointer proxy object, so clients must not call"]
-            #[doc = "wl_pointer_destroy() after using this request."]
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Notification that this seat's pointer is focused on a certain"]
-            #[doc = "surface."]
-            #[doc = ""]
-            #[doc = "When a seat's focus enters a surface, the pointer image"]
-            #[doc = "is undefined and a client should respond to this event by setting"]
-            #[doc = "an appropriate pointer image with the set_cursor request."]
-            fn enter(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_pointer interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the enter message was introduced in
+            pub const ENTER_SINCE: u32 = 1u32;
+            ///The interface version the enter message was deprecated in, if any
+            pub const ENTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this seat's pointer is focused on a certain
+            ///surface.
+            ///
+            ///When a seat's focus enters a surface, the pointer image
+            ///is undefined and a client should respond to this event by setting
+            ///an appropriate pointer image with the set_cursor request.
+            pub fn enter<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 surface: waynest::ObjectId,
                 surface_x: waynest::Fixed,
                 surface_y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.enter({}, {}, {}, {})",
-                        sender_id,
-                        serial,
-                        surface,
-                        surface_x,
-                        surface_y
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(surface))
-                        .put_fixed(surface_x)
-                        .put_fixed(surface_y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 0u16, message = "enter", direction
+                    = "out", "args.serial" = % serial, "args.surface" = % surface,
+                    "args.surface_x" = % surface_x, "args.surface_y" = % surface_y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 0u16, message = "enter",
+                    direction = "out", "args.serial" : % = serial, "args.surface" : % =
+                    surface, "args.surface_x" : % = surface_x, "args.surface_y" : % =
+                    surface_y; "wl_pointer.enter"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(surface))
+                    .put_fixed(surface_x)
+                    .put_fixed(surface_y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this seat's pointer is no longer focused on"]
-            #[doc = "a certain surface."]
-            #[doc = ""]
-            #[doc = "The leave notification is sent before the enter notification"]
-            #[doc = "for the new focus."]
-            fn leave(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the leave message was introduced in
+            pub const LEAVE_SINCE: u32 = 1u32;
+            ///The interface version the leave message was deprecated in, if any
+            pub const LEAVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this seat's pointer is no longer focused on
+            ///a certain surface.
+            ///
+            ///The leave notification is sent before the enter notification
+            ///for the new focus.
+            pub fn leave<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_pointer#{}.leave({}, {})", sender_id, serial, surface);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(surface))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 1u16, message = "leave", direction
+                    = "out", "args.serial" = % serial, "args.surface" = % surface,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 1u16, message = "leave",
+                    direction = "out", "args.serial" : % = serial, "args.surface" : % =
+                    surface; "wl_pointer.leave"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(surface))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification of pointer location change. The arguments"]
-            #[doc = "surface_x and surface_y are the location relative to the"]
-            #[doc = "focused surface."]
-            fn motion(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the motion message was introduced in
+            pub const MOTION_SINCE: u32 = 1u32;
+            ///The interface version the motion message was deprecated in, if any
+            pub const MOTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification of pointer location change. The arguments
+            ///surface_x and surface_y are the location relative to the
+            ///focused surface.
+            pub fn motion<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 surface_x: waynest::Fixed,
                 surface_y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.motion({}, {}, {})",
-                        sender_id,
-                        time,
-                        surface_x,
-                        surface_y
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_fixed(surface_x)
-                        .put_fixed(surface_y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 2u16, message = "motion", direction
+                    = "out", "args.time" = % time, "args.surface_x" = % surface_x,
+                    "args.surface_y" = % surface_y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 2u16, message = "motion",
+                    direction = "out", "args.time" : % = time, "args.surface_x" : % =
+                    surface_x, "args.surface_y" : % = surface_y; "wl_pointer.motion"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_fixed(surface_x)
+                    .put_fixed(surface_y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Mouse button click and release notifications."]
-            #[doc = ""]
-            #[doc = "The location of the click is given by the last motion or"]
-            #[doc = "enter event."]
-            #[doc = "The time argument is a timestamp with millisecond"]
-            #[doc = "granularity, with an undefined base."]
-            #[doc = ""]
-            #[doc = "The button is a button code as defined in the Linux kernel's"]
-            #[doc = "linux/input-event-codes.h header file, e.g. BTN_LEFT."]
-            #[doc = ""]
-            #[doc = "Any 16-bit button code value is reserved for future additions to the"]
-            #[doc = "kernel's event code list. All other button codes above 0xFFFF are"]
-            #[doc = "currently undefined but may be used in future versions of this"]
-            #[doc = "protocol."]
-            fn button(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the button message was introduced in
+            pub const BUTTON_SINCE: u32 = 1u32;
+            ///The interface version the button message was deprecated in, if any
+            pub const BUTTON_DEPRECATED_SINCE: Option<u32> = None;
+            ///Mouse button click and release notifications.
+            ///
+            ///The location of the click is given by the last motion or
+            ///enter event.
+            ///The time argument is a timestamp with millisecond
+            ///granularity, with an undefined base.
+            ///
+            ///The button is a button code as defined in the Linux kernel's
+            ///linux/input-event-codes.h header file, e.g. BTN_LEFT.
+            ///
+            ///Any 16-bit button code value is reserved for future additions to the
+            ///kernel's event code list. All other button codes above 0xFFFF are
+            ///currently undefined but may be used in future versions of this
+            ///protocol.
+            pub fn button<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 time: u32,
                 button: u32,
-                state: ButtonState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.button({}, {}, {}, {})",
-                        sender_id,
-                        serial,
-                        time,
-                        button,
-                        state
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(time)
-                        .put_uint(button)
-                        .put_uint(state.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+                state_arg: ButtonState,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 3u16, message = "button", direction
+                    = "out", "args.serial" = % serial, "args.time" = % time,
+                    "args.button" = % button, "args.state" = % state_arg,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 3u16, message = "button",
+                    direction = "out", "args.serial" : % = serial, "args.time" : % =
+                    time, "args.button" : % = button, "args.state" : % = state_arg;
+                    "wl_pointer.button"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(time)
+                    .put_uint(button)
+                    .put_uint(state_arg.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Scroll and other axis notifications."]
-            #[doc = ""]
-            #[doc = "For scroll events (vertical and horizontal scroll axes), the"]
-            #[doc = "value parameter is the length of a vector along the specified"]
-            #[doc = "axis in a coordinate space identical to those of motion events,"]
-            #[doc = "representing a relative movement along the specified axis."]
-            #[doc = ""]
-            #[doc = "For devices that support movements non-parallel to axes multiple"]
-            #[doc = "axis events will be emitted."]
-            #[doc = ""]
-            #[doc = "When applicable, for example for touch pads, the server can"]
-            #[doc = "choose to emit scroll events where the motion vector is"]
-            #[doc = "equivalent to a motion event vector."]
-            #[doc = ""]
-            #[doc = "When applicable, a client can transform its content relative to the"]
-            #[doc = "scroll distance."]
-            fn axis(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the axis message was introduced in
+            pub const AXIS_SINCE: u32 = 1u32;
+            ///The interface version the axis message was deprecated in, if any
+            pub const AXIS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Scroll and other axis notifications.
+            ///
+            ///For scroll events (vertical and horizontal scroll axes), the
+            ///value parameter is the length of a vector along the specified
+            ///axis in a coordinate space identical to those of motion events,
+            ///representing a relative movement along the specified axis.
+            ///
+            ///For devices that support movements non-parallel to axes multiple
+            ///axis events will be emitted.
+            ///
+            ///When applicable, for example for touch pads, the server can
+            ///choose to emit scroll events where the motion vector is
+            ///equivalent to a motion event vector.
+            ///
+            ///When applicable, a client can transform its content relative to the
+            ///scroll distance.
+            pub fn axis<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 axis: Axis,
                 value: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.axis({}, {}, {})",
-                        sender_id,
-                        time,
-                        axis,
-                        value
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_uint(axis.into())
-                        .put_fixed(value)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 4u16, message = "axis", direction =
+                    "out", "args.time" = % time, "args.axis" = % axis, "args.value" = %
+                    value,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 4u16, message = "axis", direction
+                    = "out", "args.time" : % = time, "args.axis" : % = axis, "args.value"
+                    : % = value; "wl_pointer.axis"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_uint(axis.into())
+                    .put_fixed(value)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Indicates the end of a set of events that logically belong together."]
-            #[doc = "A client is expected to accumulate the data in all events within the"]
-            #[doc = "frame before proceeding."]
-            #[doc = ""]
-            #[doc = "All wl_pointer events before a wl_pointer.frame event belong"]
-            #[doc = "logically together. For example, in a diagonal scroll motion the"]
-            #[doc = "compositor will send an optional wl_pointer.axis_source event, two"]
-            #[doc = "wl_pointer.axis events (horizontal and vertical) and finally a"]
-            #[doc = "wl_pointer.frame event. The client may use this information to"]
-            #[doc = "calculate a diagonal vector for scrolling."]
-            #[doc = ""]
-            #[doc = "When multiple wl_pointer.axis events occur within the same frame,"]
-            #[doc = "the motion vector is the combined motion of all events."]
-            #[doc = "When a wl_pointer.axis and a wl_pointer.axis_stop event occur within"]
-            #[doc = "the same frame, this indicates that axis movement in one axis has"]
-            #[doc = "stopped but continues in the other axis."]
-            #[doc = "When multiple wl_pointer.axis_stop events occur within the same"]
-            #[doc = "frame, this indicates that these axes stopped in the same instance."]
-            #[doc = ""]
-            #[doc = "A wl_pointer.frame event is sent for every logical event group,"]
-            #[doc = "even if the group only contains a single wl_pointer event."]
-            #[doc = "Specifically, a client may get a sequence: motion, frame, button,"]
-            #[doc = "frame, axis, frame, axis_stop, frame."]
-            #[doc = ""]
-            #[doc = "The wl_pointer.enter and wl_pointer.leave events are logical events"]
-            #[doc = "generated by the compositor and not the hardware. These events are"]
-            #[doc = "also grouped by a wl_pointer.frame. When a pointer moves from one"]
-            #[doc = "surface to another, a compositor should group the"]
-            #[doc = "wl_pointer.leave event within the same wl_pointer.frame."]
-            #[doc = "However, a client must not rely on wl_pointer.leave and"]
-            #[doc = "wl_pointer.enter being in the same wl_pointer.frame."]
-            #[doc = "Compositor-specific policies may require the wl_pointer.leave and"]
-            #[doc = "wl_pointer.enter event being split across multiple wl_pointer.frame"]
-            #[doc = "groups."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_pointer#{}.frame()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 5u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the end of a set of events that logically belong together.
+            ///A client is expected to accumulate the data in all events within the
+            ///frame before proceeding.
+            ///
+            ///All wl_pointer events before a wl_pointer.frame event belong
+            ///logically together. For example, in a diagonal scroll motion the
+            ///compositor will send an optional wl_pointer.axis_source event, two
+            ///wl_pointer.axis events (horizontal and vertical) and finally a
+            ///wl_pointer.frame event. The client may use this information to
+            ///calculate a diagonal vector for scrolling.
+            ///
+            ///When multiple wl_pointer.axis events occur within the same frame,
+            ///the motion vector is the combined motion of all events.
+            ///When a wl_pointer.axis and a wl_pointer.axis_stop event occur within
+            ///the same frame, this indicates that axis movement in one axis has
+            ///stopped but continues in the other axis.
+            ///When multiple wl_pointer.axis_stop events occur within the same
+            ///frame, this indicates that these axes stopped in the same instance.
+            ///
+            ///A wl_pointer.frame event is sent for every logical event group,
+            ///even if the group only contains a single wl_pointer event.
+            ///Specifically, a client may get a sequence: motion, frame, button,
+            ///frame, axis, frame, axis_stop, frame.
+            ///
+            ///The wl_pointer.enter and wl_pointer.leave events are logical events
+            ///generated by the compositor and not the hardware. These events are
+            ///also grouped by a wl_pointer.frame. When a pointer moves from one
+            ///surface to another, a compositor should group the
+            ///wl_pointer.leave event within the same wl_pointer.frame.
+            ///However, a client must not rely on wl_pointer.leave and
+            ///wl_pointer.enter being in the same wl_pointer.frame.
+            ///Compositor-specific policies may require the wl_pointer.leave and
+            ///wl_pointer.enter event being split across multiple wl_pointer.frame
+            ///groups.
+            ///
+            ///Available since version 5
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 5u16, message = "frame", direction
+                    = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 5u16, message = "frame",
+                    direction = "out"; "wl_pointer.frame"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Source information for scroll and other axes."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own. It is sent before a"]
-            #[doc = "wl_pointer.frame event and carries the source information for"]
-            #[doc = "all events within that frame."]
-            #[doc = ""]
-            #[doc = "The source specifies how this event was generated. If the source is"]
-            #[doc = "wl_pointer.axis_source.finger, a wl_pointer.axis_stop event will be"]
-            #[doc = "sent when the user lifts the finger off the device."]
-            #[doc = ""]
-            #[doc = "If the source is wl_pointer.axis_source.wheel,"]
-            #[doc = "wl_pointer.axis_source.wheel_tilt or"]
-            #[doc = "wl_pointer.axis_source.continuous, a wl_pointer.axis_stop event may"]
-            #[doc = "or may not be sent. Whether a compositor sends an axis_stop event"]
-            #[doc = "for these sources is hardware-specific and implementation-dependent;"]
-            #[doc = "clients must not rely on receiving an axis_stop event for these"]
-            #[doc = "scroll sources and should treat scroll sequences from these scroll"]
-            #[doc = "sources as unterminated by default."]
-            #[doc = ""]
-            #[doc = "This event is optional. If the source is unknown for a particular"]
-            #[doc = "axis event sequence, no event is sent."]
-            #[doc = "Only one wl_pointer.axis_source event is permitted per frame."]
-            #[doc = ""]
-            #[doc = "The order of wl_pointer.axis_discrete and wl_pointer.axis_source is"]
-            #[doc = "not guaranteed."]
-            fn axis_source(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the axis_source message was introduced in
+            pub const AXIS_SOURCE_SINCE: u32 = 5u32;
+            ///The interface version the axis_source message was deprecated in, if any
+            pub const AXIS_SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Source information for scroll and other axes.
+            ///
+            ///This event does not occur on its own. It is sent before a
+            ///wl_pointer.frame event and carries the source information for
+            ///all events within that frame.
+            ///
+            ///The source specifies how this event was generated. If the source is
+            ///wl_pointer.axis_source.finger, a wl_pointer.axis_stop event will be
+            ///sent when the user lifts the finger off the device.
+            ///
+            ///If the source is wl_pointer.axis_source.wheel,
+            ///wl_pointer.axis_source.wheel_tilt or
+            ///wl_pointer.axis_source.continuous, a wl_pointer.axis_stop event may
+            ///or may not be sent. Whether a compositor sends an axis_stop event
+            ///for these sources is hardware-specific and implementation-dependent;
+            ///clients must not rely on receiving an axis_stop event for these
+            ///scroll sources and should treat scroll sequences from these scroll
+            ///sources as unterminated by default.
+            ///
+            ///This event is optional. If the source is unknown for a particular
+            ///axis event sequence, no event is sent.
+            ///Only one wl_pointer.axis_source event is permitted per frame.
+            ///
+            ///The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+            ///not guaranteed.
+            ///
+            ///Available since version 5
+            pub fn axis_source<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 axis_source: AxisSource,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_pointer#{}.axis_source({})", sender_id, axis_source);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(axis_source.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 6u16, message = "axis_source",
+                    direction = "out", "args.axis_source" = % axis_source,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 6u16, message = "axis_source",
+                    direction = "out", "args.axis_source" : % = axis_source;
+                    "wl_pointer.axis_source"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(axis_source.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Stop notification for scroll and other axes."]
-            #[doc = ""]
-            #[doc = "For some wl_pointer.axis_source types, a wl_pointer.axis_stop event"]
-            #[doc = "is sent to notify a client that the axis sequence has terminated."]
-            #[doc = "This enables the client to implement kinetic scrolling."]
-            #[doc = "See the wl_pointer.axis_source documentation for information on when"]
-            #[doc = "this event may be generated."]
-            #[doc = ""]
-            #[doc = "Any wl_pointer.axis events with the same axis_source after this"]
-            #[doc = "event should be considered as the start of a new axis motion."]
-            #[doc = ""]
-            #[doc = "The timestamp is to be interpreted identical to the timestamp in the"]
-            #[doc = "wl_pointer.axis event. The timestamp value may be the same as a"]
-            #[doc = "preceding wl_pointer.axis event."]
-            fn axis_stop(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the axis_stop message was introduced in
+            pub const AXIS_STOP_SINCE: u32 = 5u32;
+            ///The interface version the axis_stop message was deprecated in, if any
+            pub const AXIS_STOP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Stop notification for scroll and other axes.
+            ///
+            ///For some wl_pointer.axis_source types, a wl_pointer.axis_stop event
+            ///is sent to notify a client that the axis sequence has terminated.
+            ///This enables the client to implement kinetic scrolling.
+            ///See the wl_pointer.axis_source documentation for information on when
+            ///this event may be generated.
+            ///
+            ///Any wl_pointer.axis events with the same axis_source after this
+            ///event should be considered as the start of a new axis motion.
+            ///
+            ///The timestamp is to be interpreted identical to the timestamp in the
+            ///wl_pointer.axis event. The timestamp value may be the same as a
+            ///preceding wl_pointer.axis event.
+            ///
+            ///Available since version 5
+            pub fn axis_stop<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 axis: Axis,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_pointer#{}.axis_stop({}, {})", sender_id, time, axis);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_uint(axis.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 7u16, message = "axis_stop",
+                    direction = "out", "args.time" = % time, "args.axis" = % axis,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 7u16, message = "axis_stop",
+                    direction = "out", "args.time" : % = time, "args.axis" : % = axis;
+                    "wl_pointer.axis_stop"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_uint(axis.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 7u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Discrete step information for scroll and other axes."]
-            #[doc = ""]
-            #[doc = "This event carries the axis value of the wl_pointer.axis event in"]
-            #[doc = "discrete steps (e.g. mouse wheel clicks)."]
-            #[doc = ""]
-            #[doc = "This event is deprecated with wl_pointer version 8 - this event is not"]
-            #[doc = "sent to clients supporting version 8 or later."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own, it is coupled with a"]
-            #[doc = "wl_pointer.axis event that represents this axis value on a"]
-            #[doc = "continuous scale. The protocol guarantees that each axis_discrete"]
-            #[doc = "event is always followed by exactly one axis event with the same"]
-            #[doc = "axis number within the same wl_pointer.frame. Note that the protocol"]
-            #[doc = "allows for other events to occur between the axis_discrete and"]
-            #[doc = "its coupled axis event, including other axis_discrete or axis"]
-            #[doc = "events. A wl_pointer.frame must not contain more than one axis_discrete"]
-            #[doc = "event per axis type."]
-            #[doc = ""]
-            #[doc = "This event is optional; continuous scrolling devices"]
-            #[doc = "like two-finger scrolling on touchpads do not have discrete"]
-            #[doc = "steps and do not generate this event."]
-            #[doc = ""]
-            #[doc = "The discrete value carries the directional information. e.g. a value"]
-            #[doc = "of -2 is two steps towards the negative direction of this axis."]
-            #[doc = ""]
-            #[doc = "The axis number is identical to the axis number in the associated"]
-            #[doc = "axis event."]
-            #[doc = ""]
-            #[doc = "The order of wl_pointer.axis_discrete and wl_pointer.axis_source is"]
-            #[doc = "not guaranteed."]
-            fn axis_discrete(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the axis_discrete message was introduced in
+            pub const AXIS_DISCRETE_SINCE: u32 = 5u32;
+            ///The interface version the axis_discrete message was deprecated in, if any
+            pub const AXIS_DISCRETE_DEPRECATED_SINCE: Option<u32> = Some(8u32);
+            ///Discrete step information for scroll and other axes.
+            ///
+            ///This event carries the axis value of the wl_pointer.axis event in
+            ///discrete steps (e.g. mouse wheel clicks).
+            ///
+            ///This event is deprecated with wl_pointer version 8 - this event is not
+            ///sent to clients supporting version 8 or later.
+            ///
+            ///This event does not occur on its own, it is coupled with a
+            ///wl_pointer.axis event that represents this axis value on a
+            ///continuous scale. The protocol guarantees that each axis_discrete
+            ///event is always followed by exactly one axis event with the same
+            ///axis number within the same wl_pointer.frame. Note that the protocol
+            ///allows for other events to occur between the axis_discrete and
+            ///its coupled axis event, including other axis_discrete or axis
+            ///events. A wl_pointer.frame must not contain more than one axis_discrete
+            ///event per axis type.
+            ///
+            ///This event is optional; continuous scrolling devices
+            ///like two-finger scrolling on touchpads do not have discrete
+            ///steps and do not generate this event.
+            ///
+            ///The discrete value carries the directional information. e.g. a value
+            ///of -2 is two steps towards the negative direction of this axis.
+            ///
+            ///The axis number is identical to the axis number in the associated
+            ///axis event.
+            ///
+            ///The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+            ///not guaranteed.
+            ///
+            ///Available since version 5
+            ///
+            ///Deprecated since version 8
+            #[deprecated(note = "deprecated since version 8 of the interface")]
+            pub fn axis_discrete<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 axis: Axis,
                 discrete: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.axis_discrete({}, {})",
-                        sender_id,
-                        axis,
-                        discrete
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(axis.into())
-                        .put_int(discrete)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 8u16, message = "axis_discrete",
+                    direction = "out", "args.axis" = % axis, "args.discrete" = %
+                    discrete,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 8u16, message = "axis_discrete",
+                    direction = "out", "args.axis" : % = axis, "args.discrete" : % =
+                    discrete; "wl_pointer.axis_discrete"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(axis.into())
+                    .put_int(discrete)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 8u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Discrete high-resolution scroll information."]
-            #[doc = ""]
-            #[doc = "This event carries high-resolution wheel scroll information,"]
-            #[doc = "with each multiple of 120 representing one logical scroll step"]
-            #[doc = "(a wheel detent). For example, an axis_value120 of 30 is one quarter of"]
-            #[doc = "a logical scroll step in the positive direction, a value120 of"]
-            #[doc = "-240 are two logical scroll steps in the negative direction within the"]
-            #[doc = "same hardware event."]
-            #[doc = "Clients that rely on discrete scrolling should accumulate the"]
-            #[doc = "value120 to multiples of 120 before processing the event."]
-            #[doc = ""]
-            #[doc = "The value120 must not be zero."]
-            #[doc = ""]
-            #[doc = "This event replaces the wl_pointer.axis_discrete event in clients"]
-            #[doc = "supporting wl_pointer version 8 or later."]
-            #[doc = ""]
-            #[doc = "Where a wl_pointer.axis_source event occurs in the same"]
-            #[doc = "wl_pointer.frame, the axis source applies to this event."]
-            #[doc = ""]
-            #[doc = "The order of wl_pointer.axis_value120 and wl_pointer.axis_source is"]
-            #[doc = "not guaranteed."]
-            fn axis_value120(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the axis_value120 message was introduced in
+            pub const AXIS_VALUE120_SINCE: u32 = 8u32;
+            ///The interface version the axis_value120 message was deprecated in, if any
+            pub const AXIS_VALUE120_DEPRECATED_SINCE: Option<u32> = None;
+            ///Discrete high-resolution scroll information.
+            ///
+            ///This event carries high-resolution wheel scroll information,
+            ///with each multiple of 120 representing one logical scroll step
+            ///(a wheel detent). For example, an axis_value120 of 30 is one quarter of
+            ///a logical scroll step in the positive direction, a value120 of
+            ///-240 are two logical scroll steps in the negative direction within the
+            ///same hardware event.
+            ///Clients that rely on discrete scrolling should accumulate the
+            ///value120 to multiples of 120 before processing the event.
+            ///
+            ///The value120 must not be zero.
+            ///
+            ///This event replaces the wl_pointer.axis_discrete event in clients
+            ///supporting wl_pointer version 8 or later.
+            ///
+            ///Where a wl_pointer.axis_source event occurs in the same
+            ///wl_pointer.frame, the axis source applies to this event.
+            ///
+            ///The order of wl_pointer.axis_value120 and wl_pointer.axis_source is
+            ///not guaranteed.
+            ///
+            ///Available since version 8
+            pub fn axis_value120<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 axis: Axis,
                 value120: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.axis_value120({}, {})",
-                        sender_id,
-                        axis,
-                        value120
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(axis.into())
-                        .put_int(value120)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 9u16, message = "axis_value120",
+                    direction = "out", "args.axis" = % axis, "args.value120" = %
+                    value120,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 9u16, message = "axis_value120",
+                    direction = "out", "args.axis" : % = axis, "args.value120" : % =
+                    value120; "wl_pointer.axis_value120"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(axis.into())
+                    .put_int(value120)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 9u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Relative directional information of the entity causing the axis"]
-            #[doc = "motion."]
-            #[doc = ""]
-            #[doc = "For a wl_pointer.axis event, the wl_pointer.axis_relative_direction"]
-            #[doc = "event specifies the movement direction of the entity causing the"]
-            #[doc = "wl_pointer.axis event. For example:"]
-            #[doc = "- if a user's fingers on a touchpad move down and this"]
-            #[doc = "causes a wl_pointer.axis vertical_scroll down event, the physical"]
-            #[doc = "direction is 'identical'"]
-            #[doc = "- if a user's fingers on a touchpad move down and this causes a"]
-            #[doc = "wl_pointer.axis vertical_scroll up scroll up event ('natural"]
-            #[doc = "scrolling'), the physical direction is 'inverted'."]
-            #[doc = ""]
-            #[doc = "A client may use this information to adjust scroll motion of"]
-            #[doc = "components. Specifically, enabling natural scrolling causes the"]
-            #[doc = "content to change direction compared to traditional scrolling."]
-            #[doc = "Some widgets like volume control sliders should usually match the"]
-            #[doc = "physical direction regardless of whether natural scrolling is"]
-            #[doc = "active. This event enables clients to match the scroll direction of"]
-            #[doc = "a widget to the physical direction."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own, it is coupled with a"]
-            #[doc = "wl_pointer.axis event that represents this axis value."]
-            #[doc = "The protocol guarantees that each axis_relative_direction event is"]
-            #[doc = "always followed by exactly one axis event with the same"]
-            #[doc = "axis number within the same wl_pointer.frame. Note that the protocol"]
-            #[doc = "allows for other events to occur between the axis_relative_direction"]
-            #[doc = "and its coupled axis event."]
-            #[doc = ""]
-            #[doc = "The axis number is identical to the axis number in the associated"]
-            #[doc = "axis event."]
-            #[doc = ""]
-            #[doc = "The order of wl_pointer.axis_relative_direction,"]
-            #[doc = "wl_pointer.axis_discrete and wl_pointer.axis_source is not"]
-            #[doc = "guaranteed."]
-            fn axis_relative_direction(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the axis_relative_direction message was introduced in
+            pub const AXIS_RELATIVE_DIRECTION_SINCE: u32 = 9u32;
+            ///The interface version the axis_relative_direction message was deprecated in, if any
+            pub const AXIS_RELATIVE_DIRECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Relative directional information of the entity causing the axis
+            ///motion.
+            ///
+            ///For a wl_pointer.axis event, the wl_pointer.axis_relative_direction
+            ///event specifies the movement direction of the entity causing the
+            ///wl_pointer.axis event. For example:
+            ///- if a user's fingers on a touchpad move down and this
+            ///causes a wl_pointer.axis vertical_scroll down event, the physical
+            ///direction is 'identical'
+            ///- if a user's fingers on a touchpad move down and this causes a
+            ///wl_pointer.axis vertical_scroll up scroll up event ('natural
+            ///scrolling'), the physical direction is 'inverted'.
+            ///
+            ///A client may use this information to adjust scroll motion of
+            ///components. Specifically, enabling natural scrolling causes the
+            ///content to change direction compared to traditional scrolling.
+            ///Some widgets like volume control sliders should usually match the
+            ///physical direction regardless of whether natural scrolling is
+            ///active. This event enables clients to match the scroll direction of
+            ///a widget to the physical direction.
+            ///
+            ///This event does not occur on its own, it is coupled with a
+            ///wl_pointer.axis event that represents this axis value.
+            ///The protocol guarantees that each axis_relative_direction event is
+            ///always followed by exactly one axis event with the same
+            ///axis number within the same wl_pointer.frame. Note that the protocol
+            ///allows for other events to occur between the axis_relative_direction
+            ///and its coupled axis event.
+            ///
+            ///The axis number is identical to the axis number in the associated
+            ///axis event.
+            ///
+            ///The order of wl_pointer.axis_relative_direction,
+            ///wl_pointer.axis_discrete and wl_pointer.axis_source is not
+            ///guaranteed.
+            ///
+            ///Available since version 9
+            pub fn axis_relative_direction<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 axis: Axis,
                 direction: AxisRelativeDirection,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_pointer#{}.axis_relative_direction({}, {})",
-                        sender_id,
-                        axis,
-                        direction
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(axis.into())
-                        .put_uint(direction.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_pointer",
+                    object_id = % sender_id, opcode = 10u16, message =
+                    "axis_relative_direction", direction = "out", "args.axis" = % axis,
+                    "args.direction" = % direction,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_pointer",
+                    object_id : % = sender_id, opcode = 10u16, message =
+                    "axis_relative_direction", direction = "out", "args.axis" : % = axis,
+                    "args.direction" : % = direction;
+                    "wl_pointer.axis_relative_direction"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(axis.into())
+                    .put_uint(direction.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 10u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wl_pointer interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_pointer` interface",
+            label = "`WlPointer` is not implemented for `{Self}`"
+        )]
+        pub trait WlPointer
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wl_pointer";
+            const VERSION: u32 = 10u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_cursor message was introduced in
+            const SET_CURSOR_SINCE: u32 = 1u32;
+            ///The interface version the set_cursor message was deprecated in, if any
+            const SET_CURSOR_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the pointer surface, i.e., the surface that contains the
+            ///pointer image (cursor). This request gives the surface the role
+            ///of a cursor. If the surface already has another role, it raises
+            ///a protocol error.
+            ///
+            ///The cursor actually changes only if the pointer
+            ///focus for this device is one of the requesting client's surfaces
+            ///or the surface parameter is the current pointer surface. If
+            ///there was a previous surface set with this request it is
+            ///replaced. If surface is NULL, the pointer image is hidden.
+            ///
+            ///The parameters hotspot_x and hotspot_y define the position of
+            ///the pointer surface relative to the pointer location. Its
+            ///top-left corner is always at (x, y) - (hotspot_x, hotspot_y),
+            ///where (x, y) are the coordinates of the pointer location, in
+            ///surface-local coordinates.
+            ///
+            ///On wl_surface.offset requests to the pointer surface, hotspot_x
+            ///and hotspot_y are decremented by the x and y parameters
+            ///passed to the request. The offset must be applied by
+            ///wl_surface.commit as usual.
+            ///
+            ///The hotspot can also be updated by passing the currently set
+            ///pointer surface to this request with new values for hotspot_x
+            ///and hotspot_y.
+            ///
+            ///The input region is ignored for wl_surfaces with the role of
+            ///a cursor. When the use as a cursor ends, the wl_surface is
+            ///unmapped.
+            ///
+            ///The serial parameter must match the latest wl_pointer.enter
+            ///serial number sent to the client. Otherwise the request will be
+            ///ignored.
+            fn set_cursor(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+                surface: Option<waynest::ObjectId>,
+                hotspot_x: i32,
+                hotspot_y: i32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 3u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Using this request a client can tell the server that it is not going to
+            ///use the pointer object anymore.
+            ///
+            ///This request destroys the pointer proxy object, so clients must not call
+            ///wl_pointer_destroy() after using this request.
+            ///
+            ///Available since version 3
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -4727,62 +9141,134 @@ pub mod wayland {
                             let hotspot_x = message.int()?;
                             let hotspot_y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_pointer#{}.set_cursor({}, {}, {}, {})",
-                                sender_id,
-                                serial,
-                                surface
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                hotspot_x,
-                                hotspot_y
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_pointer", object_id = % sender_id, opcode = 0u16,
+                                message = "set_cursor", direction = "in", "args.serial" = %
+                                serial, "args.surface" = % surface.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string()), "args.hotspot_x" = %
+                                hotspot_x, "args.hotspot_y" = % hotspot_y,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_pointer", object_id : % = sender_id, opcode = 0u16,
+                                message = "set_cursor", direction = "in", "args.serial" : %
+                                = serial, "args.surface" : % = surface.as_ref()
+                                .map_or("null".to_string(), | v | v.to_string()),
+                                "args.hotspot_x" : % = hotspot_x, "args.hotspot_y" : % =
+                                hotspot_y; "wl_pointer.set_cursor"
                             );
                             self.set_cursor(
-                                connection, sender_id, serial, surface, hotspot_x, hotspot_y,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    serial,
+                                    surface,
+                                    hotspot_x,
+                                    hotspot_y,
+                                )
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_pointer#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_pointer", object_id = % sender_id, opcode = 1u16,
+                                message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_pointer", object_id : % = sender_id, opcode = 1u16,
+                                message = "release", direction = "in"; "wl_pointer.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The wl_keyboard interface represents one or more keyboards"]
-    #[doc = "associated with a seat."]
-    #[doc = ""]
-    #[doc = "Each wl_keyboard has the following logical state:"]
-    #[doc = ""]
-    #[doc = "- an active surface (possibly null),"]
-    #[doc = "- the keys currently logically down,"]
-    #[doc = "- the active modifiers,"]
-    #[doc = "- the active group."]
-    #[doc = ""]
-    #[doc = "By default, the active surface is null, the keys currently logically down"]
-    #[doc = "are empty, the active modifiers and the active group are 0."]
+    ///The wl_keyboard interface represents one or more keyboards
+    ///associated with a seat.
+    ///
+    ///Each wl_keyboard has the following logical state:
+    ///
+    ///- an active surface (possibly null),
+    ///- the keys currently logically down,
+    ///- the active modifiers,
+    ///- the active group.
+    ///
+    ///By default, the active surface is null, the keys currently logically down
+    ///are empty, the active modifiers and the active group are 0.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_keyboard {
-        #[doc = "This specifies the format of the keymap provided to the"]
-        #[doc = "client with the wl_keyboard.keymap event."]
+        ///This specifies the format of the keymap provided to the
+        ///client with the wl_keyboard.keymap event.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum KeymapFormat {
-            #[doc = "no keymap; client must understand how to interpret the raw keycode"]
+            ///no keymap; client must understand how to interpret the raw keycode
             NoKeymap = 0u32,
-            #[doc = "libxkbcommon compatible, null-terminated string; to determine the xkb keycode, clients must add 8 to the key event keycode"]
+            ///libxkbcommon compatible, null-terminated string; to determine the xkb keycode, clients must add 8 to the key event keycode
             XkbV1 = 1u32,
         }
+        #[allow(deprecated)]
+        impl KeymapFormat {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::NoKeymap => 1u32,
+                    Self::XkbV1 => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::NoKeymap => None,
+                    Self::XkbV1 => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("no_keymap", Self::NoKeymap),
+                ("xkb_v1", Self::XkbV1),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::NoKeymap => "no_keymap",
+                    Self::XkbV1 => "xkb_v1",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<KeymapFormat> for u32 {
             fn from(value: KeymapFormat) -> Self {
                 value as u32
             }
         }
+        impl From<KeymapFormat> for i32 {
+            fn from(value: KeymapFormat) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for KeymapFormat {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4793,36 +9279,94 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for KeymapFormat {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for KeymapFormat {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
-            }
-        }
-        #[doc = "Describes the physical state of a key that produced the key event."]
-        #[doc = ""]
-        #[doc = "Since version 10, the key can be in a \"repeated\" pseudo-state which"]
-        #[doc = "means the same as \"pressed\", but is used to signal repetition in the"]
-        #[doc = "key event."]
-        #[doc = ""]
-        #[doc = "The key may only enter the repeated state after entering the pressed"]
-        #[doc = "state and before entering the released state. This event may be"]
-        #[doc = "generated multiple times while the key is down."]
+                f.write_str(self.as_str())
+            }
+        }
+        ///Describes the physical state of a key that produced the key event.
+        ///
+        ///Since version 10, the key can be in a "repeated" pseudo-state which
+        ///means the same as "pressed", but is used to signal repetition in the
+        ///key event.
+        ///
+        ///The key may only enter the repeated state after entering the pressed
+        ///state and before entering the released state. This event may be
+        ///generated multiple times while the key is down.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum KeyState {
-            #[doc = "key is not pressed"]
+            ///key is not pressed
             Released = 0u32,
-            #[doc = "key is pressed"]
+            ///key is pressed
             Pressed = 1u32,
-            #[doc = "key was repeated"]
+            ///key was repeated
+            ///
+            ///Available since version 10
             Repeated = 2u32,
         }
+        #[allow(deprecated)]
+        impl KeyState {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Released => 1u32,
+                    Self::Pressed => 1u32,
+                    Self::Repeated => 10u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Released => None,
+                    Self::Pressed => None,
+                    Self::Repeated => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("released", Self::Released),
+                ("pressed", Self::Pressed),
+                ("repeated", Self::Repeated),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Released => "released",
+                    Self::Pressed => "pressed",
+                    Self::Repeated => "repeated",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<KeyState> for u32 {
             fn from(value: KeyState) -> Self {
                 value as u32
             }
         }
+        impl From<KeyState> for i32 {
+            fn from(value: KeyState) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for KeyState {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4830,345 +9374,435 @@ pub mod wayland {
                     0u32 => Ok(Self::Released),
                     1u32 => Ok(Self::Pressed),
                     2u32 => Ok(Self::Repeated),
-                    _ => Err(waynest::ProtocolError::MalformedPayload),
-                }
-            }
-        }
-        impl std::fmt::Display for KeyState {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
-            }
-        }
-        #[doc = "Trait to implement the wl_keyboard interface. See the module level documentation for more info"]
-        pub trait WlKeyboard
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wl_keyboard";
-            const VERSION: u32 = 10u32;
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event provides a file descriptor to the client which can be"]
-            #[doc = "memory-mapped in read-only mode to provide a keyboard mapping"]
-            #[doc = "description."]
-            #[doc = ""]
-            #[doc = "From version 7 onwards, the fd must be mapped with MAP_PRIVATE by"]
-            #[doc = "the recipient, as MAP_SHARED may fail."]
-            fn keymap(
-                &self,
-                connection: &mut Self::Connection,
+                    _ => Err(waynest::ProtocolError::MalformedPayload),
+                }
+            }
+        }
+        impl TryFrom<i32> for KeyState {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
+        impl std::fmt::Display for KeyState {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_keyboard interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the keymap message was introduced in
+            pub const KEYMAP_SINCE: u32 = 1u32;
+            ///The interface version the keymap message was deprecated in, if any
+            pub const KEYMAP_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event provides a file descriptor to the client which can be
+            ///memory-mapped in read-only mode to provide a keyboard mapping
+            ///description.
+            ///
+            ///From version 7 onwards, the fd must be mapped with MAP_PRIVATE by
+            ///the recipient, as MAP_SHARED may fail.
+            pub fn keymap<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 format: KeymapFormat,
                 fd: std::os::fd::OwnedFd,
                 size: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_keyboard#{}.keymap({}, {}, {})",
-                        sender_id,
-                        format,
-                        std::os::fd::AsRawFd::as_raw_fd(&fd),
-                        size
-                    );
-                    waynest::Connection::push_fd(connection, fd);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(format.into())
-                        .put_uint(size)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_keyboard",
+                    object_id = % sender_id, opcode = 0u16, message = "keymap", direction
+                    = "out", "args.format" = % format, "args.fd" = %
+                    std::os::fd::AsRawFd::as_raw_fd(& fd), "args.size" = % size,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_keyboard",
+                    object_id : % = sender_id, opcode = 0u16, message = "keymap",
+                    direction = "out", "args.format" : % = format, "args.fd" : % =
+                    std::os::fd::AsRawFd::as_raw_fd(& fd), "args.size" : % = size;
+                    "wl_keyboard.keymap"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(format.into())
+                    .put_uint(size)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![fd],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this seat's keyboard focus is on a certain"]
-            #[doc = "surface."]
-            #[doc = ""]
-            #[doc = "The compositor must send the wl_keyboard.modifiers event after this"]
-            #[doc = "event."]
-            #[doc = ""]
-            #[doc = "In the wl_keyboard logical state, this event sets the active surface to"]
-            #[doc = "the surface argument and the keys currently logically down to the keys"]
-            #[doc = "in the keys argument. The compositor must not send this event if the"]
-            #[doc = "wl_keyboard already had an active surface immediately before this event."]
-            #[doc = ""]
-            #[doc = "Clients should not use the list of pressed keys to emulate key-press"]
-            #[doc = "events. The order of keys in the list is unspecified."]
-            fn enter(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the enter message was introduced in
+            pub const ENTER_SINCE: u32 = 1u32;
+            ///The interface version the enter message was deprecated in, if any
+            pub const ENTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this seat's keyboard focus is on a certain
+            ///surface.
+            ///
+            ///The compositor must send the wl_keyboard.modifiers event after this
+            ///event.
+            ///
+            ///In the wl_keyboard logical state, this event sets the active surface to
+            ///the surface argument and the keys currently logically down to the keys
+            ///in the keys argument. The compositor must not send this event if the
+            ///wl_keyboard already had an active surface immediately before this event.
+            ///
+            ///Clients should not use the list of pressed keys to emulate key-press
+            ///events. The order of keys in the list is unspecified.
+            pub fn enter<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 surface: waynest::ObjectId,
                 keys: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_keyboard#{}.enter({}, {}, array[{}])",
-                        sender_id,
-                        serial,
-                        surface,
-                        keys.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(surface))
-                        .put_array(keys)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_keyboard",
+                    object_id = % sender_id, opcode = 1u16, message = "enter", direction
+                    = "out", "args.serial" = % serial, "args.surface" = % surface,
+                    "args.keys" = % keys.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_keyboard",
+                    object_id : % = sender_id, opcode = 1u16, message = "enter",
+                    direction = "out", "args.serial" : % = serial, "args.surface" : % =
+                    surface, "args.keys" : % = keys.len(); "wl_keyboard.enter"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(surface))
+                    .put_array(keys)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this seat's keyboard focus is no longer on"]
-            #[doc = "a certain surface."]
-            #[doc = ""]
-            #[doc = "The leave notification is sent before the enter notification"]
-            #[doc = "for the new focus."]
-            #[doc = ""]
-            #[doc = "In the wl_keyboard logical state, this event resets all values to their"]
-            #[doc = "defaults. The compositor must not send this event if the active surface"]
-            #[doc = "of the wl_keyboard was not equal to the surface argument immediately"]
-            #[doc = "before this event."]
-            fn leave(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the leave message was introduced in
+            pub const LEAVE_SINCE: u32 = 1u32;
+            ///The interface version the leave message was deprecated in, if any
+            pub const LEAVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this seat's keyboard focus is no longer on
+            ///a certain surface.
+            ///
+            ///The leave notification is sent before the enter notification
+            ///for the new focus.
+            ///
+            ///In the wl_keyboard logical state, this event resets all values to their
+            ///defaults. The compositor must not send this event if the active surface
+            ///of the wl_keyboard was not equal to the surface argument immediately
+            ///before this event.
+            pub fn leave<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_keyboard#{}.leave({}, {})",
-                        sender_id,
-                        serial,
-                        surface
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(surface))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_keyboard",
+                    object_id = % sender_id, opcode = 2u16, message = "leave", direction
+                    = "out", "args.serial" = % serial, "args.surface" = % surface,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_keyboard",
+                    object_id : % = sender_id, opcode = 2u16, message = "leave",
+                    direction = "out", "args.serial" : % = serial, "args.surface" : % =
+                    surface; "wl_keyboard.leave"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(surface))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "A key was pressed or released."]
-            #[doc = "The time argument is a timestamp with millisecond"]
-            #[doc = "granularity, with an undefined base."]
-            #[doc = ""]
-            #[doc = "The key is a platform-specific key code that can be interpreted"]
-            #[doc = "by feeding it to the keyboard mapping (see the keymap event)."]
-            #[doc = ""]
-            #[doc = "If this event produces a change in modifiers, then the resulting"]
-            #[doc = "wl_keyboard.modifiers event must be sent after this event."]
-            #[doc = ""]
-            #[doc = "In the wl_keyboard logical state, this event adds the key to the keys"]
-            #[doc = "currently logically down (if the state argument is pressed) or removes"]
-            #[doc = "the key from the keys currently logically down (if the state argument is"]
-            #[doc = "released). The compositor must not send this event if the wl_keyboard"]
-            #[doc = "did not have an active surface immediately before this event. The"]
-            #[doc = "compositor must not send this event if state is pressed (resp. released)"]
-            #[doc = "and the key was already logically down (resp. was not logically down)"]
-            #[doc = "immediately before this event."]
-            #[doc = ""]
-            #[doc = "Since version 10, compositors may send key events with the \"repeated\""]
-            #[doc = "key state when a wl_keyboard.repeat_info event with a rate argument of"]
-            #[doc = "0 has been received. This allows the compositor to take over the"]
-            #[doc = "responsibility of key repetition."]
-            fn key(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the key message was introduced in
+            pub const KEY_SINCE: u32 = 1u32;
+            ///The interface version the key message was deprecated in, if any
+            pub const KEY_DEPRECATED_SINCE: Option<u32> = None;
+            ///A key was pressed or released.
+            ///The time argument is a timestamp with millisecond
+            ///granularity, with an undefined base.
+            ///
+            ///The key is a platform-specific key code that can be interpreted
+            ///by feeding it to the keyboard mapping (see the keymap event).
+            ///
+            ///If this event produces a change in modifiers, then the resulting
+            ///wl_keyboard.modifiers event must be sent after this event.
+            ///
+            ///In the wl_keyboard logical state, this event adds the key to the keys
+            ///currently logically down (if the state argument is pressed) or removes
+            ///the key from the keys currently logically down (if the state argument is
+            ///released). The compositor must not send this event if the wl_keyboard
+            ///did not have an active surface immediately before this event. The
+            ///compositor must not send this event if state is pressed (resp. released)
+            ///and the key was already logically down (resp. was not logically down)
+            ///immediately before this event.
+            ///
+            ///Since version 10, compositors may send key events with the "repeated"
+            ///key state when a wl_keyboard.repeat_info event with a rate argument of
+            ///0 has been received. This allows the compositor to take over the
+            ///responsibility of key repetition.
+            pub fn key<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 time: u32,
                 key: u32,
-                state: KeyState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_keyboard#{}.key({}, {}, {}, {})",
-                        sender_id,
-                        serial,
-                        time,
-                        key,
-                        state
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(time)
-                        .put_uint(key)
-                        .put_uint(state.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+                state_arg: KeyState,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_keyboard",
+                    object_id = % sender_id, opcode = 3u16, message = "key", direction =
+                    "out", "args.serial" = % serial, "args.time" = % time, "args.key" = %
+                    key, "args.state" = % state_arg,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_keyboard",
+                    object_id : % = sender_id, opcode = 3u16, message = "key", direction
+                    = "out", "args.serial" : % = serial, "args.time" : % = time,
+                    "args.key" : % = key, "args.state" : % = state_arg; "wl_keyboard.key"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(time)
+                    .put_uint(key)
+                    .put_uint(state_arg.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notifies clients that the modifier and/or group state has"]
-            #[doc = "changed, and it should update its local state."]
-            #[doc = ""]
-            #[doc = "The compositor may send this event without a surface of the client"]
-            #[doc = "having keyboard focus, for example to tie modifier information to"]
-            #[doc = "pointer focus instead. If a modifier event with pressed modifiers is sent"]
-            #[doc = "without a prior enter event, the client can assume the modifier state is"]
-            #[doc = "valid until it receives the next wl_keyboard.modifiers event. In order to"]
-            #[doc = "reset the modifier state again, the compositor can send a"]
-            #[doc = "wl_keyboard.modifiers event with no pressed modifiers."]
-            #[doc = ""]
-            #[doc = "In the wl_keyboard logical state, this event updates the modifiers and"]
-            #[doc = "group."]
-            fn modifiers(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the modifiers message was introduced in
+            pub const MODIFIERS_SINCE: u32 = 1u32;
+            ///The interface version the modifiers message was deprecated in, if any
+            pub const MODIFIERS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notifies clients that the modifier and/or group state has
+            ///changed, and it should update its local state.
+            ///
+            ///The compositor may send this event without a surface of the client
+            ///having keyboard focus, for example to tie modifier information to
+            ///pointer focus instead. If a modifier event with pressed modifiers is sent
+            ///without a prior enter event, the client can assume the modifier state is
+            ///valid until it receives the next wl_keyboard.modifiers event. In order to
+            ///reset the modifier state again, the compositor can send a
+            ///wl_keyboard.modifiers event with no pressed modifiers.
+            ///
+            ///In the wl_keyboard logical state, this event updates the modifiers and
+            ///group.
+            pub fn modifiers<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 mods_depressed: u32,
                 mods_latched: u32,
                 mods_locked: u32,
                 group: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_keyboard#{}.modifiers({}, {}, {}, {}, {})",
-                        sender_id,
-                        serial,
-                        mods_depressed,
-                        mods_latched,
-                        mods_locked,
-                        group
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(mods_depressed)
-                        .put_uint(mods_latched)
-                        .put_uint(mods_locked)
-                        .put_uint(group)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_keyboard",
+                    object_id = % sender_id, opcode = 4u16, message = "modifiers",
+                    direction = "out", "args.serial" = % serial, "args.mods_depressed" =
+                    % mods_depressed, "args.mods_latched" = % mods_latched,
+                    "args.mods_locked" = % mods_locked, "args.group" = % group,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_keyboard",
+                    object_id : % = sender_id, opcode = 4u16, message = "modifiers",
+                    direction = "out", "args.serial" : % = serial, "args.mods_depressed"
+                    : % = mods_depressed, "args.mods_latched" : % = mods_latched,
+                    "args.mods_locked" : % = mods_locked, "args.group" : % = group;
+                    "wl_keyboard.modifiers"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(mods_depressed)
+                    .put_uint(mods_latched)
+                    .put_uint(mods_locked)
+                    .put_uint(group)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Informs the client about the keyboard's repeat rate and delay."]
-            #[doc = ""]
-            #[doc = "This event is sent as soon as the wl_keyboard object has been created,"]
-            #[doc = "and is guaranteed to be received by the client before any key press"]
-            #[doc = "event."]
-            #[doc = ""]
-            #[doc = "Negative values for either rate or delay are illegal. A rate of zero"]
-            #[doc = "will disable any repeating (regardless of the value of delay)."]
-            #[doc = ""]
-            #[doc = "This event can be sent later on as well with a new value if necessary,"]
-            #[doc = "so clients should continue listening for the event past the creation"]
-            #[doc = "of wl_keyboard."]
-            fn repeat_info(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the repeat_info message was introduced in
+            pub const REPEAT_INFO_SINCE: u32 = 4u32;
+            ///The interface version the repeat_info message was deprecated in, if any
+            pub const REPEAT_INFO_DEPRECATED_SINCE: Option<u32> = None;
+            ///Informs the client about the keyboard's repeat rate and delay.
+            ///
+            ///This event is sent as soon as the wl_keyboard object has been created,
+            ///and is guaranteed to be received by the client before any key press
+            ///event.
+            ///
+            ///Negative values for either rate or delay are illegal. A rate of zero
+            ///will disable any repeating (regardless of the value of delay).
+            ///
+            ///This event can be sent later on as well with a new value if necessary,
+            ///so clients should continue listening for the event past the creation
+            ///of wl_keyboard.
+            ///
+            ///Available since version 4
+            pub fn repeat_info<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 rate: i32,
                 delay: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_keyboard#{}.repeat_info({}, {})",
-                        sender_id,
-                        rate,
-                        delay
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(rate)
-                        .put_int(delay)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_keyboard",
+                    object_id = % sender_id, opcode = 5u16, message = "repeat_info",
+                    direction = "out", "args.rate" = % rate, "args.delay" = % delay,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_keyboard",
+                    object_id : % = sender_id, opcode = 5u16, message = "repeat_info",
+                    direction = "out", "args.rate" : % = rate, "args.delay" : % = delay;
+                    "wl_keyboard.repeat_info"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(rate)
+                    .put_int(delay)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wl_keyboard interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_keyboard` interface",
+            label = "`WlKeyboard` is not implemented for `{Self}`"
+        )]
+        pub trait WlKeyboard
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wl_keyboard";
+            const VERSION: u32 = 10u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 3u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///
+            ///Available since version 3
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_keyboard#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_keyboard", object_id = % sender_id, opcode = 0u16,
+                                message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_keyboard", object_id : % = sender_id, opcode = 0u16,
+                                message = "release", direction = "in"; "wl_keyboard.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The wl_touch interface represents a touchscreen"]
-    #[doc = "associated with a seat."]
-    #[doc = ""]
-    #[doc = "Touch interactions can consist of one or more contacts."]
-    #[doc = "For each contact, a series of events is generated, starting"]
-    #[doc = "with a down event, followed by zero or more motion events,"]
-    #[doc = "and ending with an up event. Events relating to the same"]
-    #[doc = "contact point can be identified by the ID of the sequence."]
+    ///The wl_touch interface represents a touchscreen
+    ///associated with a seat.
+    ///
+    ///Touch interactions can consist of one or more contacts.
+    ///For each contact, a series of events is generated, starting
+    ///with a down event, followed by zero or more motion events,
+    ///and ending with an up event. Events relating to the same
+    ///contact point can be identified by the ID of the sequence.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_touch {
-        #[doc = "Trait to implement the wl_touch interface. See the module level documentation for more info"]
-        pub trait WlTouch
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wl_touch";
-            const VERSION: u32 = 10u32;
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "A new touch point has appeared on the surface. This touch point is"]
-            #[doc = "assigned a unique ID. Future events from this touch point reference"]
-            #[doc = "this ID. The ID ceases to be valid after a touch up event and may be"]
-            #[doc = "reused in the future."]
-            fn down(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_touch interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the down message was introduced in
+            pub const DOWN_SINCE: u32 = 1u32;
+            ///The interface version the down message was deprecated in, if any
+            pub const DOWN_DEPRECATED_SINCE: Option<u32> = None;
+            ///A new touch point has appeared on the surface. This touch point is
+            ///assigned a unique ID. Future events from this touch point reference
+            ///this ID. The ID ceases to be valid after a touch up event and may be
+            ///reused in the future.
+            pub fn down<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 time: u32,
@@ -5176,311 +9810,468 @@ pub mod wayland {
                 id: i32,
                 x: waynest::Fixed,
                 y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_touch#{}.down({}, {}, {}, {}, {}, {})",
-                        sender_id,
-                        serial,
-                        time,
-                        surface,
-                        id,
-                        x,
-                        y
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(time)
-                        .put_object(Some(surface))
-                        .put_int(id)
-                        .put_fixed(x)
-                        .put_fixed(y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 0u16, message = "down", direction =
+                    "out", "args.serial" = % serial, "args.time" = % time, "args.surface"
+                    = % surface, "args.id" = % id, "args.x" = % x, "args.y" = % y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 0u16, message = "down", direction
+                    = "out", "args.serial" : % = serial, "args.time" : % = time,
+                    "args.surface" : % = surface, "args.id" : % = id, "args.x" : % = x,
+                    "args.y" : % = y; "wl_touch.down"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(time)
+                    .put_object(Some(surface))
+                    .put_int(id)
+                    .put_fixed(x)
+                    .put_fixed(y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The touch point has disappeared. No further events will be sent for"]
-            #[doc = "this touch point and the touch point's ID is released and may be"]
-            #[doc = "reused in a future touch down event."]
-            fn up(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the up message was introduced in
+            pub const UP_SINCE: u32 = 1u32;
+            ///The interface version the up message was deprecated in, if any
+            pub const UP_DEPRECATED_SINCE: Option<u32> = None;
+            ///The touch point has disappeared. No further events will be sent for
+            ///this touch point and the touch point's ID is released and may be
+            ///reused in a future touch down event.
+            pub fn up<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 time: u32,
                 id: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_touch#{}.up({}, {}, {})", sender_id, serial, time, id);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(time)
-                        .put_int(id)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 1u16, message = "up", direction =
+                    "out", "args.serial" = % serial, "args.time" = % time, "args.id" = %
+                    id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 1u16, message = "up", direction =
+                    "out", "args.serial" : % = serial, "args.time" : % = time, "args.id"
+                    : % = id; "wl_touch.up"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(time)
+                    .put_int(id)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "A touch point has changed coordinates."]
-            fn motion(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the motion message was introduced in
+            pub const MOTION_SINCE: u32 = 1u32;
+            ///The interface version the motion message was deprecated in, if any
+            pub const MOTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///A touch point has changed coordinates.
+            pub fn motion<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 id: i32,
                 x: waynest::Fixed,
                 y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_touch#{}.motion({}, {}, {}, {})",
-                        sender_id,
-                        time,
-                        id,
-                        x,
-                        y
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_int(id)
-                        .put_fixed(x)
-                        .put_fixed(y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 2u16, message = "motion", direction
+                    = "out", "args.time" = % time, "args.id" = % id, "args.x" = % x,
+                    "args.y" = % y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 2u16, message = "motion",
+                    direction = "out", "args.time" : % = time, "args.id" : % = id,
+                    "args.x" : % = x, "args.y" : % = y; "wl_touch.motion"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_int(id)
+                    .put_fixed(x)
+                    .put_fixed(y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Indicates the end of a set of events that logically belong together."]
-            #[doc = "A client is expected to accumulate the data in all events within the"]
-            #[doc = "frame before proceeding."]
-            #[doc = ""]
-            #[doc = "A wl_touch.frame terminates at least one event but otherwise no"]
-            #[doc = "guarantee is provided about the set of events within a frame. A client"]
-            #[doc = "must assume that any state not updated in a frame is unchanged from the"]
-            #[doc = "previously known state."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_touch#{}.frame()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the end of a set of events that logically belong together.
+            ///A client is expected to accumulate the data in all events within the
+            ///frame before proceeding.
+            ///
+            ///A wl_touch.frame terminates at least one event but otherwise no
+            ///guarantee is provided about the set of events within a frame. A client
+            ///must assume that any state not updated in a frame is unchanged from the
+            ///previously known state.
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 3u16, message = "frame", direction
+                    = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 3u16, message = "frame",
+                    direction = "out"; "wl_touch.frame"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent if the compositor decides the touch stream is a global"]
-            #[doc = "gesture. No further events are sent to the clients from that"]
-            #[doc = "particular gesture. Touch cancellation applies to all touch points"]
-            #[doc = "currently active on this client's surface. The client is"]
-            #[doc = "responsible for finalizing the touch points, future touch points on"]
-            #[doc = "this surface may reuse the touch point ID."]
-            #[doc = ""]
-            #[doc = "No frame event is required after the cancel event."]
-            fn cancel(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_touch#{}.cancel()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the cancel message was introduced in
+            pub const CANCEL_SINCE: u32 = 1u32;
+            ///The interface version the cancel message was deprecated in, if any
+            pub const CANCEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent if the compositor decides the touch stream is a global
+            ///gesture. No further events are sent to the clients from that
+            ///particular gesture. Touch cancellation applies to all touch points
+            ///currently active on this client's surface. The client is
+            ///responsible for finalizing the touch points, future touch points on
+            ///this surface may reuse the touch point ID.
+            ///
+            ///No frame event is required after the cancel event.
+            pub fn cancel<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 4u16, message = "cancel", direction
+                    = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 4u16, message = "cancel",
+                    direction = "out"; "wl_touch.cancel"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent when a touchpoint has changed its shape."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own. It is sent before a"]
-            #[doc = "wl_touch.frame event and carries the new shape information for"]
-            #[doc = "any previously reported, or new touch points of that frame."]
-            #[doc = ""]
-            #[doc = "Other events describing the touch point such as wl_touch.down,"]
-            #[doc = "wl_touch.motion or wl_touch.orientation may be sent within the"]
-            #[doc = "same wl_touch.frame. A client should treat these events as a single"]
-            #[doc = "logical touch point update. The order of wl_touch.shape,"]
-            #[doc = "wl_touch.orientation and wl_touch.motion is not guaranteed."]
-            #[doc = "A wl_touch.down event is guaranteed to occur before the first"]
-            #[doc = "wl_touch.shape event for this touch ID but both events may occur within"]
-            #[doc = "the same wl_touch.frame."]
-            #[doc = ""]
-            #[doc = "A touchpoint shape is approximated by an ellipse through the major and"]
-            #[doc = "minor axis length. The major axis length describes the longer diameter"]
-            #[doc = "of the ellipse, while the minor axis length describes the shorter"]
-            #[doc = "diameter. Major and minor are orthogonal and both are specified in"]
-            #[doc = "surface-local coordinates. The center of the ellipse is always at the"]
-            #[doc = "touchpoint location as reported by wl_touch.down or wl_touch.move."]
-            #[doc = ""]
-            #[doc = "This event is only sent by the compositor if the touch device supports"]
-            #[doc = "shape reports. The client has to make reasonable assumptions about the"]
-            #[doc = "shape if it did not receive this event."]
-            fn shape(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the shape message was introduced in
+            pub const SHAPE_SINCE: u32 = 6u32;
+            ///The interface version the shape message was deprecated in, if any
+            pub const SHAPE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent when a touchpoint has changed its shape.
+            ///
+            ///This event does not occur on its own. It is sent before a
+            ///wl_touch.frame event and carries the new shape information for
+            ///any previously reported, or new touch points of that frame.
+            ///
+            ///Other events describing the touch point such as wl_touch.down,
+            ///wl_touch.motion or wl_touch.orientation may be sent within the
+            ///same wl_touch.frame. A client should treat these events as a single
+            ///logical touch point update. The order of wl_touch.shape,
+            ///wl_touch.orientation and wl_touch.motion is not guaranteed.
+            ///A wl_touch.down event is guaranteed to occur before the first
+            ///wl_touch.shape event for this touch ID but both events may occur within
+            ///the same wl_touch.frame.
+            ///
+            ///A touchpoint shape is approximated by an ellipse through the major and
+            ///minor axis length. The major axis length describes the longer diameter
+            ///of the ellipse, while the minor axis length describes the shorter
+            ///diameter. Major and minor are orthogonal and both are specified in
+            ///surface-local coordinates. The center of the ellipse is always at the
+            ///touchpoint location as reported by wl_touch.down or wl_touch.move.
+            ///
+            ///This event is only sent by the compositor if the touch device supports
+            ///shape reports. The client has to make reasonable assumptions about the
+            ///shape if it did not receive this event.
+            ///
+            ///Available since version 6
+            pub fn shape<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: i32,
                 major: waynest::Fixed,
                 minor: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_touch#{}.shape({}, {}, {})",
-                        sender_id,
-                        id,
-                        major,
-                        minor
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(id)
-                        .put_fixed(major)
-                        .put_fixed(minor)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 5u16, message = "shape", direction
+                    = "out", "args.id" = % id, "args.major" = % major, "args.minor" = %
+                    minor,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 5u16, message = "shape",
+                    direction = "out", "args.id" : % = id, "args.major" : % = major,
+                    "args.minor" : % = minor; "wl_touch.shape"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(id)
+                    .put_fixed(major)
+                    .put_fixed(minor)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent when a touchpoint has changed its orientation."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own. It is sent before a"]
-            #[doc = "wl_touch.frame event and carries the new shape information for"]
-            #[doc = "any previously reported, or new touch points of that frame."]
-            #[doc = ""]
-            #[doc = "Other events describing the touch point such as wl_touch.down,"]
-            #[doc = "wl_touch.motion or wl_touch.shape may be sent within the"]
-            #[doc = "same wl_touch.frame. A client should treat these events as a single"]
-            #[doc = "logical touch point update. The order of wl_touch.shape,"]
-            #[doc = "wl_touch.orientation and wl_touch.motion is not guaranteed."]
-            #[doc = "A wl_touch.down event is guaranteed to occur before the first"]
-            #[doc = "wl_touch.orientation event for this touch ID but both events may occur"]
-            #[doc = "within the same wl_touch.frame."]
-            #[doc = ""]
-            #[doc = "The orientation describes the clockwise angle of a touchpoint's major"]
-            #[doc = "axis to the positive surface y-axis and is normalized to the -180 to"]
-            #[doc = "+180 degree range. The granularity of orientation depends on the touch"]
-            #[doc = "device, some devices only support binary rotation values between 0 and"]
-            #[doc = "90 degrees."]
-            #[doc = ""]
-            #[doc = "This event is only sent by the compositor if the touch device supports"]
-            #[doc = "orientation reports."]
-            fn orientation(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the orientation message was introduced in
+            pub const ORIENTATION_SINCE: u32 = 6u32;
+            ///The interface version the orientation message was deprecated in, if any
+            pub const ORIENTATION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent when a touchpoint has changed its orientation.
+            ///
+            ///This event does not occur on its own. It is sent before a
+            ///wl_touch.frame event and carries the new shape information for
+            ///any previously reported, or new touch points of that frame.
+            ///
+            ///Other events describing the touch point such as wl_touch.down,
+            ///wl_touch.motion or wl_touch.shape may be sent within the
+            ///same wl_touch.frame. A client should treat these events as a single
+            ///logical touch point update. The order of wl_touch.shape,
+            ///wl_touch.orientation and wl_touch.motion is not guaranteed.
+            ///A wl_touch.down event is guaranteed to occur before the first
+            ///wl_touch.orientation event for this touch ID but both events may occur
+            ///within the same wl_touch.frame.
+            ///
+            ///The orientation describes the clockwise angle of a touchpoint's major
+            ///axis to the positive surface y-axis and is normalized to the -180 to
+            ///+180 degree range. The granularity of orientation depends on the touch
+            ///device, some devices only support binary rotation values between 0 and
+            ///90 degrees.
+            ///
+            ///This event is only sent by the compositor if the touch device supports
+            ///orientation reports.
+            ///
+            ///Available since version 6
+            pub fn orientation<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: i32,
                 orientation: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_touch#{}.orientation({}, {})",
-                        sender_id,
-                        id,
-                        orientation
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(id)
-                        .put_fixed(orientation)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_touch",
+                    object_id = % sender_id, opcode = 6u16, message = "orientation",
+                    direction = "out", "args.id" = % id, "args.orientation" = %
+                    orientation,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_touch",
+                    object_id : % = sender_id, opcode = 6u16, message = "orientation",
+                    direction = "out", "args.id" : % = id, "args.orientation" : % =
+                    orientation; "wl_touch.orientation"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(id)
+                    .put_fixed(orientation)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wl_touch interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_touch` interface",
+            label = "`WlTouch` is not implemented for `{Self}`"
+        )]
+        pub trait WlTouch
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wl_touch";
+            const VERSION: u32 = 10u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 3u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///
+            ///Available since version 3
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_touch#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_touch", object_id = % sender_id, opcode = 0u16, message
+                                = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_touch", object_id : % = sender_id, opcode = 0u16,
+                                message = "release", direction = "in"; "wl_touch.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "An output describes part of the compositor geometry.  The"]
-    #[doc = "compositor works in the 'compositor coordinate system' and an"]
-    #[doc = "output corresponds to a rectangular area in that space that is"]
-    #[doc = "actually visible.  This typically corresponds to a monitor that"]
-    #[doc = "displays part of the compositor space.  This object is published"]
-    #[doc = "as global during start up, or when a monitor is hotplugged."]
+    ///An output describes part of the compositor geometry.  The
+    ///compositor works in the 'compositor coordinate system' and an
+    ///output corresponds to a rectangular area in that space that is
+    ///actually visible.  This typically corresponds to a monitor that
+    ///displays part of the compositor space.  This object is published
+    ///as global during start up, or when a monitor is hotplugged.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_output {
-        #[doc = "This enumeration describes how the physical"]
-        #[doc = "pixels on an output are laid out."]
+        ///This enumeration describes how the physical
+        ///pixels on an output are laid out.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Subpixel {
-            #[doc = "unknown geometry"]
+            ///unknown geometry
             Unknown = 0u32,
-            #[doc = "no geometry"]
+            ///no geometry
             None = 1u32,
-            #[doc = "horizontal RGB"]
+            ///horizontal RGB
             HorizontalRgb = 2u32,
-            #[doc = "horizontal BGR"]
+            ///horizontal BGR
             HorizontalBgr = 3u32,
-            #[doc = "vertical RGB"]
+            ///vertical RGB
             VerticalRgb = 4u32,
-            #[doc = "vertical BGR"]
+            ///vertical BGR
             VerticalBgr = 5u32,
         }
+        #[allow(deprecated)]
+        impl Subpixel {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Unknown => 1u32,
+                    Self::None => 1u32,
+                    Self::HorizontalRgb => 1u32,
+                    Self::HorizontalBgr => 1u32,
+                    Self::VerticalRgb => 1u32,
+                    Self::VerticalBgr => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Unknown => None,
+                    Self::None => None,
+                    Self::HorizontalRgb => None,
+                    Self::HorizontalBgr => None,
+                    Self::VerticalRgb => None,
+                    Self::VerticalBgr => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("unknown", Self::Unknown),
+                ("none", Self::None),
+                ("horizontal_rgb", Self::HorizontalRgb),
+                ("horizontal_bgr", Self::HorizontalBgr),
+                ("vertical_rgb", Self::VerticalRgb),
+                ("vertical_bgr", Self::VerticalBgr),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Unknown => "unknown",
+                    Self::None => "none",
+                    Self::HorizontalRgb => "horizontal_rgb",
+                    Self::HorizontalBgr => "horizontal_bgr",
+                    Self::VerticalRgb => "vertical_rgb",
+                    Self::VerticalBgr => "vertical_bgr",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Subpixel> for u32 {
             fn from(value: Subpixel) -> Self {
                 value as u32
             }
         }
+        impl From<Subpixel> for i32 {
+            fn from(value: Subpixel) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Subpixel {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -5495,47 +10286,123 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Subpixel {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Subpixel {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
-            }
-        }
-        #[doc = "This describes transformations that clients and compositors apply to"]
-        #[doc = "buffer contents."]
-        #[doc = ""]
-        #[doc = "The flipped values correspond to an initial flip around a"]
-        #[doc = "vertical axis followed by rotation."]
-        #[doc = ""]
-        #[doc = "The purpose is mainly to allow clients to render accordingly and"]
-        #[doc = "tell the compositor, so that for fullscreen surfaces, the"]
-        #[doc = "compositor will still be able to scan out directly from client"]
-        #[doc = "surfaces."]
+                f.write_str(self.as_str())
+            }
+        }
+        ///This describes transformations that clients and compositors apply to
+        ///buffer contents.
+        ///
+        ///The flipped values correspond to an initial flip around a
+        ///vertical axis followed by rotation.
+        ///
+        ///The purpose is mainly to allow clients to render accordingly and
+        ///tell the compositor, so that for fullscreen surfaces, the
+        ///compositor will still be able to scan out directly from client
+        ///surfaces.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Transform {
-            #[doc = "no transform"]
+            ///no transform
             Normal = 0u32,
-            #[doc = "90 degrees counter-clockwise"]
+            ///90 degrees counter-clockwise
             _90 = 1u32,
-            #[doc = "180 degrees counter-clockwise"]
+            ///180 degrees counter-clockwise
             _180 = 2u32,
-            #[doc = "270 degrees counter-clockwise"]
+            ///270 degrees counter-clockwise
             _270 = 3u32,
-            #[doc = "180 degree flip around a vertical axis"]
+            ///180 degree flip around a vertical axis
             Flipped = 4u32,
-            #[doc = "flip and rotate 90 degrees counter-clockwise"]
+            ///flip and rotate 90 degrees counter-clockwise
             Flipped90 = 5u32,
-            #[doc = "flip and rotate 180 degrees counter-clockwise"]
+            ///flip and rotate 180 degrees counter-clockwise
             Flipped180 = 6u32,
-            #[doc = "flip and rotate 270 degrees counter-clockwise"]
+            ///flip and rotate 270 degrees counter-clockwise
             Flipped270 = 7u32,
         }
+        #[allow(deprecated)]
+        impl Transform {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Normal => 1u32,
+                    Self::_90 => 1u32,
+                    Self::_180 => 1u32,
+                    Self::_270 => 1u32,
+                    Self::Flipped => 1u32,
+                    Self::Flipped90 => 1u32,
+                    Self::Flipped180 => 1u32,
+                    Self::Flipped270 => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Normal => None,
+                    Self::_90 => None,
+                    Self::_180 => None,
+                    Self::_270 => None,
+                    Self::Flipped => None,
+                    Self::Flipped90 => None,
+                    Self::Flipped180 => None,
+                    Self::Flipped270 => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("normal", Self::Normal),
+                ("90", Self::_90),
+                ("180", Self::_180),
+                ("270", Self::_270),
+                ("flipped", Self::Flipped),
+                ("flipped_90", Self::Flipped90),
+                ("flipped_180", Self::Flipped180),
+                ("flipped_270", Self::Flipped270),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Normal => "normal",
+                    Self::_90 => "90",
+                    Self::_180 => "180",
+                    Self::_270 => "270",
+                    Self::Flipped => "flipped",
+                    Self::Flipped90 => "flipped_90",
+                    Self::Flipped180 => "flipped_180",
+                    Self::Flipped270 => "flipped_270",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Transform> for u32 {
             fn from(value: Transform) -> Self {
                 value as u32
             }
         }
+        impl From<Transform> for i32 {
+            fn from(value: Transform) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Transform {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -5552,12 +10419,43 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Transform {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Transform {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        bitflags::bitflags! {
+            #[doc = "These flags describe properties of an output mode."] #[doc =
+            "They are used in the flags bitfield of the mode event."] #[derive(Debug,
+            PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub struct Mode : u32 {
+            #[doc = "indicates this is the current mode"] const Current = 1u32; #[doc =
+            "indicates this is the preferred mode"] const Preferred = 2u32; }
+        }
+        #[allow(deprecated)]
+        impl Mode {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("current", Self::Current),
+                ("preferred", Self::Preferred),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
             }
         }
-        bitflags::bitflags! { # [doc = "These flags describe properties of an output mode."] # [doc = "They are used in the flags bitfield of the mode event."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Mode : u32 { # [doc = "indicates this is the current mode"] const Current = 1u32 ; # [doc = "indicates this is the preferred mode"] const Preferred = 2u32 ; } }
         impl From<Mode> for u32 {
             fn from(value: Mode) -> Self {
                 value.bits()
@@ -5571,47 +10469,77 @@ pub mod wayland {
         }
         impl std::fmt::Display for Mode {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Trait to implement the wl_output interface. See the module level documentation for more info"]
-        pub trait WlOutput
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wl_output";
-            const VERSION: u32 = 4u32;
-            #[doc = "Using this request a client can tell the server that it is not going to"]
-            #[doc = "use the output object anymore."]
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The geometry event describes geometric properties of the output."]
-            #[doc = "The event is sent when binding to the output object and whenever"]
-            #[doc = "any of the properties change."]
-            #[doc = ""]
-            #[doc = "The physical size can be set to zero if it doesn't make sense for this"]
-            #[doc = "output (e.g. for projectors or virtual outputs)."]
-            #[doc = ""]
-            #[doc = "The geometry event will be followed by a done event (starting from"]
-            #[doc = "version 2)."]
-            #[doc = ""]
-            #[doc = "Clients should use wl_surface.preferred_buffer_transform instead of the"]
-            #[doc = "transform advertised by this event to find the preferred buffer"]
-            #[doc = "transform to use for a surface."]
-            #[doc = ""]
-            #[doc = "Note: wl_output only advertises partial information about the output"]
-            #[doc = "position and identification. Some compositors, for instance those not"]
-            #[doc = "implementing a desktop-style output layout or those exposing virtual"]
-            #[doc = "outputs, might fake this information. Instead of using x and y, clients"]
-            #[doc = "should use xdg_output.logical_position. Instead of using make and model,"]
-            #[doc = "clients should use name and description."]
-            fn geometry(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_output interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the geometry message was introduced in
+            pub const GEOMETRY_SINCE: u32 = 1u32;
+            ///The interface version the geometry message was deprecated in, if any
+            pub const GEOMETRY_DEPRECATED_SINCE: Option<u32> = None;
+            ///The geometry event describes geometric properties of the output.
+            ///The event is sent when binding to the output object and whenever
+            ///any of the properties change.
+            ///
+            ///The physical size can be set to zero if it doesn't make sense for this
+            ///output (e.g. for projectors or virtual outputs).
+            ///
+            ///The geometry event will be followed by a done event (starting from
+            ///version 2).
+            ///
+            ///Clients should use wl_surface.preferred_buffer_transform instead of the
+            ///transform advertised by this event to find the preferred buffer
+            ///transform to use for a surface.
+            ///
+            ///Note: wl_output only advertises partial information about the output
+            ///position and identification. Some compositors, for instance those not
+            ///implementing a desktop-style output layout or those exposing virtual
+            ///outputs, might fake this information. Instead of using x and y, clients
+            ///should use xdg_output.logical_position. Instead of using make and model,
+            ///clients should use name and description.
+            pub fn geometry<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
@@ -5621,330 +10549,558 @@ pub mod wayland {
                 make: String,
                 model: String,
                 transform: Transform,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_output#{}.geometry({}, {}, {}, {}, {}, \"{}\", \"{}\", {})",
-                        sender_id,
-                        x,
-                        y,
-                        physical_width,
-                        physical_height,
-                        subpixel,
-                        make,
-                        model,
-                        transform
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(x)
-                        .put_int(y)
-                        .put_int(physical_width)
-                        .put_int(physical_height)
-                        .put_uint(subpixel.into())
-                        .put_string(Some(make))
-                        .put_string(Some(model))
-                        .put_uint(transform.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_output",
+                    object_id = % sender_id, opcode = 0u16, message = "geometry",
+                    direction = "out", "args.x" = % x, "args.y" = % y,
+                    "args.physical_width" = % physical_width, "args.physical_height" = %
+                    physical_height, "args.subpixel" = % subpixel, "args.make" = % make,
+                    "args.model" = % model, "args.transform" = % transform,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_output",
+                    object_id : % = sender_id, opcode = 0u16, message = "geometry",
+                    direction = "out", "args.x" : % = x, "args.y" : % = y,
+                    "args.physical_width" : % = physical_width, "args.physical_height" :
+                    % = physical_height, "args.subpixel" : % = subpixel, "args.make" : %
+                    = make, "args.model" : % = model, "args.transform" : % = transform;
+                    "wl_output.geometry"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(x)
+                    .put_int(y)
+                    .put_int(physical_width)
+                    .put_int(physical_height)
+                    .put_int(subpixel.into())
+                    .put_string(Some(make))
+                    .put_string(Some(model))
+                    .put_int(transform.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The mode event describes an available mode for the output."]
-            #[doc = ""]
-            #[doc = "The event is sent when binding to the output object and there"]
-            #[doc = "will always be one mode, the current mode.  The event is sent"]
-            #[doc = "again if an output changes mode, for the mode that is now"]
-            #[doc = "current.  In other words, the current mode is always the last"]
-            #[doc = "mode that was received with the current flag set."]
-            #[doc = ""]
-            #[doc = "Non-current modes are deprecated. A compositor can decide to only"]
-            #[doc = "advertise the current mode and never send other modes. Clients"]
-            #[doc = "should not rely on non-current modes."]
-            #[doc = ""]
-            #[doc = "The size of a mode is given in physical hardware units of"]
-            #[doc = "the output device. This is not necessarily the same as"]
-            #[doc = "the output size in the global compositor space. For instance,"]
-            #[doc = "the output may be scaled, as described in wl_output.scale,"]
-            #[doc = "or transformed, as described in wl_output.transform. Clients"]
-            #[doc = "willing to retrieve the output size in the global compositor"]
-            #[doc = "space should use xdg_output.logical_size instead."]
-            #[doc = ""]
-            #[doc = "The vertical refresh rate can be set to zero if it doesn't make"]
-            #[doc = "sense for this output (e.g. for virtual outputs)."]
-            #[doc = ""]
-            #[doc = "The mode event will be followed by a done event (starting from"]
-            #[doc = "version 2)."]
-            #[doc = ""]
-            #[doc = "Clients should not use the refresh rate to schedule frames. Instead,"]
-            #[doc = "they should use the wl_surface.frame event or the presentation-time"]
-            #[doc = "protocol."]
-            #[doc = ""]
-            #[doc = "Note: this information is not always meaningful for all outputs. Some"]
-            #[doc = "compositors, such as those exposing virtual outputs, might fake the"]
-            #[doc = "refresh rate or the size."]
-            fn mode(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the mode message was introduced in
+            pub const MODE_SINCE: u32 = 1u32;
+            ///The interface version the mode message was deprecated in, if any
+            pub const MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The mode event describes an available mode for the output.
+            ///
+            ///The event is sent when binding to the output object and there
+            ///will always be one mode, the current mode.  The event is sent
+            ///again if an output changes mode, for the mode that is now
+            ///current.  In other words, the current mode is always the last
+            ///mode that was received with the current flag set.
+            ///
+            ///Non-current modes are deprecated. A compositor can decide to only
+            ///advertise the current mode and never send other modes. Clients
+            ///should not rely on non-current modes.
+            ///
+            ///The size of a mode is given in physical hardware units of
+            ///the output device. This is not necessarily the same as
+            ///the output size in the global compositor space. For instance,
+            ///the output may be scaled, as described in wl_output.scale,
+            ///or transformed, as described in wl_output.transform. Clients
+            ///willing to retrieve the output size in the global compositor
+            ///space should use xdg_output.logical_size instead.
+            ///
+            ///The vertical refresh rate can be set to zero if it doesn't make
+            ///sense for this output (e.g. for virtual outputs).
+            ///
+            ///The mode event will be followed by a done event (starting from
+            ///version 2).
+            ///
+            ///Clients should not use the refresh rate to schedule frames. Instead,
+            ///they should use the wl_surface.frame event or the presentation-time
+            ///protocol.
+            ///
+            ///Note: this information is not always meaningful for all outputs. Some
+            ///compositors, such as those exposing virtual outputs, might fake the
+            ///refresh rate or the size.
+            pub fn mode<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 flags: Mode,
                 width: i32,
                 height: i32,
                 refresh: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_output#{}.mode({}, {}, {}, {})",
-                        sender_id,
-                        flags,
-                        width,
-                        height,
-                        refresh
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(flags.into())
-                        .put_int(width)
-                        .put_int(height)
-                        .put_int(refresh)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_output",
+                    object_id = % sender_id, opcode = 1u16, message = "mode", direction =
+                    "out", "args.flags" = % flags, "args.width" = % width, "args.height"
+                    = % height, "args.refresh" = % refresh,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_output",
+                    object_id : % = sender_id, opcode = 1u16, message = "mode", direction
+                    = "out", "args.flags" : % = flags, "args.width" : % = width,
+                    "args.height" : % = height, "args.refresh" : % = refresh;
+                    "wl_output.mode"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(flags.into())
+                    .put_int(width)
+                    .put_int(height)
+                    .put_int(refresh)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent after all other properties have been"]
-            #[doc = "sent after binding to the output object and after any"]
-            #[doc = "other property changes done after that. This allows"]
-            #[doc = "changes to the output properties to be seen as"]
-            #[doc = "atomic, even if they happen via multiple events."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_output#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 2u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent after all other properties have been
+            ///sent after binding to the output object and after any
+            ///other property changes done after that. This allows
+            ///changes to the output properties to be seen as
+            ///atomic, even if they happen via multiple events.
+            ///
+            ///Available since version 2
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_output",
+                    object_id = % sender_id, opcode = 2u16, message = "done", direction =
+                    "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_output",
+                    object_id : % = sender_id, opcode = 2u16, message = "done", direction
+                    = "out"; "wl_output.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event contains scaling geometry information"]
-            #[doc = "that is not in the geometry event. It may be sent after"]
-            #[doc = "binding the output object or if the output scale changes"]
-            #[doc = "later. The compositor will emit a non-zero, positive"]
-            #[doc = "value for scale. If it is not sent, the client should"]
-            #[doc = "assume a scale of 1."]
-            #[doc = ""]
-            #[doc = "A scale larger than 1 means that the compositor will"]
-            #[doc = "automatically scale surface buffers by this amount"]
-            #[doc = "when rendering. This is used for very high resolution"]
-            #[doc = "displays where applications rendering at the native"]
-            #[doc = "resolution would be too small to be legible."]
-            #[doc = ""]
-            #[doc = "Clients should use wl_surface.preferred_buffer_scale"]
-            #[doc = "instead of this event to find the preferred buffer"]
-            #[doc = "scale to use for a surface."]
-            #[doc = ""]
-            #[doc = "The scale event will be followed by a done event."]
-            fn scale(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the scale message was introduced in
+            pub const SCALE_SINCE: u32 = 2u32;
+            ///The interface version the scale message was deprecated in, if any
+            pub const SCALE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event contains scaling geometry information
+            ///that is not in the geometry event. It may be sent after
+            ///binding the output object or if the output scale changes
+            ///later. The compositor will emit a non-zero, positive
+            ///value for scale. If it is not sent, the client should
+            ///assume a scale of 1.
+            ///
+            ///A scale larger than 1 means that the compositor will
+            ///automatically scale surface buffers by this amount
+            ///when rendering. This is used for very high resolution
+            ///displays where applications rendering at the native
+            ///resolution would be too small to be legible.
+            ///
+            ///Clients should use wl_surface.preferred_buffer_scale
+            ///instead of this event to find the preferred buffer
+            ///scale to use for a surface.
+            ///
+            ///The scale event will be followed by a done event.
+            ///
+            ///Available since version 2
+            pub fn scale<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 factor: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_output#{}.scale({})", sender_id, factor);
-                    let payload = waynest::PayloadBuilder::new().put_int(factor).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_output",
+                    object_id = % sender_id, opcode = 3u16, message = "scale", direction
+                    = "out", "args.factor" = % factor,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_output",
+                    object_id : % = sender_id, opcode = 3u16, message = "scale",
+                    direction = "out", "args.factor" : % = factor; "wl_output.scale"
+                );
+                let payload = waynest::PayloadBuilder::new().put_int(factor).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Many compositors will assign user-friendly names to their outputs, show"]
-            #[doc = "them to the user, allow the user to refer to an output, etc. The client"]
-            #[doc = "may wish to know this name as well to offer the user similar behaviors."]
-            #[doc = ""]
-            #[doc = "The name is a UTF-8 string with no convention defined for its contents."]
-            #[doc = "Each name is unique among all wl_output globals. The name is only"]
-            #[doc = "guaranteed to be unique for the compositor instance."]
-            #[doc = ""]
-            #[doc = "The same output name is used for all clients for a given wl_output"]
-            #[doc = "global. Thus, the name can be shared across processes to refer to a"]
-            #[doc = "specific wl_output global."]
-            #[doc = ""]
-            #[doc = "The name is not guaranteed to be persistent across sessions, thus cannot"]
-            #[doc = "be used to reliably identify an output in e.g. configuration files."]
-            #[doc = ""]
-            #[doc = "Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do"]
-            #[doc = "not assume that the name is a reflection of an underlying DRM connector,"]
-            #[doc = "X11 connection, etc."]
-            #[doc = ""]
-            #[doc = "The name event is sent after binding the output object. This event is"]
-            #[doc = "only sent once per output object, and the name does not change over the"]
-            #[doc = "lifetime of the wl_output global."]
-            #[doc = ""]
-            #[doc = "Compositors may re-use the same output name if the wl_output global is"]
-            #[doc = "destroyed and re-created later. Compositors should avoid re-using the"]
-            #[doc = "same name if possible."]
-            #[doc = ""]
-            #[doc = "The name event will be followed by a done event."]
-            fn name(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the name message was introduced in
+            pub const NAME_SINCE: u32 = 4u32;
+            ///The interface version the name message was deprecated in, if any
+            pub const NAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Many compositors will assign user-friendly names to their outputs, show
+            ///them to the user, allow the user to refer to an output, etc. The client
+            ///may wish to know this name as well to offer the user similar behaviors.
+            ///
+            ///The name is a UTF-8 string with no convention defined for its contents.
+            ///Each name is unique among all wl_output globals. The name is only
+            ///guaranteed to be unique for the compositor instance.
+            ///
+            ///The same output name is used for all clients for a given wl_output
+            ///global. Thus, the name can be shared across processes to refer to a
+            ///specific wl_output global.
+            ///
+            ///The name is not guaranteed to be persistent across sessions, thus cannot
+            ///be used to reliably identify an output in e.g. configuration files.
+            ///
+            ///Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+            ///not assume that the name is a reflection of an underlying DRM connector,
+            ///X11 connection, etc.
+            ///
+            ///The name event is sent after binding the output object. This event is
+            ///only sent once per output object, and the name does not change over the
+            ///lifetime of the wl_output global.
+            ///
+            ///Compositors may re-use the same output name if the wl_output global is
+            ///destroyed and re-created later. Compositors should avoid re-using the
+            ///same name if possible.
+            ///
+            ///The name event will be followed by a done event.
+            ///
+            ///Available since version 4
+            pub fn name<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 name: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wl_output#{}.name(\"{}\")", sender_id, name);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(name))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_output",
+                    object_id = % sender_id, opcode = 4u16, message = "name", direction =
+                    "out", "args.name" = % name,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_output",
+                    object_id : % = sender_id, opcode = 4u16, message = "name", direction
+                    = "out", "args.name" : % = name; "wl_output.name"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(name))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Many compositors can produce human-readable descriptions of their"]
-            #[doc = "outputs. The client may wish to know this description as well, e.g. for"]
-            #[doc = "output selection purposes."]
-            #[doc = ""]
-            #[doc = "The description is a UTF-8 string with no convention defined for its"]
-            #[doc = "contents. The description is not guaranteed to be unique among all"]
-            #[doc = "wl_output globals. Examples might include 'Foocorp 11\" Display' or"]
-            #[doc = "'Virtual X11 output via :1'."]
-            #[doc = ""]
-            #[doc = "The description event is sent after binding the output object and"]
-            #[doc = "whenever the description changes. The description is optional, and may"]
-            #[doc = "not be sent at all."]
-            #[doc = ""]
-            #[doc = "The description event will be followed by a done event."]
-            fn description(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the description message was introduced in
+            pub const DESCRIPTION_SINCE: u32 = 4u32;
+            ///The interface version the description message was deprecated in, if any
+            pub const DESCRIPTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Many compositors can produce human-readable descriptions of their
+            ///outputs. The client may wish to know this description as well, e.g. for
+            ///output selection purposes.
+            ///
+            ///The description is a UTF-8 string with no convention defined for its
+            ///contents. The description is not guaranteed to be unique among all
+            ///wl_output globals. Examples might include 'Foocorp 11" Display' or
+            ///'Virtual X11 output via :1'.
+            ///
+            ///The description event is sent after binding the output object and
+            ///whenever the description changes. The description is optional, and may
+            ///not be sent at all.
+            ///
+            ///The description event will be followed by a done event.
+            ///
+            ///Available since version 4
+            pub fn description<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 description: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wl_output#{}.description(\"{}\")",
-                        sender_id,
-                        description
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(description))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "wl_output",
+                    object_id = % sender_id, opcode = 5u16, message = "description",
+                    direction = "out", "args.description" = % description,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wl_output",
+                    object_id : % = sender_id, opcode = 5u16, message = "description",
+                    direction = "out", "args.description" : % = description;
+                    "wl_output.description"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(description))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wl_output interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_output` interface",
+            label = "`WlOutput` is not implemented for `{Self}`"
+        )]
+        pub trait WlOutput
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wl_output";
+            const VERSION: u32 = 4u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 3u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Using this request a client can tell the server that it is not going to
+            ///use the output object anymore.
+            ///
+            ///Available since version 3
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_output#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_output", object_id = % sender_id, opcode = 0u16, message
+                                = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_output", object_id : % = sender_id, opcode = 0u16,
+                                message = "release", direction = "in"; "wl_output.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A region object describes an area."]
-    #[doc = ""]
-    #[doc = "Region objects are used to describe the opaque and input"]
-    #[doc = "regions of a surface."]
+    ///A region object describes an area.
+    ///
+    ///Region objects are used to describe the opaque and input
+    ///regions of a surface.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_region {
-        #[doc = "Trait to implement the wl_region interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "add",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "subtract",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_region interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_region interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_region` interface",
+            label = "`WlRegion` is not implemented for `{Self}`"
+        )]
         pub trait WlRegion
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_region";
             const VERSION: u32 = 7u32;
-            #[doc = "Destroy the region.  This will invalidate the object ID."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the region.  This will invalidate the object ID.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Add the specified rectangle to the region."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the add message was introduced in
+            const ADD_SINCE: u32 = 1u32;
+            ///The interface version the add message was deprecated in, if any
+            const ADD_DEPRECATED_SINCE: Option<u32> = None;
+            ///Add the specified rectangle to the region.
             fn add(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Subtract the specified rectangle from the region."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the subtract message was introduced in
+            const SUBTRACT_SINCE: u32 = 1u32;
+            ///The interface version the subtract message was deprecated in, if any
+            const SUBTRACT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Subtract the specified rectangle from the region.
             fn subtract(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_region#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_region", object_id = % sender_id, opcode = 0u16, message
+                                = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_region", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "wl_region.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let x = message.int()?;
@@ -5952,15 +11108,22 @@ pub mod wayland {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_region#{}.add({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.add(connection, sender_id, x, y, width, height).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_region", object_id = % sender_id, opcode = 1u16, message
+                                = "add", direction = "in", "args.x" = % x, "args.y" = % y,
+                                "args.width" = % width, "args.height" = % height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_region", object_id : % = sender_id, opcode = 1u16,
+                                message = "add", direction = "in", "args.x" : % = x,
+                                "args.y" : % = y, "args.width" : % = width, "args.height" :
+                                % = height; "wl_region.add"
+                            );
+                            self.add(connection, state, sender_id, x, y, width, height)
+                                .await
                         }
                         2u16 => {
                             let x = message.int()?;
@@ -5968,58 +11131,120 @@ pub mod wayland {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_region#{}.subtract({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.subtract(connection, sender_id, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_region", object_id = % sender_id, opcode = 2u16, message
+                                = "subtract", direction = "in", "args.x" = % x, "args.y" = %
+                                y, "args.width" = % width, "args.height" = % height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_region", object_id : % = sender_id, opcode = 2u16,
+                                message = "subtract", direction = "in", "args.x" : % = x,
+                                "args.y" : % = y, "args.width" : % = width, "args.height" :
+                                % = height; "wl_region.subtract"
+                            );
+                            self.subtract(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "The global interface exposing sub-surface compositing capabilities."]
-    #[doc = "A wl_surface, that has sub-surfaces associated, is called the"]
-    #[doc = "parent surface. Sub-surfaces can be arbitrarily nested and create"]
-    #[doc = "a tree of sub-surfaces."]
-    #[doc = ""]
-    #[doc = "The root surface in a tree of sub-surfaces is the main"]
-    #[doc = "surface. The main surface cannot be a sub-surface, because"]
-    #[doc = "sub-surfaces must always have a parent."]
-    #[doc = ""]
-    #[doc = "A main surface with its sub-surfaces forms a (compound) window."]
-    #[doc = "For window management purposes, this set of wl_surface objects is"]
-    #[doc = "to be considered as a single window, and it should also behave as"]
-    #[doc = "such."]
-    #[doc = ""]
-    #[doc = "The aim of sub-surfaces is to offload some of the compositing work"]
-    #[doc = "within a window from clients to the compositor. A prime example is"]
-    #[doc = "a video player with decorations and video in separate wl_surface"]
-    #[doc = "objects. This should allow the compositor to pass YUV video buffer"]
-    #[doc = "processing to dedicated overlay hardware when possible."]
+    ///The global interface exposing sub-surface compositing capabilities.
+    ///A wl_surface, that has sub-surfaces associated, is called the
+    ///parent surface. Sub-surfaces can be arbitrarily nested and create
+    ///a tree of sub-surfaces.
+    ///
+    ///The root surface in a tree of sub-surfaces is the main
+    ///surface. The main surface cannot be a sub-surface, because
+    ///sub-surfaces must always have a parent.
+    ///
+    ///A main surface with its sub-surfaces forms a (compound) window.
+    ///For window management purposes, this set of wl_surface objects is
+    ///to be considered as a single window, and it should also behave as
+    ///such.
+    ///
+    ///The aim of sub-surfaces is to offload some of the compositing work
+    ///within a window from clients to the compositor. A prime example is
+    ///a video player with decorations and video in separate wl_surface
+    ///objects. This should allow the compositor to pass YUV video buffer
+    ///processing to dedicated overlay hardware when possible.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_subcompositor {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "the to-be sub-surface is invalid"]
+            ///the to-be sub-surface is invalid
             BadSurface = 0u32,
-            #[doc = "the to-be sub-surface parent is invalid"]
+            ///the to-be sub-surface parent is invalid
             BadParent = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::BadSurface => 1u32,
+                    Self::BadParent => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::BadSurface => None,
+                    Self::BadParent => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("bad_surface", Self::BadSurface),
+                ("bad_parent", Self::BadParent),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::BadSurface => "bad_surface",
+                    Self::BadParent => "bad_parent",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -6030,68 +11255,155 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_subcompositor interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_subsurface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "noo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_subsurface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_subcompositor interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_subcompositor interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_subcompositor` interface",
+            label = "`WlSubcompositor` is not implemented for `{Self}`"
+        )]
         pub trait WlSubcompositor
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_subcompositor";
             const VERSION: u32 = 1u32;
-            #[doc = "Informs the server that the client will not be using this"]
-            #[doc = "protocol object anymore. This does not affect any other"]
-            #[doc = "objects, wl_subsurface objects included."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Informs the server that the client will not be using this
+            ///protocol object anymore. This does not affect any other
+            ///objects, wl_subsurface objects included.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Create a sub-surface interface for the given surface, and"]
-            #[doc = "associate it with the given parent surface. This turns a"]
-            #[doc = "plain wl_surface into a sub-surface."]
-            #[doc = ""]
-            #[doc = "The to-be sub-surface must not already have another role, and it"]
-            #[doc = "must not have an existing wl_subsurface object. Otherwise the"]
-            #[doc = "bad_surface protocol error is raised."]
-            #[doc = ""]
-            #[doc = "Adding sub-surfaces to a parent is a double-buffered operation on the"]
-            #[doc = "parent (see wl_surface.commit). The effect of adding a sub-surface"]
-            #[doc = "becomes visible on the next time the state of the parent surface is"]
-            #[doc = "applied."]
-            #[doc = ""]
-            #[doc = "The parent surface must not be one of the child surface's descendants,"]
-            #[doc = "and the parent must be different from the child surface, otherwise the"]
-            #[doc = "bad_parent protocol error is raised."]
-            #[doc = ""]
-            #[doc = "This request modifies the behaviour of wl_surface.commit request on"]
-            #[doc = "the sub-surface, see the documentation on wl_subsurface interface."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_subsurface message was introduced in
+            const GET_SUBSURFACE_SINCE: u32 = 1u32;
+            ///The interface version the get_subsurface message was deprecated in, if any
+            const GET_SUBSURFACE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a sub-surface interface for the given surface, and
+            ///associate it with the given parent surface. This turns a
+            ///plain wl_surface into a sub-surface.
+            ///
+            ///The to-be sub-surface must not already have another role, and it
+            ///must not have an existing wl_subsurface object. Otherwise the
+            ///bad_surface protocol error is raised.
+            ///
+            ///Adding sub-surfaces to a parent is a double-buffered operation on the
+            ///parent (see wl_surface.commit). The effect of adding a sub-surface
+            ///becomes visible on the next time the state of the parent surface is
+            ///applied.
+            ///
+            ///The parent surface must not be one of the child surface's descendants,
+            ///and the parent must be different from the child surface, otherwise the
+            ///bad_parent protocol error is raised.
+            ///
+            ///This request modifies the behaviour of wl_surface.commit request on
+            ///the sub-surface, see the documentation on wl_subsurface interface.
             fn get_subsurface(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 surface: waynest::ObjectId,
                 parent: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_subcompositor#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subcompositor", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subcompositor", object_id : % = sender_id, opcode =
+                                0u16, message = "destroy", direction = "in";
+                                "wl_subcompositor.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let id = message
@@ -6104,83 +11416,142 @@ pub mod wayland {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_subcompositor#{}.get_subsurface({}, {}, {})",
-                                sender_id,
-                                id,
-                                surface,
-                                parent
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subcompositor", object_id = % sender_id, opcode = 1u16,
+                                message = "get_subsurface", direction = "in", "args.id" = %
+                                id, "args.surface" = % surface, "args.parent" = % parent,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subcompositor", object_id : % = sender_id, opcode =
+                                1u16, message = "get_subsurface", direction = "in",
+                                "args.id" : % = id, "args.surface" : % = surface,
+                                "args.parent" : % = parent;
+                                "wl_subcompositor.get_subsurface"
                             );
-                            self.get_subsurface(connection, sender_id, id, surface, parent)
+                            self.get_subsurface(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    surface,
+                                    parent,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "An additional interface to a wl_surface object, which has been"]
-    #[doc = "made a sub-surface. A sub-surface has one parent surface. A"]
-    #[doc = "sub-surface's size and position are not limited to that of the parent."]
-    #[doc = "Particularly, a sub-surface is not automatically clipped to its"]
-    #[doc = "parent's area."]
-    #[doc = ""]
-    #[doc = "A sub-surface becomes mapped, when a non-NULL wl_buffer is applied"]
-    #[doc = "and the parent surface is mapped. The order of which one happens"]
-    #[doc = "first is irrelevant. A sub-surface is hidden if the parent becomes"]
-    #[doc = "hidden, or if a NULL wl_buffer is applied. These rules apply"]
-    #[doc = "recursively through the tree of surfaces."]
-    #[doc = ""]
-    #[doc = "A sub-surface can be in one of two modes. The possible modes are"]
-    #[doc = "synchronized and desynchronized, see methods wl_subsurface.set_sync and"]
-    #[doc = "wl_subsurface.set_desync."]
-    #[doc = ""]
-    #[doc = "The main surface can be thought to be always in desynchronized mode,"]
-    #[doc = "since it does not have a parent in the sub-surfaces sense."]
-    #[doc = ""]
-    #[doc = "Even if a sub-surface is in desynchronized mode, it will behave as"]
-    #[doc = "in synchronized mode, if its parent surface behaves as in"]
-    #[doc = "synchronized mode. This rule is applied recursively throughout the"]
-    #[doc = "tree of surfaces. This means, that one can set a sub-surface into"]
-    #[doc = "synchronized mode, and then assume that all its child and grand-child"]
-    #[doc = "sub-surfaces are synchronized, too, without explicitly setting them."]
-    #[doc = ""]
-    #[doc = "If a surface behaves as in synchronized mode, it is effectively"]
-    #[doc = "synchronized, otherwise it is effectively desynchronized."]
-    #[doc = ""]
-    #[doc = "A sub-surface is initially in the synchronized mode."]
-    #[doc = ""]
-    #[doc = "The wl_subsurface interface has requests which modify double-buffered"]
-    #[doc = "state of the parent surface (wl_subsurface.set_position, .place_above and"]
-    #[doc = ".place_below)."]
-    #[doc = ""]
-    #[doc = "Destroying a sub-surface takes effect immediately. If you need to"]
-    #[doc = "synchronize the removal of a sub-surface to the parent surface update,"]
-    #[doc = "unmap the sub-surface first by attaching a NULL wl_buffer, update parent,"]
-    #[doc = "and then destroy the sub-surface."]
-    #[doc = ""]
-    #[doc = "If the parent wl_surface object is destroyed, the sub-surface is"]
-    #[doc = "unmapped."]
-    #[doc = ""]
-    #[doc = "A sub-surface never has the keyboard focus of any seat."]
-    #[doc = ""]
-    #[doc = "The wl_surface.offset request is ignored: clients must use set_position"]
-    #[doc = "instead to move the sub-surface."]
+    ///An additional interface to a wl_surface object, which has been
+    ///made a sub-surface. A sub-surface has one parent surface. A
+    ///sub-surface's size and position are not limited to that of the parent.
+    ///Particularly, a sub-surface is not automatically clipped to its
+    ///parent's area.
+    ///
+    ///A sub-surface becomes mapped, when a non-NULL wl_buffer is applied
+    ///and the parent surface is mapped. The order of which one happens
+    ///first is irrelevant. A sub-surface is hidden if the parent becomes
+    ///hidden, or if a NULL wl_buffer is applied. These rules apply
+    ///recursively through the tree of surfaces.
+    ///
+    ///A sub-surface can be in one of two modes. The possible modes are
+    ///synchronized and desynchronized, see methods wl_subsurface.set_sync and
+    ///wl_subsurface.set_desync.
+    ///
+    ///The main surface can be thought to be always in desynchronized mode,
+    ///since it does not have a parent in the sub-surfaces sense.
+    ///
+    ///Even if a sub-surface is in desynchronized mode, it will behave as
+    ///in synchronized mode, if its parent surface behaves as in
+    ///synchronized mode. This rule is applied recursively throughout the
+    ///tree of surfaces. This means, that one can set a sub-surface into
+    ///synchronized mode, and then assume that all its child and grand-child
+    ///sub-surfaces are synchronized, too, without explicitly setting them.
+    ///
+    ///If a surface behaves as in synchronized mode, it is effectively
+    ///synchronized, otherwise it is effectively desynchronized.
+    ///
+    ///A sub-surface is initially in the synchronized mode.
+    ///
+    ///The wl_subsurface interface has requests which modify double-buffered
+    ///state of the parent surface (wl_subsurface.set_position, .place_above and
+    ///.place_below).
+    ///
+    ///Destroying a sub-surface takes effect immediately. If you need to
+    ///synchronize the removal of a sub-surface to the parent surface update,
+    ///unmap the sub-surface first by attaching a NULL wl_buffer, update parent,
+    ///and then destroy the sub-surface.
+    ///
+    ///If the parent wl_surface object is destroyed, the sub-surface is
+    ///unmapped.
+    ///
+    ///A sub-surface never has the keyboard focus of any seat.
+    ///
+    ///The wl_surface.offset request is ignored: clients must use set_position
+    ///instead to move the sub-surface.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_subsurface {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "wl_surface is not a sibling or the parent"]
+            ///wl_surface is not a sibling or the parent
             BadSurface = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::BadSurface => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::BadSurface => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("bad_surface", Self::BadSurface),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::BadSurface => "bad_surface",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -6190,210 +11561,498 @@ pub mod wayland {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wl_subsurface interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "place_above",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "sibling",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "place_below",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "sibling",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_sync",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_desync",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the wl_subsurface interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_subsurface interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_subsurface` interface",
+            label = "`WlSubsurface` is not implemented for `{Self}`"
+        )]
         pub trait WlSubsurface
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_subsurface";
             const VERSION: u32 = 1u32;
-            #[doc = "The sub-surface interface is removed from the wl_surface object"]
-            #[doc = "that was turned into a sub-surface with a"]
-            #[doc = "wl_subcompositor.get_subsurface request. The wl_surface's association"]
-            #[doc = "to the parent is deleted. The wl_surface is unmapped immediately."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///The sub-surface interface is removed from the wl_surface object
+            ///that was turned into a sub-surface with a
+            ///wl_subcompositor.get_subsurface request. The wl_surface's association
+            ///to the parent is deleted. The wl_surface is unmapped immediately.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This sets the position of the sub-surface, relative to the parent"]
-            #[doc = "surface."]
-            #[doc = ""]
-            #[doc = "The sub-surface will be moved so that its origin (top left"]
-            #[doc = "corner pixel) will be at the location x, y of the parent surface"]
-            #[doc = "coordinate system. The coordinates are not restricted to the parent"]
-            #[doc = "surface area. Negative values are allowed."]
-            #[doc = ""]
-            #[doc = "The initial position is 0, 0."]
-            #[doc = ""]
-            #[doc = "Position is double-buffered state on the parent surface, see"]
-            #[doc = "wl_subsurface and wl_surface.commit for more information."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_position message was introduced in
+            const SET_POSITION_SINCE: u32 = 1u32;
+            ///The interface version the set_position message was deprecated in, if any
+            const SET_POSITION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This sets the position of the sub-surface, relative to the parent
+            ///surface.
+            ///
+            ///The sub-surface will be moved so that its origin (top left
+            ///corner pixel) will be at the location x, y of the parent surface
+            ///coordinate system. The coordinates are not restricted to the parent
+            ///surface area. Negative values are allowed.
+            ///
+            ///The initial position is 0, 0.
+            ///
+            ///Position is double-buffered state on the parent surface, see
+            ///wl_subsurface and wl_surface.commit for more information.
             fn set_position(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This sub-surface is taken from the stack, and put back just"]
-            #[doc = "above the reference surface, changing the z-order of the sub-surfaces."]
-            #[doc = "The reference surface must be one of the sibling surfaces, or the"]
-            #[doc = "parent surface. Using any other surface, including this sub-surface,"]
-            #[doc = "will cause a protocol error."]
-            #[doc = ""]
-            #[doc = "A new sub-surface is initially added as the top-most in the stack"]
-            #[doc = "of its siblings and parent."]
-            #[doc = ""]
-            #[doc = "Z-order is double-buffered state on the parent surface, see"]
-            #[doc = "wl_subsurface and wl_surface.commit for more information."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the place_above message was introduced in
+            const PLACE_ABOVE_SINCE: u32 = 1u32;
+            ///The interface version the place_above message was deprecated in, if any
+            const PLACE_ABOVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This sub-surface is taken from the stack, and put back just
+            ///above the reference surface, changing the z-order of the sub-surfaces.
+            ///The reference surface must be one of the sibling surfaces, or the
+            ///parent surface. Using any other surface, including this sub-surface,
+            ///will cause a protocol error.
+            ///
+            ///A new sub-surface is initially added as the top-most in the stack
+            ///of its siblings and parent.
+            ///
+            ///Z-order is double-buffered state on the parent surface, see
+            ///wl_subsurface and wl_surface.commit for more information.
             fn place_above(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 sibling: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The sub-surface is placed just below the reference surface."]
-            #[doc = ""]
-            #[doc = "See wl_subsurface.place_above."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the place_below message was introduced in
+            const PLACE_BELOW_SINCE: u32 = 1u32;
+            ///The interface version the place_below message was deprecated in, if any
+            const PLACE_BELOW_DEPRECATED_SINCE: Option<u32> = None;
+            ///The sub-surface is placed just below the reference surface.
+            ///
+            ///See wl_subsurface.place_above.
             fn place_below(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 sibling: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Change the commit behaviour of the sub-surface to synchronized"]
-            #[doc = "mode."]
-            #[doc = ""]
-            #[doc = "See wl_subsurface and wl_surface.commit for more information."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_sync message was introduced in
+            const SET_SYNC_SINCE: u32 = 1u32;
+            ///The interface version the set_sync message was deprecated in, if any
+            const SET_SYNC_DEPRECATED_SINCE: Option<u32> = None;
+            ///Change the commit behaviour of the sub-surface to synchronized
+            ///mode.
+            ///
+            ///See wl_subsurface and wl_surface.commit for more information.
             fn set_sync(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Change the commit behaviour of the sub-surface to desynchronized"]
-            #[doc = "mode."]
-            #[doc = ""]
-            #[doc = "See wl_subsurface and wl_surface.commit for more information."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_desync message was introduced in
+            const SET_DESYNC_SINCE: u32 = 1u32;
+            ///The interface version the set_desync message was deprecated in, if any
+            const SET_DESYNC_DEPRECATED_SINCE: Option<u32> = None;
+            ///Change the commit behaviour of the sub-surface to desynchronized
+            ///mode.
+            ///
+            ///See wl_subsurface and wl_surface.commit for more information.
             fn set_desync(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_subsurface#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subsurface", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subsurface", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in";
+                                "wl_subsurface.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let x = message.int()?;
                             let y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_subsurface#{}.set_position({}, {})",
-                                sender_id,
-                                x,
-                                y
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subsurface", object_id = % sender_id, opcode = 1u16,
+                                message = "set_position", direction = "in", "args.x" = % x,
+                                "args.y" = % y,
                             );
-                            self.set_position(connection, sender_id, x, y).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subsurface", object_id : % = sender_id, opcode = 1u16,
+                                message = "set_position", direction = "in", "args.x" : % =
+                                x, "args.y" : % = y; "wl_subsurface.set_position"
+                            );
+                            self.set_position(connection, state, sender_id, x, y).await
                         }
                         2u16 => {
                             let sibling = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_subsurface#{}.place_above({})", sender_id, sibling);
-                            self.place_above(connection, sender_id, sibling).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subsurface", object_id = % sender_id, opcode = 2u16,
+                                message = "place_above", direction = "in", "args.sibling" =
+                                % sibling,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subsurface", object_id : % = sender_id, opcode = 2u16,
+                                message = "place_above", direction = "in", "args.sibling" :
+                                % = sibling; "wl_subsurface.place_above"
+                            );
+                            self.place_above(connection, state, sender_id, sibling).await
                         }
                         3u16 => {
                             let sibling = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_subsurface#{}.place_below({})", sender_id, sibling);
-                            self.place_below(connection, sender_id, sibling).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subsurface", object_id = % sender_id, opcode = 3u16,
+                                message = "place_below", direction = "in", "args.sibling" =
+                                % sibling,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subsurface", object_id : % = sender_id, opcode = 3u16,
+                                message = "place_below", direction = "in", "args.sibling" :
+                                % = sibling; "wl_subsurface.place_below"
+                            );
+                            self.place_below(connection, state, sender_id, sibling).await
                         }
                         4u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_subsurface#{}.set_sync()", sender_id,);
-                            self.set_sync(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subsurface", object_id = % sender_id, opcode = 4u16,
+                                message = "set_sync", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subsurface", object_id : % = sender_id, opcode = 4u16,
+                                message = "set_sync", direction = "in";
+                                "wl_subsurface.set_sync"
+                            );
+                            self.set_sync(connection, state, sender_id).await
                         }
                         5u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_subsurface#{}.set_desync()", sender_id,);
-                            self.set_desync(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_subsurface", object_id = % sender_id, opcode = 5u16,
+                                message = "set_desync", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_subsurface", object_id : % = sender_id, opcode = 5u16,
+                                message = "set_desync", direction = "in";
+                                "wl_subsurface.set_desync"
+                            );
+                            self.set_desync(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This global fixes problems with other core-protocol interfaces that"]
-    #[doc = "cannot be fixed in these interfaces themselves."]
+    ///This global fixes problems with other core-protocol interfaces that
+    ///cannot be fixed in these interfaces themselves.
     #[allow(clippy::too_many_arguments)]
     pub mod wl_fixes {
-        #[doc = "Trait to implement the wl_fixes interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy_registry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "registry",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_registry"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wl_fixes interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wl_fixes interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wl_fixes` interface",
+            label = "`WlFixes` is not implemented for `{Self}`"
+        )]
         pub trait WlFixes
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wl_fixes";
             const VERSION: u32 = 1u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request destroys a wl_registry object."]
-            #[doc = ""]
-            #[doc = "The client should no longer use the wl_registry after making this"]
-            #[doc = "request."]
-            #[doc = ""]
-            #[doc = "The compositor will emit a wl_display.delete_id event with the object ID"]
-            #[doc = "of the registry and will no longer emit any events on the registry. The"]
-            #[doc = "client should re-use the object ID once it receives the"]
-            #[doc = "wl_display.delete_id event."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy_registry message was introduced in
+            const DESTROY_REGISTRY_SINCE: u32 = 1u32;
+            ///The interface version the destroy_registry message was deprecated in, if any
+            const DESTROY_REGISTRY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request destroys a wl_registry object.
+            ///
+            ///The client should no longer use the wl_registry after making this
+            ///request.
+            ///
+            ///The compositor will emit a wl_display.delete_id event with the object ID
+            ///of the registry and will no longer emit any events on the registry. The
+            ///client should re-use the object ID once it receives the
+            ///wl_display.delete_id event.
             fn destroy_registry(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 registry: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wl_fixes#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_fixes", object_id = % sender_id, opcode = 0u16, message
+                                = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_fixes", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "wl_fixes.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let registry = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wl_fixes#{}.destroy_registry({})",
-                                sender_id,
-                                registry
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wl_fixes", object_id = % sender_id, opcode = 1u16, message
+                                = "destroy_registry", direction = "in", "args.registry" = %
+                                registry,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wl_fixes", object_id : % = sender_id, opcode = 1u16,
+                                message = "destroy_registry", direction = "in",
+                                "args.registry" : % = registry; "wl_fixes.destroy_registry"
                             );
-                            self.destroy_registry(connection, sender_id, registry).await
+                            self.destroy_registry(connection, state, sender_id, registry)
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
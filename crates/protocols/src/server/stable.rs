@@ -1,239 +1,536 @@
-#[allow(clippy::module_inception)]
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the linux_dmabuf_v1 protocol (external/protocols/wayland-protocols/stable/linux-dmabuf/linux-dmabuf-v1.xml)
+//
+//   Copyright © 2014, 2015 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the presentation_time protocol (external/protocols/wayland-protocols/stable/presentation-time/presentation-time.xml)
+//
+//   Copyright © 2013-2014 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the tablet_v2 protocol (external/protocols/wayland-protocols/stable/tablet/tablet-v2.xml)
+//
+//   Copyright 2014 © Stephen "Lyude" Chandler Paul
+//   Copyright 2015-2024 © Red Hat, Inc.
+//
+//   Permission is hereby granted, free of charge, to any person
+//   obtaining a copy of this software and associated documentation files
+//   (the "Software"), to deal in the Software without restriction,
+//   including without limitation the rights to use, copy, modify, merge,
+//   publish, distribute, sublicense, and/or sell copies of the Software,
+//   and to permit persons to whom the Software is furnished to do so,
+//   subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the
+//   next paragraph) shall be included in all copies or substantial
+//   portions of the Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//   EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+//   MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+//   NONINFRINGEMENT.  IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS
+//   BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN
+//   ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+//   CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+//   SOFTWARE.
+//
+// Generated from the viewporter protocol (external/protocols/wayland-protocols/stable/viewporter/viewporter.xml)
+//
+//   Copyright © 2013-2016 Collabora, Ltd.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the xdg_shell protocol (external/protocols/wayland-protocols/stable/xdg-shell/xdg-shell.xml)
+//
+//   Copyright © 2008-2013 Kristian Høgsberg
+//   Copyright © 2013      Rafael Antognolli
+//   Copyright © 2013      Jasper St. Pierre
+//   Copyright © 2010-2013 Intel Corporation
+//   Copyright © 2015-2017 Samsung Electronics Co., Ltd
+//   Copyright © 2015-2017 Red Hat Inc.
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "linux-dmabuf-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "linux-dmabuf-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod linux_dmabuf_v1 {
-    #[doc = "This interface offers ways to create generic dmabuf-based wl_buffers."]
-    #[doc = ""]
-    #[doc = "For more information about dmabuf, see:"]
-    #[doc = "https://www.kernel.org/doc/html/next/userspace-api/dma-buf-alloc-exchange.html"]
-    #[doc = ""]
-    #[doc = "Clients can use the get_surface_feedback request to get dmabuf feedback"]
-    #[doc = "for a particular surface. If the client wants to retrieve feedback not"]
-    #[doc = "tied to a surface, they can use the get_default_feedback request."]
-    #[doc = ""]
-    #[doc = "The following are required from clients:"]
-    #[doc = ""]
-    #[doc = "- Clients must ensure that either all data in the dma-buf is"]
-    #[doc = "coherent for all subsequent read access or that coherency is"]
-    #[doc = "correctly handled by the underlying kernel-side dma-buf"]
-    #[doc = "implementation."]
-    #[doc = ""]
-    #[doc = "- Don't make any more attachments after sending the buffer to the"]
-    #[doc = "compositor. Making more attachments later increases the risk of"]
-    #[doc = "the compositor not being able to use (re-import) an existing"]
-    #[doc = "dmabuf-based wl_buffer."]
-    #[doc = ""]
-    #[doc = "The underlying graphics stack must ensure the following:"]
-    #[doc = ""]
-    #[doc = "- The dmabuf file descriptors relayed to the server will stay valid"]
-    #[doc = "for the whole lifetime of the wl_buffer. This means the server may"]
-    #[doc = "at any time use those fds to import the dmabuf into any kernel"]
-    #[doc = "sub-system that might accept it."]
-    #[doc = ""]
-    #[doc = "However, when the underlying graphics stack fails to deliver the"]
-    #[doc = "promise, because of e.g. a device hot-unplug which raises internal"]
-    #[doc = "errors, after the wl_buffer has been successfully created the"]
-    #[doc = "compositor must not raise protocol errors to the client when dmabuf"]
-    #[doc = "import later fails."]
-    #[doc = ""]
-    #[doc = "To create a wl_buffer from one or more dmabufs, a client creates a"]
-    #[doc = "zwp_linux_buffer_params_v1 object with a zwp_linux_dmabuf_v1.create_params"]
-    #[doc = "request. All planes required by the intended format are added with"]
-    #[doc = "the 'add' request. Finally, a 'create' or 'create_immed' request is"]
-    #[doc = "issued, which has the following outcome depending on the import success."]
-    #[doc = ""]
-    #[doc = "The 'create' request,"]
-    #[doc = "- on success, triggers a 'created' event which provides the final"]
-    #[doc = "wl_buffer to the client."]
-    #[doc = "- on failure, triggers a 'failed' event to convey that the server"]
-    #[doc = "cannot use the dmabufs received from the client."]
-    #[doc = ""]
-    #[doc = "For the 'create_immed' request,"]
-    #[doc = "- on success, the server immediately imports the added dmabufs to"]
-    #[doc = "create a wl_buffer. No event is sent from the server in this case."]
-    #[doc = "- on failure, the server can choose to either:"]
-    #[doc = "- terminate the client by raising a fatal error."]
-    #[doc = "- mark the wl_buffer as failed, and send a 'failed' event to the"]
-    #[doc = "client. If the client uses a failed wl_buffer as an argument to any"]
-    #[doc = "request, the behaviour is compositor implementation-defined."]
-    #[doc = ""]
-    #[doc = "For all DRM formats and unless specified in another protocol extension,"]
-    #[doc = "pre-multiplied alpha is used for pixel values."]
-    #[doc = ""]
-    #[doc = "Unless specified otherwise in another protocol extension, implicit"]
-    #[doc = "synchronization is used. In other words, compositors and clients must"]
-    #[doc = "wait and signal fences implicitly passed via the DMA-BUF's reservation"]
-    #[doc = "mechanism."]
+    ///This interface offers ways to create generic dmabuf-based wl_buffers.
+    ///
+    ///For more information about dmabuf, see:
+    ///https://www.kernel.org/doc/html/next/userspace-api/dma-buf-alloc-exchange.html
+    ///
+    ///Clients can use the get_surface_feedback request to get dmabuf feedback
+    ///for a particular surface. If the client wants to retrieve feedback not
+    ///tied to a surface, they can use the get_default_feedback request.
+    ///
+    ///The following are required from clients:
+    ///
+    ///- Clients must ensure that either all data in the dma-buf is
+    ///coherent for all subsequent read access or that coherency is
+    ///correctly handled by the underlying kernel-side dma-buf
+    ///implementation.
+    ///
+    ///- Don't make any more attachments after sending the buffer to the
+    ///compositor. Making more attachments later increases the risk of
+    ///the compositor not being able to use (re-import) an existing
+    ///dmabuf-based wl_buffer.
+    ///
+    ///The underlying graphics stack must ensure the following:
+    ///
+    ///- The dmabuf file descriptors relayed to the server will stay valid
+    ///for the whole lifetime of the wl_buffer. This means the server may
+    ///at any time use those fds to import the dmabuf into any kernel
+    ///sub-system that might accept it.
+    ///
+    ///However, when the underlying graphics stack fails to deliver the
+    ///promise, because of e.g. a device hot-unplug which raises internal
+    ///errors, after the wl_buffer has been successfully created the
+    ///compositor must not raise protocol errors to the client when dmabuf
+    ///import later fails.
+    ///
+    ///To create a wl_buffer from one or more dmabufs, a client creates a
+    ///zwp_linux_buffer_params_v1 object with a zwp_linux_dmabuf_v1.create_params
+    ///request. All planes required by the intended format are added with
+    ///the 'add' request. Finally, a 'create' or 'create_immed' request is
+    ///issued, which has the following outcome depending on the import success.
+    ///
+    ///The 'create' request,
+    ///- on success, triggers a 'created' event which provides the final
+    ///wl_buffer to the client.
+    ///- on failure, triggers a 'failed' event to convey that the server
+    ///cannot use the dmabufs received from the client.
+    ///
+    ///For the 'create_immed' request,
+    ///- on success, the server immediately imports the added dmabufs to
+    ///create a wl_buffer. No event is sent from the server in this case.
+    ///- on failure, the server can choose to either:
+    ///- terminate the client by raising a fatal error.
+    ///- mark the wl_buffer as failed, and send a 'failed' event to the
+    ///client. If the client uses a failed wl_buffer as an argument to any
+    ///request, the behaviour is compositor implementation-defined.
+    ///
+    ///For all DRM formats and unless specified in another protocol extension,
+    ///pre-multiplied alpha is used for pixel values.
+    ///
+    ///Unless specified otherwise in another protocol extension, implicit
+    ///synchronization is used. In other words, compositors and clients must
+    ///wait and signal fences implicitly passed via the DMA-BUF's reservation
+    ///mechanism.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_linux_dmabuf_v1 {
-        #[doc = "Trait to implement the zwp_linux_dmabuf_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_params",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "params_id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_buffer_params_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_default_feedback",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_surface_feedback",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwp_linux_dmabuf_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the format message was introduced in
+            pub const FORMAT_SINCE: u32 = 1u32;
+            ///The interface version the format message was deprecated in, if any
+            pub const FORMAT_DEPRECATED_SINCE: Option<u32> = Some(4u32);
+            ///This event advertises one buffer format that the server supports.
+            ///All the supported formats are advertised once when the client
+            ///binds to this interface. A roundtrip after binding guarantees
+            ///that the client has received all supported formats.
+            ///
+            ///For the definition of the format codes, see the
+            ///zwp_linux_buffer_params_v1::create request.
+            ///
+            ///Starting version 4, the format event is deprecated and must not be
+            ///sent by compositors. Instead, use get_default_feedback or
+            ///get_surface_feedback.
+            ///
+            ///Deprecated since version 4
+            #[deprecated(note = "deprecated since version 4 of the interface")]
+            pub fn format<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                format: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "format", direction = "out", "args.format" = % format,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "format", direction = "out", "args.format" : % = format;
+                    "zwp_linux_dmabuf_v1.format"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(format).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the modifier message was introduced in
+            pub const MODIFIER_SINCE: u32 = 3u32;
+            ///The interface version the modifier message was deprecated in, if any
+            pub const MODIFIER_DEPRECATED_SINCE: Option<u32> = Some(4u32);
+            ///This event advertises the formats that the server supports, along with
+            ///the modifiers supported for each format. All the supported modifiers
+            ///for all the supported formats are advertised once when the client
+            ///binds to this interface. A roundtrip after binding guarantees that
+            ///the client has received all supported format-modifier pairs.
+            ///
+            ///For legacy support, DRM_FORMAT_MOD_INVALID (that is, modifier_hi ==
+            ///0x00ffffff and modifier_lo == 0xffffffff) is allowed in this event.
+            ///It indicates that the server can support the format with an implicit
+            ///modifier. When a plane has DRM_FORMAT_MOD_INVALID as its modifier, it
+            ///is as if no explicit modifier is specified. The effective modifier
+            ///will be derived from the dmabuf.
+            ///
+            ///A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for
+            ///a given format supports both explicit modifiers and implicit modifiers.
+            ///
+            ///For the definition of the format and modifier codes, see the
+            ///zwp_linux_buffer_params_v1::create and zwp_linux_buffer_params_v1::add
+            ///requests.
+            ///
+            ///Starting version 4, the modifier event is deprecated and must not be
+            ///sent by compositors. Instead, use get_default_feedback or
+            ///get_surface_feedback.
+            ///
+            ///Available since version 3
+            ///
+            ///Deprecated since version 4
+            #[deprecated(note = "deprecated since version 4 of the interface")]
+            pub fn modifier<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                format: u32,
+                modifier_hi: u32,
+                modifier_lo: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "modifier", direction = "out", "args.format" = % format,
+                    "args.modifier_hi" = % modifier_hi, "args.modifier_lo" = %
+                    modifier_lo,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "modifier", direction = "out", "args.format" : % = format,
+                    "args.modifier_hi" : % = modifier_hi, "args.modifier_lo" : % =
+                    modifier_lo; "zwp_linux_dmabuf_v1.modifier"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(format)
+                    .put_uint(modifier_hi)
+                    .put_uint(modifier_lo)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the zwp_linux_dmabuf_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_linux_dmabuf_v1` interface",
+            label = "`ZwpLinuxDmabufV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwpLinuxDmabufV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwp_linux_dmabuf_v1";
             const VERSION: u32 = 6u32;
-            #[doc = "Objects created through this interface, especially wl_buffers, will"]
-            #[doc = "remain valid."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Objects created through this interface, especially wl_buffers, will
+            ///remain valid.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This temporary object is used to collect multiple dmabuf handles into"]
-            #[doc = "a single batch to create a wl_buffer. It can only be used once and"]
-            #[doc = "should be destroyed after a 'created' or 'failed' event has been"]
-            #[doc = "received."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the create_params message was introduced in
+            const CREATE_PARAMS_SINCE: u32 = 1u32;
+            ///The interface version the create_params message was deprecated in, if any
+            const CREATE_PARAMS_DEPRECATED_SINCE: Option<u32> = None;
+            ///This temporary object is used to collect multiple dmabuf handles into
+            ///a single batch to create a wl_buffer. It can only be used once and
+            ///should be destroyed after a 'created' or 'failed' event has been
+            ///received.
             fn create_params(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 params_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request creates a new zwp_linux_dmabuf_feedback_v1 object not bound"]
-            #[doc = "to a particular surface. This object will deliver feedback about dmabuf"]
-            #[doc = "parameters to use if the client doesn't support per-surface feedback"]
-            #[doc = "(see get_surface_feedback)."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_default_feedback message was introduced in
+            const GET_DEFAULT_FEEDBACK_SINCE: u32 = 4u32;
+            ///The interface version the get_default_feedback message was deprecated in, if any
+            const GET_DEFAULT_FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request creates a new zwp_linux_dmabuf_feedback_v1 object not bound
+            ///to a particular surface. This object will deliver feedback about dmabuf
+            ///parameters to use if the client doesn't support per-surface feedback
+            ///(see get_surface_feedback).
+            ///
+            ///Available since version 4
             fn get_default_feedback(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request creates a new zwp_linux_dmabuf_feedback_v1 object for the"]
-            #[doc = "specified wl_surface. This object will deliver feedback about dmabuf"]
-            #[doc = "parameters to use for buffers attached to this surface."]
-            #[doc = ""]
-            #[doc = "If the surface is destroyed before the zwp_linux_dmabuf_feedback_v1 object,"]
-            #[doc = "the feedback object becomes inert."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_surface_feedback message was introduced in
+            const GET_SURFACE_FEEDBACK_SINCE: u32 = 4u32;
+            ///The interface version the get_surface_feedback message was deprecated in, if any
+            const GET_SURFACE_FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request creates a new zwp_linux_dmabuf_feedback_v1 object for the
+            ///specified wl_surface. This object will deliver feedback about dmabuf
+            ///parameters to use for buffers attached to this surface.
+            ///
+            ///If the surface is destroyed before the zwp_linux_dmabuf_feedback_v1 object,
+            ///the feedback object becomes inert.
+            ///
+            ///Available since version 4
             fn get_surface_feedback(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event advertises one buffer format that the server supports."]
-            #[doc = "All the supported formats are advertised once when the client"]
-            #[doc = "binds to this interface. A roundtrip after binding guarantees"]
-            #[doc = "that the client has received all supported formats."]
-            #[doc = ""]
-            #[doc = "For the definition of the format codes, see the"]
-            #[doc = "zwp_linux_buffer_params_v1::create request."]
-            #[doc = ""]
-            #[doc = "Starting version 4, the format event is deprecated and must not be"]
-            #[doc = "sent by compositors. Instead, use get_default_feedback or"]
-            #[doc = "get_surface_feedback."]
-            fn format(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                format: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_linux_dmabuf_v1#{}.format({})", sender_id, format);
-                    let payload = waynest::PayloadBuilder::new().put_uint(format).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event advertises the formats that the server supports, along with"]
-            #[doc = "the modifiers supported for each format. All the supported modifiers"]
-            #[doc = "for all the supported formats are advertised once when the client"]
-            #[doc = "binds to this interface. A roundtrip after binding guarantees that"]
-            #[doc = "the client has received all supported format-modifier pairs."]
-            #[doc = ""]
-            #[doc = "For legacy support, DRM_FORMAT_MOD_INVALID (that is, modifier_hi =="]
-            #[doc = "0x00ffffff and modifier_lo == 0xffffffff) is allowed in this event."]
-            #[doc = "It indicates that the server can support the format with an implicit"]
-            #[doc = "modifier. When a plane has DRM_FORMAT_MOD_INVALID as its modifier, it"]
-            #[doc = "is as if no explicit modifier is specified. The effective modifier"]
-            #[doc = "will be derived from the dmabuf."]
-            #[doc = ""]
-            #[doc = "A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for"]
-            #[doc = "a given format supports both explicit modifiers and implicit modifiers."]
-            #[doc = ""]
-            #[doc = "For the definition of the format and modifier codes, see the"]
-            #[doc = "zwp_linux_buffer_params_v1::create and zwp_linux_buffer_params_v1::add"]
-            #[doc = "requests."]
-            #[doc = ""]
-            #[doc = "Starting version 4, the modifier event is deprecated and must not be"]
-            #[doc = "sent by compositors. Instead, use get_default_feedback or"]
-            #[doc = "get_surface_feedback."]
-            fn modifier(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                format: u32,
-                modifier_hi: u32,
-                modifier_lo: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_v1#{}.modifier({}, {}, {})",
-                        sender_id,
-                        format,
-                        modifier_hi,
-                        modifier_lo
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(format)
-                        .put_uint(modifier_hi)
-                        .put_uint(modifier_lo)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_linux_dmabuf_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_dmabuf_v1", object_id = % sender_id, opcode =
+                                0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_dmabuf_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "destroy", direction = "in";
+                                "zwp_linux_dmabuf_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let params_id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_dmabuf_v1#{}.create_params({})",
-                                sender_id,
-                                params_id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_dmabuf_v1", object_id = % sender_id, opcode =
+                                1u16, message = "create_params", direction = "in",
+                                "args.params_id" = % params_id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_dmabuf_v1", object_id : % = sender_id, opcode =
+                                1u16, message = "create_params", direction = "in",
+                                "args.params_id" : % = params_id;
+                                "zwp_linux_dmabuf_v1.create_params"
                             );
-                            self.create_params(connection, sender_id, params_id).await
+                            self.create_params(connection, state, sender_id, params_id)
+                                .await
                         }
                         2u16 => {
                             let id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_dmabuf_v1#{}.get_default_feedback({})",
-                                sender_id,
-                                id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_dmabuf_v1", object_id = % sender_id, opcode =
+                                2u16, message = "get_default_feedback", direction = "in",
+                                "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_dmabuf_v1", object_id : % = sender_id, opcode =
+                                2u16, message = "get_default_feedback", direction = "in",
+                                "args.id" : % = id;
+                                "zwp_linux_dmabuf_v1.get_default_feedback"
                             );
-                            self.get_default_feedback(connection, sender_id, id).await
+                            self.get_default_feedback(connection, state, sender_id, id)
+                                .await
                         }
                         3u16 => {
                             let id = message
@@ -243,67 +540,157 @@ pub mod linux_dmabuf_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_dmabuf_v1#{}.get_surface_feedback({}, {})",
-                                sender_id,
-                                id,
-                                surface
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_dmabuf_v1", object_id = % sender_id, opcode =
+                                3u16, message = "get_surface_feedback", direction = "in",
+                                "args.id" = % id, "args.surface" = % surface,
                             );
-                            self.get_surface_feedback(connection, sender_id, id, surface)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_dmabuf_v1", object_id : % = sender_id, opcode =
+                                3u16, message = "get_surface_feedback", direction = "in",
+                                "args.id" : % = id, "args.surface" : % = surface;
+                                "zwp_linux_dmabuf_v1.get_surface_feedback"
+                            );
+                            self.get_surface_feedback(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    surface,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "This temporary object is a collection of dmabufs and other"]
-    #[doc = "parameters that together form a single logical buffer. The temporary"]
-    #[doc = "object may eventually create one wl_buffer unless cancelled by"]
-    #[doc = "destroying it before requesting 'create'."]
-    #[doc = ""]
-    #[doc = "Single-planar formats only require one dmabuf, however"]
-    #[doc = "multi-planar formats may require more than one dmabuf. For all"]
-    #[doc = "formats, an 'add' request must be called once per plane (even if the"]
-    #[doc = "underlying dmabuf fd is identical)."]
-    #[doc = ""]
-    #[doc = "You must use consecutive plane indices ('plane_idx' argument for 'add')"]
-    #[doc = "from zero to the number of planes used by the drm_fourcc format code."]
-    #[doc = "All planes required by the format must be given exactly once, but can"]
-    #[doc = "be given in any order. Each plane index can only be set once; subsequent"]
-    #[doc = "calls with a plane index which has already been set will result in a"]
-    #[doc = "plane_set error being generated."]
+    ///This temporary object is a collection of dmabufs and other
+    ///parameters that together form a single logical buffer. The temporary
+    ///object may eventually create one wl_buffer unless cancelled by
+    ///destroying it before requesting 'create'.
+    ///
+    ///Single-planar formats only require one dmabuf, however
+    ///multi-planar formats may require more than one dmabuf. For all
+    ///formats, an 'add' request must be called once per plane (even if the
+    ///underlying dmabuf fd is identical).
+    ///
+    ///You must use consecutive plane indices ('plane_idx' argument for 'add')
+    ///from zero to the number of planes used by the drm_fourcc format code.
+    ///All planes required by the format must be given exactly once, but can
+    ///be given in any order. Each plane index can only be set once; subsequent
+    ///calls with a plane index which has already been set will result in a
+    ///plane_set error being generated.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_linux_buffer_params_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "the zwp_linux_buffer_params_v1 object has already been used to create a wl_buffer"]
+            ///the zwp_linux_buffer_params_v1 object has already been used to create a wl_buffer
             AlreadyUsed = 0u32,
-            #[doc = "plane index out of bounds"]
+            ///plane index out of bounds
             PlaneIdx = 1u32,
-            #[doc = "the plane index was already set"]
+            ///the plane index was already set
             PlaneSet = 2u32,
-            #[doc = "missing or too many planes to create a buffer"]
+            ///missing or too many planes to create a buffer
             Incomplete = 3u32,
-            #[doc = "format not supported"]
+            ///format not supported
             InvalidFormat = 4u32,
-            #[doc = "invalid width or height"]
+            ///invalid width or height
             InvalidDimensions = 5u32,
-            #[doc = "offset + stride * height goes out of dmabuf bounds"]
+            ///offset + stride * height goes out of dmabuf bounds
             OutOfBounds = 6u32,
-            #[doc = "invalid wl_buffer resulted from importing dmabufs via                the create_immed request on given buffer_params"]
+            ///invalid wl_buffer resulted from importing dmabufs via                the create_immed request on given buffer_params
             InvalidWlBuffer = 7u32,
-            #[doc = "an array with mismatching size for a dev_t was used"]
+            ///an array with mismatching size for a dev_t was used
             InvalidDevTSize = 8u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::AlreadyUsed => 1u32,
+                    Self::PlaneIdx => 1u32,
+                    Self::PlaneSet => 1u32,
+                    Self::Incomplete => 1u32,
+                    Self::InvalidFormat => 1u32,
+                    Self::InvalidDimensions => 1u32,
+                    Self::OutOfBounds => 1u32,
+                    Self::InvalidWlBuffer => 1u32,
+                    Self::InvalidDevTSize => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::AlreadyUsed => None,
+                    Self::PlaneIdx => None,
+                    Self::PlaneSet => None,
+                    Self::Incomplete => None,
+                    Self::InvalidFormat => None,
+                    Self::InvalidDimensions => None,
+                    Self::OutOfBounds => None,
+                    Self::InvalidWlBuffer => None,
+                    Self::InvalidDevTSize => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("already_used", Self::AlreadyUsed),
+                ("plane_idx", Self::PlaneIdx),
+                ("plane_set", Self::PlaneSet),
+                ("incomplete", Self::Incomplete),
+                ("invalid_format", Self::InvalidFormat),
+                ("invalid_dimensions", Self::InvalidDimensions),
+                ("out_of_bounds", Self::OutOfBounds),
+                ("invalid_wl_buffer", Self::InvalidWlBuffer),
+                ("invalid_dev_t_size", Self::InvalidDevTSize),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::AlreadyUsed => "already_used",
+                    Self::PlaneIdx => "plane_idx",
+                    Self::PlaneSet => "plane_set",
+                    Self::Incomplete => "incomplete",
+                    Self::InvalidFormat => "invalid_format",
+                    Self::InvalidDimensions => "invalid_dimensions",
+                    Self::OutOfBounds => "out_of_bounds",
+                    Self::InvalidWlBuffer => "invalid_wl_buffer",
+                    Self::InvalidDevTSize => "invalid_dev_t_size",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -321,12 +708,43 @@ pub mod linux_dmabuf_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        bitflags::bitflags! {
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub
+            struct Flags : u32 { #[doc = "contents are y-inverted"] const YInvert = 1u32;
+            #[doc = "content is interlaced"] const Interlaced = 2u32; #[doc =
+            "bottom field first"] const BottomFirst = 4u32; }
+        }
+        #[allow(deprecated)]
+        impl Flags {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("y_invert", Self::YInvert),
+                ("interlaced", Self::Interlaced),
+                ("bottom_first", Self::BottomFirst),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
             }
         }
-        bitflags::bitflags! { # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Flags : u32 { # [doc = "contents are y-inverted"] const YInvert = 1u32 ; # [doc = "content is interlaced"] const Interlaced = 2u32 ; # [doc = "bottom field first"] const BottomFirst = 4u32 ; } }
         impl From<Flags> for u32 {
             fn from(value: Flags) -> Self {
                 value.bits()
@@ -340,46 +758,328 @@ pub mod linux_dmabuf_v1 {
         }
         impl std::fmt::Display for Flags {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "add",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "huuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "plane_idx",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "offset",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "stride",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_hi",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "modifier_lo",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "create_immed",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2niiuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer_id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "format",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "flags",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("flags"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_sampling_device",
+                since: 6u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "6a",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "device",
+                        kind: waynest::ArgKind::Array,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwp_linux_buffer_params_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the created message was introduced in
+            pub const CREATED_SINCE: u32 = 1u32;
+            ///The interface version the created message was deprecated in, if any
+            pub const CREATED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the attempted buffer creation was
+            ///successful. It provides the new wl_buffer referencing the dmabuf(s).
+            ///
+            ///Upon receiving this event, the client should destroy the
+            ///zwp_linux_buffer_params_v1 object.
+            pub fn created<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                buffer: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_buffer_params_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "created", direction = "out", "args.buffer" = % buffer,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_buffer_params_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "created", direction = "out", "args.buffer" : % =
+                    buffer; "zwp_linux_buffer_params_v1.created"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(buffer))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the failed message was introduced in
+            pub const FAILED_SINCE: u32 = 1u32;
+            ///The interface version the failed message was deprecated in, if any
+            pub const FAILED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the attempted buffer creation has
+            ///failed. It usually means that one of the dmabuf constraints
+            ///has not been fulfilled.
+            ///
+            ///Upon receiving this event, the client should destroy the
+            ///zwp_linux_buffer_params_v1 object.
+            pub fn failed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_buffer_params_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "failed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_buffer_params_v1", object_id : % = sender_id, opcode =
+                    1u16, message = "failed", direction = "out";
+                    "zwp_linux_buffer_params_v1.failed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwp_linux_buffer_params_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwp_linux_buffer_params_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_linux_buffer_params_v1` interface",
+            label = "`ZwpLinuxBufferParamsV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwpLinuxBufferParamsV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwp_linux_buffer_params_v1";
             const VERSION: u32 = 6u32;
-            #[doc = "Cleans up the temporary data sent to the server for dmabuf-based"]
-            #[doc = "wl_buffer creation."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Cleans up the temporary data sent to the server for dmabuf-based
+            ///wl_buffer creation.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request adds one dmabuf to the set in this"]
-            #[doc = "zwp_linux_buffer_params_v1."]
-            #[doc = ""]
-            #[doc = "The 64-bit unsigned value combined from modifier_hi and modifier_lo"]
-            #[doc = "is the dmabuf layout modifier. DRM AddFB2 ioctl calls this the"]
-            #[doc = "fb modifier, which is defined in drm_mode.h of Linux UAPI."]
-            #[doc = "This is an opaque token. Drivers use this token to express tiling,"]
-            #[doc = "compression, etc. driver-specific modifications to the base format"]
-            #[doc = "defined by the DRM fourcc code."]
-            #[doc = ""]
-            #[doc = "Starting from version 4, the invalid_format protocol error is sent if"]
-            #[doc = "the format + modifier pair was not advertised as supported."]
-            #[doc = ""]
-            #[doc = "Starting from version 5, the invalid_format protocol error is sent if"]
-            #[doc = "all planes don't use the same modifier."]
-            #[doc = ""]
-            #[doc = "This request raises the PLANE_IDX error if plane_idx is too large."]
-            #[doc = "The error PLANE_SET is raised if attempting to set a plane that"]
-            #[doc = "was already set."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the add message was introduced in
+            const ADD_SINCE: u32 = 1u32;
+            ///The interface version the add message was deprecated in, if any
+            const ADD_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request adds one dmabuf to the set in this
+            ///zwp_linux_buffer_params_v1.
+            ///
+            ///The 64-bit unsigned value combined from modifier_hi and modifier_lo
+            ///is the dmabuf layout modifier. DRM AddFB2 ioctl calls this the
+            ///fb modifier, which is defined in drm_mode.h of Linux UAPI.
+            ///This is an opaque token. Drivers use this token to express tiling,
+            ///compression, etc. driver-specific modifications to the base format
+            ///defined by the DRM fourcc code.
+            ///
+            ///Starting from version 4, the invalid_format protocol error is sent if
+            ///the format + modifier pair was not advertised as supported.
+            ///
+            ///Starting from version 5, the invalid_format protocol error is sent if
+            ///all planes don't use the same modifier.
+            ///
+            ///This request raises the PLANE_IDX error if plane_idx is too large.
+            ///The error PLANE_SET is raised if attempting to set a plane that
+            ///was already set.
             fn add(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 fd: std::os::fd::OwnedFd,
                 plane_idx: u32,
@@ -387,195 +1087,182 @@ pub mod linux_dmabuf_v1 {
                 stride: u32,
                 modifier_hi: u32,
                 modifier_lo: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This asks for creation of a wl_buffer from the added dmabuf"]
-            #[doc = "buffers. The wl_buffer is not created immediately but returned via"]
-            #[doc = "the 'created' event if the dmabuf sharing succeeds. The sharing"]
-            #[doc = "may fail at runtime for reasons a client cannot predict, in"]
-            #[doc = "which case the 'failed' event is triggered."]
-            #[doc = ""]
-            #[doc = "The 'format' argument is a DRM_FORMAT code, as defined by the"]
-            #[doc = "libdrm's drm_fourcc.h. The Linux kernel's DRM sub-system is the"]
-            #[doc = "authoritative source on how the format codes should work."]
-            #[doc = ""]
-            #[doc = "The 'flags' is a bitfield of the flags defined in enum \"flags\"."]
-            #[doc = "'y_invert' means that the image needs to be y-flipped."]
-            #[doc = ""]
-            #[doc = "Flag 'interlaced' means that the frame in the buffer is not"]
-            #[doc = "progressive as usual, but interlaced. An interlaced buffer as"]
-            #[doc = "supported here must always contain both top and bottom fields."]
-            #[doc = "The top field always begins on the first pixel row. The temporal"]
-            #[doc = "ordering between the two fields is top field first, unless"]
-            #[doc = "'bottom_first' is specified. It is undefined whether 'bottom_first'"]
-            #[doc = "is ignored if 'interlaced' is not set."]
-            #[doc = ""]
-            #[doc = "This protocol does not convey any information about field rate,"]
-            #[doc = "duration, or timing, other than the relative ordering between the"]
-            #[doc = "two fields in one buffer. A compositor may have to estimate the"]
-            #[doc = "intended field rate from the incoming buffer rate. It is undefined"]
-            #[doc = "whether the time of receiving wl_surface.commit with a new buffer"]
-            #[doc = "attached, applying the wl_surface state, wl_surface.frame callback"]
-            #[doc = "trigger, presentation, or any other point in the compositor cycle"]
-            #[doc = "is used to measure the frame or field times. There is no support"]
-            #[doc = "for detecting missed or late frames/fields/buffers either, and"]
-            #[doc = "there is no support whatsoever for cooperating with interlaced"]
-            #[doc = "compositor output."]
-            #[doc = ""]
-            #[doc = "The composited image quality resulting from the use of interlaced"]
-            #[doc = "buffers is explicitly undefined. A compositor may use elaborate"]
-            #[doc = "hardware features or software to deinterlace and create progressive"]
-            #[doc = "output frames from a sequence of interlaced input buffers, or it"]
-            #[doc = "may produce substandard image quality. However, compositors that"]
-            #[doc = "cannot guarantee reasonable image quality in all cases are recommended"]
-            #[doc = "to just reject all interlaced buffers."]
-            #[doc = ""]
-            #[doc = "Any argument errors, including non-positive width or height,"]
-            #[doc = "mismatch between the number of planes and the format, bad"]
-            #[doc = "format, bad offset or stride, may be indicated by fatal protocol"]
-            #[doc = "errors: INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS,"]
-            #[doc = "OUT_OF_BOUNDS."]
-            #[doc = ""]
-            #[doc = "Dmabuf import errors in the server that are not obvious client"]
-            #[doc = "bugs are returned via the 'failed' event as non-fatal. This"]
-            #[doc = "allows attempting dmabuf sharing and falling back in the client"]
-            #[doc = "if it fails."]
-            #[doc = ""]
-            #[doc = "This request can be sent only once in the object's lifetime, after"]
-            #[doc = "which the only legal request is destroy. This object should be"]
-            #[doc = "destroyed after issuing a 'create' request. Attempting to use this"]
-            #[doc = "object after issuing 'create' raises the ALREADY_USED protocol error."]
-            #[doc = ""]
-            #[doc = "It is not mandatory to issue 'create'. If a client wants to"]
-            #[doc = "cancel the buffer creation, it can just destroy this object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the create message was introduced in
+            const CREATE_SINCE: u32 = 1u32;
+            ///The interface version the create message was deprecated in, if any
+            const CREATE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This asks for creation of a wl_buffer from the added dmabuf
+            ///buffers. The wl_buffer is not created immediately but returned via
+            ///the 'created' event if the dmabuf sharing succeeds. The sharing
+            ///may fail at runtime for reasons a client cannot predict, in
+            ///which case the 'failed' event is triggered.
+            ///
+            ///The 'format' argument is a DRM_FORMAT code, as defined by the
+            ///libdrm's drm_fourcc.h. The Linux kernel's DRM sub-system is the
+            ///authoritative source on how the format codes should work.
+            ///
+            ///The 'flags' is a bitfield of the flags defined in enum "flags".
+            ///'y_invert' means that the image needs to be y-flipped.
+            ///
+            ///Flag 'interlaced' means that the frame in the buffer is not
+            ///progressive as usual, but interlaced. An interlaced buffer as
+            ///supported here must always contain both top and bottom fields.
+            ///The top field always begins on the first pixel row. The temporal
+            ///ordering between the two fields is top field first, unless
+            ///'bottom_first' is specified. It is undefined whether 'bottom_first'
+            ///is ignored if 'interlaced' is not set.
+            ///
+            ///This protocol does not convey any information about field rate,
+            ///duration, or timing, other than the relative ordering between the
+            ///two fields in one buffer. A compositor may have to estimate the
+            ///intended field rate from the incoming buffer rate. It is undefined
+            ///whether the time of receiving wl_surface.commit with a new buffer
+            ///attached, applying the wl_surface state, wl_surface.frame callback
+            ///trigger, presentation, or any other point in the compositor cycle
+            ///is used to measure the frame or field times. There is no support
+            ///for detecting missed or late frames/fields/buffers either, and
+            ///there is no support whatsoever for cooperating with interlaced
+            ///compositor output.
+            ///
+            ///The composited image quality resulting from the use of interlaced
+            ///buffers is explicitly undefined. A compositor may use elaborate
+            ///hardware features or software to deinterlace and create progressive
+            ///output frames from a sequence of interlaced input buffers, or it
+            ///may produce substandard image quality. However, compositors that
+            ///cannot guarantee reasonable image quality in all cases are recommended
+            ///to just reject all interlaced buffers.
+            ///
+            ///Any argument errors, including non-positive width or height,
+            ///mismatch between the number of planes and the format, bad
+            ///format, bad offset or stride, may be indicated by fatal protocol
+            ///errors: INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS,
+            ///OUT_OF_BOUNDS.
+            ///
+            ///Dmabuf import errors in the server that are not obvious client
+            ///bugs are returned via the 'failed' event as non-fatal. This
+            ///allows attempting dmabuf sharing and falling back in the client
+            ///if it fails.
+            ///
+            ///This request can be sent only once in the object's lifetime, after
+            ///which the only legal request is destroy. This object should be
+            ///destroyed after issuing a 'create' request. Attempting to use this
+            ///object after issuing 'create' raises the ALREADY_USED protocol error.
+            ///
+            ///It is not mandatory to issue 'create'. If a client wants to
+            ///cancel the buffer creation, it can just destroy this object.
             fn create(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
                 format: u32,
                 flags: Flags,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This asks for immediate creation of a wl_buffer by importing the"]
-            #[doc = "added dmabufs."]
-            #[doc = ""]
-            #[doc = "In case of import success, no event is sent from the server, and the"]
-            #[doc = "wl_buffer is ready to be used by the client."]
-            #[doc = ""]
-            #[doc = "Upon import failure, either of the following may happen, as seen fit"]
-            #[doc = "by the implementation:"]
-            #[doc = "- the client is terminated with one of the following fatal protocol"]
-            #[doc = "errors:"]
-            #[doc = "- INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS, OUT_OF_BOUNDS,"]
-            #[doc = "in case of argument errors such as mismatch between the number"]
-            #[doc = "of planes and the format, bad format, non-positive width or"]
-            #[doc = "height, or bad offset or stride."]
-            #[doc = "- INVALID_WL_BUFFER, in case the cause for failure is unknown or"]
-            #[doc = "platform specific."]
-            #[doc = "- the server creates an invalid wl_buffer, marks it as failed and"]
-            #[doc = "sends a 'failed' event to the client. The result of using this"]
-            #[doc = "invalid wl_buffer as an argument in any request by the client is"]
-            #[doc = "defined by the compositor implementation."]
-            #[doc = ""]
-            #[doc = "This takes the same arguments as a 'create' request, and obeys the"]
-            #[doc = "same restrictions."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the create_immed message was introduced in
+            const CREATE_IMMED_SINCE: u32 = 2u32;
+            ///The interface version the create_immed message was deprecated in, if any
+            const CREATE_IMMED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This asks for immediate creation of a wl_buffer by importing the
+            ///added dmabufs.
+            ///
+            ///In case of import success, no event is sent from the server, and the
+            ///wl_buffer is ready to be used by the client.
+            ///
+            ///Upon import failure, either of the following may happen, as seen fit
+            ///by the implementation:
+            ///- the client is terminated with one of the following fatal protocol
+            ///errors:
+            ///- INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS, OUT_OF_BOUNDS,
+            ///in case of argument errors such as mismatch between the number
+            ///of planes and the format, bad format, non-positive width or
+            ///height, or bad offset or stride.
+            ///- INVALID_WL_BUFFER, in case the cause for failure is unknown or
+            ///platform specific.
+            ///- the server creates an invalid wl_buffer, marks it as failed and
+            ///sends a 'failed' event to the client. The result of using this
+            ///invalid wl_buffer as an argument in any request by the client is
+            ///defined by the compositor implementation.
+            ///
+            ///This takes the same arguments as a 'create' request, and obeys the
+            ///same restrictions.
+            ///
+            ///Available since version 2
             fn create_immed(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 buffer_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
                 format: u32,
                 flags: Flags,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the device the compositor should import the dmabufs to for sampling"]
-            #[doc = "in the next create or create_immed request."]
-            #[doc = ""]
-            #[doc = "To avoid race conditions when the compositor removes a device from the"]
-            #[doc = "tranches, it is not a protocol error if the device hasn't been advertised"]
-            #[doc = "by the compositor in a tranche with the sampling flag, but the import is"]
-            #[doc = "likely to fail in that case."]
-            #[doc = ""]
-            #[doc = "If the client doesn't know a suitable target device, it shouldn't set one,"]
-            #[doc = "and the compositor should attempt import on all devices it supports."]
-            #[doc = ""]
-            #[doc = "If the array is too small to contain a dev_t or larger than required, the"]
-            #[doc = "invalid_dev_t_size error will be emitted."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_sampling_device message was introduced in
+            const SET_SAMPLING_DEVICE_SINCE: u32 = 6u32;
+            ///The interface version the set_sampling_device message was deprecated in, if any
+            const SET_SAMPLING_DEVICE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the device the compositor should import the dmabufs to for sampling
+            ///in the next create or create_immed request.
+            ///
+            ///To avoid race conditions when the compositor removes a device from the
+            ///tranches, it is not a protocol error if the device hasn't been advertised
+            ///by the compositor in a tranche with the sampling flag, but the import is
+            ///likely to fail in that case.
+            ///
+            ///If the client doesn't know a suitable target device, it shouldn't set one,
+            ///and the compositor should attempt import on all devices it supports.
+            ///
+            ///If the array is too small to contain a dev_t or larger than required, the
+            ///invalid_dev_t_size error will be emitted.
+            ///
+            ///Available since version 6
             fn set_sampling_device(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 device: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event indicates that the attempted buffer creation was"]
-            #[doc = "successful. It provides the new wl_buffer referencing the dmabuf(s)."]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy the"]
-            #[doc = "zwp_linux_buffer_params_v1 object."]
-            fn created(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                buffer: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_buffer_params_v1#{}.created({})",
-                        sender_id,
-                        buffer
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(buffer))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates that the attempted buffer creation has"]
-            #[doc = "failed. It usually means that one of the dmabuf constraints"]
-            #[doc = "has not been fulfilled."]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy the"]
-            #[doc = "zwp_linux_buffer_params_v1 object."]
-            fn failed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_linux_buffer_params_v1#{}.failed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_linux_buffer_params_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_buffer_params_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_buffer_params_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "destroy", direction = "in";
+                                "zwp_linux_buffer_params_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let fd = waynest::Connection::fd(connection)?;
@@ -585,51 +1272,71 @@ pub mod linux_dmabuf_v1 {
                             let modifier_hi = message.uint()?;
                             let modifier_lo = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_buffer_params_v1#{}.add({}, {}, {}, {}, {}, {})",
-                                sender_id,
-                                std::os::fd::AsRawFd::as_raw_fd(&fd),
-                                plane_idx,
-                                offset,
-                                stride,
-                                modifier_hi,
-                                modifier_lo
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_buffer_params_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "add", direction = "in", "args.fd"
+                                = % std::os::fd::AsRawFd::as_raw_fd(& fd), "args.plane_idx"
+                                = % plane_idx, "args.offset" = % offset, "args.stride" = %
+                                stride, "args.modifier_hi" = % modifier_hi,
+                                "args.modifier_lo" = % modifier_lo,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_buffer_params_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "add", direction = "in", "args.fd"
+                                : % = std::os::fd::AsRawFd::as_raw_fd(& fd),
+                                "args.plane_idx" : % = plane_idx, "args.offset" : % =
+                                offset, "args.stride" : % = stride, "args.modifier_hi" : % =
+                                modifier_hi, "args.modifier_lo" : % = modifier_lo;
+                                "zwp_linux_buffer_params_v1.add"
                             );
                             self.add(
-                                connection,
-                                sender_id,
-                                fd,
-                                plane_idx,
-                                offset,
-                                stride,
-                                modifier_hi,
-                                modifier_lo,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    fd,
+                                    plane_idx,
+                                    offset,
+                                    stride,
+                                    modifier_hi,
+                                    modifier_lo,
+                                )
+                                .await
                         }
                         2u16 => {
                             let width = message.int()?;
                             let height = message.int()?;
                             let format = message.uint()?;
-                            let flags = message.uint()?;
+                            let flags: Flags = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_buffer_params_v1#{}.create({}, {}, {}, {})",
-                                sender_id,
-                                width,
-                                height,
-                                format,
-                                flags
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_buffer_params_v1", object_id = % sender_id,
+                                opcode = 2u16, message = "create", direction = "in",
+                                "args.width" = % width, "args.height" = % height,
+                                "args.format" = % format, "args.flags" = % flags,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_buffer_params_v1", object_id : % = sender_id,
+                                opcode = 2u16, message = "create", direction = "in",
+                                "args.width" : % = width, "args.height" : % = height,
+                                "args.format" : % = format, "args.flags" : % = flags;
+                                "zwp_linux_buffer_params_v1.create"
                             );
                             self.create(
-                                connection,
-                                sender_id,
-                                width,
-                                height,
-                                format,
-                                flags.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    width,
+                                    height,
+                                    format,
+                                    flags,
+                                )
+                                .await
                         }
                         3u16 => {
                             let buffer_id = message
@@ -638,76 +1345,126 @@ pub mod linux_dmabuf_v1 {
                             let width = message.int()?;
                             let height = message.int()?;
                             let format = message.uint()?;
-                            let flags = message.uint()?;
+                            let flags: Flags = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_buffer_params_v1#{}.create_immed({}, {}, {}, {}, {})",
-                                sender_id,
-                                buffer_id,
-                                width,
-                                height,
-                                format,
-                                flags
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_buffer_params_v1", object_id = % sender_id,
+                                opcode = 3u16, message = "create_immed", direction = "in",
+                                "args.buffer_id" = % buffer_id, "args.width" = % width,
+                                "args.height" = % height, "args.format" = % format,
+                                "args.flags" = % flags,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_buffer_params_v1", object_id : % = sender_id,
+                                opcode = 3u16, message = "create_immed", direction = "in",
+                                "args.buffer_id" : % = buffer_id, "args.width" : % = width,
+                                "args.height" : % = height, "args.format" : % = format,
+                                "args.flags" : % = flags;
+                                "zwp_linux_buffer_params_v1.create_immed"
                             );
                             self.create_immed(
-                                connection,
-                                sender_id,
-                                buffer_id,
-                                width,
-                                height,
-                                format,
-                                flags.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    buffer_id,
+                                    width,
+                                    height,
+                                    format,
+                                    flags,
+                                )
+                                .await
                         }
                         4u16 => {
                             let device = message.array()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_linux_buffer_params_v1#{}.set_sampling_device(array[{}])",
-                                sender_id,
-                                device.len()
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_buffer_params_v1", object_id = % sender_id,
+                                opcode = 4u16, message = "set_sampling_device", direction =
+                                "in", "args.device" = % device.len(),
                             );
-                            self.set_sampling_device(connection, sender_id, device)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_buffer_params_v1", object_id : % = sender_id,
+                                opcode = 4u16, message = "set_sampling_device", direction =
+                                "in", "args.device" : % = device.len();
+                                "zwp_linux_buffer_params_v1.set_sampling_device"
+                            );
+                            self.set_sampling_device(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    device,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "This object advertises dmabuf parameters feedback. This includes the"]
-    #[doc = "preferred devices and the supported formats/modifiers."]
-    #[doc = ""]
-    #[doc = "The parameters are sent once when this object is created and whenever they"]
-    #[doc = "change. The done event is always sent once after all parameters have been"]
-    #[doc = "sent. When a single parameter changes, all parameters are re-sent by the"]
-    #[doc = "compositor."]
-    #[doc = ""]
-    #[doc = "Compositors can re-send the parameters when the current client buffer"]
-    #[doc = "allocations are sub-optimal. Compositors should not re-send the"]
-    #[doc = "parameters if re-allocating the buffers would not result in a more optimal"]
-    #[doc = "configuration. In particular, compositors should avoid sending the exact"]
-    #[doc = "same parameters multiple times in a row."]
-    #[doc = ""]
-    #[doc = "The tranche_target_device and tranche_formats events are grouped by"]
-    #[doc = "tranches of preference. For each tranche, a tranche_target_device, one"]
-    #[doc = "tranche_flags and one or more tranche_formats events are sent, followed"]
-    #[doc = "by a tranche_done event finishing the list. The tranches are sent in"]
-    #[doc = "descending order of preference. All formats and modifiers in the same"]
-    #[doc = "tranche have the same preference."]
-    #[doc = ""]
-    #[doc = "To send parameters, the compositor sends one main_device event (unless"]
-    #[doc = "the client bound version 6 or above), tranches (each consisting of one"]
-    #[doc = "tranche_target_device event, one tranche_flags event, tranche_formats"]
-    #[doc = "events and then a tranche_done event), then one done event."]
-    #[doc = ""]
-    #[doc = "With version 6 and above, the compositor must always advertise at least"]
-    #[doc = "one tranche with the sampling flag set."]
+    ///This object advertises dmabuf parameters feedback. This includes the
+    ///preferred devices and the supported formats/modifiers.
+    ///
+    ///The parameters are sent once when this object is created and whenever they
+    ///change. The done event is always sent once after all parameters have been
+    ///sent. When a single parameter changes, all parameters are re-sent by the
+    ///compositor.
+    ///
+    ///Compositors can re-send the parameters when the current client buffer
+    ///allocations are sub-optimal. Compositors should not re-send the
+    ///parameters if re-allocating the buffers would not result in a more optimal
+    ///configuration. In particular, compositors should avoid sending the exact
+    ///same parameters multiple times in a row.
+    ///
+    ///The tranche_target_device and tranche_formats events are grouped by
+    ///tranches of preference. For each tranche, a tranche_target_device, one
+    ///tranche_flags and one or more tranche_formats events are sent, followed
+    ///by a tranche_done event finishing the list. The tranches are sent in
+    ///descending order of preference. All formats and modifiers in the same
+    ///tranche have the same preference.
+    ///
+    ///To send parameters, the compositor sends one main_device event (unless
+    ///the client bound version 6 or above), tranches (each consisting of one
+    ///tranche_target_device event, one tranche_flags event, tranche_formats
+    ///events and then a tranche_done event), then one done event.
+    ///
+    ///With version 6 and above, the compositor must always advertise at least
+    ///one tranche with the sampling flag set.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_linux_dmabuf_feedback_v1 {
-        bitflags::bitflags! { # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct TrancheFlags : u32 { const Scanout = 1u32 ; const Sampling = 2u32 ; } }
+        bitflags::bitflags! {
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub
+            struct TrancheFlags : u32 { #[doc = ""] #[doc = "Available since version 4"]
+            const Scanout = 1u32; #[doc = ""] #[doc = "Available since version 6"] const
+            Sampling = 2u32; }
+        }
+        #[allow(deprecated)]
+        impl TrancheFlags {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("scanout", Self::Scanout),
+                ("sampling", Self::Sampling),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<TrancheFlags> for u32 {
             fn from(value: TrancheFlags) -> Self {
                 value.bits()
@@ -721,350 +1478,528 @@ pub mod linux_dmabuf_v1 {
         }
         impl std::fmt::Display for TrancheFlags {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Trait to implement the zwp_linux_dmabuf_feedback_v1 interface. See the module level documentation for more info"]
-        pub trait ZwpLinuxDmabufFeedbackV1
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_linux_dmabuf_feedback_v1";
-            const VERSION: u32 = 6u32;
-            #[doc = "Using this request a client can tell the server that it is not going to"]
-            #[doc = "use the zwp_linux_dmabuf_feedback_v1 object anymore."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event is sent after all parameters of a zwp_linux_dmabuf_feedback_v1"]
-            #[doc = "object have been sent."]
-            #[doc = ""]
-            #[doc = "This allows changes to the zwp_linux_dmabuf_feedback_v1 parameters to be"]
-            #[doc = "seen as atomic, even if they happen via multiple events."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_linux_dmabuf_feedback_v1#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_linux_dmabuf_feedback_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent after all parameters of a zwp_linux_dmabuf_feedback_v1
+            ///object have been sent.
+            ///
+            ///This allows changes to the zwp_linux_dmabuf_feedback_v1 parameters to be
+            ///seen as atomic, even if they happen via multiple events.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    0u16, message = "done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "done", direction = "out";
+                    "zwp_linux_dmabuf_feedback_v1.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event provides a file descriptor which can be memory-mapped to"]
-            #[doc = "access the format and modifier table."]
-            #[doc = ""]
-            #[doc = "The table contains a tightly packed array of consecutive format +"]
-            #[doc = "modifier pairs. Each pair is 16 bytes wide. It contains a format as a"]
-            #[doc = "32-bit unsigned integer, followed by 4 bytes of unused padding, and a"]
-            #[doc = "modifier as a 64-bit unsigned integer. The native endianness is used."]
-            #[doc = ""]
-            #[doc = "The client must map the file descriptor in read-only private mode."]
-            #[doc = ""]
-            #[doc = "Compositors are not allowed to mutate the table file contents once this"]
-            #[doc = "event has been sent. Instead, compositors must create a new, separate"]
-            #[doc = "table file and re-send feedback parameters. Compositors are allowed to"]
-            #[doc = "store duplicate format + modifier pairs in the table."]
-            fn format_table(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the format_table message was introduced in
+            pub const FORMAT_TABLE_SINCE: u32 = 1u32;
+            ///The interface version the format_table message was deprecated in, if any
+            pub const FORMAT_TABLE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event provides a file descriptor which can be memory-mapped to
+            ///access the format and modifier table.
+            ///
+            ///The table contains a tightly packed array of consecutive format +
+            ///modifier pairs. Each pair is 16 bytes wide. It contains a format as a
+            ///32-bit unsigned integer, followed by 4 bytes of unused padding, and a
+            ///modifier as a 64-bit unsigned integer. The native endianness is used.
+            ///
+            ///The client must map the file descriptor in read-only private mode.
+            ///
+            ///Compositors are not allowed to mutate the table file contents once this
+            ///event has been sent. Instead, compositors must create a new, separate
+            ///table file and re-send feedback parameters. Compositors are allowed to
+            ///store duplicate format + modifier pairs in the table.
+            pub fn format_table<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 fd: std::os::fd::OwnedFd,
                 size: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_feedback_v1#{}.format_table({}, {})",
-                        sender_id,
-                        std::os::fd::AsRawFd::as_raw_fd(&fd),
-                        size
-                    );
-                    waynest::Connection::push_fd(connection, fd);
-                    let payload = waynest::PayloadBuilder::new().put_uint(size).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    1u16, message = "format_table", direction = "out", "args.fd" = %
+                    std::os::fd::AsRawFd::as_raw_fd(& fd), "args.size" = % size,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    1u16, message = "format_table", direction = "out", "args.fd" : % =
+                    std::os::fd::AsRawFd::as_raw_fd(& fd), "args.size" : % = size;
+                    "zwp_linux_dmabuf_feedback_v1.format_table"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(size).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![fd],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event advertises the main device that the server prefers to use"]
-            #[doc = "when direct scan-out to the target device isn't possible. The"]
-            #[doc = "advertised main device may be different for each"]
-            #[doc = "zwp_linux_dmabuf_feedback_v1 object, and may change over time."]
-            #[doc = ""]
-            #[doc = "There is exactly one main device. The compositor must send at least"]
-            #[doc = "one preference tranche with tranche_target_device equal to main_device."]
-            #[doc = ""]
-            #[doc = "Clients need to create buffers that the main device can import and"]
-            #[doc = "read from, otherwise creating the dmabuf wl_buffer will fail (see the"]
-            #[doc = "zwp_linux_buffer_params_v1.create and create_immed requests for details)."]
-            #[doc = "The main device will also likely be kept active by the compositor,"]
-            #[doc = "so clients can use it instead of waking up another device for power"]
-            #[doc = "savings."]
-            #[doc = ""]
-            #[doc = "In general the device is a DRM node. The DRM node type (primary vs."]
-            #[doc = "render) is unspecified. Clients must not rely on the compositor sending"]
-            #[doc = "a particular node type. Clients cannot check two devices for equality"]
-            #[doc = "by comparing the dev_t value."]
-            #[doc = ""]
-            #[doc = "If explicit modifiers are not supported and the client performs buffer"]
-            #[doc = "allocations on a different device than the main device, then the client"]
-            #[doc = "must force the buffer to have a linear layout."]
-            #[doc = ""]
-            #[doc = "With version 6 and above, this event is no longer sent. Clients should"]
-            #[doc = "use a device with the sampling flag in the tranches instead."]
-            fn main_device(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the main_device message was introduced in
+            pub const MAIN_DEVICE_SINCE: u32 = 1u32;
+            ///The interface version the main_device message was deprecated in, if any
+            pub const MAIN_DEVICE_DEPRECATED_SINCE: Option<u32> = Some(6u32);
+            ///This event advertises the main device that the server prefers to use
+            ///when direct scan-out to the target device isn't possible. The
+            ///advertised main device may be different for each
+            ///zwp_linux_dmabuf_feedback_v1 object, and may change over time.
+            ///
+            ///There is exactly one main device. The compositor must send at least
+            ///one preference tranche with tranche_target_device equal to main_device.
+            ///
+            ///Clients need to create buffers that the main device can import and
+            ///read from, otherwise creating the dmabuf wl_buffer will fail (see the
+            ///zwp_linux_buffer_params_v1.create and create_immed requests for details).
+            ///The main device will also likely be kept active by the compositor,
+            ///so clients can use it instead of waking up another device for power
+            ///savings.
+            ///
+            ///In general the device is a DRM node. The DRM node type (primary vs.
+            ///render) is unspecified. Clients must not rely on the compositor sending
+            ///a particular node type. Clients cannot check two devices for equality
+            ///by comparing the dev_t value.
+            ///
+            ///If explicit modifiers are not supported and the client performs buffer
+            ///allocations on a different device than the main device, then the client
+            ///must force the buffer to have a linear layout.
+            ///
+            ///With version 6 and above, this event is no longer sent. Clients should
+            ///use a device with the sampling flag in the tranches instead.
+            ///
+            ///Deprecated since version 6
+            #[deprecated(note = "deprecated since version 6 of the interface")]
+            pub fn main_device<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 device: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_feedback_v1#{}.main_device(array[{}])",
-                        sender_id,
-                        device.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_array(device).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    2u16, message = "main_device", direction = "out", "args.device" = %
+                    device.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    2u16, message = "main_device", direction = "out", "args.device" : % =
+                    device.len(); "zwp_linux_dmabuf_feedback_v1.main_device"
+                );
+                let payload = waynest::PayloadBuilder::new().put_array(device).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event splits tranche_target_device and tranche_formats events into"]
-            #[doc = "preference tranches. It is sent after a set of tranche_target_device"]
-            #[doc = "and tranche_formats events; it represents the end of a tranche. The"]
-            #[doc = "next tranche will have a lower preference."]
-            fn tranche_done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_feedback_v1#{}.tranche_done()",
-                        sender_id,
-                    );
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the tranche_done message was introduced in
+            pub const TRANCHE_DONE_SINCE: u32 = 1u32;
+            ///The interface version the tranche_done message was deprecated in, if any
+            pub const TRANCHE_DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event splits tranche_target_device and tranche_formats events into
+            ///preference tranches. It is sent after a set of tranche_target_device
+            ///and tranche_formats events; it represents the end of a tranche. The
+            ///next tranche will have a lower preference.
+            pub fn tranche_done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    3u16, message = "tranche_done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    3u16, message = "tranche_done", direction = "out";
+                    "zwp_linux_dmabuf_feedback_v1.tranche_done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event advertises the target device that the server prefers to use"]
-            #[doc = "for a buffer created given this tranche. The advertised target device"]
-            #[doc = "may be different for each preference tranche, and may change over time."]
-            #[doc = ""]
-            #[doc = "There is exactly one target device per tranche."]
-            #[doc = ""]
-            #[doc = "The target device may be a scan-out device, for example if the"]
-            #[doc = "compositor prefers to directly scan-out a buffer created given this"]
-            #[doc = "tranche. The target device may be a rendering device, for example if"]
-            #[doc = "the compositor prefers to texture from said buffer."]
-            #[doc = ""]
-            #[doc = "The client can use this hint to allocate the buffer in a way that makes"]
-            #[doc = "it accessible from the target device, ideally directly. The buffer must"]
-            #[doc = "still be accessible from a device with the sampling flag, either through"]
-            #[doc = "direct import or a potentially more expensive fallback path. If the"]
-            #[doc = "buffer can't be directly imported for sampling, then clients must be"]
-            #[doc = "prepared for the compositor changing the tranche priority or making"]
-            #[doc = "wl_buffer creation fail (see the zwp_linux_buffer_params_v1.create and"]
-            #[doc = "create_immed requests for details)."]
-            #[doc = ""]
-            #[doc = "If the device is a DRM node, the DRM node type (primary vs. render) is"]
-            #[doc = "unspecified. Clients must not rely on the compositor sending a"]
-            #[doc = "particular node type. Clients cannot check two devices for equality by"]
-            #[doc = "comparing the dev_t value."]
-            #[doc = ""]
-            #[doc = "This event is tied to a preference tranche, see the tranche_done event."]
-            fn tranche_target_device(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the tranche_target_device message was introduced in
+            pub const TRANCHE_TARGET_DEVICE_SINCE: u32 = 1u32;
+            ///The interface version the tranche_target_device message was deprecated in, if any
+            pub const TRANCHE_TARGET_DEVICE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event advertises the target device that the server prefers to use
+            ///for a buffer created given this tranche. The advertised target device
+            ///may be different for each preference tranche, and may change over time.
+            ///
+            ///There is exactly one target device per tranche.
+            ///
+            ///The target device may be a scan-out device, for example if the
+            ///compositor prefers to directly scan-out a buffer created given this
+            ///tranche. The target device may be a rendering device, for example if
+            ///the compositor prefers to texture from said buffer.
+            ///
+            ///The client can use this hint to allocate the buffer in a way that makes
+            ///it accessible from the target device, ideally directly. The buffer must
+            ///still be accessible from a device with the sampling flag, either through
+            ///direct import or a potentially more expensive fallback path. If the
+            ///buffer can't be directly imported for sampling, then clients must be
+            ///prepared for the compositor changing the tranche priority or making
+            ///wl_buffer creation fail (see the zwp_linux_buffer_params_v1.create and
+            ///create_immed requests for details).
+            ///
+            ///If the device is a DRM node, the DRM node type (primary vs. render) is
+            ///unspecified. Clients must not rely on the compositor sending a
+            ///particular node type. Clients cannot check two devices for equality by
+            ///comparing the dev_t value.
+            ///
+            ///This event is tied to a preference tranche, see the tranche_done event.
+            pub fn tranche_target_device<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 device: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_feedback_v1#{}.tranche_target_device(array[{}])",
-                        sender_id,
-                        device.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_array(device).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    4u16, message = "tranche_target_device", direction = "out",
+                    "args.device" = % device.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    4u16, message = "tranche_target_device", direction = "out",
+                    "args.device" : % = device.len();
+                    "zwp_linux_dmabuf_feedback_v1.tranche_target_device"
+                );
+                let payload = waynest::PayloadBuilder::new().put_array(device).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event advertises the format + modifier combinations that the"]
-            #[doc = "compositor supports."]
-            #[doc = ""]
-            #[doc = "It carries an array of indices, each referring to a format + modifier"]
-            #[doc = "pair in the last received format table (see the format_table event)."]
-            #[doc = "Each index is a 16-bit unsigned integer in native endianness."]
-            #[doc = ""]
-            #[doc = "For legacy support, DRM_FORMAT_MOD_INVALID is an allowed modifier."]
-            #[doc = "It indicates that the server can support the format with an implicit"]
-            #[doc = "modifier. When a buffer has DRM_FORMAT_MOD_INVALID as its modifier, it"]
-            #[doc = "is as if no explicit modifier is specified. The effective modifier"]
-            #[doc = "will be derived from the dmabuf."]
-            #[doc = ""]
-            #[doc = "A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for"]
-            #[doc = "a given format supports both explicit modifiers and implicit modifiers."]
-            #[doc = ""]
-            #[doc = "Compositors must not send duplicate format + modifier pairs within the"]
-            #[doc = "same tranche or across two different tranches with the same target"]
-            #[doc = "device and flags."]
-            #[doc = ""]
-            #[doc = "This event is tied to a preference tranche, see the tranche_done event."]
-            #[doc = ""]
-            #[doc = "For the definition of the format and modifier codes, see the"]
-            #[doc = "zwp_linux_buffer_params_v1.create request."]
-            fn tranche_formats(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the tranche_formats message was introduced in
+            pub const TRANCHE_FORMATS_SINCE: u32 = 1u32;
+            ///The interface version the tranche_formats message was deprecated in, if any
+            pub const TRANCHE_FORMATS_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event advertises the format + modifier combinations that the
+            ///compositor supports.
+            ///
+            ///It carries an array of indices, each referring to a format + modifier
+            ///pair in the last received format table (see the format_table event).
+            ///Each index is a 16-bit unsigned integer in native endianness.
+            ///
+            ///For legacy support, DRM_FORMAT_MOD_INVALID is an allowed modifier.
+            ///It indicates that the server can support the format with an implicit
+            ///modifier. When a buffer has DRM_FORMAT_MOD_INVALID as its modifier, it
+            ///is as if no explicit modifier is specified. The effective modifier
+            ///will be derived from the dmabuf.
+            ///
+            ///A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for
+            ///a given format supports both explicit modifiers and implicit modifiers.
+            ///
+            ///Compositors must not send duplicate format + modifier pairs within the
+            ///same tranche or across two different tranches with the same target
+            ///device and flags.
+            ///
+            ///This event is tied to a preference tranche, see the tranche_done event.
+            ///
+            ///For the definition of the format and modifier codes, see the
+            ///zwp_linux_buffer_params_v1.create request.
+            pub fn tranche_formats<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 indices: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_feedback_v1#{}.tranche_formats(array[{}])",
-                        sender_id,
-                        indices.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_array(indices).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    5u16, message = "tranche_formats", direction = "out", "args.indices"
+                    = % indices.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    5u16, message = "tranche_formats", direction = "out", "args.indices"
+                    : % = indices.len(); "zwp_linux_dmabuf_feedback_v1.tranche_formats"
+                );
+                let payload = waynest::PayloadBuilder::new().put_array(indices).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event sets tranche-specific flags. This event is tied to a"]
-            #[doc = "preference tranche, see the tranche_done event."]
-            #[doc = "With version 6 and above, the compositor must set at least one flag"]
-            #[doc = "in each tranche."]
-            fn tranche_flags(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the tranche_flags message was introduced in
+            pub const TRANCHE_FLAGS_SINCE: u32 = 1u32;
+            ///The interface version the tranche_flags message was deprecated in, if any
+            pub const TRANCHE_FLAGS_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event sets tranche-specific flags. This event is tied to a
+            ///preference tranche, see the tranche_done event.
+            ///With version 6 and above, the compositor must set at least one flag
+            ///in each tranche.
+            pub fn tranche_flags<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 flags: TrancheFlags,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_linux_dmabuf_feedback_v1#{}.tranche_flags({})",
-                        sender_id,
-                        flags
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(flags.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id, opcode =
+                    6u16, message = "tranche_flags", direction = "out", "args.flags" = %
+                    flags,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id, opcode =
+                    6u16, message = "tranche_flags", direction = "out", "args.flags" : %
+                    = flags; "zwp_linux_dmabuf_feedback_v1.tranche_flags"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(flags.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_linux_dmabuf_feedback_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_linux_dmabuf_feedback_v1` interface",
+            label = "`ZwpLinuxDmabufFeedbackV1` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpLinuxDmabufFeedbackV1
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_linux_dmabuf_feedback_v1";
+            const VERSION: u32 = 6u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Using this request a client can tell the server that it is not going to
+            ///use the zwp_linux_dmabuf_feedback_v1 object anymore.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_linux_dmabuf_feedback_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_linux_dmabuf_feedback_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_linux_dmabuf_feedback_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "destroy", direction = "in";
+                                "zwp_linux_dmabuf_feedback_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "presentation-time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "presentation-time")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod presentation_time {
-    #[doc = ""]
-    #[doc = ""]
-    #[doc = ""]
-    #[doc = "The main feature of this interface is accurate presentation"]
-    #[doc = "timing feedback to ensure smooth video playback while maintaining"]
-    #[doc = "audio/video synchronization. Some features use the concept of a"]
-    #[doc = "presentation clock, which is defined in the"]
-    #[doc = "presentation.clock_id event."]
-    #[doc = ""]
-    #[doc = "A content update for a wl_surface is submitted by a"]
-    #[doc = "wl_surface.commit request. Request 'feedback' associates with"]
-    #[doc = "the wl_surface.commit and provides feedback on the content"]
-    #[doc = "update, particularly the final realized presentation time."]
-    #[doc = ""]
-    #[doc = ""]
-    #[doc = ""]
-    #[doc = "When the final realized presentation time is available, e.g."]
-    #[doc = "after a framebuffer flip completes, the requested"]
-    #[doc = "presentation_feedback.presented events are sent. The final"]
-    #[doc = "presentation time can differ from the compositor's predicted"]
-    #[doc = "display update time and the update's target time, especially"]
-    #[doc = "when the compositor misses its target vertical blanking period."]
+    ///
+    ///
+    ///
+    ///The main feature of this interface is accurate presentation
+    ///timing feedback to ensure smooth video playback while maintaining
+    ///audio/video synchronization. Some features use the concept of a
+    ///presentation clock, which is defined in the
+    ///presentation.clock_id event.
+    ///
+    ///A content update for a wl_surface is submitted by a
+    ///wl_surface.commit request. Request 'feedback' associates with
+    ///the wl_surface.commit and provides feedback on the content
+    ///update, particularly the final realized presentation time.
+    ///
+    ///
+    ///
+    ///When the final realized presentation time is available, e.g.
+    ///after a framebuffer flip completes, the requested
+    ///presentation_feedback.presented events are sent. The final
+    ///presentation time can differ from the compositor's predicted
+    ///display update time and the update's target time, especially
+    ///when the compositor misses its target vertical blanking period.
     #[allow(clippy::too_many_arguments)]
     pub mod wp_presentation {
-        #[doc = "These fatal protocol errors may be emitted in response to"]
-        #[doc = "illegal presentation requests."]
+        ///These fatal protocol errors may be emitted in response to
+        ///illegal presentation requests.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "invalid value in tv_nsec"]
+            ///invalid value in tv_nsec
             InvalidTimestamp = 0u32,
-            #[doc = "invalid flag"]
+            ///invalid flag
             InvalidFlag = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidTimestamp => 1u32,
+                    Self::InvalidFlag => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidTimestamp => None,
+                    Self::InvalidFlag => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_timestamp", Self::InvalidTimestamp),
+                ("invalid_flag", Self::InvalidFlag),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidTimestamp => "invalid_timestamp",
+                    Self::InvalidFlag => "invalid_flag",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1075,103 +2010,194 @@ pub mod presentation_time {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "on",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "callback",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_presentation_feedback"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wp_presentation interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the clock_id message was introduced in
+            pub const CLOCK_ID_SINCE: u32 = 1u32;
+            ///The interface version the clock_id message was deprecated in, if any
+            pub const CLOCK_ID_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event tells the client in which clock domain the
+            ///compositor interprets the timestamps used by the presentation
+            ///extension. This clock is called the presentation clock.
+            ///
+            ///The compositor sends this event when the client binds to the
+            ///presentation interface. The presentation clock does not change
+            ///during the lifetime of the client connection.
+            ///
+            ///The clock identifier is platform dependent. On POSIX platforms, the
+            ///identifier value is one of the clockid_t values accepted by
+            ///clock_gettime(). clock_gettime() is defined by POSIX.1-2001.
+            ///
+            ///Timestamps in this clock domain are expressed as tv_sec_hi,
+            ///tv_sec_lo, tv_nsec triples, each component being an unsigned
+            ///32-bit value. Whole seconds are in tv_sec which is a 64-bit
+            ///value combined from tv_sec_hi and tv_sec_lo, and the
+            ///additional fractional part in tv_nsec as nanoseconds. Hence,
+            ///for valid timestamps tv_nsec must be in [0, 999999999].
+            ///
+            ///Note that clock_id applies only to the presentation clock,
+            ///and implies nothing about e.g. the timestamps used in the
+            ///Wayland core protocol input events.
+            ///
+            ///Compositors should prefer a clock which does not jump and is
+            ///not slewed e.g. by NTP. The absolute value of the clock is
+            ///irrelevant. Precision of one millisecond or better is
+            ///recommended. Clients must be able to query the current clock
+            ///value directly, not by asking the compositor.
+            pub fn clock_id<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                clk_id: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wp_presentation", object_id = % sender_id, opcode = 0u16, message =
+                    "clock_id", direction = "out", "args.clk_id" = % clk_id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "wp_presentation",
+                    object_id : % = sender_id, opcode = 0u16, message = "clock_id",
+                    direction = "out", "args.clk_id" : % = clk_id;
+                    "wp_presentation.clock_id"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(clk_id).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the wp_presentation interface. See the module level documentation for more info"]
+        ///Trait to implement the wp_presentation interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wp_presentation` interface",
+            label = "`WpPresentation` is not implemented for `{Self}`"
+        )]
         pub trait WpPresentation
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wp_presentation";
             const VERSION: u32 = 2u32;
-            #[doc = "Informs the server that the client will no longer be using"]
-            #[doc = "this protocol object. Existing objects created by this object"]
-            #[doc = "are not affected."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Informs the server that the client will no longer be using
+            ///this protocol object. Existing objects created by this object
+            ///are not affected.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Request presentation feedback for the current content submission"]
-            #[doc = "on the given surface. This creates a new presentation_feedback"]
-            #[doc = "object, which will deliver the feedback information once. If"]
-            #[doc = "multiple presentation_feedback objects are created for the same"]
-            #[doc = "submission, they will all deliver the same information."]
-            #[doc = ""]
-            #[doc = "For details on what information is returned, see the"]
-            #[doc = "presentation_feedback interface."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the feedback message was introduced in
+            const FEEDBACK_SINCE: u32 = 1u32;
+            ///The interface version the feedback message was deprecated in, if any
+            const FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request presentation feedback for the current content submission
+            ///on the given surface. This creates a new presentation_feedback
+            ///object, which will deliver the feedback information once. If
+            ///multiple presentation_feedback objects are created for the same
+            ///submission, they will all deliver the same information.
+            ///
+            ///For details on what information is returned, see the
+            ///presentation_feedback interface.
             fn feedback(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 surface: waynest::ObjectId,
                 callback: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event tells the client in which clock domain the"]
-            #[doc = "compositor interprets the timestamps used by the presentation"]
-            #[doc = "extension. This clock is called the presentation clock."]
-            #[doc = ""]
-            #[doc = "The compositor sends this event when the client binds to the"]
-            #[doc = "presentation interface. The presentation clock does not change"]
-            #[doc = "during the lifetime of the client connection."]
-            #[doc = ""]
-            #[doc = "The clock identifier is platform dependent. On POSIX platforms, the"]
-            #[doc = "identifier value is one of the clockid_t values accepted by"]
-            #[doc = "clock_gettime(). clock_gettime() is defined by POSIX.1-2001."]
-            #[doc = ""]
-            #[doc = "Timestamps in this clock domain are expressed as tv_sec_hi,"]
-            #[doc = "tv_sec_lo, tv_nsec triples, each component being an unsigned"]
-            #[doc = "32-bit value. Whole seconds are in tv_sec which is a 64-bit"]
-            #[doc = "value combined from tv_sec_hi and tv_sec_lo, and the"]
-            #[doc = "additional fractional part in tv_nsec as nanoseconds. Hence,"]
-            #[doc = "for valid timestamps tv_nsec must be in [0, 999999999]."]
-            #[doc = ""]
-            #[doc = "Note that clock_id applies only to the presentation clock,"]
-            #[doc = "and implies nothing about e.g. the timestamps used in the"]
-            #[doc = "Wayland core protocol input events."]
-            #[doc = ""]
-            #[doc = "Compositors should prefer a clock which does not jump and is"]
-            #[doc = "not slewed e.g. by NTP. The absolute value of the clock is"]
-            #[doc = "irrelevant. Precision of one millisecond or better is"]
-            #[doc = "recommended. Clients must be able to query the current clock"]
-            #[doc = "value directly, not by asking the compositor."]
-            fn clock_id(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                clk_id: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wp_presentation#{}.clock_id({})", sender_id, clk_id);
-                    let payload = waynest::PayloadBuilder::new().put_uint(clk_id).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wp_presentation#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_presentation", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_presentation", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in";
+                                "wp_presentation.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let surface = message
@@ -1181,35 +2207,80 @@ pub mod presentation_time {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wp_presentation#{}.feedback({}, {})",
-                                sender_id,
-                                surface,
-                                callback
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_presentation", object_id = % sender_id, opcode = 1u16,
+                                message = "feedback", direction = "in", "args.surface" = %
+                                surface, "args.callback" = % callback,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_presentation", object_id : % = sender_id, opcode = 1u16,
+                                message = "feedback", direction = "in", "args.surface" : % =
+                                surface, "args.callback" : % = callback;
+                                "wp_presentation.feedback"
                             );
-                            self.feedback(connection, sender_id, surface, callback)
+                            self.feedback(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    surface,
+                                    callback,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "A presentation_feedback object returns an indication that a"]
-    #[doc = "wl_surface content update has become visible to the user."]
-    #[doc = "One object corresponds to one content update submission"]
-    #[doc = "(wl_surface.commit). There are two possible outcomes: the"]
-    #[doc = "content update is presented to the user, and a presentation"]
-    #[doc = "timestamp delivered; or, the user did not see the content"]
-    #[doc = "update because it was superseded or its surface destroyed,"]
-    #[doc = "and the content update is discarded."]
-    #[doc = ""]
-    #[doc = "Once a presentation_feedback object has delivered a 'presented'"]
-    #[doc = "or 'discarded' event it is automatically destroyed."]
+    ///A presentation_feedback object returns an indication that a
+    ///wl_surface content update has become visible to the user.
+    ///One object corresponds to one content update submission
+    ///(wl_surface.commit). There are two possible outcomes: the
+    ///content update is presented to the user, and a presentation
+    ///timestamp delivered; or, the user did not see the content
+    ///update because it was superseded or its surface destroyed,
+    ///and the content update is discarded.
+    ///
+    ///Once a presentation_feedback object has delivered a 'presented'
+    ///or 'discarded' event it is automatically destroyed.
     #[allow(clippy::too_many_arguments)]
     pub mod wp_presentation_feedback {
-        bitflags::bitflags! { # [doc = "These flags provide information about how the presentation of"] # [doc = "the related content update was done. The intent is to help"] # [doc = "clients assess the reliability of the feedback and the visual"] # [doc = "quality with respect to possible tearing and timings."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Kind : u32 { const Vsync = 1u32 ; const HwClock = 2u32 ; const HwCompletion = 4u32 ; const ZeroCopy = 8u32 ; } }
+        bitflags::bitflags! {
+            #[doc = "These flags provide information about how the presentation of"]
+            #[doc = "the related content update was done. The intent is to help"] #[doc =
+            "clients assess the reliability of the feedback and the visual"] #[doc =
+            "quality with respect to possible tearing and timings."] #[derive(Debug,
+            PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub struct Kind : u32 {
+            const Vsync = 1u32; const HwClock = 2u32; const HwCompletion = 4u32; const
+            ZeroCopy = 8u32; }
+        }
+        #[allow(deprecated)]
+        impl Kind {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("vsync", Self::Vsync),
+                ("hw_clock", Self::HwClock),
+                ("hw_completion", Self::HwCompletion),
+                ("zero_copy", Self::ZeroCopy),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Kind> for u32 {
             fn from(value: Kind) -> Self {
                 value.bits()
@@ -1223,97 +2294,131 @@ pub mod presentation_time {
         }
         impl std::fmt::Display for Kind {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Trait to implement the wp_presentation_feedback interface. See the module level documentation for more info"]
-        pub trait WpPresentationFeedback
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "wp_presentation_feedback";
-            const VERSION: u32 = 2u32;
-            #[doc = "As presentation can be synchronized to only one output at a"]
-            #[doc = "time, this event tells which output it was. This event is only"]
-            #[doc = "sent prior to the presented event."]
-            #[doc = ""]
-            #[doc = "As clients may bind to the same global wl_output multiple"]
-            #[doc = "times, this event is sent for each bound instance that matches"]
-            #[doc = "the synchronized output. If a client has not bound to the"]
-            #[doc = "right wl_output global at all, this event is not sent."]
-            fn sync_output(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[];
+        ///Sends the events of the wp_presentation_feedback interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the sync_output message was introduced in
+            pub const SYNC_OUTPUT_SINCE: u32 = 1u32;
+            ///The interface version the sync_output message was deprecated in, if any
+            pub const SYNC_OUTPUT_DEPRECATED_SINCE: Option<u32> = None;
+            ///As presentation can be synchronized to only one output at a
+            ///time, this event tells which output it was. This event is only
+            ///sent prior to the presented event.
+            ///
+            ///As clients may bind to the same global wl_output multiple
+            ///times, this event is sent for each bound instance that matches
+            ///the synchronized output. If a client has not bound to the
+            ///right wl_output global at all, this event is not sent.
+            pub fn sync_output<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wp_presentation_feedback#{}.sync_output({})",
-                        sender_id,
-                        output
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(output))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wp_presentation_feedback", object_id = % sender_id, opcode = 0u16,
+                    message = "sync_output", direction = "out", "args.output" = % output,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "wp_presentation_feedback", object_id : % = sender_id, opcode = 0u16,
+                    message = "sync_output", direction = "out", "args.output" : % =
+                    output; "wp_presentation_feedback.sync_output"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(output))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The associated content update was displayed to the user at the"]
-            #[doc = "indicated time (tv_sec_hi/lo, tv_nsec). For the interpretation of"]
-            #[doc = "the timestamp, see presentation.clock_id event."]
-            #[doc = ""]
-            #[doc = "The timestamp corresponds to the time when the content update"]
-            #[doc = "turned into light the first time on the surface's main output."]
-            #[doc = "Compositors may approximate this from the framebuffer flip"]
-            #[doc = "completion events from the system, and the latency of the"]
-            #[doc = "physical display path if known."]
-            #[doc = ""]
-            #[doc = "This event is preceded by all related sync_output events"]
-            #[doc = "telling which output's refresh cycle the feedback corresponds"]
-            #[doc = "to, i.e. the main output for the surface. Compositors are"]
-            #[doc = "recommended to choose the output containing the largest part"]
-            #[doc = "of the wl_surface, or keeping the output they previously"]
-            #[doc = "chose. Having a stable presentation output association helps"]
-            #[doc = "clients predict future output refreshes (vblank)."]
-            #[doc = ""]
-            #[doc = "The 'refresh' argument gives the compositor's prediction of how"]
-            #[doc = "many nanoseconds after tv_sec, tv_nsec the very next output"]
-            #[doc = "refresh may occur. This is to further aid clients in"]
-            #[doc = "predicting future refreshes, i.e., estimating the timestamps"]
-            #[doc = "targeting the next few vblanks. If such prediction cannot"]
-            #[doc = "usefully be done, the argument is zero."]
-            #[doc = ""]
-            #[doc = "For version 2 and later, if the output does not have a constant"]
-            #[doc = "refresh rate, explicit video mode switches excluded, then the"]
-            #[doc = "refresh argument must be either an appropriate rate picked by the"]
-            #[doc = "compositor (e.g. fastest rate), or 0 if no such rate exists."]
-            #[doc = "For version 1, if the output does not have a constant refresh rate,"]
-            #[doc = "the refresh argument must be zero."]
-            #[doc = ""]
-            #[doc = "The 64-bit value combined from seq_hi and seq_lo is the value"]
-            #[doc = "of the output's vertical retrace counter when the content"]
-            #[doc = "update was first scanned out to the display. This value must"]
-            #[doc = "be compatible with the definition of MSC in"]
-            #[doc = "GLX_OML_sync_control specification. Note, that if the display"]
-            #[doc = "path has a non-zero latency, the time instant specified by"]
-            #[doc = "this counter may differ from the timestamp's."]
-            #[doc = ""]
-            #[doc = "If the output does not have a concept of vertical retrace or a"]
-            #[doc = "refresh cycle, or the output device is self-refreshing without"]
-            #[doc = "a way to query the refresh count, then the arguments seq_hi"]
-            #[doc = "and seq_lo must be zero."]
-            fn presented(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the presented message was introduced in
+            pub const PRESENTED_SINCE: u32 = 1u32;
+            ///The interface version the presented message was deprecated in, if any
+            pub const PRESENTED_DEPRECATED_SINCE: Option<u32> = None;
+            ///The associated content update was displayed to the user at the
+            ///indicated time (tv_sec_hi/lo, tv_nsec). For the interpretation of
+            ///the timestamp, see presentation.clock_id event.
+            ///
+            ///The timestamp corresponds to the time when the content update
+            ///turned into light the first time on the surface's main output.
+            ///Compositors may approximate this from the framebuffer flip
+            ///completion events from the system, and the latency of the
+            ///physical display path if known.
+            ///
+            ///This event is preceded by all related sync_output events
+            ///telling which output's refresh cycle the feedback corresponds
+            ///to, i.e. the main output for the surface. Compositors are
+            ///recommended to choose the output containing the largest part
+            ///of the wl_surface, or keeping the output they previously
+            ///chose. Having a stable presentation output association helps
+            ///clients predict future output refreshes (vblank).
+            ///
+            ///The 'refresh' argument gives the compositor's prediction of how
+            ///many nanoseconds after tv_sec, tv_nsec the very next output
+            ///refresh may occur. This is to further aid clients in
+            ///predicting future refreshes, i.e., estimating the timestamps
+            ///targeting the next few vblanks. If such prediction cannot
+            ///usefully be done, the argument is zero.
+            ///
+            ///For version 2 and later, if the output does not have a constant
+            ///refresh rate, explicit video mode switches excluded, then the
+            ///refresh argument must be either an appropriate rate picked by the
+            ///compositor (e.g. fastest rate), or 0 if no such rate exists.
+            ///For version 1, if the output does not have a constant refresh rate,
+            ///the refresh argument must be zero.
+            ///
+            ///The 64-bit value combined from seq_hi and seq_lo is the value
+            ///of the output's vertical retrace counter when the content
+            ///update was first scanned out to the display. This value must
+            ///be compatible with the definition of MSC in
+            ///GLX_OML_sync_control specification. Note, that if the display
+            ///path has a non-zero latency, the time instant specified by
+            ///this counter may differ from the timestamp's.
+            ///
+            ///If the output does not have a concept of vertical retrace or a
+            ///refresh cycle, or the output device is self-refreshing without
+            ///a way to query the refresh count, then the arguments seq_hi
+            ///and seq_lo must be zero.
+            pub fn presented<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 tv_sec_hi: u32,
                 tv_sec_lo: u32,
@@ -1322,188 +2427,289 @@ pub mod presentation_time {
                 seq_hi: u32,
                 seq_lo: u32,
                 flags: Kind,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> wp_presentation_feedback#{}.presented({}, {}, {}, {}, {}, {}, {})",
-                        sender_id,
-                        tv_sec_hi,
-                        tv_sec_lo,
-                        tv_nsec,
-                        refresh,
-                        seq_hi,
-                        seq_lo,
-                        flags
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(tv_sec_hi)
-                        .put_uint(tv_sec_lo)
-                        .put_uint(tv_nsec)
-                        .put_uint(refresh)
-                        .put_uint(seq_hi)
-                        .put_uint(seq_lo)
-                        .put_uint(flags.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wp_presentation_feedback", object_id = % sender_id, opcode = 1u16,
+                    message = "presented", direction = "out", "args.tv_sec_hi" = %
+                    tv_sec_hi, "args.tv_sec_lo" = % tv_sec_lo, "args.tv_nsec" = %
+                    tv_nsec, "args.refresh" = % refresh, "args.seq_hi" = % seq_hi,
+                    "args.seq_lo" = % seq_lo, "args.flags" = % flags,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "wp_presentation_feedback", object_id : % = sender_id, opcode = 1u16,
+                    message = "presented", direction = "out", "args.tv_sec_hi" : % =
+                    tv_sec_hi, "args.tv_sec_lo" : % = tv_sec_lo, "args.tv_nsec" : % =
+                    tv_nsec, "args.refresh" : % = refresh, "args.seq_hi" : % = seq_hi,
+                    "args.seq_lo" : % = seq_lo, "args.flags" : % = flags;
+                    "wp_presentation_feedback.presented"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(tv_sec_hi)
+                    .put_uint(tv_sec_lo)
+                    .put_uint(tv_nsec)
+                    .put_uint(refresh)
+                    .put_uint(seq_hi)
+                    .put_uint(seq_lo)
+                    .put_uint(flags.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The content update was never displayed to the user."]
-            fn discarded(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> wp_presentation_feedback#{}.discarded()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the discarded message was introduced in
+            pub const DISCARDED_SINCE: u32 = 1u32;
+            ///The interface version the discarded message was deprecated in, if any
+            pub const DISCARDED_DEPRECATED_SINCE: Option<u32> = None;
+            ///The content update was never displayed to the user.
+            pub fn discarded<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "wp_presentation_feedback", object_id = % sender_id, opcode = 2u16,
+                    message = "discarded", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "wp_presentation_feedback", object_id : % = sender_id, opcode = 2u16,
+                    message = "discarded", direction = "out";
+                    "wp_presentation_feedback.discarded"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the wp_presentation_feedback interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wp_presentation_feedback` interface",
+            label = "`WpPresentationFeedback` is not implemented for `{Self}`"
+        )]
+        pub trait WpPresentationFeedback
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "wp_presentation_feedback";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 _connection: &mut Self::Connection,
+                _state: &mut Self::State,
                 _sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
 }
-#[doc = "This description provides a high-level overview of the interplay between"]
-#[doc = "the interfaces defined this protocol. For details, see the protocol"]
-#[doc = "specification."]
-#[doc = ""]
-#[doc = "More than one tablet may exist, and device-specifics matter. Tablets are"]
-#[doc = "not represented by a single virtual device like wl_pointer. A client"]
-#[doc = "binds to the tablet manager object which is just a proxy object. From"]
-#[doc = "that, the client requests zwp_tablet_manager_v2.get_tablet_seat(wl_seat)"]
-#[doc = "and that returns the actual interface that has all the tablets. With"]
-#[doc = "this indirection, we can avoid merging zwp_tablet_v2 into the actual Wayland"]
-#[doc = "protocol, a long-term benefit."]
-#[doc = ""]
-#[doc = "The zwp_tablet_seat_v2 sends a \"tablet added\" event for each tablet"]
-#[doc = "connected. That event is followed by descriptive events about the"]
-#[doc = "hardware; currently that includes events for name, vid/pid and"]
-#[doc = "a zwp_tablet_v2.path event that describes a local path. This path can be"]
-#[doc = "used to uniquely identify a tablet or get more information through"]
-#[doc = "libwacom. Emulated or nested tablets can skip any of those, e.g. a"]
-#[doc = "virtual tablet may not have a vid/pid. The sequence of descriptive"]
-#[doc = "events is terminated by a zwp_tablet_v2.done event to signal that a client"]
-#[doc = "may now finalize any initialization for that tablet."]
-#[doc = ""]
-#[doc = "Events from tablets require a tool in proximity. Tools are also managed"]
-#[doc = "by the tablet seat; a \"tool added\" event is sent whenever a tool is new"]
-#[doc = "to the compositor. That event is followed by a number of descriptive"]
-#[doc = "events about the hardware; currently that includes capabilities,"]
-#[doc = "hardware id and serial number, and tool type. Similar to the tablet"]
-#[doc = "interface, a zwp_tablet_tool_v2.done event is sent to terminate that initial"]
-#[doc = "sequence."]
-#[doc = ""]
-#[doc = "Any event from a tool happens on the zwp_tablet_tool_v2 interface. When the"]
-#[doc = "tool gets into proximity of the tablet, a proximity_in event is sent on"]
-#[doc = "the zwp_tablet_tool_v2 interface, listing the tablet and the surface. That"]
-#[doc = "event is followed by a motion event with the coordinates. After that,"]
-#[doc = "it's the usual motion, axis, button, etc. events. The protocol's"]
-#[doc = "serialisation means events are grouped by zwp_tablet_tool_v2.frame events."]
-#[doc = ""]
-#[doc = "Two special events (that don't exist in X) are down and up. They signal"]
-#[doc = "\"tip touching the surface\". For tablets without real proximity"]
-#[doc = "detection, the sequence is: proximity_in, motion, down, frame."]
-#[doc = ""]
-#[doc = "When the tool leaves proximity, a proximity_out event is sent. If any"]
-#[doc = "button is still down, a button release event is sent before this"]
-#[doc = "proximity event. These button events are sent in the same frame as the"]
-#[doc = "proximity event to signal to the client that the buttons were held when"]
-#[doc = "the tool left proximity."]
-#[doc = ""]
-#[doc = "If the tool moves out of the surface but stays in proximity (i.e."]
-#[doc = "between windows), compositor-specific grab policies apply. This usually"]
-#[doc = "means that the proximity-out is delayed until all buttons are released."]
-#[doc = ""]
-#[doc = "Moving a tool physically from one tablet to the other has no real effect"]
-#[doc = "on the protocol, since we already have the tool object from the \"tool"]
-#[doc = "added\" event. All the information is already there and the proximity"]
-#[doc = "events on both tablets are all a client needs to reconstruct what"]
-#[doc = "happened."]
-#[doc = ""]
-#[doc = "Some extra axes are normalized, i.e. the client knows the range as"]
-#[doc = "specified in the protocol (e.g. [0, 65535]), the granularity however is"]
-#[doc = "unknown. The current normalized axes are pressure, distance, and slider."]
-#[doc = ""]
-#[doc = "Other extra axes are in physical units as specified in the protocol."]
-#[doc = "The current extra axes with physical units are tilt, rotation and"]
-#[doc = "wheel rotation."]
-#[doc = ""]
-#[doc = "Since tablets work independently of the pointer controlled by the mouse,"]
-#[doc = "the focus handling is independent too and controlled by proximity."]
-#[doc = "The zwp_tablet_tool_v2.set_cursor request sets a tool-specific cursor."]
-#[doc = "This cursor surface may be the same as the mouse cursor, and it may be"]
-#[doc = "the same across tools but it is possible to be more fine-grained. For"]
-#[doc = "example, a client may set different cursors for the pen and eraser."]
-#[doc = ""]
-#[doc = "Tools are generally independent of tablets and it is"]
-#[doc = "compositor-specific policy when a tool can be removed. Common approaches"]
-#[doc = "will likely include some form of removing a tool when all tablets the"]
-#[doc = "tool was used on are removed."]
-#[allow(clippy::module_inception)]
+///This description provides a high-level overview of the interplay between
+///the interfaces defined this protocol. For details, see the protocol
+///specification.
+///
+///More than one tablet may exist, and device-specifics matter. Tablets are
+///not represented by a single virtual device like wl_pointer. A client
+///binds to the tablet manager object which is just a proxy object. From
+///that, the client requests zwp_tablet_manager_v2.get_tablet_seat(wl_seat)
+///and that returns the actual interface that has all the tablets. With
+///this indirection, we can avoid merging zwp_tablet_v2 into the actual Wayland
+///protocol, a long-term benefit.
+///
+///The zwp_tablet_seat_v2 sends a "tablet added" event for each tablet
+///connected. That event is followed by descriptive events about the
+///hardware; currently that includes events for name, vid/pid and
+///a zwp_tablet_v2.path event that describes a local path. This path can be
+///used to uniquely identify a tablet or get more information through
+///libwacom. Emulated or nested tablets can skip any of those, e.g. a
+///virtual tablet may not have a vid/pid. The sequence of descriptive
+///events is terminated by a zwp_tablet_v2.done event to signal that a client
+///may now finalize any initialization for that tablet.
+///
+///Events from tablets require a tool in proximity. Tools are also managed
+///by the tablet seat; a "tool added" event is sent whenever a tool is new
+///to the compositor. That event is followed by a number of descriptive
+///events about the hardware; currently that includes capabilities,
+///hardware id and serial number, and tool type. Similar to the tablet
+///interface, a zwp_tablet_tool_v2.done event is sent to terminate that initial
+///sequence.
+///
+///Any event from a tool happens on the zwp_tablet_tool_v2 interface. When the
+///tool gets into proximity of the tablet, a proximity_in event is sent on
+///the zwp_tablet_tool_v2 interface, listing the tablet and the surface. That
+///event is followed by a motion event with the coordinates. After that,
+///it's the usual motion, axis, button, etc. events. The protocol's
+///serialisation means events are grouped by zwp_tablet_tool_v2.frame events.
+///
+///Two special events (that don't exist in X) are down and up. They signal
+///"tip touching the surface". For tablets without real proximity
+///detection, the sequence is: proximity_in, motion, down, frame.
+///
+///When the tool leaves proximity, a proximity_out event is sent. If any
+///button is still down, a button release event is sent before this
+///proximity event. These button events are sent in the same frame as the
+///proximity event to signal to the client that the buttons were held when
+///the tool left proximity.
+///
+///If the tool moves out of the surface but stays in proximity (i.e.
+///between windows), compositor-specific grab policies apply. This usually
+///means that the proximity-out is delayed until all buttons are released.
+///
+///Moving a tool physically from one tablet to the other has no real effect
+///on the protocol, since we already have the tool object from the "tool
+///added" event. All the information is already there and the proximity
+///events on both tablets are all a client needs to reconstruct what
+///happened.
+///
+///Some extra axes are normalized, i.e. the client knows the range as
+///specified in the protocol (e.g. [0, 65535]), the granularity however is
+///unknown. The current normalized axes are pressure, distance, and slider.
+///
+///Other extra axes are in physical units as specified in the protocol.
+///The current extra axes with physical units are tilt, rotation and
+///wheel rotation.
+///
+///Since tablets work independently of the pointer controlled by the mouse,
+///the focus handling is independent too and controlled by proximity.
+///The zwp_tablet_tool_v2.set_cursor request sets a tool-specific cursor.
+///This cursor surface may be the same as the mouse cursor, and it may be
+///the same across tools but it is possible to be more fine-grained. For
+///example, a client may set different cursors for the pen and eraser.
+///
+///Tools are generally independent of tablets and it is
+///compositor-specific policy when a tool can be removed. Common approaches
+///will likely include some form of removing a tool when all tablets the
+///tool was used on are removed.
+#[cfg(feature = "tablet-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tablet-v2")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod tablet_v2 {
-    #[doc = "An object that provides access to the graphics tablets available on this"]
-    #[doc = "system. All tablets are associated with a seat, to get access to the"]
-    #[doc = "actual tablets, use zwp_tablet_manager_v2.get_tablet_seat."]
+    ///An object that provides access to the graphics tablets available on this
+    ///system. All tablets are associated with a seat, to get access to the
+    ///actual tablets, use zwp_tablet_manager_v2.get_tablet_seat.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_manager_v2 {
-        #[doc = "Trait to implement the zwp_tablet_manager_v2 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_tablet_seat",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "tablet_seat",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwp_tablet_seat_v2"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_manager_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwp_tablet_manager_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_manager_v2` interface",
+            label = "`ZwpTabletManagerV2` is not implemented for `{Self}`"
+        )]
         pub trait ZwpTabletManagerV2
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwp_tablet_manager_v2";
             const VERSION: u32 = 2u32;
-            #[doc = "Get the zwp_tablet_seat_v2 object for the given seat. This object"]
-            #[doc = "provides access to all graphics tablets in this seat."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_tablet_seat message was introduced in
+            const GET_TABLET_SEAT_SINCE: u32 = 1u32;
+            ///The interface version the get_tablet_seat message was deprecated in, if any
+            const GET_TABLET_SEAT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Get the zwp_tablet_seat_v2 object for the given seat. This object
+            ///provides access to all graphics tablets in this seat.
             fn get_tablet_seat(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 tablet_seat: waynest::ObjectId,
                 seat: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroy the zwp_tablet_manager_v2 object. Objects created from this"]
-            #[doc = "object are unaffected and should be destroyed separately."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the zwp_tablet_manager_v2 object. Objects created from this
+            ///object are unaffected and should be destroyed separately.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -1515,199 +2721,379 @@ pub mod tablet_v2 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_tablet_manager_v2#{}.get_tablet_seat({}, {})",
-                                sender_id,
-                                tablet_seat,
-                                seat
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_manager_v2", object_id = % sender_id, opcode =
+                                0u16, message = "get_tablet_seat", direction = "in",
+                                "args.tablet_seat" = % tablet_seat, "args.seat" = % seat,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_manager_v2", object_id : % = sender_id, opcode =
+                                0u16, message = "get_tablet_seat", direction = "in",
+                                "args.tablet_seat" : % = tablet_seat, "args.seat" : % =
+                                seat; "zwp_tablet_manager_v2.get_tablet_seat"
                             );
-                            self.get_tablet_seat(connection, sender_id, tablet_seat, seat)
+                            self.get_tablet_seat(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    tablet_seat,
+                                    seat,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_manager_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_manager_v2", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_manager_v2", object_id : % = sender_id, opcode =
+                                1u16, message = "destroy", direction = "in";
+                                "zwp_tablet_manager_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "An object that provides access to the graphics tablets available on this"]
-    #[doc = "seat. After binding to this interface, the compositor sends a set of"]
-    #[doc = "zwp_tablet_seat_v2.tablet_added and zwp_tablet_seat_v2.tool_added events."]
+    ///An object that provides access to the graphics tablets available on this
+    ///seat. After binding to this interface, the compositor sends a set of
+    ///zwp_tablet_seat_v2.tablet_added and zwp_tablet_seat_v2.tool_added events.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_seat_v2 {
-        #[doc = "Trait to implement the zwp_tablet_seat_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletSeatV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_seat_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "Destroy the zwp_tablet_seat_v2 object. Objects created from this"]
-            #[doc = "object are unaffected and should be destroyed separately."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event is sent whenever a new tablet becomes available on this"]
-            #[doc = "seat. This event only provides the object id of the tablet, any"]
-            #[doc = "static information about the tablet (device name, vid/pid, etc.) is"]
-            #[doc = "sent through the zwp_tablet_v2 interface."]
-            fn tablet_added(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_seat_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the tablet_added message was introduced in
+            pub const TABLET_ADDED_SINCE: u32 = 1u32;
+            ///The interface version the tablet_added message was deprecated in, if any
+            pub const TABLET_ADDED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent whenever a new tablet becomes available on this
+            ///seat. This event only provides the object id of the tablet, any
+            ///static information about the tablet (device name, vid/pid, etc.) is
+            ///sent through the zwp_tablet_v2 interface.
+            pub fn tablet_added<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_seat_v2#{}.tablet_added({})", sender_id, id);
-                    let payload = waynest::PayloadBuilder::new().put_object(Some(id)).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_seat_v2", object_id = % sender_id, opcode = 0u16, message
+                    = "tablet_added", direction = "out", "args.id" = % id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_seat_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "tablet_added", direction = "out", "args.id" : % = id;
+                    "zwp_tablet_seat_v2.tablet_added"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(id))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent whenever a tool that has not previously been used"]
-            #[doc = "with a tablet comes into use. This event only provides the object id"]
-            #[doc = "of the tool; any static information about the tool (capabilities,"]
-            #[doc = "type, etc.) is sent through the zwp_tablet_tool_v2 interface."]
-            fn tool_added(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the tool_added message was introduced in
+            pub const TOOL_ADDED_SINCE: u32 = 1u32;
+            ///The interface version the tool_added message was deprecated in, if any
+            pub const TOOL_ADDED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent whenever a tool that has not previously been used
+            ///with a tablet comes into use. This event only provides the object id
+            ///of the tool; any static information about the tool (capabilities,
+            ///type, etc.) is sent through the zwp_tablet_tool_v2 interface.
+            pub fn tool_added<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_seat_v2#{}.tool_added({})", sender_id, id);
-                    let payload = waynest::PayloadBuilder::new().put_object(Some(id)).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_seat_v2", object_id = % sender_id, opcode = 1u16, message
+                    = "tool_added", direction = "out", "args.id" = % id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_seat_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "tool_added", direction = "out", "args.id" : % = id;
+                    "zwp_tablet_seat_v2.tool_added"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(id))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent whenever a new pad is known to the system. Typically,"]
-            #[doc = "pads are physically attached to tablets and a pad_added event is"]
-            #[doc = "sent immediately after the zwp_tablet_seat_v2.tablet_added."]
-            #[doc = "However, some standalone pad devices logically attach to tablets at"]
-            #[doc = "runtime, and the client must wait for zwp_tablet_pad_v2.enter to know"]
-            #[doc = "the tablet a pad is attached to."]
-            #[doc = ""]
-            #[doc = "This event only provides the object id of the pad. All further"]
-            #[doc = "features (buttons, strips, rings) are sent through the zwp_tablet_pad_v2"]
-            #[doc = "interface."]
-            fn pad_added(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the pad_added message was introduced in
+            pub const PAD_ADDED_SINCE: u32 = 1u32;
+            ///The interface version the pad_added message was deprecated in, if any
+            pub const PAD_ADDED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent whenever a new pad is known to the system. Typically,
+            ///pads are physically attached to tablets and a pad_added event is
+            ///sent immediately after the zwp_tablet_seat_v2.tablet_added.
+            ///However, some standalone pad devices logically attach to tablets at
+            ///runtime, and the client must wait for zwp_tablet_pad_v2.enter to know
+            ///the tablet a pad is attached to.
+            ///
+            ///This event only provides the object id of the pad. All further
+            ///features (buttons, strips, rings) are sent through the zwp_tablet_pad_v2
+            ///interface.
+            pub fn pad_added<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_seat_v2#{}.pad_added({})", sender_id, id);
-                    let payload = waynest::PayloadBuilder::new().put_object(Some(id)).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_seat_v2", object_id = % sender_id, opcode = 2u16, message
+                    = "pad_added", direction = "out", "args.id" = % id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_seat_v2", object_id : % = sender_id, opcode = 2u16,
+                    message = "pad_added", direction = "out", "args.id" : % = id;
+                    "zwp_tablet_seat_v2.pad_added"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(id))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_seat_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_seat_v2` interface",
+            label = "`ZwpTabletSeatV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletSeatV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_seat_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the zwp_tablet_seat_v2 object. Objects created from this
+            ///object are unaffected and should be destroyed separately.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_seat_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_seat_v2", object_id = % sender_id, opcode =
+                                0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_seat_v2", object_id : % = sender_id, opcode =
+                                0u16, message = "destroy", direction = "in";
+                                "zwp_tablet_seat_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "An object that represents a physical tool that has been, or is"]
-    #[doc = "currently in use with a tablet in this seat. Each zwp_tablet_tool_v2"]
-    #[doc = "object stays valid until the client destroys it; the compositor"]
-    #[doc = "reuses the zwp_tablet_tool_v2 object to indicate that the object's"]
-    #[doc = "respective physical tool has come into proximity of a tablet again."]
-    #[doc = ""]
-    #[doc = "A zwp_tablet_tool_v2 object's relation to a physical tool depends on the"]
-    #[doc = "tablet's ability to report serial numbers. If the tablet supports"]
-    #[doc = "this capability, then the object represents a specific physical tool"]
-    #[doc = "and can be identified even when used on multiple tablets."]
-    #[doc = ""]
-    #[doc = "A tablet tool has a number of static characteristics, e.g. tool type,"]
-    #[doc = "hardware_serial and capabilities. These capabilities are sent in an"]
-    #[doc = "event sequence after the zwp_tablet_seat_v2.tool_added event before any"]
-    #[doc = "actual events from this tool. This initial event sequence is"]
-    #[doc = "terminated by a zwp_tablet_tool_v2.done event."]
-    #[doc = ""]
-    #[doc = "Tablet tool events are grouped by zwp_tablet_tool_v2.frame events."]
-    #[doc = "Any events received before a zwp_tablet_tool_v2.frame event should be"]
-    #[doc = "considered part of the same hardware state change."]
+    ///An object that represents a physical tool that has been, or is
+    ///currently in use with a tablet in this seat. Each zwp_tablet_tool_v2
+    ///object stays valid until the client destroys it; the compositor
+    ///reuses the zwp_tablet_tool_v2 object to indicate that the object's
+    ///respective physical tool has come into proximity of a tablet again.
+    ///
+    ///A zwp_tablet_tool_v2 object's relation to a physical tool depends on the
+    ///tablet's ability to report serial numbers. If the tablet supports
+    ///this capability, then the object represents a specific physical tool
+    ///and can be identified even when used on multiple tablets.
+    ///
+    ///A tablet tool has a number of static characteristics, e.g. tool type,
+    ///hardware_serial and capabilities. These capabilities are sent in an
+    ///event sequence after the zwp_tablet_seat_v2.tool_added event before any
+    ///actual events from this tool. This initial event sequence is
+    ///terminated by a zwp_tablet_tool_v2.done event.
+    ///
+    ///Tablet tool events are grouped by zwp_tablet_tool_v2.frame events.
+    ///Any events received before a zwp_tablet_tool_v2.frame event should be
+    ///considered part of the same hardware state change.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_tool_v2 {
-        #[doc = "Describes the physical type of a tool. The physical type of a tool"]
-        #[doc = "generally defines its base usage."]
-        #[doc = ""]
-        #[doc = "The mouse tool represents a mouse-shaped tool that is not a relative"]
-        #[doc = "device but bound to the tablet's surface, providing absolute"]
-        #[doc = "coordinates."]
-        #[doc = ""]
-        #[doc = "The lens tool is a mouse-shaped tool with an attached lens to"]
-        #[doc = "provide precision focus."]
+        ///Describes the physical type of a tool. The physical type of a tool
+        ///generally defines its base usage.
+        ///
+        ///The mouse tool represents a mouse-shaped tool that is not a relative
+        ///device but bound to the tablet's surface, providing absolute
+        ///coordinates.
+        ///
+        ///The lens tool is a mouse-shaped tool with an attached lens to
+        ///provide precision focus.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Type {
-            #[doc = "Pen"]
+            ///Pen
             Pen = 320u32,
-            #[doc = "Eraser"]
+            ///Eraser
             Eraser = 321u32,
-            #[doc = "Brush"]
+            ///Brush
             Brush = 322u32,
-            #[doc = "Pencil"]
+            ///Pencil
             Pencil = 323u32,
-            #[doc = "Airbrush"]
+            ///Airbrush
             Airbrush = 324u32,
-            #[doc = "Finger"]
+            ///Finger
             Finger = 325u32,
-            #[doc = "Mouse"]
+            ///Mouse
             Mouse = 326u32,
-            #[doc = "Lens"]
+            ///Lens
             Lens = 327u32,
         }
+        #[allow(deprecated)]
+        impl Type {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Pen => 1u32,
+                    Self::Eraser => 1u32,
+                    Self::Brush => 1u32,
+                    Self::Pencil => 1u32,
+                    Self::Airbrush => 1u32,
+                    Self::Finger => 1u32,
+                    Self::Mouse => 1u32,
+                    Self::Lens => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Pen => None,
+                    Self::Eraser => None,
+                    Self::Brush => None,
+                    Self::Pencil => None,
+                    Self::Airbrush => None,
+                    Self::Finger => None,
+                    Self::Mouse => None,
+                    Self::Lens => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("pen", Self::Pen),
+                ("eraser", Self::Eraser),
+                ("brush", Self::Brush),
+                ("pencil", Self::Pencil),
+                ("airbrush", Self::Airbrush),
+                ("finger", Self::Finger),
+                ("mouse", Self::Mouse),
+                ("lens", Self::Lens),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Pen => "pen",
+                    Self::Eraser => "eraser",
+                    Self::Brush => "brush",
+                    Self::Pencil => "pencil",
+                    Self::Airbrush => "airbrush",
+                    Self::Finger => "finger",
+                    Self::Mouse => "mouse",
+                    Self::Lens => "lens",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Type> for u32 {
             fn from(value: Type) -> Self {
                 value as u32
             }
         }
+        impl From<Type> for i32 {
+            fn from(value: Type) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Type {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1724,37 +3110,105 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for Type {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Type {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Describes extra capabilities on a tablet."]
-        #[doc = ""]
-        #[doc = "Any tool must provide x and y values, extra axes are"]
-        #[doc = "device-specific."]
+        ///Describes extra capabilities on a tablet.
+        ///
+        ///Any tool must provide x and y values, extra axes are
+        ///device-specific.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Capability {
-            #[doc = "Tilt axes"]
+            ///Tilt axes
             Tilt = 1u32,
-            #[doc = "Pressure axis"]
+            ///Pressure axis
             Pressure = 2u32,
-            #[doc = "Distance axis"]
+            ///Distance axis
             Distance = 3u32,
-            #[doc = "Z-rotation axis"]
+            ///Z-rotation axis
             Rotation = 4u32,
-            #[doc = "Slider axis"]
+            ///Slider axis
             Slider = 5u32,
-            #[doc = "Wheel axis"]
+            ///Wheel axis
             Wheel = 6u32,
         }
+        #[allow(deprecated)]
+        impl Capability {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Tilt => 1u32,
+                    Self::Pressure => 1u32,
+                    Self::Distance => 1u32,
+                    Self::Rotation => 1u32,
+                    Self::Slider => 1u32,
+                    Self::Wheel => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Tilt => None,
+                    Self::Pressure => None,
+                    Self::Distance => None,
+                    Self::Rotation => None,
+                    Self::Slider => None,
+                    Self::Wheel => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("tilt", Self::Tilt),
+                ("pressure", Self::Pressure),
+                ("distance", Self::Distance),
+                ("rotation", Self::Rotation),
+                ("slider", Self::Slider),
+                ("wheel", Self::Wheel),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Tilt => "tilt",
+                    Self::Pressure => "pressure",
+                    Self::Distance => "distance",
+                    Self::Rotation => "rotation",
+                    Self::Slider => "slider",
+                    Self::Wheel => "wheel",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Capability> for u32 {
             fn from(value: Capability) -> Self {
                 value as u32
             }
         }
+        impl From<Capability> for i32 {
+            fn from(value: Capability) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Capability {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1769,26 +3223,78 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for Capability {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Capability {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Describes the physical state of a button that produced the button event."]
+        ///Describes the physical state of a button that produced the button event.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum ButtonState {
-            #[doc = "button is not pressed"]
+            ///button is not pressed
             Released = 0u32,
-            #[doc = "button is pressed"]
+            ///button is pressed
             Pressed = 1u32,
         }
+        #[allow(deprecated)]
+        impl ButtonState {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Released => 1u32,
+                    Self::Pressed => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Released => None,
+                    Self::Pressed => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("released", Self::Released),
+                ("pressed", Self::Pressed),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Released => "released",
+                    Self::Pressed => "pressed",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<ButtonState> for u32 {
             fn from(value: ButtonState) -> Self {
                 value as u32
             }
         }
+        impl From<ButtonState> for i32 {
+            fn from(value: ButtonState) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for ButtonState {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1799,23 +3305,69 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for ButtonState {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for ButtonState {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "given wl_surface has another role"]
+            ///given wl_surface has another role
             Role = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Role => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Role => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[("role", Self::Role)];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Role => "role",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1825,648 +3377,897 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwp_tablet_tool_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletToolV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_tool_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "Sets the surface of the cursor used for this tool on the given"]
-            #[doc = "tablet. This request only takes effect if the tool is in proximity"]
-            #[doc = "of one of the requesting client's surfaces or the surface parameter"]
-            #[doc = "is the current pointer surface. If there was a previous surface set"]
-            #[doc = "with this request it is replaced. If surface is NULL, the cursor"]
-            #[doc = "image is hidden."]
-            #[doc = ""]
-            #[doc = "The parameters hotspot_x and hotspot_y define the position of the"]
-            #[doc = "pointer surface relative to the pointer location. Its top-left corner"]
-            #[doc = "is always at (x, y) - (hotspot_x, hotspot_y), where (x, y) are the"]
-            #[doc = "coordinates of the pointer location, in surface-local coordinates."]
-            #[doc = ""]
-            #[doc = "On surface.attach requests to the pointer surface, hotspot_x and"]
-            #[doc = "hotspot_y are decremented by the x and y parameters passed to the"]
-            #[doc = "request. Attach must be confirmed by wl_surface.commit as usual."]
-            #[doc = ""]
-            #[doc = "The hotspot can also be updated by passing the currently set pointer"]
-            #[doc = "surface to this request with new values for hotspot_x and hotspot_y."]
-            #[doc = ""]
-            #[doc = "The current and pending input regions of the wl_surface are cleared,"]
-            #[doc = "and wl_surface.set_input_region is ignored until the wl_surface is no"]
-            #[doc = "longer used as the cursor. When the use as a cursor ends, the current"]
-            #[doc = "and pending input regions become undefined, and the wl_surface is"]
-            #[doc = "unmapped."]
-            #[doc = ""]
-            #[doc = "This request gives the surface the role of a zwp_tablet_tool_v2 cursor. A"]
-            #[doc = "surface may only ever be used as the cursor surface for one"]
-            #[doc = "zwp_tablet_tool_v2. If the surface already has another role or has"]
-            #[doc = "previously been used as cursor surface for a different tool, a"]
-            #[doc = "protocol error is raised."]
-            fn set_cursor(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-                surface: Option<waynest::ObjectId>,
-                hotspot_x: i32,
-                hotspot_y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This destroys the client's resource for this tool object."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The tool type is the high-level type of the tool and usually decides"]
-            #[doc = "the interaction expected from this tool."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_tool_v2.done event."]
-            fn r#type(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_cursor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u?oii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "hotspot_y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_tool_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the type message was introduced in
+            pub const TYPE_SINCE: u32 = 1u32;
+            ///The interface version the type message was deprecated in, if any
+            pub const TYPE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The tool type is the high-level type of the tool and usually decides
+            ///the interaction expected from this tool.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_tool_v2.done event.
+            pub fn r#type<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 tool_type: Type,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.type({})", sender_id, tool_type);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(tool_type.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 0u16, message
+                    = "type", direction = "out", "args.tool_type" = % tool_type,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "type", direction = "out", "args.tool_type" : % =
+                    tool_type; "zwp_tablet_tool_v2.type"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(tool_type.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "If the physical tool can be identified by a unique 64-bit serial"]
-            #[doc = "number, this event notifies the client of this serial number."]
-            #[doc = ""]
-            #[doc = "If multiple tablets are available in the same seat and the tool is"]
-            #[doc = "uniquely identifiable by the serial number, that tool may move"]
-            #[doc = "between tablets."]
-            #[doc = ""]
-            #[doc = "Otherwise, if the tool has no serial number and this event is"]
-            #[doc = "missing, the tool is tied to the tablet it first comes into"]
-            #[doc = "proximity with. Even if the physical tool is used on multiple"]
-            #[doc = "tablets, separate zwp_tablet_tool_v2 objects will be created, one per"]
-            #[doc = "tablet."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_tool_v2.done event."]
-            fn hardware_serial(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the hardware_serial message was introduced in
+            pub const HARDWARE_SERIAL_SINCE: u32 = 1u32;
+            ///The interface version the hardware_serial message was deprecated in, if any
+            pub const HARDWARE_SERIAL_DEPRECATED_SINCE: Option<u32> = None;
+            ///If the physical tool can be identified by a unique 64-bit serial
+            ///number, this event notifies the client of this serial number.
+            ///
+            ///If multiple tablets are available in the same seat and the tool is
+            ///uniquely identifiable by the serial number, that tool may move
+            ///between tablets.
+            ///
+            ///Otherwise, if the tool has no serial number and this event is
+            ///missing, the tool is tied to the tablet it first comes into
+            ///proximity with. Even if the physical tool is used on multiple
+            ///tablets, separate zwp_tablet_tool_v2 objects will be created, one per
+            ///tablet.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_tool_v2.done event.
+            pub fn hardware_serial<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 hardware_serial_hi: u32,
                 hardware_serial_lo: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.hardware_serial({}, {})",
-                        sender_id,
-                        hardware_serial_hi,
-                        hardware_serial_lo
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(hardware_serial_hi)
-                        .put_uint(hardware_serial_lo)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 1u16, message
+                    = "hardware_serial", direction = "out", "args.hardware_serial_hi" = %
+                    hardware_serial_hi, "args.hardware_serial_lo" = % hardware_serial_lo,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "hardware_serial", direction = "out",
+                    "args.hardware_serial_hi" : % = hardware_serial_hi,
+                    "args.hardware_serial_lo" : % = hardware_serial_lo;
+                    "zwp_tablet_tool_v2.hardware_serial"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(hardware_serial_hi)
+                    .put_uint(hardware_serial_lo)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event notifies the client of a hardware id available on this tool."]
-            #[doc = ""]
-            #[doc = "The hardware id is a device-specific 64-bit id that provides extra"]
-            #[doc = "information about the tool in use, beyond the wl_tool.type"]
-            #[doc = "enumeration. The format of the id is specific to tablets made by"]
-            #[doc = "Wacom Inc. For example, the hardware id of a Wacom Grip"]
-            #[doc = "Pen (a stylus) is 0x802."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_tool_v2.done event."]
-            fn hardware_id_wacom(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the hardware_id_wacom message was introduced in
+            pub const HARDWARE_ID_WACOM_SINCE: u32 = 1u32;
+            ///The interface version the hardware_id_wacom message was deprecated in, if any
+            pub const HARDWARE_ID_WACOM_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event notifies the client of a hardware id available on this tool.
+            ///
+            ///The hardware id is a device-specific 64-bit id that provides extra
+            ///information about the tool in use, beyond the wl_tool.type
+            ///enumeration. The format of the id is specific to tablets made by
+            ///Wacom Inc. For example, the hardware id of a Wacom Grip
+            ///Pen (a stylus) is 0x802.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_tool_v2.done event.
+            pub fn hardware_id_wacom<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 hardware_id_hi: u32,
                 hardware_id_lo: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.hardware_id_wacom({}, {})",
-                        sender_id,
-                        hardware_id_hi,
-                        hardware_id_lo
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(hardware_id_hi)
-                        .put_uint(hardware_id_lo)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 2u16, message
+                    = "hardware_id_wacom", direction = "out", "args.hardware_id_hi" = %
+                    hardware_id_hi, "args.hardware_id_lo" = % hardware_id_lo,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 2u16,
+                    message = "hardware_id_wacom", direction = "out",
+                    "args.hardware_id_hi" : % = hardware_id_hi, "args.hardware_id_lo" : %
+                    = hardware_id_lo; "zwp_tablet_tool_v2.hardware_id_wacom"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(hardware_id_hi)
+                    .put_uint(hardware_id_lo)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event notifies the client of any capabilities of this tool,"]
-            #[doc = "beyond the main set of x/y axes and tip up/down detection."]
-            #[doc = ""]
-            #[doc = "One event is sent for each extra capability available on this tool."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_tool_v2.done event."]
-            fn capability(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the capability message was introduced in
+            pub const CAPABILITY_SINCE: u32 = 1u32;
+            ///The interface version the capability message was deprecated in, if any
+            pub const CAPABILITY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event notifies the client of any capabilities of this tool,
+            ///beyond the main set of x/y axes and tip up/down detection.
+            ///
+            ///One event is sent for each extra capability available on this tool.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_tool_v2.done event.
+            pub fn capability<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 capability: Capability,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.capability({})",
-                        sender_id,
-                        capability
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(capability.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 3u16, message
+                    = "capability", direction = "out", "args.capability" = % capability,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 3u16,
+                    message = "capability", direction = "out", "args.capability" : % =
+                    capability; "zwp_tablet_tool_v2.capability"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(capability.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event signals the end of the initial burst of descriptive"]
-            #[doc = "events. A client may consider the static description of the tool to"]
-            #[doc = "be complete and finalize initialization of the tool."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event signals the end of the initial burst of descriptive
+            ///events. A client may consider the static description of the tool to
+            ///be complete and finalize initialization of the tool.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 4u16, message
+                    = "done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 4u16,
+                    message = "done", direction = "out"; "zwp_tablet_tool_v2.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent when the tool is removed from the system and will"]
-            #[doc = "send no further events. Should the physical tool come back into"]
-            #[doc = "proximity later, a new zwp_tablet_tool_v2 object will be created."]
-            #[doc = ""]
-            #[doc = "It is compositor-dependent when a tool is removed. A compositor may"]
-            #[doc = "remove a tool on proximity out, tablet removal or any other reason."]
-            #[doc = "A compositor may also keep a tool alive until shutdown."]
-            #[doc = ""]
-            #[doc = "If the tool is currently in proximity, a proximity_out event will be"]
-            #[doc = "sent before the removed event. See zwp_tablet_tool_v2.proximity_out for"]
-            #[doc = "the handling of any buttons logically down."]
-            #[doc = ""]
-            #[doc = "When this event is received, the client must zwp_tablet_tool_v2.destroy"]
-            #[doc = "the object."]
-            fn removed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.removed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the removed message was introduced in
+            pub const REMOVED_SINCE: u32 = 1u32;
+            ///The interface version the removed message was deprecated in, if any
+            pub const REMOVED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent when the tool is removed from the system and will
+            ///send no further events. Should the physical tool come back into
+            ///proximity later, a new zwp_tablet_tool_v2 object will be created.
+            ///
+            ///It is compositor-dependent when a tool is removed. A compositor may
+            ///remove a tool on proximity out, tablet removal or any other reason.
+            ///A compositor may also keep a tool alive until shutdown.
+            ///
+            ///If the tool is currently in proximity, a proximity_out event will be
+            ///sent before the removed event. See zwp_tablet_tool_v2.proximity_out for
+            ///the handling of any buttons logically down.
+            ///
+            ///When this event is received, the client must zwp_tablet_tool_v2.destroy
+            ///the object.
+            pub fn removed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 5u16, message
+                    = "removed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 5u16,
+                    message = "removed", direction = "out"; "zwp_tablet_tool_v2.removed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this tool is focused on a certain surface."]
-            #[doc = ""]
-            #[doc = "This event can be received when the tool has moved from one surface to"]
-            #[doc = "another, or when the tool has come back into proximity above the"]
-            #[doc = "surface."]
-            #[doc = ""]
-            #[doc = "If any button is logically down when the tool comes into proximity,"]
-            #[doc = "the respective button event is sent after the proximity_in event but"]
-            #[doc = "within the same frame as the proximity_in event."]
-            fn proximity_in(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the proximity_in message was introduced in
+            pub const PROXIMITY_IN_SINCE: u32 = 1u32;
+            ///The interface version the proximity_in message was deprecated in, if any
+            pub const PROXIMITY_IN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this tool is focused on a certain surface.
+            ///
+            ///This event can be received when the tool has moved from one surface to
+            ///another, or when the tool has come back into proximity above the
+            ///surface.
+            ///
+            ///If any button is logically down when the tool comes into proximity,
+            ///the respective button event is sent after the proximity_in event but
+            ///within the same frame as the proximity_in event.
+            pub fn proximity_in<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 tablet: waynest::ObjectId,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.proximity_in({}, {}, {})",
-                        sender_id,
-                        serial,
-                        tablet,
-                        surface
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(tablet))
-                        .put_object(Some(surface))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 6u16, message
+                    = "proximity_in", direction = "out", "args.serial" = % serial,
+                    "args.tablet" = % tablet, "args.surface" = % surface,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 6u16,
+                    message = "proximity_in", direction = "out", "args.serial" : % =
+                    serial, "args.tablet" : % = tablet, "args.surface" : % = surface;
+                    "zwp_tablet_tool_v2.proximity_in"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(tablet))
+                    .put_object(Some(surface))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this tool has either left proximity, or is no"]
-            #[doc = "longer focused on a certain surface."]
-            #[doc = ""]
-            #[doc = "When the tablet tool leaves proximity of the tablet, button release"]
-            #[doc = "events are sent for each button that was held down at the time of"]
-            #[doc = "leaving proximity. These events are sent before the proximity_out"]
-            #[doc = "event but within the same zwp_tablet_v2.frame."]
-            #[doc = ""]
-            #[doc = "If the tool stays within proximity of the tablet, but the focus"]
-            #[doc = "changes from one surface to another, a button release event may not"]
-            #[doc = "be sent until the button is actually released or the tool leaves the"]
-            #[doc = "proximity of the tablet."]
-            fn proximity_out(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.proximity_out()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the proximity_out message was introduced in
+            pub const PROXIMITY_OUT_SINCE: u32 = 1u32;
+            ///The interface version the proximity_out message was deprecated in, if any
+            pub const PROXIMITY_OUT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this tool has either left proximity, or is no
+            ///longer focused on a certain surface.
+            ///
+            ///When the tablet tool leaves proximity of the tablet, button release
+            ///events are sent for each button that was held down at the time of
+            ///leaving proximity. These events are sent before the proximity_out
+            ///event but within the same zwp_tablet_v2.frame.
+            ///
+            ///If the tool stays within proximity of the tablet, but the focus
+            ///changes from one surface to another, a button release event may not
+            ///be sent until the button is actually released or the tool leaves the
+            ///proximity of the tablet.
+            pub fn proximity_out<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 7u16, message
+                    = "proximity_out", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 7u16,
+                    message = "proximity_out", direction = "out";
+                    "zwp_tablet_tool_v2.proximity_out"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 7u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the tablet tool comes in contact with the surface of the"]
-            #[doc = "tablet."]
-            #[doc = ""]
-            #[doc = "If the tool is already in contact with the tablet when entering the"]
-            #[doc = "input region, the client owning said region will receive a"]
-            #[doc = "zwp_tablet_v2.proximity_in event, followed by a zwp_tablet_v2.down"]
-            #[doc = "event and a zwp_tablet_v2.frame event."]
-            #[doc = ""]
-            #[doc = "Note that this event describes logical contact, not physical"]
-            #[doc = "contact. On some devices, a compositor may not consider a tool in"]
-            #[doc = "logical contact until a minimum physical pressure threshold is"]
-            #[doc = "exceeded."]
-            fn down(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the down message was introduced in
+            pub const DOWN_SINCE: u32 = 1u32;
+            ///The interface version the down message was deprecated in, if any
+            pub const DOWN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the tablet tool comes in contact with the surface of the
+            ///tablet.
+            ///
+            ///If the tool is already in contact with the tablet when entering the
+            ///input region, the client owning said region will receive a
+            ///zwp_tablet_v2.proximity_in event, followed by a zwp_tablet_v2.down
+            ///event and a zwp_tablet_v2.frame event.
+            ///
+            ///Note that this event describes logical contact, not physical
+            ///contact. On some devices, a compositor may not consider a tool in
+            ///logical contact until a minimum physical pressure threshold is
+            ///exceeded.
+            pub fn down<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.down({})", sender_id, serial);
-                    let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 8u16, message
+                    = "down", direction = "out", "args.serial" = % serial,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 8u16,
+                    message = "down", direction = "out", "args.serial" : % = serial;
+                    "zwp_tablet_tool_v2.down"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 8u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the tablet tool stops making contact with the surface of"]
-            #[doc = "the tablet, or when the tablet tool moves out of the input region"]
-            #[doc = "and the compositor grab (if any) is dismissed."]
-            #[doc = ""]
-            #[doc = "If the tablet tool moves out of the input region while in contact"]
-            #[doc = "with the surface of the tablet and the compositor does not have an"]
-            #[doc = "ongoing grab on the surface, the client owning said region will"]
-            #[doc = "receive a zwp_tablet_v2.up event, followed by a zwp_tablet_v2.proximity_out"]
-            #[doc = "event and a zwp_tablet_v2.frame event. If the compositor has an ongoing"]
-            #[doc = "grab on this device, this event sequence is sent whenever the grab"]
-            #[doc = "is dismissed in the future."]
-            #[doc = ""]
-            #[doc = "Note that this event describes logical contact, not physical"]
-            #[doc = "contact. On some devices, a compositor may not consider a tool out"]
-            #[doc = "of logical contact until physical pressure falls below a specific"]
-            #[doc = "threshold."]
-            fn up(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.up()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the up message was introduced in
+            pub const UP_SINCE: u32 = 1u32;
+            ///The interface version the up message was deprecated in, if any
+            pub const UP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the tablet tool stops making contact with the surface of
+            ///the tablet, or when the tablet tool moves out of the input region
+            ///and the compositor grab (if any) is dismissed.
+            ///
+            ///If the tablet tool moves out of the input region while in contact
+            ///with the surface of the tablet and the compositor does not have an
+            ///ongoing grab on the surface, the client owning said region will
+            ///receive a zwp_tablet_v2.up event, followed by a zwp_tablet_v2.proximity_out
+            ///event and a zwp_tablet_v2.frame event. If the compositor has an ongoing
+            ///grab on this device, this event sequence is sent whenever the grab
+            ///is dismissed in the future.
+            ///
+            ///Note that this event describes logical contact, not physical
+            ///contact. On some devices, a compositor may not consider a tool out
+            ///of logical contact until physical pressure falls below a specific
+            ///threshold.
+            pub fn up<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 9u16, message
+                    = "up", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 9u16,
+                    message = "up", direction = "out"; "zwp_tablet_tool_v2.up"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 9u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever a tablet tool moves."]
-            fn motion(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the motion message was introduced in
+            pub const MOTION_SINCE: u32 = 1u32;
+            ///The interface version the motion message was deprecated in, if any
+            pub const MOTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever a tablet tool moves.
+            pub fn motion<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 x: waynest::Fixed,
                 y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.motion({}, {})", sender_id, x, y);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_fixed(x)
-                        .put_fixed(y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 10u16,
+                    message = "motion", direction = "out", "args.x" = % x, "args.y" = %
+                    y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 10u16,
+                    message = "motion", direction = "out", "args.x" : % = x, "args.y" : %
+                    = y; "zwp_tablet_tool_v2.motion"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_fixed(x)
+                    .put_fixed(y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 10u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the pressure axis on a tool changes. The value of this"]
-            #[doc = "event is normalized to a value between 0 and 65535."]
-            #[doc = ""]
-            #[doc = "Note that pressure may be nonzero even when a tool is not in logical"]
-            #[doc = "contact. See the down and up events for more details."]
-            fn pressure(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the pressure message was introduced in
+            pub const PRESSURE_SINCE: u32 = 1u32;
+            ///The interface version the pressure message was deprecated in, if any
+            pub const PRESSURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the pressure axis on a tool changes. The value of this
+            ///event is normalized to a value between 0 and 65535.
+            ///
+            ///Note that pressure may be nonzero even when a tool is not in logical
+            ///contact. See the down and up events for more details.
+            pub fn pressure<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 pressure: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.pressure({})", sender_id, pressure);
-                    let payload = waynest::PayloadBuilder::new().put_uint(pressure).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 11u16,
+                    message = "pressure", direction = "out", "args.pressure" = %
+                    pressure,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 11u16,
+                    message = "pressure", direction = "out", "args.pressure" : % =
+                    pressure; "zwp_tablet_tool_v2.pressure"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(pressure).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 11u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the distance axis on a tool changes. The value of this"]
-            #[doc = "event is normalized to a value between 0 and 65535."]
-            #[doc = ""]
-            #[doc = "Note that distance may be nonzero even when a tool is not in logical"]
-            #[doc = "contact. See the down and up events for more details."]
-            fn distance(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the distance message was introduced in
+            pub const DISTANCE_SINCE: u32 = 1u32;
+            ///The interface version the distance message was deprecated in, if any
+            pub const DISTANCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the distance axis on a tool changes. The value of this
+            ///event is normalized to a value between 0 and 65535.
+            ///
+            ///Note that distance may be nonzero even when a tool is not in logical
+            ///contact. See the down and up events for more details.
+            pub fn distance<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 distance: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.distance({})", sender_id, distance);
-                    let payload = waynest::PayloadBuilder::new().put_uint(distance).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 12u16,
+                    message = "distance", direction = "out", "args.distance" = %
+                    distance,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 12u16,
+                    message = "distance", direction = "out", "args.distance" : % =
+                    distance; "zwp_tablet_tool_v2.distance"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(distance).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 12u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever one or both of the tilt axes on a tool change. Each tilt"]
-            #[doc = "value is in degrees, relative to the z-axis of the tablet."]
-            #[doc = "The angle is positive when the top of a tool tilts along the"]
-            #[doc = "positive x or y axis."]
-            fn tilt(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the tilt message was introduced in
+            pub const TILT_SINCE: u32 = 1u32;
+            ///The interface version the tilt message was deprecated in, if any
+            pub const TILT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever one or both of the tilt axes on a tool change. Each tilt
+            ///value is in degrees, relative to the z-axis of the tablet.
+            ///The angle is positive when the top of a tool tilts along the
+            ///positive x or y axis.
+            pub fn tilt<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 tilt_x: waynest::Fixed,
                 tilt_y: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.tilt({}, {})",
-                        sender_id,
-                        tilt_x,
-                        tilt_y
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_fixed(tilt_x)
-                        .put_fixed(tilt_y)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 13u16,
+                    message = "tilt", direction = "out", "args.tilt_x" = % tilt_x,
+                    "args.tilt_y" = % tilt_y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 13u16,
+                    message = "tilt", direction = "out", "args.tilt_x" : % = tilt_x,
+                    "args.tilt_y" : % = tilt_y; "zwp_tablet_tool_v2.tilt"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_fixed(tilt_x)
+                    .put_fixed(tilt_y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 13u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the z-rotation axis on the tool changes. The"]
-            #[doc = "rotation value is in degrees clockwise from the tool's"]
-            #[doc = "logical neutral position."]
-            fn rotation(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the rotation message was introduced in
+            pub const ROTATION_SINCE: u32 = 1u32;
+            ///The interface version the rotation message was deprecated in, if any
+            pub const ROTATION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the z-rotation axis on the tool changes. The
+            ///rotation value is in degrees clockwise from the tool's
+            ///logical neutral position.
+            pub fn rotation<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 degrees: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.rotation({})", sender_id, degrees);
-                    let payload = waynest::PayloadBuilder::new().put_fixed(degrees).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 14u16,
+                    message = "rotation", direction = "out", "args.degrees" = % degrees,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 14u16,
+                    message = "rotation", direction = "out", "args.degrees" : % =
+                    degrees; "zwp_tablet_tool_v2.rotation"
+                );
+                let payload = waynest::PayloadBuilder::new().put_fixed(degrees).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 14u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the slider position on the tool changes. The"]
-            #[doc = "value is normalized between -65535 and 65535, with 0 as the logical"]
-            #[doc = "neutral position of the slider."]
-            #[doc = ""]
-            #[doc = "The slider is available on e.g. the Wacom Airbrush tool."]
-            fn slider(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the slider message was introduced in
+            pub const SLIDER_SINCE: u32 = 1u32;
+            ///The interface version the slider message was deprecated in, if any
+            pub const SLIDER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the slider position on the tool changes. The
+            ///value is normalized between -65535 and 65535, with 0 as the logical
+            ///neutral position of the slider.
+            ///
+            ///The slider is available on e.g. the Wacom Airbrush tool.
+            pub fn slider<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 position: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.slider({})", sender_id, position);
-                    let payload = waynest::PayloadBuilder::new().put_int(position).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 15u16,
+                    message = "slider", direction = "out", "args.position" = % position,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 15u16,
+                    message = "slider", direction = "out", "args.position" : % =
+                    position; "zwp_tablet_tool_v2.slider"
+                );
+                let payload = waynest::PayloadBuilder::new().put_int(position).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 15u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the wheel on the tool emits an event. This event"]
-            #[doc = "contains two values for the same axis change. The degrees value is"]
-            #[doc = "in the same orientation as the wl_pointer.vertical_scroll axis. The"]
-            #[doc = "clicks value is in discrete logical clicks of the mouse wheel. This"]
-            #[doc = "value may be zero if the movement of the wheel was less"]
-            #[doc = "than one logical click."]
-            #[doc = ""]
-            #[doc = "Clients should choose either value and avoid mixing degrees and"]
-            #[doc = "clicks. The compositor may accumulate values smaller than a logical"]
-            #[doc = "click and emulate click events when a certain threshold is met."]
-            #[doc = "Thus, zwp_tablet_tool_v2.wheel events with non-zero clicks values may"]
-            #[doc = "have different degrees values."]
-            fn wheel(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the wheel message was introduced in
+            pub const WHEEL_SINCE: u32 = 1u32;
+            ///The interface version the wheel message was deprecated in, if any
+            pub const WHEEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the wheel on the tool emits an event. This event
+            ///contains two values for the same axis change. The degrees value is
+            ///in the same orientation as the wl_pointer.vertical_scroll axis. The
+            ///clicks value is in discrete logical clicks of the mouse wheel. This
+            ///value may be zero if the movement of the wheel was less
+            ///than one logical click.
+            ///
+            ///Clients should choose either value and avoid mixing degrees and
+            ///clicks. The compositor may accumulate values smaller than a logical
+            ///click and emulate click events when a certain threshold is met.
+            ///Thus, zwp_tablet_tool_v2.wheel events with non-zero clicks values may
+            ///have different degrees values.
+            pub fn wheel<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 degrees: waynest::Fixed,
                 clicks: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.wheel({}, {})",
-                        sender_id,
-                        degrees,
-                        clicks
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_fixed(degrees)
-                        .put_int(clicks)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 16u16,
+                    message = "wheel", direction = "out", "args.degrees" = % degrees,
+                    "args.clicks" = % clicks,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 16u16,
+                    message = "wheel", direction = "out", "args.degrees" : % = degrees,
+                    "args.clicks" : % = clicks; "zwp_tablet_tool_v2.wheel"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_fixed(degrees)
+                    .put_int(clicks)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 16u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever a button on the tool is pressed or released."]
-            #[doc = ""]
-            #[doc = "If a button is held down when the tool moves in or out of proximity,"]
-            #[doc = "button events are generated by the compositor. See"]
-            #[doc = "zwp_tablet_tool_v2.proximity_in and zwp_tablet_tool_v2.proximity_out for"]
-            #[doc = "details."]
-            fn button(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the button message was introduced in
+            pub const BUTTON_SINCE: u32 = 1u32;
+            ///The interface version the button message was deprecated in, if any
+            pub const BUTTON_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever a button on the tool is pressed or released.
+            ///
+            ///If a button is held down when the tool moves in or out of proximity,
+            ///button events are generated by the compositor. See
+            ///zwp_tablet_tool_v2.proximity_in and zwp_tablet_tool_v2.proximity_out for
+            ///details.
+            pub fn button<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 button: u32,
-                state: ButtonState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_tool_v2#{}.button({}, {}, {})",
-                        sender_id,
-                        serial,
-                        button,
-                        state
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(button)
-                        .put_uint(state.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+                state_arg: ButtonState,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 17u16,
+                    message = "button", direction = "out", "args.serial" = % serial,
+                    "args.button" = % button, "args.state" = % state_arg,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 17u16,
+                    message = "button", direction = "out", "args.serial" : % = serial,
+                    "args.button" : % = button, "args.state" : % = state_arg;
+                    "zwp_tablet_tool_v2.button"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(button)
+                    .put_uint(state_arg.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 17u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Marks the end of a series of axis and/or button updates from the"]
-            #[doc = "tablet. The Wayland protocol requires axis updates to be sent"]
-            #[doc = "sequentially, however all events within a frame should be considered"]
-            #[doc = "one hardware event."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Marks the end of a series of axis and/or button updates from the
+            ///tablet. The Wayland protocol requires axis updates to be sent
+            ///sequentially, however all events within a frame should be considered
+            ///one hardware event.
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_tool_v2#{}.frame({})", sender_id, time);
-                    let payload = waynest::PayloadBuilder::new().put_uint(time).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_tool_v2", object_id = % sender_id, opcode = 18u16,
+                    message = "frame", direction = "out", "args.time" = % time,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_tool_v2", object_id : % = sender_id, opcode = 18u16,
+                    message = "frame", direction = "out", "args.time" : % = time;
+                    "zwp_tablet_tool_v2.frame"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(time).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 18u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_tool_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_tool_v2` interface",
+            label = "`ZwpTabletToolV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletToolV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_tool_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_cursor message was introduced in
+            const SET_CURSOR_SINCE: u32 = 1u32;
+            ///The interface version the set_cursor message was deprecated in, if any
+            const SET_CURSOR_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sets the surface of the cursor used for this tool on the given
+            ///tablet. This request only takes effect if the tool is in proximity
+            ///of one of the requesting client's surfaces or the surface parameter
+            ///is the current pointer surface. If there was a previous surface set
+            ///with this request it is replaced. If surface is NULL, the cursor
+            ///image is hidden.
+            ///
+            ///The parameters hotspot_x and hotspot_y define the position of the
+            ///pointer surface relative to the pointer location. Its top-left corner
+            ///is always at (x, y) - (hotspot_x, hotspot_y), where (x, y) are the
+            ///coordinates of the pointer location, in surface-local coordinates.
+            ///
+            ///On surface.attach requests to the pointer surface, hotspot_x and
+            ///hotspot_y are decremented by the x and y parameters passed to the
+            ///request. Attach must be confirmed by wl_surface.commit as usual.
+            ///
+            ///The hotspot can also be updated by passing the currently set pointer
+            ///surface to this request with new values for hotspot_x and hotspot_y.
+            ///
+            ///The current and pending input regions of the wl_surface are cleared,
+            ///and wl_surface.set_input_region is ignored until the wl_surface is no
+            ///longer used as the cursor. When the use as a cursor ends, the current
+            ///and pending input regions become undefined, and the wl_surface is
+            ///unmapped.
+            ///
+            ///This request gives the surface the role of a zwp_tablet_tool_v2 cursor. A
+            ///surface may only ever be used as the cursor surface for one
+            ///zwp_tablet_tool_v2. If the surface already has another role or has
+            ///previously been used as cursor surface for a different tool, a
+            ///protocol error is raised.
+            fn set_cursor(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+                surface: Option<waynest::ObjectId>,
+                hotspot_x: i32,
+                hotspot_y: i32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This destroys the client's resource for this tool object.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2476,63 +4277,151 @@ pub mod tablet_v2 {
                             let hotspot_x = message.int()?;
                             let hotspot_y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_tablet_tool_v2#{}.set_cursor({}, {}, {}, {})",
-                                sender_id,
-                                serial,
-                                surface
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                hotspot_x,
-                                hotspot_y
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_tool_v2", object_id = % sender_id, opcode =
+                                0u16, message = "set_cursor", direction = "in",
+                                "args.serial" = % serial, "args.surface" = % surface
+                                .as_ref().map_or("null".to_string(), | v | v.to_string()),
+                                "args.hotspot_x" = % hotspot_x, "args.hotspot_y" = %
+                                hotspot_y,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_tool_v2", object_id : % = sender_id, opcode =
+                                0u16, message = "set_cursor", direction = "in",
+                                "args.serial" : % = serial, "args.surface" : % = surface
+                                .as_ref().map_or("null".to_string(), | v | v.to_string()),
+                                "args.hotspot_x" : % = hotspot_x, "args.hotspot_y" : % =
+                                hotspot_y; "zwp_tablet_tool_v2.set_cursor"
                             );
                             self.set_cursor(
-                                connection, sender_id, serial, surface, hotspot_x, hotspot_y,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    serial,
+                                    surface,
+                                    hotspot_x,
+                                    hotspot_y,
+                                )
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_tool_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_tool_v2", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_tool_v2", object_id : % = sender_id, opcode =
+                                1u16, message = "destroy", direction = "in";
+                                "zwp_tablet_tool_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The zwp_tablet_v2 interface represents one graphics tablet device. The"]
-    #[doc = "tablet interface itself does not generate events; all events are"]
-    #[doc = "generated by zwp_tablet_tool_v2 objects when in proximity above a tablet."]
-    #[doc = ""]
-    #[doc = "A tablet has a number of static characteristics, e.g. device name and"]
-    #[doc = "pid/vid. These capabilities are sent in an event sequence after the"]
-    #[doc = "zwp_tablet_seat_v2.tablet_added event. This initial event sequence is"]
-    #[doc = "terminated by a zwp_tablet_v2.done event."]
+    ///The zwp_tablet_v2 interface represents one graphics tablet device. The
+    ///tablet interface itself does not generate events; all events are
+    ///generated by zwp_tablet_tool_v2 objects when in proximity above a tablet.
+    ///
+    ///A tablet has a number of static characteristics, e.g. device name and
+    ///pid/vid. These capabilities are sent in an event sequence after the
+    ///zwp_tablet_seat_v2.tablet_added event. This initial event sequence is
+    ///terminated by a zwp_tablet_v2.done event.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_v2 {
-        #[doc = "Describes the bus types this tablet is connected to."]
+        ///Describes the bus types this tablet is connected to.
+        ///
+        ///Available since version 2
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Bustype {
-            #[doc = "USB"]
+            ///USB
             Usb = 3u32,
-            #[doc = "Bluetooth"]
+            ///Bluetooth
             Bluetooth = 5u32,
-            #[doc = "Virtual"]
+            ///Virtual
             Virtual = 6u32,
-            #[doc = "Serial"]
+            ///Serial
             Serial = 17u32,
-            #[doc = "I2C"]
+            ///I2C
             I2c = 24u32,
         }
+        #[allow(deprecated)]
+        impl Bustype {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 2u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Usb => 1u32,
+                    Self::Bluetooth => 1u32,
+                    Self::Virtual => 1u32,
+                    Self::Serial => 1u32,
+                    Self::I2c => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Usb => None,
+                    Self::Bluetooth => None,
+                    Self::Virtual => None,
+                    Self::Serial => None,
+                    Self::I2c => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("usb", Self::Usb),
+                ("bluetooth", Self::Bluetooth),
+                ("virtual", Self::Virtual),
+                ("serial", Self::Serial),
+                ("i2c", Self::I2c),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Usb => "usb",
+                    Self::Bluetooth => "bluetooth",
+                    Self::Virtual => "virtual",
+                    Self::Serial => "serial",
+                    Self::I2c => "i2c",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Bustype> for u32 {
             fn from(value: Bustype) -> Self {
                 value as u32
             }
         }
+        impl From<Bustype> for i32 {
+            fn from(value: Bustype) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Bustype {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2546,240 +4435,392 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for Bustype {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Bustype {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwp_tablet_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "This destroys the client's resource for this tablet object."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "A descriptive name for the tablet device."]
-            #[doc = ""]
-            #[doc = "If the device has no descriptive name, this event is not sent."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_v2.done event."]
-            fn name(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the name message was introduced in
+            pub const NAME_SINCE: u32 = 1u32;
+            ///The interface version the name message was deprecated in, if any
+            pub const NAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///A descriptive name for the tablet device.
+            ///
+            ///If the device has no descriptive name, this event is not sent.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_v2.done event.
+            pub fn name<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 name: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_v2#{}.name(\"{}\")", sender_id, name);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(name))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_v2", object_id = % sender_id, opcode = 0u16, message =
+                    "name", direction = "out", "args.name" = % name,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "zwp_tablet_v2",
+                    object_id : % = sender_id, opcode = 0u16, message = "name", direction
+                    = "out", "args.name" : % = name; "zwp_tablet_v2.name"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(name))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The vendor and product IDs for the tablet device."]
-            #[doc = ""]
-            #[doc = "The interpretation of the id depends on the zwp_tablet_v2.bustype."]
-            #[doc = "Prior to version v2 of this protocol, the id was implied to be a USB"]
-            #[doc = "vendor and product ID. If no zwp_tablet_v2.bustype is sent, the ID"]
-            #[doc = "is to be interpreted as USB vendor and product ID."]
-            #[doc = ""]
-            #[doc = "If the device has no vendor/product ID, this event is not sent."]
-            #[doc = "This can happen for virtual devices or non-USB devices, for instance."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_v2.done event."]
-            fn id(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the id message was introduced in
+            pub const ID_SINCE: u32 = 1u32;
+            ///The interface version the id message was deprecated in, if any
+            pub const ID_DEPRECATED_SINCE: Option<u32> = None;
+            ///The vendor and product IDs for the tablet device.
+            ///
+            ///The interpretation of the id depends on the zwp_tablet_v2.bustype.
+            ///Prior to version v2 of this protocol, the id was implied to be a USB
+            ///vendor and product ID. If no zwp_tablet_v2.bustype is sent, the ID
+            ///is to be interpreted as USB vendor and product ID.
+            ///
+            ///If the device has no vendor/product ID, this event is not sent.
+            ///This can happen for virtual devices or non-USB devices, for instance.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_v2.done event.
+            pub fn id<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 vid: u32,
                 pid: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_v2#{}.id({}, {})", sender_id, vid, pid);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(vid)
-                        .put_uint(pid)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_v2", object_id = % sender_id, opcode = 1u16, message =
+                    "id", direction = "out", "args.vid" = % vid, "args.pid" = % pid,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "zwp_tablet_v2",
+                    object_id : % = sender_id, opcode = 1u16, message = "id", direction =
+                    "out", "args.vid" : % = vid, "args.pid" : % = pid; "zwp_tablet_v2.id"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(vid)
+                    .put_uint(pid)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "A system-specific device path that indicates which device is behind"]
-            #[doc = "this zwp_tablet_v2. This information may be used to gather additional"]
-            #[doc = "information about the device, e.g. through libwacom."]
-            #[doc = ""]
-            #[doc = "A device may have more than one device path. If so, multiple"]
-            #[doc = "zwp_tablet_v2.path events are sent. A device may be emulated and not"]
-            #[doc = "have a device path, and in that case this event will not be sent."]
-            #[doc = ""]
-            #[doc = "The format of the path is unspecified, it may be a device node, a"]
-            #[doc = "sysfs path, or some other identifier. It is up to the client to"]
-            #[doc = "identify the string provided."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_v2.done event."]
-            fn path(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the path message was introduced in
+            pub const PATH_SINCE: u32 = 1u32;
+            ///The interface version the path message was deprecated in, if any
+            pub const PATH_DEPRECATED_SINCE: Option<u32> = None;
+            ///A system-specific device path that indicates which device is behind
+            ///this zwp_tablet_v2. This information may be used to gather additional
+            ///information about the device, e.g. through libwacom.
+            ///
+            ///A device may have more than one device path. If so, multiple
+            ///zwp_tablet_v2.path events are sent. A device may be emulated and not
+            ///have a device path, and in that case this event will not be sent.
+            ///
+            ///The format of the path is unspecified, it may be a device node, a
+            ///sysfs path, or some other identifier. It is up to the client to
+            ///identify the string provided.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_v2.done event.
+            pub fn path<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 path: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_v2#{}.path(\"{}\")", sender_id, path);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(path))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_v2", object_id = % sender_id, opcode = 2u16, message =
+                    "path", direction = "out", "args.path" = % path,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "zwp_tablet_v2",
+                    object_id : % = sender_id, opcode = 2u16, message = "path", direction
+                    = "out", "args.path" : % = path; "zwp_tablet_v2.path"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(path))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent immediately to signal the end of the initial"]
-            #[doc = "burst of descriptive events. A client may consider the static"]
-            #[doc = "description of the tablet to be complete and finalize initialization"]
-            #[doc = "of the tablet."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_v2#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent immediately to signal the end of the initial
+            ///burst of descriptive events. A client may consider the static
+            ///description of the tablet to be complete and finalize initialization
+            ///of the tablet.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_v2", object_id = % sender_id, opcode = 3u16, message =
+                    "done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "zwp_tablet_v2",
+                    object_id : % = sender_id, opcode = 3u16, message = "done", direction
+                    = "out"; "zwp_tablet_v2.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent when the tablet has been removed from the system. When a tablet"]
-            #[doc = "is removed, some tools may be removed."]
-            #[doc = ""]
-            #[doc = "When this event is received, the client must zwp_tablet_v2.destroy"]
-            #[doc = "the object."]
-            fn removed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_v2#{}.removed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the removed message was introduced in
+            pub const REMOVED_SINCE: u32 = 1u32;
+            ///The interface version the removed message was deprecated in, if any
+            pub const REMOVED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent when the tablet has been removed from the system. When a tablet
+            ///is removed, some tools may be removed.
+            ///
+            ///When this event is received, the client must zwp_tablet_v2.destroy
+            ///the object.
+            pub fn removed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_v2", object_id = % sender_id, opcode = 4u16, message =
+                    "removed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "zwp_tablet_v2",
+                    object_id : % = sender_id, opcode = 4u16, message = "removed",
+                    direction = "out"; "zwp_tablet_v2.removed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "The bustype argument is one of the BUS_ defines in the Linux kernel's"]
-            #[doc = "linux/input.h"]
-            #[doc = ""]
-            #[doc = "If the device has no known bustype or the bustype cannot be"]
-            #[doc = "queried, this event is not sent."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_v2.done event."]
-            fn bustype(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the bustype message was introduced in
+            pub const BUSTYPE_SINCE: u32 = 2u32;
+            ///The interface version the bustype message was deprecated in, if any
+            pub const BUSTYPE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The bustype argument is one of the BUS_ defines in the Linux kernel's
+            ///linux/input.h
+            ///
+            ///If the device has no known bustype or the bustype cannot be
+            ///queried, this event is not sent.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_v2.done event.
+            ///
+            ///Available since version 2
+            pub fn bustype<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 bustype: Bustype,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_v2#{}.bustype({})", sender_id, bustype);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(bustype.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_v2", object_id = % sender_id, opcode = 5u16, message =
+                    "bustype", direction = "out", "args.bustype" = % bustype,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "zwp_tablet_v2",
+                    object_id : % = sender_id, opcode = 5u16, message = "bustype",
+                    direction = "out", "args.bustype" : % = bustype;
+                    "zwp_tablet_v2.bustype"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(bustype.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_v2` interface",
+            label = "`ZwpTabletV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This destroys the client's resource for this tablet object.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_v2", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_v2", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in";
+                                "zwp_tablet_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A circular interaction area, such as the touch ring on the Wacom Intuos"]
-    #[doc = "Pro series tablets."]
-    #[doc = ""]
-    #[doc = "Events on a ring are logically grouped by the zwp_tablet_pad_ring_v2.frame"]
-    #[doc = "event."]
+    ///A circular interaction area, such as the touch ring on the Wacom Intuos
+    ///Pro series tablets.
+    ///
+    ///Events on a ring are logically grouped by the zwp_tablet_pad_ring_v2.frame
+    ///event.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_pad_ring_v2 {
-        #[doc = "Describes the source types for ring events. This indicates to the"]
-        #[doc = "client how a ring event was physically generated; a client may"]
-        #[doc = "adjust the user interface accordingly. For example, events"]
-        #[doc = "from a \"finger\" source may trigger kinetic scrolling."]
+        ///Describes the source types for ring events. This indicates to the
+        ///client how a ring event was physically generated; a client may
+        ///adjust the user interface accordingly. For example, events
+        ///from a "finger" source may trigger kinetic scrolling.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Source {
-            #[doc = "finger"]
+            ///finger
             Finger = 1u32,
         }
+        #[allow(deprecated)]
+        impl Source {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Finger => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Finger => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("finger", Self::Finger),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Finger => "finger",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Source> for u32 {
             fn from(value: Source) -> Self {
                 value as u32
             }
         }
+        impl From<Source> for i32 {
+            fn from(value: Source) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Source {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2789,174 +4830,283 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for Source {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Source {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwp_tablet_pad_ring_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletPadRingV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_pad_ring_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "Request that the compositor use the provided feedback string"]
-            #[doc = "associated with this ring. This request should be issued immediately"]
-            #[doc = "after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding"]
-            #[doc = "group is received, or whenever the ring is mapped to a different"]
-            #[doc = "action. See zwp_tablet_pad_group_v2.mode_switch for more details."]
-            #[doc = ""]
-            #[doc = "Clients are encouraged to provide context-aware descriptions for"]
-            #[doc = "the actions associated with the ring; compositors may use this"]
-            #[doc = "information to offer visual feedback about the button layout"]
-            #[doc = "(eg. on-screen displays)."]
-            #[doc = ""]
-            #[doc = "The provided string 'description' is a UTF-8 encoded string to be"]
-            #[doc = "associated with this ring, and is considered user-visible; general"]
-            #[doc = "internationalization rules apply."]
-            #[doc = ""]
-            #[doc = "The serial argument will be that of the last"]
-            #[doc = "zwp_tablet_pad_group_v2.mode_switch event received for the group of this"]
-            #[doc = "ring. Requests providing other serials than the most recent one will be"]
-            #[doc = "ignored."]
-            fn set_feedback(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                description: String,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This destroys the client's resource for this ring object."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Source information for ring events."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own. It is sent before a"]
-            #[doc = "zwp_tablet_pad_ring_v2.frame event and carries the source information"]
-            #[doc = "for all events within that frame."]
-            #[doc = ""]
-            #[doc = "The source specifies how this event was generated. If the source is"]
-            #[doc = "zwp_tablet_pad_ring_v2.source.finger, a zwp_tablet_pad_ring_v2.stop event"]
-            #[doc = "will be sent when the user lifts the finger off the device."]
-            #[doc = ""]
-            #[doc = "This event is optional. If the source is unknown for an interaction,"]
-            #[doc = "no event is sent."]
-            fn source(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_pad_ring_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the source message was introduced in
+            pub const SOURCE_SINCE: u32 = 1u32;
+            ///The interface version the source message was deprecated in, if any
+            pub const SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Source information for ring events.
+            ///
+            ///This event does not occur on its own. It is sent before a
+            ///zwp_tablet_pad_ring_v2.frame event and carries the source information
+            ///for all events within that frame.
+            ///
+            ///The source specifies how this event was generated. If the source is
+            ///zwp_tablet_pad_ring_v2.source.finger, a zwp_tablet_pad_ring_v2.stop event
+            ///will be sent when the user lifts the finger off the device.
+            ///
+            ///This event is optional. If the source is unknown for an interaction,
+            ///no event is sent.
+            pub fn source<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 source: Source,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_ring_v2#{}.source({})", sender_id, source);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(source.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_ring_v2", object_id = % sender_id, opcode = 0u16,
+                    message = "source", direction = "out", "args.source" = % source,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_ring_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "source", direction = "out", "args.source" : % = source;
+                    "zwp_tablet_pad_ring_v2.source"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(source.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the angle on a ring changes."]
-            #[doc = ""]
-            #[doc = "The angle is provided in degrees clockwise from the logical"]
-            #[doc = "north of the ring in the pad's current rotation."]
-            fn angle(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the angle message was introduced in
+            pub const ANGLE_SINCE: u32 = 1u32;
+            ///The interface version the angle message was deprecated in, if any
+            pub const ANGLE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the angle on a ring changes.
+            ///
+            ///The angle is provided in degrees clockwise from the logical
+            ///north of the ring in the pad's current rotation.
+            pub fn angle<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 degrees: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_ring_v2#{}.angle({})", sender_id, degrees);
-                    let payload = waynest::PayloadBuilder::new().put_fixed(degrees).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_ring_v2", object_id = % sender_id, opcode = 1u16,
+                    message = "angle", direction = "out", "args.degrees" = % degrees,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_ring_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "angle", direction = "out", "args.degrees" : % = degrees;
+                    "zwp_tablet_pad_ring_v2.angle"
+                );
+                let payload = waynest::PayloadBuilder::new().put_fixed(degrees).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Stop notification for ring events."]
-            #[doc = ""]
-            #[doc = "For some zwp_tablet_pad_ring_v2.source types, a zwp_tablet_pad_ring_v2.stop"]
-            #[doc = "event is sent to notify a client that the interaction with the ring"]
-            #[doc = "has terminated. This enables the client to implement kinetic scrolling."]
-            #[doc = "See the zwp_tablet_pad_ring_v2.source documentation for information on"]
-            #[doc = "when this event may be generated."]
-            #[doc = ""]
-            #[doc = "Any zwp_tablet_pad_ring_v2.angle events with the same source after this"]
-            #[doc = "event should be considered as the start of a new interaction."]
-            fn stop(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_ring_v2#{}.stop()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the stop message was introduced in
+            pub const STOP_SINCE: u32 = 1u32;
+            ///The interface version the stop message was deprecated in, if any
+            pub const STOP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Stop notification for ring events.
+            ///
+            ///For some zwp_tablet_pad_ring_v2.source types, a zwp_tablet_pad_ring_v2.stop
+            ///event is sent to notify a client that the interaction with the ring
+            ///has terminated. This enables the client to implement kinetic scrolling.
+            ///See the zwp_tablet_pad_ring_v2.source documentation for information on
+            ///when this event may be generated.
+            ///
+            ///Any zwp_tablet_pad_ring_v2.angle events with the same source after this
+            ///event should be considered as the start of a new interaction.
+            pub fn stop<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_ring_v2", object_id = % sender_id, opcode = 2u16,
+                    message = "stop", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_ring_v2", object_id : % = sender_id, opcode = 2u16,
+                    message = "stop", direction = "out"; "zwp_tablet_pad_ring_v2.stop"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Indicates the end of a set of ring events that logically belong"]
-            #[doc = "together. A client is expected to accumulate the data in all events"]
-            #[doc = "within the frame before proceeding."]
-            #[doc = ""]
-            #[doc = "All zwp_tablet_pad_ring_v2 events before a zwp_tablet_pad_ring_v2.frame event belong"]
-            #[doc = "logically together. For example, on termination of a finger interaction"]
-            #[doc = "on a ring the compositor will send a zwp_tablet_pad_ring_v2.source event,"]
-            #[doc = "a zwp_tablet_pad_ring_v2.stop event and a zwp_tablet_pad_ring_v2.frame event."]
-            #[doc = ""]
-            #[doc = "A zwp_tablet_pad_ring_v2.frame event is sent for every logical event"]
-            #[doc = "group, even if the group only contains a single zwp_tablet_pad_ring_v2"]
-            #[doc = "event. Specifically, a client may get a sequence: angle, frame,"]
-            #[doc = "angle, frame, etc."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the end of a set of ring events that logically belong
+            ///together. A client is expected to accumulate the data in all events
+            ///within the frame before proceeding.
+            ///
+            ///All zwp_tablet_pad_ring_v2 events before a zwp_tablet_pad_ring_v2.frame event belong
+            ///logically together. For example, on termination of a finger interaction
+            ///on a ring the compositor will send a zwp_tablet_pad_ring_v2.source event,
+            ///a zwp_tablet_pad_ring_v2.stop event and a zwp_tablet_pad_ring_v2.frame event.
+            ///
+            ///A zwp_tablet_pad_ring_v2.frame event is sent for every logical event
+            ///group, even if the group only contains a single zwp_tablet_pad_ring_v2
+            ///event. Specifically, a client may get a sequence: angle, frame,
+            ///angle, frame, etc.
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_ring_v2#{}.frame({})", sender_id, time);
-                    let payload = waynest::PayloadBuilder::new().put_uint(time).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_ring_v2", object_id = % sender_id, opcode = 3u16,
+                    message = "frame", direction = "out", "args.time" = % time,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_ring_v2", object_id : % = sender_id, opcode = 3u16,
+                    message = "frame", direction = "out", "args.time" : % = time;
+                    "zwp_tablet_pad_ring_v2.frame"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(time).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_pad_ring_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_pad_ring_v2` interface",
+            label = "`ZwpTabletPadRingV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletPadRingV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_pad_ring_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_feedback message was introduced in
+            const SET_FEEDBACK_SINCE: u32 = 1u32;
+            ///The interface version the set_feedback message was deprecated in, if any
+            const SET_FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request that the compositor use the provided feedback string
+            ///associated with this ring. This request should be issued immediately
+            ///after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+            ///group is received, or whenever the ring is mapped to a different
+            ///action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+            ///
+            ///Clients are encouraged to provide context-aware descriptions for
+            ///the actions associated with the ring; compositors may use this
+            ///information to offer visual feedback about the button layout
+            ///(eg. on-screen displays).
+            ///
+            ///The provided string 'description' is a UTF-8 encoded string to be
+            ///associated with this ring, and is considered user-visible; general
+            ///internationalization rules apply.
+            ///
+            ///The serial argument will be that of the last
+            ///zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+            ///ring. Requests providing other serials than the most recent one will be
+            ///ignored.
+            fn set_feedback(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                description: String,
+                serial: u32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This destroys the client's resource for this ring object.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2966,49 +5116,119 @@ pub mod tablet_v2 {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_tablet_pad_ring_v2#{}.set_feedback(\"{}\", {})",
-                                sender_id,
-                                description,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_ring_v2", object_id = % sender_id, opcode =
+                                0u16, message = "set_feedback", direction = "in",
+                                "args.description" = % description, "args.serial" = %
+                                serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_ring_v2", object_id : % = sender_id, opcode
+                                = 0u16, message = "set_feedback", direction = "in",
+                                "args.description" : % = description, "args.serial" : % =
+                                serial; "zwp_tablet_pad_ring_v2.set_feedback"
                             );
-                            self.set_feedback(connection, sender_id, description, serial)
+                            self.set_feedback(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    description,
+                                    serial,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_pad_ring_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_ring_v2", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_ring_v2", object_id : % = sender_id, opcode
+                                = 1u16, message = "destroy", direction = "in";
+                                "zwp_tablet_pad_ring_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A linear interaction area, such as the strips found in Wacom Cintiq"]
-    #[doc = "models."]
-    #[doc = ""]
-    #[doc = "Events on a strip are logically grouped by the zwp_tablet_pad_strip_v2.frame"]
-    #[doc = "event."]
+    ///A linear interaction area, such as the strips found in Wacom Cintiq
+    ///models.
+    ///
+    ///Events on a strip are logically grouped by the zwp_tablet_pad_strip_v2.frame
+    ///event.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_pad_strip_v2 {
-        #[doc = "Describes the source types for strip events. This indicates to the"]
-        #[doc = "client how a strip event was physically generated; a client may"]
-        #[doc = "adjust the user interface accordingly. For example, events"]
-        #[doc = "from a \"finger\" source may trigger kinetic scrolling."]
+        ///Describes the source types for strip events. This indicates to the
+        ///client how a strip event was physically generated; a client may
+        ///adjust the user interface accordingly. For example, events
+        ///from a "finger" source may trigger kinetic scrolling.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Source {
-            #[doc = "finger"]
+            ///finger
             Finger = 1u32,
         }
+        #[allow(deprecated)]
+        impl Source {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Finger => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Finger => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("finger", Self::Finger),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Finger => "finger",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Source> for u32 {
             fn from(value: Source) -> Self {
                 value as u32
             }
         }
+        impl From<Source> for i32 {
+            fn from(value: Source) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Source {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -3018,184 +5238,286 @@ pub mod tablet_v2 {
                 }
             }
         }
+        impl TryFrom<i32> for Source {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Source {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwp_tablet_pad_strip_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletPadStripV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_pad_strip_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "Requests the compositor to use the provided feedback string"]
-            #[doc = "associated with this strip. This request should be issued immediately"]
-            #[doc = "after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding"]
-            #[doc = "group is received, or whenever the strip is mapped to a different"]
-            #[doc = "action. See zwp_tablet_pad_group_v2.mode_switch for more details."]
-            #[doc = ""]
-            #[doc = "Clients are encouraged to provide context-aware descriptions for"]
-            #[doc = "the actions associated with the strip, and compositors may use this"]
-            #[doc = "information to offer visual feedback about the button layout"]
-            #[doc = "(eg. on-screen displays)."]
-            #[doc = ""]
-            #[doc = "The provided string 'description' is a UTF-8 encoded string to be"]
-            #[doc = "associated with this ring, and is considered user-visible; general"]
-            #[doc = "internationalization rules apply."]
-            #[doc = ""]
-            #[doc = "The serial argument will be that of the last"]
-            #[doc = "zwp_tablet_pad_group_v2.mode_switch event received for the group of this"]
-            #[doc = "strip. Requests providing other serials than the most recent one will be"]
-            #[doc = "ignored."]
-            fn set_feedback(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                description: String,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This destroys the client's resource for this strip object."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Source information for strip events."]
-            #[doc = ""]
-            #[doc = "This event does not occur on its own. It is sent before a"]
-            #[doc = "zwp_tablet_pad_strip_v2.frame event and carries the source information"]
-            #[doc = "for all events within that frame."]
-            #[doc = ""]
-            #[doc = "The source specifies how this event was generated. If the source is"]
-            #[doc = "zwp_tablet_pad_strip_v2.source.finger, a zwp_tablet_pad_strip_v2.stop event"]
-            #[doc = "will be sent when the user lifts their finger off the device."]
-            #[doc = ""]
-            #[doc = "This event is optional. If the source is unknown for an interaction,"]
-            #[doc = "no event is sent."]
-            fn source(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_pad_strip_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the source message was introduced in
+            pub const SOURCE_SINCE: u32 = 1u32;
+            ///The interface version the source message was deprecated in, if any
+            pub const SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Source information for strip events.
+            ///
+            ///This event does not occur on its own. It is sent before a
+            ///zwp_tablet_pad_strip_v2.frame event and carries the source information
+            ///for all events within that frame.
+            ///
+            ///The source specifies how this event was generated. If the source is
+            ///zwp_tablet_pad_strip_v2.source.finger, a zwp_tablet_pad_strip_v2.stop event
+            ///will be sent when the user lifts their finger off the device.
+            ///
+            ///This event is optional. If the source is unknown for an interaction,
+            ///no event is sent.
+            pub fn source<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 source: Source,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_strip_v2#{}.source({})",
-                        sender_id,
-                        source
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(source.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_strip_v2", object_id = % sender_id, opcode = 0u16,
+                    message = "source", direction = "out", "args.source" = % source,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_strip_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "source", direction = "out", "args.source" : % = source;
+                    "zwp_tablet_pad_strip_v2.source"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(source.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the position on a strip changes."]
-            #[doc = ""]
-            #[doc = "The position is normalized to a range of [0, 65535], the 0-value"]
-            #[doc = "represents the top-most and/or left-most position of the strip in"]
-            #[doc = "the pad's current rotation."]
-            fn position(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the position message was introduced in
+            pub const POSITION_SINCE: u32 = 1u32;
+            ///The interface version the position message was deprecated in, if any
+            pub const POSITION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the position on a strip changes.
+            ///
+            ///The position is normalized to a range of [0, 65535], the 0-value
+            ///represents the top-most and/or left-most position of the strip in
+            ///the pad's current rotation.
+            pub fn position<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 position: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_strip_v2#{}.position({})",
-                        sender_id,
-                        position
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_uint(position).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_strip_v2", object_id = % sender_id, opcode = 1u16,
+                    message = "position", direction = "out", "args.position" = %
+                    position,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_strip_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "position", direction = "out", "args.position" : % =
+                    position; "zwp_tablet_pad_strip_v2.position"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(position).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Stop notification for strip events."]
-            #[doc = ""]
-            #[doc = "For some zwp_tablet_pad_strip_v2.source types, a zwp_tablet_pad_strip_v2.stop"]
-            #[doc = "event is sent to notify a client that the interaction with the strip"]
-            #[doc = "has terminated. This enables the client to implement kinetic"]
-            #[doc = "scrolling. See the zwp_tablet_pad_strip_v2.source documentation for"]
-            #[doc = "information on when this event may be generated."]
-            #[doc = ""]
-            #[doc = "Any zwp_tablet_pad_strip_v2.position events with the same source after this"]
-            #[doc = "event should be considered as the start of a new interaction."]
-            fn stop(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_strip_v2#{}.stop()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the stop message was introduced in
+            pub const STOP_SINCE: u32 = 1u32;
+            ///The interface version the stop message was deprecated in, if any
+            pub const STOP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Stop notification for strip events.
+            ///
+            ///For some zwp_tablet_pad_strip_v2.source types, a zwp_tablet_pad_strip_v2.stop
+            ///event is sent to notify a client that the interaction with the strip
+            ///has terminated. This enables the client to implement kinetic
+            ///scrolling. See the zwp_tablet_pad_strip_v2.source documentation for
+            ///information on when this event may be generated.
+            ///
+            ///Any zwp_tablet_pad_strip_v2.position events with the same source after this
+            ///event should be considered as the start of a new interaction.
+            pub fn stop<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_strip_v2", object_id = % sender_id, opcode = 2u16,
+                    message = "stop", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_strip_v2", object_id : % = sender_id, opcode = 2u16,
+                    message = "stop", direction = "out"; "zwp_tablet_pad_strip_v2.stop"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Indicates the end of a set of events that represent one logical"]
-            #[doc = "hardware strip event. A client is expected to accumulate the data"]
-            #[doc = "in all events within the frame before proceeding."]
-            #[doc = ""]
-            #[doc = "All zwp_tablet_pad_strip_v2 events before a zwp_tablet_pad_strip_v2.frame event belong"]
-            #[doc = "logically together. For example, on termination of a finger interaction"]
-            #[doc = "on a strip the compositor will send a zwp_tablet_pad_strip_v2.source event,"]
-            #[doc = "a zwp_tablet_pad_strip_v2.stop event and a zwp_tablet_pad_strip_v2.frame"]
-            #[doc = "event."]
-            #[doc = ""]
-            #[doc = "A zwp_tablet_pad_strip_v2.frame event is sent for every logical event"]
-            #[doc = "group, even if the group only contains a single zwp_tablet_pad_strip_v2"]
-            #[doc = "event. Specifically, a client may get a sequence: position, frame,"]
-            #[doc = "position, frame, etc."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the end of a set of events that represent one logical
+            ///hardware strip event. A client is expected to accumulate the data
+            ///in all events within the frame before proceeding.
+            ///
+            ///All zwp_tablet_pad_strip_v2 events before a zwp_tablet_pad_strip_v2.frame event belong
+            ///logically together. For example, on termination of a finger interaction
+            ///on a strip the compositor will send a zwp_tablet_pad_strip_v2.source event,
+            ///a zwp_tablet_pad_strip_v2.stop event and a zwp_tablet_pad_strip_v2.frame
+            ///event.
+            ///
+            ///A zwp_tablet_pad_strip_v2.frame event is sent for every logical event
+            ///group, even if the group only contains a single zwp_tablet_pad_strip_v2
+            ///event. Specifically, a client may get a sequence: position, frame,
+            ///position, frame, etc.
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_strip_v2#{}.frame({})", sender_id, time);
-                    let payload = waynest::PayloadBuilder::new().put_uint(time).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_strip_v2", object_id = % sender_id, opcode = 3u16,
+                    message = "frame", direction = "out", "args.time" = % time,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_strip_v2", object_id : % = sender_id, opcode = 3u16,
+                    message = "frame", direction = "out", "args.time" : % = time;
+                    "zwp_tablet_pad_strip_v2.frame"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(time).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_pad_strip_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_pad_strip_v2` interface",
+            label = "`ZwpTabletPadStripV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletPadStripV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_pad_strip_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_feedback message was introduced in
+            const SET_FEEDBACK_SINCE: u32 = 1u32;
+            ///The interface version the set_feedback message was deprecated in, if any
+            const SET_FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests the compositor to use the provided feedback string
+            ///associated with this strip. This request should be issued immediately
+            ///after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+            ///group is received, or whenever the strip is mapped to a different
+            ///action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+            ///
+            ///Clients are encouraged to provide context-aware descriptions for
+            ///the actions associated with the strip, and compositors may use this
+            ///information to offer visual feedback about the button layout
+            ///(eg. on-screen displays).
+            ///
+            ///The provided string 'description' is a UTF-8 encoded string to be
+            ///associated with this ring, and is considered user-visible; general
+            ///internationalization rules apply.
+            ///
+            ///The serial argument will be that of the last
+            ///zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+            ///strip. Requests providing other serials than the most recent one will be
+            ///ignored.
+            fn set_feedback(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                description: String,
+                serial: u32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This destroys the client's resource for this strip object.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3205,639 +5527,967 @@ pub mod tablet_v2 {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_tablet_pad_strip_v2#{}.set_feedback(\"{}\", {})",
-                                sender_id,
-                                description,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_strip_v2", object_id = % sender_id, opcode =
+                                0u16, message = "set_feedback", direction = "in",
+                                "args.description" = % description, "args.serial" = %
+                                serial,
                             );
-                            self.set_feedback(connection, sender_id, description, serial)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_strip_v2", object_id : % = sender_id, opcode
+                                = 0u16, message = "set_feedback", direction = "in",
+                                "args.description" : % = description, "args.serial" : % =
+                                serial; "zwp_tablet_pad_strip_v2.set_feedback"
+                            );
+                            self.set_feedback(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    description,
+                                    serial,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_pad_strip_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_strip_v2", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_strip_v2", object_id : % = sender_id, opcode
+                                = 1u16, message = "destroy", direction = "in";
+                                "zwp_tablet_pad_strip_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A pad group describes a distinct (sub)set of buttons, rings and strips"]
-    #[doc = "present in the tablet. The criteria of this grouping is usually positional,"]
-    #[doc = "eg. if a tablet has buttons on the left and right side, 2 groups will be"]
-    #[doc = "presented. The physical arrangement of groups is undisclosed and may"]
-    #[doc = "change on the fly."]
-    #[doc = ""]
-    #[doc = "Pad groups will announce their features during pad initialization. Between"]
-    #[doc = "the corresponding zwp_tablet_pad_v2.group event and zwp_tablet_pad_group_v2.done, the"]
-    #[doc = "pad group will announce the buttons, rings and strips contained in it,"]
-    #[doc = "plus the number of supported modes."]
-    #[doc = ""]
-    #[doc = "Modes are a mechanism to allow multiple groups of actions for every element"]
-    #[doc = "in the pad group. The number of groups and available modes in each is"]
-    #[doc = "persistent across device plugs. The current mode is user-switchable, it"]
-    #[doc = "will be announced through the zwp_tablet_pad_group_v2.mode_switch event both"]
-    #[doc = "whenever it is switched, and after zwp_tablet_pad_v2.enter."]
-    #[doc = ""]
-    #[doc = "The current mode logically applies to all elements in the pad group,"]
-    #[doc = "although it is at clients' discretion whether to actually perform different"]
-    #[doc = "actions, and/or issue the respective .set_feedback requests to notify the"]
-    #[doc = "compositor. See the zwp_tablet_pad_group_v2.mode_switch event for more details."]
+    ///A pad group describes a distinct (sub)set of buttons, rings and strips
+    ///present in the tablet. The criteria of this grouping is usually positional,
+    ///eg. if a tablet has buttons on the left and right side, 2 groups will be
+    ///presented. The physical arrangement of groups is undisclosed and may
+    ///change on the fly.
+    ///
+    ///Pad groups will announce their features during pad initialization. Between
+    ///the corresponding zwp_tablet_pad_v2.group event and zwp_tablet_pad_group_v2.done, the
+    ///pad group will announce the buttons, rings and strips contained in it,
+    ///plus the number of supported modes.
+    ///
+    ///Modes are a mechanism to allow multiple groups of actions for every element
+    ///in the pad group. The number of groups and available modes in each is
+    ///persistent across device plugs. The current mode is user-switchable, it
+    ///will be announced through the zwp_tablet_pad_group_v2.mode_switch event both
+    ///whenever it is switched, and after zwp_tablet_pad_v2.enter.
+    ///
+    ///The current mode logically applies to all elements in the pad group,
+    ///although it is at clients' discretion whether to actually perform different
+    ///actions, and/or issue the respective .set_feedback requests to notify the
+    ///compositor. See the zwp_tablet_pad_group_v2.mode_switch event for more details.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_pad_group_v2 {
-        #[doc = "Trait to implement the zwp_tablet_pad_group_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletPadGroupV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_pad_group_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "Destroy the zwp_tablet_pad_group_v2 object. Objects created from this object"]
-            #[doc = "are unaffected and should be destroyed separately."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent on zwp_tablet_pad_group_v2 initialization to announce the available"]
-            #[doc = "buttons in the group. Button indices start at 0, a button may only be"]
-            #[doc = "in one group at a time."]
-            #[doc = ""]
-            #[doc = "This event is first sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_group_v2.done event."]
-            #[doc = ""]
-            #[doc = "Some buttons are reserved by the compositor. These buttons may not be"]
-            #[doc = "assigned to any zwp_tablet_pad_group_v2. Compositors may broadcast this"]
-            #[doc = "event in the case of changes to the mapping of these reserved buttons."]
-            #[doc = "If the compositor happens to reserve all buttons in a group, this event"]
-            #[doc = "will be sent with an empty array."]
-            fn buttons(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_pad_group_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the buttons message was introduced in
+            pub const BUTTONS_SINCE: u32 = 1u32;
+            ///The interface version the buttons message was deprecated in, if any
+            pub const BUTTONS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_group_v2 initialization to announce the available
+            ///buttons in the group. Button indices start at 0, a button may only be
+            ///in one group at a time.
+            ///
+            ///This event is first sent in the initial burst of events before the
+            ///zwp_tablet_pad_group_v2.done event.
+            ///
+            ///Some buttons are reserved by the compositor. These buttons may not be
+            ///assigned to any zwp_tablet_pad_group_v2. Compositors may broadcast this
+            ///event in the case of changes to the mapping of these reserved buttons.
+            ///If the compositor happens to reserve all buttons in a group, this event
+            ///will be sent with an empty array.
+            pub fn buttons<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 buttons: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_group_v2#{}.buttons(array[{}])",
-                        sender_id,
-                        buttons.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_array(buttons).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 0u16,
+                    message = "buttons", direction = "out", "args.buttons" = % buttons
+                    .len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "buttons", direction = "out", "args.buttons" : % = buttons
+                    .len(); "zwp_tablet_pad_group_v2.buttons"
+                );
+                let payload = waynest::PayloadBuilder::new().put_array(buttons).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent on zwp_tablet_pad_group_v2 initialization to announce available rings."]
-            #[doc = "One event is sent for each ring available on this pad group."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_group_v2.done event."]
-            fn ring(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the ring message was introduced in
+            pub const RING_SINCE: u32 = 1u32;
+            ///The interface version the ring message was deprecated in, if any
+            pub const RING_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_group_v2 initialization to announce available rings.
+            ///One event is sent for each ring available on this pad group.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_group_v2.done event.
+            pub fn ring<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 ring: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_group_v2#{}.ring({})", sender_id, ring);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(ring))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 1u16,
+                    message = "ring", direction = "out", "args.ring" = % ring,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "ring", direction = "out", "args.ring" : % = ring;
+                    "zwp_tablet_pad_group_v2.ring"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(ring))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent on zwp_tablet_pad_v2 initialization to announce available strips."]
-            #[doc = "One event is sent for each strip available on this pad group."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_group_v2.done event."]
-            fn strip(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the strip message was introduced in
+            pub const STRIP_SINCE: u32 = 1u32;
+            ///The interface version the strip message was deprecated in, if any
+            pub const STRIP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_v2 initialization to announce available strips.
+            ///One event is sent for each strip available on this pad group.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_group_v2.done event.
+            pub fn strip<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 strip: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_group_v2#{}.strip({})", sender_id, strip);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(strip))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 2u16,
+                    message = "strip", direction = "out", "args.strip" = % strip,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 2u16,
+                    message = "strip", direction = "out", "args.strip" : % = strip;
+                    "zwp_tablet_pad_group_v2.strip"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(strip))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent on zwp_tablet_pad_group_v2 initialization to announce that the pad"]
-            #[doc = "group may switch between modes. A client may use a mode to store a"]
-            #[doc = "specific configuration for buttons, rings and strips and use the"]
-            #[doc = "zwp_tablet_pad_group_v2.mode_switch event to toggle between these"]
-            #[doc = "configurations. Mode indices start at 0."]
-            #[doc = ""]
-            #[doc = "Switching modes is compositor-dependent. See the"]
-            #[doc = "zwp_tablet_pad_group_v2.mode_switch event for more details."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_group_v2.done event. This event is only sent when"]
-            #[doc = "more than one mode is available."]
-            fn modes(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the modes message was introduced in
+            pub const MODES_SINCE: u32 = 1u32;
+            ///The interface version the modes message was deprecated in, if any
+            pub const MODES_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_group_v2 initialization to announce that the pad
+            ///group may switch between modes. A client may use a mode to store a
+            ///specific configuration for buttons, rings and strips and use the
+            ///zwp_tablet_pad_group_v2.mode_switch event to toggle between these
+            ///configurations. Mode indices start at 0.
+            ///
+            ///Switching modes is compositor-dependent. See the
+            ///zwp_tablet_pad_group_v2.mode_switch event for more details.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_group_v2.done event. This event is only sent when
+            ///more than one mode is available.
+            pub fn modes<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 modes: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_group_v2#{}.modes({})", sender_id, modes);
-                    let payload = waynest::PayloadBuilder::new().put_uint(modes).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 3u16,
+                    message = "modes", direction = "out", "args.modes" = % modes,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 3u16,
+                    message = "modes", direction = "out", "args.modes" : % = modes;
+                    "zwp_tablet_pad_group_v2.modes"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(modes).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent immediately to signal the end of the initial"]
-            #[doc = "burst of descriptive events. A client may consider the static"]
-            #[doc = "description of the tablet to be complete and finalize initialization"]
-            #[doc = "of the tablet group."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_group_v2#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent immediately to signal the end of the initial
+            ///burst of descriptive events. A client may consider the static
+            ///description of the tablet to be complete and finalize initialization
+            ///of the tablet group.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 4u16,
+                    message = "done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 4u16,
+                    message = "done", direction = "out"; "zwp_tablet_pad_group_v2.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that the mode was switched."]
-            #[doc = ""]
-            #[doc = "A mode applies to all buttons, rings, strips and dials in a group"]
-            #[doc = "simultaneously, but a client is not required to assign different actions"]
-            #[doc = "for each mode. For example, a client may have mode-specific button"]
-            #[doc = "mappings but map the ring to vertical scrolling in all modes. Mode"]
-            #[doc = "indices start at 0."]
-            #[doc = ""]
-            #[doc = "Switching modes is compositor-dependent. The compositor may provide"]
-            #[doc = "visual cues to the user about the mode, e.g. by toggling LEDs on"]
-            #[doc = "the tablet device. Mode-switching may be software-controlled or"]
-            #[doc = "controlled by one or more physical buttons. For example, on a Wacom"]
-            #[doc = "Intuos Pro, the button inside the ring may be assigned to switch"]
-            #[doc = "between modes."]
-            #[doc = ""]
-            #[doc = "The compositor will also send this event after zwp_tablet_pad_v2.enter on"]
-            #[doc = "each group in order to notify of the current mode. Groups that only"]
-            #[doc = "feature one mode will use mode=0 when emitting this event."]
-            #[doc = ""]
-            #[doc = "If a button action in the new mode differs from the action in the"]
-            #[doc = "previous mode, the client should immediately issue a"]
-            #[doc = "zwp_tablet_pad_v2.set_feedback request for each changed button."]
-            #[doc = ""]
-            #[doc = "If a ring, strip or dial action in the new mode differs from the action"]
-            #[doc = "in the previous mode, the client should immediately issue a"]
-            #[doc = "zwp_tablet_ring_v2.set_feedback, zwp_tablet_strip_v2.set_feedback or"]
-            #[doc = "zwp_tablet_dial_v2.set_feedback request for each changed ring, strip or dial."]
-            fn mode_switch(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the mode_switch message was introduced in
+            pub const MODE_SWITCH_SINCE: u32 = 1u32;
+            ///The interface version the mode_switch message was deprecated in, if any
+            pub const MODE_SWITCH_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that the mode was switched.
+            ///
+            ///A mode applies to all buttons, rings, strips and dials in a group
+            ///simultaneously, but a client is not required to assign different actions
+            ///for each mode. For example, a client may have mode-specific button
+            ///mappings but map the ring to vertical scrolling in all modes. Mode
+            ///indices start at 0.
+            ///
+            ///Switching modes is compositor-dependent. The compositor may provide
+            ///visual cues to the user about the mode, e.g. by toggling LEDs on
+            ///the tablet device. Mode-switching may be software-controlled or
+            ///controlled by one or more physical buttons. For example, on a Wacom
+            ///Intuos Pro, the button inside the ring may be assigned to switch
+            ///between modes.
+            ///
+            ///The compositor will also send this event after zwp_tablet_pad_v2.enter on
+            ///each group in order to notify of the current mode. Groups that only
+            ///feature one mode will use mode=0 when emitting this event.
+            ///
+            ///If a button action in the new mode differs from the action in the
+            ///previous mode, the client should immediately issue a
+            ///zwp_tablet_pad_v2.set_feedback request for each changed button.
+            ///
+            ///If a ring, strip or dial action in the new mode differs from the action
+            ///in the previous mode, the client should immediately issue a
+            ///zwp_tablet_ring_v2.set_feedback, zwp_tablet_strip_v2.set_feedback or
+            ///zwp_tablet_dial_v2.set_feedback request for each changed ring, strip or dial.
+            pub fn mode_switch<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 serial: u32,
                 mode: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_group_v2#{}.mode_switch({}, {}, {})",
-                        sender_id,
-                        time,
-                        serial,
-                        mode
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_uint(serial)
-                        .put_uint(mode)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 5u16,
+                    message = "mode_switch", direction = "out", "args.time" = % time,
+                    "args.serial" = % serial, "args.mode" = % mode,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 5u16,
+                    message = "mode_switch", direction = "out", "args.time" : % = time,
+                    "args.serial" : % = serial, "args.mode" : % = mode;
+                    "zwp_tablet_pad_group_v2.mode_switch"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_uint(serial)
+                    .put_uint(mode)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent on zwp_tablet_pad_v2 initialization to announce available dials."]
-            #[doc = "One event is sent for each dial available on this pad group."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_group_v2.done event."]
-            fn dial(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the dial message was introduced in
+            pub const DIAL_SINCE: u32 = 2u32;
+            ///The interface version the dial message was deprecated in, if any
+            pub const DIAL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_v2 initialization to announce available dials.
+            ///One event is sent for each dial available on this pad group.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_group_v2.done event.
+            ///
+            ///Available since version 2
+            pub fn dial<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 dial: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_group_v2#{}.dial({})", sender_id, dial);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(dial))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode = 6u16,
+                    message = "dial", direction = "out", "args.dial" = % dial,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode = 6u16,
+                    message = "dial", direction = "out", "args.dial" : % = dial;
+                    "zwp_tablet_pad_group_v2.dial"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(dial))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_pad_group_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_pad_group_v2` interface",
+            label = "`ZwpTabletPadGroupV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletPadGroupV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_pad_group_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the zwp_tablet_pad_group_v2 object. Objects created from this object
+            ///are unaffected and should be destroyed separately.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_pad_group_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_group_v2", object_id = % sender_id, opcode =
+                                0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_group_v2", object_id : % = sender_id, opcode
+                                = 0u16, message = "destroy", direction = "in";
+                                "zwp_tablet_pad_group_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A pad device is a set of buttons, rings, strips and dials"]
-    #[doc = "usually physically present on the tablet device itself. Some"]
-    #[doc = "exceptions exist where the pad device is physically detached, e.g. the"]
-    #[doc = "Wacom ExpressKey Remote."]
-    #[doc = ""]
-    #[doc = "Pad devices have no axes that control the cursor and are generally"]
-    #[doc = "auxiliary devices to the tool devices used on the tablet surface."]
-    #[doc = ""]
-    #[doc = "A pad device has a number of static characteristics, e.g. the number"]
-    #[doc = "of rings. These capabilities are sent in an event sequence after the"]
-    #[doc = "zwp_tablet_seat_v2.pad_added event before any actual events from this pad."]
-    #[doc = "This initial event sequence is terminated by a zwp_tablet_pad_v2.done"]
-    #[doc = "event."]
-    #[doc = ""]
-    #[doc = "All pad features (buttons, rings, strips and dials) are logically divided into"]
-    #[doc = "groups and all pads have at least one group. The available groups are"]
-    #[doc = "notified through the zwp_tablet_pad_v2.group event; the compositor will"]
-    #[doc = "emit one event per group before emitting zwp_tablet_pad_v2.done."]
-    #[doc = ""]
-    #[doc = "Groups may have multiple modes. Modes allow clients to map multiple"]
-    #[doc = "actions to a single pad feature. Only one mode can be active per group,"]
-    #[doc = "although different groups may have different active modes."]
+    ///A pad device is a set of buttons, rings, strips and dials
+    ///usually physically present on the tablet device itself. Some
+    ///exceptions exist where the pad device is physically detached, e.g. the
+    ///Wacom ExpressKey Remote.
+    ///
+    ///Pad devices have no axes that control the cursor and are generally
+    ///auxiliary devices to the tool devices used on the tablet surface.
+    ///
+    ///A pad device has a number of static characteristics, e.g. the number
+    ///of rings. These capabilities are sent in an event sequence after the
+    ///zwp_tablet_seat_v2.pad_added event before any actual events from this pad.
+    ///This initial event sequence is terminated by a zwp_tablet_pad_v2.done
+    ///event.
+    ///
+    ///All pad features (buttons, rings, strips and dials) are logically divided into
+    ///groups and all pads have at least one group. The available groups are
+    ///notified through the zwp_tablet_pad_v2.group event; the compositor will
+    ///emit one event per group before emitting zwp_tablet_pad_v2.done.
+    ///
+    ///Groups may have multiple modes. Modes allow clients to map multiple
+    ///actions to a single pad feature. Only one mode can be active per group,
+    ///although different groups may have different active modes.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_pad_v2 {
-        #[doc = "Describes the physical state of a button that caused the button"]
-        #[doc = "event."]
+        ///Describes the physical state of a button that caused the button
+        ///event.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum ButtonState {
-            #[doc = "the button is not pressed"]
+            ///the button is not pressed
             Released = 0u32,
-            #[doc = "the button is pressed"]
+            ///the button is pressed
             Pressed = 1u32,
         }
+        #[allow(deprecated)]
+        impl ButtonState {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Released => 1u32,
+                    Self::Pressed => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Released => None,
+                    Self::Pressed => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("released", Self::Released),
+                ("pressed", Self::Pressed),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Released => "released",
+                    Self::Pressed => "pressed",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<ButtonState> for u32 {
             fn from(value: ButtonState) -> Self {
                 value as u32
             }
         }
+        impl From<ButtonState> for i32 {
+            fn from(value: ButtonState) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for ButtonState {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
                 match v {
                     0u32 => Ok(Self::Released),
                     1u32 => Ok(Self::Pressed),
-                    _ => Err(waynest::ProtocolError::MalformedPayload),
-                }
-            }
-        }
-        impl std::fmt::Display for ButtonState {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
-            }
-        }
-        #[doc = "Trait to implement the zwp_tablet_pad_v2 interface. See the module level documentation for more info"]
-        pub trait ZwpTabletPadV2
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwp_tablet_pad_v2";
-            const VERSION: u32 = 2u32;
-            #[doc = "Requests the compositor to use the provided feedback string"]
-            #[doc = "associated with this button. This request should be issued immediately"]
-            #[doc = "after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding"]
-            #[doc = "group is received, or whenever a button is mapped to a different"]
-            #[doc = "action. See zwp_tablet_pad_group_v2.mode_switch for more details."]
-            #[doc = ""]
-            #[doc = "Clients are encouraged to provide context-aware descriptions for"]
-            #[doc = "the actions associated with each button, and compositors may use"]
-            #[doc = "this information to offer visual feedback on the button layout"]
-            #[doc = "(e.g. on-screen displays)."]
-            #[doc = ""]
-            #[doc = "Button indices start at 0. Setting the feedback string on a button"]
-            #[doc = "that is reserved by the compositor (i.e. not belonging to any"]
-            #[doc = "zwp_tablet_pad_group_v2) does not generate an error but the compositor"]
-            #[doc = "is free to ignore the request."]
-            #[doc = ""]
-            #[doc = "The provided string 'description' is a UTF-8 encoded string to be"]
-            #[doc = "associated with this ring, and is considered user-visible; general"]
-            #[doc = "internationalization rules apply."]
-            #[doc = ""]
-            #[doc = "The serial argument will be that of the last"]
-            #[doc = "zwp_tablet_pad_group_v2.mode_switch event received for the group of this"]
-            #[doc = "button. Requests providing other serials than the most recent one will"]
-            #[doc = "be ignored."]
-            fn set_feedback(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                button: u32,
-                description: String,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroy the zwp_tablet_pad_v2 object. Objects created from this object"]
-            #[doc = "are unaffected and should be destroyed separately."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent on zwp_tablet_pad_v2 initialization to announce available groups."]
-            #[doc = "One event is sent for each pad group available."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_v2.done event. At least one group will be announced."]
-            fn group(
-                &self,
-                connection: &mut Self::Connection,
+                    _ => Err(waynest::ProtocolError::MalformedPayload),
+                }
+            }
+        }
+        impl TryFrom<i32> for ButtonState {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
+        impl std::fmt::Display for ButtonState {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "usu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_pad_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the group message was introduced in
+            pub const GROUP_SINCE: u32 = 1u32;
+            ///The interface version the group message was deprecated in, if any
+            pub const GROUP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_v2 initialization to announce available groups.
+            ///One event is sent for each pad group available.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_v2.done event. At least one group will be announced.
+            pub fn group<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 pad_group: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_v2#{}.group({})", sender_id, pad_group);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(pad_group))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 0u16, message
+                    = "group", direction = "out", "args.pad_group" = % pad_group,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "group", direction = "out", "args.pad_group" : % =
+                    pad_group; "zwp_tablet_pad_v2.group"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(pad_group))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "A system-specific device path that indicates which device is behind"]
-            #[doc = "this zwp_tablet_pad_v2. This information may be used to gather additional"]
-            #[doc = "information about the device, e.g. through libwacom."]
-            #[doc = ""]
-            #[doc = "The format of the path is unspecified, it may be a device node, a"]
-            #[doc = "sysfs path, or some other identifier. It is up to the client to"]
-            #[doc = "identify the string provided."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_v2.done event."]
-            fn path(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the path message was introduced in
+            pub const PATH_SINCE: u32 = 1u32;
+            ///The interface version the path message was deprecated in, if any
+            pub const PATH_DEPRECATED_SINCE: Option<u32> = None;
+            ///A system-specific device path that indicates which device is behind
+            ///this zwp_tablet_pad_v2. This information may be used to gather additional
+            ///information about the device, e.g. through libwacom.
+            ///
+            ///The format of the path is unspecified, it may be a device node, a
+            ///sysfs path, or some other identifier. It is up to the client to
+            ///identify the string provided.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_v2.done event.
+            pub fn path<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 path: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_v2#{}.path(\"{}\")", sender_id, path);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(path))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 1u16, message
+                    = "path", direction = "out", "args.path" = % path,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "path", direction = "out", "args.path" : % = path;
+                    "zwp_tablet_pad_v2.path"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(path))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent on zwp_tablet_pad_v2 initialization to announce the available"]
-            #[doc = "buttons."]
-            #[doc = ""]
-            #[doc = "This event is sent in the initial burst of events before the"]
-            #[doc = "zwp_tablet_pad_v2.done event. This event is only sent when at least one"]
-            #[doc = "button is available."]
-            fn buttons(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the buttons message was introduced in
+            pub const BUTTONS_SINCE: u32 = 1u32;
+            ///The interface version the buttons message was deprecated in, if any
+            pub const BUTTONS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent on zwp_tablet_pad_v2 initialization to announce the available
+            ///buttons.
+            ///
+            ///This event is sent in the initial burst of events before the
+            ///zwp_tablet_pad_v2.done event. This event is only sent when at least one
+            ///button is available.
+            pub fn buttons<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 buttons: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_v2#{}.buttons({})", sender_id, buttons);
-                    let payload = waynest::PayloadBuilder::new().put_uint(buttons).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 2u16, message
+                    = "buttons", direction = "out", "args.buttons" = % buttons,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 2u16,
+                    message = "buttons", direction = "out", "args.buttons" : % = buttons;
+                    "zwp_tablet_pad_v2.buttons"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(buttons).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event signals the end of the initial burst of descriptive"]
-            #[doc = "events. A client may consider the static description of the pad to"]
-            #[doc = "be complete and finalize initialization of the pad."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_v2#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event signals the end of the initial burst of descriptive
+            ///events. A client may consider the static description of the pad to
+            ///be complete and finalize initialization of the pad.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 3u16, message
+                    = "done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 3u16,
+                    message = "done", direction = "out"; "zwp_tablet_pad_v2.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent whenever the physical state of a button changes."]
-            fn button(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the button message was introduced in
+            pub const BUTTON_SINCE: u32 = 1u32;
+            ///The interface version the button message was deprecated in, if any
+            pub const BUTTON_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the physical state of a button changes.
+            pub fn button<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 button: u32,
-                state: ButtonState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_v2#{}.button({}, {}, {})",
-                        sender_id,
-                        time,
-                        button,
-                        state
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(time)
-                        .put_uint(button)
-                        .put_uint(state.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+                state_arg: ButtonState,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 4u16, message
+                    = "button", direction = "out", "args.time" = % time, "args.button" =
+                    % button, "args.state" = % state_arg,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 4u16,
+                    message = "button", direction = "out", "args.time" : % = time,
+                    "args.button" : % = button, "args.state" : % = state_arg;
+                    "zwp_tablet_pad_v2.button"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(time)
+                    .put_uint(button)
+                    .put_uint(state_arg.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this pad is focused on the specified surface."]
-            fn enter(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the enter message was introduced in
+            pub const ENTER_SINCE: u32 = 1u32;
+            ///The interface version the enter message was deprecated in, if any
+            pub const ENTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this pad is focused on the specified surface.
+            pub fn enter<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 tablet: waynest::ObjectId,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_v2#{}.enter({}, {}, {})",
-                        sender_id,
-                        serial,
-                        tablet,
-                        surface
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(tablet))
-                        .put_object(Some(surface))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 5u16, message
+                    = "enter", direction = "out", "args.serial" = % serial, "args.tablet"
+                    = % tablet, "args.surface" = % surface,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 5u16,
+                    message = "enter", direction = "out", "args.serial" : % = serial,
+                    "args.tablet" : % = tablet, "args.surface" : % = surface;
+                    "zwp_tablet_pad_v2.enter"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(tablet))
+                    .put_object(Some(surface))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Notification that this pad is no longer focused on the specified"]
-            #[doc = "surface."]
-            fn leave(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the leave message was introduced in
+            pub const LEAVE_SINCE: u32 = 1u32;
+            ///The interface version the leave message was deprecated in, if any
+            pub const LEAVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notification that this pad is no longer focused on the specified
+            ///surface.
+            pub fn leave<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial: u32,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_v2#{}.leave({}, {})",
-                        sender_id,
-                        serial,
-                        surface
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_object(Some(surface))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 6u16, message
+                    = "leave", direction = "out", "args.serial" = % serial,
+                    "args.surface" = % surface,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 6u16,
+                    message = "leave", direction = "out", "args.serial" : % = serial,
+                    "args.surface" : % = surface; "zwp_tablet_pad_v2.leave"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_object(Some(surface))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Sent when the pad has been removed from the system. When a tablet"]
-            #[doc = "is removed its pad(s) will be removed too."]
-            #[doc = ""]
-            #[doc = "When this event is received, the client must destroy all rings, strips"]
-            #[doc = "and groups that were offered by this pad, and issue zwp_tablet_pad_v2.destroy"]
-            #[doc = "the pad itself."]
-            fn removed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_v2#{}.removed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the removed message was introduced in
+            pub const REMOVED_SINCE: u32 = 1u32;
+            ///The interface version the removed message was deprecated in, if any
+            pub const REMOVED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent when the pad has been removed from the system. When a tablet
+            ///is removed its pad(s) will be removed too.
+            ///
+            ///When this event is received, the client must destroy all rings, strips
+            ///and groups that were offered by this pad, and issue zwp_tablet_pad_v2.destroy
+            ///the pad itself.
+            pub fn removed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 7u16, message
+                    = "removed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_v2", object_id : % = sender_id, opcode = 7u16,
+                    message = "removed", direction = "out"; "zwp_tablet_pad_v2.removed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 7u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwp_tablet_pad_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_pad_v2` interface",
+            label = "`ZwpTabletPadV2` is not implemented for `{Self}`"
+        )]
+        pub trait ZwpTabletPadV2
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwp_tablet_pad_v2";
+            const VERSION: u32 = 2u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_feedback message was introduced in
+            const SET_FEEDBACK_SINCE: u32 = 1u32;
+            ///The interface version the set_feedback message was deprecated in, if any
+            const SET_FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests the compositor to use the provided feedback string
+            ///associated with this button. This request should be issued immediately
+            ///after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+            ///group is received, or whenever a button is mapped to a different
+            ///action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+            ///
+            ///Clients are encouraged to provide context-aware descriptions for
+            ///the actions associated with each button, and compositors may use
+            ///this information to offer visual feedback on the button layout
+            ///(e.g. on-screen displays).
+            ///
+            ///Button indices start at 0. Setting the feedback string on a button
+            ///that is reserved by the compositor (i.e. not belonging to any
+            ///zwp_tablet_pad_group_v2) does not generate an error but the compositor
+            ///is free to ignore the request.
+            ///
+            ///The provided string 'description' is a UTF-8 encoded string to be
+            ///associated with this ring, and is considered user-visible; general
+            ///internationalization rules apply.
+            ///
+            ///The serial argument will be that of the last
+            ///zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+            ///button. Requests providing other serials than the most recent one will
+            ///be ignored.
+            fn set_feedback(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                button: u32,
+                description: String,
+                serial: u32,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the zwp_tablet_pad_v2 object. Objects created from this object
+            ///are unaffected and should be destroyed separately.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3848,143 +6498,252 @@ pub mod tablet_v2 {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_tablet_pad_v2#{}.set_feedback({}, \"{}\", {})",
-                                sender_id,
-                                button,
-                                description,
-                                serial
-                            );
-                            self.set_feedback(connection, sender_id, button, description, serial)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 0u16,
+                                message = "set_feedback", direction = "in", "args.button" =
+                                % button, "args.description" = % description, "args.serial"
+                                = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_v2", object_id : % = sender_id, opcode =
+                                0u16, message = "set_feedback", direction = "in",
+                                "args.button" : % = button, "args.description" : % =
+                                description, "args.serial" : % = serial;
+                                "zwp_tablet_pad_v2.set_feedback"
+                            );
+                            self.set_feedback(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    button,
+                                    description,
+                                    serial,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_pad_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_v2", object_id = % sender_id, opcode = 1u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_v2", object_id : % = sender_id, opcode =
+                                1u16, message = "destroy", direction = "in";
+                                "zwp_tablet_pad_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A rotary control, e.g. a dial or a wheel."]
-    #[doc = ""]
-    #[doc = "Events on a dial are logically grouped by the zwp_tablet_pad_dial_v2.frame"]
-    #[doc = "event."]
+    ///A rotary control, e.g. a dial or a wheel.
+    ///
+    ///Events on a dial are logically grouped by the zwp_tablet_pad_dial_v2.frame
+    ///event.
     #[allow(clippy::too_many_arguments)]
     pub mod zwp_tablet_pad_dial_v2 {
-        #[doc = "Trait to implement the zwp_tablet_pad_dial_v2 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_feedback",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "su",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "description",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwp_tablet_pad_dial_v2 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the delta message was introduced in
+            pub const DELTA_SINCE: u32 = 1u32;
+            ///The interface version the delta message was deprecated in, if any
+            pub const DELTA_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent whenever the position on a dial changes.
+            ///
+            ///This event carries the wheel delta as multiples or fractions
+            ///of 120 with each multiple of 120 representing one logical wheel detent.
+            ///For example, an axis_value120 of 30 is one quarter of
+            ///a logical wheel step in the positive direction, a value120 of
+            ///-240 are two logical wheel steps in the negative direction within the
+            ///same hardware event. See the wl_pointer.axis_value120 for more details.
+            ///
+            ///The value120 must not be zero.
+            pub fn delta<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                value120: i32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_dial_v2", object_id = % sender_id, opcode = 0u16,
+                    message = "delta", direction = "out", "args.value120" = % value120,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_dial_v2", object_id : % = sender_id, opcode = 0u16,
+                    message = "delta", direction = "out", "args.value120" : % = value120;
+                    "zwp_tablet_pad_dial_v2.delta"
+                );
+                let payload = waynest::PayloadBuilder::new().put_int(value120).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the end of a set of events that represent one logical
+            ///hardware dial event. A client is expected to accumulate the data
+            ///in all events within the frame before proceeding.
+            ///
+            ///All zwp_tablet_pad_dial_v2 events before a zwp_tablet_pad_dial_v2.frame event belong
+            ///logically together.
+            ///
+            ///A zwp_tablet_pad_dial_v2.frame event is sent for every logical event
+            ///group, even if the group only contains a single zwp_tablet_pad_dial_v2
+            ///event. Specifically, a client may get a sequence: delta, frame,
+            ///delta, frame, etc.
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                time: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwp_tablet_pad_dial_v2", object_id = % sender_id, opcode = 1u16,
+                    message = "frame", direction = "out", "args.time" = % time,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwp_tablet_pad_dial_v2", object_id : % = sender_id, opcode = 1u16,
+                    message = "frame", direction = "out", "args.time" : % = time;
+                    "zwp_tablet_pad_dial_v2.frame"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(time).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the zwp_tablet_pad_dial_v2 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwp_tablet_pad_dial_v2` interface",
+            label = "`ZwpTabletPadDialV2` is not implemented for `{Self}`"
+        )]
         pub trait ZwpTabletPadDialV2
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwp_tablet_pad_dial_v2";
             const VERSION: u32 = 2u32;
-            #[doc = "Requests the compositor to use the provided feedback string"]
-            #[doc = "associated with this dial. This request should be issued immediately"]
-            #[doc = "after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding"]
-            #[doc = "group is received, or whenever the dial is mapped to a different"]
-            #[doc = "action. See zwp_tablet_pad_group_v2.mode_switch for more details."]
-            #[doc = ""]
-            #[doc = "Clients are encouraged to provide context-aware descriptions for"]
-            #[doc = "the actions associated with the dial, and compositors may use this"]
-            #[doc = "information to offer visual feedback about the button layout"]
-            #[doc = "(eg. on-screen displays)."]
-            #[doc = ""]
-            #[doc = "The provided string 'description' is a UTF-8 encoded string to be"]
-            #[doc = "associated with this ring, and is considered user-visible; general"]
-            #[doc = "internationalization rules apply."]
-            #[doc = ""]
-            #[doc = "The serial argument will be that of the last"]
-            #[doc = "zwp_tablet_pad_group_v2.mode_switch event received for the group of this"]
-            #[doc = "dial. Requests providing other serials than the most recent one will be"]
-            #[doc = "ignored."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_feedback message was introduced in
+            const SET_FEEDBACK_SINCE: u32 = 1u32;
+            ///The interface version the set_feedback message was deprecated in, if any
+            const SET_FEEDBACK_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests the compositor to use the provided feedback string
+            ///associated with this dial. This request should be issued immediately
+            ///after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+            ///group is received, or whenever the dial is mapped to a different
+            ///action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+            ///
+            ///Clients are encouraged to provide context-aware descriptions for
+            ///the actions associated with the dial, and compositors may use this
+            ///information to offer visual feedback about the button layout
+            ///(eg. on-screen displays).
+            ///
+            ///The provided string 'description' is a UTF-8 encoded string to be
+            ///associated with this ring, and is considered user-visible; general
+            ///internationalization rules apply.
+            ///
+            ///The serial argument will be that of the last
+            ///zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+            ///dial. Requests providing other serials than the most recent one will be
+            ///ignored.
             fn set_feedback(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 description: String,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This destroys the client's resource for this dial object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This destroys the client's resource for this dial object.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent whenever the position on a dial changes."]
-            #[doc = ""]
-            #[doc = "This event carries the wheel delta as multiples or fractions"]
-            #[doc = "of 120 with each multiple of 120 representing one logical wheel detent."]
-            #[doc = "For example, an axis_value120 of 30 is one quarter of"]
-            #[doc = "a logical wheel step in the positive direction, a value120 of"]
-            #[doc = "-240 are two logical wheel steps in the negative direction within the"]
-            #[doc = "same hardware event. See the wl_pointer.axis_value120 for more details."]
-            #[doc = ""]
-            #[doc = "The value120 must not be zero."]
-            fn delta(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                value120: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwp_tablet_pad_dial_v2#{}.delta({})",
-                        sender_id,
-                        value120
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_int(value120).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "Indicates the end of a set of events that represent one logical"]
-            #[doc = "hardware dial event. A client is expected to accumulate the data"]
-            #[doc = "in all events within the frame before proceeding."]
-            #[doc = ""]
-            #[doc = "All zwp_tablet_pad_dial_v2 events before a zwp_tablet_pad_dial_v2.frame event belong"]
-            #[doc = "logically together."]
-            #[doc = ""]
-            #[doc = "A zwp_tablet_pad_dial_v2.frame event is sent for every logical event"]
-            #[doc = "group, even if the group only contains a single zwp_tablet_pad_dial_v2"]
-            #[doc = "event. Specifically, a client may get a sequence: delta, frame,"]
-            #[doc = "delta, frame, etc."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                time: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwp_tablet_pad_dial_v2#{}.frame({})", sender_id, time);
-                    let payload = waynest::PayloadBuilder::new().put_uint(time).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3994,49 +6753,121 @@ pub mod tablet_v2 {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwp_tablet_pad_dial_v2#{}.set_feedback(\"{}\", {})",
-                                sender_id,
-                                description,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_dial_v2", object_id = % sender_id, opcode =
+                                0u16, message = "set_feedback", direction = "in",
+                                "args.description" = % description, "args.serial" = %
+                                serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_dial_v2", object_id : % = sender_id, opcode
+                                = 0u16, message = "set_feedback", direction = "in",
+                                "args.description" : % = description, "args.serial" : % =
+                                serial; "zwp_tablet_pad_dial_v2.set_feedback"
                             );
-                            self.set_feedback(connection, sender_id, description, serial)
+                            self.set_feedback(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    description,
+                                    serial,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwp_tablet_pad_dial_v2#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwp_tablet_pad_dial_v2", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwp_tablet_pad_dial_v2", object_id : % = sender_id, opcode
+                                = 1u16, message = "destroy", direction = "in";
+                                "zwp_tablet_pad_dial_v2.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "viewporter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "viewporter")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod viewporter {
-    #[doc = "The global interface exposing surface cropping and scaling"]
-    #[doc = "capabilities is used to instantiate an interface extension for a"]
-    #[doc = "wl_surface object. This extended interface will then allow"]
-    #[doc = "cropping and scaling the surface contents, effectively"]
-    #[doc = "disconnecting the direct relationship between the buffer and the"]
-    #[doc = "surface size."]
+    ///The global interface exposing surface cropping and scaling
+    ///capabilities is used to instantiate an interface extension for a
+    ///wl_surface object. This extended interface will then allow
+    ///cropping and scaling the surface contents, effectively
+    ///disconnecting the direct relationship between the buffer and the
+    ///surface size.
     #[allow(clippy::too_many_arguments)]
     pub mod wp_viewporter {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "the surface already has a viewport object associated"]
+            ///the surface already has a viewport object associated
             ViewportExists = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::ViewportExists => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::ViewportExists => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("viewport_exists", Self::ViewportExists),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::ViewportExists => "viewport_exists",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4046,52 +6877,132 @@ pub mod viewporter {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wp_viewporter interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_viewport",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("wp_viewport"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wp_viewporter interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wp_viewporter interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wp_viewporter` interface",
+            label = "`WpViewporter` is not implemented for `{Self}`"
+        )]
         pub trait WpViewporter
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wp_viewporter";
             const VERSION: u32 = 1u32;
-            #[doc = "Informs the server that the client will not be using this"]
-            #[doc = "protocol object anymore. This does not affect any other objects,"]
-            #[doc = "wp_viewport objects included."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Informs the server that the client will not be using this
+            ///protocol object anymore. This does not affect any other objects,
+            ///wp_viewport objects included.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Instantiate an interface extension for the given wl_surface to"]
-            #[doc = "crop and scale its content. If the given wl_surface already has"]
-            #[doc = "a wp_viewport object associated, the viewport_exists"]
-            #[doc = "protocol error is raised."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_viewport message was introduced in
+            const GET_VIEWPORT_SINCE: u32 = 1u32;
+            ///The interface version the get_viewport message was deprecated in, if any
+            const GET_VIEWPORT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Instantiate an interface extension for the given wl_surface to
+            ///crop and scale its content. If the given wl_surface already has
+            ///a wp_viewport object associated, the viewport_exists
+            ///protocol error is raised.
             fn get_viewport(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wp_viewporter#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_viewporter", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_viewporter", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in";
+                                "wp_viewporter.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let id = message
@@ -4101,94 +7012,159 @@ pub mod viewporter {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wp_viewporter#{}.get_viewport({}, {})",
-                                sender_id,
-                                id,
-                                surface
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_viewporter", object_id = % sender_id, opcode = 1u16,
+                                message = "get_viewport", direction = "in", "args.id" = %
+                                id, "args.surface" = % surface,
                             );
-                            self.get_viewport(connection, sender_id, id, surface).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_viewporter", object_id : % = sender_id, opcode = 1u16,
+                                message = "get_viewport", direction = "in", "args.id" : % =
+                                id, "args.surface" : % = surface;
+                                "wp_viewporter.get_viewport"
+                            );
+                            self.get_viewport(connection, state, sender_id, id, surface)
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "An additional interface to a wl_surface object, which allows the"]
-    #[doc = "client to specify the cropping and scaling of the surface"]
-    #[doc = "contents."]
-    #[doc = ""]
-    #[doc = "This interface works with two concepts: the source rectangle (src_x,"]
-    #[doc = "src_y, src_width, src_height), and the destination size (dst_width,"]
-    #[doc = "dst_height). The contents of the source rectangle are scaled to the"]
-    #[doc = "destination size, and content outside the source rectangle is ignored."]
-    #[doc = "This state is double-buffered, see wl_surface.commit."]
-    #[doc = ""]
-    #[doc = "The two parts of crop and scale state are independent: the source"]
-    #[doc = "rectangle, and the destination size. Initially both are unset, that"]
-    #[doc = "is, no scaling is applied. The whole of the current wl_buffer is"]
-    #[doc = "used as the source, and the surface size is as defined in"]
-    #[doc = "wl_surface.attach."]
-    #[doc = ""]
-    #[doc = "If the destination size is set, it causes the surface size to become"]
-    #[doc = "dst_width, dst_height. The source (rectangle) is scaled to exactly"]
-    #[doc = "this size. This overrides whatever the attached wl_buffer size is,"]
-    #[doc = "unless the wl_buffer is NULL. If the wl_buffer is NULL, the surface"]
-    #[doc = "has no content and therefore no size. Otherwise, the size is always"]
-    #[doc = "at least 1x1 in surface local coordinates."]
-    #[doc = ""]
-    #[doc = "If the source rectangle is set, it defines what area of the wl_buffer is"]
-    #[doc = "taken as the source. If the source rectangle is set and the destination"]
-    #[doc = "size is not set, then src_width and src_height must be integers, and the"]
-    #[doc = "surface size becomes the source rectangle size. This results in cropping"]
-    #[doc = "without scaling. If src_width or src_height are not integers and"]
-    #[doc = "destination size is not set, the bad_size protocol error is raised when"]
-    #[doc = "the surface state is applied."]
-    #[doc = ""]
-    #[doc = "The coordinate transformations from buffer pixel coordinates up to"]
-    #[doc = "the surface-local coordinates happen in the following order:"]
-    #[doc = "1. buffer_transform (wl_surface.set_buffer_transform)"]
-    #[doc = "2. buffer_scale (wl_surface.set_buffer_scale)"]
-    #[doc = "3. crop and scale (wp_viewport.set*)"]
-    #[doc = "This means, that the source rectangle coordinates of crop and scale"]
-    #[doc = "are given in the coordinates after the buffer transform and scale,"]
-    #[doc = "i.e. in the coordinates that would be the surface-local coordinates"]
-    #[doc = "if the crop and scale was not applied."]
-    #[doc = ""]
-    #[doc = "If src_x or src_y are negative, the bad_value protocol error is raised."]
-    #[doc = "Otherwise, if the source rectangle is partially or completely outside of"]
-    #[doc = "the non-NULL wl_buffer, then the out_of_buffer protocol error is raised"]
-    #[doc = "when the surface state is applied. A NULL wl_buffer does not raise the"]
-    #[doc = "out_of_buffer error."]
-    #[doc = ""]
-    #[doc = "If the wl_surface associated with the wp_viewport is destroyed,"]
-    #[doc = "all wp_viewport requests except 'destroy' raise the protocol error"]
-    #[doc = "no_surface."]
-    #[doc = ""]
-    #[doc = "If the wp_viewport object is destroyed, the crop and scale"]
-    #[doc = "state is removed from the wl_surface. The change will be applied"]
-    #[doc = "on the next wl_surface.commit."]
+    ///An additional interface to a wl_surface object, which allows the
+    ///client to specify the cropping and scaling of the surface
+    ///contents.
+    ///
+    ///This interface works with two concepts: the source rectangle (src_x,
+    ///src_y, src_width, src_height), and the destination size (dst_width,
+    ///dst_height). The contents of the source rectangle are scaled to the
+    ///destination size, and content outside the source rectangle is ignored.
+    ///This state is double-buffered, see wl_surface.commit.
+    ///
+    ///The two parts of crop and scale state are independent: the source
+    ///rectangle, and the destination size. Initially both are unset, that
+    ///is, no scaling is applied. The whole of the current wl_buffer is
+    ///used as the source, and the surface size is as defined in
+    ///wl_surface.attach.
+    ///
+    ///If the destination size is set, it causes the surface size to become
+    ///dst_width, dst_height. The source (rectangle) is scaled to exactly
+    ///this size. This overrides whatever the attached wl_buffer size is,
+    ///unless the wl_buffer is NULL. If the wl_buffer is NULL, the surface
+    ///has no content and therefore no size. Otherwise, the size is always
+    ///at least 1x1 in surface local coordinates.
+    ///
+    ///If the source rectangle is set, it defines what area of the wl_buffer is
+    ///taken as the source. If the source rectangle is set and the destination
+    ///size is not set, then src_width and src_height must be integers, and the
+    ///surface size becomes the source rectangle size. This results in cropping
+    ///without scaling. If src_width or src_height are not integers and
+    ///destination size is not set, the bad_size protocol error is raised when
+    ///the surface state is applied.
+    ///
+    ///The coordinate transformations from buffer pixel coordinates up to
+    ///the surface-local coordinates happen in the following order:
+    ///1. buffer_transform (wl_surface.set_buffer_transform)
+    ///2. buffer_scale (wl_surface.set_buffer_scale)
+    ///3. crop and scale (wp_viewport.set*)
+    ///This means, that the source rectangle coordinates of crop and scale
+    ///are given in the coordinates after the buffer transform and scale,
+    ///i.e. in the coordinates that would be the surface-local coordinates
+    ///if the crop and scale was not applied.
+    ///
+    ///If src_x or src_y are negative, the bad_value protocol error is raised.
+    ///Otherwise, if the source rectangle is partially or completely outside of
+    ///the non-NULL wl_buffer, then the out_of_buffer protocol error is raised
+    ///when the surface state is applied. A NULL wl_buffer does not raise the
+    ///out_of_buffer error.
+    ///
+    ///If the wl_surface associated with the wp_viewport is destroyed,
+    ///all wp_viewport requests except 'destroy' raise the protocol error
+    ///no_surface.
+    ///
+    ///If the wp_viewport object is destroyed, the crop and scale
+    ///state is removed from the wl_surface. The change will be applied
+    ///on the next wl_surface.commit.
     #[allow(clippy::too_many_arguments)]
     pub mod wp_viewport {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "negative or zero values in width or height"]
+            ///negative or zero values in width or height
             BadValue = 0u32,
-            #[doc = "destination size is not integer"]
+            ///destination size is not integer
             BadSize = 1u32,
-            #[doc = "source rectangle extends outside of the content area"]
+            ///source rectangle extends outside of the content area
             OutOfBuffer = 2u32,
-            #[doc = "the wl_surface was destroyed"]
+            ///the wl_surface was destroyed
             NoSurface = 3u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::BadValue => 1u32,
+                    Self::BadSize => 1u32,
+                    Self::OutOfBuffer => 1u32,
+                    Self::NoSurface => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::BadValue => None,
+                    Self::BadSize => None,
+                    Self::OutOfBuffer => None,
+                    Self::NoSurface => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("bad_value", Self::BadValue),
+                ("bad_size", Self::BadSize),
+                ("out_of_buffer", Self::OutOfBuffer),
+                ("no_surface", Self::NoSurface),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::BadValue => "bad_value",
+                    Self::BadSize => "bad_size",
+                    Self::OutOfBuffer => "out_of_buffer",
+                    Self::NoSurface => "no_surface",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4201,76 +7177,199 @@ pub mod viewporter {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the wp_viewport interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ffff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_destination",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the wp_viewport interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the wp_viewport interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `wp_viewport` interface",
+            label = "`WpViewport` is not implemented for `{Self}`"
+        )]
         pub trait WpViewport
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "wp_viewport";
             const VERSION: u32 = 1u32;
-            #[doc = "The associated wl_surface's crop and scale state is removed."]
-            #[doc = "The change is applied on the next wl_surface.commit."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///The associated wl_surface's crop and scale state is removed.
+            ///The change is applied on the next wl_surface.commit.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the source rectangle of the associated wl_surface. See"]
-            #[doc = "wp_viewport for the description, and relation to the wl_buffer"]
-            #[doc = "size."]
-            #[doc = ""]
-            #[doc = "If all of x, y, width and height are -1.0, the source rectangle is"]
-            #[doc = "unset instead. Any other set of values where width or height are zero"]
-            #[doc = "or negative, or x or y are negative, raise the bad_value protocol"]
-            #[doc = "error."]
-            #[doc = ""]
-            #[doc = "The crop and scale state is double-buffered, see wl_surface.commit."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_source message was introduced in
+            const SET_SOURCE_SINCE: u32 = 1u32;
+            ///The interface version the set_source message was deprecated in, if any
+            const SET_SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the source rectangle of the associated wl_surface. See
+            ///wp_viewport for the description, and relation to the wl_buffer
+            ///size.
+            ///
+            ///If all of x, y, width and height are -1.0, the source rectangle is
+            ///unset instead. Any other set of values where width or height are zero
+            ///or negative, or x or y are negative, raise the bad_value protocol
+            ///error.
+            ///
+            ///The crop and scale state is double-buffered, see wl_surface.commit.
             fn set_source(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: waynest::Fixed,
                 y: waynest::Fixed,
                 width: waynest::Fixed,
                 height: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the destination size of the associated wl_surface. See"]
-            #[doc = "wp_viewport for the description, and relation to the wl_buffer"]
-            #[doc = "size."]
-            #[doc = ""]
-            #[doc = "If width is -1 and height is -1, the destination size is unset"]
-            #[doc = "instead. Any other pair of values for width and height that"]
-            #[doc = "contains zero or negative values raises the bad_value protocol"]
-            #[doc = "error."]
-            #[doc = ""]
-            #[doc = "The crop and scale state is double-buffered, see wl_surface.commit."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_destination message was introduced in
+            const SET_DESTINATION_SINCE: u32 = 1u32;
+            ///The interface version the set_destination message was deprecated in, if any
+            const SET_DESTINATION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the destination size of the associated wl_surface. See
+            ///wp_viewport for the description, and relation to the wl_buffer
+            ///size.
+            ///
+            ///If width is -1 and height is -1, the destination size is unset
+            ///instead. Any other pair of values for width and height that
+            ///contains zero or negative values raises the bad_value protocol
+            ///error.
+            ///
+            ///The crop and scale state is double-buffered, see wl_surface.commit.
             fn set_destination(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("wp_viewport#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_viewport", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_viewport", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "wp_viewport.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let x = message.fixed()?;
@@ -4278,70 +7377,169 @@ pub mod viewporter {
                             let width = message.fixed()?;
                             let height = message.fixed()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wp_viewport#{}.set_source({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.set_source(connection, sender_id, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_viewport", object_id = % sender_id, opcode = 1u16,
+                                message = "set_source", direction = "in", "args.x" = % x,
+                                "args.y" = % y, "args.width" = % width, "args.height" = %
+                                height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_viewport", object_id : % = sender_id, opcode = 1u16,
+                                message = "set_source", direction = "in", "args.x" : % = x,
+                                "args.y" : % = y, "args.width" : % = width, "args.height" :
+                                % = height; "wp_viewport.set_source"
+                            );
+                            self.set_source(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         2u16 => {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "wp_viewport#{}.set_destination({}, {})",
-                                sender_id,
-                                width,
-                                height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "wp_viewport", object_id = % sender_id, opcode = 2u16,
+                                message = "set_destination", direction = "in", "args.width"
+                                = % width, "args.height" = % height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "wp_viewport", object_id : % = sender_id, opcode = 2u16,
+                                message = "set_destination", direction = "in", "args.width"
+                                : % = width, "args.height" : % = height;
+                                "wp_viewport.set_destination"
                             );
-                            self.set_destination(connection, sender_id, width, height)
+                            self.set_destination(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "xdg-shell")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xdg-shell")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod xdg_shell {
-    #[doc = "The xdg_wm_base interface is exposed as a global object enabling clients"]
-    #[doc = "to turn their wl_surfaces into windows in a desktop environment. It"]
-    #[doc = "defines the basic functionality needed for clients and the compositor to"]
-    #[doc = "create windows that can be dragged, resized, maximized, etc, as well as"]
-    #[doc = "creating transient windows such as popup menus."]
+    ///The xdg_wm_base interface is exposed as a global object enabling clients
+    ///to turn their wl_surfaces into windows in a desktop environment. It
+    ///defines the basic functionality needed for clients and the compositor to
+    ///create windows that can be dragged, resized, maximized, etc, as well as
+    ///creating transient windows such as popup menus.
     #[allow(clippy::too_many_arguments)]
     pub mod xdg_wm_base {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "given wl_surface has another role"]
+            ///given wl_surface has another role
             Role = 0u32,
-            #[doc = "xdg_wm_base was destroyed before children"]
+            ///xdg_wm_base was destroyed before children
             DefunctSurfaces = 1u32,
-            #[doc = "the client tried to map or destroy a non-topmost popup"]
+            ///the client tried to map or destroy a non-topmost popup
             NotTheTopmostPopup = 2u32,
-            #[doc = "the client specified an invalid popup parent surface"]
+            ///the client specified an invalid popup parent surface
             InvalidPopupParent = 3u32,
-            #[doc = "the client provided an invalid surface state"]
+            ///the client provided an invalid surface state
             InvalidSurfaceState = 4u32,
-            #[doc = "the client provided an invalid positioner"]
+            ///the client provided an invalid positioner
             InvalidPositioner = 5u32,
-            #[doc = "the client didn’t respond to a ping event in time"]
+            ///the client didn’t respond to a ping event in time
             Unresponsive = 6u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Role => 1u32,
+                    Self::DefunctSurfaces => 1u32,
+                    Self::NotTheTopmostPopup => 1u32,
+                    Self::InvalidPopupParent => 1u32,
+                    Self::InvalidSurfaceState => 1u32,
+                    Self::InvalidPositioner => 1u32,
+                    Self::Unresponsive => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Role => None,
+                    Self::DefunctSurfaces => None,
+                    Self::NotTheTopmostPopup => None,
+                    Self::InvalidPopupParent => None,
+                    Self::InvalidSurfaceState => None,
+                    Self::InvalidPositioner => None,
+                    Self::Unresponsive => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("role", Self::Role),
+                ("defunct_surfaces", Self::DefunctSurfaces),
+                ("not_the_topmost_popup", Self::NotTheTopmostPopup),
+                ("invalid_popup_parent", Self::InvalidPopupParent),
+                ("invalid_surface_state", Self::InvalidSurfaceState),
+                ("invalid_positioner", Self::InvalidPositioner),
+                ("unresponsive", Self::Unresponsive),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Role => "role",
+                    Self::DefunctSurfaces => "defunct_surfaces",
+                    Self::NotTheTopmostPopup => "not_the_topmost_popup",
+                    Self::InvalidPopupParent => "invalid_popup_parent",
+                    Self::InvalidSurfaceState => "invalid_surface_state",
+                    Self::InvalidPositioner => "invalid_positioner",
+                    Self::Unresponsive => "unresponsive",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4357,120 +7555,268 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_positioner",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_positioner"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_xdg_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "pong",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the xdg_wm_base interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the ping message was introduced in
+            pub const PING_SINCE: u32 = 1u32;
+            ///The interface version the ping message was deprecated in, if any
+            pub const PING_DEPRECATED_SINCE: Option<u32> = None;
+            ///The ping event asks the client if it's still alive. Pass the
+            ///serial specified in the event back to the compositor by sending
+            ///a "pong" request back with the specified serial. See xdg_wm_base.pong.
+            ///
+            ///Compositors can use this to determine if the client is still
+            ///alive. It's unspecified what will happen if the client doesn't
+            ///respond to the ping request, or in what timeframe. Clients should
+            ///try to respond in a reasonable amount of time. The “unresponsive”
+            ///error is provided for compositors that wish to disconnect unresponsive
+            ///clients.
+            ///
+            ///A compositor is free to ping in any way it wants, but a client must
+            ///always respond to any xdg_wm_base object it created.
+            pub fn ping<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "xdg_wm_base",
+                    object_id = % sender_id, opcode = 0u16, message = "ping", direction =
+                    "out", "args.serial" = % serial,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_wm_base",
+                    object_id : % = sender_id, opcode = 0u16, message = "ping", direction
+                    = "out", "args.serial" : % = serial; "xdg_wm_base.ping"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the xdg_wm_base interface. See the module level documentation for more info"]
+        ///Trait to implement the xdg_wm_base interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `xdg_wm_base` interface",
+            label = "`XdgWmBase` is not implemented for `{Self}`"
+        )]
         pub trait XdgWmBase
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "xdg_wm_base";
             const VERSION: u32 = 7u32;
-            #[doc = "Destroy this xdg_wm_base object."]
-            #[doc = ""]
-            #[doc = "Destroying a bound xdg_wm_base object while there are surfaces"]
-            #[doc = "still alive created by this xdg_wm_base object instance is illegal"]
-            #[doc = "and will result in a defunct_surfaces error."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy this xdg_wm_base object.
+            ///
+            ///Destroying a bound xdg_wm_base object while there are surfaces
+            ///still alive created by this xdg_wm_base object instance is illegal
+            ///and will result in a defunct_surfaces error.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Create a positioner object. A positioner object is used to position"]
-            #[doc = "surfaces relative to some parent surface. See the interface description"]
-            #[doc = "and xdg_surface.get_popup for details."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the create_positioner message was introduced in
+            const CREATE_POSITIONER_SINCE: u32 = 1u32;
+            ///The interface version the create_positioner message was deprecated in, if any
+            const CREATE_POSITIONER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a positioner object. A positioner object is used to position
+            ///surfaces relative to some parent surface. See the interface description
+            ///and xdg_surface.get_popup for details.
             fn create_positioner(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This creates an xdg_surface for the given surface. An xdg_surface is"]
-            #[doc = "used as basis to define a role to a given surface, such as xdg_toplevel"]
-            #[doc = "or xdg_popup. It also manages functionality shared between xdg_surface"]
-            #[doc = "based surface roles."]
-            #[doc = ""]
-            #[doc = "While xdg_surface itself is not a role, the corresponding surface may"]
-            #[doc = "only be assigned a role extending xdg_surface, such as xdg_toplevel or"]
-            #[doc = "xdg_popup. It is illegal to create an xdg_surface for a wl_surface which"]
-            #[doc = "already has anassigned role and this will result in a role error."]
-            #[doc = ""]
-            #[doc = "See the documentation of xdg_surface for more details about what an"]
-            #[doc = "xdg_surface is and how it is used."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_xdg_surface message was introduced in
+            const GET_XDG_SURFACE_SINCE: u32 = 1u32;
+            ///The interface version the get_xdg_surface message was deprecated in, if any
+            const GET_XDG_SURFACE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This creates an xdg_surface for the given surface. An xdg_surface is
+            ///used as basis to define a role to a given surface, such as xdg_toplevel
+            ///or xdg_popup. It also manages functionality shared between xdg_surface
+            ///based surface roles.
+            ///
+            ///While xdg_surface itself is not a role, the corresponding surface may
+            ///only be assigned a role extending xdg_surface, such as xdg_toplevel or
+            ///xdg_popup. It is illegal to create an xdg_surface for a wl_surface which
+            ///already has anassigned role and this will result in a role error.
+            ///
+            ///See the documentation of xdg_surface for more details about what an
+            ///xdg_surface is and how it is used.
             fn get_xdg_surface(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 surface: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "A client must respond to a ping event with a pong request or"]
-            #[doc = "the client may be deemed unresponsive. See xdg_wm_base.ping"]
-            #[doc = "and xdg_wm_base.error.unresponsive."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the pong message was introduced in
+            const PONG_SINCE: u32 = 1u32;
+            ///The interface version the pong message was deprecated in, if any
+            const PONG_DEPRECATED_SINCE: Option<u32> = None;
+            ///A client must respond to a ping event with a pong request or
+            ///the client may be deemed unresponsive. See xdg_wm_base.ping
+            ///and xdg_wm_base.error.unresponsive.
             fn pong(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The ping event asks the client if it's still alive. Pass the"]
-            #[doc = "serial specified in the event back to the compositor by sending"]
-            #[doc = "a \"pong\" request back with the specified serial. See xdg_wm_base.pong."]
-            #[doc = ""]
-            #[doc = "Compositors can use this to determine if the client is still"]
-            #[doc = "alive. It's unspecified what will happen if the client doesn't"]
-            #[doc = "respond to the ping request, or in what timeframe. Clients should"]
-            #[doc = "try to respond in a reasonable amount of time. The “unresponsive”"]
-            #[doc = "error is provided for compositors that wish to disconnect unresponsive"]
-            #[doc = "clients."]
-            #[doc = ""]
-            #[doc = "A compositor is free to ping in any way it wants, but a client must"]
-            #[doc = "always respond to any xdg_wm_base object it created."]
-            fn ping(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> xdg_wm_base#{}.ping({})", sender_id, serial);
-                    let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_wm_base#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_wm_base", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_wm_base", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "xdg_wm_base.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_wm_base#{}.create_positioner({})", sender_id, id);
-                            self.create_positioner(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_wm_base", object_id = % sender_id, opcode = 1u16,
+                                message = "create_positioner", direction = "in", "args.id" =
+                                % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_wm_base", object_id : % = sender_id, opcode = 1u16,
+                                message = "create_positioner", direction = "in", "args.id" :
+                                % = id; "xdg_wm_base.create_positioner"
+                            );
+                            self.create_positioner(connection, state, sender_id, id)
+                                .await
                         }
                         2u16 => {
                             let id = message
@@ -4480,60 +7826,130 @@ pub mod xdg_shell {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_wm_base#{}.get_xdg_surface({}, {})",
-                                sender_id,
-                                id,
-                                surface
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_wm_base", object_id = % sender_id, opcode = 2u16,
+                                message = "get_xdg_surface", direction = "in", "args.id" = %
+                                id, "args.surface" = % surface,
                             );
-                            self.get_xdg_surface(connection, sender_id, id, surface)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_wm_base", object_id : % = sender_id, opcode = 2u16,
+                                message = "get_xdg_surface", direction = "in", "args.id" : %
+                                = id, "args.surface" : % = surface;
+                                "xdg_wm_base.get_xdg_surface"
+                            );
+                            self.get_xdg_surface(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    surface,
+                                )
                                 .await
                         }
                         3u16 => {
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_wm_base#{}.pong({})", sender_id, serial);
-                            self.pong(connection, sender_id, serial).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_wm_base", object_id = % sender_id, opcode = 3u16,
+                                message = "pong", direction = "in", "args.serial" = %
+                                serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_wm_base", object_id : % = sender_id, opcode = 3u16,
+                                message = "pong", direction = "in", "args.serial" : % =
+                                serial; "xdg_wm_base.pong"
+                            );
+                            self.pong(connection, state, sender_id, serial).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "The xdg_positioner provides a collection of rules for the placement of a"]
-    #[doc = "child surface relative to a parent surface. Rules can be defined to ensure"]
-    #[doc = "the child surface remains within the visible area's borders, and to"]
-    #[doc = "specify how the child surface changes its position, such as sliding along"]
-    #[doc = "an axis, or flipping around a rectangle. These positioner-created rules are"]
-    #[doc = "constrained by the requirement that a child surface must intersect with or"]
-    #[doc = "be at least partially adjacent to its parent surface."]
-    #[doc = ""]
-    #[doc = "See the various requests for details about possible rules."]
-    #[doc = ""]
-    #[doc = "At the time of the request, the compositor makes a copy of the rules"]
-    #[doc = "specified by the xdg_positioner. Thus, after the request is complete the"]
-    #[doc = "xdg_positioner object can be destroyed or reused; further changes to the"]
-    #[doc = "object will have no effect on previous usages."]
-    #[doc = ""]
-    #[doc = "For an xdg_positioner object to be considered complete, it must have a"]
-    #[doc = "non-zero size set by set_size, and a non-zero anchor rectangle set by"]
-    #[doc = "set_anchor_rect. Passing an incomplete xdg_positioner object when"]
-    #[doc = "positioning a surface raises an invalid_positioner error."]
+    ///The xdg_positioner provides a collection of rules for the placement of a
+    ///child surface relative to a parent surface. Rules can be defined to ensure
+    ///the child surface remains within the visible area's borders, and to
+    ///specify how the child surface changes its position, such as sliding along
+    ///an axis, or flipping around a rectangle. These positioner-created rules are
+    ///constrained by the requirement that a child surface must intersect with or
+    ///be at least partially adjacent to its parent surface.
+    ///
+    ///See the various requests for details about possible rules.
+    ///
+    ///At the time of the request, the compositor makes a copy of the rules
+    ///specified by the xdg_positioner. Thus, after the request is complete the
+    ///xdg_positioner object can be destroyed or reused; further changes to the
+    ///object will have no effect on previous usages.
+    ///
+    ///For an xdg_positioner object to be considered complete, it must have a
+    ///non-zero size set by set_size, and a non-zero anchor rectangle set by
+    ///set_anchor_rect. Passing an incomplete xdg_positioner object when
+    ///positioning a surface raises an invalid_positioner error.
     #[allow(clippy::too_many_arguments)]
     pub mod xdg_positioner {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "invalid input provided"]
+            ///invalid input provided
             InvalidInput = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidInput => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidInput => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_input", Self::InvalidInput),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidInput => "invalid_input",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4543,9 +7959,15 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
         #[repr(u32)]
@@ -4562,11 +7984,85 @@ pub mod xdg_shell {
             TopRight = 7u32,
             BottomRight = 8u32,
         }
+        #[allow(deprecated)]
+        impl Anchor {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::None => 1u32,
+                    Self::Top => 1u32,
+                    Self::Bottom => 1u32,
+                    Self::Left => 1u32,
+                    Self::Right => 1u32,
+                    Self::TopLeft => 1u32,
+                    Self::BottomLeft => 1u32,
+                    Self::TopRight => 1u32,
+                    Self::BottomRight => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::None => None,
+                    Self::Top => None,
+                    Self::Bottom => None,
+                    Self::Left => None,
+                    Self::Right => None,
+                    Self::TopLeft => None,
+                    Self::BottomLeft => None,
+                    Self::TopRight => None,
+                    Self::BottomRight => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("top", Self::Top),
+                ("bottom", Self::Bottom),
+                ("left", Self::Left),
+                ("right", Self::Right),
+                ("top_left", Self::TopLeft),
+                ("bottom_left", Self::BottomLeft),
+                ("top_right", Self::TopRight),
+                ("bottom_right", Self::BottomRight),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::None => "none",
+                    Self::Top => "top",
+                    Self::Bottom => "bottom",
+                    Self::Left => "left",
+                    Self::Right => "right",
+                    Self::TopLeft => "top_left",
+                    Self::BottomLeft => "bottom_left",
+                    Self::TopRight => "top_right",
+                    Self::BottomRight => "bottom_right",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Anchor> for u32 {
             fn from(value: Anchor) -> Self {
                 value as u32
             }
         }
+        impl From<Anchor> for i32 {
+            fn from(value: Anchor) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Anchor {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4584,9 +8080,15 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Anchor {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Anchor {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
         #[repr(u32)]
@@ -4603,11 +8105,85 @@ pub mod xdg_shell {
             TopRight = 7u32,
             BottomRight = 8u32,
         }
+        #[allow(deprecated)]
+        impl Gravity {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::None => 1u32,
+                    Self::Top => 1u32,
+                    Self::Bottom => 1u32,
+                    Self::Left => 1u32,
+                    Self::Right => 1u32,
+                    Self::TopLeft => 1u32,
+                    Self::BottomLeft => 1u32,
+                    Self::TopRight => 1u32,
+                    Self::BottomRight => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::None => None,
+                    Self::Top => None,
+                    Self::Bottom => None,
+                    Self::Left => None,
+                    Self::Right => None,
+                    Self::TopLeft => None,
+                    Self::BottomLeft => None,
+                    Self::TopRight => None,
+                    Self::BottomRight => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("top", Self::Top),
+                ("bottom", Self::Bottom),
+                ("left", Self::Left),
+                ("right", Self::Right),
+                ("top_left", Self::TopLeft),
+                ("bottom_left", Self::BottomLeft),
+                ("top_right", Self::TopRight),
+                ("bottom_right", Self::BottomRight),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::None => "none",
+                    Self::Top => "top",
+                    Self::Bottom => "bottom",
+                    Self::Left => "left",
+                    Self::Right => "right",
+                    Self::TopLeft => "top_left",
+                    Self::BottomLeft => "bottom_left",
+                    Self::TopRight => "top_right",
+                    Self::BottomRight => "bottom_right",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Gravity> for u32 {
             fn from(value: Gravity) -> Self {
                 value as u32
             }
         }
+        impl From<Gravity> for i32 {
+            fn from(value: Gravity) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Gravity {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4625,12 +8201,59 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Gravity {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Gravity {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        bitflags::bitflags! {
+            #[doc =
+            "The constraint adjustment value define ways the compositor will adjust"]
+            #[doc =
+            "the position of the surface, if the unadjusted position would result"] #[doc
+            = "in the surface being partly constrained."] #[doc = ""] #[doc =
+            "Whether a surface is considered 'constrained' is left to the compositor"]
+            #[doc = "to determine. For example, the surface may be partly outside the"]
+            #[doc =
+            "compositor's defined 'work area', thus necessitating the child surface's"]
+            #[doc = "position be adjusted until it is entirely inside the work area."]
+            #[doc = ""] #[doc =
+            "The adjustments can be combined, according to a defined precedence: 1)"]
+            #[doc = "Flip, 2) Slide, 3) Resize."] #[derive(Debug, PartialEq, Eq,
+            PartialOrd, Ord, Hash, Clone, Copy)] pub struct ConstraintAdjustment : u32 {
+            const None = 0u32; const SlideX = 1u32; const SlideY = 2u32; const FlipX =
+            4u32; const FlipY = 8u32; const ResizeX = 16u32; const ResizeY = 32u32; }
+        }
+        #[allow(deprecated)]
+        impl ConstraintAdjustment {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("slide_x", Self::SlideX),
+                ("slide_y", Self::SlideY),
+                ("flip_x", Self::FlipX),
+                ("flip_y", Self::FlipY),
+                ("resize_x", Self::ResizeX),
+                ("resize_y", Self::ResizeY),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
             }
         }
-        bitflags::bitflags! { # [doc = "The constraint adjustment value define ways the compositor will adjust"] # [doc = "the position of the surface, if the unadjusted position would result"] # [doc = "in the surface being partly constrained."] # [doc = ""] # [doc = "Whether a surface is considered 'constrained' is left to the compositor"] # [doc = "to determine. For example, the surface may be partly outside the"] # [doc = "compositor's defined 'work area', thus necessitating the child surface's"] # [doc = "position be adjusted until it is entirely inside the work area."] # [doc = ""] # [doc = "The adjustments can be combined, according to a defined precedence: 1)"] # [doc = "Flip, 2) Slide, 3) Resize."] # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct ConstraintAdjustment : u32 { const None = 0u32 ; const SlideX = 1u32 ; const SlideY = 2u32 ; const FlipX = 4u32 ; const FlipY = 8u32 ; const ResizeX = 16u32 ; const ResizeY = 32u32 ; } }
         impl From<ConstraintAdjustment> for u32 {
             fn from(value: ConstraintAdjustment) -> Self {
                 value.bits()
@@ -4644,177 +8267,504 @@ pub mod xdg_shell {
         }
         impl std::fmt::Display for ConstraintAdjustment {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Trait to implement the xdg_positioner interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor_rect",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_gravity",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "gravity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("gravity"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_constraint_adjustment",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "constraint_adjustment",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("constraint_adjustment"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_offset",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_reactive",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent_size",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent_width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent_height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_parent_configure",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the xdg_positioner interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the xdg_positioner interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `xdg_positioner` interface",
+            label = "`XdgPositioner` is not implemented for `{Self}`"
+        )]
         pub trait XdgPositioner
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "xdg_positioner";
             const VERSION: u32 = 7u32;
-            #[doc = "Notify the compositor that the xdg_positioner will no longer be used."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Notify the compositor that the xdg_positioner will no longer be used.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the size of the surface that is to be positioned with the positioner"]
-            #[doc = "object. The size is in surface-local coordinates and corresponds to the"]
-            #[doc = "window geometry. See xdg_surface.set_window_geometry."]
-            #[doc = ""]
-            #[doc = "If a zero or negative size is set the invalid_input error is raised."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_size message was introduced in
+            const SET_SIZE_SINCE: u32 = 1u32;
+            ///The interface version the set_size message was deprecated in, if any
+            const SET_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the size of the surface that is to be positioned with the positioner
+            ///object. The size is in surface-local coordinates and corresponds to the
+            ///window geometry. See xdg_surface.set_window_geometry.
+            ///
+            ///If a zero or negative size is set the invalid_input error is raised.
             fn set_size(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Specify the anchor rectangle within the parent surface that the child"]
-            #[doc = "surface will be placed relative to. The rectangle is relative to the"]
-            #[doc = "window geometry as defined by xdg_surface.set_window_geometry of the"]
-            #[doc = "parent surface."]
-            #[doc = ""]
-            #[doc = "When the xdg_positioner object is used to position a child surface, the"]
-            #[doc = "anchor rectangle may not extend outside the window geometry of the"]
-            #[doc = "positioned child's parent surface."]
-            #[doc = ""]
-            #[doc = "If a negative size is set the invalid_input error is raised."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_anchor_rect message was introduced in
+            const SET_ANCHOR_RECT_SINCE: u32 = 1u32;
+            ///The interface version the set_anchor_rect message was deprecated in, if any
+            const SET_ANCHOR_RECT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Specify the anchor rectangle within the parent surface that the child
+            ///surface will be placed relative to. The rectangle is relative to the
+            ///window geometry as defined by xdg_surface.set_window_geometry of the
+            ///parent surface.
+            ///
+            ///When the xdg_positioner object is used to position a child surface, the
+            ///anchor rectangle may not extend outside the window geometry of the
+            ///positioned child's parent surface.
+            ///
+            ///If a negative size is set the invalid_input error is raised.
             fn set_anchor_rect(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Defines the anchor point for the anchor rectangle. The specified anchor"]
-            #[doc = "is used to derive an anchor point that the child surface will be"]
-            #[doc = "positioned relative to. If a corner anchor is set (e.g. 'top_left' or"]
-            #[doc = "'bottom_right'), the anchor point will be at the specified corner;"]
-            #[doc = "otherwise, the derived anchor point will be centered on the specified"]
-            #[doc = "edge, or in the center of the anchor rectangle if no edge is specified."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_anchor message was introduced in
+            const SET_ANCHOR_SINCE: u32 = 1u32;
+            ///The interface version the set_anchor message was deprecated in, if any
+            const SET_ANCHOR_DEPRECATED_SINCE: Option<u32> = None;
+            ///Defines the anchor point for the anchor rectangle. The specified anchor
+            ///is used to derive an anchor point that the child surface will be
+            ///positioned relative to. If a corner anchor is set (e.g. 'top_left' or
+            ///'bottom_right'), the anchor point will be at the specified corner;
+            ///otherwise, the derived anchor point will be centered on the specified
+            ///edge, or in the center of the anchor rectangle if no edge is specified.
             fn set_anchor(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 anchor: Anchor,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Defines in what direction a surface should be positioned, relative to"]
-            #[doc = "the anchor point of the parent surface. If a corner gravity is"]
-            #[doc = "specified (e.g. 'bottom_right' or 'top_left'), then the child surface"]
-            #[doc = "will be placed towards the specified gravity; otherwise, the child"]
-            #[doc = "surface will be centered over the anchor point on any axis that had no"]
-            #[doc = "gravity specified. If the gravity is not in the ‘gravity’ enum, an"]
-            #[doc = "invalid_input error is raised."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_gravity message was introduced in
+            const SET_GRAVITY_SINCE: u32 = 1u32;
+            ///The interface version the set_gravity message was deprecated in, if any
+            const SET_GRAVITY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Defines in what direction a surface should be positioned, relative to
+            ///the anchor point of the parent surface. If a corner gravity is
+            ///specified (e.g. 'bottom_right' or 'top_left'), then the child surface
+            ///will be placed towards the specified gravity; otherwise, the child
+            ///surface will be centered over the anchor point on any axis that had no
+            ///gravity specified. If the gravity is not in the ‘gravity’ enum, an
+            ///invalid_input error is raised.
             fn set_gravity(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 gravity: Gravity,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Specify how the window should be positioned if the originally intended"]
-            #[doc = "position caused the surface to be constrained, meaning at least"]
-            #[doc = "partially outside positioning boundaries set by the compositor. The"]
-            #[doc = "adjustment is set by constructing a bitmask describing the adjustment to"]
-            #[doc = "be made when the surface is constrained on that axis."]
-            #[doc = ""]
-            #[doc = "If no bit for one axis is set, the compositor will assume that the child"]
-            #[doc = "surface should not change its position on that axis when constrained."]
-            #[doc = ""]
-            #[doc = "If more than one bit for one axis is set, the order of how adjustments"]
-            #[doc = "are applied is specified in the corresponding adjustment descriptions."]
-            #[doc = ""]
-            #[doc = "The default adjustment is none."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_constraint_adjustment message was introduced in
+            const SET_CONSTRAINT_ADJUSTMENT_SINCE: u32 = 1u32;
+            ///The interface version the set_constraint_adjustment message was deprecated in, if any
+            const SET_CONSTRAINT_ADJUSTMENT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Specify how the window should be positioned if the originally intended
+            ///position caused the surface to be constrained, meaning at least
+            ///partially outside positioning boundaries set by the compositor. The
+            ///adjustment is set by constructing a bitmask describing the adjustment to
+            ///be made when the surface is constrained on that axis.
+            ///
+            ///If no bit for one axis is set, the compositor will assume that the child
+            ///surface should not change its position on that axis when constrained.
+            ///
+            ///If more than one bit for one axis is set, the order of how adjustments
+            ///are applied is specified in the corresponding adjustment descriptions.
+            ///
+            ///The default adjustment is none.
             fn set_constraint_adjustment(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 constraint_adjustment: ConstraintAdjustment,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Specify the surface position offset relative to the position of the"]
-            #[doc = "anchor on the anchor rectangle and the anchor on the surface. For"]
-            #[doc = "example if the anchor of the anchor rectangle is at (x, y), the surface"]
-            #[doc = "has the gravity bottom|right, and the offset is (ox, oy), the calculated"]
-            #[doc = "surface position will be (x + ox, y + oy). The offset position of the"]
-            #[doc = "surface is the one used for constraint testing. See"]
-            #[doc = "set_constraint_adjustment."]
-            #[doc = ""]
-            #[doc = "An example use case is placing a popup menu on top of a user interface"]
-            #[doc = "element, while aligning the user interface element of the parent surface"]
-            #[doc = "with some user interface element placed somewhere in the popup surface."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_offset message was introduced in
+            const SET_OFFSET_SINCE: u32 = 1u32;
+            ///The interface version the set_offset message was deprecated in, if any
+            const SET_OFFSET_DEPRECATED_SINCE: Option<u32> = None;
+            ///Specify the surface position offset relative to the position of the
+            ///anchor on the anchor rectangle and the anchor on the surface. For
+            ///example if the anchor of the anchor rectangle is at (x, y), the surface
+            ///has the gravity bottom|right, and the offset is (ox, oy), the calculated
+            ///surface position will be (x + ox, y + oy). The offset position of the
+            ///surface is the one used for constraint testing. See
+            ///set_constraint_adjustment.
+            ///
+            ///An example use case is placing a popup menu on top of a user interface
+            ///element, while aligning the user interface element of the parent surface
+            ///with some user interface element placed somewhere in the popup surface.
             fn set_offset(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "When set reactive, the surface is reconstrained if the conditions used"]
-            #[doc = "for constraining changed, e.g. the parent window moved."]
-            #[doc = ""]
-            #[doc = "If the conditions changed and the popup was reconstrained, an"]
-            #[doc = "xdg_popup.configure event is sent with updated geometry, followed by an"]
-            #[doc = "xdg_surface.configure event."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_reactive message was introduced in
+            const SET_REACTIVE_SINCE: u32 = 3u32;
+            ///The interface version the set_reactive message was deprecated in, if any
+            const SET_REACTIVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///When set reactive, the surface is reconstrained if the conditions used
+            ///for constraining changed, e.g. the parent window moved.
+            ///
+            ///If the conditions changed and the popup was reconstrained, an
+            ///xdg_popup.configure event is sent with updated geometry, followed by an
+            ///xdg_surface.configure event.
+            ///
+            ///Available since version 3
             fn set_reactive(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the parent window geometry the compositor should use when"]
-            #[doc = "positioning the popup. The compositor may use this information to"]
-            #[doc = "determine the future state the popup should be constrained using. If"]
-            #[doc = "this doesn't match the dimension of the parent the popup is eventually"]
-            #[doc = "positioned against, the behavior is undefined."]
-            #[doc = ""]
-            #[doc = "The arguments are given in the surface-local coordinate space."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_parent_size message was introduced in
+            const SET_PARENT_SIZE_SINCE: u32 = 3u32;
+            ///The interface version the set_parent_size message was deprecated in, if any
+            const SET_PARENT_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the parent window geometry the compositor should use when
+            ///positioning the popup. The compositor may use this information to
+            ///determine the future state the popup should be constrained using. If
+            ///this doesn't match the dimension of the parent the popup is eventually
+            ///positioned against, the behavior is undefined.
+            ///
+            ///The arguments are given in the surface-local coordinate space.
+            ///
+            ///Available since version 3
             fn set_parent_size(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 parent_width: i32,
                 parent_height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the serial of an xdg_surface.configure event this positioner will be"]
-            #[doc = "used in response to. The compositor may use this information together"]
-            #[doc = "with set_parent_size to determine what future state the popup should be"]
-            #[doc = "constrained using."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_parent_configure message was introduced in
+            const SET_PARENT_CONFIGURE_SINCE: u32 = 3u32;
+            ///The interface version the set_parent_configure message was deprecated in, if any
+            const SET_PARENT_CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the serial of an xdg_surface.configure event this positioner will be
+            ///used in response to. The compositor may use this information together
+            ///with set_parent_size to determine what future state the popup should be
+            ///constrained using.
+            ///
+            ///Available since version 3
             fn set_parent_configure(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_positioner#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in";
+                                "xdg_positioner.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_size({}, {})",
-                                sender_id,
-                                width,
-                                height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 1u16,
+                                message = "set_size", direction = "in", "args.width" = %
+                                width, "args.height" = % height,
                             );
-                            self.set_size(connection, sender_id, width, height).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 1u16,
+                                message = "set_size", direction = "in", "args.width" : % =
+                                width, "args.height" : % = height; "xdg_positioner.set_size"
+                            );
+                            self.set_size(connection, state, sender_id, width, height)
+                                .await
                         }
                         2u16 => {
                             let x = message.int()?;
@@ -4822,169 +8772,325 @@ pub mod xdg_shell {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_anchor_rect({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.set_anchor_rect(connection, sender_id, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 2u16,
+                                message = "set_anchor_rect", direction = "in", "args.x" = %
+                                x, "args.y" = % y, "args.width" = % width, "args.height" = %
+                                height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 2u16,
+                                message = "set_anchor_rect", direction = "in", "args.x" : %
+                                = x, "args.y" : % = y, "args.width" : % = width,
+                                "args.height" : % = height; "xdg_positioner.set_anchor_rect"
+                            );
+                            self.set_anchor_rect(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         3u16 => {
-                            let anchor = message.uint()?;
+                            let anchor: Anchor = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_positioner#{}.set_anchor({})", sender_id, anchor);
-                            self.set_anchor(connection, sender_id, anchor.try_into()?)
-                                .await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 3u16,
+                                message = "set_anchor", direction = "in", "args.anchor" = %
+                                anchor,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 3u16,
+                                message = "set_anchor", direction = "in", "args.anchor" : %
+                                = anchor; "xdg_positioner.set_anchor"
+                            );
+                            self.set_anchor(connection, state, sender_id, anchor).await
                         }
                         4u16 => {
-                            let gravity = message.uint()?;
+                            let gravity: Gravity = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_gravity({})",
-                                sender_id,
-                                gravity
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 4u16,
+                                message = "set_gravity", direction = "in", "args.gravity" =
+                                % gravity,
                             );
-                            self.set_gravity(connection, sender_id, gravity.try_into()?)
-                                .await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 4u16,
+                                message = "set_gravity", direction = "in", "args.gravity" :
+                                % = gravity; "xdg_positioner.set_gravity"
+                            );
+                            self.set_gravity(connection, state, sender_id, gravity).await
                         }
                         5u16 => {
-                            let constraint_adjustment = message.uint()?;
+                            let constraint_adjustment: ConstraintAdjustment = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_constraint_adjustment({})",
-                                sender_id,
-                                constraint_adjustment
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 5u16,
+                                message = "set_constraint_adjustment", direction = "in",
+                                "args.constraint_adjustment" = % constraint_adjustment,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 5u16,
+                                message = "set_constraint_adjustment", direction = "in",
+                                "args.constraint_adjustment" : % = constraint_adjustment;
+                                "xdg_positioner.set_constraint_adjustment"
                             );
                             self.set_constraint_adjustment(
-                                connection,
-                                sender_id,
-                                constraint_adjustment.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    constraint_adjustment,
+                                )
+                                .await
                         }
                         6u16 => {
                             let x = message.int()?;
                             let y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_offset({}, {})",
-                                sender_id,
-                                x,
-                                y
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 6u16,
+                                message = "set_offset", direction = "in", "args.x" = % x,
+                                "args.y" = % y,
                             );
-                            self.set_offset(connection, sender_id, x, y).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 6u16,
+                                message = "set_offset", direction = "in", "args.x" : % = x,
+                                "args.y" : % = y; "xdg_positioner.set_offset"
+                            );
+                            self.set_offset(connection, state, sender_id, x, y).await
                         }
                         7u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_positioner#{}.set_reactive()", sender_id,);
-                            self.set_reactive(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 7u16,
+                                message = "set_reactive", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 7u16,
+                                message = "set_reactive", direction = "in";
+                                "xdg_positioner.set_reactive"
+                            );
+                            self.set_reactive(connection, state, sender_id).await
                         }
                         8u16 => {
                             let parent_width = message.int()?;
                             let parent_height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_parent_size({}, {})",
-                                sender_id,
-                                parent_width,
-                                parent_height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 8u16,
+                                message = "set_parent_size", direction = "in",
+                                "args.parent_width" = % parent_width, "args.parent_height" =
+                                % parent_height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 8u16,
+                                message = "set_parent_size", direction = "in",
+                                "args.parent_width" : % = parent_width, "args.parent_height"
+                                : % = parent_height; "xdg_positioner.set_parent_size"
                             );
-                            self.set_parent_size(connection, sender_id, parent_width, parent_height)
+                            self.set_parent_size(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    parent_width,
+                                    parent_height,
+                                )
                                 .await
                         }
                         9u16 => {
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_positioner#{}.set_parent_configure({})",
-                                sender_id,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_positioner", object_id = % sender_id, opcode = 9u16,
+                                message = "set_parent_configure", direction = "in",
+                                "args.serial" = % serial,
                             );
-                            self.set_parent_configure(connection, sender_id, serial)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_positioner", object_id : % = sender_id, opcode = 9u16,
+                                message = "set_parent_configure", direction = "in",
+                                "args.serial" : % = serial;
+                                "xdg_positioner.set_parent_configure"
+                            );
+                            self.set_parent_configure(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    serial,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "An interface that may be implemented by a wl_surface, for"]
-    #[doc = "implementations that provide a desktop-style user interface."]
-    #[doc = ""]
-    #[doc = "It provides a base set of functionality required to construct user"]
-    #[doc = "interface elements requiring management by the compositor, such as"]
-    #[doc = "toplevel windows, menus, etc. The types of functionality are split into"]
-    #[doc = "xdg_surface roles."]
-    #[doc = ""]
-    #[doc = "Creating an xdg_surface does not set the role for a wl_surface. In order"]
-    #[doc = "to map an xdg_surface, the client must create a role-specific object"]
-    #[doc = "using, e.g., get_toplevel, get_popup. The wl_surface for any given"]
-    #[doc = "xdg_surface can have at most one role, and may not be assigned any role"]
-    #[doc = "not based on xdg_surface."]
-    #[doc = ""]
-    #[doc = "A role must be assigned before any other requests are made to the"]
-    #[doc = "xdg_surface object."]
-    #[doc = ""]
-    #[doc = "The client must call wl_surface.commit on the corresponding wl_surface"]
-    #[doc = "for the xdg_surface state to take effect."]
-    #[doc = ""]
-    #[doc = "Creating an xdg_surface from a wl_surface which has a buffer attached or"]
-    #[doc = "committed is a client error, and any attempts by a client to attach or"]
-    #[doc = "manipulate a buffer prior to the first xdg_surface.configure call must"]
-    #[doc = "also be treated as errors."]
-    #[doc = ""]
-    #[doc = "After creating a role-specific object and setting it up (e.g. by sending"]
-    #[doc = "the title, app ID, size constraints, parent, etc), the client must"]
-    #[doc = "perform an initial commit without any buffer attached. The compositor"]
-    #[doc = "will reply with initial wl_surface state such as"]
-    #[doc = "wl_surface.preferred_buffer_scale followed by an xdg_surface.configure"]
-    #[doc = "event. The client must acknowledge it and is then allowed to attach a"]
-    #[doc = "buffer to map the surface."]
-    #[doc = ""]
-    #[doc = "Mapping an xdg_surface-based role surface is defined as making it"]
-    #[doc = "possible for the surface to be shown by the compositor. Note that"]
-    #[doc = "a mapped surface is not guaranteed to be visible once it is mapped."]
-    #[doc = ""]
-    #[doc = "For an xdg_surface to be mapped by the compositor, the following"]
-    #[doc = "conditions must be met:"]
-    #[doc = "(1) the client has assigned an xdg_surface-based role to the surface"]
-    #[doc = "(2) the client has set and committed the xdg_surface state and the"]
-    #[doc = "role-dependent state to the surface"]
-    #[doc = "(3) the client has committed a buffer to the surface"]
-    #[doc = ""]
-    #[doc = "A newly-unmapped surface is considered to have met condition (1) out"]
-    #[doc = "of the 3 required conditions for mapping a surface if its role surface"]
-    #[doc = "has not been destroyed, i.e. the client must perform the initial commit"]
-    #[doc = "again before attaching a buffer."]
+    ///An interface that may be implemented by a wl_surface, for
+    ///implementations that provide a desktop-style user interface.
+    ///
+    ///It provides a base set of functionality required to construct user
+    ///interface elements requiring management by the compositor, such as
+    ///toplevel windows, menus, etc. The types of functionality are split into
+    ///xdg_surface roles.
+    ///
+    ///Creating an xdg_surface does not set the role for a wl_surface. In order
+    ///to map an xdg_surface, the client must create a role-specific object
+    ///using, e.g., get_toplevel, get_popup. The wl_surface for any given
+    ///xdg_surface can have at most one role, and may not be assigned any role
+    ///not based on xdg_surface.
+    ///
+    ///A role must be assigned before any other requests are made to the
+    ///xdg_surface object.
+    ///
+    ///The client must call wl_surface.commit on the corresponding wl_surface
+    ///for the xdg_surface state to take effect.
+    ///
+    ///Creating an xdg_surface from a wl_surface which has a buffer attached or
+    ///committed is a client error, and any attempts by a client to attach or
+    ///manipulate a buffer prior to the first xdg_surface.configure call must
+    ///also be treated as errors.
+    ///
+    ///After creating a role-specific object and setting it up (e.g. by sending
+    ///the title, app ID, size constraints, parent, etc), the client must
+    ///perform an initial commit without any buffer attached. The compositor
+    ///will reply with initial wl_surface state such as
+    ///wl_surface.preferred_buffer_scale followed by an xdg_surface.configure
+    ///event. The client must acknowledge it and is then allowed to attach a
+    ///buffer to map the surface.
+    ///
+    ///Mapping an xdg_surface-based role surface is defined as making it
+    ///possible for the surface to be shown by the compositor. Note that
+    ///a mapped surface is not guaranteed to be visible once it is mapped.
+    ///
+    ///For an xdg_surface to be mapped by the compositor, the following
+    ///conditions must be met:
+    ///(1) the client has assigned an xdg_surface-based role to the surface
+    ///(2) the client has set and committed the xdg_surface state and the
+    ///role-dependent state to the surface
+    ///(3) the client has committed a buffer to the surface
+    ///
+    ///A newly-unmapped surface is considered to have met condition (1) out
+    ///of the 3 required conditions for mapping a surface if its role surface
+    ///has not been destroyed, i.e. the client must perform the initial commit
+    ///again before attaching a buffer.
     #[allow(clippy::too_many_arguments)]
     pub mod xdg_surface {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "Surface was not fully constructed"]
+            ///Surface was not fully constructed
             NotConstructed = 1u32,
-            #[doc = "Surface was already constructed"]
+            ///Surface was already constructed
             AlreadyConstructed = 2u32,
-            #[doc = "Attaching a buffer to an unconfigured surface"]
+            ///Attaching a buffer to an unconfigured surface
             UnconfiguredBuffer = 3u32,
-            #[doc = "Invalid serial number when acking a configure event"]
+            ///Invalid serial number when acking a configure event
             InvalidSerial = 4u32,
-            #[doc = "Width or height was zero or negative"]
+            ///Width or height was zero or negative
             InvalidSize = 5u32,
-            #[doc = "Surface was destroyed before its role object"]
+            ///Surface was destroyed before its role object
             DefunctRoleObject = 6u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::NotConstructed => 1u32,
+                    Self::AlreadyConstructed => 1u32,
+                    Self::UnconfiguredBuffer => 1u32,
+                    Self::InvalidSerial => 1u32,
+                    Self::InvalidSize => 1u32,
+                    Self::DefunctRoleObject => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::NotConstructed => None,
+                    Self::AlreadyConstructed => None,
+                    Self::UnconfiguredBuffer => None,
+                    Self::InvalidSerial => None,
+                    Self::InvalidSize => None,
+                    Self::DefunctRoleObject => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("not_constructed", Self::NotConstructed),
+                ("already_constructed", Self::AlreadyConstructed),
+                ("unconfigured_buffer", Self::UnconfiguredBuffer),
+                ("invalid_serial", Self::InvalidSerial),
+                ("invalid_size", Self::InvalidSize),
+                ("defunct_role_object", Self::DefunctRoleObject),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::NotConstructed => "not_constructed",
+                    Self::AlreadyConstructed => "already_constructed",
+                    Self::UnconfiguredBuffer => "unconfigured_buffer",
+                    Self::InvalidSerial => "invalid_serial",
+                    Self::InvalidSize => "invalid_size",
+                    Self::DefunctRoleObject => "defunct_role_object",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4999,198 +9105,397 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "get_toplevel",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n?oo",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("xdg_popup"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_surface"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "positioner",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_positioner"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_window_geometry",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the xdg_surface interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the configure message was introduced in
+            pub const CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the configure message was deprecated in, if any
+            pub const CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The configure event marks the end of a configure sequence. A configure
+            ///sequence is a set of one or more events configuring the state of the
+            ///xdg_surface, including the final xdg_surface.configure event.
+            ///
+            ///Where applicable, xdg_surface surface roles will during a configure
+            ///sequence extend this event as a latched state sent as events before the
+            ///xdg_surface.configure event. Such events should be considered to make up
+            ///a set of atomically applied configuration states, where the
+            ///xdg_surface.configure commits the accumulated state.
+            ///
+            ///Clients should arrange their surface for the new states, and then send
+            ///an ack_configure request with the serial sent in this configure event at
+            ///some point before committing the new surface.
+            ///
+            ///If the client receives multiple configure events before it can respond
+            ///to one, it is free to discard all but the last event it received.
+            pub fn configure<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "xdg_surface",
+                    object_id = % sender_id, opcode = 0u16, message = "configure",
+                    direction = "out", "args.serial" = % serial,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_surface",
+                    object_id : % = sender_id, opcode = 0u16, message = "configure",
+                    direction = "out", "args.serial" : % = serial;
+                    "xdg_surface.configure"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the xdg_surface interface. See the module level documentation for more info"]
+        ///Trait to implement the xdg_surface interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `xdg_surface` interface",
+            label = "`XdgSurface` is not implemented for `{Self}`"
+        )]
         pub trait XdgSurface
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "xdg_surface";
             const VERSION: u32 = 7u32;
-            #[doc = "Destroy the xdg_surface object. An xdg_surface must only be destroyed"]
-            #[doc = "after its role object has been destroyed, otherwise"]
-            #[doc = "a defunct_role_object error is raised."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the xdg_surface object. An xdg_surface must only be destroyed
+            ///after its role object has been destroyed, otherwise
+            ///a defunct_role_object error is raised.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This creates an xdg_toplevel object for the given xdg_surface and gives"]
-            #[doc = "the associated wl_surface the xdg_toplevel role."]
-            #[doc = ""]
-            #[doc = "See the documentation of xdg_toplevel for more details about what an"]
-            #[doc = "xdg_toplevel is and how it is used."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_toplevel message was introduced in
+            const GET_TOPLEVEL_SINCE: u32 = 1u32;
+            ///The interface version the get_toplevel message was deprecated in, if any
+            const GET_TOPLEVEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///This creates an xdg_toplevel object for the given xdg_surface and gives
+            ///the associated wl_surface the xdg_toplevel role.
+            ///
+            ///See the documentation of xdg_toplevel for more details about what an
+            ///xdg_toplevel is and how it is used.
             fn get_toplevel(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This creates an xdg_popup object for the given xdg_surface and gives"]
-            #[doc = "the associated wl_surface the xdg_popup role."]
-            #[doc = ""]
-            #[doc = "If null is passed as a parent, a parent surface must be specified using"]
-            #[doc = "some other protocol, before committing the initial state."]
-            #[doc = ""]
-            #[doc = "See the documentation of xdg_popup for more details about what an"]
-            #[doc = "xdg_popup is and how it is used."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_popup message was introduced in
+            const GET_POPUP_SINCE: u32 = 1u32;
+            ///The interface version the get_popup message was deprecated in, if any
+            const GET_POPUP_DEPRECATED_SINCE: Option<u32> = None;
+            ///This creates an xdg_popup object for the given xdg_surface and gives
+            ///the associated wl_surface the xdg_popup role.
+            ///
+            ///If null is passed as a parent, a parent surface must be specified using
+            ///some other protocol, before committing the initial state.
+            ///
+            ///See the documentation of xdg_popup for more details about what an
+            ///xdg_popup is and how it is used.
             fn get_popup(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 parent: Option<waynest::ObjectId>,
                 positioner: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The window geometry of a surface is its \"visible bounds\" from the"]
-            #[doc = "user's perspective. Client-side decorations often have invisible"]
-            #[doc = "portions like drop-shadows which should be ignored for the"]
-            #[doc = "purposes of aligning, placing and constraining windows. Note that"]
-            #[doc = "in some situations, compositors may clip rendering to the window"]
-            #[doc = "geometry, so the client should avoid putting functional elements"]
-            #[doc = "outside of it."]
-            #[doc = ""]
-            #[doc = "The window geometry is double-buffered state, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "When maintaining a position, the compositor should treat the (x, y)"]
-            #[doc = "coordinate of the window geometry as the top left corner of the window."]
-            #[doc = "A client changing the (x, y) window geometry coordinate should in"]
-            #[doc = "general not alter the position of the window."]
-            #[doc = ""]
-            #[doc = "Once the window geometry of the surface is set, it is not possible to"]
-            #[doc = "unset it, and it will remain the same until set_window_geometry is"]
-            #[doc = "called again, even if a new subsurface or buffer is attached."]
-            #[doc = ""]
-            #[doc = "If never set, the value is the full bounds of the surface,"]
-            #[doc = "including any subsurfaces. This updates dynamically on every"]
-            #[doc = "commit. This unset is meant for extremely simple clients."]
-            #[doc = ""]
-            #[doc = "The arguments are given in the surface-local coordinate space of"]
-            #[doc = "the wl_surface associated with this xdg_surface, and may extend outside"]
-            #[doc = "of the wl_surface itself to mark parts of the subsurface tree as part of"]
-            #[doc = "the window geometry."]
-            #[doc = ""]
-            #[doc = "When applied, the effective window geometry will be the set window"]
-            #[doc = "geometry clamped to the bounding rectangle of the combined"]
-            #[doc = "geometry of the surface of the xdg_surface and the associated"]
-            #[doc = "subsurfaces."]
-            #[doc = ""]
-            #[doc = "The effective geometry will not be recalculated unless a new call to"]
-            #[doc = "set_window_geometry is done and the new pending surface state is"]
-            #[doc = "subsequently applied."]
-            #[doc = ""]
-            #[doc = "The width and height of the effective window geometry must be"]
-            #[doc = "greater than zero. Setting an invalid size will raise an"]
-            #[doc = "invalid_size error."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_window_geometry message was introduced in
+            const SET_WINDOW_GEOMETRY_SINCE: u32 = 1u32;
+            ///The interface version the set_window_geometry message was deprecated in, if any
+            const SET_WINDOW_GEOMETRY_DEPRECATED_SINCE: Option<u32> = None;
+            ///The window geometry of a surface is its "visible bounds" from the
+            ///user's perspective. Client-side decorations often have invisible
+            ///portions like drop-shadows which should be ignored for the
+            ///purposes of aligning, placing and constraining windows. Note that
+            ///in some situations, compositors may clip rendering to the window
+            ///geometry, so the client should avoid putting functional elements
+            ///outside of it.
+            ///
+            ///The window geometry is double-buffered state, see wl_surface.commit.
+            ///
+            ///When maintaining a position, the compositor should treat the (x, y)
+            ///coordinate of the window geometry as the top left corner of the window.
+            ///A client changing the (x, y) window geometry coordinate should in
+            ///general not alter the position of the window.
+            ///
+            ///Once the window geometry of the surface is set, it is not possible to
+            ///unset it, and it will remain the same until set_window_geometry is
+            ///called again, even if a new subsurface or buffer is attached.
+            ///
+            ///If never set, the value is the full bounds of the surface,
+            ///including any subsurfaces. This updates dynamically on every
+            ///commit. This unset is meant for extremely simple clients.
+            ///
+            ///The arguments are given in the surface-local coordinate space of
+            ///the wl_surface associated with this xdg_surface, and may extend outside
+            ///of the wl_surface itself to mark parts of the subsurface tree as part of
+            ///the window geometry.
+            ///
+            ///When applied, the effective window geometry will be the set window
+            ///geometry clamped to the bounding rectangle of the combined
+            ///geometry of the surface of the xdg_surface and the associated
+            ///subsurfaces.
+            ///
+            ///The effective geometry will not be recalculated unless a new call to
+            ///set_window_geometry is done and the new pending surface state is
+            ///subsequently applied.
+            ///
+            ///The width and height of the effective window geometry must be
+            ///greater than zero. Setting an invalid size will raise an
+            ///invalid_size error.
             fn set_window_geometry(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "When a configure event is received, if a client commits the"]
-            #[doc = "surface in response to the configure event, then the client"]
-            #[doc = "must make an ack_configure request sometime before the commit"]
-            #[doc = "request, passing along the serial of the configure event."]
-            #[doc = ""]
-            #[doc = "For instance, for toplevel surfaces the compositor might use this"]
-            #[doc = "information to move a surface to the top left only when the client has"]
-            #[doc = "drawn itself for the maximized or fullscreen state."]
-            #[doc = ""]
-            #[doc = "If the client receives multiple configure events before it"]
-            #[doc = "can respond to one, it only has to ack the last configure event."]
-            #[doc = "Acking a configure event that was never sent raises an invalid_serial"]
-            #[doc = "error."]
-            #[doc = ""]
-            #[doc = "A client is not required to commit immediately after sending"]
-            #[doc = "an ack_configure request - it may even ack_configure several times"]
-            #[doc = "before its next surface commit."]
-            #[doc = ""]
-            #[doc = "A client may send multiple ack_configure requests before committing, but"]
-            #[doc = "only the last request sent before a commit indicates which configure"]
-            #[doc = "event the client really is responding to."]
-            #[doc = ""]
-            #[doc = "Sending an ack_configure request consumes the serial number sent with"]
-            #[doc = "the request, as well as serial numbers sent by all configure events"]
-            #[doc = "sent on this xdg_surface prior to the configure event referenced by"]
-            #[doc = "the committed serial."]
-            #[doc = ""]
-            #[doc = "It is an error to issue multiple ack_configure requests referencing a"]
-            #[doc = "serial from the same configure event, or to issue an ack_configure"]
-            #[doc = "request referencing a serial from a configure event issued before the"]
-            #[doc = "event identified by the last ack_configure request for the same"]
-            #[doc = "xdg_surface. Doing so will raise an invalid_serial error."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the ack_configure message was introduced in
+            const ACK_CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the ack_configure message was deprecated in, if any
+            const ACK_CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///When a configure event is received, if a client commits the
+            ///surface in response to the configure event, then the client
+            ///must make an ack_configure request sometime before the commit
+            ///request, passing along the serial of the configure event.
+            ///
+            ///For instance, for toplevel surfaces the compositor might use this
+            ///information to move a surface to the top left only when the client has
+            ///drawn itself for the maximized or fullscreen state.
+            ///
+            ///If the client receives multiple configure events before it
+            ///can respond to one, it only has to ack the last configure event.
+            ///Acking a configure event that was never sent raises an invalid_serial
+            ///error.
+            ///
+            ///A client is not required to commit immediately after sending
+            ///an ack_configure request - it may even ack_configure several times
+            ///before its next surface commit.
+            ///
+            ///A client may send multiple ack_configure requests before committing, but
+            ///only the last request sent before a commit indicates which configure
+            ///event the client really is responding to.
+            ///
+            ///Sending an ack_configure request consumes the serial number sent with
+            ///the request, as well as serial numbers sent by all configure events
+            ///sent on this xdg_surface prior to the configure event referenced by
+            ///the committed serial.
+            ///
+            ///It is an error to issue multiple ack_configure requests referencing a
+            ///serial from the same configure event, or to issue an ack_configure
+            ///request referencing a serial from a configure event issued before the
+            ///event identified by the last ack_configure request for the same
+            ///xdg_surface. Doing so will raise an invalid_serial error.
             fn ack_configure(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The configure event marks the end of a configure sequence. A configure"]
-            #[doc = "sequence is a set of one or more events configuring the state of the"]
-            #[doc = "xdg_surface, including the final xdg_surface.configure event."]
-            #[doc = ""]
-            #[doc = "Where applicable, xdg_surface surface roles will during a configure"]
-            #[doc = "sequence extend this event as a latched state sent as events before the"]
-            #[doc = "xdg_surface.configure event. Such events should be considered to make up"]
-            #[doc = "a set of atomically applied configuration states, where the"]
-            #[doc = "xdg_surface.configure commits the accumulated state."]
-            #[doc = ""]
-            #[doc = "Clients should arrange their surface for the new states, and then send"]
-            #[doc = "an ack_configure request with the serial sent in this configure event at"]
-            #[doc = "some point before committing the new surface."]
-            #[doc = ""]
-            #[doc = "If the client receives multiple configure events before it can respond"]
-            #[doc = "to one, it is free to discard all but the last event it received."]
-            fn configure(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> xdg_surface#{}.configure({})", sender_id, serial);
-                    let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_surface#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_surface", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_surface", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "xdg_surface.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let id = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_surface#{}.get_toplevel({})", sender_id, id);
-                            self.get_toplevel(connection, sender_id, id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_surface", object_id = % sender_id, opcode = 1u16,
+                                message = "get_toplevel", direction = "in", "args.id" = %
+                                id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_surface", object_id : % = sender_id, opcode = 1u16,
+                                message = "get_toplevel", direction = "in", "args.id" : % =
+                                id; "xdg_surface.get_toplevel"
+                            );
+                            self.get_toplevel(connection, state, sender_id, id).await
                         }
                         2u16 => {
                             let id = message
@@ -5201,16 +9506,30 @@ pub mod xdg_shell {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_surface#{}.get_popup({}, {}, {})",
-                                sender_id,
-                                id,
-                                parent
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                positioner
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_surface", object_id = % sender_id, opcode = 2u16,
+                                message = "get_popup", direction = "in", "args.id" = % id,
+                                "args.parent" = % parent.as_ref().map_or("null".to_string(),
+                                | v | v.to_string()), "args.positioner" = % positioner,
                             );
-                            self.get_popup(connection, sender_id, id, parent, positioner)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_surface", object_id : % = sender_id, opcode = 2u16,
+                                message = "get_popup", direction = "in", "args.id" : % = id,
+                                "args.parent" : % = parent.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string()), "args.positioner" : % =
+                                positioner; "xdg_surface.get_popup"
+                            );
+                            self.get_popup(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    parent,
+                                    positioner,
+                                )
                                 .await
                         }
                         3u16 => {
@@ -5219,68 +9538,149 @@ pub mod xdg_shell {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_surface#{}.set_window_geometry({}, {}, {}, {})",
-                                sender_id,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.set_window_geometry(connection, sender_id, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_surface", object_id = % sender_id, opcode = 3u16,
+                                message = "set_window_geometry", direction = "in", "args.x"
+                                = % x, "args.y" = % y, "args.width" = % width, "args.height"
+                                = % height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_surface", object_id : % = sender_id, opcode = 3u16,
+                                message = "set_window_geometry", direction = "in", "args.x"
+                                : % = x, "args.y" : % = y, "args.width" : % = width,
+                                "args.height" : % = height;
+                                "xdg_surface.set_window_geometry"
+                            );
+                            self.set_window_geometry(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         4u16 => {
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_surface#{}.ack_configure({})", sender_id, serial);
-                            self.ack_configure(connection, sender_id, serial).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_surface", object_id = % sender_id, opcode = 4u16,
+                                message = "ack_configure", direction = "in", "args.serial" =
+                                % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_surface", object_id : % = sender_id, opcode = 4u16,
+                                message = "ack_configure", direction = "in", "args.serial" :
+                                % = serial; "xdg_surface.ack_configure"
+                            );
+                            self.ack_configure(connection, state, sender_id, serial)
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This interface defines an xdg_surface role which allows a surface to,"]
-    #[doc = "among other things, set window-like properties such as maximize,"]
-    #[doc = "fullscreen, and minimize, set application-specific metadata like title and"]
-    #[doc = "id, and well as trigger user interactive operations such as interactive"]
-    #[doc = "resize and move."]
-    #[doc = ""]
-    #[doc = "An xdg_toplevel by default is responsible for providing the full intended"]
-    #[doc = "visual representation of the toplevel, which depending on the window"]
-    #[doc = "state, may mean things like a title bar, window controls and drop shadow."]
-    #[doc = ""]
-    #[doc = "Unmapping an xdg_toplevel means that the surface cannot be shown"]
-    #[doc = "by the compositor until it is explicitly mapped again."]
-    #[doc = "All active operations (e.g., move, resize) are canceled and all"]
-    #[doc = "attributes (e.g. title, state, stacking, ...) are discarded for"]
-    #[doc = "an xdg_toplevel surface when it is unmapped. The xdg_toplevel returns to"]
-    #[doc = "the state it had right after xdg_surface.get_toplevel. The client"]
-    #[doc = "can re-map the toplevel by performing a commit without any buffer"]
-    #[doc = "attached, waiting for a configure event and handling it as usual (see"]
-    #[doc = "xdg_surface description)."]
-    #[doc = ""]
-    #[doc = "Attaching a null buffer to a toplevel unmaps the surface."]
+    ///This interface defines an xdg_surface role which allows a surface to,
+    ///among other things, set window-like properties such as maximize,
+    ///fullscreen, and minimize, set application-specific metadata like title and
+    ///id, and well as trigger user interactive operations such as interactive
+    ///resize and move.
+    ///
+    ///An xdg_toplevel by default is responsible for providing the full intended
+    ///visual representation of the toplevel, which depending on the window
+    ///state, may mean things like a title bar, window controls and drop shadow.
+    ///
+    ///Unmapping an xdg_toplevel means that the surface cannot be shown
+    ///by the compositor until it is explicitly mapped again.
+    ///All active operations (e.g., move, resize) are canceled and all
+    ///attributes (e.g. title, state, stacking, ...) are discarded for
+    ///an xdg_toplevel surface when it is unmapped. The xdg_toplevel returns to
+    ///the state it had right after xdg_surface.get_toplevel. The client
+    ///can re-map the toplevel by performing a commit without any buffer
+    ///attached, waiting for a configure event and handling it as usual (see
+    ///xdg_surface description).
+    ///
+    ///Attaching a null buffer to a toplevel unmaps the surface.
     #[allow(clippy::too_many_arguments)]
     pub mod xdg_toplevel {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "provided value is         not a valid variant of the resize_edge enum"]
+            ///provided value is         not a valid variant of the resize_edge enum
             InvalidResizeEdge = 0u32,
-            #[doc = "invalid parent toplevel"]
+            ///invalid parent toplevel
             InvalidParent = 1u32,
-            #[doc = "client provided an invalid min or max size"]
+            ///client provided an invalid min or max size
             InvalidSize = 2u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidResizeEdge => 1u32,
+                    Self::InvalidParent => 1u32,
+                    Self::InvalidSize => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidResizeEdge => None,
+                    Self::InvalidParent => None,
+                    Self::InvalidSize => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_resize_edge", Self::InvalidResizeEdge),
+                ("invalid_parent", Self::InvalidParent),
+                ("invalid_size", Self::InvalidSize),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidResizeEdge => "invalid_resize_edge",
+                    Self::InvalidParent => "invalid_parent",
+                    Self::InvalidSize => "invalid_size",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -5292,13 +9692,19 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "These values are used to indicate which edge of a surface"]
-        #[doc = "is being dragged in a resize operation."]
+        ///These values are used to indicate which edge of a surface
+        ///is being dragged in a resize operation.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
@@ -5313,11 +9719,85 @@ pub mod xdg_shell {
             TopRight = 9u32,
             BottomRight = 10u32,
         }
+        #[allow(deprecated)]
+        impl ResizeEdge {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::None => 1u32,
+                    Self::Top => 1u32,
+                    Self::Bottom => 1u32,
+                    Self::Left => 1u32,
+                    Self::TopLeft => 1u32,
+                    Self::BottomLeft => 1u32,
+                    Self::Right => 1u32,
+                    Self::TopRight => 1u32,
+                    Self::BottomRight => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::None => None,
+                    Self::Top => None,
+                    Self::Bottom => None,
+                    Self::Left => None,
+                    Self::TopLeft => None,
+                    Self::BottomLeft => None,
+                    Self::Right => None,
+                    Self::TopRight => None,
+                    Self::BottomRight => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("top", Self::Top),
+                ("bottom", Self::Bottom),
+                ("left", Self::Left),
+                ("top_left", Self::TopLeft),
+                ("bottom_left", Self::BottomLeft),
+                ("right", Self::Right),
+                ("top_right", Self::TopRight),
+                ("bottom_right", Self::BottomRight),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::None => "none",
+                    Self::Top => "top",
+                    Self::Bottom => "bottom",
+                    Self::Left => "left",
+                    Self::TopLeft => "top_left",
+                    Self::BottomLeft => "bottom_left",
+                    Self::Right => "right",
+                    Self::TopRight => "top_right",
+                    Self::BottomRight => "bottom_right",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<ResizeEdge> for u32 {
             fn from(value: ResizeEdge) -> Self {
                 value as u32
             }
         }
+        impl From<ResizeEdge> for i32 {
+            fn from(value: ResizeEdge) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for ResizeEdge {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -5335,44 +9815,158 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for ResizeEdge {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for ResizeEdge {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "The different state values used on the surface. This is designed for"]
-        #[doc = "state values like maximized, fullscreen. It is paired with the"]
-        #[doc = "configure event to ensure that both the client and the compositor"]
-        #[doc = "setting the state can be synchronized."]
-        #[doc = ""]
-        #[doc = "States set in this way are double-buffered, see wl_surface.commit."]
+        ///The different state values used on the surface. This is designed for
+        ///state values like maximized, fullscreen. It is paired with the
+        ///configure event to ensure that both the client and the compositor
+        ///setting the state can be synchronized.
+        ///
+        ///States set in this way are double-buffered, see wl_surface.commit.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum State {
-            #[doc = "the surface is maximized"]
+            ///the surface is maximized
             Maximized = 1u32,
-            #[doc = "the surface is fullscreen"]
+            ///the surface is fullscreen
             Fullscreen = 2u32,
-            #[doc = "the surface is being resized"]
+            ///the surface is being resized
             Resizing = 3u32,
-            #[doc = "the surface is now activated"]
+            ///the surface is now activated
             Activated = 4u32,
+            ///
+            ///Available since version 2
             TiledLeft = 5u32,
+            ///
+            ///Available since version 2
             TiledRight = 6u32,
+            ///
+            ///Available since version 2
             TiledTop = 7u32,
+            ///
+            ///Available since version 2
             TiledBottom = 8u32,
+            ///
+            ///Available since version 6
             Suspended = 9u32,
+            ///
+            ///Available since version 7
             ConstrainedLeft = 10u32,
+            ///
+            ///Available since version 7
             ConstrainedRight = 11u32,
+            ///
+            ///Available since version 7
             ConstrainedTop = 12u32,
+            ///
+            ///Available since version 7
             ConstrainedBottom = 13u32,
         }
+        #[allow(deprecated)]
+        impl State {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Maximized => 1u32,
+                    Self::Fullscreen => 1u32,
+                    Self::Resizing => 1u32,
+                    Self::Activated => 1u32,
+                    Self::TiledLeft => 2u32,
+                    Self::TiledRight => 2u32,
+                    Self::TiledTop => 2u32,
+                    Self::TiledBottom => 2u32,
+                    Self::Suspended => 6u32,
+                    Self::ConstrainedLeft => 7u32,
+                    Self::ConstrainedRight => 7u32,
+                    Self::ConstrainedTop => 7u32,
+                    Self::ConstrainedBottom => 7u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Maximized => None,
+                    Self::Fullscreen => None,
+                    Self::Resizing => None,
+                    Self::Activated => None,
+                    Self::TiledLeft => None,
+                    Self::TiledRight => None,
+                    Self::TiledTop => None,
+                    Self::TiledBottom => None,
+                    Self::Suspended => None,
+                    Self::ConstrainedLeft => None,
+                    Self::ConstrainedRight => None,
+                    Self::ConstrainedTop => None,
+                    Self::ConstrainedBottom => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("maximized", Self::Maximized),
+                ("fullscreen", Self::Fullscreen),
+                ("resizing", Self::Resizing),
+                ("activated", Self::Activated),
+                ("tiled_left", Self::TiledLeft),
+                ("tiled_right", Self::TiledRight),
+                ("tiled_top", Self::TiledTop),
+                ("tiled_bottom", Self::TiledBottom),
+                ("suspended", Self::Suspended),
+                ("constrained_left", Self::ConstrainedLeft),
+                ("constrained_right", Self::ConstrainedRight),
+                ("constrained_top", Self::ConstrainedTop),
+                ("constrained_bottom", Self::ConstrainedBottom),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Maximized => "maximized",
+                    Self::Fullscreen => "fullscreen",
+                    Self::Resizing => "resizing",
+                    Self::Activated => "activated",
+                    Self::TiledLeft => "tiled_left",
+                    Self::TiledRight => "tiled_right",
+                    Self::TiledTop => "tiled_top",
+                    Self::TiledBottom => "tiled_bottom",
+                    Self::Suspended => "suspended",
+                    Self::ConstrainedLeft => "constrained_left",
+                    Self::ConstrainedRight => "constrained_right",
+                    Self::ConstrainedTop => "constrained_top",
+                    Self::ConstrainedBottom => "constrained_bottom",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<State> for u32 {
             fn from(value: State) -> Self {
                 value as u32
             }
         }
+        impl From<State> for i32 {
+            fn from(value: State) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for State {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -5394,29 +9988,91 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for State {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for State {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
+        ///
+        ///Available since version 5
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum WmCapabilities {
-            #[doc = "show_window_menu is available"]
+            ///show_window_menu is available
             WindowMenu = 1u32,
-            #[doc = "set_maximized and unset_maximized are available"]
+            ///set_maximized and unset_maximized are available
             Maximize = 2u32,
-            #[doc = "set_fullscreen and unset_fullscreen are available"]
+            ///set_fullscreen and unset_fullscreen are available
             Fullscreen = 3u32,
-            #[doc = "set_minimized is available"]
+            ///set_minimized is available
             Minimize = 4u32,
         }
+        #[allow(deprecated)]
+        impl WmCapabilities {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 5u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::WindowMenu => 1u32,
+                    Self::Maximize => 1u32,
+                    Self::Fullscreen => 1u32,
+                    Self::Minimize => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::WindowMenu => None,
+                    Self::Maximize => None,
+                    Self::Fullscreen => None,
+                    Self::Minimize => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("window_menu", Self::WindowMenu),
+                ("maximize", Self::Maximize),
+                ("fullscreen", Self::Fullscreen),
+                ("minimize", Self::Minimize),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::WindowMenu => "window_menu",
+                    Self::Maximize => "maximize",
+                    Self::Fullscreen => "fullscreen",
+                    Self::Minimize => "minimize",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<WmCapabilities> for u32 {
             fn from(value: WmCapabilities) -> Self {
                 value as u32
             }
         }
+        impl From<WmCapabilities> for i32 {
+            fn from(value: WmCapabilities) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for WmCapabilities {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -5429,584 +10085,1017 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for WmCapabilities {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for WmCapabilities {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_parent",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "parent",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_toplevel"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_title",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "title",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_app_id",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "app_id",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "show_window_menu",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "move",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "resize",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ouu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "edges",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("resize_edge"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_max_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_min_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_fullscreen",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the xdg_toplevel interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the configure message was introduced in
+            pub const CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the configure message was deprecated in, if any
+            pub const CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This configure event asks the client to resize its toplevel surface or
+            ///to change its state. The configured state should not be applied
+            ///immediately. See xdg_surface.configure for details.
+            ///
+            ///The width and height arguments specify a hint to the window
+            ///about how its surface should be resized in window geometry
+            ///coordinates. See set_window_geometry.
+            ///
+            ///If the width or height arguments are zero, it means the client
+            ///should decide its own window dimension. This may happen when the
+            ///compositor needs to configure the state of the surface but doesn't
+            ///have any information about any previous or expected dimension.
+            ///
+            ///The states listed in the event specify how the width/height
+            ///arguments should be interpreted, and possibly how it should be
+            ///drawn.
+            ///
+            ///The states are sent as an array of 32-bit unsigned integers in
+            ///native endianness. State values are defined in the state enum.
+            ///
+            ///Clients must send an ack_configure in response to this event. See
+            ///xdg_surface.configure and xdg_surface.ack_configure for details.
+            pub fn configure<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                width: i32,
+                height: i32,
+                states: Vec<u8>,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "xdg_toplevel", object_id = % sender_id, opcode = 0u16, message =
+                    "configure", direction = "out", "args.width" = % width, "args.height"
+                    = % height, "args.states" = % states.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_toplevel",
+                    object_id : % = sender_id, opcode = 0u16, message = "configure",
+                    direction = "out", "args.width" : % = width, "args.height" : % =
+                    height, "args.states" : % = states.len(); "xdg_toplevel.configure"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(width)
+                    .put_int(height)
+                    .put_array(states)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the close message was introduced in
+            pub const CLOSE_SINCE: u32 = 1u32;
+            ///The interface version the close message was deprecated in, if any
+            pub const CLOSE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The close event is sent by the compositor when the user
+            ///wants the surface to be closed. This should be equivalent to
+            ///the user clicking the close button in client-side decorations,
+            ///if your application has any.
+            ///
+            ///This is only a request that the user intends to close the
+            ///window. The client may choose to ignore this request, or show
+            ///a dialog to ask the user to save their data, etc.
+            pub fn close<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "xdg_toplevel", object_id = % sender_id, opcode = 1u16, message =
+                    "close", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_toplevel",
+                    object_id : % = sender_id, opcode = 1u16, message = "close",
+                    direction = "out"; "xdg_toplevel.close"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the configure_bounds message was introduced in
+            pub const CONFIGURE_BOUNDS_SINCE: u32 = 4u32;
+            ///The interface version the configure_bounds message was deprecated in, if any
+            pub const CONFIGURE_BOUNDS_DEPRECATED_SINCE: Option<u32> = None;
+            ///The configure_bounds event may be sent prior to a xdg_toplevel.configure
+            ///event to communicate the bounds a window geometry size is recommended
+            ///to constrain to.
+            ///
+            ///The passed width and height are in surface coordinate space. If width
+            ///and height are 0, it means bounds is unknown and equivalent to as if no
+            ///configure_bounds event was ever sent for this surface.
+            ///
+            ///The bounds can for example correspond to the size of a monitor excluding
+            ///any panels or other shell components, so that a surface isn't created in
+            ///a way that it cannot fit.
+            ///
+            ///The bounds may change at any point, and in such a case, a new
+            ///xdg_toplevel.configure_bounds will be sent, followed by
+            ///xdg_toplevel.configure and xdg_surface.configure.
+            ///
+            ///Available since version 4
+            pub fn configure_bounds<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                width: i32,
+                height: i32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "xdg_toplevel", object_id = % sender_id, opcode = 2u16, message =
+                    "configure_bounds", direction = "out", "args.width" = % width,
+                    "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_toplevel",
+                    object_id : % = sender_id, opcode = 2u16, message =
+                    "configure_bounds", direction = "out", "args.width" : % = width,
+                    "args.height" : % = height; "xdg_toplevel.configure_bounds"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(width)
+                    .put_int(height)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the wm_capabilities message was introduced in
+            pub const WM_CAPABILITIES_SINCE: u32 = 5u32;
+            ///The interface version the wm_capabilities message was deprecated in, if any
+            pub const WM_CAPABILITIES_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event advertises the capabilities supported by the compositor. If
+            ///a capability isn't supported, clients should hide or disable the UI
+            ///elements that expose this functionality. For instance, if the
+            ///compositor doesn't advertise support for minimized toplevels, a button
+            ///triggering the set_minimized request should not be displayed.
+            ///
+            ///The compositor will ignore requests it doesn't support. For instance,
+            ///a compositor which doesn't advertise support for minimized will ignore
+            ///set_minimized requests.
+            ///
+            ///Compositors must send this event once before the first
+            ///xdg_surface.configure event. When the capabilities change, compositors
+            ///must send this event again and then send an xdg_surface.configure
+            ///event.
+            ///
+            ///The configured state should not be applied immediately. See
+            ///xdg_surface.configure for details.
+            ///
+            ///The capabilities are sent as an array of 32-bit unsigned integers in
+            ///native endianness. Capability values are defined in the wm_capabilities enum.
+            ///
+            ///Available since version 5
+            pub fn wm_capabilities<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                capabilities: Vec<u8>,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "xdg_toplevel", object_id = % sender_id, opcode = 3u16, message =
+                    "wm_capabilities", direction = "out", "args.capabilities" = %
+                    capabilities.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_toplevel",
+                    object_id : % = sender_id, opcode = 3u16, message =
+                    "wm_capabilities", direction = "out", "args.capabilities" : % =
+                    capabilities.len(); "xdg_toplevel.wm_capabilities"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_array(capabilities)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the xdg_toplevel interface. See the module level documentation for more info"]
+        ///Trait to implement the xdg_toplevel interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `xdg_toplevel` interface",
+            label = "`XdgToplevel` is not implemented for `{Self}`"
+        )]
         pub trait XdgToplevel
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "xdg_toplevel";
             const VERSION: u32 = 7u32;
-            #[doc = "This request destroys the role surface and unmaps the surface;"]
-            #[doc = "see \"Unmapping\" behavior in interface section for details."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request destroys the role surface and unmaps the surface;
+            ///see "Unmapping" behavior in interface section for details.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set the \"parent\" of this surface. This surface should be stacked"]
-            #[doc = "above the parent surface and all other ancestor surfaces."]
-            #[doc = ""]
-            #[doc = "Parent surfaces should be set on dialogs, toolboxes, or other"]
-            #[doc = "\"auxiliary\" surfaces, so that the parent is raised when the dialog"]
-            #[doc = "is raised."]
-            #[doc = ""]
-            #[doc = "Setting a null parent for a child surface unsets its parent. Setting"]
-            #[doc = "a null parent for a surface which currently has no parent is a no-op."]
-            #[doc = ""]
-            #[doc = "Only mapped surfaces can have child surfaces. Setting a parent which"]
-            #[doc = "is not mapped is equivalent to setting a null parent. If a surface"]
-            #[doc = "becomes unmapped, its children's parent is set to the parent of"]
-            #[doc = "the now-unmapped surface. If the now-unmapped surface has no parent,"]
-            #[doc = "its children's parent is unset. If the now-unmapped surface becomes"]
-            #[doc = "mapped again, its parent-child relationship is not restored."]
-            #[doc = ""]
-            #[doc = "The parent toplevel must not be one of the child toplevel's"]
-            #[doc = "descendants, and the parent must be different from the child toplevel,"]
-            #[doc = "otherwise the invalid_parent protocol error is raised."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_parent message was introduced in
+            const SET_PARENT_SINCE: u32 = 1u32;
+            ///The interface version the set_parent message was deprecated in, if any
+            const SET_PARENT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the "parent" of this surface. This surface should be stacked
+            ///above the parent surface and all other ancestor surfaces.
+            ///
+            ///Parent surfaces should be set on dialogs, toolboxes, or other
+            ///"auxiliary" surfaces, so that the parent is raised when the dialog
+            ///is raised.
+            ///
+            ///Setting a null parent for a child surface unsets its parent. Setting
+            ///a null parent for a surface which currently has no parent is a no-op.
+            ///
+            ///Only mapped surfaces can have child surfaces. Setting a parent which
+            ///is not mapped is equivalent to setting a null parent. If a surface
+            ///becomes unmapped, its children's parent is set to the parent of
+            ///the now-unmapped surface. If the now-unmapped surface has no parent,
+            ///its children's parent is unset. If the now-unmapped surface becomes
+            ///mapped again, its parent-child relationship is not restored.
+            ///
+            ///The parent toplevel must not be one of the child toplevel's
+            ///descendants, and the parent must be different from the child toplevel,
+            ///otherwise the invalid_parent protocol error is raised.
             fn set_parent(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 parent: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set a short title for the surface."]
-            #[doc = ""]
-            #[doc = "This string may be used to identify the surface in a task bar,"]
-            #[doc = "window list, or other user interface elements provided by the"]
-            #[doc = "compositor."]
-            #[doc = ""]
-            #[doc = "The string must be encoded in UTF-8."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_title message was introduced in
+            const SET_TITLE_SINCE: u32 = 1u32;
+            ///The interface version the set_title message was deprecated in, if any
+            const SET_TITLE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set a short title for the surface.
+            ///
+            ///This string may be used to identify the surface in a task bar,
+            ///window list, or other user interface elements provided by the
+            ///compositor.
+            ///
+            ///The string must be encoded in UTF-8.
             fn set_title(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 title: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set an application identifier for the surface."]
-            #[doc = ""]
-            #[doc = "The app ID identifies the general class of applications to which"]
-            #[doc = "the surface belongs. The compositor can use this to group multiple"]
-            #[doc = "surfaces together, or to determine how to launch a new application."]
-            #[doc = ""]
-            #[doc = "For D-Bus activatable applications, the app ID is used as the D-Bus"]
-            #[doc = "service name."]
-            #[doc = ""]
-            #[doc = "The compositor shell will try to group application surfaces together"]
-            #[doc = "by their app ID. As a best practice, it is suggested to select app"]
-            #[doc = "ID's that match the basename of the application's .desktop file."]
-            #[doc = "For example, \"org.freedesktop.FooViewer\" where the .desktop file is"]
-            #[doc = "\"org.freedesktop.FooViewer.desktop\"."]
-            #[doc = ""]
-            #[doc = "Like other properties, a set_app_id request can be sent after the"]
-            #[doc = "xdg_toplevel has been mapped to update the property."]
-            #[doc = ""]
-            #[doc = "See the desktop-entry specification [0] for more details on"]
-            #[doc = "application identifiers and how they relate to well-known D-Bus"]
-            #[doc = "names and .desktop files."]
-            #[doc = ""]
-            #[doc = "[0] https://standards.freedesktop.org/desktop-entry-spec/"]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_app_id message was introduced in
+            const SET_APP_ID_SINCE: u32 = 1u32;
+            ///The interface version the set_app_id message was deprecated in, if any
+            const SET_APP_ID_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set an application identifier for the surface.
+            ///
+            ///The app ID identifies the general class of applications to which
+            ///the surface belongs. The compositor can use this to group multiple
+            ///surfaces together, or to determine how to launch a new application.
+            ///
+            ///For D-Bus activatable applications, the app ID is used as the D-Bus
+            ///service name.
+            ///
+            ///The compositor shell will try to group application surfaces together
+            ///by their app ID. As a best practice, it is suggested to select app
+            ///ID's that match the basename of the application's .desktop file.
+            ///For example, "org.freedesktop.FooViewer" where the .desktop file is
+            ///"org.freedesktop.FooViewer.desktop".
+            ///
+            ///Like other properties, a set_app_id request can be sent after the
+            ///xdg_toplevel has been mapped to update the property.
+            ///
+            ///See the desktop-entry specification [0] for more details on
+            ///application identifiers and how they relate to well-known D-Bus
+            ///names and .desktop files.
+            ///
+            ///[0] https://standards.freedesktop.org/desktop-entry-spec/
             fn set_app_id(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 app_id: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Clients implementing client-side decorations might want to show"]
-            #[doc = "a context menu when right-clicking on the decorations, giving the"]
-            #[doc = "user a menu that they can use to maximize or minimize the window."]
-            #[doc = ""]
-            #[doc = "This request asks the compositor to pop up such a window menu at"]
-            #[doc = "the given position, relative to the local surface coordinates of"]
-            #[doc = "the parent surface. There are no guarantees as to what menu items"]
-            #[doc = "the window menu contains, or even if a window menu will be drawn"]
-            #[doc = "at all."]
-            #[doc = ""]
-            #[doc = "This request must be used in response to some sort of user action"]
-            #[doc = "like a button press, key press, or touch down event."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the show_window_menu message was introduced in
+            const SHOW_WINDOW_MENU_SINCE: u32 = 1u32;
+            ///The interface version the show_window_menu message was deprecated in, if any
+            const SHOW_WINDOW_MENU_DEPRECATED_SINCE: Option<u32> = None;
+            ///Clients implementing client-side decorations might want to show
+            ///a context menu when right-clicking on the decorations, giving the
+            ///user a menu that they can use to maximize or minimize the window.
+            ///
+            ///This request asks the compositor to pop up such a window menu at
+            ///the given position, relative to the local surface coordinates of
+            ///the parent surface. There are no guarantees as to what menu items
+            ///the window menu contains, or even if a window menu will be drawn
+            ///at all.
+            ///
+            ///This request must be used in response to some sort of user action
+            ///like a button press, key press, or touch down event.
             fn show_window_menu(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Start an interactive, user-driven move of the surface."]
-            #[doc = ""]
-            #[doc = "This request must be used in response to some sort of user action"]
-            #[doc = "like a button press, key press, or touch down event. The passed"]
-            #[doc = "serial is used to determine the type of interactive move (touch,"]
-            #[doc = "pointer, etc)."]
-            #[doc = ""]
-            #[doc = "The server may ignore move requests depending on the state of"]
-            #[doc = "the surface (e.g. fullscreen or maximized), or if the passed serial"]
-            #[doc = "is no longer valid."]
-            #[doc = ""]
-            #[doc = "If triggered, the surface will lose the focus of the device"]
-            #[doc = "(wl_pointer, wl_touch, etc) used for the move. It is up to the"]
-            #[doc = "compositor to visually indicate that the move is taking place, such as"]
-            #[doc = "updating a pointer cursor, during the move. There is no guarantee"]
-            #[doc = "that the device focus will return when the move is completed."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the move message was introduced in
+            const MOVE_SINCE: u32 = 1u32;
+            ///The interface version the move message was deprecated in, if any
+            const MOVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Start an interactive, user-driven move of the surface.
+            ///
+            ///This request must be used in response to some sort of user action
+            ///like a button press, key press, or touch down event. The passed
+            ///serial is used to determine the type of interactive move (touch,
+            ///pointer, etc).
+            ///
+            ///The server may ignore move requests depending on the state of
+            ///the surface (e.g. fullscreen or maximized), or if the passed serial
+            ///is no longer valid.
+            ///
+            ///If triggered, the surface will lose the focus of the device
+            ///(wl_pointer, wl_touch, etc) used for the move. It is up to the
+            ///compositor to visually indicate that the move is taking place, such as
+            ///updating a pointer cursor, during the move. There is no guarantee
+            ///that the device focus will return when the move is completed.
             fn r#move(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Start a user-driven, interactive resize of the surface."]
-            #[doc = ""]
-            #[doc = "This request must be used in response to some sort of user action"]
-            #[doc = "like a button press, key press, or touch down event. The passed"]
-            #[doc = "serial is used to determine the type of interactive resize (touch,"]
-            #[doc = "pointer, etc)."]
-            #[doc = ""]
-            #[doc = "The server may ignore resize requests depending on the state of"]
-            #[doc = "the surface (e.g. fullscreen or maximized)."]
-            #[doc = ""]
-            #[doc = "If triggered, the client will receive configure events with the"]
-            #[doc = "\"resize\" state enum value and the expected sizes. See the \"resize\""]
-            #[doc = "enum value for more details about what is required. The client"]
-            #[doc = "must also acknowledge configure events using \"ack_configure\". After"]
-            #[doc = "the resize is completed, the client will receive another \"configure\""]
-            #[doc = "event without the resize state."]
-            #[doc = ""]
-            #[doc = "If triggered, the surface also will lose the focus of the device"]
-            #[doc = "(wl_pointer, wl_touch, etc) used for the resize. It is up to the"]
-            #[doc = "compositor to visually indicate that the resize is taking place,"]
-            #[doc = "such as updating a pointer cursor, during the resize. There is no"]
-            #[doc = "guarantee that the device focus will return when the resize is"]
-            #[doc = "completed."]
-            #[doc = ""]
-            #[doc = "The edges parameter specifies how the surface should be resized, and"]
-            #[doc = "is one of the values of the resize_edge enum. Values not matching"]
-            #[doc = "a variant of the enum will cause the invalid_resize_edge protocol error."]
-            #[doc = "The compositor may use this information to update the surface position"]
-            #[doc = "for example when dragging the top left corner. The compositor may also"]
-            #[doc = "use this information to adapt its behavior, e.g. choose an appropriate"]
-            #[doc = "cursor image."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the resize message was introduced in
+            const RESIZE_SINCE: u32 = 1u32;
+            ///The interface version the resize message was deprecated in, if any
+            const RESIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Start a user-driven, interactive resize of the surface.
+            ///
+            ///This request must be used in response to some sort of user action
+            ///like a button press, key press, or touch down event. The passed
+            ///serial is used to determine the type of interactive resize (touch,
+            ///pointer, etc).
+            ///
+            ///The server may ignore resize requests depending on the state of
+            ///the surface (e.g. fullscreen or maximized).
+            ///
+            ///If triggered, the client will receive configure events with the
+            ///"resize" state enum value and the expected sizes. See the "resize"
+            ///enum value for more details about what is required. The client
+            ///must also acknowledge configure events using "ack_configure". After
+            ///the resize is completed, the client will receive another "configure"
+            ///event without the resize state.
+            ///
+            ///If triggered, the surface also will lose the focus of the device
+            ///(wl_pointer, wl_touch, etc) used for the resize. It is up to the
+            ///compositor to visually indicate that the resize is taking place,
+            ///such as updating a pointer cursor, during the resize. There is no
+            ///guarantee that the device focus will return when the resize is
+            ///completed.
+            ///
+            ///The edges parameter specifies how the surface should be resized, and
+            ///is one of the values of the resize_edge enum. Values not matching
+            ///a variant of the enum will cause the invalid_resize_edge protocol error.
+            ///The compositor may use this information to update the surface position
+            ///for example when dragging the top left corner. The compositor may also
+            ///use this information to adapt its behavior, e.g. choose an appropriate
+            ///cursor image.
             fn resize(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
                 edges: ResizeEdge,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set a maximum size for the window."]
-            #[doc = ""]
-            #[doc = "The client can specify a maximum size so that the compositor does"]
-            #[doc = "not try to configure the window beyond this size."]
-            #[doc = ""]
-            #[doc = "The width and height arguments are in window geometry coordinates."]
-            #[doc = "See xdg_surface.set_window_geometry."]
-            #[doc = ""]
-            #[doc = "Values set in this way are double-buffered, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "The compositor can use this information to allow or disallow"]
-            #[doc = "different states like maximize or fullscreen and draw accurate"]
-            #[doc = "animations."]
-            #[doc = ""]
-            #[doc = "Similarly, a tiling window manager may use this information to"]
-            #[doc = "place and resize client windows in a more effective way."]
-            #[doc = ""]
-            #[doc = "The client should not rely on the compositor to obey the maximum"]
-            #[doc = "size. The compositor may decide to ignore the values set by the"]
-            #[doc = "client and request a larger size."]
-            #[doc = ""]
-            #[doc = "If never set, or a value of zero in the request, means that the"]
-            #[doc = "client has no expected maximum size in the given dimension."]
-            #[doc = "As a result, a client wishing to reset the maximum size"]
-            #[doc = "to an unspecified state can use zero for width and height in the"]
-            #[doc = "request."]
-            #[doc = ""]
-            #[doc = "Requesting a maximum size to be smaller than the minimum size of"]
-            #[doc = "a surface is illegal and will result in an invalid_size error."]
-            #[doc = ""]
-            #[doc = "The width and height must be greater than or equal to zero. Using"]
-            #[doc = "strictly negative values for width or height will result in an"]
-            #[doc = "invalid_size error."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_max_size message was introduced in
+            const SET_MAX_SIZE_SINCE: u32 = 1u32;
+            ///The interface version the set_max_size message was deprecated in, if any
+            const SET_MAX_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set a maximum size for the window.
+            ///
+            ///The client can specify a maximum size so that the compositor does
+            ///not try to configure the window beyond this size.
+            ///
+            ///The width and height arguments are in window geometry coordinates.
+            ///See xdg_surface.set_window_geometry.
+            ///
+            ///Values set in this way are double-buffered, see wl_surface.commit.
+            ///
+            ///The compositor can use this information to allow or disallow
+            ///different states like maximize or fullscreen and draw accurate
+            ///animations.
+            ///
+            ///Similarly, a tiling window manager may use this information to
+            ///place and resize client windows in a more effective way.
+            ///
+            ///The client should not rely on the compositor to obey the maximum
+            ///size. The compositor may decide to ignore the values set by the
+            ///client and request a larger size.
+            ///
+            ///If never set, or a value of zero in the request, means that the
+            ///client has no expected maximum size in the given dimension.
+            ///As a result, a client wishing to reset the maximum size
+            ///to an unspecified state can use zero for width and height in the
+            ///request.
+            ///
+            ///Requesting a maximum size to be smaller than the minimum size of
+            ///a surface is illegal and will result in an invalid_size error.
+            ///
+            ///The width and height must be greater than or equal to zero. Using
+            ///strictly negative values for width or height will result in an
+            ///invalid_size error.
             fn set_max_size(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set a minimum size for the window."]
-            #[doc = ""]
-            #[doc = "The client can specify a minimum size so that the compositor does"]
-            #[doc = "not try to configure the window below this size."]
-            #[doc = ""]
-            #[doc = "The width and height arguments are in window geometry coordinates."]
-            #[doc = "See xdg_surface.set_window_geometry."]
-            #[doc = ""]
-            #[doc = "Values set in this way are double-buffered, see wl_surface.commit."]
-            #[doc = ""]
-            #[doc = "The compositor can use this information to allow or disallow"]
-            #[doc = "different states like maximize or fullscreen and draw accurate"]
-            #[doc = "animations."]
-            #[doc = ""]
-            #[doc = "Similarly, a tiling window manager may use this information to"]
-            #[doc = "place and resize client windows in a more effective way."]
-            #[doc = ""]
-            #[doc = "The client should not rely on the compositor to obey the minimum"]
-            #[doc = "size. The compositor may decide to ignore the values set by the"]
-            #[doc = "client and request a smaller size."]
-            #[doc = ""]
-            #[doc = "If never set, or a value of zero in the request, means that the"]
-            #[doc = "client has no expected minimum size in the given dimension."]
-            #[doc = "As a result, a client wishing to reset the minimum size"]
-            #[doc = "to an unspecified state can use zero for width and height in the"]
-            #[doc = "request."]
-            #[doc = ""]
-            #[doc = "Requesting a minimum size to be larger than the maximum size of"]
-            #[doc = "a surface is illegal and will result in an invalid_size error."]
-            #[doc = ""]
-            #[doc = "The width and height must be greater than or equal to zero. Using"]
-            #[doc = "strictly negative values for width and height will result in an"]
-            #[doc = "invalid_size error."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_min_size message was introduced in
+            const SET_MIN_SIZE_SINCE: u32 = 1u32;
+            ///The interface version the set_min_size message was deprecated in, if any
+            const SET_MIN_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set a minimum size for the window.
+            ///
+            ///The client can specify a minimum size so that the compositor does
+            ///not try to configure the window below this size.
+            ///
+            ///The width and height arguments are in window geometry coordinates.
+            ///See xdg_surface.set_window_geometry.
+            ///
+            ///Values set in this way are double-buffered, see wl_surface.commit.
+            ///
+            ///The compositor can use this information to allow or disallow
+            ///different states like maximize or fullscreen and draw accurate
+            ///animations.
+            ///
+            ///Similarly, a tiling window manager may use this information to
+            ///place and resize client windows in a more effective way.
+            ///
+            ///The client should not rely on the compositor to obey the minimum
+            ///size. The compositor may decide to ignore the values set by the
+            ///client and request a smaller size.
+            ///
+            ///If never set, or a value of zero in the request, means that the
+            ///client has no expected minimum size in the given dimension.
+            ///As a result, a client wishing to reset the minimum size
+            ///to an unspecified state can use zero for width and height in the
+            ///request.
+            ///
+            ///Requesting a minimum size to be larger than the maximum size of
+            ///a surface is illegal and will result in an invalid_size error.
+            ///
+            ///The width and height must be greater than or equal to zero. Using
+            ///strictly negative values for width and height will result in an
+            ///invalid_size error.
             fn set_min_size(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Maximize the surface."]
-            #[doc = ""]
-            #[doc = "After requesting that the surface should be maximized, the compositor"]
-            #[doc = "will respond by emitting a configure event. Whether this configure"]
-            #[doc = "actually sets the window maximized is subject to compositor policies."]
-            #[doc = "The client must then update its content, drawing in the configured"]
-            #[doc = "state. The client must also acknowledge the configure when committing"]
-            #[doc = "the new content (see ack_configure)."]
-            #[doc = ""]
-            #[doc = "It is up to the compositor to decide how and where to maximize the"]
-            #[doc = "surface, for example which output and what region of the screen should"]
-            #[doc = "be used."]
-            #[doc = ""]
-            #[doc = "If the surface was already maximized, the compositor will still emit"]
-            #[doc = "a configure event with the \"maximized\" state."]
-            #[doc = ""]
-            #[doc = "If the surface is in a fullscreen state, this request has no direct"]
-            #[doc = "effect. It may alter the state the surface is returned to when"]
-            #[doc = "unmaximized unless overridden by the compositor."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_maximized message was introduced in
+            const SET_MAXIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the set_maximized message was deprecated in, if any
+            const SET_MAXIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Maximize the surface.
+            ///
+            ///After requesting that the surface should be maximized, the compositor
+            ///will respond by emitting a configure event. Whether this configure
+            ///actually sets the window maximized is subject to compositor policies.
+            ///The client must then update its content, drawing in the configured
+            ///state. The client must also acknowledge the configure when committing
+            ///the new content (see ack_configure).
+            ///
+            ///It is up to the compositor to decide how and where to maximize the
+            ///surface, for example which output and what region of the screen should
+            ///be used.
+            ///
+            ///If the surface was already maximized, the compositor will still emit
+            ///a configure event with the "maximized" state.
+            ///
+            ///If the surface is in a fullscreen state, this request has no direct
+            ///effect. It may alter the state the surface is returned to when
+            ///unmaximized unless overridden by the compositor.
             fn set_maximized(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Unmaximize the surface."]
-            #[doc = ""]
-            #[doc = "After requesting that the surface should be unmaximized, the compositor"]
-            #[doc = "will respond by emitting a configure event. Whether this actually"]
-            #[doc = "un-maximizes the window is subject to compositor policies."]
-            #[doc = "If available and applicable, the compositor will include the window"]
-            #[doc = "geometry dimensions the window had prior to being maximized in the"]
-            #[doc = "configure event. The client must then update its content, drawing it in"]
-            #[doc = "the configured state. The client must also acknowledge the configure"]
-            #[doc = "when committing the new content (see ack_configure)."]
-            #[doc = ""]
-            #[doc = "It is up to the compositor to position the surface after it was"]
-            #[doc = "unmaximized; usually the position the surface had before maximizing, if"]
-            #[doc = "applicable."]
-            #[doc = ""]
-            #[doc = "If the surface was already not maximized, the compositor will still"]
-            #[doc = "emit a configure event without the \"maximized\" state."]
-            #[doc = ""]
-            #[doc = "If the surface is in a fullscreen state, this request has no direct"]
-            #[doc = "effect. It may alter the state the surface is returned to when"]
-            #[doc = "unmaximized unless overridden by the compositor."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the unset_maximized message was introduced in
+            const UNSET_MAXIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the unset_maximized message was deprecated in, if any
+            const UNSET_MAXIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Unmaximize the surface.
+            ///
+            ///After requesting that the surface should be unmaximized, the compositor
+            ///will respond by emitting a configure event. Whether this actually
+            ///un-maximizes the window is subject to compositor policies.
+            ///If available and applicable, the compositor will include the window
+            ///geometry dimensions the window had prior to being maximized in the
+            ///configure event. The client must then update its content, drawing it in
+            ///the configured state. The client must also acknowledge the configure
+            ///when committing the new content (see ack_configure).
+            ///
+            ///It is up to the compositor to position the surface after it was
+            ///unmaximized; usually the position the surface had before maximizing, if
+            ///applicable.
+            ///
+            ///If the surface was already not maximized, the compositor will still
+            ///emit a configure event without the "maximized" state.
+            ///
+            ///If the surface is in a fullscreen state, this request has no direct
+            ///effect. It may alter the state the surface is returned to when
+            ///unmaximized unless overridden by the compositor.
             fn unset_maximized(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Make the surface fullscreen."]
-            #[doc = ""]
-            #[doc = "After requesting that the surface should be fullscreened, the"]
-            #[doc = "compositor will respond by emitting a configure event. Whether the"]
-            #[doc = "client is actually put into a fullscreen state is subject to compositor"]
-            #[doc = "policies. The client must also acknowledge the configure when"]
-            #[doc = "committing the new content (see ack_configure)."]
-            #[doc = ""]
-            #[doc = "The output passed by the request indicates the client's preference as"]
-            #[doc = "to which display it should be set fullscreen on. If this value is NULL,"]
-            #[doc = "it's up to the compositor to choose which display will be used to map"]
-            #[doc = "this surface."]
-            #[doc = ""]
-            #[doc = "If the surface doesn't cover the whole output, the compositor will"]
-            #[doc = "position the surface in the center of the output and compensate with"]
-            #[doc = "border fill covering the rest of the output. The content of the"]
-            #[doc = "border fill is undefined, but should be assumed to be in some way that"]
-            #[doc = "attempts to blend into the surrounding area (e.g. solid black)."]
-            #[doc = ""]
-            #[doc = "If the fullscreened surface is not opaque, the compositor must make"]
-            #[doc = "sure that other screen content not part of the same surface tree (made"]
-            #[doc = "up of subsurfaces, popups or similarly coupled surfaces) are not"]
-            #[doc = "visible below the fullscreened surface."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_fullscreen message was introduced in
+            const SET_FULLSCREEN_SINCE: u32 = 1u32;
+            ///The interface version the set_fullscreen message was deprecated in, if any
+            const SET_FULLSCREEN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Make the surface fullscreen.
+            ///
+            ///After requesting that the surface should be fullscreened, the
+            ///compositor will respond by emitting a configure event. Whether the
+            ///client is actually put into a fullscreen state is subject to compositor
+            ///policies. The client must also acknowledge the configure when
+            ///committing the new content (see ack_configure).
+            ///
+            ///The output passed by the request indicates the client's preference as
+            ///to which display it should be set fullscreen on. If this value is NULL,
+            ///it's up to the compositor to choose which display will be used to map
+            ///this surface.
+            ///
+            ///If the surface doesn't cover the whole output, the compositor will
+            ///position the surface in the center of the output and compensate with
+            ///border fill covering the rest of the output. The content of the
+            ///border fill is undefined, but should be assumed to be in some way that
+            ///attempts to blend into the surrounding area (e.g. solid black).
+            ///
+            ///If the fullscreened surface is not opaque, the compositor must make
+            ///sure that other screen content not part of the same surface tree (made
+            ///up of subsurfaces, popups or similarly coupled surfaces) are not
+            ///visible below the fullscreened surface.
             fn set_fullscreen(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 output: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Make the surface no longer fullscreen."]
-            #[doc = ""]
-            #[doc = "After requesting that the surface should be unfullscreened, the"]
-            #[doc = "compositor will respond by emitting a configure event."]
-            #[doc = "Whether this actually removes the fullscreen state of the client is"]
-            #[doc = "subject to compositor policies."]
-            #[doc = ""]
-            #[doc = "Making a surface unfullscreen sets states for the surface based on the following:"]
-            #[doc = "* the state(s) it may have had before becoming fullscreen"]
-            #[doc = "* any state(s) decided by the compositor"]
-            #[doc = "* any state(s) requested by the client while the surface was fullscreen"]
-            #[doc = ""]
-            #[doc = "The compositor may include the previous window geometry dimensions in"]
-            #[doc = "the configure event, if applicable."]
-            #[doc = ""]
-            #[doc = "The client must also acknowledge the configure when committing the new"]
-            #[doc = "content (see ack_configure)."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the unset_fullscreen message was introduced in
+            const UNSET_FULLSCREEN_SINCE: u32 = 1u32;
+            ///The interface version the unset_fullscreen message was deprecated in, if any
+            const UNSET_FULLSCREEN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Make the surface no longer fullscreen.
+            ///
+            ///After requesting that the surface should be unfullscreened, the
+            ///compositor will respond by emitting a configure event.
+            ///Whether this actually removes the fullscreen state of the client is
+            ///subject to compositor policies.
+            ///
+            ///Making a surface unfullscreen sets states for the surface based on the following:
+            ///* the state(s) it may have had before becoming fullscreen
+            ///* any state(s) decided by the compositor
+            ///* any state(s) requested by the client while the surface was fullscreen
+            ///
+            ///The compositor may include the previous window geometry dimensions in
+            ///the configure event, if applicable.
+            ///
+            ///The client must also acknowledge the configure when committing the new
+            ///content (see ack_configure).
             fn unset_fullscreen(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Request that the compositor minimize your surface. There is no"]
-            #[doc = "way to know if the surface is currently minimized, nor is there"]
-            #[doc = "any way to unset minimization on this surface."]
-            #[doc = ""]
-            #[doc = "If you are looking to throttle redrawing when minimized, please"]
-            #[doc = "instead use the wl_surface.frame event for this, as this will"]
-            #[doc = "also work with live previews on windows in Alt-Tab, Expose or"]
-            #[doc = "similar compositor features."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_minimized message was introduced in
+            const SET_MINIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the set_minimized message was deprecated in, if any
+            const SET_MINIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request that the compositor minimize your surface. There is no
+            ///way to know if the surface is currently minimized, nor is there
+            ///any way to unset minimization on this surface.
+            ///
+            ///If you are looking to throttle redrawing when minimized, please
+            ///instead use the wl_surface.frame event for this, as this will
+            ///also work with live previews on windows in Alt-Tab, Expose or
+            ///similar compositor features.
             fn set_minimized(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This configure event asks the client to resize its toplevel surface or"]
-            #[doc = "to change its state. The configured state should not be applied"]
-            #[doc = "immediately. See xdg_surface.configure for details."]
-            #[doc = ""]
-            #[doc = "The width and height arguments specify a hint to the window"]
-            #[doc = "about how its surface should be resized in window geometry"]
-            #[doc = "coordinates. See set_window_geometry."]
-            #[doc = ""]
-            #[doc = "If the width or height arguments are zero, it means the client"]
-            #[doc = "should decide its own window dimension. This may happen when the"]
-            #[doc = "compositor needs to configure the state of the surface but doesn't"]
-            #[doc = "have any information about any previous or expected dimension."]
-            #[doc = ""]
-            #[doc = "The states listed in the event specify how the width/height"]
-            #[doc = "arguments should be interpreted, and possibly how it should be"]
-            #[doc = "drawn."]
-            #[doc = ""]
-            #[doc = "The states are sent as an array of 32-bit unsigned integers in"]
-            #[doc = "native endianness. State values are defined in the state enum."]
-            #[doc = ""]
-            #[doc = "Clients must send an ack_configure in response to this event. See"]
-            #[doc = "xdg_surface.configure and xdg_surface.ack_configure for details."]
-            fn configure(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                width: i32,
-                height: i32,
-                states: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> xdg_toplevel#{}.configure({}, {}, array[{}])",
-                        sender_id,
-                        width,
-                        height,
-                        states.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(width)
-                        .put_int(height)
-                        .put_array(states)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The close event is sent by the compositor when the user"]
-            #[doc = "wants the surface to be closed. This should be equivalent to"]
-            #[doc = "the user clicking the close button in client-side decorations,"]
-            #[doc = "if your application has any."]
-            #[doc = ""]
-            #[doc = "This is only a request that the user intends to close the"]
-            #[doc = "window. The client may choose to ignore this request, or show"]
-            #[doc = "a dialog to ask the user to save their data, etc."]
-            fn close(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> xdg_toplevel#{}.close()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The configure_bounds event may be sent prior to a xdg_toplevel.configure"]
-            #[doc = "event to communicate the bounds a window geometry size is recommended"]
-            #[doc = "to constrain to."]
-            #[doc = ""]
-            #[doc = "The passed width and height are in surface coordinate space. If width"]
-            #[doc = "and height are 0, it means bounds is unknown and equivalent to as if no"]
-            #[doc = "configure_bounds event was ever sent for this surface."]
-            #[doc = ""]
-            #[doc = "The bounds can for example correspond to the size of a monitor excluding"]
-            #[doc = "any panels or other shell components, so that a surface isn't created in"]
-            #[doc = "a way that it cannot fit."]
-            #[doc = ""]
-            #[doc = "The bounds may change at any point, and in such a case, a new"]
-            #[doc = "xdg_toplevel.configure_bounds will be sent, followed by"]
-            #[doc = "xdg_toplevel.configure and xdg_surface.configure."]
-            fn configure_bounds(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                width: i32,
-                height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> xdg_toplevel#{}.configure_bounds({}, {})",
-                        sender_id,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(width)
-                        .put_int(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event advertises the capabilities supported by the compositor. If"]
-            #[doc = "a capability isn't supported, clients should hide or disable the UI"]
-            #[doc = "elements that expose this functionality. For instance, if the"]
-            #[doc = "compositor doesn't advertise support for minimized toplevels, a button"]
-            #[doc = "triggering the set_minimized request should not be displayed."]
-            #[doc = ""]
-            #[doc = "The compositor will ignore requests it doesn't support. For instance,"]
-            #[doc = "a compositor which doesn't advertise support for minimized will ignore"]
-            #[doc = "set_minimized requests."]
-            #[doc = ""]
-            #[doc = "Compositors must send this event once before the first"]
-            #[doc = "xdg_surface.configure event. When the capabilities change, compositors"]
-            #[doc = "must send this event again and then send an xdg_surface.configure"]
-            #[doc = "event."]
-            #[doc = ""]
-            #[doc = "The configured state should not be applied immediately. See"]
-            #[doc = "xdg_surface.configure for details."]
-            #[doc = ""]
-            #[doc = "The capabilities are sent as an array of 32-bit unsigned integers in"]
-            #[doc = "native endianness. Capability values are defined in the wm_capabilities enum."]
-            fn wm_capabilities(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                capabilities: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> xdg_toplevel#{}.wm_capabilities(array[{}])",
-                        sender_id,
-                        capabilities.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_array(capabilities)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 3u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_toplevel#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in";
+                                "xdg_toplevel.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let parent = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.set_parent({})",
-                                sender_id,
-                                parent
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_parent(connection, sender_id, parent).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 1u16,
+                                message = "set_parent", direction = "in", "args.parent" = %
+                                parent.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 1u16,
+                                message = "set_parent", direction = "in", "args.parent" : %
+                                = parent.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()); "xdg_toplevel.set_parent"
+                            );
+                            self.set_parent(connection, state, sender_id, parent).await
                         }
                         2u16 => {
                             let title = message
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_toplevel#{}.set_title(\"{}\")", sender_id, title);
-                            self.set_title(connection, sender_id, title).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 2u16,
+                                message = "set_title", direction = "in", "args.title" = %
+                                title,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 2u16,
+                                message = "set_title", direction = "in", "args.title" : % =
+                                title; "xdg_toplevel.set_title"
+                            );
+                            self.set_title(connection, state, sender_id, title).await
                         }
                         3u16 => {
                             let app_id = message
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.set_app_id(\"{}\")",
-                                sender_id,
-                                app_id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 3u16,
+                                message = "set_app_id", direction = "in", "args.app_id" = %
+                                app_id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 3u16,
+                                message = "set_app_id", direction = "in", "args.app_id" : %
+                                = app_id; "xdg_toplevel.set_app_id"
                             );
-                            self.set_app_id(connection, sender_id, app_id).await
+                            self.set_app_id(connection, state, sender_id, app_id).await
                         }
                         4u16 => {
                             let seat = message
@@ -6016,15 +11105,30 @@ pub mod xdg_shell {
                             let x = message.int()?;
                             let y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.show_window_menu({}, {}, {}, {})",
-                                sender_id,
-                                seat,
-                                serial,
-                                x,
-                                y
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 4u16,
+                                message = "show_window_menu", direction = "in", "args.seat"
+                                = % seat, "args.serial" = % serial, "args.x" = % x, "args.y"
+                                = % y,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 4u16,
+                                message = "show_window_menu", direction = "in", "args.seat"
+                                : % = seat, "args.serial" : % = serial, "args.x" : % = x,
+                                "args.y" : % = y; "xdg_toplevel.show_window_menu"
                             );
-                            self.show_window_menu(connection, sender_id, seat, serial, x, y)
+                            self.show_window_menu(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    seat,
+                                    serial,
+                                    x,
+                                    y,
+                                )
                                 .await
                         }
                         5u16 => {
@@ -6033,133 +11137,279 @@ pub mod xdg_shell {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.move({}, {})",
-                                sender_id,
-                                seat,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 5u16,
+                                message = "move", direction = "in", "args.seat" = % seat,
+                                "args.serial" = % serial,
                             );
-                            self.r#move(connection, sender_id, seat, serial).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 5u16,
+                                message = "move", direction = "in", "args.seat" : % = seat,
+                                "args.serial" : % = serial; "xdg_toplevel.move"
+                            );
+                            self.r#move(connection, state, sender_id, seat, serial).await
                         }
                         6u16 => {
                             let seat = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
-                            let edges = message.uint()?;
+                            let edges: ResizeEdge = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.resize({}, {}, {})",
-                                sender_id,
-                                seat,
-                                serial,
-                                edges
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 6u16,
+                                message = "resize", direction = "in", "args.seat" = % seat,
+                                "args.serial" = % serial, "args.edges" = % edges,
                             );
-                            self.resize(connection, sender_id, seat, serial, edges.try_into()?)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 6u16,
+                                message = "resize", direction = "in", "args.seat" : % =
+                                seat, "args.serial" : % = serial, "args.edges" : % = edges;
+                                "xdg_toplevel.resize"
+                            );
+                            self.resize(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    seat,
+                                    serial,
+                                    edges,
+                                )
                                 .await
                         }
                         7u16 => {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.set_max_size({}, {})",
-                                sender_id,
-                                width,
-                                height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 7u16,
+                                message = "set_max_size", direction = "in", "args.width" = %
+                                width, "args.height" = % height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 7u16,
+                                message = "set_max_size", direction = "in", "args.width" : %
+                                = width, "args.height" : % = height;
+                                "xdg_toplevel.set_max_size"
                             );
-                            self.set_max_size(connection, sender_id, width, height)
+                            self.set_max_size(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         8u16 => {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.set_min_size({}, {})",
-                                sender_id,
-                                width,
-                                height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 8u16,
+                                message = "set_min_size", direction = "in", "args.width" = %
+                                width, "args.height" = % height,
                             );
-                            self.set_min_size(connection, sender_id, width, height)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 8u16,
+                                message = "set_min_size", direction = "in", "args.width" : %
+                                = width, "args.height" : % = height;
+                                "xdg_toplevel.set_min_size"
+                            );
+                            self.set_min_size(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         9u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_toplevel#{}.set_maximized()", sender_id,);
-                            self.set_maximized(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 9u16,
+                                message = "set_maximized", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 9u16,
+                                message = "set_maximized", direction = "in";
+                                "xdg_toplevel.set_maximized"
+                            );
+                            self.set_maximized(connection, state, sender_id).await
                         }
                         10u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_toplevel#{}.unset_maximized()", sender_id,);
-                            self.unset_maximized(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 10u16,
+                                message = "unset_maximized", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 10u16,
+                                message = "unset_maximized", direction = "in";
+                                "xdg_toplevel.unset_maximized"
+                            );
+                            self.unset_maximized(connection, state, sender_id).await
                         }
                         11u16 => {
                             let output = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_toplevel#{}.set_fullscreen({})",
-                                sender_id,
-                                output
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_fullscreen(connection, sender_id, output).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 11u16,
+                                message = "set_fullscreen", direction = "in", "args.output"
+                                = % output.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 11u16,
+                                message = "set_fullscreen", direction = "in", "args.output"
+                                : % = output.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()); "xdg_toplevel.set_fullscreen"
+                            );
+                            self.set_fullscreen(connection, state, sender_id, output)
+                                .await
                         }
                         12u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_toplevel#{}.unset_fullscreen()", sender_id,);
-                            self.unset_fullscreen(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 12u16,
+                                message = "unset_fullscreen", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 12u16,
+                                message = "unset_fullscreen", direction = "in";
+                                "xdg_toplevel.unset_fullscreen"
+                            );
+                            self.unset_fullscreen(connection, state, sender_id).await
                         }
                         13u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_toplevel#{}.set_minimized()", sender_id,);
-                            self.set_minimized(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_toplevel", object_id = % sender_id, opcode = 13u16,
+                                message = "set_minimized", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_toplevel", object_id : % = sender_id, opcode = 13u16,
+                                message = "set_minimized", direction = "in";
+                                "xdg_toplevel.set_minimized"
+                            );
+                            self.set_minimized(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A popup surface is a short-lived, temporary surface. It can be used to"]
-    #[doc = "implement for example menus, popovers, tooltips and other similar user"]
-    #[doc = "interface concepts."]
-    #[doc = ""]
-    #[doc = "A popup can be made to take an explicit grab. See xdg_popup.grab for"]
-    #[doc = "details."]
-    #[doc = ""]
-    #[doc = "When the popup is dismissed, a popup_done event will be sent out, and at"]
-    #[doc = "the same time the surface will be unmapped. See the xdg_popup.popup_done"]
-    #[doc = "event for details."]
-    #[doc = ""]
-    #[doc = "Explicitly destroying the xdg_popup object will also dismiss the popup and"]
-    #[doc = "unmap the surface. Clients that want to dismiss the popup when another"]
-    #[doc = "surface of their own is clicked should dismiss the popup using the destroy"]
-    #[doc = "request."]
-    #[doc = ""]
-    #[doc = "A newly created xdg_popup will be stacked on top of all previously created"]
-    #[doc = "xdg_popup surfaces associated with the same xdg_toplevel."]
-    #[doc = ""]
-    #[doc = "The parent of an xdg_popup must be mapped (see the xdg_surface"]
-    #[doc = "description) before the xdg_popup itself."]
-    #[doc = ""]
-    #[doc = "The client must call wl_surface.commit on the corresponding wl_surface"]
-    #[doc = "for the xdg_popup state to take effect."]
+    ///A popup surface is a short-lived, temporary surface. It can be used to
+    ///implement for example menus, popovers, tooltips and other similar user
+    ///interface concepts.
+    ///
+    ///A popup can be made to take an explicit grab. See xdg_popup.grab for
+    ///details.
+    ///
+    ///When the popup is dismissed, a popup_done event will be sent out, and at
+    ///the same time the surface will be unmapped. See the xdg_popup.popup_done
+    ///event for details.
+    ///
+    ///Explicitly destroying the xdg_popup object will also dismiss the popup and
+    ///unmap the surface. Clients that want to dismiss the popup when another
+    ///surface of their own is clicked should dismiss the popup using the destroy
+    ///request.
+    ///
+    ///A newly created xdg_popup will be stacked on top of all previously created
+    ///xdg_popup surfaces associated with the same xdg_toplevel.
+    ///
+    ///The parent of an xdg_popup must be mapped (see the xdg_surface
+    ///description) before the xdg_popup itself.
+    ///
+    ///The client must call wl_surface.commit on the corresponding wl_surface
+    ///for the xdg_popup state to take effect.
     #[allow(clippy::too_many_arguments)]
     pub mod xdg_popup {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "tried to grab after being mapped"]
+            ///tried to grab after being mapped
             InvalidGrab = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidGrab => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidGrab => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_grab", Self::InvalidGrab),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidGrab => "invalid_grab",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -6169,218 +11419,354 @@ pub mod xdg_shell {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "grab",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "reposition",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "3ou",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "positioner",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_positioner"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "token",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the xdg_popup interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the configure message was introduced in
+            pub const CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the configure message was deprecated in, if any
+            pub const CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event asks the popup surface to configure itself given the
+            ///configuration. The configured state should not be applied immediately.
+            ///See xdg_surface.configure for details.
+            ///
+            ///The x and y arguments represent the position the popup was placed at
+            ///given the xdg_positioner rule, relative to the upper left corner of the
+            ///window geometry of the parent surface.
+            ///
+            ///For version 2 or older, the configure event for an xdg_popup is only
+            ///ever sent once for the initial configuration. Starting with version 3,
+            ///it may be sent again if the popup is setup with an xdg_positioner with
+            ///set_reactive requested, or in response to xdg_popup.reposition requests.
+            pub fn configure<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                x: i32,
+                y: i32,
+                width: i32,
+                height: i32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "xdg_popup",
+                    object_id = % sender_id, opcode = 0u16, message = "configure",
+                    direction = "out", "args.x" = % x, "args.y" = % y, "args.width" = %
+                    width, "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_popup",
+                    object_id : % = sender_id, opcode = 0u16, message = "configure",
+                    direction = "out", "args.x" : % = x, "args.y" : % = y, "args.width" :
+                    % = width, "args.height" : % = height; "xdg_popup.configure"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(x)
+                    .put_int(y)
+                    .put_int(width)
+                    .put_int(height)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the popup_done message was introduced in
+            pub const POPUP_DONE_SINCE: u32 = 1u32;
+            ///The interface version the popup_done message was deprecated in, if any
+            pub const POPUP_DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The popup_done event is sent out when a popup is dismissed by the
+            ///compositor. The client should destroy the xdg_popup object at this
+            ///point.
+            pub fn popup_done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "xdg_popup",
+                    object_id = % sender_id, opcode = 1u16, message = "popup_done",
+                    direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_popup",
+                    object_id : % = sender_id, opcode = 1u16, message = "popup_done",
+                    direction = "out"; "xdg_popup.popup_done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the repositioned message was introduced in
+            pub const REPOSITIONED_SINCE: u32 = 3u32;
+            ///The interface version the repositioned message was deprecated in, if any
+            pub const REPOSITIONED_DEPRECATED_SINCE: Option<u32> = None;
+            ///The repositioned event is sent as part of a popup configuration
+            ///sequence, together with xdg_popup.configure and lastly
+            ///xdg_surface.configure to notify the completion of a reposition request.
+            ///
+            ///The repositioned event is to notify about the completion of a
+            ///xdg_popup.reposition request. The token argument is the token passed
+            ///in the xdg_popup.reposition request.
+            ///
+            ///Immediately after this event is emitted, xdg_popup.configure and
+            ///xdg_surface.configure will be sent with the updated size and position,
+            ///as well as a new configure serial.
+            ///
+            ///The client should optionally update the content of the popup, but must
+            ///acknowledge the new popup configuration for the new position to take
+            ///effect. See xdg_surface.ack_configure for details.
+            ///
+            ///Available since version 3
+            pub fn repositioned<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                token: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface = "xdg_popup",
+                    object_id = % sender_id, opcode = 2u16, message = "repositioned",
+                    direction = "out", "args.token" = % token,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface = "xdg_popup",
+                    object_id : % = sender_id, opcode = 2u16, message = "repositioned",
+                    direction = "out", "args.token" : % = token; "xdg_popup.repositioned"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(token).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the xdg_popup interface. See the module level documentation for more info"]
+        ///Trait to implement the xdg_popup interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `xdg_popup` interface",
+            label = "`XdgPopup` is not implemented for `{Self}`"
+        )]
         pub trait XdgPopup
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "xdg_popup";
             const VERSION: u32 = 7u32;
-            #[doc = "This destroys the popup. Explicitly destroying the xdg_popup"]
-            #[doc = "object will also dismiss the popup, and unmap the surface."]
-            #[doc = ""]
-            #[doc = "If this xdg_popup is not the \"topmost\" popup, the"]
-            #[doc = "xdg_wm_base.not_the_topmost_popup protocol error will be sent."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This destroys the popup. Explicitly destroying the xdg_popup
+            ///object will also dismiss the popup, and unmap the surface.
+            ///
+            ///If this xdg_popup is not the "topmost" popup, the
+            ///xdg_wm_base.not_the_topmost_popup protocol error will be sent.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request makes the created popup take an explicit grab. An explicit"]
-            #[doc = "grab will be dismissed when the user dismisses the popup, or when the"]
-            #[doc = "client destroys the xdg_popup. This can be done by the user clicking"]
-            #[doc = "outside the surface, using the keyboard, or even locking the screen"]
-            #[doc = "through closing the lid or a timeout."]
-            #[doc = ""]
-            #[doc = "If the compositor denies the grab, the popup will be immediately"]
-            #[doc = "dismissed."]
-            #[doc = ""]
-            #[doc = "This request must be used in response to some sort of user action like a"]
-            #[doc = "button press, key press, or touch down event. The serial number of the"]
-            #[doc = "event should be passed as 'serial'."]
-            #[doc = ""]
-            #[doc = "The parent of a grabbing popup must either be an xdg_toplevel surface or"]
-            #[doc = "another xdg_popup with an explicit grab. If the parent is another"]
-            #[doc = "xdg_popup it means that the popups are nested, with this popup now being"]
-            #[doc = "the topmost popup."]
-            #[doc = ""]
-            #[doc = "Nested popups must be destroyed in the reverse order they were created"]
-            #[doc = "in, e.g. the only popup you are allowed to destroy at all times is the"]
-            #[doc = "topmost one."]
-            #[doc = ""]
-            #[doc = "When compositors choose to dismiss a popup, they may dismiss every"]
-            #[doc = "nested grabbing popup as well. When a compositor dismisses popups, it"]
-            #[doc = "will follow the same dismissing order as required from the client."]
-            #[doc = ""]
-            #[doc = "If the topmost grabbing popup is destroyed, the grab will be returned to"]
-            #[doc = "the parent of the popup, if that parent previously had an explicit grab."]
-            #[doc = ""]
-            #[doc = "If the parent is a grabbing popup which has already been dismissed, this"]
-            #[doc = "popup will be immediately dismissed. If the parent is a popup that did"]
-            #[doc = "not take an explicit grab, an error will be raised."]
-            #[doc = ""]
-            #[doc = "During a popup grab, the client owning the grab will receive pointer"]
-            #[doc = "and touch events for all their surfaces as normal (similar to an"]
-            #[doc = "\"owner-events\" grab in X11 parlance), while the top most grabbing popup"]
-            #[doc = "will always have keyboard focus."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the grab message was introduced in
+            const GRAB_SINCE: u32 = 1u32;
+            ///The interface version the grab message was deprecated in, if any
+            const GRAB_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request makes the created popup take an explicit grab. An explicit
+            ///grab will be dismissed when the user dismisses the popup, or when the
+            ///client destroys the xdg_popup. This can be done by the user clicking
+            ///outside the surface, using the keyboard, or even locking the screen
+            ///through closing the lid or a timeout.
+            ///
+            ///If the compositor denies the grab, the popup will be immediately
+            ///dismissed.
+            ///
+            ///This request must be used in response to some sort of user action like a
+            ///button press, key press, or touch down event. The serial number of the
+            ///event should be passed as 'serial'.
+            ///
+            ///The parent of a grabbing popup must either be an xdg_toplevel surface or
+            ///another xdg_popup with an explicit grab. If the parent is another
+            ///xdg_popup it means that the popups are nested, with this popup now being
+            ///the topmost popup.
+            ///
+            ///Nested popups must be destroyed in the reverse order they were created
+            ///in, e.g. the only popup you are allowed to destroy at all times is the
+            ///topmost one.
+            ///
+            ///When compositors choose to dismiss a popup, they may dismiss every
+            ///nested grabbing popup as well. When a compositor dismisses popups, it
+            ///will follow the same dismissing order as required from the client.
+            ///
+            ///If the topmost grabbing popup is destroyed, the grab will be returned to
+            ///the parent of the popup, if that parent previously had an explicit grab.
+            ///
+            ///If the parent is a grabbing popup which has already been dismissed, this
+            ///popup will be immediately dismissed. If the parent is a popup that did
+            ///not take an explicit grab, an error will be raised.
+            ///
+            ///During a popup grab, the client owning the grab will receive pointer
+            ///and touch events for all their surfaces as normal (similar to an
+            ///"owner-events" grab in X11 parlance), while the top most grabbing popup
+            ///will always have keyboard focus.
             fn grab(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Reposition an already-mapped popup. The popup will be placed given the"]
-            #[doc = "details in the passed xdg_positioner object, and a"]
-            #[doc = "xdg_popup.repositioned followed by xdg_popup.configure and"]
-            #[doc = "xdg_surface.configure will be emitted in response. Any parameters set"]
-            #[doc = "by the previous positioner will be discarded."]
-            #[doc = ""]
-            #[doc = "The passed token will be sent in the corresponding"]
-            #[doc = "xdg_popup.repositioned event. The new popup position will not take"]
-            #[doc = "effect until the corresponding configure event is acknowledged by the"]
-            #[doc = "client. See xdg_popup.repositioned for details. The token itself is"]
-            #[doc = "opaque, and has no other special meaning."]
-            #[doc = ""]
-            #[doc = "If multiple reposition requests are sent, the compositor may skip all"]
-            #[doc = "but the last one."]
-            #[doc = ""]
-            #[doc = "If the popup is repositioned in response to a configure event for its"]
-            #[doc = "parent, the client should send an xdg_positioner.set_parent_configure"]
-            #[doc = "and possibly an xdg_positioner.set_parent_size request to allow the"]
-            #[doc = "compositor to properly constrain the popup."]
-            #[doc = ""]
-            #[doc = "If the popup is repositioned together with a parent that is being"]
-            #[doc = "resized, but not in response to a configure event, the client should"]
-            #[doc = "send an xdg_positioner.set_parent_size request."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the reposition message was introduced in
+            const REPOSITION_SINCE: u32 = 3u32;
+            ///The interface version the reposition message was deprecated in, if any
+            const REPOSITION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Reposition an already-mapped popup. The popup will be placed given the
+            ///details in the passed xdg_positioner object, and a
+            ///xdg_popup.repositioned followed by xdg_popup.configure and
+            ///xdg_surface.configure will be emitted in response. Any parameters set
+            ///by the previous positioner will be discarded.
+            ///
+            ///The passed token will be sent in the corresponding
+            ///xdg_popup.repositioned event. The new popup position will not take
+            ///effect until the corresponding configure event is acknowledged by the
+            ///client. See xdg_popup.repositioned for details. The token itself is
+            ///opaque, and has no other special meaning.
+            ///
+            ///If multiple reposition requests are sent, the compositor may skip all
+            ///but the last one.
+            ///
+            ///If the popup is repositioned in response to a configure event for its
+            ///parent, the client should send an xdg_positioner.set_parent_configure
+            ///and possibly an xdg_positioner.set_parent_size request to allow the
+            ///compositor to properly constrain the popup.
+            ///
+            ///If the popup is repositioned together with a parent that is being
+            ///resized, but not in response to a configure event, the client should
+            ///send an xdg_positioner.set_parent_size request.
+            ///
+            ///Available since version 3
             fn reposition(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 positioner: waynest::ObjectId,
                 token: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event asks the popup surface to configure itself given the"]
-            #[doc = "configuration. The configured state should not be applied immediately."]
-            #[doc = "See xdg_surface.configure for details."]
-            #[doc = ""]
-            #[doc = "The x and y arguments represent the position the popup was placed at"]
-            #[doc = "given the xdg_positioner rule, relative to the upper left corner of the"]
-            #[doc = "window geometry of the parent surface."]
-            #[doc = ""]
-            #[doc = "For version 2 or older, the configure event for an xdg_popup is only"]
-            #[doc = "ever sent once for the initial configuration. Starting with version 3,"]
-            #[doc = "it may be sent again if the popup is setup with an xdg_positioner with"]
-            #[doc = "set_reactive requested, or in response to xdg_popup.reposition requests."]
-            fn configure(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                x: i32,
-                y: i32,
-                width: i32,
-                height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> xdg_popup#{}.configure({}, {}, {}, {})",
-                        sender_id,
-                        x,
-                        y,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(x)
-                        .put_int(y)
-                        .put_int(width)
-                        .put_int(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The popup_done event is sent out when a popup is dismissed by the"]
-            #[doc = "compositor. The client should destroy the xdg_popup object at this"]
-            #[doc = "point."]
-            fn popup_done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> xdg_popup#{}.popup_done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The repositioned event is sent as part of a popup configuration"]
-            #[doc = "sequence, together with xdg_popup.configure and lastly"]
-            #[doc = "xdg_surface.configure to notify the completion of a reposition request."]
-            #[doc = ""]
-            #[doc = "The repositioned event is to notify about the completion of a"]
-            #[doc = "xdg_popup.reposition request. The token argument is the token passed"]
-            #[doc = "in the xdg_popup.reposition request."]
-            #[doc = ""]
-            #[doc = "Immediately after this event is emitted, xdg_popup.configure and"]
-            #[doc = "xdg_surface.configure will be sent with the updated size and position,"]
-            #[doc = "as well as a new configure serial."]
-            #[doc = ""]
-            #[doc = "The client should optionally update the content of the popup, but must"]
-            #[doc = "acknowledge the new popup configuration for the new position to take"]
-            #[doc = "effect. See xdg_surface.ack_configure for details."]
-            fn repositioned(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                token: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> xdg_popup#{}.repositioned({})", sender_id, token);
-                    let payload = waynest::PayloadBuilder::new().put_uint(token).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_popup#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_popup", object_id = % sender_id, opcode = 0u16, message
+                                = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_popup", object_id : % = sender_id, opcode = 0u16,
+                                message = "destroy", direction = "in"; "xdg_popup.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         1u16 => {
                             let seat = message
@@ -6388,8 +11774,20 @@ pub mod xdg_shell {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("xdg_popup#{}.grab({}, {})", sender_id, seat, serial);
-                            self.grab(connection, sender_id, seat, serial).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_popup", object_id = % sender_id, opcode = 1u16, message
+                                = "grab", direction = "in", "args.seat" = % seat,
+                                "args.serial" = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_popup", object_id : % = sender_id, opcode = 1u16,
+                                message = "grab", direction = "in", "args.seat" : % = seat,
+                                "args.serial" : % = serial; "xdg_popup.grab"
+                            );
+                            self.grab(connection, state, sender_id, seat, serial).await
                         }
                         2u16 => {
                             let positioner = message
@@ -6397,16 +11795,32 @@ pub mod xdg_shell {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let token = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "xdg_popup#{}.reposition({}, {})",
-                                sender_id,
-                                positioner,
-                                token
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "xdg_popup", object_id = % sender_id, opcode = 2u16, message
+                                = "reposition", direction = "in", "args.positioner" = %
+                                positioner, "args.token" = % token,
                             );
-                            self.reposition(connection, sender_id, positioner, token)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "xdg_popup", object_id : % = sender_id, opcode = 2u16,
+                                message = "reposition", direction = "in", "args.positioner"
+                                : % = positioner, "args.token" : % = token;
+                                "xdg_popup.reposition"
+                            );
+                            self.reposition(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    positioner,
+                                    token,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
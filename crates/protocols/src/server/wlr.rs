@@ -1,61 +1,396 @@
-#[doc = "This protocol allows a privileged client to control data devices. In"]
-#[doc = "particular, the client will be able to manage the current selection and take"]
-#[doc = "the role of a clipboard manager."]
-#[doc = ""]
-#[doc = "Warning! The protocol described in this file is experimental and"]
-#[doc = "backward incompatible changes may be made. Backward compatible changes"]
-#[doc = "may be added together with the corresponding interface version bump."]
-#[doc = "Backward incompatible changes are done by bumping the version number in"]
-#[doc = "the protocol and interface names and resetting the interface version."]
-#[doc = "Once the protocol is to be declared stable, the 'z' prefix and the"]
-#[doc = "version number in the protocol and interface names are removed and the"]
-#[doc = "interface version number is reset."]
-#[doc = ""]
-#[doc = "Note! This protocol is deprecated and not intended for production use."]
-#[doc = "For clipboard management, use the ext-data-control-v1 protocol."]
-#[allow(clippy::module_inception)]
+// This file was generated by waynest-gen, do not edit it manually.
+//
+// Generated from the wlr_data_control_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-data-control-unstable-v1.xml)
+//
+//   Copyright © 2018 Simon Ser
+//   Copyright © 2019 Ivan Molodetskikh
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_export_dmabuf_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-export-dmabuf-unstable-v1.xml)
+//
+//   Copyright © 2018 Rostislav Pehlivanov
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the wlr_foreign_toplevel_management_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-foreign-toplevel-management-unstable-v1.xml)
+//
+//   Copyright © 2018 Ilia Bozhinov
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_gamma_control_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-gamma-control-unstable-v1.xml)
+//
+//   Copyright © 2015 Giulio camuffo
+//   Copyright © 2018 Simon Ser
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_input_inhibit_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-input-inhibitor-unstable-v1.xml)
+//
+//   Copyright © 2018 Drew DeVault
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_layer_shell_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-layer-shell-unstable-v1.xml)
+//
+//   Copyright © 2017 Drew DeVault
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_output_management_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-output-management-unstable-v1.xml)
+//
+//   Copyright © 2019 Purism SPC
+//
+//   Permission to use, copy, modify, distribute, and sell this
+//   software and its documentation for any purpose is hereby granted
+//   without fee, provided that the above copyright notice appear in
+//   all copies and that both that copyright notice and this permission
+//   notice appear in supporting documentation, and that the name of
+//   the copyright holders not be used in advertising or publicity
+//   pertaining to distribution of the software without specific,
+//   written prior permission.  The copyright holders make no
+//   representations about the suitability of this software for any
+//   purpose.  It is provided "as is" without express or implied
+//   warranty.
+//
+//   THE COPYRIGHT HOLDERS DISCLAIM ALL WARRANTIES WITH REGARD TO THIS
+//   SOFTWARE, INCLUDING ALL IMPLIED WARRANTIES OF MERCHANTABILITY AND
+//   FITNESS, IN NO EVENT SHALL THE COPYRIGHT HOLDERS BE LIABLE FOR ANY
+//   SPECIAL, INDIRECT OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+//   WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN
+//   AN ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION,
+//   ARISING OUT OF OR IN CONNECTION WITH THE USE OR PERFORMANCE OF
+//   THIS SOFTWARE.
+//
+// Generated from the wlr_output_power_management_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-output-power-management-unstable-v1.xml)
+//
+//   Copyright © 2019 Purism SPC
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the wlr_screencopy_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-screencopy-unstable-v1.xml)
+//
+//   Copyright © 2018 Simon Ser
+//   Copyright © 2019 Andri Yngvason
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+//
+// Generated from the wlr_virtual_pointer_unstable_v1 protocol (external/protocols/wlr-protocols/unstable/wlr-virtual-pointer-unstable-v1.xml)
+//
+//   Copyright © 2019 Josef Gajdusek
+//
+//   Permission is hereby granted, free of charge, to any person obtaining a
+//   copy of this software and associated documentation files (the "Software"),
+//   to deal in the Software without restriction, including without limitation
+//   the rights to use, copy, modify, merge, publish, distribute, sublicense,
+//   and/or sell copies of the Software, and to permit persons to whom the
+//   Software is furnished to do so, subject to the following conditions:
+//
+//   The above copyright notice and this permission notice (including the next
+//   paragraph) shall be included in all copies or substantial portions of the
+//   Software.
+//
+//   THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+//   IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+//   FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+//   THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//   LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+//   FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+//   DEALINGS IN THE SOFTWARE.
+
+///This protocol allows a privileged client to control data devices. In
+///particular, the client will be able to manage the current selection and take
+///the role of a clipboard manager.
+///
+///Warning! The protocol described in this file is experimental and
+///backward incompatible changes may be made. Backward compatible changes
+///may be added together with the corresponding interface version bump.
+///Backward incompatible changes are done by bumping the version number in
+///the protocol and interface names and resetting the interface version.
+///Once the protocol is to be declared stable, the 'z' prefix and the
+///version number in the protocol and interface names are removed and the
+///interface version number is reset.
+///
+///Note! This protocol is deprecated and not intended for production use.
+///For clipboard management, use the ext-data-control-v1 protocol.
+#[cfg(feature = "wlr-data-control-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-data-control-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_data_control_unstable_v1 {
-    #[doc = "This interface is a manager that allows creating per-seat data device"]
-    #[doc = "controls."]
+    ///This interface is a manager that allows creating per-seat data device
+    ///controls.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_data_control_manager_v1 {
-        #[doc = "Trait to implement the zwlr_data_control_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_data_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_data_device",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_data_control_device_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_data_control_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_data_control_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_data_control_manager_v1` interface",
+            label = "`ZwlrDataControlManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrDataControlManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_data_control_manager_v1";
             const VERSION: u32 = 2u32;
-            #[doc = "Create a new data source."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_data_source message was introduced in
+            const CREATE_DATA_SOURCE_SINCE: u32 = 1u32;
+            ///The interface version the create_data_source message was deprecated in, if any
+            const CREATE_DATA_SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a new data source.
             fn create_data_source(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Create a data device that can be used to manage a seat's selection."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_data_device message was introduced in
+            const GET_DATA_DEVICE_SINCE: u32 = 1u32;
+            ///The interface version the get_data_device message was deprecated in, if any
+            const GET_DATA_DEVICE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a data device that can be used to manage a seat's selection.
             fn get_data_device(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 seat: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "All objects created by the manager will still remain valid, until their"]
-            #[doc = "appropriate destroy request has been called."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///All objects created by the manager will still remain valid, until their
+            ///appropriate destroy request has been called.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -64,12 +399,22 @@ pub mod wlr_data_control_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_data_control_manager_v1#{}.create_data_source({})",
-                                sender_id,
-                                id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "create_data_source", direction =
+                                "in", "args.id" = % id,
                             );
-                            self.create_data_source(connection, sender_id, id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_manager_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "create_data_source", direction =
+                                "in", "args.id" : % = id;
+                                "zwlr_data_control_manager_v1.create_data_source"
+                            );
+                            self.create_data_source(connection, state, sender_id, id)
+                                .await
                         }
                         1u16 => {
                             let id = message
@@ -79,42 +424,108 @@ pub mod wlr_data_control_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_data_control_manager_v1#{}.get_data_device({}, {})",
-                                sender_id,
-                                id,
-                                seat
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_manager_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "get_data_device", direction =
+                                "in", "args.id" = % id, "args.seat" = % seat,
                             );
-                            self.get_data_device(connection, sender_id, id, seat).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_manager_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "get_data_device", direction =
+                                "in", "args.id" : % = id, "args.seat" : % = seat;
+                                "zwlr_data_control_manager_v1.get_data_device"
+                            );
+                            self.get_data_device(connection, state, sender_id, id, seat)
+                                .await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_data_control_manager_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_manager_v1", object_id = % sender_id,
+                                opcode = 2u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_manager_v1", object_id : % = sender_id,
+                                opcode = 2u16, message = "destroy", direction = "in";
+                                "zwlr_data_control_manager_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This interface allows a client to manage a seat's selection."]
-    #[doc = ""]
-    #[doc = "When the seat is destroyed, this object becomes inert."]
+    ///This interface allows a client to manage a seat's selection.
+    ///
+    ///When the seat is destroyed, this object becomes inert.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_data_control_device_v1 {
+        ///
+        ///Available since version 2
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "source given to set_selection or set_primary_selection was already used before"]
+            ///source given to set_selection or set_primary_selection was already used before
             UsedSource = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 2u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::UsedSource => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::UsedSource => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("used_source", Self::UsedSource),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::UsedSource => "used_source",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -124,242 +535,445 @@ pub mod wlr_data_control_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_selection",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_primary_selection",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "source",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_data_control_source_v1"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwlr_data_control_device_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the data_offer message was introduced in
+            pub const DATA_OFFER_SINCE: u32 = 1u32;
+            ///The interface version the data_offer message was deprecated in, if any
+            pub const DATA_OFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///The data_offer event introduces a new wlr_data_control_offer object,
+            ///which will subsequently be used in either the
+            ///wlr_data_control_device.selection event (for the regular clipboard
+            ///selections) or the wlr_data_control_device.primary_selection event (for
+            ///the primary clipboard selections). Immediately following the
+            ///wlr_data_control_device.data_offer event, the new data_offer object
+            ///will send out wlr_data_control_offer.offer events to describe the MIME
+            ///types it offers.
+            pub fn data_offer<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_device_v1", object_id = % sender_id, opcode =
+                    0u16, message = "data_offer", direction = "out", "args.id" = % id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_device_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "data_offer", direction = "out", "args.id" : % = id;
+                    "zwlr_data_control_device_v1.data_offer"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(id))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the selection message was introduced in
+            pub const SELECTION_SINCE: u32 = 1u32;
+            ///The interface version the selection message was deprecated in, if any
+            pub const SELECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///The selection event is sent out to notify the client of a new
+            ///wlr_data_control_offer for the selection for this device. The
+            ///wlr_data_control_device.data_offer and the wlr_data_control_offer.offer
+            ///events are sent out immediately before this event to introduce the data
+            ///offer object. The selection event is sent to a client when a new
+            ///selection is set. The wlr_data_control_offer is valid until a new
+            ///wlr_data_control_offer or NULL is received. The client must destroy the
+            ///previous selection wlr_data_control_offer, if any, upon receiving this
+            ///event.
+            ///
+            ///The first selection event is sent upon binding the
+            ///wlr_data_control_device object.
+            pub fn selection<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                id: Option<waynest::ObjectId>,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_device_v1", object_id = % sender_id, opcode =
+                    1u16, message = "selection", direction = "out", "args.id" = % id
+                    .as_ref().map_or("null".to_string(), | v | v.to_string()),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_device_v1", object_id : % = sender_id, opcode =
+                    1u16, message = "selection", direction = "out", "args.id" : % = id
+                    .as_ref().map_or("null".to_string(), | v | v.to_string());
+                    "zwlr_data_control_device_v1.selection"
+                );
+                let payload = waynest::PayloadBuilder::new().put_object(id).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the finished message was introduced in
+            pub const FINISHED_SINCE: u32 = 1u32;
+            ///The interface version the finished message was deprecated in, if any
+            pub const FINISHED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This data control object is no longer valid and should be destroyed by
+            ///the client.
+            pub fn finished<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_device_v1", object_id = % sender_id, opcode =
+                    2u16, message = "finished", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_device_v1", object_id : % = sender_id, opcode =
+                    2u16, message = "finished", direction = "out";
+                    "zwlr_data_control_device_v1.finished"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the primary_selection message was introduced in
+            pub const PRIMARY_SELECTION_SINCE: u32 = 2u32;
+            ///The interface version the primary_selection message was deprecated in, if any
+            pub const PRIMARY_SELECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///The primary_selection event is sent out to notify the client of a new
+            ///wlr_data_control_offer for the primary selection for this device. The
+            ///wlr_data_control_device.data_offer and the wlr_data_control_offer.offer
+            ///events are sent out immediately before this event to introduce the data
+            ///offer object. The primary_selection event is sent to a client when a
+            ///new primary selection is set. The wlr_data_control_offer is valid until
+            ///a new wlr_data_control_offer or NULL is received. The client must
+            ///destroy the previous primary selection wlr_data_control_offer, if any,
+            ///upon receiving this event.
+            ///
+            ///If the compositor supports primary selection, the first
+            ///primary_selection event is sent upon binding the
+            ///wlr_data_control_device object.
+            ///
+            ///Available since version 2
+            pub fn primary_selection<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                id: Option<waynest::ObjectId>,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_device_v1", object_id = % sender_id, opcode =
+                    3u16, message = "primary_selection", direction = "out", "args.id" = %
+                    id.as_ref().map_or("null".to_string(), | v | v.to_string()),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_device_v1", object_id : % = sender_id, opcode =
+                    3u16, message = "primary_selection", direction = "out", "args.id" : %
+                    = id.as_ref().map_or("null".to_string(), | v | v.to_string());
+                    "zwlr_data_control_device_v1.primary_selection"
+                );
+                let payload = waynest::PayloadBuilder::new().put_object(id).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwlr_data_control_device_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwlr_data_control_device_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_data_control_device_v1` interface",
+            label = "`ZwlrDataControlDeviceV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrDataControlDeviceV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_data_control_device_v1";
             const VERSION: u32 = 2u32;
-            #[doc = "This request asks the compositor to set the selection to the data from"]
-            #[doc = "the source on behalf of the client."]
-            #[doc = ""]
-            #[doc = "The given source may not be used in any further set_selection or"]
-            #[doc = "set_primary_selection requests. Attempting to use a previously used"]
-            #[doc = "source is a protocol error."]
-            #[doc = ""]
-            #[doc = "To unset the selection, set the source to NULL."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_selection message was introduced in
+            const SET_SELECTION_SINCE: u32 = 1u32;
+            ///The interface version the set_selection message was deprecated in, if any
+            const SET_SELECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request asks the compositor to set the selection to the data from
+            ///the source on behalf of the client.
+            ///
+            ///The given source may not be used in any further set_selection or
+            ///set_primary_selection requests. Attempting to use a previously used
+            ///source is a protocol error.
+            ///
+            ///To unset the selection, set the source to NULL.
             fn set_selection(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 source: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the data device object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the data device object.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request asks the compositor to set the primary selection to the"]
-            #[doc = "data from the source on behalf of the client."]
-            #[doc = ""]
-            #[doc = "The given source may not be used in any further set_selection or"]
-            #[doc = "set_primary_selection requests. Attempting to use a previously used"]
-            #[doc = "source is a protocol error."]
-            #[doc = ""]
-            #[doc = "To unset the primary selection, set the source to NULL."]
-            #[doc = ""]
-            #[doc = "The compositor will ignore this request if it does not support primary"]
-            #[doc = "selection."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_primary_selection message was introduced in
+            const SET_PRIMARY_SELECTION_SINCE: u32 = 2u32;
+            ///The interface version the set_primary_selection message was deprecated in, if any
+            const SET_PRIMARY_SELECTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request asks the compositor to set the primary selection to the
+            ///data from the source on behalf of the client.
+            ///
+            ///The given source may not be used in any further set_selection or
+            ///set_primary_selection requests. Attempting to use a previously used
+            ///source is a protocol error.
+            ///
+            ///To unset the primary selection, set the source to NULL.
+            ///
+            ///The compositor will ignore this request if it does not support primary
+            ///selection.
+            ///
+            ///Available since version 2
             fn set_primary_selection(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 source: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The data_offer event introduces a new wlr_data_control_offer object,"]
-            #[doc = "which will subsequently be used in either the"]
-            #[doc = "wlr_data_control_device.selection event (for the regular clipboard"]
-            #[doc = "selections) or the wlr_data_control_device.primary_selection event (for"]
-            #[doc = "the primary clipboard selections). Immediately following the"]
-            #[doc = "wlr_data_control_device.data_offer event, the new data_offer object"]
-            #[doc = "will send out wlr_data_control_offer.offer events to describe the MIME"]
-            #[doc = "types it offers."]
-            fn data_offer(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_data_control_device_v1#{}.data_offer({})",
-                        sender_id,
-                        id
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_object(Some(id)).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The selection event is sent out to notify the client of a new"]
-            #[doc = "wlr_data_control_offer for the selection for this device. The"]
-            #[doc = "wlr_data_control_device.data_offer and the wlr_data_control_offer.offer"]
-            #[doc = "events are sent out immediately before this event to introduce the data"]
-            #[doc = "offer object. The selection event is sent to a client when a new"]
-            #[doc = "selection is set. The wlr_data_control_offer is valid until a new"]
-            #[doc = "wlr_data_control_offer or NULL is received. The client must destroy the"]
-            #[doc = "previous selection wlr_data_control_offer, if any, upon receiving this"]
-            #[doc = "event."]
-            #[doc = ""]
-            #[doc = "The first selection event is sent upon binding the"]
-            #[doc = "wlr_data_control_device object."]
-            fn selection(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                id: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_data_control_device_v1#{}.selection({})",
-                        sender_id,
-                        id.as_ref().map_or("null".to_string(), |v| v.to_string())
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_object(id).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This data control object is no longer valid and should be destroyed by"]
-            #[doc = "the client."]
-            fn finished(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_data_control_device_v1#{}.finished()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The primary_selection event is sent out to notify the client of a new"]
-            #[doc = "wlr_data_control_offer for the primary selection for this device. The"]
-            #[doc = "wlr_data_control_device.data_offer and the wlr_data_control_offer.offer"]
-            #[doc = "events are sent out immediately before this event to introduce the data"]
-            #[doc = "offer object. The primary_selection event is sent to a client when a"]
-            #[doc = "new primary selection is set. The wlr_data_control_offer is valid until"]
-            #[doc = "a new wlr_data_control_offer or NULL is received. The client must"]
-            #[doc = "destroy the previous primary selection wlr_data_control_offer, if any,"]
-            #[doc = "upon receiving this event."]
-            #[doc = ""]
-            #[doc = "If the compositor supports primary selection, the first"]
-            #[doc = "primary_selection event is sent upon binding the"]
-            #[doc = "wlr_data_control_device object."]
-            fn primary_selection(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                id: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_data_control_device_v1#{}.primary_selection({})",
-                        sender_id,
-                        id.as_ref().map_or("null".to_string(), |v| v.to_string())
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_object(id).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 3u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             let source = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_data_control_device_v1#{}.set_selection({})",
-                                sender_id,
-                                source
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_selection(connection, sender_id, source).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_device_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "set_selection", direction = "in",
+                                "args.source" = % source.as_ref().map_or("null".to_string(),
+                                | v | v.to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_device_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "set_selection", direction = "in",
+                                "args.source" : % = source.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string());
+                                "zwlr_data_control_device_v1.set_selection"
+                            );
+                            self.set_selection(connection, state, sender_id, source)
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_data_control_device_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_device_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_device_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_data_control_device_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         2u16 => {
                             let source = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_data_control_device_v1#{}.set_primary_selection({})",
-                                sender_id,
-                                source
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_primary_selection(connection, sender_id, source)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_device_v1", object_id = % sender_id,
+                                opcode = 2u16, message = "set_primary_selection", direction
+                                = "in", "args.source" = % source.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_device_v1", object_id : % = sender_id,
+                                opcode = 2u16, message = "set_primary_selection", direction
+                                = "in", "args.source" : % = source.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string());
+                                "zwlr_data_control_device_v1.set_primary_selection"
+                            );
+                            self.set_primary_selection(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    source,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
-    #[doc = "The wlr_data_control_source object is the source side of a"]
-    #[doc = "wlr_data_control_offer. It is created by the source client in a data"]
-    #[doc = "transfer and provides a way to describe the offered data and a way to"]
-    #[doc = "respond to requests to transfer the data."]
+    ///The wlr_data_control_source object is the source side of a
+    ///wlr_data_control_offer. It is created by the source client in a data
+    ///transfer and provides a way to describe the offered data and a way to
+    ///respond to requests to transfer the data.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_data_control_source_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "offer sent after wlr_data_control_device.set_selection"]
+            ///offer sent after wlr_data_control_device.set_selection
             InvalidOffer = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidOffer => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidOffer => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_offer", Self::InvalidOffer),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidOffer => "invalid_offer",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -369,95 +983,174 @@ pub mod wlr_data_control_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "offer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "s",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_data_control_source_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the send message was introduced in
+            pub const SEND_SINCE: u32 = 1u32;
+            ///The interface version the send message was deprecated in, if any
+            pub const SEND_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request for data from the client. Send the data as the specified MIME
+            ///type over the passed file descriptor, then close it.
+            pub fn send<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                mime_type: String,
+                fd: std::os::fd::OwnedFd,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_source_v1", object_id = % sender_id, opcode =
+                    0u16, message = "send", direction = "out", "args.mime_type" = %
+                    mime_type, "args.fd" = % std::os::fd::AsRawFd::as_raw_fd(& fd),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_source_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "send", direction = "out", "args.mime_type" : % =
+                    mime_type, "args.fd" : % = std::os::fd::AsRawFd::as_raw_fd(& fd);
+                    "zwlr_data_control_source_v1.send"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(mime_type))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![fd],
+                    )
+            }
+            ///The interface version the cancelled message was introduced in
+            pub const CANCELLED_SINCE: u32 = 1u32;
+            ///The interface version the cancelled message was deprecated in, if any
+            pub const CANCELLED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This data source is no longer valid. The data source has been replaced
+            ///by another data source.
+            ///
+            ///The client should clean up and destroy this data source.
+            pub fn cancelled<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_source_v1", object_id = % sender_id, opcode =
+                    1u16, message = "cancelled", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_source_v1", object_id : % = sender_id, opcode =
+                    1u16, message = "cancelled", direction = "out";
+                    "zwlr_data_control_source_v1.cancelled"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwlr_data_control_source_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwlr_data_control_source_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_data_control_source_v1` interface",
+            label = "`ZwlrDataControlSourceV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrDataControlSourceV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_data_control_source_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "This request adds a MIME type to the set of MIME types advertised to"]
-            #[doc = "targets. Can be called several times to offer multiple types."]
-            #[doc = ""]
-            #[doc = "Calling this after wlr_data_control_device.set_selection is a protocol"]
-            #[doc = "error."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the offer message was introduced in
+            const OFFER_SINCE: u32 = 1u32;
+            ///The interface version the offer message was deprecated in, if any
+            const OFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request adds a MIME type to the set of MIME types advertised to
+            ///targets. Can be called several times to offer multiple types.
+            ///
+            ///Calling this after wlr_data_control_device.set_selection is a protocol
+            ///error.
             fn offer(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 mime_type: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the data source object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the data source object.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Request for data from the client. Send the data as the specified MIME"]
-            #[doc = "type over the passed file descriptor, then close it."]
-            fn send(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                mime_type: String,
-                fd: std::os::fd::OwnedFd,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_data_control_source_v1#{}.send(\"{}\", {})",
-                        sender_id,
-                        mime_type,
-                        std::os::fd::AsRawFd::as_raw_fd(&fd)
-                    );
-                    waynest::Connection::push_fd(connection, fd);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(mime_type))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This data source is no longer valid. The data source has been replaced"]
-            #[doc = "by another data source."]
-            #[doc = ""]
-            #[doc = "The client should clean up and destroy this data source."]
-            fn cancelled(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_data_control_source_v1#{}.cancelled()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -466,95 +1159,187 @@ pub mod wlr_data_control_unstable_v1 {
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_data_control_source_v1#{}.offer(\"{}\")",
-                                sender_id,
-                                mime_type
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_source_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "offer", direction = "in",
+                                "args.mime_type" = % mime_type,
                             );
-                            self.offer(connection, sender_id, mime_type).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_source_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "offer", direction = "in",
+                                "args.mime_type" : % = mime_type;
+                                "zwlr_data_control_source_v1.offer"
+                            );
+                            self.offer(connection, state, sender_id, mime_type).await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_data_control_source_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_source_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_source_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_data_control_source_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A wlr_data_control_offer represents a piece of data offered for transfer"]
-    #[doc = "by another client (the source client). The offer describes the different"]
-    #[doc = "MIME types that the data can be converted to and provides the mechanism"]
-    #[doc = "for transferring the data directly from the source client."]
+    ///A wlr_data_control_offer represents a piece of data offered for transfer
+    ///by another client (the source client). The offer describes the different
+    ///MIME types that the data can be converted to and provides the mechanism
+    ///for transferring the data directly from the source client.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_data_control_offer_v1 {
-        #[doc = "Trait to implement the zwlr_data_control_offer_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "receive",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "sh",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mime_type",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_data_control_offer_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the offer message was introduced in
+            pub const OFFER_SINCE: u32 = 1u32;
+            ///The interface version the offer message was deprecated in, if any
+            pub const OFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent immediately after creating the wlr_data_control_offer object.
+            ///One event per offered MIME type.
+            pub fn offer<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                mime_type: String,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_data_control_offer_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "offer", direction = "out", "args.mime_type" = % mime_type,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_data_control_offer_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "offer", direction = "out", "args.mime_type" : % =
+                    mime_type; "zwlr_data_control_offer_v1.offer"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(mime_type))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the zwlr_data_control_offer_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_data_control_offer_v1` interface",
+            label = "`ZwlrDataControlOfferV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrDataControlOfferV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_data_control_offer_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "To transfer the offered data, the client issues this request and"]
-            #[doc = "indicates the MIME type it wants to receive. The transfer happens"]
-            #[doc = "through the passed file descriptor (typically created with the pipe"]
-            #[doc = "system call). The source client writes the data in the MIME type"]
-            #[doc = "representation requested and then closes the file descriptor."]
-            #[doc = ""]
-            #[doc = "The receiving client reads from the read end of the pipe until EOF and"]
-            #[doc = "then closes its end, at which point the transfer is complete."]
-            #[doc = ""]
-            #[doc = "This request may happen multiple times for different MIME types."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the receive message was introduced in
+            const RECEIVE_SINCE: u32 = 1u32;
+            ///The interface version the receive message was deprecated in, if any
+            const RECEIVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///To transfer the offered data, the client issues this request and
+            ///indicates the MIME type it wants to receive. The transfer happens
+            ///through the passed file descriptor (typically created with the pipe
+            ///system call). The source client writes the data in the MIME type
+            ///representation requested and then closes the file descriptor.
+            ///
+            ///The receiving client reads from the read end of the pipe until EOF and
+            ///then closes its end, at which point the transfer is complete.
+            ///
+            ///This request may happen multiple times for different MIME types.
             fn receive(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 mime_type: String,
                 fd: std::os::fd::OwnedFd,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the data offer object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the data offer object.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent immediately after creating the wlr_data_control_offer object."]
-            #[doc = "One event per offered MIME type."]
-            fn offer(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                mime_type: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_data_control_offer_v1#{}.offer(\"{}\")",
-                        sender_id,
-                        mime_type
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(mime_type))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -564,72 +1349,168 @@ pub mod wlr_data_control_unstable_v1 {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let fd = waynest::Connection::fd(connection)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_data_control_offer_v1#{}.receive(\"{}\", {})",
-                                sender_id,
-                                mime_type,
-                                std::os::fd::AsRawFd::as_raw_fd(&fd)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_offer_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "receive", direction = "in",
+                                "args.mime_type" = % mime_type, "args.fd" = %
+                                std::os::fd::AsRawFd::as_raw_fd(& fd),
                             );
-                            self.receive(connection, sender_id, mime_type, fd).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_offer_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "receive", direction = "in",
+                                "args.mime_type" : % = mime_type, "args.fd" : % =
+                                std::os::fd::AsRawFd::as_raw_fd(& fd);
+                                "zwlr_data_control_offer_v1.receive"
+                            );
+                            self.receive(connection, state, sender_id, mime_type, fd)
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_data_control_offer_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_data_control_offer_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_data_control_offer_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_data_control_offer_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[doc = "An interface to capture surfaces in an efficient way by exporting DMA-BUFs."]
-#[doc = ""]
-#[doc = "Warning! The protocol described in this file is experimental and"]
-#[doc = "backward incompatible changes may be made. Backward compatible changes"]
-#[doc = "may be added together with the corresponding interface version bump."]
-#[doc = "Backward incompatible changes are done by bumping the version number in"]
-#[doc = "the protocol and interface names and resetting the interface version."]
-#[doc = "Once the protocol is to be declared stable, the 'z' prefix and the"]
-#[doc = "version number in the protocol and interface names are removed and the"]
-#[doc = "interface version number is reset."]
-#[allow(clippy::module_inception)]
+///An interface to capture surfaces in an efficient way by exporting DMA-BUFs.
+///
+///Warning! The protocol described in this file is experimental and
+///backward incompatible changes may be made. Backward compatible changes
+///may be added together with the corresponding interface version bump.
+///Backward incompatible changes are done by bumping the version number in
+///the protocol and interface names and resetting the interface version.
+///Once the protocol is to be declared stable, the 'z' prefix and the
+///version number in the protocol and interface names are removed and the
+///interface version number is reset.
+#[cfg(feature = "wlr-export-dmabuf-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-export-dmabuf-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_export_dmabuf_unstable_v1 {
-    #[doc = "This object is a manager with which to start capturing from sources."]
+    ///This object is a manager with which to start capturing from sources.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_export_dmabuf_manager_v1 {
-        #[doc = "Trait to implement the zwlr_export_dmabuf_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "capture_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nio",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_export_dmabuf_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "overlay_cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_export_dmabuf_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_export_dmabuf_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_export_dmabuf_manager_v1` interface",
+            label = "`ZwlrExportDmabufManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrExportDmabufManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_export_dmabuf_manager_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Capture the next frame of an entire output."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the capture_output message was introduced in
+            const CAPTURE_OUTPUT_SINCE: u32 = 1u32;
+            ///The interface version the capture_output message was deprecated in, if any
+            const CAPTURE_OUTPUT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Capture the next frame of an entire output.
             fn capture_output(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 frame: waynest::ObjectId,
                 overlay_cursor: i32,
                 output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "All objects created by the manager will still remain valid, until their"]
-            #[doc = "appropriate destroy request has been called."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///All objects created by the manager will still remain valid, until their
+            ///appropriate destroy request has been called.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -642,65 +1523,127 @@ pub mod wlr_export_dmabuf_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_export_dmabuf_manager_v1#{}.capture_output({}, {}, {})",
-                                sender_id,
-                                frame,
-                                overlay_cursor,
-                                output
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_export_dmabuf_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "capture_output", direction = "in",
+                                "args.frame" = % frame, "args.overlay_cursor" = %
+                                overlay_cursor, "args.output" = % output,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_export_dmabuf_manager_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "capture_output", direction = "in",
+                                "args.frame" : % = frame, "args.overlay_cursor" : % =
+                                overlay_cursor, "args.output" : % = output;
+                                "zwlr_export_dmabuf_manager_v1.capture_output"
                             );
                             self.capture_output(
-                                connection,
-                                sender_id,
-                                frame,
-                                overlay_cursor,
-                                output,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    frame,
+                                    overlay_cursor,
+                                    output,
+                                )
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_export_dmabuf_manager_v1#{}.destroy()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_export_dmabuf_manager_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_export_dmabuf_manager_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_export_dmabuf_manager_v1.destroy"
                             );
-                            self.destroy(connection, sender_id).await
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object represents a single DMA-BUF frame."]
-    #[doc = ""]
-    #[doc = "If the capture is successful, the compositor will first send a \"frame\""]
-    #[doc = "event, followed by one or several \"object\". When the frame is available"]
-    #[doc = "for readout, the \"ready\" event is sent."]
-    #[doc = ""]
-    #[doc = "If the capture failed, the \"cancel\" event is sent. This can happen anytime"]
-    #[doc = "before the \"ready\" event."]
-    #[doc = ""]
-    #[doc = "Once either a \"ready\" or a \"cancel\" event is received, the client should"]
-    #[doc = "destroy the frame. Once an \"object\" event is received, the client is"]
-    #[doc = "responsible for closing the associated file descriptor."]
-    #[doc = ""]
-    #[doc = "All frames are read-only and may not be written into or altered."]
+    ///This object represents a single DMA-BUF frame.
+    ///
+    ///If the capture is successful, the compositor will first send a "frame"
+    ///event, followed by one or several "object". When the frame is available
+    ///for readout, the "ready" event is sent.
+    ///
+    ///If the capture failed, the "cancel" event is sent. This can happen anytime
+    ///before the "ready" event.
+    ///
+    ///Once either a "ready" or a "cancel" event is received, the client should
+    ///destroy the frame. Once an "object" event is received, the client is
+    ///responsible for closing the associated file descriptor.
+    ///
+    ///All frames are read-only and may not be written into or altered.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_export_dmabuf_frame_v1 {
-        #[doc = "Special flags that should be respected by the client."]
+        ///Special flags that should be respected by the client.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Flags {
-            #[doc = "clients should copy frame before processing"]
+            ///clients should copy frame before processing
             Transient = 1u32,
         }
+        #[allow(deprecated)]
+        impl Flags {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Transient => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Transient => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("transient", Self::Transient),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Transient => "transient",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Flags> for u32 {
             fn from(value: Flags) -> Self {
                 value as u32
             }
         }
+        impl From<Flags> for i32 {
+            fn from(value: Flags) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Flags {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -710,28 +1653,84 @@ pub mod wlr_export_dmabuf_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Flags {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Flags {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Indicates reason for cancelling the frame."]
+        ///Indicates reason for cancelling the frame.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum CancelReason {
-            #[doc = "temporary error, source will produce more frames"]
+            ///temporary error, source will produce more frames
             Temporary = 0u32,
-            #[doc = "fatal error, source will not produce frames"]
+            ///fatal error, source will not produce frames
             Permanent = 1u32,
-            #[doc = "temporary error, source will produce more frames"]
+            ///temporary error, source will produce more frames
             Resizing = 2u32,
         }
+        #[allow(deprecated)]
+        impl CancelReason {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Temporary => 1u32,
+                    Self::Permanent => 1u32,
+                    Self::Resizing => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Temporary => None,
+                    Self::Permanent => None,
+                    Self::Resizing => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("temporary", Self::Temporary),
+                ("permanent", Self::Permanent),
+                ("resizing", Self::Resizing),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Temporary => "temporary",
+                    Self::Permanent => "permanent",
+                    Self::Resizing => "resizing",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<CancelReason> for u32 {
             fn from(value: CancelReason) -> Self {
                 value as u32
             }
         }
+        impl From<CancelReason> for i32 {
+            fn from(value: CancelReason) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for CancelReason {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -743,38 +1742,43 @@ pub mod wlr_export_dmabuf_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for CancelReason {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for CancelReason {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_export_dmabuf_frame_v1 interface. See the module level documentation for more info"]
-        pub trait ZwlrExportDmabufFrameV1
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwlr_export_dmabuf_frame_v1";
-            const VERSION: u32 = 1u32;
-            #[doc = "Unreferences the frame. This request must be called as soon as it's no"]
-            #[doc = "longer used."]
-            #[doc = ""]
-            #[doc = "It can be called at any time by the client. The client will still have"]
-            #[doc = "to close any FDs it has been given."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Main event supplying the client with information about the frame. If the"]
-            #[doc = "capture didn't fail, this event is always emitted first before any other"]
-            #[doc = "events."]
-            #[doc = ""]
-            #[doc = "This event is followed by a number of \"object\" as specified by the"]
-            #[doc = "\"num_objects\" argument."]
-            fn frame(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_export_dmabuf_frame_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the frame message was introduced in
+            pub const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            pub const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Main event supplying the client with information about the frame. If the
+            ///capture didn't fail, this event is always emitted first before any other
+            ///events.
+            ///
+            ///This event is followed by a number of "object" as specified by the
+            ///"num_objects" argument.
+            pub fn frame<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 width: u32,
                 height: u32,
@@ -786,52 +1790,59 @@ pub mod wlr_export_dmabuf_unstable_v1 {
                 mod_high: u32,
                 mod_low: u32,
                 num_objects: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_export_dmabuf_frame_v1#{}.frame({}, {}, {}, {}, {}, {}, {}, {}, {}, {})",
-                        sender_id,
-                        width,
-                        height,
-                        offset_x,
-                        offset_y,
-                        buffer_flags,
-                        flags,
-                        format,
-                        mod_high,
-                        mod_low,
-                        num_objects
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(width)
-                        .put_uint(height)
-                        .put_uint(offset_x)
-                        .put_uint(offset_y)
-                        .put_uint(buffer_flags)
-                        .put_uint(flags.into())
-                        .put_uint(format)
-                        .put_uint(mod_high)
-                        .put_uint(mod_low)
-                        .put_uint(num_objects)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id = % sender_id, opcode =
+                    0u16, message = "frame", direction = "out", "args.width" = % width,
+                    "args.height" = % height, "args.offset_x" = % offset_x,
+                    "args.offset_y" = % offset_y, "args.buffer_flags" = % buffer_flags,
+                    "args.flags" = % flags, "args.format" = % format, "args.mod_high" = %
+                    mod_high, "args.mod_low" = % mod_low, "args.num_objects" = %
+                    num_objects,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "frame", direction = "out", "args.width" : % = width,
+                    "args.height" : % = height, "args.offset_x" : % = offset_x,
+                    "args.offset_y" : % = offset_y, "args.buffer_flags" : % =
+                    buffer_flags, "args.flags" : % = flags, "args.format" : % = format,
+                    "args.mod_high" : % = mod_high, "args.mod_low" : % = mod_low,
+                    "args.num_objects" : % = num_objects;
+                    "zwlr_export_dmabuf_frame_v1.frame"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(width)
+                    .put_uint(height)
+                    .put_uint(offset_x)
+                    .put_uint(offset_y)
+                    .put_uint(buffer_flags)
+                    .put_uint(flags.into())
+                    .put_uint(format)
+                    .put_uint(mod_high)
+                    .put_uint(mod_low)
+                    .put_uint(num_objects)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Event which serves to supply the client with the file descriptors"]
-            #[doc = "containing the data for each object."]
-            #[doc = ""]
-            #[doc = "After receiving this event, the client must always close the file"]
-            #[doc = "descriptor as soon as they're done with it and even if the frame fails."]
-            fn object(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the object message was introduced in
+            pub const OBJECT_SINCE: u32 = 1u32;
+            ///The interface version the object message was deprecated in, if any
+            pub const OBJECT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Event which serves to supply the client with the file descriptors
+            ///containing the data for each object.
+            ///
+            ///After receiving this event, the client must always close the file
+            ///descriptor as soon as they're done with it and even if the frame fails.
+            pub fn object<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 index: u32,
                 fd: std::os::fd::OwnedFd,
@@ -839,271 +1850,455 @@ pub mod wlr_export_dmabuf_unstable_v1 {
                 offset: u32,
                 stride: u32,
                 plane_index: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_export_dmabuf_frame_v1#{}.object({}, {}, {}, {}, {}, {})",
-                        sender_id,
-                        index,
-                        std::os::fd::AsRawFd::as_raw_fd(&fd),
-                        size,
-                        offset,
-                        stride,
-                        plane_index
-                    );
-                    waynest::Connection::push_fd(connection, fd);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(index)
-                        .put_uint(size)
-                        .put_uint(offset)
-                        .put_uint(stride)
-                        .put_uint(plane_index)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id = % sender_id, opcode =
+                    1u16, message = "object", direction = "out", "args.index" = % index,
+                    "args.fd" = % std::os::fd::AsRawFd::as_raw_fd(& fd), "args.size" = %
+                    size, "args.offset" = % offset, "args.stride" = % stride,
+                    "args.plane_index" = % plane_index,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id : % = sender_id, opcode =
+                    1u16, message = "object", direction = "out", "args.index" : % =
+                    index, "args.fd" : % = std::os::fd::AsRawFd::as_raw_fd(& fd),
+                    "args.size" : % = size, "args.offset" : % = offset, "args.stride" : %
+                    = stride, "args.plane_index" : % = plane_index;
+                    "zwlr_export_dmabuf_frame_v1.object"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(index)
+                    .put_uint(size)
+                    .put_uint(offset)
+                    .put_uint(stride)
+                    .put_uint(plane_index)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![fd],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent as soon as the frame is presented, indicating it is"]
-            #[doc = "available for reading. This event includes the time at which"]
-            #[doc = "presentation happened at."]
-            #[doc = ""]
-            #[doc = "The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,"]
-            #[doc = "each component being an unsigned 32-bit value. Whole seconds are in"]
-            #[doc = "tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,"]
-            #[doc = "and the additional fractional part in tv_nsec as nanoseconds. Hence,"]
-            #[doc = "for valid timestamps tv_nsec must be in [0, 999999999]. The seconds part"]
-            #[doc = "may have an arbitrary offset at start."]
-            #[doc = ""]
-            #[doc = "After receiving this event, the client should destroy this object."]
-            fn ready(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the ready message was introduced in
+            pub const READY_SINCE: u32 = 1u32;
+            ///The interface version the ready message was deprecated in, if any
+            pub const READY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent as soon as the frame is presented, indicating it is
+            ///available for reading. This event includes the time at which
+            ///presentation happened at.
+            ///
+            ///The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+            ///each component being an unsigned 32-bit value. Whole seconds are in
+            ///tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+            ///and the additional fractional part in tv_nsec as nanoseconds. Hence,
+            ///for valid timestamps tv_nsec must be in [0, 999999999]. The seconds part
+            ///may have an arbitrary offset at start.
+            ///
+            ///After receiving this event, the client should destroy this object.
+            pub fn ready<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 tv_sec_hi: u32,
                 tv_sec_lo: u32,
                 tv_nsec: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_export_dmabuf_frame_v1#{}.ready({}, {}, {})",
-                        sender_id,
-                        tv_sec_hi,
-                        tv_sec_lo,
-                        tv_nsec
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(tv_sec_hi)
-                        .put_uint(tv_sec_lo)
-                        .put_uint(tv_nsec)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id = % sender_id, opcode =
+                    2u16, message = "ready", direction = "out", "args.tv_sec_hi" = %
+                    tv_sec_hi, "args.tv_sec_lo" = % tv_sec_lo, "args.tv_nsec" = %
+                    tv_nsec,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id : % = sender_id, opcode =
+                    2u16, message = "ready", direction = "out", "args.tv_sec_hi" : % =
+                    tv_sec_hi, "args.tv_sec_lo" : % = tv_sec_lo, "args.tv_nsec" : % =
+                    tv_nsec; "zwlr_export_dmabuf_frame_v1.ready"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(tv_sec_hi)
+                    .put_uint(tv_sec_lo)
+                    .put_uint(tv_nsec)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "If the capture failed or if the frame is no longer valid after the"]
-            #[doc = "\"frame\" event has been emitted, this event will be used to inform the"]
-            #[doc = "client to scrap the frame."]
-            #[doc = ""]
-            #[doc = "If the failure is temporary, the client may capture again the same"]
-            #[doc = "source. If the failure is permanent, any further attempts to capture the"]
-            #[doc = "same source will fail again."]
-            #[doc = ""]
-            #[doc = "After receiving this event, the client should destroy this object."]
-            fn cancel(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the cancel message was introduced in
+            pub const CANCEL_SINCE: u32 = 1u32;
+            ///The interface version the cancel message was deprecated in, if any
+            pub const CANCEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///If the capture failed or if the frame is no longer valid after the
+            ///"frame" event has been emitted, this event will be used to inform the
+            ///client to scrap the frame.
+            ///
+            ///If the failure is temporary, the client may capture again the same
+            ///source. If the failure is permanent, any further attempts to capture the
+            ///same source will fail again.
+            ///
+            ///After receiving this event, the client should destroy this object.
+            pub fn cancel<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 reason: CancelReason,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_export_dmabuf_frame_v1#{}.cancel({})",
-                        sender_id,
-                        reason
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(reason.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id = % sender_id, opcode =
+                    3u16, message = "cancel", direction = "out", "args.reason" = %
+                    reason,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_export_dmabuf_frame_v1", object_id : % = sender_id, opcode =
+                    3u16, message = "cancel", direction = "out", "args.reason" : % =
+                    reason; "zwlr_export_dmabuf_frame_v1.cancel"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(reason.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwlr_export_dmabuf_frame_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_export_dmabuf_frame_v1` interface",
+            label = "`ZwlrExportDmabufFrameV1` is not implemented for `{Self}`"
+        )]
+        pub trait ZwlrExportDmabufFrameV1
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwlr_export_dmabuf_frame_v1";
+            const VERSION: u32 = 1u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Unreferences the frame. This request must be called as soon as its no
+            ///longer used.
+            ///
+            ///It can be called at any time by the client. The client will still have
+            ///to close any FDs it has been given.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_export_dmabuf_frame_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_export_dmabuf_frame_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_export_dmabuf_frame_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "destroy", direction = "in";
+                                "zwlr_export_dmabuf_frame_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "wlr-foreign-toplevel-management-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-foreign-toplevel-management-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_foreign_toplevel_management_unstable_v1 {
-    #[doc = "The purpose of this protocol is to enable the creation of taskbars"]
-    #[doc = "and docks by providing them with a list of opened applications and"]
-    #[doc = "letting them request certain actions on them, like maximizing, etc."]
-    #[doc = ""]
-    #[doc = "After a client binds the zwlr_foreign_toplevel_manager_v1, each opened"]
-    #[doc = "toplevel window will be sent via the toplevel event"]
+    ///The purpose of this protocol is to enable the creation of taskbars
+    ///and docks by providing them with a list of opened applications and
+    ///letting them request certain actions on them, like maximizing, etc.
+    ///
+    ///After a client binds the zwlr_foreign_toplevel_manager_v1, each opened
+    ///toplevel window will be sent via the toplevel event
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_foreign_toplevel_manager_v1 {
-        #[doc = "Trait to implement the zwlr_foreign_toplevel_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_foreign_toplevel_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the toplevel message was introduced in
+            pub const TOPLEVEL_SINCE: u32 = 1u32;
+            ///The interface version the toplevel message was deprecated in, if any
+            pub const TOPLEVEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted whenever a new toplevel window is created. It
+            ///is emitted for all toplevels, regardless of the app that has created
+            ///them.
+            ///
+            ///All initial details of the toplevel(title, app_id, states, etc.) will
+            ///be sent immediately after this event via the corresponding events in
+            ///zwlr_foreign_toplevel_handle_v1.
+            pub fn toplevel<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                toplevel: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_manager_v1", object_id = % sender_id, opcode =
+                    0u16, message = "toplevel", direction = "out", "args.toplevel" = %
+                    toplevel,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_manager_v1", object_id : % = sender_id, opcode
+                    = 0u16, message = "toplevel", direction = "out", "args.toplevel" : %
+                    = toplevel; "zwlr_foreign_toplevel_manager_v1.toplevel"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(toplevel))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the finished message was introduced in
+            pub const FINISHED_SINCE: u32 = 1u32;
+            ///The interface version the finished message was deprecated in, if any
+            pub const FINISHED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the compositor is done sending events to the
+            ///zwlr_foreign_toplevel_manager_v1. The server will destroy the object
+            ///immediately after sending this request, so it will become invalid and
+            ///the client should free any resources associated with it.
+            pub fn finished<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_manager_v1", object_id = % sender_id, opcode =
+                    1u16, message = "finished", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_manager_v1", object_id : % = sender_id, opcode
+                    = 1u16, message = "finished", direction = "out";
+                    "zwlr_foreign_toplevel_manager_v1.finished"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the zwlr_foreign_toplevel_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_foreign_toplevel_manager_v1` interface",
+            label = "`ZwlrForeignToplevelManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrForeignToplevelManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_foreign_toplevel_manager_v1";
             const VERSION: u32 = 3u32;
-            #[doc = "Indicates the client no longer wishes to receive events for new toplevels."]
-            #[doc = "However the compositor may emit further toplevel_created events, until"]
-            #[doc = "the finished event is emitted."]
-            #[doc = ""]
-            #[doc = "The client must not send any more requests after this one."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the stop message was introduced in
+            const STOP_SINCE: u32 = 1u32;
+            ///The interface version the stop message was deprecated in, if any
+            const STOP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the client no longer wishes to receive events for new toplevels.
+            ///However the compositor may emit further toplevel_created events, until
+            ///the finished event is emitted.
+            ///
+            ///The client must not send any more requests after this one.
             fn stop(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event is emitted whenever a new toplevel window is created. It"]
-            #[doc = "is emitted for all toplevels, regardless of the app that has created"]
-            #[doc = "them."]
-            #[doc = ""]
-            #[doc = "All initial details of the toplevel(title, app_id, states, etc.) will"]
-            #[doc = "be sent immediately after this event via the corresponding events in"]
-            #[doc = "zwlr_foreign_toplevel_handle_v1."]
-            fn toplevel(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                toplevel: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_manager_v1#{}.toplevel({})",
-                        sender_id,
-                        toplevel
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(toplevel))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates that the compositor is done sending events to the"]
-            #[doc = "zwlr_foreign_toplevel_manager_v1. The server will destroy the object"]
-            #[doc = "immediately after sending this request, so it will become invalid and"]
-            #[doc = "the client should free any resources associated with it."]
-            fn finished(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_manager_v1#{}.finished()",
-                        sender_id,
-                    );
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_manager_v1#{}.stop()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "stop", direction = "in",
                             );
-                            self.stop(connection, sender_id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_manager_v1", object_id : % =
+                                sender_id, opcode = 0u16, message = "stop", direction =
+                                "in"; "zwlr_foreign_toplevel_manager_v1.stop"
+                            );
+                            self.stop(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A zwlr_foreign_toplevel_handle_v1 object represents an opened toplevel"]
-    #[doc = "window. Each app may have multiple opened toplevels."]
-    #[doc = ""]
-    #[doc = "Each toplevel has a list of outputs it is visible on, conveyed to the"]
-    #[doc = "client with the output_enter and output_leave events."]
+    ///A zwlr_foreign_toplevel_handle_v1 object represents an opened toplevel
+    ///window. Each app may have multiple opened toplevels.
+    ///
+    ///Each toplevel has a list of outputs it is visible on, conveyed to the
+    ///client with the output_enter and output_leave events.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_foreign_toplevel_handle_v1 {
-        #[doc = "The different states that a toplevel can have. These have the same meaning"]
-        #[doc = "as the states with the same names defined in xdg-toplevel"]
+        ///The different states that a toplevel can have. These have the same meaning
+        ///as the states with the same names defined in xdg-toplevel
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum State {
-            #[doc = "the toplevel is maximized"]
+            ///the toplevel is maximized
             Maximized = 0u32,
-            #[doc = "the toplevel is minimized"]
+            ///the toplevel is minimized
             Minimized = 1u32,
-            #[doc = "the toplevel is active"]
+            ///the toplevel is active
             Activated = 2u32,
-            #[doc = "the toplevel is fullscreen"]
+            ///the toplevel is fullscreen
+            ///
+            ///Available since version 2
             Fullscreen = 3u32,
         }
-        impl From<State> for u32 {
-            fn from(value: State) -> Self {
+        #[allow(deprecated)]
+        impl State {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Maximized => 1u32,
+                    Self::Minimized => 1u32,
+                    Self::Activated => 1u32,
+                    Self::Fullscreen => 2u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Maximized => None,
+                    Self::Minimized => None,
+                    Self::Activated => None,
+                    Self::Fullscreen => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("maximized", Self::Maximized),
+                ("minimized", Self::Minimized),
+                ("activated", Self::Activated),
+                ("fullscreen", Self::Fullscreen),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Maximized => "maximized",
+                    Self::Minimized => "minimized",
+                    Self::Activated => "activated",
+                    Self::Fullscreen => "fullscreen",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
+        impl From<State> for u32 {
+            fn from(value: State) -> Self {
                 value as u32
             }
         }
+        impl From<State> for i32 {
+            fn from(value: State) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for State {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1116,23 +2311,71 @@ pub mod wlr_foreign_toplevel_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for State {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for State {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "the provided rectangle is invalid"]
+            ///the provided rectangle is invalid
             InvalidRectangle = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidRectangle => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidRectangle => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_rectangle", Self::InvalidRectangle),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidRectangle => "invalid_rectangle",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1142,385 +2385,735 @@ pub mod wlr_foreign_toplevel_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_maximized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "unset_minimized",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "activate",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "close",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_rectangle",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "oiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_fullscreen",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "unset_fullscreen",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_foreign_toplevel_handle_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the title message was introduced in
+            pub const TITLE_SINCE: u32 = 1u32;
+            ///The interface version the title message was deprecated in, if any
+            pub const TITLE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted whenever the title of the toplevel changes.
+            pub fn title<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                title: String,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    0u16, message = "title", direction = "out", "args.title" = % title,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 0u16, message = "title", direction = "out", "args.title" : % =
+                    title; "zwlr_foreign_toplevel_handle_v1.title"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(title))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the app_id message was introduced in
+            pub const APP_ID_SINCE: u32 = 1u32;
+            ///The interface version the app_id message was deprecated in, if any
+            pub const APP_ID_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted whenever the app-id of the toplevel changes.
+            pub fn app_id<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                app_id: String,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    1u16, message = "app_id", direction = "out", "args.app_id" = %
+                    app_id,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 1u16, message = "app_id", direction = "out", "args.app_id" : % =
+                    app_id; "zwlr_foreign_toplevel_handle_v1.app_id"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(app_id))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the output_enter message was introduced in
+            pub const OUTPUT_ENTER_SINCE: u32 = 1u32;
+            ///The interface version the output_enter message was deprecated in, if any
+            pub const OUTPUT_ENTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted whenever the toplevel becomes visible on
+            ///the given output. A toplevel may be visible on multiple outputs.
+            pub fn output_enter<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                output: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    2u16, message = "output_enter", direction = "out", "args.output" = %
+                    output,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 2u16, message = "output_enter", direction = "out", "args.output" :
+                    % = output; "zwlr_foreign_toplevel_handle_v1.output_enter"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(output))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the output_leave message was introduced in
+            pub const OUTPUT_LEAVE_SINCE: u32 = 1u32;
+            ///The interface version the output_leave message was deprecated in, if any
+            pub const OUTPUT_LEAVE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted whenever the toplevel stops being visible on
+            ///the given output. It is guaranteed that an entered-output event
+            ///with the same output has been emitted before this event.
+            pub fn output_leave<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                output: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    3u16, message = "output_leave", direction = "out", "args.output" = %
+                    output,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 3u16, message = "output_leave", direction = "out", "args.output" :
+                    % = output; "zwlr_foreign_toplevel_handle_v1.output_leave"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(output))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the state message was introduced in
+            pub const STATE_SINCE: u32 = 1u32;
+            ///The interface version the state message was deprecated in, if any
+            pub const STATE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted immediately after the zlw_foreign_toplevel_handle_v1
+            ///is created and each time the toplevel state changes, either because of a
+            ///compositor action or because of a request in this protocol.
+            pub fn state<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                state_arg: Vec<u8>,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    4u16, message = "state", direction = "out", "args.state" = %
+                    state_arg.len(),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 4u16, message = "state", direction = "out", "args.state" : % =
+                    state_arg.len(); "zwlr_foreign_toplevel_handle_v1.state"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_array(state_arg)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent after all changes in the toplevel state have been
+            ///sent.
+            ///
+            ///This allows changes to the zwlr_foreign_toplevel_handle_v1 properties
+            ///to be seen as atomic, even if they happen via multiple events.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    5u16, message = "done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 5u16, message = "done", direction = "out";
+                    "zwlr_foreign_toplevel_handle_v1.done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the closed message was introduced in
+            pub const CLOSED_SINCE: u32 = 1u32;
+            ///The interface version the closed message was deprecated in, if any
+            pub const CLOSED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event means the toplevel has been destroyed. It is guaranteed there
+            ///won't be any more events for this zwlr_foreign_toplevel_handle_v1. The
+            ///toplevel itself becomes inert so any requests will be ignored except the
+            ///destroy request.
+            pub fn closed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    6u16, message = "closed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 6u16, message = "closed", direction = "out";
+                    "zwlr_foreign_toplevel_handle_v1.closed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the parent message was introduced in
+            pub const PARENT_SINCE: u32 = 3u32;
+            ///The interface version the parent message was deprecated in, if any
+            pub const PARENT_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is emitted whenever the parent of the toplevel changes.
+            ///
+            ///No event is emitted when the parent handle is destroyed by the client.
+            ///
+            ///Available since version 3
+            pub fn parent<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                parent: Option<waynest::ObjectId>,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id, opcode =
+                    7u16, message = "parent", direction = "out", "args.parent" = % parent
+                    .as_ref().map_or("null".to_string(), | v | v.to_string()),
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_foreign_toplevel_handle_v1", object_id : % = sender_id, opcode
+                    = 7u16, message = "parent", direction = "out", "args.parent" : % =
+                    parent.as_ref().map_or("null".to_string(), | v | v.to_string());
+                    "zwlr_foreign_toplevel_handle_v1.parent"
+                );
+                let payload = waynest::PayloadBuilder::new().put_object(parent).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 7u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwlr_foreign_toplevel_handle_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwlr_foreign_toplevel_handle_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_foreign_toplevel_handle_v1` interface",
+            label = "`ZwlrForeignToplevelHandleV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrForeignToplevelHandleV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_foreign_toplevel_handle_v1";
             const VERSION: u32 = 3u32;
-            #[doc = "Requests that the toplevel be maximized. If the maximized state actually"]
-            #[doc = "changes, this will be indicated by the state event."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_maximized message was introduced in
+            const SET_MAXIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the set_maximized message was deprecated in, if any
+            const SET_MAXIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the toplevel be maximized. If the maximized state actually
+            ///changes, this will be indicated by the state event.
             fn set_maximized(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the toplevel be unmaximized. If the maximized state actually"]
-            #[doc = "changes, this will be indicated by the state event."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the unset_maximized message was introduced in
+            const UNSET_MAXIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the unset_maximized message was deprecated in, if any
+            const UNSET_MAXIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the toplevel be unmaximized. If the maximized state actually
+            ///changes, this will be indicated by the state event.
             fn unset_maximized(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the toplevel be minimized. If the minimized state actually"]
-            #[doc = "changes, this will be indicated by the state event."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_minimized message was introduced in
+            const SET_MINIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the set_minimized message was deprecated in, if any
+            const SET_MINIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the toplevel be minimized. If the minimized state actually
+            ///changes, this will be indicated by the state event.
             fn set_minimized(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the toplevel be unminimized. If the minimized state actually"]
-            #[doc = "changes, this will be indicated by the state event."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the unset_minimized message was introduced in
+            const UNSET_MINIMIZED_SINCE: u32 = 1u32;
+            ///The interface version the unset_minimized message was deprecated in, if any
+            const UNSET_MINIMIZED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the toplevel be unminimized. If the minimized state actually
+            ///changes, this will be indicated by the state event.
             fn unset_minimized(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Request that this toplevel be activated on the given seat."]
-            #[doc = "There is no guarantee the toplevel will be actually activated."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the activate message was introduced in
+            const ACTIVATE_SINCE: u32 = 1u32;
+            ///The interface version the activate message was deprecated in, if any
+            const ACTIVATE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Request that this toplevel be activated on the given seat.
+            ///There is no guarantee the toplevel will be actually activated.
             fn activate(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Send a request to the toplevel to close itself. The compositor would"]
-            #[doc = "typically use a shell-specific method to carry out this request, for"]
-            #[doc = "example by sending the xdg_toplevel.close event. However, this gives"]
-            #[doc = "no guarantees the toplevel will actually be destroyed. If and when"]
-            #[doc = "this happens, the zwlr_foreign_toplevel_handle_v1.closed event will"]
-            #[doc = "be emitted."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the close message was introduced in
+            const CLOSE_SINCE: u32 = 1u32;
+            ///The interface version the close message was deprecated in, if any
+            const CLOSE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Send a request to the toplevel to close itself. The compositor would
+            ///typically use a shell-specific method to carry out this request, for
+            ///example by sending the xdg_toplevel.close event. However, this gives
+            ///no guarantees the toplevel will actually be destroyed. If and when
+            ///this happens, the zwlr_foreign_toplevel_handle_v1.closed event will
+            ///be emitted.
             fn close(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The rectangle of the surface specified in this request corresponds to"]
-            #[doc = "the place where the app using this protocol represents the given toplevel."]
-            #[doc = "It can be used by the compositor as a hint for some operations, e.g"]
-            #[doc = "minimizing. The client is however not required to set this, in which"]
-            #[doc = "case the compositor is free to decide some default value."]
-            #[doc = ""]
-            #[doc = "If the client specifies more than one rectangle, only the last one is"]
-            #[doc = "considered."]
-            #[doc = ""]
-            #[doc = "The dimensions are given in surface-local coordinates."]
-            #[doc = "Setting width=height=0 removes the already-set rectangle."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_rectangle message was introduced in
+            const SET_RECTANGLE_SINCE: u32 = 1u32;
+            ///The interface version the set_rectangle message was deprecated in, if any
+            const SET_RECTANGLE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The rectangle of the surface specified in this request corresponds to
+            ///the place where the app using this protocol represents the given toplevel.
+            ///It can be used by the compositor as a hint for some operations, e.g
+            ///minimizing. The client is however not required to set this, in which
+            ///case the compositor is free to decide some default value.
+            ///
+            ///If the client specifies more than one rectangle, only the last one is
+            ///considered.
+            ///
+            ///The dimensions are given in surface-local coordinates.
+            ///Setting width=height=0 removes the already-set rectangle.
             fn set_rectangle(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 surface: waynest::ObjectId,
                 x: i32,
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the zwlr_foreign_toplevel_handle_v1 object."]
-            #[doc = ""]
-            #[doc = "This request should be called either when the client does not want to"]
-            #[doc = "use the toplevel anymore or after the closed event to finalize the"]
-            #[doc = "destruction of the object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the zwlr_foreign_toplevel_handle_v1 object.
+            ///
+            ///This request should be called either when the client does not want to
+            ///use the toplevel anymore or after the closed event to finalize the
+            ///destruction of the object.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the toplevel be fullscreened on the given output. If the"]
-            #[doc = "fullscreen state and/or the outputs the toplevel is visible on actually"]
-            #[doc = "change, this will be indicated by the state and output_enter/leave"]
-            #[doc = "events."]
-            #[doc = ""]
-            #[doc = "The output parameter is only a hint to the compositor. Also, if output"]
-            #[doc = "is NULL, the compositor should decide which output the toplevel will be"]
-            #[doc = "fullscreened on, if at all."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_fullscreen message was introduced in
+            const SET_FULLSCREEN_SINCE: u32 = 2u32;
+            ///The interface version the set_fullscreen message was deprecated in, if any
+            const SET_FULLSCREEN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the toplevel be fullscreened on the given output. If the
+            ///fullscreen state and/or the outputs the toplevel is visible on actually
+            ///change, this will be indicated by the state and output_enter/leave
+            ///events.
+            ///
+            ///The output parameter is only a hint to the compositor. Also, if output
+            ///is NULL, the compositor should decide which output the toplevel will be
+            ///fullscreened on, if at all.
+            ///
+            ///Available since version 2
             fn set_fullscreen(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 output: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the toplevel be unfullscreened. If the fullscreen state"]
-            #[doc = "actually changes, this will be indicated by the state event."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the unset_fullscreen message was introduced in
+            const UNSET_FULLSCREEN_SINCE: u32 = 2u32;
+            ///The interface version the unset_fullscreen message was deprecated in, if any
+            const UNSET_FULLSCREEN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the toplevel be unfullscreened. If the fullscreen state
+            ///actually changes, this will be indicated by the state event.
+            ///
+            ///Available since version 2
             fn unset_fullscreen(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event is emitted whenever the title of the toplevel changes."]
-            fn title(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                title: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_handle_v1#{}.title(\"{}\")",
-                        sender_id,
-                        title
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(title))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is emitted whenever the app-id of the toplevel changes."]
-            fn app_id(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                app_id: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_handle_v1#{}.app_id(\"{}\")",
-                        sender_id,
-                        app_id
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(app_id))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is emitted whenever the toplevel becomes visible on"]
-            #[doc = "the given output. A toplevel may be visible on multiple outputs."]
-            fn output_enter(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_handle_v1#{}.output_enter({})",
-                        sender_id,
-                        output
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(output))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is emitted whenever the toplevel stops being visible on"]
-            #[doc = "the given output. It is guaranteed that an entered-output event"]
-            #[doc = "with the same output has been emitted before this event."]
-            fn output_leave(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_handle_v1#{}.output_leave({})",
-                        sender_id,
-                        output
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(output))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 3u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is emitted immediately after the zlw_foreign_toplevel_handle_v1"]
-            #[doc = "is created and each time the toplevel state changes, either because of a"]
-            #[doc = "compositor action or because of a request in this protocol."]
-            fn state(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                state: Vec<u8>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_handle_v1#{}.state(array[{}])",
-                        sender_id,
-                        state.len()
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_array(state).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 4u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is sent after all changes in the toplevel state have been"]
-            #[doc = "sent."]
-            #[doc = ""]
-            #[doc = "This allows changes to the zwlr_foreign_toplevel_handle_v1 properties"]
-            #[doc = "to be seen as atomic, even if they happen via multiple events."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_foreign_toplevel_handle_v1#{}.done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 5u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event means the toplevel has been destroyed. It is guaranteed there"]
-            #[doc = "won't be any more events for this zwlr_foreign_toplevel_handle_v1. The"]
-            #[doc = "toplevel itself becomes inert so any requests will be ignored except the"]
-            #[doc = "destroy request."]
-            fn closed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_foreign_toplevel_handle_v1#{}.closed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 6u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is emitted whenever the parent of the toplevel changes."]
-            #[doc = ""]
-            #[doc = "No event is emitted when the parent handle is destroyed by the client."]
-            fn parent(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                parent: Option<waynest::ObjectId>,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_foreign_toplevel_handle_v1#{}.parent({})",
-                        sender_id,
-                        parent
-                            .as_ref()
-                            .map_or("null".to_string(), |v| v.to_string())
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_object(parent).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 7u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.set_maximized()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "set_maximized", direction = "in",
                             );
-                            self.set_maximized(connection, sender_id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 0u16, message = "set_maximized",
+                                direction = "in";
+                                "zwlr_foreign_toplevel_handle_v1.set_maximized"
+                            );
+                            self.set_maximized(connection, state, sender_id).await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.unset_maximized()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "unset_maximized", direction =
+                                "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 1u16, message = "unset_maximized",
+                                direction = "in";
+                                "zwlr_foreign_toplevel_handle_v1.unset_maximized"
                             );
-                            self.unset_maximized(connection, sender_id).await
+                            self.unset_maximized(connection, state, sender_id).await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.set_minimized()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 2u16, message = "set_minimized", direction = "in",
                             );
-                            self.set_minimized(connection, sender_id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 2u16, message = "set_minimized",
+                                direction = "in";
+                                "zwlr_foreign_toplevel_handle_v1.set_minimized"
+                            );
+                            self.set_minimized(connection, state, sender_id).await
                         }
                         3u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.unset_minimized()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 3u16, message = "unset_minimized", direction =
+                                "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 3u16, message = "unset_minimized",
+                                direction = "in";
+                                "zwlr_foreign_toplevel_handle_v1.unset_minimized"
                             );
-                            self.unset_minimized(connection, sender_id).await
+                            self.unset_minimized(connection, state, sender_id).await
                         }
                         4u16 => {
                             let seat = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.activate({})",
-                                sender_id,
-                                seat
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 4u16, message = "activate", direction = "in",
+                                "args.seat" = % seat,
                             );
-                            self.activate(connection, sender_id, seat).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 4u16, message = "activate", direction =
+                                "in", "args.seat" : % = seat;
+                                "zwlr_foreign_toplevel_handle_v1.activate"
+                            );
+                            self.activate(connection, state, sender_id, seat).await
                         }
                         5u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.close()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 5u16, message = "close", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 5u16, message = "close", direction =
+                                "in"; "zwlr_foreign_toplevel_handle_v1.close"
                             );
-                            self.close(connection, sender_id).await
+                            self.close(connection, state, sender_id).await
                         }
                         6u16 => {
                             let surface = message
@@ -1531,101 +3124,213 @@ pub mod wlr_foreign_toplevel_management_unstable_v1 {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.set_rectangle({}, {}, {}, {}, {})",
-                                sender_id,
-                                surface,
-                                x,
-                                y,
-                                width,
-                                height
-                            );
-                            self.set_rectangle(connection, sender_id, surface, x, y, width, height)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 6u16, message = "set_rectangle", direction = "in",
+                                "args.surface" = % surface, "args.x" = % x, "args.y" = % y,
+                                "args.width" = % width, "args.height" = % height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 6u16, message = "set_rectangle",
+                                direction = "in", "args.surface" : % = surface, "args.x" : %
+                                = x, "args.y" : % = y, "args.width" : % = width,
+                                "args.height" : % = height;
+                                "zwlr_foreign_toplevel_handle_v1.set_rectangle"
+                            );
+                            self.set_rectangle(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    surface,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
                                 .await
                         }
                         7u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.destroy()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 7u16, message = "destroy", direction = "in",
                             );
-                            self.destroy(connection, sender_id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 7u16, message = "destroy", direction =
+                                "in"; "zwlr_foreign_toplevel_handle_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         8u16 => {
                             let output = message.object()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.set_fullscreen({})",
-                                sender_id,
-                                output
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string())
-                            );
-                            self.set_fullscreen(connection, sender_id, output).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 8u16, message = "set_fullscreen", direction = "in",
+                                "args.output" = % output.as_ref().map_or("null".to_string(),
+                                | v | v.to_string()),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 8u16, message = "set_fullscreen",
+                                direction = "in", "args.output" : % = output.as_ref()
+                                .map_or("null".to_string(), | v | v.to_string());
+                                "zwlr_foreign_toplevel_handle_v1.set_fullscreen"
+                            );
+                            self.set_fullscreen(connection, state, sender_id, output)
+                                .await
                         }
                         9u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_foreign_toplevel_handle_v1#{}.unset_fullscreen()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id = % sender_id,
+                                opcode = 9u16, message = "unset_fullscreen", direction =
+                                "in",
                             );
-                            self.unset_fullscreen(connection, sender_id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_foreign_toplevel_handle_v1", object_id : % =
+                                sender_id, opcode = 9u16, message = "unset_fullscreen",
+                                direction = "in";
+                                "zwlr_foreign_toplevel_handle_v1.unset_fullscreen"
+                            );
+                            self.unset_fullscreen(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[doc = "This protocol allows a privileged client to set the gamma tables for"]
-#[doc = "outputs."]
-#[doc = ""]
-#[doc = "Warning! The protocol described in this file is experimental and"]
-#[doc = "backward incompatible changes may be made. Backward compatible changes"]
-#[doc = "may be added together with the corresponding interface version bump."]
-#[doc = "Backward incompatible changes are done by bumping the version number in"]
-#[doc = "the protocol and interface names and resetting the interface version."]
-#[doc = "Once the protocol is to be declared stable, the 'z' prefix and the"]
-#[doc = "version number in the protocol and interface names are removed and the"]
-#[doc = "interface version number is reset."]
-#[allow(clippy::module_inception)]
+///This protocol allows a privileged client to set the gamma tables for
+///outputs.
+///
+///Warning! The protocol described in this file is experimental and
+///backward incompatible changes may be made. Backward compatible changes
+///may be added together with the corresponding interface version bump.
+///Backward incompatible changes are done by bumping the version number in
+///the protocol and interface names and resetting the interface version.
+///Once the protocol is to be declared stable, the 'z' prefix and the
+///version number in the protocol and interface names are removed and the
+///interface version number is reset.
+#[cfg(feature = "wlr-gamma-control-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-gamma-control-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_gamma_control_unstable_v1 {
-    #[doc = "This interface is a manager that allows creating per-output gamma"]
-    #[doc = "controls."]
+    ///This interface is a manager that allows creating per-output gamma
+    ///controls.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_gamma_control_manager_v1 {
-        #[doc = "Trait to implement the zwlr_gamma_control_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_gamma_control",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_gamma_control_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_gamma_control_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_gamma_control_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_gamma_control_manager_v1` interface",
+            label = "`ZwlrGammaControlManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrGammaControlManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_gamma_control_manager_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Create a gamma control that can be used to adjust gamma tables for the"]
-            #[doc = "provided output."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_gamma_control message was introduced in
+            const GET_GAMMA_CONTROL_SINCE: u32 = 1u32;
+            ///The interface version the get_gamma_control message was deprecated in, if any
+            const GET_GAMMA_CONTROL_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a gamma control that can be used to adjust gamma tables for the
+            ///provided output.
             fn get_gamma_control(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "All objects created by the manager will still remain valid, until their"]
-            #[doc = "appropriate destroy request has been called."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///All objects created by the manager will still remain valid, until their
+            ///appropriate destroy request has been called.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -1637,53 +3342,119 @@ pub mod wlr_gamma_control_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_gamma_control_manager_v1#{}.get_gamma_control({}, {})",
-                                sender_id,
-                                id,
-                                output
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_gamma_control_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "get_gamma_control", direction =
+                                "in", "args.id" = % id, "args.output" = % output,
                             );
-                            self.get_gamma_control(connection, sender_id, id, output)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_gamma_control_manager_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "get_gamma_control", direction =
+                                "in", "args.id" : % = id, "args.output" : % = output;
+                                "zwlr_gamma_control_manager_v1.get_gamma_control"
+                            );
+                            self.get_gamma_control(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    output,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_gamma_control_manager_v1#{}.destroy()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_gamma_control_manager_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_gamma_control_manager_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_gamma_control_manager_v1.destroy"
                             );
-                            self.destroy(connection, sender_id).await
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This interface allows a client to adjust gamma tables for a particular"]
-    #[doc = "output."]
-    #[doc = ""]
-    #[doc = "The client will receive the gamma size, and will then be able to set gamma"]
-    #[doc = "tables. At any time the compositor can send a failed event indicating that"]
-    #[doc = "this object is no longer valid."]
-    #[doc = ""]
-    #[doc = "There can only be at most one gamma control object per output, which"]
-    #[doc = "has exclusive access to this particular output. When the gamma control"]
-    #[doc = "object is destroyed, the gamma table is restored to its original value."]
+    ///This interface allows a client to adjust gamma tables for a particular
+    ///output.
+    ///
+    ///The client will receive the gamma size, and will then be able to set gamma
+    ///tables. At any time the compositor can send a failed event indicating that
+    ///this object is no longer valid.
+    ///
+    ///There can only be at most one gamma control object per output, which
+    ///has exclusive access to this particular output. When the gamma control
+    ///object is destroyed, the gamma table is restored to its original value.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_gamma_control_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "invalid gamma tables"]
+            ///invalid gamma tables
             InvalidGamma = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidGamma => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidGamma => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_gamma", Self::InvalidGamma),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidGamma => "invalid_gamma",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1693,146 +3464,290 @@ pub mod wlr_gamma_control_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_gamma",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "h",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "fd",
+                        kind: waynest::ArgKind::Fd,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_gamma_control_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the gamma_size message was introduced in
+            pub const GAMMA_SIZE_SINCE: u32 = 1u32;
+            ///The interface version the gamma_size message was deprecated in, if any
+            pub const GAMMA_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Advertise the size of each gamma ramp.
+            ///
+            ///This event is sent immediately when the gamma control object is created.
+            pub fn gamma_size<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                size: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_gamma_control_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "gamma_size", direction = "out", "args.size" = % size,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_gamma_control_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "gamma_size", direction = "out", "args.size" : % = size;
+                    "zwlr_gamma_control_v1.gamma_size"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(size).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the failed message was introduced in
+            pub const FAILED_SINCE: u32 = 1u32;
+            ///The interface version the failed message was deprecated in, if any
+            pub const FAILED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the gamma control is no longer valid. This
+            ///can happen for a number of reasons, including:
+            ///- The output doesn't support gamma tables
+            ///- Setting the gamma tables failed
+            ///- Another client already has exclusive gamma control for this output
+            ///- The compositor has transferred gamma control to another client
+            ///
+            ///Upon receiving this event, the client should destroy this object.
+            pub fn failed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_gamma_control_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "failed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_gamma_control_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "failed", direction = "out"; "zwlr_gamma_control_v1.failed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwlr_gamma_control_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwlr_gamma_control_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_gamma_control_v1` interface",
+            label = "`ZwlrGammaControlV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrGammaControlV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_gamma_control_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Set the gamma table. The file descriptor can be memory-mapped to provide"]
-            #[doc = "the raw gamma table, which contains successive gamma ramps for the red,"]
-            #[doc = "green and blue channels. Each gamma ramp is an array of 16-byte unsigned"]
-            #[doc = "integers which has the same length as the gamma size."]
-            #[doc = ""]
-            #[doc = "The file descriptor data must have the same length as three times the"]
-            #[doc = "gamma size."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_gamma message was introduced in
+            const SET_GAMMA_SINCE: u32 = 1u32;
+            ///The interface version the set_gamma message was deprecated in, if any
+            const SET_GAMMA_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set the gamma table. The file descriptor can be memory-mapped to provide
+            ///the raw gamma table, which contains successive gamma ramps for the red,
+            ///green and blue channels. Each gamma ramp is an array of 16-byte unsigned
+            ///integers which has the same length as the gamma size.
+            ///
+            ///The file descriptor data must have the same length as three times the
+            ///gamma size.
             fn set_gamma(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 fd: std::os::fd::OwnedFd,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the gamma control object. If the object is still valid, this"]
-            #[doc = "restores the original gamma tables."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the gamma control object. If the object is still valid, this
+            ///restores the original gamma tables.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Advertise the size of each gamma ramp."]
-            #[doc = ""]
-            #[doc = "This event is sent immediately when the gamma control object is created."]
-            fn gamma_size(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                size: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_gamma_control_v1#{}.gamma_size({})",
-                        sender_id,
-                        size
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_uint(size).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates that the gamma control is no longer valid. This"]
-            #[doc = "can happen for a number of reasons, including:"]
-            #[doc = "- The output doesn't support gamma tables"]
-            #[doc = "- Setting the gamma tables failed"]
-            #[doc = "- Another client already has exclusive gamma control for this output"]
-            #[doc = "- The compositor has transferred gamma control to another client"]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy this object."]
-            fn failed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_gamma_control_v1#{}.failed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             let fd = waynest::Connection::fd(connection)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_gamma_control_v1#{}.set_gamma({})",
-                                sender_id,
-                                std::os::fd::AsRawFd::as_raw_fd(&fd)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_gamma_control_v1", object_id = % sender_id, opcode =
+                                0u16, message = "set_gamma", direction = "in", "args.fd" = %
+                                std::os::fd::AsRawFd::as_raw_fd(& fd),
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_gamma_control_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "set_gamma", direction = "in", "args.fd" : %
+                                = std::os::fd::AsRawFd::as_raw_fd(& fd);
+                                "zwlr_gamma_control_v1.set_gamma"
                             );
-                            self.set_gamma(connection, sender_id, fd).await
+                            self.set_gamma(connection, state, sender_id, fd).await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_gamma_control_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_gamma_control_v1", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_gamma_control_v1", object_id : % = sender_id, opcode =
+                                1u16, message = "destroy", direction = "in";
+                                "zwlr_gamma_control_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "wlr-input-inhibit-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-input-inhibit-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_input_inhibit_unstable_v1 {
-    #[doc = "Clients can use this interface to prevent input events from being sent to"]
-    #[doc = "any surfaces but its own, which is useful for example in lock screen"]
-    #[doc = "software. It is assumed that access to this interface will be locked down"]
-    #[doc = "to whitelisted clients by the compositor."]
-    #[doc = ""]
-    #[doc = "Note! This protocol is deprecated and not intended for production use."]
-    #[doc = "For screen lockers, use the ext-session-lock-v1 protocol."]
+    ///Clients can use this interface to prevent input events from being sent to
+    ///any surfaces but its own, which is useful for example in lock screen
+    ///software. It is assumed that access to this interface will be locked down
+    ///to whitelisted clients by the compositor.
+    ///
+    ///Note! This protocol is deprecated and not intended for production use.
+    ///For screen lockers, use the ext-session-lock-v1 protocol.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_input_inhibit_manager_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "an input inhibitor is already in use on the compositor"]
+            ///an input inhibitor is already in use on the compositor
             AlreadyInhibited = 0u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::AlreadyInhibited => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::AlreadyInhibited => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("already_inhibited", Self::AlreadyInhibited),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::AlreadyInhibited => "already_inhibited",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1842,34 +3757,81 @@ pub mod wlr_input_inhibit_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_input_inhibit_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_inhibitor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "n",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_input_inhibitor_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwlr_input_inhibit_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_input_inhibit_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_input_inhibit_manager_v1` interface",
+            label = "`ZwlrInputInhibitManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrInputInhibitManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_input_inhibit_manager_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Activates the input inhibitor. As long as the inhibitor is active, the"]
-            #[doc = "compositor will not send input events to other clients."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_inhibitor message was introduced in
+            const GET_INHIBITOR_SINCE: u32 = 1u32;
+            ///The interface version the get_inhibitor message was deprecated in, if any
+            const GET_INHIBITOR_DEPRECATED_SINCE: Option<u32> = None;
+            ///Activates the input inhibitor. As long as the inhibitor is active, the
+            ///compositor will not send input events to other clients.
             fn get_inhibitor(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -1878,93 +3840,202 @@ pub mod wlr_input_inhibit_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_input_inhibit_manager_v1#{}.get_inhibitor({})",
-                                sender_id,
-                                id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_input_inhibit_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "get_inhibitor", direction = "in",
+                                "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_input_inhibit_manager_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "get_inhibitor", direction = "in",
+                                "args.id" : % = id;
+                                "zwlr_input_inhibit_manager_v1.get_inhibitor"
                             );
-                            self.get_inhibitor(connection, sender_id, id).await
+                            self.get_inhibitor(connection, state, sender_id, id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "While this resource exists, input to clients other than the owner of the"]
-    #[doc = "inhibitor resource will not receive input events. Any client which"]
-    #[doc = "previously had focus will receive a leave event and will not be given"]
-    #[doc = "focus again. The client that owns this resource will receive all input"]
-    #[doc = "events normally. The compositor will also disable all of its own input"]
-    #[doc = "processing (such as keyboard shortcuts) while the inhibitor is active."]
-    #[doc = ""]
-    #[doc = "The compositor may continue to send input events to selected clients,"]
-    #[doc = "such as an on-screen keyboard (via the input-method protocol)."]
+    ///While this resource exists, input to clients other than the owner of the
+    ///inhibitor resource will not receive input events. Any client which
+    ///previously had focus will receive a leave event and will not be given
+    ///focus again. The client that owns this resource will receive all input
+    ///events normally. The compositor will also disable all of its own input
+    ///processing (such as keyboard shortcuts) while the inhibitor is active.
+    ///
+    ///The compositor may continue to send input events to selected clients,
+    ///such as an on-screen keyboard (via the input-method protocol).
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_input_inhibitor_v1 {
-        #[doc = "Trait to implement the zwlr_input_inhibitor_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_input_inhibitor_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_input_inhibitor_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_input_inhibitor_v1` interface",
+            label = "`ZwlrInputInhibitorV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrInputInhibitorV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_input_inhibitor_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Destroy the inhibitor and allow other clients to receive input."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroy the inhibitor and allow other clients to receive input.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_input_inhibitor_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_input_inhibitor_v1", object_id = % sender_id, opcode =
+                                0u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_input_inhibitor_v1", object_id : % = sender_id, opcode
+                                = 0u16, message = "destroy", direction = "in";
+                                "zwlr_input_inhibitor_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "wlr-layer-shell-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-layer-shell-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_layer_shell_unstable_v1 {
-    #[doc = "Clients can use this interface to assign the surface_layer role to"]
-    #[doc = "wl_surfaces. Such surfaces are assigned to a \"layer\" of the output and"]
-    #[doc = "rendered with a defined z-depth respective to each other. They may also be"]
-    #[doc = "anchored to the edges and corners of a screen and specify input handling"]
-    #[doc = "semantics. This interface should be suitable for the implementation of"]
-    #[doc = "many desktop shell components, and a broad number of other applications"]
-    #[doc = "that interact with the desktop."]
+    ///Clients can use this interface to assign the surface_layer role to
+    ///wl_surfaces. Such surfaces are assigned to a "layer" of the output and
+    ///rendered with a defined z-depth respective to each other. They may also be
+    ///anchored to the edges and corners of a screen and specify input handling
+    ///semantics. This interface should be suitable for the implementation of
+    ///many desktop shell components, and a broad number of other applications
+    ///that interact with the desktop.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_layer_shell_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "wl_surface has another role"]
+            ///wl_surface has another role
             Role = 0u32,
-            #[doc = "layer value is invalid"]
+            ///layer value is invalid
             InvalidLayer = 1u32,
-            #[doc = "wl_surface has a buffer attached or committed"]
+            ///wl_surface has a buffer attached or committed
             AlreadyConstructed = 2u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Role => 1u32,
+                    Self::InvalidLayer => 1u32,
+                    Self::AlreadyConstructed => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Role => None,
+                    Self::InvalidLayer => None,
+                    Self::AlreadyConstructed => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("role", Self::Role),
+                ("invalid_layer", Self::InvalidLayer),
+                ("already_constructed", Self::AlreadyConstructed),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Role => "role",
+                    Self::InvalidLayer => "invalid_layer",
+                    Self::AlreadyConstructed => "already_constructed",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -1976,17 +4047,23 @@ pub mod wlr_layer_shell_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "These values indicate which layers a surface can be rendered in. They"]
-        #[doc = "are ordered by z depth, bottom-most first. Traditional shell surfaces"]
-        #[doc = "will typically be rendered between the bottom and top layers."]
-        #[doc = "Fullscreen shell surfaces are typically rendered at the top layer."]
-        #[doc = "Multiple surfaces can share a single layer, and ordering within a"]
-        #[doc = "single layer is undefined."]
+        ///These values indicate which layers a surface can be rendered in. They
+        ///are ordered by z depth, bottom-most first. Traditional shell surfaces
+        ///will typically be rendered between the bottom and top layers.
+        ///Fullscreen shell surfaces are typically rendered at the top layer.
+        ///Multiple surfaces can share a single layer, and ordering within a
+        ///single layer is undefined.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
@@ -1996,11 +4073,65 @@ pub mod wlr_layer_shell_unstable_v1 {
             Top = 2u32,
             Overlay = 3u32,
         }
+        #[allow(deprecated)]
+        impl Layer {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Background => 1u32,
+                    Self::Bottom => 1u32,
+                    Self::Top => 1u32,
+                    Self::Overlay => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Background => None,
+                    Self::Bottom => None,
+                    Self::Top => None,
+                    Self::Overlay => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("background", Self::Background),
+                ("bottom", Self::Bottom),
+                ("top", Self::Top),
+                ("overlay", Self::Overlay),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Background => "background",
+                    Self::Bottom => "bottom",
+                    Self::Top => "top",
+                    Self::Overlay => "overlay",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Layer> for u32 {
             fn from(value: Layer) -> Self {
                 value as u32
             }
         }
+        impl From<Layer> for i32 {
+            fn from(value: Layer) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Layer {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2013,65 +4144,157 @@ pub mod wlr_layer_shell_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Layer {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Layer {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_layer_shell_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_layer_surface",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no?ous",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_layer_surface_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "surface",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_surface"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "layer",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("layer"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "namespace",
+                        kind: waynest::ArgKind::String,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_layer_shell_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_layer_shell_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_layer_shell_v1` interface",
+            label = "`ZwlrLayerShellV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrLayerShellV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_layer_shell_v1";
             const VERSION: u32 = 5u32;
-            #[doc = "Create a layer surface for an existing surface. This assigns the role of"]
-            #[doc = "layer_surface, or raises a protocol error if another role is already"]
-            #[doc = "assigned."]
-            #[doc = ""]
-            #[doc = "Creating a layer surface from a wl_surface which has a buffer attached"]
-            #[doc = "or committed is a client error, and any attempts by a client to attach"]
-            #[doc = "or manipulate a buffer prior to the first layer_surface.configure call"]
-            #[doc = "must also be treated as errors."]
-            #[doc = ""]
-            #[doc = "After creating a layer_surface object and setting it up, the client"]
-            #[doc = "must perform an initial commit without any buffer attached."]
-            #[doc = "The compositor will reply with a layer_surface.configure event."]
-            #[doc = "The client must acknowledge it and is then allowed to attach a buffer"]
-            #[doc = "to map the surface."]
-            #[doc = ""]
-            #[doc = "You may pass NULL for output to allow the compositor to decide which"]
-            #[doc = "output to use. Generally this will be the one that the user most"]
-            #[doc = "recently interacted with."]
-            #[doc = ""]
-            #[doc = "Clients can specify a namespace that defines the purpose of the layer"]
-            #[doc = "surface."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_layer_surface message was introduced in
+            const GET_LAYER_SURFACE_SINCE: u32 = 1u32;
+            ///The interface version the get_layer_surface message was deprecated in, if any
+            const GET_LAYER_SURFACE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a layer surface for an existing surface. This assigns the role of
+            ///layer_surface, or raises a protocol error if another role is already
+            ///assigned.
+            ///
+            ///Creating a layer surface from a wl_surface which has a buffer attached
+            ///or committed is a client error, and any attempts by a client to attach
+            ///or manipulate a buffer prior to the first layer_surface.configure call
+            ///must also be treated as errors.
+            ///
+            ///After creating a layer_surface object and setting it up, the client
+            ///must perform an initial commit without any buffer attached.
+            ///The compositor will reply with a layer_surface.configure event.
+            ///The client must acknowledge it and is then allowed to attach a buffer
+            ///to map the surface.
+            ///
+            ///You may pass NULL for output to allow the compositor to decide which
+            ///output to use. Generally this will be the one that the user most
+            ///recently interacted with.
+            ///
+            ///Clients can specify a namespace that defines the purpose of the layer
+            ///surface.
             fn get_layer_surface(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 surface: waynest::ObjectId,
                 output: Option<waynest::ObjectId>,
                 layer: Layer,
                 namespace: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request indicates that the client will not use the layer_shell"]
-            #[doc = "object any more. Objects that have been created through this instance"]
-            #[doc = "are not affected."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 3u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request indicates that the client will not use the layer_shell
+            ///object any more. Objects that have been created through this instance
+            ///are not affected.
+            ///
+            ///Available since version 3
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2083,79 +4306,154 @@ pub mod wlr_layer_shell_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let output = message.object()?;
-                            let layer = message.uint()?;
+                            let layer: Layer = message.uint()?.try_into()?;
                             let namespace = message
                                 .string()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_shell_v1#{}.get_layer_surface({}, {}, {}, {}, \"{}\")",
-                                sender_id,
-                                id,
-                                surface,
-                                output
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                layer,
-                                namespace
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_shell_v1", object_id = % sender_id, opcode =
+                                0u16, message = "get_layer_surface", direction = "in",
+                                "args.id" = % id, "args.surface" = % surface, "args.output"
+                                = % output.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.layer" = % layer, "args.namespace" = %
+                                namespace,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_shell_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "get_layer_surface", direction = "in",
+                                "args.id" : % = id, "args.surface" : % = surface,
+                                "args.output" : % = output.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string()), "args.layer" : % =
+                                layer, "args.namespace" : % = namespace;
+                                "zwlr_layer_shell_v1.get_layer_surface"
                             );
                             self.get_layer_surface(
-                                connection,
-                                sender_id,
-                                id,
-                                surface,
-                                output,
-                                layer.try_into()?,
-                                namespace,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    surface,
+                                    output,
+                                    layer,
+                                    namespace,
+                                )
+                                .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_layer_shell_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_shell_v1", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_shell_v1", object_id : % = sender_id, opcode =
+                                1u16, message = "destroy", direction = "in";
+                                "zwlr_layer_shell_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "An interface that may be implemented by a wl_surface, for surfaces that"]
-    #[doc = "are designed to be rendered as a layer of a stacked desktop-like"]
-    #[doc = "environment."]
-    #[doc = ""]
-    #[doc = "Layer surface state (layer, size, anchor, exclusive zone,"]
-    #[doc = "margin, interactivity) is double-buffered, and will be applied at the"]
-    #[doc = "time wl_surface.commit of the corresponding wl_surface is called."]
-    #[doc = ""]
-    #[doc = "Attaching a null buffer to a layer surface unmaps it."]
-    #[doc = ""]
-    #[doc = "Unmapping a layer_surface means that the surface cannot be shown by the"]
-    #[doc = "compositor until it is explicitly mapped again. The layer_surface"]
-    #[doc = "returns to the state it had right after layer_shell.get_layer_surface."]
-    #[doc = "The client can re-map the surface by performing a commit without any"]
-    #[doc = "buffer attached, waiting for a configure event and handling it as usual."]
+    ///An interface that may be implemented by a wl_surface, for surfaces that
+    ///are designed to be rendered as a layer of a stacked desktop-like
+    ///environment.
+    ///
+    ///Layer surface state (layer, size, anchor, exclusive zone,
+    ///margin, interactivity) is double-buffered, and will be applied at the
+    ///time wl_surface.commit of the corresponding wl_surface is called.
+    ///
+    ///Attaching a null buffer to a layer surface unmaps it.
+    ///
+    ///Unmapping a layer_surface means that the surface cannot be shown by the
+    ///compositor until it is explicitly mapped again. The layer_surface
+    ///returns to the state it had right after layer_shell.get_layer_surface.
+    ///The client can re-map the surface by performing a commit without any
+    ///buffer attached, waiting for a configure event and handling it as usual.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_layer_surface_v1 {
-        #[doc = "Types of keyboard interaction possible for layer shell surfaces. The"]
-        #[doc = "rationale for this is twofold: (1) some applications are not interested"]
-        #[doc = "in keyboard events and not allowing them to be focused can improve the"]
-        #[doc = "desktop experience; (2) some applications will want to take exclusive"]
-        #[doc = "keyboard focus."]
+        ///Types of keyboard interaction possible for layer shell surfaces. The
+        ///rationale for this is twofold: (1) some applications are not interested
+        ///in keyboard events and not allowing them to be focused can improve the
+        ///desktop experience; (2) some applications will want to take exclusive
+        ///keyboard focus.
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum KeyboardInteractivity {
             None = 0u32,
             Exclusive = 1u32,
+            ///
+            ///Available since version 4
             OnDemand = 2u32,
         }
-        impl From<KeyboardInteractivity> for u32 {
-            fn from(value: KeyboardInteractivity) -> Self {
-                value as u32
+        #[allow(deprecated)]
+        impl KeyboardInteractivity {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::None => 1u32,
+                    Self::Exclusive => 1u32,
+                    Self::OnDemand => 4u32,
+                }
             }
-        }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::None => None,
+                    Self::Exclusive => None,
+                    Self::OnDemand => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("none", Self::None),
+                ("exclusive", Self::Exclusive),
+                ("on_demand", Self::OnDemand),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::None => "none",
+                    Self::Exclusive => "exclusive",
+                    Self::OnDemand => "on_demand",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
+        impl From<KeyboardInteractivity> for u32 {
+            fn from(value: KeyboardInteractivity) -> Self {
+                value as u32
+            }
+        }
+        impl From<KeyboardInteractivity> for i32 {
+            fn from(value: KeyboardInteractivity) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for KeyboardInteractivity {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2167,31 +4465,97 @@ pub mod wlr_layer_shell_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for KeyboardInteractivity {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for KeyboardInteractivity {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "provided surface state is invalid"]
+            ///provided surface state is invalid
             InvalidSurfaceState = 0u32,
-            #[doc = "size is invalid"]
+            ///size is invalid
             InvalidSize = 1u32,
-            #[doc = "anchor bitfield is invalid"]
+            ///anchor bitfield is invalid
             InvalidAnchor = 2u32,
-            #[doc = "keyboard interactivity is invalid"]
+            ///keyboard interactivity is invalid
             InvalidKeyboardInteractivity = 3u32,
-            #[doc = "exclusive edge is invalid given the surface anchors"]
+            ///exclusive edge is invalid given the surface anchors
             InvalidExclusiveEdge = 4u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidSurfaceState => 1u32,
+                    Self::InvalidSize => 1u32,
+                    Self::InvalidAnchor => 1u32,
+                    Self::InvalidKeyboardInteractivity => 1u32,
+                    Self::InvalidExclusiveEdge => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidSurfaceState => None,
+                    Self::InvalidSize => None,
+                    Self::InvalidAnchor => None,
+                    Self::InvalidKeyboardInteractivity => None,
+                    Self::InvalidExclusiveEdge => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_surface_state", Self::InvalidSurfaceState),
+                ("invalid_size", Self::InvalidSize),
+                ("invalid_anchor", Self::InvalidAnchor),
+                ("invalid_keyboard_interactivity", Self::InvalidKeyboardInteractivity),
+                ("invalid_exclusive_edge", Self::InvalidExclusiveEdge),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidSurfaceState => "invalid_surface_state",
+                    Self::InvalidSize => "invalid_size",
+                    Self::InvalidAnchor => "invalid_anchor",
+                    Self::InvalidKeyboardInteractivity => {
+                        "invalid_keyboard_interactivity"
+                    }
+                    Self::InvalidExclusiveEdge => "invalid_exclusive_edge",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2205,12 +4569,45 @@ pub mod wlr_layer_shell_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        bitflags::bitflags! {
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub
+            struct Anchor : u32 { #[doc = "the top edge of the anchor rectangle"] const
+            Top = 1u32; #[doc = "the bottom edge of the anchor rectangle"] const Bottom =
+            2u32; #[doc = "the left edge of the anchor rectangle"] const Left = 4u32;
+            #[doc = "the right edge of the anchor rectangle"] const Right = 8u32; }
+        }
+        #[allow(deprecated)]
+        impl Anchor {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("top", Self::Top),
+                ("bottom", Self::Bottom),
+                ("left", Self::Left),
+                ("right", Self::Right),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
             }
         }
-        bitflags::bitflags! { # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Anchor : u32 { # [doc = "the top edge of the anchor rectangle"] const Top = 1u32 ; # [doc = "the bottom edge of the anchor rectangle"] const Bottom = 2u32 ; # [doc = "the left edge of the anchor rectangle"] const Left = 4u32 ; # [doc = "the right edge of the anchor rectangle"] const Right = 8u32 ; } }
         impl From<Anchor> for u32 {
             fn from(value: Anchor) -> Self {
                 value.bits()
@@ -2224,263 +4621,579 @@ pub mod wlr_layer_shell_unstable_v1 {
         }
         impl std::fmt::Display for Anchor {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_size",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_anchor",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "anchor",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_exclusive_zone",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "zone",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_margin",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "top",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "right",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "bottom",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "left",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_keyboard_interactivity",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "keyboard_interactivity",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("keyboard_interactivity"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "get_popup",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "popup",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("xdg_popup"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "ack_configure",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "set_layer",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "layer",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("zwlr_layer_shell_v1.layer"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_exclusive_edge",
+                since: 5u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "5u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "edge",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("anchor"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwlr_layer_surface_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the configure message was introduced in
+            pub const CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the configure message was deprecated in, if any
+            pub const CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The configure event asks the client to resize its surface.
+            ///
+            ///Clients should arrange their surface for the new states, and then send
+            ///an ack_configure request with the serial sent in this configure event at
+            ///some point before committing the new surface.
+            ///
+            ///The client is free to dismiss all but the last configure event it
+            ///received.
+            ///
+            ///The width and height arguments specify the size of the window in
+            ///surface-local coordinates.
+            ///
+            ///The size is a hint, in the sense that the client is free to ignore it if
+            ///it doesn't resize, pick a smaller size (to satisfy aspect ratio or
+            ///resize in steps of NxM pixels). If the client picks a smaller size and
+            ///is anchored to two opposite anchors (e.g. 'top' and 'bottom'), the
+            ///surface will be centered on this axis.
+            ///
+            ///If the width or height arguments are zero, it means the client should
+            ///decide its own window dimension.
+            pub fn configure<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+                width: u32,
+                height: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_layer_surface_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "configure", direction = "out", "args.serial" = % serial,
+                    "args.width" = % width, "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_layer_surface_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "configure", direction = "out", "args.serial" : % = serial,
+                    "args.width" : % = width, "args.height" : % = height;
+                    "zwlr_layer_surface_v1.configure"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(serial)
+                    .put_uint(width)
+                    .put_uint(height)
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the closed message was introduced in
+            pub const CLOSED_SINCE: u32 = 1u32;
+            ///The interface version the closed message was deprecated in, if any
+            pub const CLOSED_DEPRECATED_SINCE: Option<u32> = None;
+            ///The closed event is sent by the compositor when the surface will no
+            ///longer be shown. The output may have been destroyed or the user may
+            ///have asked for it to be removed. Further changes to the surface will be
+            ///ignored. The client should destroy the resource after receiving this
+            ///event, and create a new surface if they so choose.
+            pub fn closed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_layer_surface_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "closed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_layer_surface_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "closed", direction = "out"; "zwlr_layer_surface_v1.closed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwlr_layer_surface_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwlr_layer_surface_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_layer_surface_v1` interface",
+            label = "`ZwlrLayerSurfaceV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrLayerSurfaceV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_layer_surface_v1";
             const VERSION: u32 = 5u32;
-            #[doc = "Sets the size of the surface in surface-local coordinates. The"]
-            #[doc = "compositor will display the surface centered with respect to its"]
-            #[doc = "anchors."]
-            #[doc = ""]
-            #[doc = "If you pass 0 for either value, the compositor will assign it and"]
-            #[doc = "inform you of the assignment in the configure event. You must set your"]
-            #[doc = "anchor to opposite edges in the dimensions you omit; not doing so is a"]
-            #[doc = "protocol error. Both values are 0 by default."]
-            #[doc = ""]
-            #[doc = "Size is double-buffered, see wl_surface.commit."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_size message was introduced in
+            const SET_SIZE_SINCE: u32 = 1u32;
+            ///The interface version the set_size message was deprecated in, if any
+            const SET_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sets the size of the surface in surface-local coordinates. The
+            ///compositor will display the surface centered with respect to its
+            ///anchors.
+            ///
+            ///If you pass 0 for either value, the compositor will assign it and
+            ///inform you of the assignment in the configure event. You must set your
+            ///anchor to opposite edges in the dimensions you omit; not doing so is a
+            ///protocol error. Both values are 0 by default.
+            ///
+            ///Size is double-buffered, see wl_surface.commit.
             fn set_size(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: u32,
                 height: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the compositor anchor the surface to the specified edges"]
-            #[doc = "and corners. If two orthogonal edges are specified (e.g. 'top' and"]
-            #[doc = "'left'), then the anchor point will be the intersection of the edges"]
-            #[doc = "(e.g. the top left corner of the output); otherwise the anchor point"]
-            #[doc = "will be centered on that edge, or in the center if none is specified."]
-            #[doc = ""]
-            #[doc = "Anchor is double-buffered, see wl_surface.commit."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_anchor message was introduced in
+            const SET_ANCHOR_SINCE: u32 = 1u32;
+            ///The interface version the set_anchor message was deprecated in, if any
+            const SET_ANCHOR_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the compositor anchor the surface to the specified edges
+            ///and corners. If two orthogonal edges are specified (e.g. 'top' and
+            ///'left'), then the anchor point will be the intersection of the edges
+            ///(e.g. the top left corner of the output); otherwise the anchor point
+            ///will be centered on that edge, or in the center if none is specified.
+            ///
+            ///Anchor is double-buffered, see wl_surface.commit.
             fn set_anchor(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 anchor: Anchor,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the compositor avoids occluding an area with other"]
-            #[doc = "surfaces. The compositor's use of this information is"]
-            #[doc = "implementation-dependent - do not assume that this region will not"]
-            #[doc = "actually be occluded."]
-            #[doc = ""]
-            #[doc = "A positive value is only meaningful if the surface is anchored to one"]
-            #[doc = "edge or an edge and both perpendicular edges. If the surface is not"]
-            #[doc = "anchored, anchored to only two perpendicular edges (a corner), anchored"]
-            #[doc = "to only two parallel edges or anchored to all edges, a positive value"]
-            #[doc = "will be treated the same as zero."]
-            #[doc = ""]
-            #[doc = "A positive zone is the distance from the edge in surface-local"]
-            #[doc = "coordinates to consider exclusive."]
-            #[doc = ""]
-            #[doc = "Surfaces that do not wish to have an exclusive zone may instead specify"]
-            #[doc = "how they should interact with surfaces that do. If set to zero, the"]
-            #[doc = "surface indicates that it would like to be moved to avoid occluding"]
-            #[doc = "surfaces with a positive exclusive zone. If set to -1, the surface"]
-            #[doc = "indicates that it would not like to be moved to accommodate for other"]
-            #[doc = "surfaces, and the compositor should extend it all the way to the edges"]
-            #[doc = "it is anchored to."]
-            #[doc = ""]
-            #[doc = "For example, a panel might set its exclusive zone to 10, so that"]
-            #[doc = "maximized shell surfaces are not shown on top of it. A notification"]
-            #[doc = "might set its exclusive zone to 0, so that it is moved to avoid"]
-            #[doc = "occluding the panel, but shell surfaces are shown underneath it. A"]
-            #[doc = "wallpaper or lock screen might set their exclusive zone to -1, so that"]
-            #[doc = "they stretch below or over the panel."]
-            #[doc = ""]
-            #[doc = "The default value is 0."]
-            #[doc = ""]
-            #[doc = "Exclusive zone is double-buffered, see wl_surface.commit."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_exclusive_zone message was introduced in
+            const SET_EXCLUSIVE_ZONE_SINCE: u32 = 1u32;
+            ///The interface version the set_exclusive_zone message was deprecated in, if any
+            const SET_EXCLUSIVE_ZONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the compositor avoids occluding an area with other
+            ///surfaces. The compositor's use of this information is
+            ///implementation-dependent - do not assume that this region will not
+            ///actually be occluded.
+            ///
+            ///A positive value is only meaningful if the surface is anchored to one
+            ///edge or an edge and both perpendicular edges. If the surface is not
+            ///anchored, anchored to only two perpendicular edges (a corner), anchored
+            ///to only two parallel edges or anchored to all edges, a positive value
+            ///will be treated the same as zero.
+            ///
+            ///A positive zone is the distance from the edge in surface-local
+            ///coordinates to consider exclusive.
+            ///
+            ///Surfaces that do not wish to have an exclusive zone may instead specify
+            ///how they should interact with surfaces that do. If set to zero, the
+            ///surface indicates that it would like to be moved to avoid occluding
+            ///surfaces with a positive exclusive zone. If set to -1, the surface
+            ///indicates that it would not like to be moved to accommodate for other
+            ///surfaces, and the compositor should extend it all the way to the edges
+            ///it is anchored to.
+            ///
+            ///For example, a panel might set its exclusive zone to 10, so that
+            ///maximized shell surfaces are not shown on top of it. A notification
+            ///might set its exclusive zone to 0, so that it is moved to avoid
+            ///occluding the panel, but shell surfaces are shown underneath it. A
+            ///wallpaper or lock screen might set their exclusive zone to -1, so that
+            ///they stretch below or over the panel.
+            ///
+            ///The default value is 0.
+            ///
+            ///Exclusive zone is double-buffered, see wl_surface.commit.
             fn set_exclusive_zone(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 zone: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests that the surface be placed some distance away from the anchor"]
-            #[doc = "point on the output, in surface-local coordinates. Setting this value"]
-            #[doc = "for edges you are not anchored to has no effect."]
-            #[doc = ""]
-            #[doc = "The exclusive zone includes the margin."]
-            #[doc = ""]
-            #[doc = "Margin is double-buffered, see wl_surface.commit."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_margin message was introduced in
+            const SET_MARGIN_SINCE: u32 = 1u32;
+            ///The interface version the set_margin message was deprecated in, if any
+            const SET_MARGIN_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests that the surface be placed some distance away from the anchor
+            ///point on the output, in surface-local coordinates. Setting this value
+            ///for edges you are not anchored to has no effect.
+            ///
+            ///The exclusive zone includes the margin.
+            ///
+            ///Margin is double-buffered, see wl_surface.commit.
             fn set_margin(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 top: i32,
                 right: i32,
                 bottom: i32,
                 left: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Set how keyboard events are delivered to this surface. By default,"]
-            #[doc = "layer shell surfaces do not receive keyboard events; this request can"]
-            #[doc = "be used to change this."]
-            #[doc = ""]
-            #[doc = "This setting is inherited by child surfaces set by the get_popup"]
-            #[doc = "request."]
-            #[doc = ""]
-            #[doc = "Layer surfaces receive pointer, touch, and tablet events normally. If"]
-            #[doc = "you do not want to receive them, set the input region on your surface"]
-            #[doc = "to an empty region."]
-            #[doc = ""]
-            #[doc = "Keyboard interactivity is double-buffered, see wl_surface.commit."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_keyboard_interactivity message was introduced in
+            const SET_KEYBOARD_INTERACTIVITY_SINCE: u32 = 1u32;
+            ///The interface version the set_keyboard_interactivity message was deprecated in, if any
+            const SET_KEYBOARD_INTERACTIVITY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set how keyboard events are delivered to this surface. By default,
+            ///layer shell surfaces do not receive keyboard events; this request can
+            ///be used to change this.
+            ///
+            ///This setting is inherited by child surfaces set by the get_popup
+            ///request.
+            ///
+            ///Layer surfaces receive pointer, touch, and tablet events normally. If
+            ///you do not want to receive them, set the input region on your surface
+            ///to an empty region.
+            ///
+            ///Keyboard interactivity is double-buffered, see wl_surface.commit.
             fn set_keyboard_interactivity(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 keyboard_interactivity: KeyboardInteractivity,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This assigns an xdg_popup's parent to this layer_surface.  This popup"]
-            #[doc = "should have been created via xdg_surface::get_popup with the parent set"]
-            #[doc = "to NULL, and this request must be invoked before committing the popup's"]
-            #[doc = "initial state."]
-            #[doc = ""]
-            #[doc = "See the documentation of xdg_popup for more details about what an"]
-            #[doc = "xdg_popup is and how it is used."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the get_popup message was introduced in
+            const GET_POPUP_SINCE: u32 = 1u32;
+            ///The interface version the get_popup message was deprecated in, if any
+            const GET_POPUP_DEPRECATED_SINCE: Option<u32> = None;
+            ///This assigns an xdg_popup's parent to this layer_surface.  This popup
+            ///should have been created via xdg_surface::get_popup with the parent set
+            ///to NULL, and this request must be invoked before committing the popup's
+            ///initial state.
+            ///
+            ///See the documentation of xdg_popup for more details about what an
+            ///xdg_popup is and how it is used.
             fn get_popup(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 popup: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "When a configure event is received, if a client commits the"]
-            #[doc = "surface in response to the configure event, then the client"]
-            #[doc = "must make an ack_configure request sometime before the commit"]
-            #[doc = "request, passing along the serial of the configure event."]
-            #[doc = ""]
-            #[doc = "If the client receives multiple configure events before it"]
-            #[doc = "can respond to one, it only has to ack the last configure event."]
-            #[doc = ""]
-            #[doc = "A client is not required to commit immediately after sending"]
-            #[doc = "an ack_configure request - it may even ack_configure several times"]
-            #[doc = "before its next surface commit."]
-            #[doc = ""]
-            #[doc = "A client may send multiple ack_configure requests before committing, but"]
-            #[doc = "only the last request sent before a commit indicates which configure"]
-            #[doc = "event the client really is responding to."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the ack_configure message was introduced in
+            const ACK_CONFIGURE_SINCE: u32 = 1u32;
+            ///The interface version the ack_configure message was deprecated in, if any
+            const ACK_CONFIGURE_DEPRECATED_SINCE: Option<u32> = None;
+            ///When a configure event is received, if a client commits the
+            ///surface in response to the configure event, then the client
+            ///must make an ack_configure request sometime before the commit
+            ///request, passing along the serial of the configure event.
+            ///
+            ///If the client receives multiple configure events before it
+            ///can respond to one, it only has to ack the last configure event.
+            ///
+            ///A client is not required to commit immediately after sending
+            ///an ack_configure request - it may even ack_configure several times
+            ///before its next surface commit.
+            ///
+            ///A client may send multiple ack_configure requests before committing, but
+            ///only the last request sent before a commit indicates which configure
+            ///event the client really is responding to.
             fn ack_configure(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request destroys the layer surface."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request destroys the layer surface.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Change the layer that the surface is rendered on."]
-            #[doc = ""]
-            #[doc = "Layer is double-buffered, see wl_surface.commit."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_layer message was introduced in
+            const SET_LAYER_SINCE: u32 = 2u32;
+            ///The interface version the set_layer message was deprecated in, if any
+            const SET_LAYER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Change the layer that the surface is rendered on.
+            ///
+            ///Layer is double-buffered, see wl_surface.commit.
+            ///
+            ///Available since version 2
             fn set_layer(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                layer : super :: super :: super :: wlr :: wlr_layer_shell_unstable_v1 :: zwlr_layer_shell_v1 :: Layer,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Requests an edge for the exclusive zone to apply. The exclusive"]
-            #[doc = "edge will be automatically deduced from anchor points when possible,"]
-            #[doc = "but when the surface is anchored to a corner, it will be necessary"]
-            #[doc = "to set it explicitly to disambiguate, as it is not possible to deduce"]
-            #[doc = "which one of the two corner edges should be used."]
-            #[doc = ""]
-            #[doc = "The edge must be one the surface is anchored to, otherwise the"]
-            #[doc = "invalid_exclusive_edge protocol error will be raised."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                layer: super::zwlr_layer_shell_v1::Layer,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_exclusive_edge message was introduced in
+            const SET_EXCLUSIVE_EDGE_SINCE: u32 = 5u32;
+            ///The interface version the set_exclusive_edge message was deprecated in, if any
+            const SET_EXCLUSIVE_EDGE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Requests an edge for the exclusive zone to apply. The exclusive
+            ///edge will be automatically deduced from anchor points when possible,
+            ///but when the surface is anchored to a corner, it will be necessary
+            ///to set it explicitly to disambiguate, as it is not possible to deduce
+            ///which one of the two corner edges should be used.
+            ///
+            ///The edge must be one the surface is anchored to, otherwise the
+            ///invalid_exclusive_edge protocol error will be raised.
+            ///
+            ///Available since version 5
             fn set_exclusive_edge(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 edge: Anchor,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The configure event asks the client to resize its surface."]
-            #[doc = ""]
-            #[doc = "Clients should arrange their surface for the new states, and then send"]
-            #[doc = "an ack_configure request with the serial sent in this configure event at"]
-            #[doc = "some point before committing the new surface."]
-            #[doc = ""]
-            #[doc = "The client is free to dismiss all but the last configure event it"]
-            #[doc = "received."]
-            #[doc = ""]
-            #[doc = "The width and height arguments specify the size of the window in"]
-            #[doc = "surface-local coordinates."]
-            #[doc = ""]
-            #[doc = "The size is a hint, in the sense that the client is free to ignore it if"]
-            #[doc = "it doesn't resize, pick a smaller size (to satisfy aspect ratio or"]
-            #[doc = "resize in steps of NxM pixels). If the client picks a smaller size and"]
-            #[doc = "is anchored to two opposite anchors (e.g. 'top' and 'bottom'), the"]
-            #[doc = "surface will be centered on this axis."]
-            #[doc = ""]
-            #[doc = "If the width or height arguments are zero, it means the client should"]
-            #[doc = "decide its own window dimension."]
-            fn configure(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-                width: u32,
-                height: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_layer_surface_v1#{}.configure({}, {}, {})",
-                        sender_id,
-                        serial,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(serial)
-                        .put_uint(width)
-                        .put_uint(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "The closed event is sent by the compositor when the surface will no"]
-            #[doc = "longer be shown. The output may have been destroyed or the user may"]
-            #[doc = "have asked for it to be removed. Further changes to the surface will be"]
-            #[doc = "ignored. The client should destroy the resource after receiving this"]
-            #[doc = "event, and create a new surface if they so choose."]
-            fn closed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_layer_surface_v1#{}.closed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2488,34 +5201,61 @@ pub mod wlr_layer_shell_unstable_v1 {
                             let width = message.uint()?;
                             let height = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_size({}, {})",
-                                sender_id,
-                                width,
-                                height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                0u16, message = "set_size", direction = "in", "args.width" =
+                                % width, "args.height" = % height,
                             );
-                            self.set_size(connection, sender_id, width, height).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "set_size", direction = "in", "args.width" :
+                                % = width, "args.height" : % = height;
+                                "zwlr_layer_surface_v1.set_size"
+                            );
+                            self.set_size(connection, state, sender_id, width, height)
+                                .await
                         }
                         1u16 => {
-                            let anchor = message.uint()?;
+                            let anchor: Anchor = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_anchor({})",
-                                sender_id,
-                                anchor
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                1u16, message = "set_anchor", direction = "in",
+                                "args.anchor" = % anchor,
                             );
-                            self.set_anchor(connection, sender_id, anchor.try_into()?)
-                                .await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                1u16, message = "set_anchor", direction = "in",
+                                "args.anchor" : % = anchor;
+                                "zwlr_layer_surface_v1.set_anchor"
+                            );
+                            self.set_anchor(connection, state, sender_id, anchor).await
                         }
                         2u16 => {
                             let zone = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_exclusive_zone({})",
-                                sender_id,
-                                zone
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                2u16, message = "set_exclusive_zone", direction = "in",
+                                "args.zone" = % zone,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                2u16, message = "set_exclusive_zone", direction = "in",
+                                "args.zone" : % = zone;
+                                "zwlr_layer_surface_v1.set_exclusive_zone"
                             );
-                            self.set_exclusive_zone(connection, sender_id, zone).await
+                            self.set_exclusive_zone(connection, state, sender_id, zone)
+                                .await
                         }
                         3u16 => {
                             let top = message.int()?;
@@ -2523,240 +5263,415 @@ pub mod wlr_layer_shell_unstable_v1 {
                             let bottom = message.int()?;
                             let left = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_margin({}, {}, {}, {})",
-                                sender_id,
-                                top,
-                                right,
-                                bottom,
-                                left
-                            );
-                            self.set_margin(connection, sender_id, top, right, bottom, left)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                3u16, message = "set_margin", direction = "in", "args.top" =
+                                % top, "args.right" = % right, "args.bottom" = % bottom,
+                                "args.left" = % left,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                3u16, message = "set_margin", direction = "in", "args.top" :
+                                % = top, "args.right" : % = right, "args.bottom" : % =
+                                bottom, "args.left" : % = left;
+                                "zwlr_layer_surface_v1.set_margin"
+                            );
+                            self.set_margin(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    top,
+                                    right,
+                                    bottom,
+                                    left,
+                                )
                                 .await
                         }
                         4u16 => {
-                            let keyboard_interactivity = message.uint()?;
+                            let keyboard_interactivity: KeyboardInteractivity = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_keyboard_interactivity({})",
-                                sender_id,
-                                keyboard_interactivity
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                4u16, message = "set_keyboard_interactivity", direction =
+                                "in", "args.keyboard_interactivity" = %
+                                keyboard_interactivity,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                4u16, message = "set_keyboard_interactivity", direction =
+                                "in", "args.keyboard_interactivity" : % =
+                                keyboard_interactivity;
+                                "zwlr_layer_surface_v1.set_keyboard_interactivity"
                             );
                             self.set_keyboard_interactivity(
-                                connection,
-                                sender_id,
-                                keyboard_interactivity.try_into()?,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    keyboard_interactivity,
+                                )
+                                .await
                         }
                         5u16 => {
                             let popup = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.get_popup({})",
-                                sender_id,
-                                popup
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                5u16, message = "get_popup", direction = "in", "args.popup"
+                                = % popup,
                             );
-                            self.get_popup(connection, sender_id, popup).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                5u16, message = "get_popup", direction = "in", "args.popup"
+                                : % = popup; "zwlr_layer_surface_v1.get_popup"
+                            );
+                            self.get_popup(connection, state, sender_id, popup).await
                         }
                         6u16 => {
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.ack_configure({})",
-                                sender_id,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                6u16, message = "ack_configure", direction = "in",
+                                "args.serial" = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                6u16, message = "ack_configure", direction = "in",
+                                "args.serial" : % = serial;
+                                "zwlr_layer_surface_v1.ack_configure"
                             );
-                            self.ack_configure(connection, sender_id, serial).await
+                            self.ack_configure(connection, state, sender_id, serial)
+                                .await
                         }
                         7u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_layer_surface_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                7u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                7u16, message = "destroy", direction = "in";
+                                "zwlr_layer_surface_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         8u16 => {
-                            let layer = message.uint()?;
+                            let layer: super::zwlr_layer_shell_v1::Layer = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_layer({})",
-                                sender_id,
-                                layer
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                8u16, message = "set_layer", direction = "in", "args.layer"
+                                = % layer,
                             );
-                            self.set_layer(connection, sender_id, layer.try_into()?)
-                                .await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                8u16, message = "set_layer", direction = "in", "args.layer"
+                                : % = layer; "zwlr_layer_surface_v1.set_layer"
+                            );
+                            self.set_layer(connection, state, sender_id, layer).await
                         }
                         9u16 => {
-                            let edge = message.uint()?;
+                            let edge: Anchor = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_layer_surface_v1#{}.set_exclusive_edge({})",
-                                sender_id,
-                                edge
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_layer_surface_v1", object_id = % sender_id, opcode =
+                                9u16, message = "set_exclusive_edge", direction = "in",
+                                "args.edge" = % edge,
                             );
-                            self.set_exclusive_edge(connection, sender_id, edge.try_into()?)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_layer_surface_v1", object_id : % = sender_id, opcode =
+                                9u16, message = "set_exclusive_edge", direction = "in",
+                                "args.edge" : % = edge;
+                                "zwlr_layer_surface_v1.set_exclusive_edge"
+                            );
+                            self.set_exclusive_edge(connection, state, sender_id, edge)
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
 }
-#[doc = "This protocol exposes interfaces to obtain and modify output device"]
-#[doc = "configuration."]
-#[doc = ""]
-#[doc = "Warning! The protocol described in this file is experimental and"]
-#[doc = "backward incompatible changes may be made. Backward compatible changes"]
-#[doc = "may be added together with the corresponding interface version bump."]
-#[doc = "Backward incompatible changes are done by bumping the version number in"]
-#[doc = "the protocol and interface names and resetting the interface version."]
-#[doc = "Once the protocol is to be declared stable, the 'z' prefix and the"]
-#[doc = "version number in the protocol and interface names are removed and the"]
-#[doc = "interface version number is reset."]
-#[allow(clippy::module_inception)]
+///This protocol exposes interfaces to obtain and modify output device
+///configuration.
+///
+///Warning! The protocol described in this file is experimental and
+///backward incompatible changes may be made. Backward compatible changes
+///may be added together with the corresponding interface version bump.
+///Backward incompatible changes are done by bumping the version number in
+///the protocol and interface names and resetting the interface version.
+///Once the protocol is to be declared stable, the 'z' prefix and the
+///version number in the protocol and interface names are removed and the
+///interface version number is reset.
+#[cfg(feature = "wlr-output-management-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-output-management-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_output_management_unstable_v1 {
-    #[doc = "This interface is a manager that allows reading and writing the current"]
-    #[doc = "output device configuration."]
-    #[doc = ""]
-    #[doc = "Output devices that display pixels (e.g. a physical monitor or a virtual"]
-    #[doc = "output in a window) are represented as heads. Heads cannot be created nor"]
-    #[doc = "destroyed by the client, but they can be enabled or disabled and their"]
-    #[doc = "properties can be changed. Each head may have one or more available modes."]
-    #[doc = ""]
-    #[doc = "Whenever a head appears (e.g. a monitor is plugged in), it will be"]
-    #[doc = "advertised via the head event. Immediately after the output manager is"]
-    #[doc = "bound, all current heads are advertised."]
-    #[doc = ""]
-    #[doc = "Whenever a head's properties change, the relevant wlr_output_head events"]
-    #[doc = "will be sent. Not all head properties will be sent: only properties that"]
-    #[doc = "have changed need to."]
-    #[doc = ""]
-    #[doc = "Whenever a head disappears (e.g. a monitor is unplugged), a"]
-    #[doc = "wlr_output_head.finished event will be sent."]
-    #[doc = ""]
-    #[doc = "After one or more heads appear, change or disappear, the done event will"]
-    #[doc = "be sent. It carries a serial which can be used in a create_configuration"]
-    #[doc = "request to update heads properties."]
-    #[doc = ""]
-    #[doc = "The information obtained from this protocol should only be used for output"]
-    #[doc = "configuration purposes. This protocol is not designed to be a generic"]
-    #[doc = "output property advertisement protocol for regular clients. Instead,"]
-    #[doc = "protocols such as xdg-output should be used."]
+    ///This interface is a manager that allows reading and writing the current
+    ///output device configuration.
+    ///
+    ///Output devices that display pixels (e.g. a physical monitor or a virtual
+    ///output in a window) are represented as heads. Heads cannot be created nor
+    ///destroyed by the client, but they can be enabled or disabled and their
+    ///properties can be changed. Each head may have one or more available modes.
+    ///
+    ///Whenever a head appears (e.g. a monitor is plugged in), it will be
+    ///advertised via the head event. Immediately after the output manager is
+    ///bound, all current heads are advertised.
+    ///
+    ///Whenever a head's properties change, the relevant wlr_output_head events
+    ///will be sent. Not all head properties will be sent: only properties that
+    ///have changed need to.
+    ///
+    ///Whenever a head disappears (e.g. a monitor is unplugged), a
+    ///wlr_output_head.finished event will be sent.
+    ///
+    ///After one or more heads appear, change or disappear, the done event will
+    ///be sent. It carries a serial which can be used in a create_configuration
+    ///request to update heads properties.
+    ///
+    ///The information obtained from this protocol should only be used for output
+    ///configuration purposes. This protocol is not designed to be a generic
+    ///output property advertisement protocol for regular clients. Instead,
+    ///protocols such as xdg-output should be used.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_manager_v1 {
-        #[doc = "Trait to implement the zwlr_output_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_configuration",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_configuration_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "serial",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_output_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the head message was introduced in
+            pub const HEAD_SINCE: u32 = 1u32;
+            ///The interface version the head message was deprecated in, if any
+            pub const HEAD_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event introduces a new head. This happens whenever a new head
+            ///appears (e.g. a monitor is plugged in) or after the output manager is
+            ///bound.
+            pub fn head<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                head: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_manager_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "head", direction = "out", "args.head" = % head,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_manager_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "head", direction = "out", "args.head" : % = head;
+                    "zwlr_output_manager_v1.head"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(head))
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the done message was introduced in
+            pub const DONE_SINCE: u32 = 1u32;
+            ///The interface version the done message was deprecated in, if any
+            pub const DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent after all information has been sent after binding to
+            ///the output manager object and after any subsequent changes. This applies
+            ///to child head and mode objects as well. In other words, this event is
+            ///sent whenever a head or mode is created or destroyed and whenever one of
+            ///their properties has been changed. Not all state is re-sent each time
+            ///the current configuration changes: only the actual changes are sent.
+            ///
+            ///This allows changes to the output configuration to be seen as atomic,
+            ///even if they happen via multiple events.
+            ///
+            ///A serial is sent to be used in a future create_configuration request.
+            pub fn done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                serial: u32,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_manager_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "done", direction = "out", "args.serial" = % serial,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_manager_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "done", direction = "out", "args.serial" : % = serial;
+                    "zwlr_output_manager_v1.done"
+                );
+                let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the finished message was introduced in
+            pub const FINISHED_SINCE: u32 = 1u32;
+            ///The interface version the finished message was deprecated in, if any
+            pub const FINISHED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the compositor is done sending manager events.
+            ///The compositor will destroy the object immediately after sending this
+            ///event, so it will become invalid and the client should release any
+            ///resources associated with it.
+            pub fn finished<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_manager_v1", object_id = % sender_id, opcode = 2u16,
+                    message = "finished", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_manager_v1", object_id : % = sender_id, opcode = 2u16,
+                    message = "finished", direction = "out";
+                    "zwlr_output_manager_v1.finished"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the zwlr_output_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_manager_v1` interface",
+            label = "`ZwlrOutputManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrOutputManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_output_manager_v1";
             const VERSION: u32 = 4u32;
-            #[doc = "Create a new output configuration object. This allows to update head"]
-            #[doc = "properties."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_configuration message was introduced in
+            const CREATE_CONFIGURATION_SINCE: u32 = 1u32;
+            ///The interface version the create_configuration message was deprecated in, if any
+            const CREATE_CONFIGURATION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create a new output configuration object. This allows to update head
+            ///properties.
             fn create_configuration(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Indicates the client no longer wishes to receive events for output"]
-            #[doc = "configuration changes. However the compositor may emit further events,"]
-            #[doc = "until the finished event is emitted."]
-            #[doc = ""]
-            #[doc = "The client must not send any more requests after this one."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the stop message was introduced in
+            const STOP_SINCE: u32 = 1u32;
+            ///The interface version the stop message was deprecated in, if any
+            const STOP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the client no longer wishes to receive events for output
+            ///configuration changes. However the compositor may emit further events,
+            ///until the finished event is emitted.
+            ///
+            ///The client must not send any more requests after this one.
             fn stop(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event introduces a new head. This happens whenever a new head"]
-            #[doc = "appears (e.g. a monitor is plugged in) or after the output manager is"]
-            #[doc = "bound."]
-            fn head(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                head: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_manager_v1#{}.head({})", sender_id, head);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(head))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event is sent after all information has been sent after binding to"]
-            #[doc = "the output manager object and after any subsequent changes. This applies"]
-            #[doc = "to child head and mode objects as well. In other words, this event is"]
-            #[doc = "sent whenever a head or mode is created or destroyed and whenever one of"]
-            #[doc = "their properties has been changed. Not all state is re-sent each time"]
-            #[doc = "the current configuration changes: only the actual changes are sent."]
-            #[doc = ""]
-            #[doc = "This allows changes to the output configuration to be seen as atomic,"]
-            #[doc = "even if they happen via multiple events."]
-            #[doc = ""]
-            #[doc = "A serial is sent to be used in a future create_configuration request."]
-            fn done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                serial: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_manager_v1#{}.done({})", sender_id, serial);
-                    let payload = waynest::PayloadBuilder::new().put_uint(serial).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates that the compositor is done sending manager events."]
-            #[doc = "The compositor will destroy the object immediately after sending this"]
-            #[doc = "event, so it will become invalid and the client should release any"]
-            #[doc = "resources associated with it."]
-            fn finished(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_manager_v1#{}.finished()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -2766,55 +5681,130 @@ pub mod wlr_output_management_unstable_v1 {
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             let serial = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_manager_v1#{}.create_configuration({}, {})",
-                                sender_id,
-                                id,
-                                serial
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_manager_v1", object_id = % sender_id, opcode =
+                                0u16, message = "create_configuration", direction = "in",
+                                "args.id" = % id, "args.serial" = % serial,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_manager_v1", object_id : % = sender_id, opcode
+                                = 0u16, message = "create_configuration", direction = "in",
+                                "args.id" : % = id, "args.serial" : % = serial;
+                                "zwlr_output_manager_v1.create_configuration"
                             );
-                            self.create_configuration(connection, sender_id, id, serial)
+                            self.create_configuration(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    serial,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_manager_v1#{}.stop()", sender_id,);
-                            self.stop(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_manager_v1", object_id = % sender_id, opcode =
+                                1u16, message = "stop", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_manager_v1", object_id : % = sender_id, opcode
+                                = 1u16, message = "stop", direction = "in";
+                                "zwlr_output_manager_v1.stop"
+                            );
+                            self.stop(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "A head is an output device. The difference between a wl_output object and"]
-    #[doc = "a head is that heads are advertised even if they are turned off. A head"]
-    #[doc = "object only advertises properties and cannot be used directly to change"]
-    #[doc = "them."]
-    #[doc = ""]
-    #[doc = "A head has some read-only properties: modes, name, description and"]
-    #[doc = "physical_size. These cannot be changed by clients."]
-    #[doc = ""]
-    #[doc = "Other properties can be updated via a wlr_output_configuration object."]
-    #[doc = ""]
-    #[doc = "Properties sent via this interface are applied atomically via the"]
-    #[doc = "wlr_output_manager.done event. No guarantees are made regarding the order"]
-    #[doc = "in which properties are sent."]
+    ///A head is an output device. The difference between a wl_output object and
+    ///a head is that heads are advertised even if they are turned off. A head
+    ///object only advertises properties and cannot be used directly to change
+    ///them.
+    ///
+    ///A head has some read-only properties: modes, name, description and
+    ///physical_size. These cannot be changed by clients.
+    ///
+    ///Other properties can be updated via a wlr_output_configuration object.
+    ///
+    ///Properties sent via this interface are applied atomically via the
+    ///wlr_output_manager.done event. No guarantees are made regarding the order
+    ///in which properties are sent.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_head_v1 {
+        ///
+        ///Available since version 4
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum AdaptiveSyncState {
-            #[doc = "adaptive sync is disabled"]
+            ///adaptive sync is disabled
             Disabled = 0u32,
-            #[doc = "adaptive sync is enabled"]
+            ///adaptive sync is enabled
             Enabled = 1u32,
         }
+        #[allow(deprecated)]
+        impl AdaptiveSyncState {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 4u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Disabled => 1u32,
+                    Self::Enabled => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Disabled => None,
+                    Self::Enabled => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("disabled", Self::Disabled),
+                ("enabled", Self::Enabled),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Disabled => "disabled",
+                    Self::Enabled => "enabled",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<AdaptiveSyncState> for u32 {
             fn from(value: AdaptiveSyncState) -> Self {
                 value as u32
             }
         }
+        impl From<AdaptiveSyncState> for i32 {
+            fn from(value: AdaptiveSyncState) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for AdaptiveSyncState {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -2825,650 +5815,968 @@ pub mod wlr_output_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for AdaptiveSyncState {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for AdaptiveSyncState {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_output_head_v1 interface. See the module level documentation for more info"]
-        pub trait ZwlrOutputHeadV1
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwlr_output_head_v1";
-            const VERSION: u32 = 4u32;
-            #[doc = "This request indicates that the client will no longer use this head"]
-            #[doc = "object."]
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event describes the head name."]
-            #[doc = ""]
-            #[doc = "The naming convention is compositor defined, but limited to alphanumeric"]
-            #[doc = "characters and dashes (-). Each name is unique among all wlr_output_head"]
-            #[doc = "objects, but if a wlr_output_head object is destroyed the same name may"]
-            #[doc = "be reused later. The names will also remain consistent across sessions"]
-            #[doc = "with the same hardware and software configuration."]
-            #[doc = ""]
-            #[doc = "Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do"]
-            #[doc = "not assume that the name is a reflection of an underlying DRM"]
-            #[doc = "connector, X11 connection, etc."]
-            #[doc = ""]
-            #[doc = "If this head matches a wl_output, the wl_output.name event must report"]
-            #[doc = "the same name."]
-            #[doc = ""]
-            #[doc = "The name event is sent after a wlr_output_head object is created. This"]
-            #[doc = "event is only sent once per object, and the name does not change over"]
-            #[doc = "the lifetime of the wlr_output_head object."]
-            fn name(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_output_head_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the name message was introduced in
+            pub const NAME_SINCE: u32 = 1u32;
+            ///The interface version the name message was deprecated in, if any
+            pub const NAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the head name.
+            ///
+            ///The naming convention is compositor defined, but limited to alphanumeric
+            ///characters and dashes (-). Each name is unique among all wlr_output_head
+            ///objects, but if a wlr_output_head object is destroyed the same name may
+            ///be reused later. The names will also remain consistent across sessions
+            ///with the same hardware and software configuration.
+            ///
+            ///Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+            ///not assume that the name is a reflection of an underlying DRM
+            ///connector, X11 connection, etc.
+            ///
+            ///If this head matches a wl_output, the wl_output.name event must report
+            ///the same name.
+            ///
+            ///The name event is sent after a wlr_output_head object is created. This
+            ///event is only sent once per object, and the name does not change over
+            ///the lifetime of the wlr_output_head object.
+            pub fn name<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 name: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.name(\"{}\")", sender_id, name);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(name))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "name", direction = "out", "args.name" = % name,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "name", direction = "out", "args.name" : % = name;
+                    "zwlr_output_head_v1.name"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(name))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes a human-readable description of the head."]
-            #[doc = ""]
-            #[doc = "The description is a UTF-8 string with no convention defined for its"]
-            #[doc = "contents. Examples might include 'Foocorp 11\" Display' or 'Virtual X11"]
-            #[doc = "output via :1'. However, do not assume that the name is a reflection of"]
-            #[doc = "the make, model, serial of the underlying DRM connector or the display"]
-            #[doc = "name of the underlying X11 connection, etc."]
-            #[doc = ""]
-            #[doc = "If this head matches a wl_output, the wl_output.description event must"]
-            #[doc = "report the same name."]
-            #[doc = ""]
-            #[doc = "The description event is sent after a wlr_output_head object is created."]
-            #[doc = "This event is only sent once per object, and the description does not"]
-            #[doc = "change over the lifetime of the wlr_output_head object."]
-            fn description(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the description message was introduced in
+            pub const DESCRIPTION_SINCE: u32 = 1u32;
+            ///The interface version the description message was deprecated in, if any
+            pub const DESCRIPTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes a human-readable description of the head.
+            ///
+            ///The description is a UTF-8 string with no convention defined for its
+            ///contents. Examples might include 'Foocorp 11" Display' or 'Virtual X11
+            ///output via :1'. However, do not assume that the name is a reflection of
+            ///the make, model, serial of the underlying DRM connector or the display
+            ///name of the underlying X11 connection, etc.
+            ///
+            ///If this head matches a wl_output, the wl_output.description event must
+            ///report the same name.
+            ///
+            ///The description event is sent after a wlr_output_head object is created.
+            ///This event is only sent once per object, and the description does not
+            ///change over the lifetime of the wlr_output_head object.
+            pub fn description<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 description: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.description(\"{}\")",
-                        sender_id,
-                        description
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(description))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "description", direction = "out", "args.description" = %
+                    description,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "description", direction = "out", "args.description" : % =
+                    description; "zwlr_output_head_v1.description"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(description))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the physical size of the head. This event is only"]
-            #[doc = "sent if the head has a physical size (e.g. is not a projector or a"]
-            #[doc = "virtual device)."]
-            #[doc = ""]
-            #[doc = "The physical size event is sent after a wlr_output_head object is created. This"]
-            #[doc = "event is only sent once per object, and the physical size does not change over"]
-            #[doc = "the lifetime of the wlr_output_head object."]
-            fn physical_size(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the physical_size message was introduced in
+            pub const PHYSICAL_SIZE_SINCE: u32 = 1u32;
+            ///The interface version the physical_size message was deprecated in, if any
+            pub const PHYSICAL_SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the physical size of the head. This event is only
+            ///sent if the head has a physical size (e.g. is not a projector or a
+            ///virtual device).
+            ///
+            ///The physical size event is sent after a wlr_output_head object is created. This
+            ///event is only sent once per object, and the physical size does not change over
+            ///the lifetime of the wlr_output_head object.
+            pub fn physical_size<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.physical_size({}, {})",
-                        sender_id,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(width)
-                        .put_int(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 2u16,
+                    message = "physical_size", direction = "out", "args.width" = % width,
+                    "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 2u16,
+                    message = "physical_size", direction = "out", "args.width" : % =
+                    width, "args.height" : % = height;
+                    "zwlr_output_head_v1.physical_size"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(width)
+                    .put_int(height)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event introduces a mode for this head. It is sent once per"]
-            #[doc = "supported mode."]
-            fn mode(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the mode message was introduced in
+            pub const MODE_SINCE: u32 = 1u32;
+            ///The interface version the mode message was deprecated in, if any
+            pub const MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event introduces a mode for this head. It is sent once per
+            ///supported mode.
+            pub fn mode<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 mode: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.mode({})", sender_id, mode);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(mode))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 3u16,
+                    message = "mode", direction = "out", "args.mode" = % mode,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 3u16,
+                    message = "mode", direction = "out", "args.mode" : % = mode;
+                    "zwlr_output_head_v1.mode"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(mode))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes whether the head is enabled. A disabled head is not"]
-            #[doc = "mapped to a region of the global compositor space."]
-            #[doc = ""]
-            #[doc = "When a head is disabled, some properties (current_mode, position,"]
-            #[doc = "transform and scale) are irrelevant."]
-            fn enabled(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the enabled message was introduced in
+            pub const ENABLED_SINCE: u32 = 1u32;
+            ///The interface version the enabled message was deprecated in, if any
+            pub const ENABLED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes whether the head is enabled. A disabled head is not
+            ///mapped to a region of the global compositor space.
+            ///
+            ///When a head is disabled, some properties (current_mode, position,
+            ///transform and scale) are irrelevant.
+            pub fn enabled<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 enabled: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.enabled({})", sender_id, enabled);
-                    let payload = waynest::PayloadBuilder::new().put_int(enabled).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 4u16,
+                    message = "enabled", direction = "out", "args.enabled" = % enabled,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 4u16,
+                    message = "enabled", direction = "out", "args.enabled" : % = enabled;
+                    "zwlr_output_head_v1.enabled"
+                );
+                let payload = waynest::PayloadBuilder::new().put_int(enabled).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the mode currently in use for this head. It is only"]
-            #[doc = "sent if the output is enabled."]
-            fn current_mode(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the current_mode message was introduced in
+            pub const CURRENT_MODE_SINCE: u32 = 1u32;
+            ///The interface version the current_mode message was deprecated in, if any
+            pub const CURRENT_MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the mode currently in use for this head. It is only
+            ///sent if the output is enabled.
+            pub fn current_mode<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 mode: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.current_mode({})",
-                        sender_id,
-                        mode
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_object(Some(mode))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 5u16,
+                    message = "current_mode", direction = "out", "args.mode" = % mode,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 5u16,
+                    message = "current_mode", direction = "out", "args.mode" : % = mode;
+                    "zwlr_output_head_v1.current_mode"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_object(Some(mode))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This events describes the position of the head in the global compositor"]
-            #[doc = "space. It is only sent if the output is enabled."]
-            fn position(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the position message was introduced in
+            pub const POSITION_SINCE: u32 = 1u32;
+            ///The interface version the position message was deprecated in, if any
+            pub const POSITION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This events describes the position of the head in the global compositor
+            ///space. It is only sent if the output is enabled.
+            pub fn position<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.position({}, {})",
-                        sender_id,
-                        x,
-                        y
-                    );
-                    let payload = waynest::PayloadBuilder::new().put_int(x).put_int(y).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 6u16,
+                    message = "position", direction = "out", "args.x" = % x, "args.y" = %
+                    y,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 6u16,
+                    message = "position", direction = "out", "args.x" : % = x, "args.y" :
+                    % = y; "zwlr_output_head_v1.position"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(x)
+                    .put_int(y)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the transformation currently applied to the head."]
-            #[doc = "It is only sent if the output is enabled."]
-            fn transform(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                transform: super::super::super::core::wayland::wl_output::Transform,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.transform({})",
-                        sender_id,
-                        transform
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(transform.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the transform message was introduced in
+            pub const TRANSFORM_SINCE: u32 = 1u32;
+            ///The interface version the transform message was deprecated in, if any
+            pub const TRANSFORM_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the transformation currently applied to the head.
+            ///It is only sent if the output is enabled.
+            pub fn transform<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                transform: crate::server::core::wayland::wl_output::Transform,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 7u16,
+                    message = "transform", direction = "out", "args.transform" = %
+                    transform,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 7u16,
+                    message = "transform", direction = "out", "args.transform" : % =
+                    transform; "zwlr_output_head_v1.transform"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(transform.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 7u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This events describes the scale of the head in the global compositor"]
-            #[doc = "space. It is only sent if the output is enabled."]
-            fn scale(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the scale message was introduced in
+            pub const SCALE_SINCE: u32 = 1u32;
+            ///The interface version the scale message was deprecated in, if any
+            pub const SCALE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This events describes the scale of the head in the global compositor
+            ///space. It is only sent if the output is enabled.
+            pub fn scale<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 scale: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.scale({})", sender_id, scale);
-                    let payload = waynest::PayloadBuilder::new().put_fixed(scale).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 8u16,
+                    message = "scale", direction = "out", "args.scale" = % scale,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 8u16,
+                    message = "scale", direction = "out", "args.scale" : % = scale;
+                    "zwlr_output_head_v1.scale"
+                );
+                let payload = waynest::PayloadBuilder::new().put_fixed(scale).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 8u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event indicates that the head is no longer available. The head"]
-            #[doc = "object becomes inert. Clients should send a destroy request and release"]
-            #[doc = "any resources associated with it."]
-            fn finished(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.finished()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the finished message was introduced in
+            pub const FINISHED_SINCE: u32 = 1u32;
+            ///The interface version the finished message was deprecated in, if any
+            pub const FINISHED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the head is no longer available. The head
+            ///object becomes inert. Clients should send a destroy request and release
+            ///any resources associated with it.
+            pub fn finished<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 9u16,
+                    message = "finished", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 9u16,
+                    message = "finished", direction = "out";
+                    "zwlr_output_head_v1.finished"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 9u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the manufacturer of the head."]
-            #[doc = ""]
-            #[doc = "Together with the model and serial_number events the purpose is to"]
-            #[doc = "allow clients to recognize heads from previous sessions and for example"]
-            #[doc = "load head-specific configurations back."]
-            #[doc = ""]
-            #[doc = "It is not guaranteed this event will be ever sent. A reason for that"]
-            #[doc = "can be that the compositor does not have information about the make of"]
-            #[doc = "the head or the definition of a make is not sensible in the current"]
-            #[doc = "setup, for example in a virtual session. Clients can still try to"]
-            #[doc = "identify the head by available information from other events but should"]
-            #[doc = "be aware that there is an increased risk of false positives."]
-            #[doc = ""]
-            #[doc = "If sent, the make event is sent after a wlr_output_head object is"]
-            #[doc = "created and only sent once per object. The make does not change over"]
-            #[doc = "the lifetime of the wlr_output_head object."]
-            #[doc = ""]
-            #[doc = "It is not recommended to display the make string in UI to users. For"]
-            #[doc = "that the string provided by the description event should be preferred."]
-            fn make(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the make message was introduced in
+            pub const MAKE_SINCE: u32 = 2u32;
+            ///The interface version the make message was deprecated in, if any
+            pub const MAKE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the manufacturer of the head.
+            ///
+            ///Together with the model and serial_number events the purpose is to
+            ///allow clients to recognize heads from previous sessions and for example
+            ///load head-specific configurations back.
+            ///
+            ///It is not guaranteed this event will be ever sent. A reason for that
+            ///can be that the compositor does not have information about the make of
+            ///the head or the definition of a make is not sensible in the current
+            ///setup, for example in a virtual session. Clients can still try to
+            ///identify the head by available information from other events but should
+            ///be aware that there is an increased risk of false positives.
+            ///
+            ///If sent, the make event is sent after a wlr_output_head object is
+            ///created and only sent once per object. The make does not change over
+            ///the lifetime of the wlr_output_head object.
+            ///
+            ///It is not recommended to display the make string in UI to users. For
+            ///that the string provided by the description event should be preferred.
+            ///
+            ///Available since version 2
+            pub fn make<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 make: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.make(\"{}\")", sender_id, make);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(make))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 10u16,
+                    message = "make", direction = "out", "args.make" = % make,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 10u16,
+                    message = "make", direction = "out", "args.make" : % = make;
+                    "zwlr_output_head_v1.make"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(make))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 10u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the model of the head."]
-            #[doc = ""]
-            #[doc = "Together with the make and serial_number events the purpose is to"]
-            #[doc = "allow clients to recognize heads from previous sessions and for example"]
-            #[doc = "load head-specific configurations back."]
-            #[doc = ""]
-            #[doc = "It is not guaranteed this event will be ever sent. A reason for that"]
-            #[doc = "can be that the compositor does not have information about the model of"]
-            #[doc = "the head or the definition of a model is not sensible in the current"]
-            #[doc = "setup, for example in a virtual session. Clients can still try to"]
-            #[doc = "identify the head by available information from other events but should"]
-            #[doc = "be aware that there is an increased risk of false positives."]
-            #[doc = ""]
-            #[doc = "If sent, the model event is sent after a wlr_output_head object is"]
-            #[doc = "created and only sent once per object. The model does not change over"]
-            #[doc = "the lifetime of the wlr_output_head object."]
-            #[doc = ""]
-            #[doc = "It is not recommended to display the model string in UI to users. For"]
-            #[doc = "that the string provided by the description event should be preferred."]
-            fn model(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the model message was introduced in
+            pub const MODEL_SINCE: u32 = 2u32;
+            ///The interface version the model message was deprecated in, if any
+            pub const MODEL_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the model of the head.
+            ///
+            ///Together with the make and serial_number events the purpose is to
+            ///allow clients to recognize heads from previous sessions and for example
+            ///load head-specific configurations back.
+            ///
+            ///It is not guaranteed this event will be ever sent. A reason for that
+            ///can be that the compositor does not have information about the model of
+            ///the head or the definition of a model is not sensible in the current
+            ///setup, for example in a virtual session. Clients can still try to
+            ///identify the head by available information from other events but should
+            ///be aware that there is an increased risk of false positives.
+            ///
+            ///If sent, the model event is sent after a wlr_output_head object is
+            ///created and only sent once per object. The model does not change over
+            ///the lifetime of the wlr_output_head object.
+            ///
+            ///It is not recommended to display the model string in UI to users. For
+            ///that the string provided by the description event should be preferred.
+            ///
+            ///Available since version 2
+            pub fn model<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 model: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_head_v1#{}.model(\"{}\")", sender_id, model);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(model))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 11u16,
+                    message = "model", direction = "out", "args.model" = % model,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 11u16,
+                    message = "model", direction = "out", "args.model" : % = model;
+                    "zwlr_output_head_v1.model"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(model))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 11u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the serial number of the head."]
-            #[doc = ""]
-            #[doc = "Together with the make and model events the purpose is to allow clients"]
-            #[doc = "to recognize heads from previous sessions and for example load head-"]
-            #[doc = "specific configurations back."]
-            #[doc = ""]
-            #[doc = "It is not guaranteed this event will be ever sent. A reason for that"]
-            #[doc = "can be that the compositor does not have information about the serial"]
-            #[doc = "number of the head or the definition of a serial number is not sensible"]
-            #[doc = "in the current setup. Clients can still try to identify the head by"]
-            #[doc = "available information from other events but should be aware that there"]
-            #[doc = "is an increased risk of false positives."]
-            #[doc = ""]
-            #[doc = "If sent, the serial number event is sent after a wlr_output_head object"]
-            #[doc = "is created and only sent once per object. The serial number does not"]
-            #[doc = "change over the lifetime of the wlr_output_head object."]
-            #[doc = ""]
-            #[doc = "It is not recommended to display the serial_number string in UI to"]
-            #[doc = "users. For that the string provided by the description event should be"]
-            #[doc = "preferred."]
-            fn serial_number(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the serial_number message was introduced in
+            pub const SERIAL_NUMBER_SINCE: u32 = 2u32;
+            ///The interface version the serial_number message was deprecated in, if any
+            pub const SERIAL_NUMBER_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the serial number of the head.
+            ///
+            ///Together with the make and model events the purpose is to allow clients
+            ///to recognize heads from previous sessions and for example load head-
+            ///specific configurations back.
+            ///
+            ///It is not guaranteed this event will be ever sent. A reason for that
+            ///can be that the compositor does not have information about the serial
+            ///number of the head or the definition of a serial number is not sensible
+            ///in the current setup. Clients can still try to identify the head by
+            ///available information from other events but should be aware that there
+            ///is an increased risk of false positives.
+            ///
+            ///If sent, the serial number event is sent after a wlr_output_head object
+            ///is created and only sent once per object. The serial number does not
+            ///change over the lifetime of the wlr_output_head object.
+            ///
+            ///It is not recommended to display the serial_number string in UI to
+            ///users. For that the string provided by the description event should be
+            ///preferred.
+            ///
+            ///Available since version 2
+            pub fn serial_number<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 serial_number: String,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.serial_number(\"{}\")",
-                        sender_id,
-                        serial_number
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_string(Some(serial_number))
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 12u16,
+                    message = "serial_number", direction = "out", "args.serial_number" =
+                    % serial_number,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 12u16,
+                    message = "serial_number", direction = "out", "args.serial_number" :
+                    % = serial_number; "zwlr_output_head_v1.serial_number"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_string(Some(serial_number))
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 12u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes whether adaptive sync is currently enabled for"]
-            #[doc = "the head or not. Adaptive sync is also known as Variable Refresh"]
-            #[doc = "Rate or VRR."]
-            fn adaptive_sync(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                state: AdaptiveSyncState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_head_v1#{}.adaptive_sync({})",
-                        sender_id,
-                        state
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(state.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the adaptive_sync message was introduced in
+            pub const ADAPTIVE_SYNC_SINCE: u32 = 4u32;
+            ///The interface version the adaptive_sync message was deprecated in, if any
+            pub const ADAPTIVE_SYNC_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes whether adaptive sync is currently enabled for
+            ///the head or not. Adaptive sync is also known as Variable Refresh
+            ///Rate or VRR.
+            ///
+            ///Available since version 4
+            pub fn adaptive_sync<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                state_arg: AdaptiveSyncState,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_head_v1", object_id = % sender_id, opcode = 13u16,
+                    message = "adaptive_sync", direction = "out", "args.state" = %
+                    state_arg,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_head_v1", object_id : % = sender_id, opcode = 13u16,
+                    message = "adaptive_sync", direction = "out", "args.state" : % =
+                    state_arg; "zwlr_output_head_v1.adaptive_sync"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(state_arg.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 13u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwlr_output_head_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_head_v1` interface",
+            label = "`ZwlrOutputHeadV1` is not implemented for `{Self}`"
+        )]
+        pub trait ZwlrOutputHeadV1
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwlr_output_head_v1";
+            const VERSION: u32 = 4u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 3u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request indicates that the client will no longer use this head
+            ///object.
+            ///
+            ///Available since version 3
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_head_v1#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_head_v1", object_id = % sender_id, opcode =
+                                0u16, message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_head_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "release", direction = "in";
+                                "zwlr_output_head_v1.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object describes an output mode."]
-    #[doc = ""]
-    #[doc = "Some heads don't support output modes, in which case modes won't be"]
-    #[doc = "advertised."]
-    #[doc = ""]
-    #[doc = "Properties sent via this interface are applied atomically via the"]
-    #[doc = "wlr_output_manager.done event. No guarantees are made regarding the order"]
-    #[doc = "in which properties are sent."]
+    ///This object describes an output mode.
+    ///
+    ///Some heads don't support output modes, in which case modes won't be
+    ///advertised.
+    ///
+    ///Properties sent via this interface are applied atomically via the
+    ///wlr_output_manager.done event. No guarantees are made regarding the order
+    ///in which properties are sent.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_mode_v1 {
-        #[doc = "Trait to implement the zwlr_output_mode_v1 interface. See the module level documentation for more info"]
-        pub trait ZwlrOutputModeV1
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwlr_output_mode_v1";
-            const VERSION: u32 = 3u32;
-            #[doc = "This request indicates that the client will no longer use this mode"]
-            #[doc = "object."]
-            fn release(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This event describes the mode size. The size is given in physical"]
-            #[doc = "hardware units of the output device. This is not necessarily the same as"]
-            #[doc = "the output size in the global compositor space. For instance, the output"]
-            #[doc = "may be scaled or transformed."]
-            fn size(
-                &self,
-                connection: &mut Self::Connection,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "release",
+                since: 3u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "3",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_output_mode_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the size message was introduced in
+            pub const SIZE_SINCE: u32 = 1u32;
+            ///The interface version the size message was deprecated in, if any
+            pub const SIZE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the mode size. The size is given in physical
+            ///hardware units of the output device. This is not necessarily the same as
+            ///the output size in the global compositor space. For instance, the output
+            ///may be scaled or transformed.
+            pub fn size<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_output_mode_v1#{}.size({}, {})",
-                        sender_id,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_int(width)
-                        .put_int(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_mode_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "size", direction = "out", "args.width" = % width,
+                    "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_mode_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "size", direction = "out", "args.width" : % = width,
+                    "args.height" : % = height; "zwlr_output_mode_v1.size"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_int(width)
+                    .put_int(height)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event describes the mode's fixed vertical refresh rate. It is only"]
-            #[doc = "sent if the mode has a fixed refresh rate."]
-            fn refresh(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the refresh message was introduced in
+            pub const REFRESH_SINCE: u32 = 1u32;
+            ///The interface version the refresh message was deprecated in, if any
+            pub const REFRESH_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event describes the mode's fixed vertical refresh rate. It is only
+            ///sent if the mode has a fixed refresh rate.
+            pub fn refresh<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 refresh: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_mode_v1#{}.refresh({})", sender_id, refresh);
-                    let payload = waynest::PayloadBuilder::new().put_int(refresh).build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_mode_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "refresh", direction = "out", "args.refresh" = % refresh,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_mode_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "refresh", direction = "out", "args.refresh" : % = refresh;
+                    "zwlr_output_mode_v1.refresh"
+                );
+                let payload = waynest::PayloadBuilder::new().put_int(refresh).build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event advertises this mode as preferred."]
-            fn preferred(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_mode_v1#{}.preferred()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the preferred message was introduced in
+            pub const PREFERRED_SINCE: u32 = 1u32;
+            ///The interface version the preferred message was deprecated in, if any
+            pub const PREFERRED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event advertises this mode as preferred.
+            pub fn preferred<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_mode_v1", object_id = % sender_id, opcode = 2u16,
+                    message = "preferred", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_mode_v1", object_id : % = sender_id, opcode = 2u16,
+                    message = "preferred", direction = "out";
+                    "zwlr_output_mode_v1.preferred"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event indicates that the mode is no longer available. The mode"]
-            #[doc = "object becomes inert. Clients should send a destroy request and release"]
-            #[doc = "any resources associated with it."]
-            fn finished(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_mode_v1#{}.finished()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the finished message was introduced in
+            pub const FINISHED_SINCE: u32 = 1u32;
+            ///The interface version the finished message was deprecated in, if any
+            pub const FINISHED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the mode is no longer available. The mode
+            ///object becomes inert. Clients should send a destroy request and release
+            ///any resources associated with it.
+            pub fn finished<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_mode_v1", object_id = % sender_id, opcode = 3u16,
+                    message = "finished", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_mode_v1", object_id : % = sender_id, opcode = 3u16,
+                    message = "finished", direction = "out";
+                    "zwlr_output_mode_v1.finished"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwlr_output_mode_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_mode_v1` interface",
+            label = "`ZwlrOutputModeV1` is not implemented for `{Self}`"
+        )]
+        pub trait ZwlrOutputModeV1
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwlr_output_mode_v1";
+            const VERSION: u32 = 3u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the release message was introduced in
+            const RELEASE_SINCE: u32 = 3u32;
+            ///The interface version the release message was deprecated in, if any
+            const RELEASE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request indicates that the client will no longer use this mode
+            ///object.
+            ///
+            ///Available since version 3
+            fn release(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_mode_v1#{}.release()", sender_id,);
-                            self.release(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_mode_v1", object_id = % sender_id, opcode =
+                                0u16, message = "release", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_mode_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "release", direction = "in";
+                                "zwlr_output_mode_v1.release"
+                            );
+                            self.release(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object is used by the client to describe a full output configuration."]
-    #[doc = ""]
-    #[doc = "First, the client needs to setup the output configuration. Each head can"]
-    #[doc = "be either enabled (and configured) or disabled. It is a protocol error to"]
-    #[doc = "send two enable_head or disable_head requests with the same head. It is a"]
-    #[doc = "protocol error to omit a head in a configuration."]
-    #[doc = ""]
-    #[doc = "Then, the client can apply or test the configuration. The compositor will"]
-    #[doc = "then reply with a succeeded, failed or cancelled event. Finally the client"]
-    #[doc = "should destroy the configuration object."]
+    ///This object is used by the client to describe a full output configuration.
+    ///
+    ///First, the client needs to setup the output configuration. Each head can
+    ///be either enabled (and configured) or disabled. It is a protocol error to
+    ///send two enable_head or disable_head requests with the same head. It is a
+    ///protocol error to omit a head in a configuration.
+    ///
+    ///Then, the client can apply or test the configuration. The compositor will
+    ///then reply with a succeeded, failed or cancelled event. Finally the client
+    ///should destroy the configuration object.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_configuration_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "head has been configured twice"]
+            ///head has been configured twice
             AlreadyConfiguredHead = 1u32,
-            #[doc = "head has not been configured"]
+            ///head has not been configured
             UnconfiguredHead = 2u32,
-            #[doc = "request sent after configuration has been applied or tested"]
+            ///request sent after configuration has been applied or tested
             AlreadyUsed = 3u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::AlreadyConfiguredHead => 1u32,
+                    Self::UnconfiguredHead => 1u32,
+                    Self::AlreadyUsed => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::AlreadyConfiguredHead => None,
+                    Self::UnconfiguredHead => None,
+                    Self::AlreadyUsed => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("already_configured_head", Self::AlreadyConfiguredHead),
+                ("unconfigured_head", Self::UnconfiguredHead),
+                ("already_used", Self::AlreadyUsed),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::AlreadyConfiguredHead => "already_configured_head",
+                    Self::UnconfiguredHead => "unconfigured_head",
+                    Self::AlreadyUsed => "already_used",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -3480,156 +6788,309 @@ pub mod wlr_output_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_output_configuration_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "enable_head",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_configuration_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "head",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "disable_head",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "head",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_head_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "apply",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "test",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_output_configuration_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the succeeded message was introduced in
+            pub const SUCCEEDED_SINCE: u32 = 1u32;
+            ///The interface version the succeeded message was deprecated in, if any
+            pub const SUCCEEDED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent after the compositor has successfully applied the changes or
+            ///tested them.
+            ///
+            ///Upon receiving this event, the client should destroy this object.
+            ///
+            ///If the current configuration has changed, events to describe the changes
+            ///will be sent followed by a wlr_output_manager.done event.
+            pub fn succeeded<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_configuration_v1", object_id = % sender_id, opcode =
+                    0u16, message = "succeeded", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_configuration_v1", object_id : % = sender_id, opcode =
+                    0u16, message = "succeeded", direction = "out";
+                    "zwlr_output_configuration_v1.succeeded"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the failed message was introduced in
+            pub const FAILED_SINCE: u32 = 1u32;
+            ///The interface version the failed message was deprecated in, if any
+            pub const FAILED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent if the compositor rejects the changes or failed to apply them. The
+            ///compositor should revert any changes made by the apply request that
+            ///triggered this event.
+            ///
+            ///Upon receiving this event, the client should destroy this object.
+            pub fn failed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_configuration_v1", object_id = % sender_id, opcode =
+                    1u16, message = "failed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_configuration_v1", object_id : % = sender_id, opcode =
+                    1u16, message = "failed", direction = "out";
+                    "zwlr_output_configuration_v1.failed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the cancelled message was introduced in
+            pub const CANCELLED_SINCE: u32 = 1u32;
+            ///The interface version the cancelled message was deprecated in, if any
+            pub const CANCELLED_DEPRECATED_SINCE: Option<u32> = None;
+            ///Sent if the compositor cancels the configuration because the state of an
+            ///output changed and the client has outdated information (e.g. after an
+            ///output has been hotplugged).
+            ///
+            ///The client can create a new configuration with a newer serial and try
+            ///again.
+            ///
+            ///Upon receiving this event, the client should destroy this object.
+            pub fn cancelled<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_configuration_v1", object_id = % sender_id, opcode =
+                    2u16, message = "cancelled", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_configuration_v1", object_id : % = sender_id, opcode =
+                    2u16, message = "cancelled", direction = "out";
+                    "zwlr_output_configuration_v1.cancelled"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
+                    )
+            }
+        }
+        ///Trait to implement the zwlr_output_configuration_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_configuration_v1` interface",
+            label = "`ZwlrOutputConfigurationV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrOutputConfigurationV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_output_configuration_v1";
             const VERSION: u32 = 4u32;
-            #[doc = "Enable a head. This request creates a head configuration object that can"]
-            #[doc = "be used to change the head's properties."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the enable_head message was introduced in
+            const ENABLE_HEAD_SINCE: u32 = 1u32;
+            ///The interface version the enable_head message was deprecated in, if any
+            const ENABLE_HEAD_DEPRECATED_SINCE: Option<u32> = None;
+            ///Enable a head. This request creates a head configuration object that can
+            ///be used to change the head's properties.
             fn enable_head(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 head: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Disable a head."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the disable_head message was introduced in
+            const DISABLE_HEAD_SINCE: u32 = 1u32;
+            ///The interface version the disable_head message was deprecated in, if any
+            const DISABLE_HEAD_DEPRECATED_SINCE: Option<u32> = None;
+            ///Disable a head.
             fn disable_head(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 head: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Apply the new output configuration."]
-            #[doc = ""]
-            #[doc = "In case the configuration is successfully applied, there is no guarantee"]
-            #[doc = "that the new output state matches completely the requested"]
-            #[doc = "configuration. For instance, a compositor might round the scale if it"]
-            #[doc = "doesn't support fractional scaling."]
-            #[doc = ""]
-            #[doc = "After this request has been sent, the compositor must respond with an"]
-            #[doc = "succeeded, failed or cancelled event. Sending a request that isn't the"]
-            #[doc = "destructor is a protocol error."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the apply message was introduced in
+            const APPLY_SINCE: u32 = 1u32;
+            ///The interface version the apply message was deprecated in, if any
+            const APPLY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Apply the new output configuration.
+            ///
+            ///In case the configuration is successfully applied, there is no guarantee
+            ///that the new output state matches completely the requested
+            ///configuration. For instance, a compositor might round the scale if it
+            ///doesn't support fractional scaling.
+            ///
+            ///After this request has been sent, the compositor must respond with an
+            ///succeeded, failed or cancelled event. Sending a request that isn't the
+            ///destructor is a protocol error.
             fn apply(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Test the new output configuration. The configuration won't be applied,"]
-            #[doc = "but will only be validated."]
-            #[doc = ""]
-            #[doc = "Even if the compositor succeeds to test a configuration, applying it may"]
-            #[doc = "fail."]
-            #[doc = ""]
-            #[doc = "After this request has been sent, the compositor must respond with an"]
-            #[doc = "succeeded, failed or cancelled event. Sending a request that isn't the"]
-            #[doc = "destructor is a protocol error."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the test message was introduced in
+            const TEST_SINCE: u32 = 1u32;
+            ///The interface version the test message was deprecated in, if any
+            const TEST_DEPRECATED_SINCE: Option<u32> = None;
+            ///Test the new output configuration. The configuration won't be applied,
+            ///but will only be validated.
+            ///
+            ///Even if the compositor succeeds to test a configuration, applying it may
+            ///fail.
+            ///
+            ///After this request has been sent, the compositor must respond with an
+            ///succeeded, failed or cancelled event. Sending a request that isn't the
+            ///destructor is a protocol error.
             fn test(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Using this request a client can tell the compositor that it is not going"]
-            #[doc = "to use the configuration object anymore. Any changes to the outputs"]
-            #[doc = "that have not been applied will be discarded."]
-            #[doc = ""]
-            #[doc = "This request also destroys wlr_output_configuration_head objects created"]
-            #[doc = "via this object."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Sent after the compositor has successfully applied the changes or"]
-            #[doc = "tested them."]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy this object."]
-            #[doc = ""]
-            #[doc = "If the current configuration has changed, events to describe the changes"]
-            #[doc = "will be sent followed by a wlr_output_manager.done event."]
-            fn succeeded(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_configuration_v1#{}.succeeded()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "Sent if the compositor rejects the changes or failed to apply them. The"]
-            #[doc = "compositor should revert any changes made by the apply request that"]
-            #[doc = "triggered this event."]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy this object."]
-            fn failed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_configuration_v1#{}.failed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "Sent if the compositor cancels the configuration because the state of an"]
-            #[doc = "output changed and the client has outdated information (e.g. after an"]
-            #[doc = "output has been hotplugged)."]
-            #[doc = ""]
-            #[doc = "The client can create a new configuration with a newer serial and try"]
-            #[doc = "again."]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy this object."]
-            fn cancelled(
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Using this request a client can tell the compositor that it is not going
+            ///to use the configuration object anymore. Any changes to the outputs
+            ///that have not been applied will be discarded.
+            ///
+            ///This request also destroys wlr_output_configuration_head objects created
+            ///via this object.
+            fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_configuration_v1#{}.cancelled()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 2u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3641,74 +7102,191 @@ pub mod wlr_output_management_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_v1#{}.enable_head({}, {})",
-                                sender_id,
-                                id,
-                                head
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "enable_head", direction = "in",
+                                "args.id" = % id, "args.head" = % head,
                             );
-                            self.enable_head(connection, sender_id, id, head).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "enable_head", direction = "in",
+                                "args.id" : % = id, "args.head" : % = head;
+                                "zwlr_output_configuration_v1.enable_head"
+                            );
+                            self.enable_head(connection, state, sender_id, id, head)
+                                .await
                         }
                         1u16 => {
                             let head = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_v1#{}.disable_head({})",
-                                sender_id,
-                                head
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "disable_head", direction = "in",
+                                "args.head" = % head,
                             );
-                            self.disable_head(connection, sender_id, head).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "disable_head", direction = "in",
+                                "args.head" : % = head;
+                                "zwlr_output_configuration_v1.disable_head"
+                            );
+                            self.disable_head(connection, state, sender_id, head).await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_configuration_v1#{}.apply()", sender_id,);
-                            self.apply(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_v1", object_id = % sender_id,
+                                opcode = 2u16, message = "apply", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_v1", object_id : % = sender_id,
+                                opcode = 2u16, message = "apply", direction = "in";
+                                "zwlr_output_configuration_v1.apply"
+                            );
+                            self.apply(connection, state, sender_id).await
                         }
                         3u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_configuration_v1#{}.test()", sender_id,);
-                            self.test(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_v1", object_id = % sender_id,
+                                opcode = 3u16, message = "test", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_v1", object_id : % = sender_id,
+                                opcode = 3u16, message = "test", direction = "in";
+                                "zwlr_output_configuration_v1.test"
+                            );
+                            self.test(connection, state, sender_id).await
                         }
                         4u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_configuration_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_v1", object_id = % sender_id,
+                                opcode = 4u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_v1", object_id : % = sender_id,
+                                opcode = 4u16, message = "destroy", direction = "in";
+                                "zwlr_output_configuration_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object is used by the client to update a single head's configuration."]
-    #[doc = ""]
-    #[doc = "It is a protocol error to set the same property twice."]
+    ///This object is used by the client to update a single head's configuration.
+    ///
+    ///It is a protocol error to set the same property twice.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_configuration_head_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "property has already been set"]
+            ///property has already been set
             AlreadySet = 1u32,
-            #[doc = "mode doesn't belong to head"]
+            ///mode doesn't belong to head
             InvalidMode = 2u32,
-            #[doc = "mode is invalid"]
+            ///mode is invalid
             InvalidCustomMode = 3u32,
-            #[doc = "transform value outside enum"]
+            ///transform value outside enum
             InvalidTransform = 4u32,
-            #[doc = "scale negative or zero"]
+            ///scale negative or zero
             InvalidScale = 5u32,
-            #[doc = "invalid enum value used in the set_adaptive_sync request"]
+            ///invalid enum value used in the set_adaptive_sync request
+            ///
+            ///Available since version 4
             InvalidAdaptiveSyncState = 6u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::AlreadySet => 1u32,
+                    Self::InvalidMode => 1u32,
+                    Self::InvalidCustomMode => 1u32,
+                    Self::InvalidTransform => 1u32,
+                    Self::InvalidScale => 1u32,
+                    Self::InvalidAdaptiveSyncState => 4u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::AlreadySet => None,
+                    Self::InvalidMode => None,
+                    Self::InvalidCustomMode => None,
+                    Self::InvalidTransform => None,
+                    Self::InvalidScale => None,
+                    Self::InvalidAdaptiveSyncState => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("already_set", Self::AlreadySet),
+                ("invalid_mode", Self::InvalidMode),
+                ("invalid_custom_mode", Self::InvalidCustomMode),
+                ("invalid_transform", Self::InvalidTransform),
+                ("invalid_scale", Self::InvalidScale),
+                ("invalid_adaptive_sync_state", Self::InvalidAdaptiveSyncState),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::AlreadySet => "already_set",
+                    Self::InvalidMode => "invalid_mode",
+                    Self::InvalidCustomMode => "invalid_custom_mode",
+                    Self::InvalidTransform => "invalid_transform",
+                    Self::InvalidScale => "invalid_scale",
+                    Self::InvalidAdaptiveSyncState => "invalid_adaptive_sync_state",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -3723,76 +7301,261 @@ pub mod wlr_output_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_output_configuration_head_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("zwlr_output_mode_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_custom_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "iii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "refresh",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_position",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "ii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_transform",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "i",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "transform",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: Some("wl_output.transform"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_scale",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "f",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "scale",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "set_adaptive_sync",
+                since: 4u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "4u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("zwlr_output_head_v1.adaptive_sync_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwlr_output_configuration_head_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_output_configuration_head_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_configuration_head_v1` interface",
+            label = "`ZwlrOutputConfigurationHeadV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrOutputConfigurationHeadV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_output_configuration_head_v1";
             const VERSION: u32 = 4u32;
-            #[doc = "This request sets the head's mode."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_mode message was introduced in
+            const SET_MODE_SINCE: u32 = 1u32;
+            ///The interface version the set_mode message was deprecated in, if any
+            const SET_MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the head's mode.
             fn set_mode(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 mode: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request assigns a custom mode to the head. The size is given in"]
-            #[doc = "physical hardware units of the output device. If set to zero, the"]
-            #[doc = "refresh rate is unspecified."]
-            #[doc = ""]
-            #[doc = "It is a protocol error to set both a mode and a custom mode."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_custom_mode message was introduced in
+            const SET_CUSTOM_MODE_SINCE: u32 = 1u32;
+            ///The interface version the set_custom_mode message was deprecated in, if any
+            const SET_CUSTOM_MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request assigns a custom mode to the head. The size is given in
+            ///physical hardware units of the output device. If set to zero, the
+            ///refresh rate is unspecified.
+            ///
+            ///It is a protocol error to set both a mode and a custom mode.
             fn set_custom_mode(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 width: i32,
                 height: i32,
                 refresh: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets the head's position in the global compositor space."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_position message was introduced in
+            const SET_POSITION_SINCE: u32 = 1u32;
+            ///The interface version the set_position message was deprecated in, if any
+            const SET_POSITION_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the head's position in the global compositor space.
             fn set_position(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 x: i32,
                 y: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets the head's transform."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_transform message was introduced in
+            const SET_TRANSFORM_SINCE: u32 = 1u32;
+            ///The interface version the set_transform message was deprecated in, if any
+            const SET_TRANSFORM_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the head's transform.
             fn set_transform(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-                transform: super::super::super::core::wayland::wl_output::Transform,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request sets the head's scale."]
+                transform: crate::server::core::wayland::wl_output::Transform,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_scale message was introduced in
+            const SET_SCALE_SINCE: u32 = 1u32;
+            ///The interface version the set_scale message was deprecated in, if any
+            const SET_SCALE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request sets the head's scale.
             fn set_scale(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 scale: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "This request enables/disables adaptive sync. Adaptive sync is also"]
-            #[doc = "known as Variable Refresh Rate or VRR."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the set_adaptive_sync message was introduced in
+            const SET_ADAPTIVE_SYNC_SINCE: u32 = 4u32;
+            ///The interface version the set_adaptive_sync message was deprecated in, if any
+            const SET_ADAPTIVE_SYNC_DEPRECATED_SINCE: Option<u32> = None;
+            ///This request enables/disables adaptive sync. Adaptive sync is also
+            ///known as Variable Refresh Rate or VRR.
+            ///
+            ///Available since version 4
             fn set_adaptive_sync(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-                state : super :: super :: super :: wlr :: wlr_output_management_unstable_v1 :: zwlr_output_head_v1 :: AdaptiveSyncState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+                state_arg: super::zwlr_output_head_v1::AdaptiveSyncState,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3801,132 +7564,268 @@ pub mod wlr_output_management_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_head_v1#{}.set_mode({})",
-                                sender_id,
-                                mode
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_head_v1", object_id = %
+                                sender_id, opcode = 0u16, message = "set_mode", direction =
+                                "in", "args.mode" = % mode,
                             );
-                            self.set_mode(connection, sender_id, mode).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_head_v1", object_id : % =
+                                sender_id, opcode = 0u16, message = "set_mode", direction =
+                                "in", "args.mode" : % = mode;
+                                "zwlr_output_configuration_head_v1.set_mode"
+                            );
+                            self.set_mode(connection, state, sender_id, mode).await
                         }
                         1u16 => {
                             let width = message.int()?;
                             let height = message.int()?;
                             let refresh = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_head_v1#{}.set_custom_mode({}, {}, {})",
-                                sender_id,
-                                width,
-                                height,
-                                refresh
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_head_v1", object_id = %
+                                sender_id, opcode = 1u16, message = "set_custom_mode",
+                                direction = "in", "args.width" = % width, "args.height" = %
+                                height, "args.refresh" = % refresh,
                             );
-                            self.set_custom_mode(connection, sender_id, width, height, refresh)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_head_v1", object_id : % =
+                                sender_id, opcode = 1u16, message = "set_custom_mode",
+                                direction = "in", "args.width" : % = width, "args.height" :
+                                % = height, "args.refresh" : % = refresh;
+                                "zwlr_output_configuration_head_v1.set_custom_mode"
+                            );
+                            self.set_custom_mode(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    width,
+                                    height,
+                                    refresh,
+                                )
                                 .await
                         }
                         2u16 => {
                             let x = message.int()?;
                             let y = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_head_v1#{}.set_position({}, {})",
-                                sender_id,
-                                x,
-                                y
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_head_v1", object_id = %
+                                sender_id, opcode = 2u16, message = "set_position",
+                                direction = "in", "args.x" = % x, "args.y" = % y,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_head_v1", object_id : % =
+                                sender_id, opcode = 2u16, message = "set_position",
+                                direction = "in", "args.x" : % = x, "args.y" : % = y;
+                                "zwlr_output_configuration_head_v1.set_position"
                             );
-                            self.set_position(connection, sender_id, x, y).await
+                            self.set_position(connection, state, sender_id, x, y).await
                         }
                         3u16 => {
-                            let transform = message.uint()?;
+                            let transform: crate::server::core::wayland::wl_output::Transform = message
+                                .int()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_head_v1#{}.set_transform({})",
-                                sender_id,
-                                transform
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_head_v1", object_id = %
+                                sender_id, opcode = 3u16, message = "set_transform",
+                                direction = "in", "args.transform" = % transform,
                             );
-                            self.set_transform(connection, sender_id, transform.try_into()?)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_head_v1", object_id : % =
+                                sender_id, opcode = 3u16, message = "set_transform",
+                                direction = "in", "args.transform" : % = transform;
+                                "zwlr_output_configuration_head_v1.set_transform"
+                            );
+                            self.set_transform(connection, state, sender_id, transform)
                                 .await
                         }
                         4u16 => {
                             let scale = message.fixed()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_head_v1#{}.set_scale({})",
-                                sender_id,
-                                scale
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_head_v1", object_id = %
+                                sender_id, opcode = 4u16, message = "set_scale", direction =
+                                "in", "args.scale" = % scale,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_head_v1", object_id : % =
+                                sender_id, opcode = 4u16, message = "set_scale", direction =
+                                "in", "args.scale" : % = scale;
+                                "zwlr_output_configuration_head_v1.set_scale"
                             );
-                            self.set_scale(connection, sender_id, scale).await
+                            self.set_scale(connection, state, sender_id, scale).await
                         }
                         5u16 => {
-                            let state = message.uint()?;
+                            let state_arg: super::zwlr_output_head_v1::AdaptiveSyncState = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_configuration_head_v1#{}.set_adaptive_sync({})",
-                                sender_id,
-                                state
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_configuration_head_v1", object_id = %
+                                sender_id, opcode = 5u16, message = "set_adaptive_sync",
+                                direction = "in", "args.state" = % state_arg,
                             );
-                            self.set_adaptive_sync(connection, sender_id, state.try_into()?)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_configuration_head_v1", object_id : % =
+                                sender_id, opcode = 5u16, message = "set_adaptive_sync",
+                                direction = "in", "args.state" : % = state_arg;
+                                "zwlr_output_configuration_head_v1.set_adaptive_sync"
+                            );
+                            self.set_adaptive_sync(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    state_arg,
+                                )
                                 .await
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
+                        }
                     }
                 }
             }
         }
     }
 }
-#[doc = "This protocol allows clients to control power management modes"]
-#[doc = "of outputs that are currently part of the compositor space. The"]
-#[doc = "intent is to allow special clients like desktop shells to power"]
-#[doc = "down outputs when the system is idle."]
-#[doc = ""]
-#[doc = "To modify outputs not currently part of the compositor space see"]
-#[doc = "wlr-output-management."]
-#[doc = ""]
-#[doc = "Warning! The protocol described in this file is experimental and"]
-#[doc = "backward incompatible changes may be made. Backward compatible changes"]
-#[doc = "may be added together with the corresponding interface version bump."]
-#[doc = "Backward incompatible changes are done by bumping the version number in"]
-#[doc = "the protocol and interface names and resetting the interface version."]
-#[doc = "Once the protocol is to be declared stable, the 'z' prefix and the"]
-#[doc = "version number in the protocol and interface names are removed and the"]
-#[doc = "interface version number is reset."]
-#[allow(clippy::module_inception)]
+///This protocol allows clients to control power management modes
+///of outputs that are currently part of the compositor space. The
+///intent is to allow special clients like desktop shells to power
+///down outputs when the system is idle.
+///
+///To modify outputs not currently part of the compositor space see
+///wlr-output-management.
+///
+///Warning! The protocol described in this file is experimental and
+///backward incompatible changes may be made. Backward compatible changes
+///may be added together with the corresponding interface version bump.
+///Backward incompatible changes are done by bumping the version number in
+///the protocol and interface names and resetting the interface version.
+///Once the protocol is to be declared stable, the 'z' prefix and the
+///version number in the protocol and interface names are removed and the
+///interface version number is reset.
+#[cfg(feature = "wlr-output-power-management-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-output-power-management-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_output_power_management_unstable_v1 {
-    #[doc = "This interface is a manager that allows creating per-output power"]
-    #[doc = "management mode controls."]
+    ///This interface is a manager that allows creating per-output power
+    ///management mode controls.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_power_manager_v1 {
-        #[doc = "Trait to implement the zwlr_output_power_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "get_output_power",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "no",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_output_power_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_output_power_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_output_power_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_power_manager_v1` interface",
+            label = "`ZwlrOutputPowerManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrOutputPowerManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_output_power_manager_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Create an output power management mode control that can be used to"]
-            #[doc = "adjust the power management mode for a given output."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the get_output_power message was introduced in
+            const GET_OUTPUT_POWER_SINCE: u32 = 1u32;
+            ///The interface version the get_output_power message was deprecated in, if any
+            const GET_OUTPUT_POWER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Create an output power management mode control that can be used to
+            ///adjust the power management mode for a given output.
             fn get_output_power(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 id: waynest::ObjectId,
                 output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "All objects created by the manager will still remain valid, until their"]
-            #[doc = "appropriate destroy request has been called."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///All objects created by the manager will still remain valid, until their
+            ///appropriate destroy request has been called.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -3938,44 +7837,117 @@ pub mod wlr_output_power_management_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_power_manager_v1#{}.get_output_power({}, {})",
-                                sender_id,
-                                id,
-                                output
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_power_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "get_output_power", direction =
+                                "in", "args.id" = % id, "args.output" = % output,
                             );
-                            self.get_output_power(connection, sender_id, id, output)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_power_manager_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "get_output_power", direction =
+                                "in", "args.id" : % = id, "args.output" : % = output;
+                                "zwlr_output_power_manager_v1.get_output_power"
+                            );
+                            self.get_output_power(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    id,
+                                    output,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_power_manager_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_power_manager_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_power_manager_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_output_power_manager_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object offers requests to set the power management mode of"]
-    #[doc = "an output."]
+    ///This object offers requests to set the power management mode of
+    ///an output.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_output_power_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Mode {
-            #[doc = "Output is turned off."]
+            ///Output is turned off.
             Off = 0u32,
-            #[doc = "Output is turned on, no power saving"]
+            ///Output is turned on, no power saving
             On = 1u32,
         }
+        #[allow(deprecated)]
+        impl Mode {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::Off => 1u32,
+                    Self::On => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::Off => None,
+                    Self::On => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("off", Self::Off),
+                ("on", Self::On),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Off => "off",
+                    Self::On => "on",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Mode> for u32 {
             fn from(value: Mode) -> Self {
                 value as u32
             }
         }
+        impl From<Mode> for i32 {
+            fn from(value: Mode) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Mode {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -3986,23 +7958,71 @@ pub mod wlr_output_power_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Mode {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Mode {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "nonexistent power save mode"]
+            ///nonexistent power save mode
             InvalidMode = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidMode => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidMode => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_mode", Self::InvalidMode),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidMode => "invalid_mode",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4012,164 +8032,394 @@ pub mod wlr_output_power_management_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "set_mode",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "mode",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("mode"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_output_power_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the mode message was introduced in
+            pub const MODE_SINCE: u32 = 1u32;
+            ///The interface version the mode message was deprecated in, if any
+            pub const MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Report the power management mode change of an output.
+            ///
+            ///The mode event is sent after an output changed its power
+            ///management mode. The reason can be a client using set_mode or the
+            ///compositor deciding to change an output's mode.
+            ///This event is also sent immediately when the object is created
+            ///so the client is informed about the current power management mode.
+            pub fn mode<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                mode: Mode,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_power_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "mode", direction = "out", "args.mode" = % mode,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_power_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "mode", direction = "out", "args.mode" : % = mode;
+                    "zwlr_output_power_v1.mode"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(mode.into())
+                    .build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
+                    )
+            }
+            ///The interface version the failed message was introduced in
+            pub const FAILED_SINCE: u32 = 1u32;
+            ///The interface version the failed message was deprecated in, if any
+            pub const FAILED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the output power management mode control
+            ///is no longer valid. This can happen for a number of reasons,
+            ///including:
+            ///- The output doesn't support power management
+            ///- Another client already has exclusive power management mode control
+            ///for this output
+            ///- The output disappeared
+            ///
+            ///Upon receiving this event, the client should destroy this object.
+            pub fn failed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_output_power_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "failed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_output_power_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "failed", direction = "out"; "zwlr_output_power_v1.failed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
+                        waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
+                    )
             }
         }
-        #[doc = "Trait to implement the zwlr_output_power_v1 interface. See the module level documentation for more info"]
+        ///Trait to implement the zwlr_output_power_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_output_power_v1` interface",
+            label = "`ZwlrOutputPowerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrOutputPowerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_output_power_v1";
             const VERSION: u32 = 1u32;
-            #[doc = "Set an output's power save mode to the given mode. The mode change"]
-            #[doc = "is effective immediately. If the output does not support the given"]
-            #[doc = "mode a failed event is sent."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the set_mode message was introduced in
+            const SET_MODE_SINCE: u32 = 1u32;
+            ///The interface version the set_mode message was deprecated in, if any
+            const SET_MODE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Set an output's power save mode to the given mode. The mode change
+            ///is effective immediately. If the output does not support the given
+            ///mode a failed event is sent.
             fn set_mode(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 mode: Mode,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the output power management mode control object."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the output power management mode control object.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Report the power management mode change of an output."]
-            #[doc = ""]
-            #[doc = "The mode event is sent after an output changed its power"]
-            #[doc = "management mode. The reason can be a client using set_mode or the"]
-            #[doc = "compositor deciding to change an output's mode."]
-            #[doc = "This event is also sent immediately when the object is created"]
-            #[doc = "so the client is informed about the current power management mode."]
-            fn mode(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                mode: Mode,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_power_v1#{}.mode({})", sender_id, mode);
-                    let payload = waynest::PayloadBuilder::new().put_uint(mode.into()).build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 0u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
-            #[doc = "This event indicates that the output power management mode control"]
-            #[doc = "is no longer valid. This can happen for a number of reasons,"]
-            #[doc = "including:"]
-            #[doc = "- The output doesn't support power management"]
-            #[doc = "- Another client already has exclusive power management mode control"]
-            #[doc = "for this output"]
-            #[doc = "- The output disappeared"]
-            #[doc = ""]
-            #[doc = "Upon receiving this event, the client should destroy this object."]
-            fn failed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_output_power_v1#{}.failed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
-                        waynest::Message::new(sender_id, 1u16, payload),
-                    )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
-            }
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
                         0u16 => {
-                            let mode = message.uint()?;
+                            let mode: Mode = message.uint()?.try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_output_power_v1#{}.set_mode({})",
-                                sender_id,
-                                mode
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_power_v1", object_id = % sender_id, opcode =
+                                0u16, message = "set_mode", direction = "in", "args.mode" =
+                                % mode,
                             );
-                            self.set_mode(connection, sender_id, mode.try_into()?).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_power_v1", object_id : % = sender_id, opcode =
+                                0u16, message = "set_mode", direction = "in", "args.mode" :
+                                % = mode; "zwlr_output_power_v1.set_mode"
+                            );
+                            self.set_mode(connection, state, sender_id, mode).await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_output_power_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_output_power_v1", object_id = % sender_id, opcode =
+                                1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_output_power_v1", object_id : % = sender_id, opcode =
+                                1u16, message = "destroy", direction = "in";
+                                "zwlr_output_power_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[doc = "This protocol allows clients to ask the compositor to copy part of the"]
-#[doc = "screen content to a client buffer."]
-#[doc = ""]
-#[doc = "Warning! The protocol described in this file is experimental and"]
-#[doc = "backward incompatible changes may be made. Backward compatible changes"]
-#[doc = "may be added together with the corresponding interface version bump."]
-#[doc = "Backward incompatible changes are done by bumping the version number in"]
-#[doc = "the protocol and interface names and resetting the interface version."]
-#[doc = "Once the protocol is to be declared stable, the 'z' prefix and the"]
-#[doc = "version number in the protocol and interface names are removed and the"]
-#[doc = "interface version number is reset."]
-#[doc = ""]
-#[doc = "Note! This protocol is deprecated and not intended for production use."]
-#[doc = "The ext-image-copy-capture-v1 protocol should be used instead."]
-#[allow(clippy::module_inception)]
+///This protocol allows clients to ask the compositor to copy part of the
+///screen content to a client buffer.
+///
+///Warning! The protocol described in this file is experimental and
+///backward incompatible changes may be made. Backward compatible changes
+///may be added together with the corresponding interface version bump.
+///Backward incompatible changes are done by bumping the version number in
+///the protocol and interface names and resetting the interface version.
+///Once the protocol is to be declared stable, the 'z' prefix and the
+///version number in the protocol and interface names are removed and the
+///interface version number is reset.
+///
+///Note! This protocol is deprecated and not intended for production use.
+///The ext-image-copy-capture-v1 protocol should be used instead.
+#[cfg(feature = "wlr-screencopy-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-screencopy-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_screencopy_unstable_v1 {
-    #[doc = "This object is a manager which offers requests to start capturing from a"]
-    #[doc = "source."]
+    ///This object is a manager which offers requests to start capturing from a
+    ///source.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_screencopy_manager_v1 {
-        #[doc = "Trait to implement the zwlr_screencopy_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "capture_output",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nio",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_screencopy_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "overlay_cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "capture_output_region",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "nioiiii",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "frame",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_screencopy_frame_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "overlay_cursor",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "width",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "height",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_screencopy_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_screencopy_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_screencopy_manager_v1` interface",
+            label = "`ZwlrScreencopyManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrScreencopyManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_screencopy_manager_v1";
             const VERSION: u32 = 3u32;
-            #[doc = "Capture the next frame of an entire output."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the capture_output message was introduced in
+            const CAPTURE_OUTPUT_SINCE: u32 = 1u32;
+            ///The interface version the capture_output message was deprecated in, if any
+            const CAPTURE_OUTPUT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Capture the next frame of an entire output.
             fn capture_output(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 frame: waynest::ObjectId,
                 overlay_cursor: i32,
                 output: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Capture the next frame of an output's region."]
-            #[doc = ""]
-            #[doc = "The region is given in output logical coordinates, see"]
-            #[doc = "xdg_output.logical_size. The region will be clipped to the output's"]
-            #[doc = "extents."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the capture_output_region message was introduced in
+            const CAPTURE_OUTPUT_REGION_SINCE: u32 = 1u32;
+            ///The interface version the capture_output_region message was deprecated in, if any
+            const CAPTURE_OUTPUT_REGION_DEPRECATED_SINCE: Option<u32> = None;
+            ///Capture the next frame of an output's region.
+            ///
+            ///The region is given in output logical coordinates, see
+            ///xdg_output.logical_size. The region will be clipped to the output's
+            ///extents.
             fn capture_output_region(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 frame: waynest::ObjectId,
                 overlay_cursor: i32,
@@ -4178,21 +8428,33 @@ pub mod wlr_screencopy_unstable_v1 {
                 y: i32,
                 width: i32,
                 height: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "All objects created by the manager will still remain valid, until their"]
-            #[doc = "appropriate destroy request has been called."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///All objects created by the manager will still remain valid, until their
+            ///appropriate destroy request has been called.
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -4205,21 +8467,31 @@ pub mod wlr_screencopy_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_screencopy_manager_v1#{}.capture_output({}, {}, {})",
-                                sender_id,
-                                frame,
-                                overlay_cursor,
-                                output
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_screencopy_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "capture_output", direction = "in",
+                                "args.frame" = % frame, "args.overlay_cursor" = %
+                                overlay_cursor, "args.output" = % output,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_screencopy_manager_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "capture_output", direction = "in",
+                                "args.frame" : % = frame, "args.overlay_cursor" : % =
+                                overlay_cursor, "args.output" : % = output;
+                                "zwlr_screencopy_manager_v1.capture_output"
                             );
                             self.capture_output(
-                                connection,
-                                sender_id,
-                                frame,
-                                overlay_cursor,
-                                output,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    frame,
+                                    overlay_cursor,
+                                    output,
+                                )
+                                .await
                         }
                         1u16 => {
                             let frame = message
@@ -4234,73 +8506,142 @@ pub mod wlr_screencopy_unstable_v1 {
                             let width = message.int()?;
                             let height = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_screencopy_manager_v1#{}.capture_output_region({}, {}, {}, {}, {}, {}, {})",
-                                sender_id,
-                                frame,
-                                overlay_cursor,
-                                output,
-                                x,
-                                y,
-                                width,
-                                height
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_screencopy_manager_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "capture_output_region", direction
+                                = "in", "args.frame" = % frame, "args.overlay_cursor" = %
+                                overlay_cursor, "args.output" = % output, "args.x" = % x,
+                                "args.y" = % y, "args.width" = % width, "args.height" = %
+                                height,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_screencopy_manager_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "capture_output_region", direction
+                                = "in", "args.frame" : % = frame, "args.overlay_cursor" : %
+                                = overlay_cursor, "args.output" : % = output, "args.x" : % =
+                                x, "args.y" : % = y, "args.width" : % = width, "args.height"
+                                : % = height;
+                                "zwlr_screencopy_manager_v1.capture_output_region"
                             );
                             self.capture_output_region(
-                                connection,
-                                sender_id,
-                                frame,
-                                overlay_cursor,
-                                output,
-                                x,
-                                y,
-                                width,
-                                height,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    frame,
+                                    overlay_cursor,
+                                    output,
+                                    x,
+                                    y,
+                                    width,
+                                    height,
+                                )
+                                .await
                         }
                         2u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_screencopy_manager_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_screencopy_manager_v1", object_id = % sender_id,
+                                opcode = 2u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_screencopy_manager_v1", object_id : % = sender_id,
+                                opcode = 2u16, message = "destroy", direction = "in";
+                                "zwlr_screencopy_manager_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object represents a single frame."]
-    #[doc = ""]
-    #[doc = "When created, a series of buffer events will be sent, each representing a"]
-    #[doc = "supported buffer type. The \"buffer_done\" event is sent afterwards to"]
-    #[doc = "indicate that all supported buffer types have been enumerated. The client"]
-    #[doc = "will then be able to send a \"copy\" request. If the capture is successful,"]
-    #[doc = "the compositor will send a \"flags\" event followed by a \"ready\" event."]
-    #[doc = ""]
-    #[doc = "For objects version 2 or lower, wl_shm buffers are always supported, ie."]
-    #[doc = "the \"buffer\" event is guaranteed to be sent."]
-    #[doc = ""]
-    #[doc = "If the capture failed, the \"failed\" event is sent. This can happen anytime"]
-    #[doc = "before the \"ready\" event."]
-    #[doc = ""]
-    #[doc = "Once either a \"ready\" or a \"failed\" event is received, the client should"]
-    #[doc = "destroy the frame."]
+    ///This object represents a single frame.
+    ///
+    ///When created, a series of buffer events will be sent, each representing a
+    ///supported buffer type. The "buffer_done" event is sent afterwards to
+    ///indicate that all supported buffer types have been enumerated. The client
+    ///will then be able to send a "copy" request. If the capture is successful,
+    ///the compositor will send a "flags" event followed by a "ready" event.
+    ///
+    ///For objects version 2 or lower, wl_shm buffers are always supported, ie.
+    ///the "buffer" event is guaranteed to be sent.
+    ///
+    ///If the capture failed, the "failed" event is sent. This can happen anytime
+    ///before the "ready" event.
+    ///
+    ///Once either a "ready" or a "failed" event is received, the client should
+    ///destroy the frame.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_screencopy_frame_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "the object has already been used to copy a wl_buffer"]
+            ///the object has already been used to copy a wl_buffer
             AlreadyUsed = 0u32,
-            #[doc = "buffer attributes are invalid"]
+            ///buffer attributes are invalid
             InvalidBuffer = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::AlreadyUsed => 1u32,
+                    Self::InvalidBuffer => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::AlreadyUsed => None,
+                    Self::InvalidBuffer => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("already_used", Self::AlreadyUsed),
+                ("invalid_buffer", Self::InvalidBuffer),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::AlreadyUsed => "already_used",
+                    Self::InvalidBuffer => "invalid_buffer",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4311,12 +8652,40 @@ pub mod wlr_screencopy_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
+            }
+        }
+        bitflags::bitflags! {
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)] pub
+            struct Flags : u32 { #[doc = "contents are y-inverted"] const YInvert = 1u32;
+            }
+        }
+        #[allow(deprecated)]
+        impl Flags {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// Every flag of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("y_invert", Self::YInvert),
+            ];
+            /// Looks up a single flag by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
             }
         }
-        bitflags::bitflags! { # [derive (Debug , PartialEq , Eq , PartialOrd , Ord , Hash , Clone , Copy)] pub struct Flags : u32 { # [doc = "contents are y-inverted"] const YInvert = 1u32 ; } }
         impl From<Flags> for u32 {
             fn from(value: Flags) -> Self {
                 value.bits()
@@ -4330,273 +8699,442 @@ pub mod wlr_screencopy_unstable_v1 {
         }
         impl std::fmt::Display for Flags {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.bits().fmt(f)
+                if self.is_empty() {
+                    return match Self::ENTRIES.iter().find(|(_, value)| value.is_empty())
+                    {
+                        Some((name, _)) => f.write_str(name),
+                        None => f.write_str("0"),
+                    };
+                }
+                let mut remaining = *self;
+                let mut first = true;
+                for (name, value) in Self::ENTRIES {
+                    if value.is_empty() || !self.contains(*value)
+                        || !remaining.intersects(*value)
+                    {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    f.write_str(name)?;
+                    remaining.remove(*value);
+                    first = false;
+                }
+                if !remaining.is_empty() {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{:#x}", remaining.bits())?;
+                }
+                Ok(())
             }
         }
-        #[doc = "Trait to implement the zwlr_screencopy_frame_v1 interface. See the module level documentation for more info"]
-        pub trait ZwlrScreencopyFrameV1
-        where
-            Self: std::marker::Sync,
-        {
-            type Connection: waynest::Connection;
-            const INTERFACE: &'static str = "zwlr_screencopy_frame_v1";
-            const VERSION: u32 = 3u32;
-            #[doc = "Copy the frame to the supplied buffer. The buffer must have the"]
-            #[doc = "correct size, see zwlr_screencopy_frame_v1.buffer and"]
-            #[doc = "zwlr_screencopy_frame_v1.linux_dmabuf. The buffer needs to have a"]
-            #[doc = "supported format."]
-            #[doc = ""]
-            #[doc = "If the frame is successfully copied, \"flags\" and \"ready\" events are"]
-            #[doc = "sent. Otherwise, a \"failed\" event is sent."]
-            fn copy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                buffer: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Destroys the frame. This request can be sent at any time by the client."]
-            fn destroy(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Same as copy, except it waits until there is damage to copy."]
-            fn copy_with_damage(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                buffer: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Provides information about wl_shm buffer parameters that need to be"]
-            #[doc = "used for this frame. This event is sent once after the frame is created"]
-            #[doc = "if wl_shm buffers are supported."]
-            fn buffer(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-                format: super::super::super::core::wayland::wl_shm::Format,
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "copy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "copy_with_damage",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2o",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "buffer",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_buffer"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwlr_screencopy_frame_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {
+            ///The interface version the buffer message was introduced in
+            pub const BUFFER_SINCE: u32 = 1u32;
+            ///The interface version the buffer message was deprecated in, if any
+            pub const BUFFER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Provides information about wl_shm buffer parameters that need to be
+            ///used for this frame. This event is sent once after the frame is created
+            ///if wl_shm buffers are supported.
+            pub fn buffer<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+                format: crate::server::core::wayland::wl_shm::Format,
                 width: u32,
                 height: u32,
                 stride: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_screencopy_frame_v1#{}.buffer({}, {}, {}, {})",
-                        sender_id,
-                        format,
-                        width,
-                        height,
-                        stride
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(format.into())
-                        .put_uint(width)
-                        .put_uint(height)
-                        .put_uint(stride)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 0u16,
+                    message = "buffer", direction = "out", "args.format" = % format,
+                    "args.width" = % width, "args.height" = % height, "args.stride" = %
+                    stride,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 0u16,
+                    message = "buffer", direction = "out", "args.format" : % = format,
+                    "args.width" : % = width, "args.height" : % = height, "args.stride" :
+                    % = stride; "zwlr_screencopy_frame_v1.buffer"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(format.into())
+                    .put_uint(width)
+                    .put_uint(height)
+                    .put_uint(stride)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 0u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Provides flags about the frame. This event is sent once before the"]
-            #[doc = "\"ready\" event."]
-            fn flags(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the flags message was introduced in
+            pub const FLAGS_SINCE: u32 = 1u32;
+            ///The interface version the flags message was deprecated in, if any
+            pub const FLAGS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Provides flags about the frame. This event is sent once before the
+            ///"ready" event.
+            pub fn flags<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 flags: Flags,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_screencopy_frame_v1#{}.flags({})", sender_id, flags);
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(flags.into())
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 1u16,
+                    message = "flags", direction = "out", "args.flags" = % flags,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 1u16,
+                    message = "flags", direction = "out", "args.flags" : % = flags;
+                    "zwlr_screencopy_frame_v1.flags"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(flags.into())
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 1u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Called as soon as the frame is copied, indicating it is available"]
-            #[doc = "for reading. This event includes the time at which the presentation took place."]
-            #[doc = ""]
-            #[doc = "The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,"]
-            #[doc = "each component being an unsigned 32-bit value. Whole seconds are in"]
-            #[doc = "tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,"]
-            #[doc = "and the additional fractional part in tv_nsec as nanoseconds. Hence,"]
-            #[doc = "for valid timestamps tv_nsec must be in [0, 999999999]. The seconds part"]
-            #[doc = "may have an arbitrary offset at start."]
-            #[doc = ""]
-            #[doc = "After receiving this event, the client should destroy the object."]
-            fn ready(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the ready message was introduced in
+            pub const READY_SINCE: u32 = 1u32;
+            ///The interface version the ready message was deprecated in, if any
+            pub const READY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Called as soon as the frame is copied, indicating it is available
+            ///for reading. This event includes the time at which the presentation took place.
+            ///
+            ///The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+            ///each component being an unsigned 32-bit value. Whole seconds are in
+            ///tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+            ///and the additional fractional part in tv_nsec as nanoseconds. Hence,
+            ///for valid timestamps tv_nsec must be in [0, 999999999]. The seconds part
+            ///may have an arbitrary offset at start.
+            ///
+            ///After receiving this event, the client should destroy the object.
+            pub fn ready<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 tv_sec_hi: u32,
                 tv_sec_lo: u32,
                 tv_nsec: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_screencopy_frame_v1#{}.ready({}, {}, {})",
-                        sender_id,
-                        tv_sec_hi,
-                        tv_sec_lo,
-                        tv_nsec
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(tv_sec_hi)
-                        .put_uint(tv_sec_lo)
-                        .put_uint(tv_nsec)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 2u16,
+                    message = "ready", direction = "out", "args.tv_sec_hi" = % tv_sec_hi,
+                    "args.tv_sec_lo" = % tv_sec_lo, "args.tv_nsec" = % tv_nsec,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 2u16,
+                    message = "ready", direction = "out", "args.tv_sec_hi" : % =
+                    tv_sec_hi, "args.tv_sec_lo" : % = tv_sec_lo, "args.tv_nsec" : % =
+                    tv_nsec; "zwlr_screencopy_frame_v1.ready"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(tv_sec_hi)
+                    .put_uint(tv_sec_lo)
+                    .put_uint(tv_nsec)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 2u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event indicates that the attempted frame copy has failed."]
-            #[doc = ""]
-            #[doc = "After receiving this event, the client should destroy the object."]
-            fn failed(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_screencopy_frame_v1#{}.failed()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the failed message was introduced in
+            pub const FAILED_SINCE: u32 = 1u32;
+            ///The interface version the failed message was deprecated in, if any
+            pub const FAILED_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event indicates that the attempted frame copy has failed.
+            ///
+            ///After receiving this event, the client should destroy the object.
+            pub fn failed<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 3u16,
+                    message = "failed", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 3u16,
+                    message = "failed", direction = "out";
+                    "zwlr_screencopy_frame_v1.failed"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 3u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent right before the ready event when copy_with_damage is"]
-            #[doc = "requested. It may be generated multiple times for each copy_with_damage"]
-            #[doc = "request."]
-            #[doc = ""]
-            #[doc = "The arguments describe a box around an area that has changed since the"]
-            #[doc = "last copy request that was derived from the current screencopy manager"]
-            #[doc = "instance."]
-            #[doc = ""]
-            #[doc = "The union of all regions received between the call to copy_with_damage"]
-            #[doc = "and a ready event is the total damage since the prior ready event."]
-            fn damage(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the damage message was introduced in
+            pub const DAMAGE_SINCE: u32 = 2u32;
+            ///The interface version the damage message was deprecated in, if any
+            pub const DAMAGE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent right before the ready event when copy_with_damage is
+            ///requested. It may be generated multiple times for each copy_with_damage
+            ///request.
+            ///
+            ///The arguments describe a box around an area that has changed since the
+            ///last copy request that was derived from the current screencopy manager
+            ///instance.
+            ///
+            ///The union of all regions received between the call to copy_with_damage
+            ///and a ready event is the total damage since the prior ready event.
+            ///
+            ///Available since version 2
+            pub fn damage<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 x: u32,
                 y: u32,
                 width: u32,
                 height: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_screencopy_frame_v1#{}.damage({}, {}, {}, {})",
-                        sender_id,
-                        x,
-                        y,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(x)
-                        .put_uint(y)
-                        .put_uint(width)
-                        .put_uint(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 4u16,
+                    message = "damage", direction = "out", "args.x" = % x, "args.y" = %
+                    y, "args.width" = % width, "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 4u16,
+                    message = "damage", direction = "out", "args.x" : % = x, "args.y" : %
+                    = y, "args.width" : % = width, "args.height" : % = height;
+                    "zwlr_screencopy_frame_v1.damage"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(x)
+                    .put_uint(y)
+                    .put_uint(width)
+                    .put_uint(height)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 4u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "Provides information about linux-dmabuf buffer parameters that need to"]
-            #[doc = "be used for this frame. This event is sent once after the frame is"]
-            #[doc = "created if linux-dmabuf buffers are supported."]
-            fn linux_dmabuf(
-                &self,
-                connection: &mut Self::Connection,
+            ///The interface version the linux_dmabuf message was introduced in
+            pub const LINUX_DMABUF_SINCE: u32 = 3u32;
+            ///The interface version the linux_dmabuf message was deprecated in, if any
+            pub const LINUX_DMABUF_DEPRECATED_SINCE: Option<u32> = None;
+            ///Provides information about linux-dmabuf buffer parameters that need to
+            ///be used for this frame. This event is sent once after the frame is
+            ///created if linux-dmabuf buffers are supported.
+            ///
+            ///Available since version 3
+            pub fn linux_dmabuf<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
                 sender_id: waynest::ObjectId,
                 format: u32,
                 width: u32,
                 height: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!(
-                        "-> zwlr_screencopy_frame_v1#{}.linux_dmabuf({}, {}, {})",
-                        sender_id,
-                        format,
-                        width,
-                        height
-                    );
-                    let payload = waynest::PayloadBuilder::new()
-                        .put_uint(format)
-                        .put_uint(width)
-                        .put_uint(height)
-                        .build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 5u16,
+                    message = "linux_dmabuf", direction = "out", "args.format" = %
+                    format, "args.width" = % width, "args.height" = % height,
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 5u16,
+                    message = "linux_dmabuf", direction = "out", "args.format" : % =
+                    format, "args.width" : % = width, "args.height" : % = height;
+                    "zwlr_screencopy_frame_v1.linux_dmabuf"
+                );
+                let payload = waynest::PayloadBuilder::new()
+                    .put_uint(format)
+                    .put_uint(width)
+                    .put_uint(height)
+                    .build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 5u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
-            #[doc = "This event is sent once after all buffer events have been sent."]
-            #[doc = ""]
-            #[doc = "The client should proceed to create a buffer of one of the supported"]
-            #[doc = "types, and send a \"copy\" request."]
-            fn buffer_done(
-                &self,
-                connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
-                async move {
-                    #[cfg(feature = "tracing")]
-                    tracing::debug!("-> zwlr_screencopy_frame_v1#{}.buffer_done()", sender_id,);
-                    let payload = waynest::PayloadBuilder::new().build();
-                    futures_util::SinkExt::send(
-                        connection,
+            ///The interface version the buffer_done message was introduced in
+            pub const BUFFER_DONE_SINCE: u32 = 3u32;
+            ///The interface version the buffer_done message was deprecated in, if any
+            pub const BUFFER_DONE_DEPRECATED_SINCE: Option<u32> = None;
+            ///This event is sent once after all buffer events have been sent.
+            ///
+            ///The client should proceed to create a buffer of one of the supported
+            ///types, and send a "copy" request.
+            ///
+            ///Available since version 3
+            pub fn buffer_done<S: waynest::MessageSender + ?Sized>(
+                connection: &S,
+                sender_id: waynest::ObjectId,
+            ) -> Result<(), S::Error> {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    target : "waynest", tracing::Level::DEBUG, interface =
+                    "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode = 6u16,
+                    message = "buffer_done", direction = "out",
+                );
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::log!(
+                    target : "waynest", log::Level::Debug, interface =
+                    "zwlr_screencopy_frame_v1", object_id : % = sender_id, opcode = 6u16,
+                    message = "buffer_done", direction = "out";
+                    "zwlr_screencopy_frame_v1.buffer_done"
+                );
+                let payload = waynest::PayloadBuilder::new().build();
+                connection
+                    .send_message(
                         waynest::Message::new(sender_id, 6u16, payload),
+                        vec![],
                     )
-                    .await
-                    .map_err(<Self::Connection as waynest::Connection>::Error::from)
-                }
             }
+        }
+        ///Trait to implement the zwlr_screencopy_frame_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_screencopy_frame_v1` interface",
+            label = "`ZwlrScreencopyFrameV1` is not implemented for `{Self}`"
+        )]
+        pub trait ZwlrScreencopyFrameV1
+        where
+            Self: std::marker::Sync,
+        {
+            type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
+            const INTERFACE: &'static str = "zwlr_screencopy_frame_v1";
+            const VERSION: u32 = 3u32;
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the copy message was introduced in
+            const COPY_SINCE: u32 = 1u32;
+            ///The interface version the copy message was deprecated in, if any
+            const COPY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Copy the frame to the supplied buffer. The buffer must have the
+            ///correct size, see zwlr_screencopy_frame_v1.buffer and
+            ///zwlr_screencopy_frame_v1.linux_dmabuf. The buffer needs to have a
+            ///supported format.
+            ///
+            ///If the frame is successfully copied, "flags" and "ready" events are
+            ///sent. Otherwise, a "failed" event is sent.
+            fn copy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                buffer: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///Destroys the frame. This request can be sent at any time by the client.
+            fn destroy(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the copy_with_damage message was introduced in
+            const COPY_WITH_DAMAGE_SINCE: u32 = 2u32;
+            ///The interface version the copy_with_damage message was deprecated in, if any
+            const COPY_WITH_DAMAGE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Same as copy, except it waits until there is damage to copy.
+            ///
+            ///Available since version 2
+            fn copy_with_damage(
+                &self,
+                connection: &mut Self::Connection,
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+                buffer: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -4605,57 +9143,136 @@ pub mod wlr_screencopy_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_screencopy_frame_v1#{}.copy({})",
-                                sender_id,
-                                buffer
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode
+                                = 0u16, message = "copy", direction = "in", "args.buffer" =
+                                % buffer,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_screencopy_frame_v1", object_id : % = sender_id,
+                                opcode = 0u16, message = "copy", direction = "in",
+                                "args.buffer" : % = buffer; "zwlr_screencopy_frame_v1.copy"
                             );
-                            self.copy(connection, sender_id, buffer).await
+                            self.copy(connection, state, sender_id, buffer).await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_screencopy_frame_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode
+                                = 1u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_screencopy_frame_v1", object_id : % = sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in";
+                                "zwlr_screencopy_frame_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         2u16 => {
                             let buffer = message
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_screencopy_frame_v1#{}.copy_with_damage({})",
-                                sender_id,
-                                buffer
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_screencopy_frame_v1", object_id = % sender_id, opcode
+                                = 2u16, message = "copy_with_damage", direction = "in",
+                                "args.buffer" = % buffer,
                             );
-                            self.copy_with_damage(connection, sender_id, buffer).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_screencopy_frame_v1", object_id : % = sender_id,
+                                opcode = 2u16, message = "copy_with_damage", direction =
+                                "in", "args.buffer" : % = buffer;
+                                "zwlr_screencopy_frame_v1.copy_with_damage"
+                            );
+                            self.copy_with_damage(connection, state, sender_id, buffer)
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
 }
-#[allow(clippy::module_inception)]
+#[cfg(feature = "wlr-virtual-pointer-unstable-v1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wlr-virtual-pointer-unstable-v1")))]
+#[allow(clippy::module_inception, clippy::doc_lazy_continuation)]
 pub mod wlr_virtual_pointer_unstable_v1 {
-    #[doc = "This protocol allows clients to emulate a physical pointer device. The"]
-    #[doc = "requests are mostly mirror opposites of those specified in wl_pointer."]
+    ///This protocol allows clients to emulate a physical pointer device. The
+    ///requests are mostly mirror opposites of those specified in wl_pointer.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_virtual_pointer_v1 {
         #[repr(u32)]
         #[non_exhaustive]
         #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         pub enum Error {
-            #[doc = "client sent invalid axis enumeration value"]
+            ///client sent invalid axis enumeration value
             InvalidAxis = 0u32,
-            #[doc = "client sent invalid axis source enumeration value"]
+            ///client sent invalid axis source enumeration value
             InvalidAxisSource = 1u32,
         }
+        #[allow(deprecated)]
+        impl Error {
+            /// The interface version this enum was introduced in
+            pub const SINCE: u32 = 1u32;
+            /// The interface version this enum was deprecated in, if any
+            pub const DEPRECATED_SINCE: Option<u32> = None;
+            /// The interface version this entry was introduced in
+            pub const fn since(&self) -> u32 {
+                match self {
+                    Self::InvalidAxis => 1u32,
+                    Self::InvalidAxisSource => 1u32,
+                }
+            }
+            /// The interface version this entry was deprecated in, if any
+            pub const fn deprecated_since(&self) -> Option<u32> {
+                match self {
+                    Self::InvalidAxis => None,
+                    Self::InvalidAxisSource => None,
+                }
+            }
+            /// Every entry of this enum along with its name in the protocol XML
+            pub const ENTRIES: &'static [(&'static str, Self)] = &[
+                ("invalid_axis", Self::InvalidAxis),
+                ("invalid_axis_source", Self::InvalidAxisSource),
+            ];
+            /// The name of this entry in the protocol XML
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    Self::InvalidAxis => "invalid_axis",
+                    Self::InvalidAxisSource => "invalid_axis_source",
+                }
+            }
+            /// Looks up an entry by its name in the protocol XML
+            pub fn from_protocol_name(name: &str) -> Option<Self> {
+                Self::ENTRIES
+                    .iter()
+                    .find(|(entry, _)| *entry == name)
+                    .map(|(_, value)| *value)
+            }
+        }
         impl From<Error> for u32 {
             fn from(value: Error) -> Self {
                 value as u32
             }
         }
+        impl From<Error> for i32 {
+            fn from(value: Error) -> Self {
+                value as i32
+            }
+        }
+        #[allow(deprecated)]
         impl TryFrom<u32> for Error {
             type Error = waynest::ProtocolError;
             fn try_from(v: u32) -> Result<Self, Self::Error> {
@@ -4666,108 +9283,423 @@ pub mod wlr_virtual_pointer_unstable_v1 {
                 }
             }
         }
+        impl TryFrom<i32> for Error {
+            type Error = waynest::ProtocolError;
+            fn try_from(v: i32) -> Result<Self, Self::Error> {
+                Self::try_from(v as u32)
+            }
+        }
         impl std::fmt::Display for Error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                (*self as u32).fmt(f)
+                f.write_str(self.as_str())
             }
         }
-        #[doc = "Trait to implement the zwlr_virtual_pointer_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "motion",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uff",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dx",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "dy",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "motion_absolute",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuuuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "x_extent",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "y_extent",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "button",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "button",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "state",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.button_state"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uuf",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "value",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "frame",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "axis_source",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "u",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "axis_source",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis_source"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_stop",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uu",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis"),
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "axis_discrete",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "uufi",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "time",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "axis",
+                        kind: waynest::ArgKind::Uint,
+                        interface: None,
+                        r#enum: Some("wl_pointer.axis"),
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "value",
+                        kind: waynest::ArgKind::Fixed,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                    waynest::ArgInfo {
+                        name: "discrete",
+                        kind: waynest::ArgKind::Int,
+                        interface: None,
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+        ];
+        ///Sends the events of the zwlr_virtual_pointer_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_virtual_pointer_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_virtual_pointer_v1` interface",
+            label = "`ZwlrVirtualPointerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrVirtualPointerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_virtual_pointer_v1";
             const VERSION: u32 = 2u32;
-            #[doc = "The pointer has moved by a relative amount to the previous request."]
-            #[doc = ""]
-            #[doc = "Values are in the global compositor space."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the motion message was introduced in
+            const MOTION_SINCE: u32 = 1u32;
+            ///The interface version the motion message was deprecated in, if any
+            const MOTION_DEPRECATED_SINCE: Option<u32> = None;
+            ///The pointer has moved by a relative amount to the previous request.
+            ///
+            ///Values are in the global compositor space.
             fn motion(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 dx: waynest::Fixed,
                 dy: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "The pointer has moved in an absolute coordinate frame."]
-            #[doc = ""]
-            #[doc = "Value of x can range from 0 to x_extent, value of y can range from 0"]
-            #[doc = "to y_extent."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the motion_absolute message was introduced in
+            const MOTION_ABSOLUTE_SINCE: u32 = 1u32;
+            ///The interface version the motion_absolute message was deprecated in, if any
+            const MOTION_ABSOLUTE_DEPRECATED_SINCE: Option<u32> = None;
+            ///The pointer has moved in an absolute coordinate frame.
+            ///
+            ///Value of x can range from 0 to x_extent, value of y can range from 0
+            ///to y_extent.
             fn motion_absolute(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 x: u32,
                 y: u32,
                 x_extent: u32,
                 y_extent: u32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "A button was pressed or released."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the button message was introduced in
+            const BUTTON_SINCE: u32 = 1u32;
+            ///The interface version the button message was deprecated in, if any
+            const BUTTON_DEPRECATED_SINCE: Option<u32> = None;
+            ///A button was pressed or released.
             fn button(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 time: u32,
                 button: u32,
-                state: super::super::super::core::wayland::wl_pointer::ButtonState,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Scroll and other axis requests."]
+                state_arg: crate::server::core::wayland::wl_pointer::ButtonState,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the axis message was introduced in
+            const AXIS_SINCE: u32 = 1u32;
+            ///The interface version the axis message was deprecated in, if any
+            const AXIS_DEPRECATED_SINCE: Option<u32> = None;
+            ///Scroll and other axis requests.
             fn axis(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 time: u32,
-                axis: super::super::super::core::wayland::wl_pointer::Axis,
+                axis: crate::server::core::wayland::wl_pointer::Axis,
                 value: waynest::Fixed,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Indicates the set of events that logically belong together."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the frame message was introduced in
+            const FRAME_SINCE: u32 = 1u32;
+            ///The interface version the frame message was deprecated in, if any
+            const FRAME_DEPRECATED_SINCE: Option<u32> = None;
+            ///Indicates the set of events that logically belong together.
             fn frame(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Source information for scroll and other axis."]
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the axis_source message was introduced in
+            const AXIS_SOURCE_SINCE: u32 = 1u32;
+            ///The interface version the axis_source message was deprecated in, if any
+            const AXIS_SOURCE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Source information for scroll and other axis.
             fn axis_source(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-                axis_source: super::super::super::core::wayland::wl_pointer::AxisSource,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Stop notification for scroll and other axes."]
+                axis_source: crate::server::core::wayland::wl_pointer::AxisSource,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the axis_stop message was introduced in
+            const AXIS_STOP_SINCE: u32 = 1u32;
+            ///The interface version the axis_stop message was deprecated in, if any
+            const AXIS_STOP_DEPRECATED_SINCE: Option<u32> = None;
+            ///Stop notification for scroll and other axes.
             fn axis_stop(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 time: u32,
-                axis: super::super::super::core::wayland::wl_pointer::Axis,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Discrete step information for scroll and other axes."]
-            #[doc = ""]
-            #[doc = "This event allows the client to extend data normally sent using the axis"]
-            #[doc = "event with discrete value."]
+                axis: crate::server::core::wayland::wl_pointer::Axis,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the axis_discrete message was introduced in
+            const AXIS_DISCRETE_SINCE: u32 = 1u32;
+            ///The interface version the axis_discrete message was deprecated in, if any
+            const AXIS_DISCRETE_DEPRECATED_SINCE: Option<u32> = None;
+            ///Discrete step information for scroll and other axes.
+            ///
+            ///This event allows the client to extend data normally sent using the axis
+            ///event with discrete value.
             fn axis_discrete(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 time: u32,
-                axis: super::super::super::core::wayland::wl_pointer::Axis,
+                axis: crate::server::core::wayland::wl_pointer::Axis,
                 value: waynest::Fixed,
                 discrete: i32,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///
+            ///Available since version 1
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -4776,14 +9708,21 @@ pub mod wlr_virtual_pointer_unstable_v1 {
                             let dx = message.fixed()?;
                             let dy = message.fixed()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.motion({}, {}, {})",
-                                sender_id,
-                                time,
-                                dx,
-                                dy
-                            );
-                            self.motion(connection, sender_id, time, dx, dy).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                0u16, message = "motion", direction = "in", "args.time" = %
+                                time, "args.dx" = % dx, "args.dy" = % dy,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 0u16, message = "motion", direction = "in", "args.time" :
+                                % = time, "args.dx" : % = dx, "args.dy" : % = dy;
+                                "zwlr_virtual_pointer_v1.motion"
+                            );
+                            self.motion(connection, state, sender_id, time, dx, dy).await
                         }
                         1u16 => {
                             let time = message.uint()?;
@@ -4792,158 +9731,357 @@ pub mod wlr_virtual_pointer_unstable_v1 {
                             let x_extent = message.uint()?;
                             let y_extent = message.uint()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.motion_absolute({}, {}, {}, {}, {})",
-                                sender_id,
-                                time,
-                                x,
-                                y,
-                                x_extent,
-                                y_extent
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                1u16, message = "motion_absolute", direction = "in",
+                                "args.time" = % time, "args.x" = % x, "args.y" = % y,
+                                "args.x_extent" = % x_extent, "args.y_extent" = % y_extent,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 1u16, message = "motion_absolute", direction = "in",
+                                "args.time" : % = time, "args.x" : % = x, "args.y" : % = y,
+                                "args.x_extent" : % = x_extent, "args.y_extent" : % =
+                                y_extent; "zwlr_virtual_pointer_v1.motion_absolute"
                             );
                             self.motion_absolute(
-                                connection, sender_id, time, x, y, x_extent, y_extent,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    time,
+                                    x,
+                                    y,
+                                    x_extent,
+                                    y_extent,
+                                )
+                                .await
                         }
                         2u16 => {
                             let time = message.uint()?;
                             let button = message.uint()?;
-                            let state = message.uint()?;
+                            let state_arg: crate::server::core::wayland::wl_pointer::ButtonState = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.button({}, {}, {})",
-                                sender_id,
-                                time,
-                                button,
-                                state
-                            );
-                            self.button(connection, sender_id, time, button, state.try_into()?)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                2u16, message = "button", direction = "in", "args.time" = %
+                                time, "args.button" = % button, "args.state" = % state_arg,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 2u16, message = "button", direction = "in", "args.time" :
+                                % = time, "args.button" : % = button, "args.state" : % =
+                                state_arg; "zwlr_virtual_pointer_v1.button"
+                            );
+                            self.button(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    time,
+                                    button,
+                                    state_arg,
+                                )
                                 .await
                         }
                         3u16 => {
                             let time = message.uint()?;
-                            let axis = message.uint()?;
+                            let axis: crate::server::core::wayland::wl_pointer::Axis = message
+                                .uint()?
+                                .try_into()?;
                             let value = message.fixed()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.axis({}, {}, {})",
-                                sender_id,
-                                time,
-                                axis,
-                                value
-                            );
-                            self.axis(connection, sender_id, time, axis.try_into()?, value)
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                3u16, message = "axis", direction = "in", "args.time" = %
+                                time, "args.axis" = % axis, "args.value" = % value,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 3u16, message = "axis", direction = "in", "args.time" : %
+                                = time, "args.axis" : % = axis, "args.value" : % = value;
+                                "zwlr_virtual_pointer_v1.axis"
+                            );
+                            self.axis(connection, state, sender_id, time, axis, value)
                                 .await
                         }
                         4u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_virtual_pointer_v1#{}.frame()", sender_id,);
-                            self.frame(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                4u16, message = "frame", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 4u16, message = "frame", direction = "in";
+                                "zwlr_virtual_pointer_v1.frame"
+                            );
+                            self.frame(connection, state, sender_id).await
                         }
                         5u16 => {
-                            let axis_source = message.uint()?;
+                            let axis_source: crate::server::core::wayland::wl_pointer::AxisSource = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.axis_source({})",
-                                sender_id,
-                                axis_source
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                5u16, message = "axis_source", direction = "in",
+                                "args.axis_source" = % axis_source,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 5u16, message = "axis_source", direction = "in",
+                                "args.axis_source" : % = axis_source;
+                                "zwlr_virtual_pointer_v1.axis_source"
                             );
-                            self.axis_source(connection, sender_id, axis_source.try_into()?)
+                            self.axis_source(connection, state, sender_id, axis_source)
                                 .await
                         }
                         6u16 => {
                             let time = message.uint()?;
-                            let axis = message.uint()?;
+                            let axis: crate::server::core::wayland::wl_pointer::Axis = message
+                                .uint()?
+                                .try_into()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.axis_stop({}, {})",
-                                sender_id,
-                                time,
-                                axis
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                6u16, message = "axis_stop", direction = "in", "args.time" =
+                                % time, "args.axis" = % axis,
                             );
-                            self.axis_stop(connection, sender_id, time, axis.try_into()?)
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 6u16, message = "axis_stop", direction = "in", "args.time"
+                                : % = time, "args.axis" : % = axis;
+                                "zwlr_virtual_pointer_v1.axis_stop"
+                            );
+                            self.axis_stop(connection, state, sender_id, time, axis)
                                 .await
                         }
                         7u16 => {
                             let time = message.uint()?;
-                            let axis = message.uint()?;
+                            let axis: crate::server::core::wayland::wl_pointer::Axis = message
+                                .uint()?
+                                .try_into()?;
                             let value = message.fixed()?;
                             let discrete = message.int()?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_v1#{}.axis_discrete({}, {}, {}, {})",
-                                sender_id,
-                                time,
-                                axis,
-                                value,
-                                discrete
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                7u16, message = "axis_discrete", direction = "in",
+                                "args.time" = % time, "args.axis" = % axis, "args.value" = %
+                                value, "args.discrete" = % discrete,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 7u16, message = "axis_discrete", direction = "in",
+                                "args.time" : % = time, "args.axis" : % = axis, "args.value"
+                                : % = value, "args.discrete" : % = discrete;
+                                "zwlr_virtual_pointer_v1.axis_discrete"
                             );
                             self.axis_discrete(
-                                connection,
-                                sender_id,
-                                time,
-                                axis.try_into()?,
-                                value,
-                                discrete,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    time,
+                                    axis,
+                                    value,
+                                    discrete,
+                                )
+                                .await
                         }
                         8u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!("zwlr_virtual_pointer_v1#{}.destroy()", sender_id,);
-                            self.destroy(connection, sender_id).await
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_v1", object_id = % sender_id, opcode =
+                                8u16, message = "destroy", direction = "in",
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_v1", object_id : % = sender_id, opcode
+                                = 8u16, message = "destroy", direction = "in";
+                                "zwlr_virtual_pointer_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
         }
     }
-    #[doc = "This object allows clients to create individual virtual pointer objects."]
+    ///This object allows clients to create individual virtual pointer objects.
     #[allow(clippy::too_many_arguments)]
     pub mod zwlr_virtual_pointer_manager_v1 {
-        #[doc = "Trait to implement the zwlr_virtual_pointer_manager_v1 interface. See the module level documentation for more info"]
+        static REQUESTS: &[waynest::MessageInfo] = &[
+            waynest::MessageInfo {
+                name: "create_virtual_pointer",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "?on",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_virtual_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+            waynest::MessageInfo {
+                name: "destroy",
+                since: 1u32,
+                deprecated_since: None,
+                destructor: true,
+                signature: "",
+                args: &[],
+            },
+            waynest::MessageInfo {
+                name: "create_virtual_pointer_with_output",
+                since: 2u32,
+                deprecated_since: None,
+                destructor: false,
+                signature: "2?o?on",
+                args: &[
+                    waynest::ArgInfo {
+                        name: "seat",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_seat"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "output",
+                        kind: waynest::ArgKind::Object,
+                        interface: Some("wl_output"),
+                        r#enum: None,
+                        allow_null: true,
+                    },
+                    waynest::ArgInfo {
+                        name: "id",
+                        kind: waynest::ArgKind::NewId,
+                        interface: Some("zwlr_virtual_pointer_v1"),
+                        r#enum: None,
+                        allow_null: false,
+                    },
+                ],
+            },
+        ];
+        ///Sends the events of the zwlr_virtual_pointer_manager_v1 interface through any [`waynest::MessageSender`]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Events;
+        impl Events {}
+        ///Trait to implement the zwlr_virtual_pointer_manager_v1 interface. See the module level documentation for more info
+        #[diagnostic::on_unimplemented(
+            message = "`{Self}` does not implement the `zwlr_virtual_pointer_manager_v1` interface",
+            label = "`ZwlrVirtualPointerManagerV1` is not implemented for `{Self}`"
+        )]
         pub trait ZwlrVirtualPointerManagerV1
         where
             Self: std::marker::Sync,
         {
             type Connection: waynest::Connection;
+            /// State shared with every other object, such as the whole
+            /// compositor, which handlers get exclusive access to
+            type State: Send;
             const INTERFACE: &'static str = "zwlr_virtual_pointer_manager_v1";
             const VERSION: u32 = 2u32;
-            #[doc = "Creates a new virtual pointer. The optional seat is a suggestion to the"]
-            #[doc = "compositor."]
+            /// The signatures of the messages handled by this trait, by opcode
+            const REQUESTS: &'static [waynest::MessageInfo] = REQUESTS;
+            ///The interface version the create_virtual_pointer message was introduced in
+            const CREATE_VIRTUAL_POINTER_SINCE: u32 = 1u32;
+            ///The interface version the create_virtual_pointer message was deprecated in, if any
+            const CREATE_VIRTUAL_POINTER_DEPRECATED_SINCE: Option<u32> = None;
+            ///Creates a new virtual pointer. The optional seat is a suggestion to the
+            ///compositor.
             fn create_virtual_pointer(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: Option<waynest::ObjectId>,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the destroy message was introduced in
+            const DESTROY_SINCE: u32 = 1u32;
+            ///The interface version the destroy message was deprecated in, if any
+            const DESTROY_DEPRECATED_SINCE: Option<u32> = None;
+            ///
+            ///Available since version 1
             fn destroy(
                 &self,
                 connection: &mut Self::Connection,
-                sender_id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
-            #[doc = "Creates a new virtual pointer. The seat and the output arguments are"]
-            #[doc = "optional. If the seat argument is set, the compositor should assign the"]
-            #[doc = "input device to the requested seat. If the output argument is set, the"]
-            #[doc = "compositor should map the input device to the requested output."]
+                state: &mut Self::State,
+                sender_id: waynest::ObjectId,
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            ///The interface version the create_virtual_pointer_with_output message was introduced in
+            const CREATE_VIRTUAL_POINTER_WITH_OUTPUT_SINCE: u32 = 2u32;
+            ///The interface version the create_virtual_pointer_with_output message was deprecated in, if any
+            const CREATE_VIRTUAL_POINTER_WITH_OUTPUT_DEPRECATED_SINCE: Option<u32> = None;
+            ///Creates a new virtual pointer. The seat and the output arguments are
+            ///optional. If the seat argument is set, the compositor should assign the
+            ///input device to the requested seat. If the output argument is set, the
+            ///compositor should map the input device to the requested output.
+            ///
+            ///Available since version 2
             fn create_virtual_pointer_with_output(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 seat: Option<waynest::ObjectId>,
                 output: Option<waynest::ObjectId>,
                 id: waynest::ObjectId,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send;
+            #[allow(deprecated)]
             fn handle_request(
                 &self,
                 connection: &mut Self::Connection,
+                state: &mut Self::State,
                 sender_id: waynest::ObjectId,
                 message: &mut waynest::Message,
-            ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send
-            {
+            ) -> impl Future<
+                Output = Result<(), <Self::Connection as waynest::Connection>::Error>,
+            > + Send {
                 async move {
                     #[allow(clippy::match_single_binding)]
                     match message.opcode() {
@@ -4953,22 +10091,47 @@ pub mod wlr_virtual_pointer_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_manager_v1#{}.create_virtual_pointer({}, {})",
-                                sender_id,
-                                seat.as_ref().map_or("null".to_string(), |v| v.to_string()),
-                                id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_manager_v1", object_id = % sender_id,
+                                opcode = 0u16, message = "create_virtual_pointer", direction
+                                = "in", "args.seat" = % seat.as_ref().map_or("null"
+                                .to_string(), | v | v.to_string()), "args.id" = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_manager_v1", object_id : % =
+                                sender_id, opcode = 0u16, message =
+                                "create_virtual_pointer", direction = "in", "args.seat" : %
+                                = seat.as_ref().map_or("null".to_string(), | v | v
+                                .to_string()), "args.id" : % = id;
+                                "zwlr_virtual_pointer_manager_v1.create_virtual_pointer"
                             );
-                            self.create_virtual_pointer(connection, sender_id, seat, id)
+                            self.create_virtual_pointer(
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    seat,
+                                    id,
+                                )
                                 .await
                         }
                         1u16 => {
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_manager_v1#{}.destroy()",
-                                sender_id,
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_manager_v1", object_id = % sender_id,
+                                opcode = 1u16, message = "destroy", direction = "in",
                             );
-                            self.destroy(connection, sender_id).await
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_manager_v1", object_id : % =
+                                sender_id, opcode = 1u16, message = "destroy", direction =
+                                "in"; "zwlr_virtual_pointer_manager_v1.destroy"
+                            );
+                            self.destroy(connection, state, sender_id).await
                         }
                         2u16 => {
                             let seat = message.object()?;
@@ -4977,21 +10140,41 @@ pub mod wlr_virtual_pointer_unstable_v1 {
                                 .object()?
                                 .ok_or(waynest::ProtocolError::MalformedPayload)?;
                             #[cfg(feature = "tracing")]
-                            tracing::debug!(
-                                "zwlr_virtual_pointer_manager_v1#{}.create_virtual_pointer_with_output({}, {}, {})",
-                                sender_id,
-                                seat.as_ref().map_or("null".to_string(), |v| v.to_string()),
-                                output
-                                    .as_ref()
-                                    .map_or("null".to_string(), |v| v.to_string()),
-                                id
+                            tracing::event!(
+                                target : "waynest", tracing::Level::DEBUG, interface =
+                                "zwlr_virtual_pointer_manager_v1", object_id = % sender_id,
+                                opcode = 2u16, message =
+                                "create_virtual_pointer_with_output", direction = "in",
+                                "args.seat" = % seat.as_ref().map_or("null".to_string(), | v
+                                | v.to_string()), "args.output" = % output.as_ref()
+                                .map_or("null".to_string(), | v | v.to_string()), "args.id"
+                                = % id,
+                            );
+                            #[cfg(all(feature = "log", not(feature = "tracing")))]
+                            log::log!(
+                                target : "waynest", log::Level::Debug, interface =
+                                "zwlr_virtual_pointer_manager_v1", object_id : % =
+                                sender_id, opcode = 2u16, message =
+                                "create_virtual_pointer_with_output", direction = "in",
+                                "args.seat" : % = seat.as_ref().map_or("null".to_string(), |
+                                v | v.to_string()), "args.output" : % = output.as_ref()
+                                .map_or("null".to_string(), | v | v.to_string()), "args.id"
+                                : % = id;
+                                "zwlr_virtual_pointer_manager_v1.create_virtual_pointer_with_output"
                             );
                             self.create_virtual_pointer_with_output(
-                                connection, sender_id, seat, output, id,
-                            )
-                            .await
+                                    connection,
+                                    state,
+                                    sender_id,
+                                    seat,
+                                    output,
+                                    id,
+                                )
+                                .await
+                        }
+                        opcode => {
+                            Err(waynest::ProtocolError::UnknownOpcode(opcode).into())
                         }
-                        opcode => Err(waynest::ProtocolError::UnknownOpcode(opcode).into()),
                     }
                 }
             }
@@ -0,0 +1,187 @@
+use std::{
+    any::Any,
+    future::Future,
+    hint::black_box,
+    os::fd::OwnedFd,
+    pin::{Pin, pin},
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use futures_core::Stream;
+use futures_sink::Sink;
+use waynest::{Message, ObjectId, PayloadBuilder, ProtocolError};
+use waynest_server::{ObjectStore, RequestDispatcher, SlabStore, Store};
+
+/// Objects created by a client, about what a desktop client with a few windows ends up with.
+const CLIENT_OBJECTS: u32 = 1000;
+
+/// Objects created by the server, such as `wl_data_offer`s.
+const SERVER_OBJECTS: u32 = 16;
+
+struct NullConnection;
+
+impl Stream for NullConnection {
+    type Item = Result<Message, ProtocolError>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(None)
+    }
+}
+
+impl Sink<Message> for NullConnection {
+    type Error = ProtocolError;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, _item: Message) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl waynest::Connection for NullConnection {
+    type Error = ProtocolError;
+
+    fn fd(&mut self) -> Result<OwnedFd, ProtocolError> {
+        Err(ProtocolError::MissingFd)
+    }
+
+    fn push_fd(&mut self, _fd: OwnedFd) {}
+}
+
+/// An object decoding a `wl_surface.damage`-like request.
+struct Surface;
+
+#[async_trait::async_trait]
+impl RequestDispatcher for Surface {
+    type Connection = NullConnection;
+    type Error = ProtocolError;
+    type State = ();
+
+    fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static> {
+        self
+    }
+
+    async fn dispatch_request(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+        message: &mut Message,
+    ) -> Result<(), ProtocolError> {
+        black_box((
+            message.int()?,
+            message.int()?,
+            message.int()?,
+            message.int()?,
+        ));
+        Ok(())
+    }
+}
+
+fn filled<S: ObjectStore<Connection = NullConnection, Error = ProtocolError, State = ()>>(
+    mut store: S,
+) -> S {
+    let server = (0..SERVER_OBJECTS).map(|i| ObjectId::SERVER_START.as_raw() + i);
+
+    for id in (1..=CLIENT_OBJECTS).chain(server) {
+        let id = ObjectId::new(id).unwrap();
+        assert!(store.insert(id, Arc::new(Surface), 1, None).is_ok());
+    }
+
+    store
+}
+
+/// Ids spread over both ranges, in an order that defeats any locality of the lookups.
+fn ids() -> Vec<ObjectId> {
+    (0..CLIENT_OBJECTS)
+        .map(|i| (i * 7919) % CLIENT_OBJECTS + 1)
+        .chain((0..SERVER_OBJECTS).map(|i| ObjectId::SERVER_START.as_raw() + i))
+        .map(|id| ObjectId::new(id).unwrap())
+        .collect()
+}
+
+/// Every handler completes immediately, so a single poll is enough.
+fn run<F: Future<Output = Result<(), ProtocolError>>>(future: F) {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    match pin!(future).poll(&mut cx) {
+        Poll::Ready(result) => result.expect("Failed to dispatch"),
+        Poll::Pending => unreachable!(),
+    }
+}
+
+fn lookup<S: ObjectStore<Connection = NullConnection, Error = ProtocolError, State = ()>>(
+    store: &S,
+    ids: &[ObjectId],
+) {
+    for id in ids {
+        black_box(store.get(*id));
+    }
+}
+
+fn dispatch<S: ObjectStore<Connection = NullConnection, Error = ProtocolError, State = ()>>(
+    store: &S,
+    messages: &[Message],
+) {
+    let mut connection = NullConnection;
+
+    for message in messages {
+        let sender_id = message.object_id();
+        let object = store.get(sender_id).unwrap();
+
+        run(object.dispatch_request(&mut connection, &mut (), sender_id, &mut message.clone()));
+    }
+}
+
+fn stores(c: &mut Criterion) {
+    let btree = filled(Store::new());
+    let slab = filled(SlabStore::new());
+
+    let ids = ids();
+    let messages: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            let payload = PayloadBuilder::new()
+                .put_int(0)
+                .put_int(0)
+                .put_int(1920)
+                .put_int(1080)
+                .build();
+
+            Message::new(*id, 0, payload)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("lookup");
+    group.bench_function(BenchmarkId::from_parameter("btree"), |b| {
+        b.iter(|| lookup(&btree, &ids))
+    });
+    group.bench_function(BenchmarkId::from_parameter("slab"), |b| {
+        b.iter(|| lookup(&slab, &ids))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("dispatch");
+    group.bench_function(BenchmarkId::from_parameter("btree"), |b| {
+        b.iter(|| dispatch(&btree, &messages))
+    });
+    group.bench_function(BenchmarkId::from_parameter("slab"), |b| {
+        b.iter(|| dispatch(&slab, &messages))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, stores);
+criterion_main!(benches);
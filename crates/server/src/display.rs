@@ -0,0 +1,997 @@
+use std::{
+    fmt,
+    future::{Future, poll_fn},
+    io,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::Arc,
+    task::Poll,
+};
+
+use futures_core::Stream;
+use tokio::{
+    net::UnixStream,
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+use waynest::{
+    Arg, ArgKind, Args, AsProtocolError, ConnectionHandle, Message, MessageInfo, MessageSender,
+    ObjectId, Outbox, PayloadBuilder, ProtocolError, Socket,
+};
+
+use crate::{Client, Listener, ObjectStore, StoreObject};
+
+/// The opcode of the `wl_display.error` event.
+const ERROR_OPCODE: u16 = 0;
+
+/// The `invalid_object` entry of the `wl_display.error` enum.
+pub(crate) const INVALID_OBJECT: u32 = 0;
+
+/// The `invalid_method` entry of the `wl_display.error` enum.
+const INVALID_METHOD: u32 = 1;
+
+/// The error type of the objects owned by a client.
+pub type ClientError<C> = <<C as Client>::Store as ObjectStore>::Error;
+
+/// The shared state the objects of a client are dispatched with.
+type ClientState<C> = <<C as Client>::Store as ObjectStore>::State;
+
+/// Work run by the dispatch loop with exclusive access to the shared state.
+type Job<T> =
+    Box<dyn for<'a> FnOnce(&'a mut T) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send>;
+
+/// Hooks called by a [`Display`] over the lifetime of every client.
+pub trait Server: Send + Sync + 'static {
+    /// The state of a single client, which is also the connection its requests are dispatched
+    /// with.
+    type Client: Client<Store: ObjectStore<Connection = Self::Client, State = Self::State>>
+        + waynest::Connection
+        + 'static;
+
+    /// The state shared by every client, such as the scene graph or the focus of each seat.
+    ///
+    /// It is owned by the dispatch loop of the [`Display`] and handed to one request handler at
+    /// a time. A handler awaiting [`Display::with_state`] therefore deadlocks, and one awaiting
+    /// slow I/O holds up the requests of every client.
+    type State: Send + 'static;
+
+    /// Creates the state of a newly accepted client, usually inserting its `wl_display`.
+    ///
+    /// Events are sent through `handle`. Returning an error rejects the client.
+    fn connect(
+        &self,
+        socket: Socket,
+        handle: ConnectionHandle,
+    ) -> impl Future<Output = Result<Self::Client, ClientError<Self::Client>>> + Send;
+
+    /// The request signatures of `interface`, such as those found with
+    /// `waynest_protocols::metadata::lookup_interface`.
+    ///
+    /// Objects created by requests sent to zombies are zombies themselves. Requests sent to them
+    /// can only be discarded if their signature is known, the client is failed otherwise as the
+    /// file descriptors they carry would be mistaken for those of later requests.
+    fn interface_requests(&self, interface: &str) -> Option<&'static [MessageInfo]> {
+        let _ = interface;
+        None
+    }
+
+    /// Called once a client is gone, with the error it was disconnected for, if any. `client` is
+    /// `None` if [`Server::connect`] rejected it, or if a request handler panicked and took the
+    /// client down with it.
+    fn disconnect(
+        &self,
+        client: Option<Self::Client>,
+        result: Result<(), ClientError<Self::Client>>,
+    ) -> impl Future<Output = ()> + Send {
+        let _ = (client, result);
+        async {}
+    }
+}
+
+/// Accepts clients and runs each of them on its own task, dispatching their requests to the
+/// objects in their store.
+///
+/// The shared [`Server::State`] is owned by a single dispatch loop, which client tasks hand
+/// their requests to. Requests are thus dispatched one at a time across all clients, each
+/// handler getting exclusive access to the state without any lock being held, while reading
+/// requests and writing events happens on the task of each client.
+pub struct Display<S: Server> {
+    server: Arc<S>,
+    jobs: mpsc::UnboundedSender<Job<S::State>>,
+}
+
+impl<S: Server + fmt::Debug> fmt::Debug for Display<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Display")
+            .field("server", &self.server)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Server> Display<S>
+where
+    ClientError<S::Client>: AsProtocolError + Send,
+{
+    /// Creates a display and spawns its dispatch loop, which must happen within a Tokio runtime.
+    pub fn new(server: S, state: S::State) -> Self {
+        let (jobs, queue) = mpsc::unbounded_channel();
+
+        tokio::spawn(dispatch_loop(state, queue));
+
+        Self {
+            server: Arc::new(server),
+            jobs,
+        }
+    }
+
+    pub fn server(&self) -> &Arc<S> {
+        &self.server
+    }
+
+    /// Runs `f` on the dispatch loop in between two requests, for the parts of the compositor
+    /// that access the shared state outside of request handlers. Awaiting it from a handler
+    /// deadlocks, see [`Server::State`].
+    pub async fn with_state<R, F>(&self, f: F) -> R
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut S::State) -> R + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+
+        let _ = self.jobs.send(job(move |state| {
+            let _ = sender.send(f(state));
+            Box::pin(async {})
+        }));
+
+        receiver.await.expect("Accessing the shared state panicked")
+    }
+
+    /// Accepts clients from `listener` for as long as it is open. Failing to accept or set up a
+    /// client is logged and does not stop the display.
+    pub async fn run(&self, mut listener: Listener) {
+        while let Some(stream) = poll_fn(|cx| Pin::new(&mut listener).poll_next(cx)).await {
+            if let Err(err) = stream.and_then(|stream| self.spawn(stream)) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(target: "waynest", error = %err, "Failed to accept a client");
+                #[cfg(all(feature = "log", not(feature = "tracing")))]
+                log::warn!(target: "waynest", error:% = err; "Failed to accept a client");
+                #[cfg(not(any(feature = "tracing", feature = "log")))]
+                let _ = err;
+            }
+        }
+    }
+
+    /// Runs a client on its own task, for connections that did not come from a [`Listener`].
+    pub fn spawn(&self, stream: UnixStream) -> io::Result<JoinHandle<()>> {
+        let socket = Socket::new(stream)?;
+        let server = self.server.clone();
+        let jobs = self.jobs.clone();
+
+        Ok(tokio::spawn(async move {
+            let mut outbox = Outbox::new();
+
+            let client = match server.connect(socket, outbox.handle()).await {
+                Ok(client) => client,
+                Err(err) => return server.disconnect(None, Err(err)).await,
+            };
+
+            let lookup = |interface: &str| server.interface_requests(interface);
+
+            // A client whose handler panicked is gone along with the handler
+            let Some((mut client, result)) = dispatch(client, &jobs, &mut outbox, lookup).await
+            else {
+                let err = io::Error::other("a request handler panicked");
+
+                return server
+                    .disconnect(None, Err(ProtocolError::from(err).into()))
+                    .await;
+            };
+
+            // Whatever was queued before the client went away, such as a protocol error, is
+            // still sent before the connection is closed
+            let result = match outbox.flush(&mut client).await {
+                Ok(()) => result,
+                Err(err) => result.and(Err(err.into())),
+            };
+
+            server.disconnect(Some(client), result).await;
+        }))
+    }
+}
+
+/// Sends `wl_display.error` for `object_id`, after which the client is expected to be
+/// disconnected.
+pub(crate) fn post_error<S: MessageSender + ?Sized>(
+    sender: &S,
+    object_id: ObjectId,
+    code: u32,
+    message: String,
+) -> Result<(), S::Error> {
+    let payload = PayloadBuilder::new()
+        .put_object(Some(object_id))
+        .put_uint(code)
+        .put_string(Some(message))
+        .build();
+
+    sender.send_message(
+        Message::new(ObjectId::DISPLAY, ERROR_OPCODE, payload),
+        Vec::new(),
+    )
+}
+
+/// Finds an object id created by a request that the client is not allowed to use. Requests
+/// without a known signature or with a malformed payload are left for dispatch to handle.
+fn invalid_new_id<C: Client>(
+    client: &C,
+    requests: &[MessageInfo],
+    message: &Message,
+) -> Option<ObjectId> {
+    let info = requests.get(message.opcode() as usize)?;
+
+    // Most requests create nothing, there is no need to decode those twice
+    if !info.args.iter().any(|arg| arg.kind == ArgKind::NewId) {
+        return None;
+    }
+
+    let mut message = message.clone();
+    let args: Vec<Arg> = Args::new(&mut message, info)
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    let ids: Vec<_> = info
+        .args
+        .iter()
+        .filter(|arg| arg.kind != ArgKind::Fd)
+        .zip(args)
+        .filter_map(|(info, arg)| match (info.kind, arg) {
+            (ArgKind::NewId, Arg::Object(id)) => id,
+            (ArgKind::NewId, Arg::NewId(new_id)) => Some(new_id.object_id),
+            _ => None,
+        })
+        .collect();
+
+    ids.iter().enumerate().find_map(|(index, id)| {
+        let created = &ids[..index];
+
+        // Ids created earlier by the same request are not in the store yet
+        let follows = !id.is_server()
+            && !created.contains(id)
+            && created
+                .iter()
+                .any(|other| other.as_raw() + 1 == id.as_raw());
+
+        (!client.store().accepts_new_id(*id) && !follows).then_some(*id)
+    })
+}
+
+/// Consumes a request sent to a zombie, closing the file descriptors it carries so they are not
+/// mistaken for those of later requests.
+///
+/// Objects the request creates become zombies as well, with the signatures `lookup` finds for
+/// their interface. A request without a known signature fails the client, as there is no telling
+/// how many file descriptors it carries.
+fn discard<C: Client + waynest::Connection>(
+    client: &mut C,
+    requests: &[MessageInfo],
+    message: &mut Message,
+    lookup: impl Fn(&str) -> Option<&'static [MessageInfo]>,
+) -> Result<(), ClientError<C>> {
+    let Some(info) = requests.get(message.opcode() as usize) else {
+        return Err(client.post_error(
+            ObjectId::DISPLAY,
+            INVALID_METHOD,
+            format!(
+                "invalid method {} of destroyed object {}",
+                message.opcode(),
+                message.object_id()
+            ),
+        ));
+    };
+
+    let mut args = Args::new(message, info);
+
+    for arg in info.args {
+        match arg.kind {
+            ArgKind::Fd => drop(client.fd().map_err(|_| ProtocolError::MissingFd)?),
+            ArgKind::NewId => {
+                let (id, requests) = match args.next().transpose()? {
+                    Some(Arg::Object(Some(id))) => (id, arg.interface.and_then(&lookup)),
+                    Some(Arg::NewId(new_id)) => (new_id.object_id, lookup(&new_id.interface)),
+                    _ => return Err(ProtocolError::MalformedPayload.into()),
+                };
+
+                if client
+                    .store_mut()
+                    .insert_zombie(id, requests.unwrap_or_default())
+                    .is_err()
+                {
+                    return Err(client.post_error(
+                        ObjectId::DISPLAY,
+                        INVALID_OBJECT,
+                        format!("invalid new id {id}"),
+                    ));
+                }
+            }
+            _ => {
+                args.next().transpose()?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The number of requests dispatched before queued events are flushed, even if more requests
+/// are immediately available.
+const MAX_BATCH: usize = 32;
+
+/// Boxes `f` as a [`Job`], as closures cannot infer the lifetime tying their future to the state.
+fn job<T, F>(f: F) -> Job<T>
+where
+    F: for<'a> FnOnce(&'a mut T) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send + 'static,
+{
+    Box::new(f)
+}
+
+/// Runs jobs one at a time until the display and every client are gone.
+async fn dispatch_loop<T>(mut state: T, mut jobs: mpsc::UnboundedReceiver<Job<T>>) {
+    while let Some(job) = jobs.recv().await {
+        let mut job = job(&mut state);
+
+        // A panicking handler only takes down the client it was dispatching a request of
+        poll_fn(|cx| {
+            panic::catch_unwind(AssertUnwindSafe(|| job.as_mut().poll(cx)))
+                .unwrap_or(Poll::Ready(()))
+        })
+        .await;
+    }
+}
+
+/// What to do after reading from a client.
+enum Next<C: Client> {
+    /// Hand a request to the object it was sent to
+    Dispatch(StoreObject<C::Store>, Message),
+    /// Wait for the next request
+    Continue,
+    /// The client hung up
+    Closed,
+}
+
+/// Dispatches requests until the client hangs up or an error occurs. Queued events are sent after
+/// every batch of requests, which ends once no more requests are immediately available or
+/// [`MAX_BATCH`] were dispatched.
+///
+/// Returns `None` if a handler panicked, taking the client with it.
+async fn dispatch<C>(
+    mut client: C,
+    jobs: &mpsc::UnboundedSender<Job<ClientState<C>>>,
+    outbox: &mut Outbox,
+    lookup: impl Fn(&str) -> Option<&'static [MessageInfo]>,
+) -> Option<(C, Result<(), ClientError<C>>)>
+where
+    C: Client<Store: ObjectStore<Connection = C>> + waynest::Connection + 'static,
+    ClientError<C>: AsProtocolError + Send,
+{
+    let mut batch = 0;
+
+    loop {
+        let result = match next(&mut client, outbox, &mut batch, &lookup).await {
+            Ok(Next::Dispatch(object, message)) => {
+                let (returned, result) = handle(client, object, message, jobs).await?;
+
+                client = returned;
+                batch += 1;
+
+                result
+            }
+            Ok(Next::Continue) => Ok(()),
+            Ok(Next::Closed) => return Some((client, Ok(()))),
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = result {
+            // Errors from `Client::post_error` were posted already
+            if let Some(ProtocolError::Fatal {
+                object_id,
+                code,
+                message,
+            }) = err.as_protocol_error()
+                && !outbox.is_closed()
+            {
+                client.store_mut().clear();
+
+                if let Err(err) = post_error(&outbox.handle(), *object_id, *code, message.clone()) {
+                    return Some((client, Err(err.into())));
+                }
+            }
+
+            return Some((client, Err(err)));
+        }
+    }
+}
+
+/// Reads the next request of a client, handling those that do not reach a handler, or flushes
+/// the events queued so far.
+async fn next<C>(
+    client: &mut C,
+    outbox: &mut Outbox,
+    batch: &mut usize,
+    lookup: impl Fn(&str) -> Option<&'static [MessageInfo]>,
+) -> Result<Next<C>, ClientError<C>>
+where
+    C: Client<Store: ObjectStore<Connection = C>> + waynest::Connection + 'static,
+{
+    let next = poll_fn(|cx| {
+        // A client failed through its handle is flushed and disconnected
+        if outbox.is_closed() {
+            return Poll::Ready(None);
+        }
+
+        if *batch < MAX_BATCH
+            && let Poll::Ready(message) = Pin::new(&mut *client).poll_next(cx)
+        {
+            return Poll::Ready(Some(message));
+        }
+
+        if *batch > 0 || outbox.poll_queued(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    })
+    .await;
+
+    let mut message = match next {
+        Some(Some(message)) => message?,
+        Some(None) => return Ok(Next::Closed),
+        None => {
+            outbox.flush(client).await?;
+            *batch = 0;
+
+            // The handler that failed the client did not return the error
+            if outbox.is_closed() {
+                return Err(
+                    ProtocolError::from(io::Error::from(io::ErrorKind::ConnectionAborted)).into(),
+                );
+            }
+
+            return Ok(Next::Continue);
+        }
+    };
+
+    let sender_id = message.object_id();
+    let object = client.get_raw(sender_id);
+
+    let requests = match &object {
+        Some(object) => object.requests(),
+        None => match client.store().zombie(sender_id) {
+            Some(requests) => requests,
+            None => {
+                return Err(client.post_error(
+                    ObjectId::DISPLAY,
+                    INVALID_OBJECT,
+                    format!("invalid object {sender_id}"),
+                ));
+            }
+        },
+    };
+
+    if let Some(id) = invalid_new_id(client, requests, &message) {
+        return Err(client.post_error(
+            ObjectId::DISPLAY,
+            INVALID_OBJECT,
+            format!("invalid new id {id}"),
+        ));
+    }
+
+    match object {
+        Some(object) => Ok(Next::Dispatch(object, message)),
+        None => {
+            discard(client, requests, &mut message, lookup)?;
+            *batch += 1;
+
+            Ok(Next::Continue)
+        }
+    }
+}
+
+/// Dispatches a request on the dispatch loop, moving the client there and back.
+///
+/// Returns `None` if the handler panicked.
+async fn handle<C>(
+    client: C,
+    object: StoreObject<C::Store>,
+    mut message: Message,
+    jobs: &mpsc::UnboundedSender<Job<ClientState<C>>>,
+) -> Option<(C, Result<(), ClientError<C>>)>
+where
+    C: Client<Store: ObjectStore<Connection = C>> + waynest::Connection + 'static,
+    ClientError<C>: Send,
+{
+    let (sender, receiver) = oneshot::channel();
+    let sender_id = message.object_id();
+
+    jobs.send(job(move |state| {
+        Box::pin(async move {
+            let mut client = client;
+            let result = object
+                .dispatch_request(&mut client, state, sender_id, &mut message)
+                .await;
+
+            let _ = sender.send((client, result));
+        })
+    }))
+    .ok()?;
+
+    receiver.await.ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::Any,
+        fs::File,
+        future::poll_fn,
+        os::fd::OwnedFd,
+        pin::Pin,
+        sync::{Arc, Mutex},
+        task::{Context, Poll},
+    };
+
+    use futures_core::Stream;
+    use futures_sink::Sink;
+    use tokio::net::UnixStream;
+    use waynest::{
+        ArgInfo, ArgKind, ConnectionHandle, Message, MessageInfo, ObjectId, PayloadBuilder,
+        ProtocolError, Socket,
+    };
+
+    use super::{ClientError, Display, Server};
+    use crate::{Client, ObjectStore, RequestDispatcher, Store};
+
+    struct TestClient {
+        socket: Socket,
+        handle: ConnectionHandle,
+        store: Store<TestClient, ProtocolError, u32>,
+    }
+
+    impl Client for TestClient {
+        type Store = Store<TestClient, ProtocolError, u32>;
+
+        fn store(&self) -> &Self::Store {
+            &self.store
+        }
+
+        fn store_mut(&mut self) -> &mut Self::Store {
+            &mut self.store
+        }
+
+        fn handle(&self) -> Option<&ConnectionHandle> {
+            Some(&self.handle)
+        }
+    }
+
+    impl Stream for TestClient {
+        type Item = Result<Message, ProtocolError>;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Pin::new(&mut self.socket).poll_next(cx)
+        }
+    }
+
+    impl Sink<Message> for TestClient {
+        type Error = ProtocolError;
+
+        fn poll_ready(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Pin::new(&mut self.socket).poll_ready(cx)
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
+            Pin::new(&mut self.socket).start_send(item)
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Pin::new(&mut self.socket).poll_flush(cx)
+        }
+
+        fn poll_close(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Pin::new(&mut self.socket).poll_close(cx)
+        }
+    }
+
+    impl waynest::Connection for TestClient {
+        type Error = ProtocolError;
+
+        fn fd(&mut self) -> Result<OwnedFd, ProtocolError> {
+            self.socket.pop_fd()
+        }
+
+        fn push_fd(&mut self, fd: OwnedFd) {
+            self.socket.push_fd(fd)
+        }
+    }
+
+    /// An object failing the client on every request, opcode 3 not returning the error and
+    /// opcode 4 panicking.
+    struct Failing;
+
+    /// Requests of [`Failing`] and of the zombie every client starts with, opcode 1 creating an
+    /// object like `wl_display.get_registry` and opcode 2 carrying a file descriptor.
+    static REQUESTS: &[MessageInfo] = &[
+        MessageInfo {
+            name: "fail",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            signature: "",
+            args: &[],
+        },
+        MessageInfo {
+            name: "create",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            signature: "n",
+            args: &[ArgInfo {
+                name: "id",
+                kind: ArgKind::NewId,
+                interface: Some("wl_registry"),
+                r#enum: None,
+                allow_null: false,
+            }],
+        },
+        MessageInfo {
+            name: "share",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            signature: "h",
+            args: &[ArgInfo {
+                name: "fd",
+                kind: ArgKind::Fd,
+                interface: None,
+                r#enum: None,
+                allow_null: false,
+            }],
+        },
+    ];
+
+    #[async_trait::async_trait]
+    impl RequestDispatcher for Failing {
+        type Connection = TestClient;
+        type Error = ProtocolError;
+        type State = u32;
+
+        fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static> {
+            self
+        }
+
+        fn requests(&self) -> &'static [MessageInfo] {
+            REQUESTS
+        }
+
+        async fn dispatch_request(
+            &self,
+            connection: &mut TestClient,
+            state: &mut u32,
+            sender_id: ObjectId,
+            message: &mut Message,
+        ) -> Result<(), ProtocolError> {
+            *state += 1;
+
+            assert_ne!(message.opcode(), 4, "Failing panicked");
+
+            let error = connection.post_error(sender_id, 3u32, "invalid request");
+
+            match message.opcode() {
+                3 => Ok(()),
+                _ => Err(error),
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct TestServer {
+        /// The reason each client was disconnected for, and whether its objects were destroyed
+        disconnected: Mutex<Option<(Result<(), ProtocolError>, bool)>>,
+    }
+
+    impl Server for TestServer {
+        type Client = TestClient;
+        /// The number of requests handled across every client
+        type State = u32;
+
+        async fn connect(
+            &self,
+            socket: Socket,
+            handle: ConnectionHandle,
+        ) -> Result<TestClient, ProtocolError> {
+            let mut client = TestClient {
+                socket,
+                handle,
+                store: Store::new(),
+            };
+
+            assert!(client.insert(ObjectId::DISPLAY, 1, Failing).is_ok());
+            assert!(
+                client
+                    .store_mut()
+                    .insert_zombie(ObjectId::new(2).unwrap(), REQUESTS)
+                    .is_ok()
+            );
+
+            Ok(client)
+        }
+
+        fn interface_requests(&self, interface: &str) -> Option<&'static [MessageInfo]> {
+            (interface == "wl_registry").then_some(REQUESTS)
+        }
+
+        async fn disconnect(
+            &self,
+            client: Option<TestClient>,
+            result: Result<(), ClientError<TestClient>>,
+        ) {
+            let destroyed =
+                client.is_some_and(|client| client.get_raw(ObjectId::DISPLAY).is_none());
+            *self.disconnected.lock().unwrap() = Some((result, destroyed));
+        }
+    }
+
+    /// Sends requests along with file descriptors and returns the error they were answered
+    /// with, how the client was disconnected and how many requests reached a handler.
+    fn error_for(
+        requests: impl IntoIterator<Item = Message>,
+        fds: Vec<OwnedFd>,
+    ) -> (Message, Option<(Result<(), ProtocolError>, bool)>, u32) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let (server_end, client_end) = UnixStream::pair().unwrap();
+            let display = Display::new(TestServer::default(), 0);
+            let task = display.spawn(server_end).unwrap();
+
+            let mut socket = Socket::new(client_end).unwrap();
+
+            for fd in fds {
+                socket.push_fd(fd);
+            }
+
+            for request in requests {
+                poll_fn(|cx| Pin::new(&mut socket).poll_ready(cx))
+                    .await
+                    .unwrap();
+                Pin::new(&mut socket).start_send(request).unwrap();
+            }
+
+            poll_fn(|cx| Pin::new(&mut socket).poll_flush(cx))
+                .await
+                .unwrap();
+
+            let error = poll_fn(|cx| Pin::new(&mut socket).poll_next(cx))
+                .await
+                .unwrap()
+                .unwrap();
+
+            task.await.unwrap();
+
+            let disconnected = display.server().disconnected.lock().unwrap().take();
+            let handled = display.with_state(|handled| *handled).await;
+
+            (error, disconnected, handled)
+        })
+    }
+
+    #[test]
+    fn panics_only_take_down_their_client() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let (server_end, client_end) = UnixStream::pair().unwrap();
+            let display = Display::new(TestServer::default(), 0);
+            let task = display.spawn(server_end).unwrap();
+
+            let mut socket = Socket::new(client_end).unwrap();
+
+            poll_fn(|cx| Pin::new(&mut socket).poll_ready(cx))
+                .await
+                .unwrap();
+            Pin::new(&mut socket)
+                .start_send(Message::new(
+                    ObjectId::DISPLAY,
+                    4,
+                    PayloadBuilder::new().build(),
+                ))
+                .unwrap();
+            poll_fn(|cx| Pin::new(&mut socket).poll_flush(cx))
+                .await
+                .unwrap();
+
+            // The connection is dropped along with the client
+            assert!(
+                poll_fn(|cx| Pin::new(&mut socket).poll_next(cx))
+                    .await
+                    .is_none()
+            );
+
+            task.await.unwrap();
+
+            assert!(matches!(
+                display.server().disconnected.lock().unwrap().take(),
+                Some((Err(ProtocolError::IoError(_)), false))
+            ));
+            assert_eq!(display.with_state(|handled| *handled).await, 1);
+        });
+    }
+
+    #[test]
+    fn unknown_objects_are_invalid() {
+        let unknown = ObjectId::new(7).unwrap();
+        let (mut error, disconnected, _) = error_for(
+            [Message::new(unknown, 0, PayloadBuilder::new().build())],
+            Vec::new(),
+        );
+
+        assert_eq!(error.object_id(), ObjectId::DISPLAY);
+        assert_eq!(error.opcode(), 0);
+        assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+        assert_eq!(error.uint().unwrap(), 0);
+        assert_eq!(error.string().unwrap().as_deref(), Some("invalid object 7"));
+
+        assert!(matches!(
+            disconnected,
+            Some((Err(ProtocolError::Fatal { object_id, code: 0, .. }), true))
+                if object_id == ObjectId::DISPLAY
+        ));
+    }
+
+    #[test]
+    fn handlers_post_errors() {
+        let (mut error, disconnected, _) = error_for(
+            [Message::new(
+                ObjectId::DISPLAY,
+                0,
+                PayloadBuilder::new().build(),
+            )],
+            Vec::new(),
+        );
+
+        assert_eq!(error.object_id(), ObjectId::DISPLAY);
+        assert_eq!(error.opcode(), 0);
+        assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+        assert_eq!(error.uint().unwrap(), 3);
+        assert_eq!(error.string().unwrap().as_deref(), Some("invalid request"));
+
+        assert!(matches!(
+            disconnected,
+            Some((Err(ProtocolError::Fatal { code: 3, .. }), true))
+        ));
+    }
+
+    #[test]
+    fn posted_errors_disconnect() {
+        let (mut error, disconnected, handled) = error_for(
+            [
+                Message::new(ObjectId::DISPLAY, 3, PayloadBuilder::new().build()),
+                Message::new(ObjectId::DISPLAY, 3, PayloadBuilder::new().build()),
+            ],
+            Vec::new(),
+        );
+
+        assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+        assert_eq!(error.uint().unwrap(), 3);
+
+        // The second request is not dispatched, the client being dead already
+        assert_eq!(handled, 1);
+        assert!(matches!(
+            disconnected,
+            Some((Err(ProtocolError::IoError(err)), true))
+                if err.kind() == std::io::ErrorKind::ConnectionAborted
+        ));
+    }
+
+    #[test]
+    fn new_ids_are_validated() {
+        let request = |id| {
+            let id = ObjectId::new(id).unwrap();
+            Message::new(
+                ObjectId::DISPLAY,
+                1,
+                PayloadBuilder::new().put_object(Some(id)).build(),
+            )
+        };
+
+        // Ids must be unused, at most one past the highest id used so far, and never in the server
+        // range
+        for (id, message) in [
+            (1, "invalid new id 1"),
+            (4, "invalid new id 4"),
+            (ObjectId::SERVER_START.as_raw(), "invalid new id 4278190080"),
+        ] {
+            let (mut error, disconnected, _) = error_for([request(id)], Vec::new());
+
+            assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+            assert_eq!(error.uint().unwrap(), 0);
+            assert_eq!(error.string().unwrap().as_deref(), Some(message));
+            assert!(matches!(
+                disconnected,
+                Some((Err(ProtocolError::Fatal { code: 0, .. }), true))
+            ));
+        }
+
+        // Reusing the id of a zombie reaches the handler
+        let (mut error, _, handled) = error_for([request(2)], Vec::new());
+
+        assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+        assert_eq!(error.uint().unwrap(), 3);
+        assert_eq!(handled, 1);
+    }
+
+    #[test]
+    fn zombies_discard_requests() {
+        let zombie = ObjectId::new(2).unwrap();
+        let created = ObjectId::new(3).unwrap();
+        let fd = || File::open("/dev/null").unwrap().into();
+
+        let (mut error, disconnected, _) = error_for(
+            [
+                Message::new(zombie, 2, PayloadBuilder::new().build()),
+                Message::new(
+                    zombie,
+                    1,
+                    PayloadBuilder::new().put_object(Some(created)).build(),
+                ),
+                // The created object takes the signatures of its interface
+                Message::new(created, 2, PayloadBuilder::new().build()),
+                // Only valid if the object created by the zombie took id 3
+                Message::new(
+                    ObjectId::DISPLAY,
+                    1,
+                    PayloadBuilder::new()
+                        .put_object(Some(ObjectId::new(4).unwrap()))
+                        .build(),
+                ),
+            ],
+            vec![fd(), fd()],
+        );
+
+        assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+        assert_eq!(error.uint().unwrap(), 3);
+        assert!(matches!(
+            disconnected,
+            Some((Err(ProtocolError::Fatal { code: 3, .. }), true))
+        ));
+
+        // Requests without a known signature cannot be discarded
+        let (mut error, disconnected, _) = error_for(
+            [Message::new(zombie, 7, PayloadBuilder::new().build())],
+            Vec::new(),
+        );
+
+        assert_eq!(error.object().unwrap(), Some(ObjectId::DISPLAY));
+        assert_eq!(error.uint().unwrap(), 1);
+        assert_eq!(
+            error.string().unwrap().as_deref(),
+            Some("invalid method 7 of destroyed object 2")
+        );
+        assert!(matches!(
+            disconnected,
+            Some((Err(ProtocolError::Fatal { code: 1, .. }), true))
+        ));
+    }
+}
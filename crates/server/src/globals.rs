@@ -0,0 +1,634 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use waynest::{
+    ConnectionHandle, InterfaceInfo, Message, MessageSender, NewId, ObjectId, PayloadBuilder,
+    ProtocolError,
+};
+
+use crate::{Client, ClientError, display::INVALID_OBJECT};
+
+/// The opcode of the `wl_registry.global` event.
+const GLOBAL_OPCODE: u16 = 0;
+
+/// The opcode of the `wl_registry.global_remove` event.
+const GLOBAL_REMOVE_OPCODE: u16 = 1;
+
+type BindFn<C> = dyn Fn(&mut C, ObjectId, u32) -> Result<(), ClientError<C>> + Send + Sync;
+
+type FilterFn = dyn Fn(&GlobalInfo) -> bool + Send + Sync;
+
+/// A global as advertised to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlobalInfo {
+    pub name: u32,
+    pub interface: &'static InterfaceInfo,
+    pub version: u32,
+}
+
+struct Global<C: Client> {
+    info: GlobalInfo,
+    bind: Arc<BindFn<C>>,
+    /// Whether the removal was announced, after which the global is only kept around for the
+    /// binds already in flight
+    removed: bool,
+}
+
+/// The `wl_registry` of a client, which globals are advertised to.
+struct Advertised {
+    handle: ConnectionHandle,
+    id: ObjectId,
+    filter: Option<Arc<FilterFn>>,
+    /// The names of the globals the client was told about, which it is allowed to bind. They
+    /// stay here once removed, so that binds racing with the removal can be told apart from
+    /// bogus ones.
+    announced: BTreeSet<u32>,
+}
+
+impl Advertised {
+    fn announce(&mut self, global: &GlobalInfo) -> Result<(), ProtocolError> {
+        let payload = PayloadBuilder::new()
+            .put_uint(global.name)
+            .put_string(Some(global.interface.name))
+            .put_uint(global.version)
+            .build();
+
+        self.handle
+            .send_message(Message::new(self.id, GLOBAL_OPCODE, payload), Vec::new())?;
+        self.announced.insert(global.name);
+
+        Ok(())
+    }
+
+    fn send_global_remove(&self, name: u32) -> Result<(), ProtocolError> {
+        let payload = PayloadBuilder::new().put_uint(name).build();
+
+        self.handle.send_message(
+            Message::new(self.id, GLOBAL_REMOVE_OPCODE, payload),
+            Vec::new(),
+        )
+    }
+}
+
+struct State<C: Client> {
+    next_name: u32,
+    globals: BTreeMap<u32, Global<C>>,
+    next_registry: u64,
+    registries: BTreeMap<u64, Advertised>,
+}
+
+/// The globals of a display, advertised to the `wl_registry` of every client.
+///
+/// Cloning is cheap, every clone refers to the same set of globals.
+pub struct Globals<C: Client> {
+    state: Arc<Mutex<State<C>>>,
+}
+
+impl<C: Client> Clone for Globals<C> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<C: Client> Default for Globals<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Client> Globals<C> {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                next_name: 1,
+                globals: BTreeMap::new(),
+                next_registry: 0,
+                registries: BTreeMap::new(),
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<C>> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Announces `global` to those of `registries` whose filter accepts it.
+    ///
+    /// Filters are called without holding the lock, as they may well look at the globals
+    /// themselves. Registries dropped in the meantime are skipped, as is a global removed in the
+    /// meantime.
+    fn announce(
+        &self,
+        global: &GlobalInfo,
+        registries: Vec<(u64, Option<Arc<FilterFn>>)>,
+    ) -> Result<(), ProtocolError> {
+        let visible: Vec<u64> = registries
+            .into_iter()
+            .filter(|(_, filter)| filter.as_ref().is_none_or(|filter| filter(global)))
+            .map(|(key, _)| key)
+            .collect();
+
+        let mut state = self.lock();
+
+        if state
+            .globals
+            .get(&global.name)
+            .is_none_or(|global| global.removed)
+        {
+            return Ok(());
+        }
+
+        for key in visible {
+            if let Some(registry) = state.registries.get_mut(&key)
+                && !registry.announced.contains(&global.name)
+            {
+                registry.announce(global)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers a global and advertises it to every client allowed to see it, returning its
+    /// name.
+    ///
+    /// The version is clamped to the one `interface` was generated with. `bind` is called with
+    /// the id and version requested by a client binding the global, usually inserting an object
+    /// into its store with [`Client::insert`].
+    pub fn register<F>(&self, interface: &'static InterfaceInfo, version: u32, bind: F) -> u32
+    where
+        F: Fn(&mut C, ObjectId, u32) -> Result<(), ClientError<C>> + Send + Sync + 'static,
+    {
+        let (info, registries) = {
+            let mut state = self.lock();
+
+            let info = GlobalInfo {
+                name: state.next_name,
+                interface,
+                version: version.clamp(1, interface.version),
+            };
+
+            state.next_name += 1;
+            state.globals.insert(
+                info.name,
+                Global {
+                    info,
+                    bind: Arc::new(bind),
+                    removed: false,
+                },
+            );
+
+            let registries = state
+                .registries
+                .iter()
+                .map(|(key, registry)| (*key, registry.filter.clone()))
+                .collect();
+
+            (info, registries)
+        };
+
+        // A client that is gone drops its registry along with its other objects
+        let _ = self.announce(&info, registries);
+
+        info.name
+    }
+
+    /// Announces the removal of a global to every client it was advertised to, without
+    /// destroying it yet.
+    ///
+    /// The global is no longer advertised, but clients that have not processed the removal yet
+    /// can still bind it until [`Globals::destroy`] is called, typically a few seconds later.
+    /// Returns `false` if no global has this name or it was already removed.
+    pub fn remove(&self, name: u32) -> bool {
+        let mut state = self.lock();
+        let State {
+            globals,
+            registries,
+            ..
+        } = &mut *state;
+
+        let Some(global) = globals.get_mut(&name).filter(|global| !global.removed) else {
+            return false;
+        };
+
+        global.removed = true;
+
+        for registry in registries.values() {
+            if registry.announced.contains(&name) {
+                let _ = registry.send_global_remove(name);
+            }
+        }
+
+        true
+    }
+
+    /// Destroys a global, removing it first if [`Globals::remove`] was not called.
+    ///
+    /// Binds to the global from clients it was advertised to are ignored from then on.
+    /// Returns `false` if no global has this name.
+    pub fn destroy(&self, name: u32) -> bool {
+        self.remove(name);
+
+        self.lock().globals.remove(&name).is_some()
+    }
+
+    /// A snapshot of every global that was not removed.
+    pub fn globals(&self) -> Vec<GlobalInfo> {
+        self.lock()
+            .globals
+            .values()
+            .filter(|global| !global.removed)
+            .map(|global| global.info)
+            .collect()
+    }
+
+    /// Creates the registry of a client, advertising every global to it right away.
+    ///
+    /// `id` is the `wl_registry` object created by `wl_display.get_registry`.
+    pub fn registry(
+        &self,
+        handle: ConnectionHandle,
+        id: ObjectId,
+    ) -> Result<Registry<C>, ProtocolError> {
+        self.add_registry(handle, id, None)
+    }
+
+    /// Creates the registry of a client which only sees the globals `filter` accepts, such as to
+    /// keep privileged globals away from untrusted clients.
+    pub fn filtered_registry<F>(
+        &self,
+        handle: ConnectionHandle,
+        id: ObjectId,
+        filter: F,
+    ) -> Result<Registry<C>, ProtocolError>
+    where
+        F: Fn(&GlobalInfo) -> bool + Send + Sync + 'static,
+    {
+        self.add_registry(handle, id, Some(Arc::new(filter)))
+    }
+
+    fn add_registry(
+        &self,
+        handle: ConnectionHandle,
+        id: ObjectId,
+        filter: Option<Arc<FilterFn>>,
+    ) -> Result<Registry<C>, ProtocolError> {
+        // The registry is added before anything is announced to it, so that globals registered
+        // in the meantime are not missed
+        let (key, globals) = {
+            let mut state = self.lock();
+
+            let key = state.next_registry;
+            state.next_registry += 1;
+            state.registries.insert(
+                key,
+                Advertised {
+                    handle,
+                    id,
+                    filter: filter.clone(),
+                    announced: BTreeSet::new(),
+                },
+            );
+
+            let globals: Vec<_> = state
+                .globals
+                .values()
+                .filter(|global| !global.removed)
+                .map(|global| global.info)
+                .collect();
+
+            (key, globals)
+        };
+
+        let registry = Registry {
+            globals: self.clone(),
+            key,
+        };
+
+        for global in globals {
+            self.announce(&global, vec![(key, filter.clone())])?;
+        }
+
+        Ok(registry)
+    }
+}
+
+/// The registry of a single client, which stops receiving globals once dropped.
+pub struct Registry<C: Client> {
+    globals: Globals<C>,
+    key: u64,
+}
+
+impl<C: Client> Registry<C> {
+    /// Handles `wl_registry.bind`, validating the request against the advertised global before
+    /// calling its bind function.
+    ///
+    /// Binding a global the client cannot see, with another interface or with a version it
+    /// does not support posts `wl_display.error(invalid_object)`, like libwayland does. A
+    /// global that was destroyed after its removal was announced to the client is not an error
+    /// though, the client may not have processed the removal yet, and the bind is ignored.
+    pub fn bind(&self, client: &mut C, name: u32, id: NewId) -> Result<(), ClientError<C>> {
+        let (registry_id, validated) = {
+            let state = self.globals.lock();
+            let registry = &state.registries[&self.key];
+            let announced = registry.announced.contains(&name);
+
+            match state.globals.get(&name) {
+                Some(global) if announced => (registry.id, validate(global, name, &id)),
+                None if announced => return Ok(()),
+                _ => (
+                    registry.id,
+                    Err(format!("invalid global {} ({name})", id.interface)),
+                ),
+            }
+        };
+
+        match validated {
+            // The lock is released first so the bind function can register globals of its own
+            Ok(bind) => bind(client, id.object_id, id.version),
+            Err(message) => Err(client.post_error(registry_id, INVALID_OBJECT, message)),
+        }
+    }
+}
+
+/// Checks a bind request against the global it targets, returning the error message posted to
+/// the client otherwise.
+fn validate<C: Client>(
+    Global {
+        info: global, bind, ..
+    }: &Global<C>,
+    name: u32,
+    id: &NewId,
+) -> Result<Arc<BindFn<C>>, String> {
+    if global.interface.name != id.interface {
+        return Err(format!(
+            "invalid interface for global {name}: have {}, wanted {}",
+            global.interface.name, id.interface
+        ));
+    }
+
+    if id.version == 0 || id.version > global.version {
+        return Err(format!(
+            "invalid version for global {} ({name}): have {}, wanted {}",
+            id.interface, global.version, id.version
+        ));
+    }
+
+    Ok(bind.clone())
+}
+
+impl<C: Client> Drop for Registry<C> {
+    fn drop(&mut self) {
+        self.globals.lock().registries.remove(&self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use futures_core::Stream;
+    use waynest::{InterfaceInfo, Message, NewId, ObjectId, Outbox, ProtocolError, Socket};
+
+    use super::Globals;
+    use crate::{Client, Store};
+
+    static WL_COMPOSITOR: InterfaceInfo = InterfaceInfo {
+        name: "wl_compositor",
+        version: 6,
+        protocol: "wayland",
+        family: "core",
+        requests: &[],
+        events: &[],
+        enums: &[],
+    };
+
+    static WL_SHM: InterfaceInfo = InterfaceInfo {
+        name: "wl_shm",
+        version: 2,
+        protocol: "wayland",
+        family: "core",
+        requests: &[],
+        events: &[],
+        enums: &[],
+    };
+
+    #[derive(Default)]
+    struct TestClient {
+        store: Store<Socket, ProtocolError>,
+        bound: Vec<(ObjectId, u32)>,
+    }
+
+    impl Client for TestClient {
+        type Store = Store<Socket, ProtocolError>;
+
+        fn store(&self) -> &Self::Store {
+            &self.store
+        }
+
+        fn store_mut(&mut self) -> &mut Self::Store {
+            &mut self.store
+        }
+    }
+
+    fn received(outbox: &mut Outbox) -> Vec<Message> {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut messages = Vec::new();
+
+        while let Poll::Ready(Some((message, _))) = Pin::new(&mut *outbox).poll_next(&mut cx) {
+            messages.push(message);
+        }
+
+        messages
+    }
+
+    fn announced(outbox: &mut Outbox) -> Vec<(u16, u32)> {
+        received(outbox)
+            .into_iter()
+            .map(|mut message| (message.opcode(), message.uint().unwrap()))
+            .collect()
+    }
+
+    fn new_id(interface: &str, version: u32) -> NewId {
+        NewId {
+            interface: interface.to_string(),
+            version,
+            object_id: ObjectId::new(3).unwrap(),
+        }
+    }
+
+    #[test]
+    fn advertises_globals() {
+        let globals = Globals::<TestClient>::new();
+        let registry_id = ObjectId::new(2).unwrap();
+
+        let compositor = globals.register(&WL_COMPOSITOR, 8, |client, id, version| {
+            client.bound.push((id, version));
+            Ok(())
+        });
+
+        let mut trusted = Outbox::new();
+        let mut untrusted = Outbox::new();
+
+        let _trusted = globals.registry(trusted.handle(), registry_id).unwrap();
+        let _untrusted = globals
+            .filtered_registry(untrusted.handle(), registry_id, |global| {
+                global.interface.name != "wl_shm"
+            })
+            .unwrap();
+
+        let shm = globals.register(&WL_SHM, 1, |_, _, _| Ok(()));
+
+        assert_eq!(announced(&mut trusted), [(0, compositor), (0, shm)]);
+        assert_eq!(announced(&mut untrusted), [(0, compositor)]);
+
+        // Versions are clamped to the generated interface
+        assert_eq!(globals.globals()[0].version, 6);
+
+        assert!(globals.remove(shm));
+        assert!(!globals.remove(shm));
+
+        assert_eq!(announced(&mut trusted), [(1, shm)]);
+        assert_eq!(announced(&mut untrusted), []);
+
+        // Removed globals are not advertised to new clients
+        let mut late = Outbox::new();
+        let _late = globals.registry(late.handle(), registry_id).unwrap();
+
+        assert_eq!(announced(&mut late), [(0, compositor)]);
+        assert_eq!(globals.globals().len(), 1);
+
+        assert!(globals.destroy(shm));
+        assert!(!globals.destroy(shm));
+
+        // Destroying a global that was not removed yet announces its removal
+        assert!(globals.destroy(compositor));
+
+        for outbox in [&mut trusted, &mut untrusted, &mut late] {
+            assert_eq!(announced(outbox), [(1, compositor)]);
+        }
+    }
+
+    #[test]
+    fn validates_binds() {
+        let globals = Globals::<TestClient>::new();
+        let mut outbox = Outbox::new();
+        let mut client = TestClient::default();
+
+        let compositor = globals.register(&WL_COMPOSITOR, 4, |client, id, version| {
+            client.bound.push((id, version));
+            Ok(())
+        });
+        let shm = globals.register(&WL_SHM, 1, |_, _, _| Ok(()));
+
+        let registry = globals
+            .filtered_registry(outbox.handle(), ObjectId::new(2).unwrap(), |global| {
+                global.interface.name != "wl_shm"
+            })
+            .unwrap();
+        received(&mut outbox);
+
+        registry
+            .bind(&mut client, compositor, new_id("wl_compositor", 3))
+            .unwrap();
+
+        assert_eq!(client.bound, [(ObjectId::new(3).unwrap(), 3)]);
+
+        let errors = [
+            (shm, new_id("wl_shm", 1), "invalid global wl_shm (2)"),
+            (
+                compositor,
+                new_id("wl_shm", 1),
+                "invalid interface for global 1: have wl_compositor, wanted wl_shm",
+            ),
+            (
+                compositor,
+                new_id("wl_compositor", 5),
+                "invalid version for global wl_compositor (1): have 4, wanted 5",
+            ),
+        ];
+
+        for (name, id, expected) in errors {
+            // Without a handle the error is left to the display to send
+            assert!(matches!(
+                registry.bind(&mut client, name, id),
+                Err(ProtocolError::Fatal { object_id, code: 0, message })
+                    if object_id == ObjectId::new(2).unwrap() && message == expected
+            ));
+        }
+
+        assert_eq!(received(&mut outbox), []);
+        assert_eq!(client.bound.len(), 1);
+    }
+
+    #[test]
+    fn binds_race_with_removals() {
+        let globals = Globals::<TestClient>::new();
+        let outbox = Outbox::new();
+        let mut client = TestClient::default();
+
+        let compositor = globals.register(&WL_COMPOSITOR, 4, |client, id, version| {
+            client.bound.push((id, version));
+            Ok(())
+        });
+
+        let registry = globals
+            .registry(outbox.handle(), ObjectId::new(2).unwrap())
+            .unwrap();
+
+        // Removed globals can still be bound until they are destroyed
+        assert!(globals.remove(compositor));
+
+        registry
+            .bind(&mut client, compositor, new_id("wl_compositor", 1))
+            .unwrap();
+
+        assert_eq!(client.bound, [(ObjectId::new(3).unwrap(), 1)]);
+
+        // After which binds are ignored rather than fatal
+        assert!(globals.destroy(compositor));
+
+        registry
+            .bind(&mut client, compositor, new_id("wl_compositor", 1))
+            .unwrap();
+
+        assert_eq!(client.bound.len(), 1);
+
+        // Unless the client was never told about the global
+        assert!(matches!(
+            registry.bind(&mut client, compositor + 1, new_id("wl_compositor", 1)),
+            Err(ProtocolError::Fatal { .. })
+        ));
+    }
+
+    #[test]
+    fn filters_run_unlocked() {
+        let globals = Globals::<TestClient>::new();
+        let mut outbox = Outbox::new();
+
+        // Only sees the first global ever registered
+        let lookup = globals.clone();
+        let _registry = globals
+            .filtered_registry(outbox.handle(), ObjectId::new(2).unwrap(), move |global| {
+                lookup.globals()[0].name == global.name
+            })
+            .unwrap();
+
+        let compositor = globals.register(&WL_COMPOSITOR, 1, |_, _, _| Ok(()));
+        let shm = globals.register(&WL_SHM, 1, |_, _, _| Ok(()));
+
+        assert_eq!(announced(&mut outbox), [(0, compositor)]);
+
+        assert!(globals.destroy(shm));
+        assert!(globals.destroy(compositor));
+
+        assert_eq!(announced(&mut outbox), [(1, compositor)]);
+    }
+}
@@ -4,11 +4,17 @@ use std::{any::Any, collections::BTreeMap, sync::Arc};
 
 pub use async_trait;
 
-use waynest::{Message, ObjectId, ProtocolError};
+use waynest::{ConnectionHandle, Message, MessageInfo, ObjectId, ProtocolError};
 
+mod display;
+mod globals;
 mod listener;
+mod slab;
 
+pub use display::{ClientError, Display, Server};
+pub use globals::{GlobalInfo, Globals, Registry};
 pub use listener::{Listener, ListenerError};
+pub use slab::SlabStore;
 pub use waynest_macros::RequestDispatcher;
 
 pub trait Client {
@@ -17,29 +23,70 @@ pub trait Client {
     fn store(&self) -> &Self::Store;
     fn store_mut(&mut self) -> &mut Self::Store;
 
+    /// The handle the events of the client are sent through, as given to [`Server::connect`].
+    ///
+    /// Without one, [`Client::post_error`] leaves sending the error to the [`Display`] the
+    /// handler returns it to.
+    fn handle(&self) -> Option<&ConnectionHandle> {
+        None
+    }
+
+    /// Inserts an object without a parent, such as `wl_display` at version 1 or an object created
+    /// by `wl_registry.bind` at the version the client asked for.
     fn insert<
         D: RequestDispatcher<
                 Error = <Self::Store as ObjectStore>::Error,
                 Connection = <Self::Store as ObjectStore>::Connection,
+                State = <Self::Store as ObjectStore>::State,
             >,
     >(
         &mut self,
         id: ObjectId,
+        version: u32,
         object: D,
-    ) -> Result<
-        Arc<D>,
-        StoreError<
-            Arc<
-                dyn RequestDispatcher<
-                        Error = <Self::Store as ObjectStore>::Error,
-                        Connection = <Self::Store as ObjectStore>::Connection,
-                    >,
+    ) -> Result<Arc<D>, StoreError<StoreObject<Self::Store>>> {
+        self.insert_with(id, object, version, None)
+    }
+
+    /// Inserts an object created by a request of `parent`, which it takes the version of.
+    ///
+    /// Fails with the object if `parent` is not in the store.
+    fn insert_child<
+        D: RequestDispatcher<
+                Error = <Self::Store as ObjectStore>::Error,
+                Connection = <Self::Store as ObjectStore>::Connection,
+                State = <Self::Store as ObjectStore>::State,
+            >,
+    >(
+        &mut self,
+        parent: ObjectId,
+        id: ObjectId,
+        object: D,
+    ) -> Result<Arc<D>, StoreError<StoreObject<Self::Store>>> {
+        let Some(version) = self.version(parent) else {
+            return Err(StoreError(Arc::new(object)));
+        };
+
+        self.insert_with(id, object, version, Some(parent))
+    }
+
+    /// Inserts an object at `version`, recording the object whose request created it, if any.
+    fn insert_with<
+        D: RequestDispatcher<
+                Error = <Self::Store as ObjectStore>::Error,
+                Connection = <Self::Store as ObjectStore>::Connection,
+                State = <Self::Store as ObjectStore>::State,
             >,
-        >,
-    > {
+    >(
+        &mut self,
+        id: ObjectId,
+        object: D,
+        version: u32,
+        parent: Option<ObjectId>,
+    ) -> Result<Arc<D>, StoreError<StoreObject<Self::Store>>> {
         let dispatcher = Arc::new(object);
 
-        self.insert_raw(id, dispatcher.clone())?;
+        self.insert_raw(id, dispatcher.clone(), version, parent)?;
 
         Ok(dispatcher)
     }
@@ -48,23 +95,16 @@ pub trait Client {
         D: RequestDispatcher<
                 Error = <Self::Store as ObjectStore>::Error,
                 Connection = <Self::Store as ObjectStore>::Connection,
+                State = <Self::Store as ObjectStore>::State,
             >,
     >(
         &mut self,
         id: ObjectId,
         object: Arc<D>,
-    ) -> Result<
-        (),
-        StoreError<
-            Arc<
-                dyn RequestDispatcher<
-                        Error = <Self::Store as ObjectStore>::Error,
-                        Connection = <Self::Store as ObjectStore>::Connection,
-                    >,
-            >,
-        >,
-    > {
-        self.store_mut().insert(id, object)
+        version: u32,
+        parent: Option<ObjectId>,
+    ) -> Result<(), StoreError<StoreObject<Self::Store>>> {
+        self.store_mut().insert(id, object, version, parent)
     }
 
     fn get<D: RequestDispatcher>(&self, id: ObjectId) -> Option<Arc<D>> {
@@ -72,112 +112,440 @@ pub trait Client {
         Arc::downcast(dispatcher).ok()
     }
 
-    fn get_raw(
-        &self,
-        id: ObjectId,
-    ) -> Option<
-        Arc<
-            dyn RequestDispatcher<
-                    Error = <Self::Store as ObjectStore>::Error,
-                    Connection = <Self::Store as ObjectStore>::Connection,
-                >,
-        >,
-    > {
+    fn get_raw(&self, id: ObjectId) -> Option<StoreObject<Self::Store>> {
         self.store().get(id)
     }
 
+    fn info(&self, id: ObjectId) -> Option<ObjectInfo> {
+        self.store().info(id)
+    }
+
+    /// The version of an object, such as for a request handler to check which events its
+    /// client supports.
+    fn version(&self, id: ObjectId) -> Option<u32> {
+        Some(self.info(id)?.version)
+    }
+
+    /// Every object implementing `interface`, such as all of the client's `wl_output`s.
+    fn objects_of(&self, interface: &str) -> impl Iterator<Item = (ObjectId, ObjectInfo)> {
+        self.store()
+            .iter()
+            .filter(move |(_, info, _)| info.interface == Some(interface))
+            .map(|(id, info, _)| (id, info))
+    }
+
+    /// Every object dispatched by `D`.
+    fn objects<D: RequestDispatcher>(&self) -> impl Iterator<Item = (ObjectId, Arc<D>)> {
+        self.store().iter().filter_map(|(id, _, object)| {
+            let object = RequestDispatcher::as_any(object.clone());
+            Some((id, Arc::downcast(object).ok()?))
+        })
+    }
+
     fn remove(&mut self, id: ObjectId) {
         self.store_mut().remove(id)
     }
+
+    /// Destroys an object on the server's initiative, such as a `wl_callback` after `done`.
+    ///
+    /// The client may keep sending requests to the object until it processes the matching
+    /// `wl_display.delete_id`, which the caller is responsible for sending. Those requests are
+    /// discarded rather than failing the client, until the client reuses the id.
+    fn zombify(&mut self, id: ObjectId) {
+        if let Some(object) = self.store().get(id) {
+            // The id held an object, so the store accepts it
+            let _ = self.store_mut().insert_zombie(id, object.requests());
+        }
+    }
+
+    /// Fails the client with a protocol error, such as one of the generated `Error` enums.
+    ///
+    /// `wl_display.error` is queued through [`Client::handle`], which is then closed so that
+    /// nothing is sent after it, and every object is destroyed. [`Display`] flushes the error,
+    /// stops dispatching and disconnects the client. The returned error is meant to be returned
+    /// by the request handler, any handler error wrapping a [`ProtocolError::Fatal`] being
+    /// posted the same way.
+    fn post_error(
+        &mut self,
+        object_id: ObjectId,
+        code: impl Into<u32>,
+        message: impl Into<String>,
+    ) -> <Self::Store as ObjectStore>::Error {
+        let code = code.into();
+        let message = message.into();
+
+        if let Some(handle) = self.handle() {
+            // A client whose connection is gone has nothing left to be told
+            let _ = display::post_error(handle, object_id, code, message.clone());
+            handle.close();
+        }
+
+        self.store_mut().clear();
+
+        ProtocolError::Fatal {
+            object_id,
+            code,
+            message,
+        }
+        .into()
+    }
+}
+
+/// What the store knows about an object besides its dispatcher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectInfo {
+    /// The interface of the object, if its dispatcher names one
+    pub interface: Option<&'static str>,
+    pub version: u32,
+    /// The object whose request created this one
+    pub parent: Option<ObjectId>,
 }
 
-pub struct Store<C: waynest::Connection, E: From<ProtocolError>> {
-    objects: BTreeMap<ObjectId, Arc<dyn RequestDispatcher<Error = E, Connection = C>>>,
+/// An [`ObjectStore`] keeping objects in a `BTreeMap`, see [`SlabStore`] for one with constant
+/// time lookups.
+pub struct Store<C: waynest::Connection, E: From<ProtocolError>, S = ()> {
+    objects: BTreeMap<ObjectId, (Object<C, E, S>, ObjectInfo)>,
+    /// Request signatures of destroyed objects the client has not acknowledged yet
+    zombies: BTreeMap<ObjectId, &'static [MessageInfo]>,
+    /// One past the highest client id used so far
+    next_client_id: u32,
 }
 
-impl<C: waynest::Connection, E: From<ProtocolError>> Default for Store<C, E> {
+impl<C: waynest::Connection, E: From<ProtocolError>, S> Default for Store<C, E, S> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<C: waynest::Connection, E: From<ProtocolError>> Store<C, E> {
+impl<C: waynest::Connection, E: From<ProtocolError>, S> Store<C, E, S> {
     pub fn new() -> Self {
         Self {
             objects: BTreeMap::new(),
+            zombies: BTreeMap::new(),
+            next_client_id: ObjectId::DISPLAY.as_raw(),
         }
     }
 }
 
-impl<C: waynest::Connection, E: From<ProtocolError>> ObjectStore for Store<C, E> {
+impl<C: waynest::Connection + 'static, E: From<ProtocolError> + 'static, S: Send + 'static>
+    ObjectStore for Store<C, E, S>
+{
     type Error = E;
     type Connection = C;
+    type State = S;
 
     fn insert(
         &mut self,
         sender_id: ObjectId,
-        object: Arc<dyn RequestDispatcher<Error = Self::Error, Connection = Self::Connection>>,
-    ) -> Result<
-        (),
-        StoreError<Arc<dyn RequestDispatcher<Error = Self::Error, Connection = Self::Connection>>>,
-    > {
+        object: StoreObject<Self>,
+        version: u32,
+        parent: Option<ObjectId>,
+    ) -> Result<(), StoreError<StoreObject<Self>>> {
         if self.objects.contains_key(&sender_id) {
             return Err(StoreError(object));
         }
 
-        self.objects.insert(sender_id, object);
+        if !sender_id.is_server() {
+            self.next_client_id = self.next_client_id.max(sender_id.as_raw() + 1);
+        }
+
+        let info = ObjectInfo {
+            interface: object.interface(),
+            version,
+            parent,
+        };
+
+        self.zombies.remove(&sender_id);
+        self.objects.insert(sender_id, (object, info));
 
         Ok(())
     }
 
-    fn get(
-        &self,
-        id: ObjectId,
-    ) -> Option<Arc<dyn RequestDispatcher<Error = Self::Error, Connection = Self::Connection>>>
-    {
-        self.objects.get(&id).cloned()
+    fn get(&self, id: ObjectId) -> Option<StoreObject<Self>> {
+        Some(self.objects.get(&id)?.0.clone())
+    }
+
+    fn info(&self, id: ObjectId) -> Option<ObjectInfo> {
+        Some(self.objects.get(&id)?.1)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (ObjectId, ObjectInfo, &StoreObject<Self>)> {
+        self.objects
+            .iter()
+            .map(|(id, (object, info))| (*id, *info, object))
     }
 
     fn remove(&mut self, id: ObjectId) {
         self.objects.remove(&id);
+        self.zombies.remove(&id);
+    }
+
+    fn clear(&mut self) {
+        self.objects.clear();
+        self.zombies.clear();
+    }
+
+    fn insert_zombie(
+        &mut self,
+        id: ObjectId,
+        requests: &'static [MessageInfo],
+    ) -> Result<(), StoreError<&'static [MessageInfo]>> {
+        self.objects.remove(&id);
+
+        if !id.is_server() {
+            self.next_client_id = self.next_client_id.max(id.as_raw() + 1);
+            self.zombies.insert(id, requests);
+        }
+
+        Ok(())
+    }
+
+    fn zombie(&self, id: ObjectId) -> Option<&'static [MessageInfo]> {
+        self.zombies.get(&id).copied()
+    }
+
+    fn accepts_new_id(&self, id: ObjectId) -> bool {
+        !id.is_server() && id.as_raw() <= self.next_client_id && !self.objects.contains_key(&id)
+    }
+
+    fn next_server_id(&self) -> ObjectId {
+        let mut next = ObjectId::SERVER_START;
+
+        for id in self
+            .objects
+            .range(ObjectId::SERVER_START..)
+            .map(|(id, _)| *id)
+        {
+            if id != next {
+                break;
+            }
+
+            next = next
+                .as_raw()
+                .checked_add(1)
+                .and_then(ObjectId::new)
+                .expect("Server ids are exhausted");
+        }
+
+        next
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StoreError<T: Clone>(pub T);
 
+/// An object as kept by an [`ObjectStore`], shared with the handlers of its messages.
+pub type Object<C, E, S = ()> = Arc<dyn RequestDispatcher<Error = E, Connection = C, State = S>>;
+
+/// The [`Object`] type kept by the store `T`.
+pub type StoreObject<T> =
+    Object<<T as ObjectStore>::Connection, <T as ObjectStore>::Error, <T as ObjectStore>::State>;
+
 pub trait ObjectStore {
     type Connection: waynest::Connection;
     type Error: From<ProtocolError>;
+    type State: Send;
 
+    /// Inserts an object, replacing the zombie at `sender_id` if any.
+    ///
+    /// Fails with the object if `sender_id` holds one already. Stores may also reject ids more
+    /// than one past the highest id used in their range, which neither a valid client id nor one
+    /// from [`ObjectStore::next_server_id`] can be, as [`SlabStore`] does.
     fn insert(
         &mut self,
         sender_id: ObjectId,
-        object: Arc<dyn RequestDispatcher<Error = Self::Error, Connection = Self::Connection>>,
-    ) -> Result<
-        (),
-        StoreError<Arc<dyn RequestDispatcher<Error = Self::Error, Connection = Self::Connection>>>,
-    >;
+        object: StoreObject<Self>,
+        version: u32,
+        parent: Option<ObjectId>,
+    ) -> Result<(), StoreError<StoreObject<Self>>>;
 
-    fn get(
-        &self,
-        id: ObjectId,
-    ) -> Option<Arc<dyn RequestDispatcher<Error = Self::Error, Connection = Self::Connection>>>;
+    fn get(&self, id: ObjectId) -> Option<StoreObject<Self>>;
+
+    fn info(&self, id: ObjectId) -> Option<ObjectInfo>;
+
+    /// Every object in the store, in no particular order.
+    fn iter(&self) -> impl Iterator<Item = (ObjectId, ObjectInfo, &StoreObject<Self>)>;
 
     fn remove(&mut self, id: ObjectId);
+
+    /// Destroys every object, such as when the client is failed with a protocol error.
+    ///
+    /// The default removes every object yielded by [`ObjectStore::iter`] one at a time.
+    fn clear(&mut self) {
+        let ids: Vec<_> = self.iter().map(|(id, _, _)| id).collect();
+
+        for id in ids {
+            self.remove(id);
+        }
+    }
+
+    /// Replaces the object at `id`, if any, with a zombie discarding the requests described by
+    /// `requests` until the id is reused.
+    ///
+    /// Ids in the server range are freed right away, as the client cannot acknowledge their
+    /// destruction. Fails with `requests` if the store rejects `id`, under the same rules as
+    /// [`ObjectStore::insert`].
+    fn insert_zombie(
+        &mut self,
+        id: ObjectId,
+        requests: &'static [MessageInfo],
+    ) -> Result<(), StoreError<&'static [MessageInfo]>>;
+
+    /// The request signatures of the zombie at `id`.
+    fn zombie(&self, id: ObjectId) -> Option<&'static [MessageInfo]>;
+
+    /// Whether the client may create an object with this id. It must be unused, below
+    /// [`ObjectId::SERVER_START`] and at most one past the highest id the client used so far.
+    fn accepts_new_id(&self, id: ObjectId) -> bool;
+
+    /// The lowest unused id starting at [`ObjectId::SERVER_START`], for objects created by the
+    /// server.
+    fn next_server_id(&self) -> ObjectId;
 }
 
 #[async_trait::async_trait]
 pub trait RequestDispatcher: Any + Send + Sync + 'static {
     type Connection: waynest::Connection;
     type Error: From<ProtocolError>;
+    /// State shared by the objects of every client, see [`Display::new`]
+    type State: Send;
 
     fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static>;
 
+    /// The name of the interface implemented by this object.
+    fn interface(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The signatures of the requests of this object by opcode, used to validate the ids of the
+    /// objects a request creates before it is dispatched, and to discard the requests sent to the
+    /// object once it is a zombie.
+    fn requests(&self) -> &'static [MessageInfo] {
+        &[]
+    }
+
+    /// Handles a request sent to this object.
+    ///
+    /// When run by a [`Display`], this happens on its dispatch loop, which no other request of
+    /// any client is dispatched on until the returned future completes. Awaiting
+    /// [`Display::with_state`] from here thus deadlocks, the state is already the one passed in,
+    /// and slow I/O stalls every client. Such work is better spawned onto a task of its own.
     async fn dispatch_request(
         &self,
         connection: &mut Self::Connection,
+        state: &mut Self::State,
         sender_id: ObjectId,
         message: &mut Message,
     ) -> Result<(), Self::Error>;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, sync::Arc};
+
+    use waynest::{Message, ObjectId, ProtocolError, Socket};
+
+    use crate::{Client, ObjectInfo, RequestDispatcher, Store};
+
+    #[derive(Default)]
+    struct TestClient {
+        store: Store<Socket, ProtocolError>,
+    }
+
+    impl Client for TestClient {
+        type Store = Store<Socket, ProtocolError>;
+
+        fn store(&self) -> &Self::Store {
+            &self.store
+        }
+
+        fn store_mut(&mut self) -> &mut Self::Store {
+            &mut self.store
+        }
+    }
+
+    struct Compositor;
+
+    struct Surface;
+
+    #[async_trait::async_trait]
+    impl RequestDispatcher for Compositor {
+        type Connection = Socket;
+        type Error = ProtocolError;
+        type State = ();
+
+        fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static> {
+            self
+        }
+
+        fn interface(&self) -> Option<&'static str> {
+            Some("wl_compositor")
+        }
+
+        async fn dispatch_request(
+            &self,
+            _connection: &mut Socket,
+            _state: &mut (),
+            _sender_id: ObjectId,
+            _message: &mut Message,
+        ) -> Result<(), ProtocolError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RequestDispatcher for Surface {
+        type Connection = Socket;
+        type Error = ProtocolError;
+        type State = ();
+
+        fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static> {
+            self
+        }
+
+        fn interface(&self) -> Option<&'static str> {
+            Some("wl_surface")
+        }
+
+        async fn dispatch_request(
+            &self,
+            _connection: &mut Socket,
+            _state: &mut (),
+            _sender_id: ObjectId,
+            _message: &mut Message,
+        ) -> Result<(), ProtocolError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn records_object_info() {
+        let mut client = TestClient::default();
+        let id = |id| ObjectId::new(id).unwrap();
+
+        assert!(client.insert(id(3), 5, Compositor).is_ok());
+        assert!(client.insert_child(id(3), id(4), Surface).is_ok());
+        assert!(client.insert_child(id(3), id(5), Surface).is_ok());
+
+        // Children of missing objects are rejected
+        assert!(client.insert_child(id(6), id(7), Surface).is_err());
+        assert!(client.info(id(7)).is_none());
+
+        // Objects take the version of the object that created them
+        assert_eq!(
+            client.info(id(4)),
+            Some(ObjectInfo {
+                interface: Some("wl_surface"),
+                version: 5,
+                parent: Some(id(3)),
+            })
+        );
+        assert_eq!(client.version(id(3)), Some(5));
+
+        let surfaces: Vec<_> = client.objects_of("wl_surface").map(|(id, _)| id).collect();
+        assert_eq!(surfaces, [id(4), id(5)]);
+
+        let compositors: Vec<_> = client.objects::<Compositor>().map(|(id, _)| id).collect();
+        assert_eq!(compositors, [id(3)]);
+    }
+}
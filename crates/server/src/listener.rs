@@ -1,90 +1,281 @@
 use std::{
+    ffi::OsString,
+    fs::{self, File, OpenOptions, TryLockError},
     io,
+    os::{
+        fd::{FromRawFd, OwnedFd},
+        unix::{fs::OpenOptionsExt, net::UnixListener as StdUnixListener},
+    },
     path::{Path, PathBuf},
     pin::Pin,
+    process::Command,
+    sync::atomic::{AtomicBool, Ordering},
     task::{Context, Poll},
 };
 
-use pin_project_lite::pin_project;
+use rustix::net::sockopt;
 use tokio::net::{UnixListener, UnixStream};
 use tokio_stream::Stream;
 
-pin_project! {
-    pub struct Listener {
-        unix_listener: UnixListener,
-        // _lock: OwnedFd,
-        socket_path: PathBuf,
-        lock_path: PathBuf,
-    }
+/// Displays tried by [`Listener::new`], matching `wl_display_add_socket_auto`.
+const DISPLAYS: std::ops::Range<u8> = 0..32;
+
+/// The first file descriptor passed by the `LISTEN_FDS` protocol.
+const LISTEN_FDS_START: i32 = 3;
+
+/// A listening Wayland socket yielding accepted clients.
+///
+/// Sockets bound by [`Listener::new`] and [`Listener::new_with_path`] are removed along with
+/// their lock file when dropped, adopted and abstract sockets are left alone.
+#[derive(Debug)]
+pub struct Listener {
+    unix_listener: UnixListener,
+    socket_path: Option<PathBuf>,
+    lock: Option<Lock>,
+}
+
+#[derive(Debug)]
+struct Lock {
+    path: PathBuf,
+    // Held for as long as the socket exists, released when the file is closed.
+    _file: File,
 }
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ListenerError {
-    Xdg,
-    Io(io::Error),
+    /// `XDG_RUNTIME_DIR` is not set
+    MissingRuntimeDir,
+    /// `XDG_RUNTIME_DIR` is not an absolute path
+    RelativeRuntimeDir(PathBuf),
+    /// Another compositor holds the lock of the socket at this path
+    InUse(PathBuf),
+    /// Every display from `wayland-0` to `wayland-31` is in use or could not be locked
+    NoFreeDisplay,
+    /// Failed to open or lock the lock file
+    Lock(io::Error),
+    /// Failed to remove a socket left behind by a compositor that is no longer running
+    StaleSocket(io::Error),
+    /// Failed to bind the socket
+    Bind(io::Error),
+    /// No file descriptors were passed to this process through `LISTEN_FDS`, or they were
+    /// already adopted
+    NoListenFds,
+    /// The file descriptor is not a listening Unix socket
+    Adopt(io::Error),
 }
 
 impl std::fmt::Display for ListenerError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Xdg => write!(f, "Failed to access XDG socket path"),
-            Self::Io(error) => error.fmt(f),
+            Self::MissingRuntimeDir => write!(f, "XDG_RUNTIME_DIR is not set"),
+            Self::RelativeRuntimeDir(path) => write!(
+                f,
+                "XDG_RUNTIME_DIR is not an absolute path: {}",
+                path.display()
+            ),
+            Self::InUse(path) => write!(f, "Socket {} is in use", path.display()),
+            Self::NoFreeDisplay => write!(f, "No free Wayland display found"),
+            Self::Lock(_) => write!(f, "Failed to lock the socket"),
+            Self::StaleSocket(_) => write!(f, "Failed to remove a stale socket"),
+            Self::Bind(_) => write!(f, "Failed to bind the socket"),
+            Self::NoListenFds => write!(f, "No file descriptors passed through LISTEN_FDS"),
+            Self::Adopt(_) => write!(f, "Failed to adopt the socket"),
         }
     }
 }
 
-impl From<io::Error> for ListenerError {
-    fn from(err: io::Error) -> Self {
-        ListenerError::Io(err)
-    }
-}
-
 impl std::error::Error for ListenerError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            ListenerError::Io(err) => Some(err),
+            ListenerError::Lock(err)
+            | ListenerError::StaleSocket(err)
+            | ListenerError::Bind(err)
+            | ListenerError::Adopt(err) => Some(err),
             _ => None,
         }
     }
 }
 
 impl Listener {
+    /// Binds the first free display from `wayland-0` to `wayland-31` in `XDG_RUNTIME_DIR`.
+    ///
+    /// Like `wl_display_add_socket_auto`, a display whose lock cannot be taken for any reason is
+    /// skipped.
     pub fn new() -> Result<Self, ListenerError> {
-        // FIXME: add a proper error
-        let runtime_dir: PathBuf = std::env::var("XDG_RUNTIME_DIR")
-            .map_err(|_| ListenerError::Xdg)?
+        let runtime_dir: PathBuf = std::env::var_os("XDG_RUNTIME_DIR")
+            .ok_or(ListenerError::MissingRuntimeDir)?
             .into();
 
-        #[allow(clippy::never_loop)]
-        for i in 1..=32u8 {
-            let path = runtime_dir.join(format!("wayland-{i}"));
-
-            // FIXME: actually check
+        if !runtime_dir.is_absolute() {
+            return Err(ListenerError::RelativeRuntimeDir(runtime_dir));
+        }
 
-            return Self::new_with_path(path);
+        for i in DISPLAYS {
+            match Self::new_with_path(runtime_dir.join(format!("wayland-{i}"))) {
+                Err(ListenerError::InUse(_) | ListenerError::Lock(_)) => continue,
+                result => return result,
+            }
         }
 
-        Err(ListenerError::Xdg)
+        Err(ListenerError::NoFreeDisplay)
     }
 
+    /// Binds a socket at `path`, guarded by an exclusive lock on `path.lock`.
+    ///
+    /// A socket already present at `path` is removed once the lock is taken, as the compositor
+    /// that created it can no longer be running.
     pub fn new_with_path<P: AsRef<Path>>(path: P) -> Result<Self, ListenerError> {
-        if !path.as_ref().exists() {
-            // FIXME: add a proper error
-            // return Err(Error::Internal);
+        let socket_path = path.as_ref().to_path_buf();
+
+        let mut lock_path = OsString::from(&socket_path);
+        lock_path.push(".lock");
+        let lock_path = PathBuf::from(lock_path);
+
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .mode(0o660)
+            .open(&lock_path)
+            .map_err(ListenerError::Lock)?;
+
+        match lock.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => return Err(ListenerError::InUse(socket_path)),
+            Err(TryLockError::Error(err)) => return Err(ListenerError::Lock(err)),
         }
 
-        // FIXME: actually implement this
+        // The lock file is ours from here on, so it goes away with any error
+        let unlock = |err| {
+            let _ = fs::remove_file(&lock_path);
+            err
+        };
+
+        match fs::remove_file(&socket_path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                return Err(unlock(ListenerError::StaleSocket(err)));
+            }
+            _ => {}
+        }
+
+        let unix_listener =
+            UnixListener::bind(&socket_path).map_err(|err| unlock(ListenerError::Bind(err)))?;
+
         Ok(Self {
-            unix_listener: UnixListener::bind(&path)?,
-            // _lock: unsafe { OwnedFd::from_raw_fd(5) },
-            socket_path: path.as_ref().to_path_buf(),
-            lock_path: PathBuf::new(),
+            unix_listener,
+            socket_path: Some(socket_path),
+            lock: Some(Lock {
+                path: lock_path,
+                _file: lock,
+            }),
         })
     }
 
-    pub fn socket_path(&self) -> &Path {
-        &self.socket_path
+    /// Binds a socket in the Linux abstract namespace, which has no file to lock or clean up.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn new_abstract(name: &[u8]) -> Result<Self, ListenerError> {
+        #[cfg(target_os = "android")]
+        use std::os::android::net::SocketAddrExt;
+        #[cfg(target_os = "linux")]
+        use std::os::linux::net::SocketAddrExt;
+
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name)
+            .map_err(ListenerError::Bind)?;
+
+        Self::adopt(StdUnixListener::bind_addr(&addr).map_err(ListenerError::Bind)?)
+            .map_err(ListenerError::Bind)
+    }
+
+    /// Adopts a socket that is already bound and listening, such as one inherited from a parent
+    /// process.
+    pub fn from_fd(fd: OwnedFd) -> Result<Self, ListenerError> {
+        if !sockopt::socket_acceptconn(&fd).map_err(|err| ListenerError::Adopt(err.into()))? {
+            return Err(ListenerError::Adopt(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the socket is not listening",
+            )));
+        }
+
+        Self::adopt(StdUnixListener::from(fd)).map_err(ListenerError::Adopt)
+    }
+
+    /// Adopts the first socket passed through the `LISTEN_FDS` protocol, as used by systemd
+    /// socket activation. Any other descriptor passed along is closed.
+    ///
+    /// The descriptors can only be adopted once, later calls fail with
+    /// [`ListenerError::NoListenFds`]. The variables are left in the environment, children
+    /// ignore them as `LISTEN_PID` does not match their own.
+    pub fn from_listen_fds() -> Result<Self, ListenerError> {
+        static ADOPTED: AtomicBool = AtomicBool::new(false);
+
+        let var = |name| std::env::var(name).ok()?.parse::<i32>().ok();
+
+        let count = match var("LISTEN_FDS") {
+            Some(count) if count > 0 && var("LISTEN_PID") == Some(std::process::id() as i32) => {
+                count
+            }
+            _ => return Err(ListenerError::NoListenFds),
+        };
+
+        if ADOPTED.swap(true, Ordering::AcqRel) {
+            return Err(ListenerError::NoListenFds);
+        }
+
+        // SAFETY: the LISTEN_FDS protocol hands ownership of the descriptors starting at 3 to
+        // the process named by LISTEN_PID, which was checked above, and the swap makes sure
+        // they are only taken once.
+        let mut fds = (LISTEN_FDS_START..LISTEN_FDS_START.saturating_add(count))
+            .map(|fd| unsafe { OwnedFd::from_raw_fd(fd) });
+
+        let listener = StdUnixListener::from(fds.next().ok_or(ListenerError::NoListenFds)?);
+        fds.for_each(drop);
+
+        // Inherited descriptors are not close-on-exec, the duplicate is.
+        Self::from_fd(listener.try_clone().map_err(ListenerError::Adopt)?.into())
+    }
+
+    fn adopt(listener: StdUnixListener) -> io::Result<Self> {
+        listener.set_nonblocking(true)?;
+
+        let socket_path = listener.local_addr()?.as_pathname().map(Path::to_path_buf);
+
+        Ok(Self {
+            unix_listener: UnixListener::from_std(listener)?,
+            socket_path,
+            lock: None,
+        })
+    }
+
+    /// The path of the socket, unless it lives in the abstract namespace.
+    ///
+    /// Listeners bound by [`Listener::new`] and [`Listener::new_with_path`] always have a path.
+    pub fn socket_path(&self) -> Option<&Path> {
+        self.socket_path.as_deref()
+    }
+
+    /// The path of the lock file, for sockets bound by this listener.
+    pub fn lock_path(&self) -> Option<&Path> {
+        self.lock.as_ref().map(|lock| lock.path.as_path())
+    }
+
+    /// Points `WAYLAND_DISPLAY` of `command` at this socket, so the spawned process connects to
+    /// it. Abstract sockets have no path and leave the command untouched.
+    pub fn export_display<'a>(&self, command: &'a mut Command) -> &'a mut Command {
+        if let Some(path) = &self.socket_path {
+            command.env("WAYLAND_DISPLAY", path);
+        }
+
+        command
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        if let (Some(socket_path), Some(lock)) = (&self.socket_path, &self.lock) {
+            let _ = fs::remove_file(socket_path);
+            let _ = fs::remove_file(&lock.path);
+        }
     }
 }
 
@@ -102,3 +293,124 @@ impl Stream for Listener {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ffi::OsStr, fs, os::unix::net::UnixListener as StdUnixListener, path::PathBuf,
+        process::Command,
+    };
+
+    use super::{Listener, ListenerError};
+
+    fn runtime_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("waynest-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn locks_and_cleans_up() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let _guard = runtime.enter();
+
+        let dir = runtime_dir("listener");
+        let path = dir.join("wayland-0");
+
+        // A socket left behind by a compositor that exited without cleaning up
+        drop(StdUnixListener::bind(&path).unwrap());
+
+        let listener = Listener::new_with_path(&path).unwrap();
+
+        assert!(listener.lock_path().unwrap().exists());
+        assert!(matches!(
+            Listener::new_with_path(&path),
+            Err(ListenerError::InUse(in_use)) if in_use == path
+        ));
+
+        drop(listener);
+
+        assert!(!path.exists());
+        assert!(!dir.join("wayland-0.lock").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn removes_the_lock_when_binding_fails() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let _guard = runtime.enter();
+
+        let dir = runtime_dir("bind");
+        // Too long for the address of a Unix socket, though fine for the lock file
+        let path = dir.join("w".repeat(120));
+
+        assert!(matches!(
+            Listener::new_with_path(&path),
+            Err(ListenerError::Bind(_))
+        ));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn adopts_sockets() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let _guard = runtime.enter();
+
+        let dir = runtime_dir("adopt");
+        let path = dir.join("wayland-0");
+        let listener = Listener::from_fd(StdUnixListener::bind(&path).unwrap().into()).unwrap();
+
+        assert_eq!(listener.socket_path(), Some(path.as_path()));
+        assert_eq!(listener.lock_path(), None);
+
+        let mut command = Command::new("true");
+        listener.export_display(&mut command);
+
+        assert_eq!(
+            command.get_envs().collect::<Vec<_>>(),
+            [(OsStr::new("WAYLAND_DISPLAY"), Some(path.as_os_str()))]
+        );
+
+        drop(listener);
+
+        // Adopted sockets belong to whoever created them
+        assert!(path.exists());
+
+        let (stream, _) = std::os::unix::net::UnixStream::pair().unwrap();
+        assert!(matches!(
+            Listener::from_fd(stream.into()),
+            Err(ListenerError::Adopt(_))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn binds_abstract_sockets() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let _guard = runtime.enter();
+
+        let name = format!("waynest-abstract-{}", std::process::id());
+        let listener = Listener::new_abstract(name.as_bytes()).unwrap();
+
+        assert_eq!(listener.socket_path(), None);
+        assert_eq!(listener.lock_path(), None);
+    }
+}
@@ -0,0 +1,256 @@
+use waynest::{MessageInfo, ObjectId, ProtocolError};
+
+use crate::{Object, ObjectInfo, ObjectStore, StoreError, StoreObject};
+
+enum Slot<C, E, S> {
+    Empty,
+    Object(Object<C, E, S>, ObjectInfo),
+    Zombie(&'static [MessageInfo]),
+}
+
+/// An [`ObjectStore`] keeping objects in two vectors indexed by id, one for client ids and one
+/// for server ids starting at [`ObjectId::SERVER_START`].
+///
+/// Looking up an object is a single index instead of a tree walk, which pays off as every
+/// request goes through a lookup. Clients allocate ids densely from 1, so the client vector
+/// only grows as far as the highest id in use, and server ids are handed out lowest first by
+/// [`ObjectStore::next_server_id`].
+///
+/// Ids more than one past the end of their vector are rejected rather than growing it
+/// arbitrarily far, as neither a valid client id nor one from [`ObjectStore::next_server_id`]
+/// can be.
+pub struct SlabStore<C: waynest::Connection, E: From<ProtocolError>, S = ()> {
+    client: Vec<Slot<C, E, S>>,
+    server: Vec<Slot<C, E, S>>,
+}
+
+impl<C: waynest::Connection, E: From<ProtocolError>, S> Default for SlabStore<C, E, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: waynest::Connection, E: From<ProtocolError>, S> SlabStore<C, E, S> {
+    pub fn new() -> Self {
+        Self {
+            client: Vec::new(),
+            server: Vec::new(),
+        }
+    }
+
+    fn slot(&self, id: ObjectId) -> Option<&Slot<C, E, S>> {
+        if id.is_server() {
+            self.server
+                .get((id.as_raw() - ObjectId::SERVER_START.as_raw()) as usize)
+        } else {
+            self.client.get(id.as_raw() as usize - 1)
+        }
+    }
+
+    /// The slot of `id`, growing the vector it lives in by one if `id` is just past its end.
+    fn slot_mut(&mut self, id: ObjectId) -> Option<&mut Slot<C, E, S>> {
+        let (slots, index) = if id.is_server() {
+            (
+                &mut self.server,
+                (id.as_raw() - ObjectId::SERVER_START.as_raw()) as usize,
+            )
+        } else {
+            (&mut self.client, id.as_raw() as usize - 1)
+        };
+
+        if index == slots.len() {
+            slots.push(Slot::Empty);
+        }
+
+        slots.get_mut(index)
+    }
+}
+
+impl<C: waynest::Connection + 'static, E: From<ProtocolError> + 'static, S: Send + 'static>
+    ObjectStore for SlabStore<C, E, S>
+{
+    type Error = E;
+    type Connection = C;
+    type State = S;
+
+    fn insert(
+        &mut self,
+        sender_id: ObjectId,
+        object: StoreObject<Self>,
+        version: u32,
+        parent: Option<ObjectId>,
+    ) -> Result<(), StoreError<StoreObject<Self>>> {
+        let Some(slot) = self.slot_mut(sender_id) else {
+            return Err(StoreError(object));
+        };
+
+        if let Slot::Object(..) = slot {
+            return Err(StoreError(object));
+        }
+
+        let info = ObjectInfo {
+            interface: object.interface(),
+            version,
+            parent,
+        };
+
+        *slot = Slot::Object(object, info);
+
+        Ok(())
+    }
+
+    fn get(&self, id: ObjectId) -> Option<StoreObject<Self>> {
+        match self.slot(id)? {
+            Slot::Object(object, _) => Some(object.clone()),
+            _ => None,
+        }
+    }
+
+    fn info(&self, id: ObjectId) -> Option<ObjectInfo> {
+        match self.slot(id)? {
+            Slot::Object(_, info) => Some(*info),
+            _ => None,
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (ObjectId, ObjectInfo, &StoreObject<Self>)> {
+        let client = self.client.iter().zip(ObjectId::DISPLAY.as_raw()..);
+        let server = self.server.iter().zip(ObjectId::SERVER_START.as_raw()..);
+
+        client.chain(server).filter_map(|(slot, id)| match slot {
+            Slot::Object(object, info) => Some((ObjectId::new(id)?, *info, object)),
+            _ => None,
+        })
+    }
+
+    fn remove(&mut self, id: ObjectId) {
+        if self.slot(id).is_some()
+            && let Some(slot) = self.slot_mut(id)
+        {
+            *slot = Slot::Empty;
+        }
+    }
+
+    fn clear(&mut self) {
+        // Client slots are kept so the highest id used so far is remembered
+        for slot in &mut self.client {
+            *slot = Slot::Empty;
+        }
+
+        self.server.clear();
+    }
+
+    fn insert_zombie(
+        &mut self,
+        id: ObjectId,
+        requests: &'static [MessageInfo],
+    ) -> Result<(), StoreError<&'static [MessageInfo]>> {
+        if id.is_server() {
+            self.remove(id);
+        } else {
+            *self.slot_mut(id).ok_or(StoreError(requests))? = Slot::Zombie(requests);
+        }
+
+        Ok(())
+    }
+
+    fn zombie(&self, id: ObjectId) -> Option<&'static [MessageInfo]> {
+        match self.slot(id)? {
+            Slot::Zombie(requests) => Some(requests),
+            _ => None,
+        }
+    }
+
+    fn accepts_new_id(&self, id: ObjectId) -> bool {
+        !id.is_server()
+            && id.as_raw() as usize <= self.client.len() + 1
+            && !matches!(self.slot(id), Some(Slot::Object(..)))
+    }
+
+    fn next_server_id(&self) -> ObjectId {
+        let index = self
+            .server
+            .iter()
+            .position(|slot| matches!(slot, Slot::Empty))
+            .unwrap_or(self.server.len());
+
+        u32::try_from(index)
+            .ok()
+            .and_then(|index| index.checked_add(ObjectId::SERVER_START.as_raw()))
+            .and_then(ObjectId::new)
+            .expect("Server ids are exhausted")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, sync::Arc};
+
+    use waynest::{Message, ObjectId, ProtocolError, Socket};
+
+    use super::SlabStore;
+    use crate::{ObjectStore, RequestDispatcher};
+
+    struct Noop;
+
+    #[async_trait::async_trait]
+    impl RequestDispatcher for Noop {
+        type Connection = Socket;
+        type Error = ProtocolError;
+        type State = ();
+
+        fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync + 'static> {
+            self
+        }
+
+        async fn dispatch_request(
+            &self,
+            _connection: &mut Socket,
+            _state: &mut (),
+            _sender_id: ObjectId,
+            _message: &mut Message,
+        ) -> Result<(), ProtocolError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn tracks_both_ranges() {
+        let mut store = SlabStore::<Socket, ProtocolError>::new();
+        let id = |id| ObjectId::new(id).unwrap();
+        let server = |offset| id(ObjectId::SERVER_START.as_raw() + offset);
+
+        assert!(store.accepts_new_id(id(1)));
+        assert!(!store.accepts_new_id(id(2)));
+
+        assert!(store.insert(id(1), Arc::new(Noop), 1, None).is_ok());
+        assert!(store.insert(id(2), Arc::new(Noop), 1, None).is_ok());
+        assert!(store.insert(id(2), Arc::new(Noop), 1, None).is_err());
+        assert!(store.insert(id(4), Arc::new(Noop), 1, None).is_err());
+
+        assert!(!store.accepts_new_id(id(2)));
+        assert!(store.accepts_new_id(id(3)));
+        assert!(!store.accepts_new_id(id(4)));
+
+        // Zombie ids can be reused, and removed ids stay below the highest id used
+        assert!(store.insert_zombie(id(2), &[]).is_ok());
+        assert!(store.insert_zombie(id(5), &[]).is_err());
+        assert!(store.get(id(2)).is_none());
+        assert!(store.zombie(id(2)).is_some());
+        assert!(store.accepts_new_id(id(2)));
+
+        assert_eq!(store.next_server_id(), server(0));
+        assert!(store.insert(server(0), Arc::new(Noop), 1, None).is_ok());
+        assert!(store.insert(server(1), Arc::new(Noop), 1, None).is_ok());
+        assert!(store.insert(server(1000), Arc::new(Noop), 1, None).is_err());
+        store.remove(server(0));
+        assert_eq!(store.next_server_id(), server(0));
+
+        let ids: Vec<_> = store.iter().map(|(id, _, _)| id).collect();
+        assert_eq!(ids, [id(1), server(1)]);
+
+        store.clear();
+        assert_eq!(store.iter().count(), 0);
+        assert!(store.accepts_new_id(id(3)));
+    }
+}
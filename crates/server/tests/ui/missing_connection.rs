@@ -0,0 +1,6 @@
+use waynest_server::RequestDispatcher;
+
+#[derive(RequestDispatcher)]
+struct Surface;
+
+fn main() {}
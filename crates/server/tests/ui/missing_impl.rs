@@ -0,0 +1,24 @@
+use waynest::{Message, MessageInfo, ObjectId};
+use waynest_server::RequestDispatcher;
+
+trait WlSurface {
+    type Connection: waynest::Connection;
+    type State: Send;
+
+    const INTERFACE: &'static str;
+    const REQUESTS: &'static [MessageInfo];
+
+    fn handle_request(
+        &self,
+        connection: &mut Self::Connection,
+        state: &mut Self::State,
+        sender_id: ObjectId,
+        message: &mut Message,
+    ) -> impl Future<Output = Result<(), <Self::Connection as waynest::Connection>::Error>> + Send;
+}
+
+#[derive(RequestDispatcher)]
+#[waynest(interface = WlSurface)]
+struct Surface;
+
+fn main() {}
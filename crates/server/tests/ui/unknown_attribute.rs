@@ -0,0 +1,7 @@
+use waynest_server::RequestDispatcher;
+
+#[derive(RequestDispatcher)]
+#[waynest(connection = waynest::Socket, interfaces = WlSurface)]
+struct Surface;
+
+fn main() {}
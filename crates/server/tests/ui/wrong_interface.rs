@@ -0,0 +1,9 @@
+use waynest_server::RequestDispatcher;
+
+struct WlSurface;
+
+#[derive(RequestDispatcher)]
+#[waynest(interface = WlSurface)]
+struct Surface;
+
+fn main() {}
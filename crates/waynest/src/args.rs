@@ -54,6 +54,9 @@ pub struct ObjectId(NonZeroU32);
 
 impl ObjectId {
     pub const DISPLAY: Self = unsafe { Self::from_raw(1) };
+    /// The first id of the range allocated by the server, ids below it are allocated by the
+    /// client.
+    pub const SERVER_START: Self = unsafe { Self::from_raw(0xff000000) };
 
     pub const fn as_raw(&self) -> u32 {
         self.0.get()
@@ -73,6 +76,11 @@ impl ObjectId {
 
         Some(unsafe { Self::from_raw(id) })
     }
+
+    /// Whether this id was allocated by the server.
+    pub const fn is_server(&self) -> bool {
+        self.as_raw() >= Self::SERVER_START.as_raw()
+    }
 }
 
 impl std::fmt::Display for ObjectId {
@@ -0,0 +1,167 @@
+use std::slice;
+
+use crate::{ArgInfo, ArgKind, Fixed, Message, MessageInfo, NewId, ObjectId, ProtocolError};
+
+/// A message argument decoded according to its signature.
+pub enum Arg {
+    Int(i32),
+    Uint(u32),
+    Fixed(Fixed),
+    String(Option<String>),
+    Object(Option<ObjectId>),
+    NewId(NewId),
+    Array(Vec<u8>),
+}
+
+/// The arguments of a message, decoded one at a time from a signature table rather than by code
+/// generated for every message.
+///
+/// Every argument is checked against the signature before it is read from the payload. File
+/// descriptors are not part of the payload and are skipped, they have to be taken from the
+/// connection instead.
+pub struct Args<'a> {
+    message: &'a mut Message,
+    args: slice::Iter<'a, ArgInfo>,
+}
+
+impl<'a> Args<'a> {
+    pub fn new(message: &'a mut Message, info: &'a MessageInfo) -> Self {
+        Self {
+            message,
+            args: info.args.iter(),
+        }
+    }
+
+    /// Moves to the next argument in the payload, failing unless it is accepted by `accepts`.
+    fn next_arg(
+        &mut self,
+        accepts: impl FnOnce(&ArgInfo) -> bool,
+    ) -> Result<&mut Message, ProtocolError> {
+        match self.args.find(|arg| arg.kind != ArgKind::Fd) {
+            Some(arg) if accepts(arg) => Ok(self.message),
+            _ => Err(ProtocolError::MalformedPayload),
+        }
+    }
+
+    /// Takes the next argument as an `int`, reinterpreting a `uint` as both share the same
+    /// wire representation.
+    pub fn int(&mut self) -> Result<i32, ProtocolError> {
+        self.next_arg(|arg| matches!(arg.kind, ArgKind::Int | ArgKind::Uint))?
+            .int()
+    }
+
+    /// Takes the next argument as a `uint`, reinterpreting an `int` as both share the same
+    /// wire representation.
+    pub fn uint(&mut self) -> Result<u32, ProtocolError> {
+        self.next_arg(|arg| matches!(arg.kind, ArgKind::Int | ArgKind::Uint))?
+            .uint()
+    }
+
+    pub fn fixed(&mut self) -> Result<Fixed, ProtocolError> {
+        self.next_arg(|arg| arg.kind == ArgKind::Fixed)?.fixed()
+    }
+
+    pub fn string(&mut self) -> Result<Option<String>, ProtocolError> {
+        self.next_arg(|arg| arg.kind == ArgKind::String)?.string()
+    }
+
+    /// Takes the next argument as an object, including new ids of a known interface, which are
+    /// sent as a bare id.
+    pub fn object(&mut self) -> Result<Option<ObjectId>, ProtocolError> {
+        self.next_arg(|arg| {
+            arg.kind == ArgKind::Object || (arg.kind == ArgKind::NewId && arg.interface.is_some())
+        })?
+        .object()
+    }
+
+    /// Takes the next argument as a new id of an interface picked by the sender.
+    pub fn new_id(&mut self) -> Result<NewId, ProtocolError> {
+        self.next_arg(|arg| arg.kind == ArgKind::NewId && arg.interface.is_none())?
+            .new_id()
+    }
+
+    pub fn array(&mut self) -> Result<Vec<u8>, ProtocolError> {
+        self.next_arg(|arg| arg.kind == ArgKind::Array)?.array()
+    }
+}
+
+impl Iterator for Args<'_> {
+    type Item = Result<Arg, ProtocolError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let arg = self.args.find(|arg| arg.kind != ArgKind::Fd)?;
+
+        Some(match arg.kind {
+            ArgKind::Int => self.message.int().map(Arg::Int),
+            ArgKind::Uint => self.message.uint().map(Arg::Uint),
+            ArgKind::Fixed => self.message.fixed().map(Arg::Fixed),
+            ArgKind::String => self.message.string().map(Arg::String),
+            ArgKind::Object => self.message.object().map(Arg::Object),
+            ArgKind::NewId if arg.interface.is_some() => self.message.object().map(Arg::Object),
+            ArgKind::NewId => self.message.new_id().map(Arg::NewId),
+            ArgKind::Array => self.message.array().map(Arg::Array),
+            ArgKind::Fd => unreachable!("File descriptors are skipped"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Arg, Args};
+    use crate::{ArgInfo, ArgKind, Message, MessageInfo, ObjectId, PayloadBuilder, ProtocolError};
+
+    const fn arg(name: &'static str, kind: ArgKind) -> ArgInfo {
+        ArgInfo {
+            name,
+            kind,
+            interface: None,
+            r#enum: None,
+            allow_null: false,
+        }
+    }
+
+    /// Shaped like `wl_keyboard.keymap`, with a file descriptor between payload arguments.
+    static KEYMAP: MessageInfo = MessageInfo {
+        name: "keymap",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        signature: "uhu",
+        args: &[
+            arg("format", ArgKind::Uint),
+            arg("fd", ArgKind::Fd),
+            arg("size", ArgKind::Uint),
+        ],
+    };
+
+    fn keymap() -> Message {
+        let payload = PayloadBuilder::new().put_uint(1).put_uint(4096).build();
+        Message::new(ObjectId::DISPLAY, 0, payload)
+    }
+
+    #[test]
+    fn args_follow_the_signature() {
+        let mut message = keymap();
+        let mut args = Args::new(&mut message, &KEYMAP);
+
+        assert_eq!(args.uint().unwrap(), 1);
+        // Both integer kinds share the same wire representation
+        assert_eq!(args.int().unwrap(), 4096);
+        assert!(matches!(args.uint(), Err(ProtocolError::MalformedPayload)));
+
+        let mut message = keymap();
+        let mut args = Args::new(&mut message, &KEYMAP);
+
+        assert!(matches!(
+            args.string(),
+            Err(ProtocolError::MalformedPayload)
+        ));
+
+        let mut message = keymap();
+        let args: Vec<_> = Args::new(&mut message, &KEYMAP)
+            .map(Result::unwrap)
+            .collect();
+
+        assert!(matches!(args[..], [Arg::Uint(1), Arg::Uint(4096)]));
+    }
+}
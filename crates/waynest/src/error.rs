@@ -4,8 +4,16 @@ pub enum ProtocolError {
     MissingFd,
     MalformedPayload,
     UnknownOpcode(u16),
+    UnknownObject(crate::ObjectId),
     InvalidLength(usize),
     IoError(std::io::Error),
+    /// A protocol error to be sent to the peer as `wl_display.error`, after which the connection
+    /// is closed
+    Fatal {
+        object_id: crate::ObjectId,
+        code: u32,
+        message: String,
+    },
 }
 
 impl std::fmt::Display for ProtocolError {
@@ -17,8 +25,16 @@ impl std::fmt::Display for ProtocolError {
             ProtocolError::UnknownOpcode(opcode) => {
                 write!(f, "Received unsupported opcode: {}", opcode)
             }
+            ProtocolError::UnknownObject(id) => {
+                write!(f, "Received a message for unknown object: {id}")
+            }
             ProtocolError::InvalidLength(len) => write!(f, "Invalid payload length: {len} bytes"),
             ProtocolError::IoError(err) => write!(f, "I/O error during decoding: {err}"),
+            ProtocolError::Fatal {
+                object_id,
+                code,
+                message,
+            } => write!(f, "Protocol error {code} on object {object_id}: {message}"),
         }
     }
 }
@@ -37,3 +53,15 @@ impl From<std::io::Error> for ProtocolError {
         ProtocolError::IoError(err)
     }
 }
+
+/// Errors that may wrap a [`ProtocolError`], letting whoever drives the connection act on it,
+/// such as sending a [`ProtocolError::Fatal`] to the peer.
+pub trait AsProtocolError {
+    fn as_protocol_error(&self) -> Option<&ProtocolError>;
+}
+
+impl AsProtocolError for ProtocolError {
+    fn as_protocol_error(&self) -> Option<&ProtocolError> {
+        Some(self)
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    collections::VecDeque,
+    future::poll_fn,
+    io,
+    os::fd::OwnedFd,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+
+use crate::{Connection, Message, ProtocolError};
+
+/// Something messages can be sent through without exclusive access to the connection, used by
+/// the generated event and request senders.
+pub trait MessageSender {
+    type Error: From<ProtocolError>;
+
+    /// Queues a message along with the file descriptors it carries.
+    fn send_message(&self, message: Message, fds: Vec<OwnedFd>) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug, Default)]
+struct State {
+    messages: VecDeque<(Message, Vec<OwnedFd>)>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// A cloneable handle queueing messages into an [`Outbox`], which can be shared across tasks.
+#[derive(Debug, Clone)]
+pub struct ConnectionHandle {
+    state: Arc<Mutex<State>>,
+}
+
+impl ConnectionHandle {
+    /// Refuses any further message, such as after a fatal protocol error. Messages queued so far
+    /// are still written out by the [`Outbox`].
+    pub fn close(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        state.closed = true;
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl MessageSender for ConnectionHandle {
+    type Error = ProtocolError;
+
+    fn send_message(&self, message: Message, fds: Vec<OwnedFd>) -> Result<(), ProtocolError> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        if state.closed {
+            return Err(io::Error::from(io::ErrorKind::BrokenPipe).into());
+        }
+
+        state.messages.push_back((message, fds));
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+
+        Ok(())
+    }
+}
+
+/// The receiving end of [`ConnectionHandle`]s, owned by whoever drives the connection.
+///
+/// Queued messages are yielded as a [`Stream`] or written out with [`Outbox::flush`]. Sending
+/// through a handle fails once the outbox is dropped.
+#[derive(Debug, Default)]
+pub struct Outbox {
+    state: Arc<Mutex<State>>,
+}
+
+impl Outbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle(&self) -> ConnectionHandle {
+        ConnectionHandle {
+            state: self.state.clone(),
+        }
+    }
+
+    fn pop(&self) -> Option<(Message, Vec<OwnedFd>)> {
+        self.state
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .messages
+            .pop_front()
+    }
+
+    /// Whether a handle was closed, after which nothing can be sent anymore.
+    pub fn is_closed(&self) -> bool {
+        self.state
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .closed
+    }
+
+    /// Resolves once a message is queued, without taking it out of the outbox.
+    pub fn poll_queued(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        if state.messages.is_empty() {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+
+    /// Writes every queued message to `connection` and flushes it.
+    pub async fn flush<C: Connection>(&self, connection: &mut C) -> Result<(), ProtocolError> {
+        while let Some((message, fds)) = self.pop() {
+            poll_fn(|cx| Pin::new(&mut *connection).poll_ready(cx)).await?;
+
+            for fd in fds {
+                connection.push_fd(fd);
+            }
+
+            Pin::new(&mut *connection).start_send(message)?;
+        }
+
+        poll_fn(|cx| Pin::new(&mut *connection).poll_flush(cx)).await
+    }
+}
+
+impl Stream for Outbox {
+    type Item = (Message, Vec<OwnedFd>);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        match state.messages.pop_front() {
+            Some(message) => Poll::Ready(Some(message)),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for Outbox {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        state.closed = true;
+        state.messages.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use bytes::Bytes;
+    use futures_core::Stream;
+
+    use super::{MessageSender, Outbox};
+    use crate::{Message, ObjectId};
+
+    #[test]
+    fn handles_queue_into_outbox() {
+        let mut outbox = Outbox::new();
+        let handle = outbox.handle();
+        let message = Message::new(ObjectId::DISPLAY, 0, Bytes::new());
+
+        handle.send_message(message.clone(), Vec::new()).unwrap();
+
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert!(matches!(
+            Pin::new(&mut outbox).poll_next(&mut cx),
+            Poll::Ready(Some((queued, fds))) if queued == message && fds.is_empty()
+        ));
+        assert!(Pin::new(&mut outbox).poll_next(&mut cx).is_pending());
+
+        handle.send_message(message.clone(), Vec::new()).unwrap();
+        handle.close();
+
+        // Closing keeps what was queued before
+        assert!(outbox.is_closed());
+        assert!(handle.send_message(message.clone(), Vec::new()).is_err());
+        assert!(matches!(
+            Pin::new(&mut outbox).poll_next(&mut cx),
+            Poll::Ready(Some((queued, _))) if queued == message
+        ));
+
+        drop(outbox);
+
+        assert!(handle.send_message(message, Vec::new()).is_err());
+    }
+}
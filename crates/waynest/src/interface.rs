@@ -0,0 +1,59 @@
+/// Static description of a wayland interface, as declared in its protocol XML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+    pub version: u32,
+    /// Name of the protocol declaring the interface, e.g. `xdg_shell`
+    pub protocol: &'static str,
+    /// Family the protocol is shipped with, e.g. `stable`, `wlr` or `plasma`
+    pub family: &'static str,
+    pub requests: &'static [MessageInfo],
+    pub events: &'static [MessageInfo],
+    /// Names of the enums declared by the interface, as referenced by the `enum` attribute
+    pub enums: &'static [&'static str],
+}
+
+impl InterfaceInfo {
+    pub fn request(&self, opcode: u16) -> Option<&'static MessageInfo> {
+        self.requests.get(opcode as usize)
+    }
+
+    pub fn event(&self, opcode: u16) -> Option<&'static MessageInfo> {
+        self.events.get(opcode as usize)
+    }
+}
+
+/// Static description of a request or an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageInfo {
+    pub name: &'static str,
+    pub since: u32,
+    pub deprecated_since: Option<u32>,
+    pub destructor: bool,
+    /// The message signature in the format used by libwayland, e.g. `2?su`
+    pub signature: &'static str,
+    pub args: &'static [ArgInfo],
+}
+
+/// Static description of a message argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArgInfo {
+    pub name: &'static str,
+    pub kind: ArgKind,
+    pub interface: Option<&'static str>,
+    pub r#enum: Option<&'static str>,
+    pub allow_null: bool,
+}
+
+/// The wire type of a message argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArgKind {
+    Int,
+    Uint,
+    Fixed,
+    String,
+    Object,
+    NewId,
+    Array,
+    Fd,
+}
@@ -1,13 +1,19 @@
 mod args;
 mod connection;
+mod decode;
 mod error;
+mod handle;
+mod interface;
 mod message;
 mod payload;
 mod socket;
 
 pub use args::{Fixed, NewId, ObjectId};
 pub use connection::Connection;
-pub use error::ProtocolError;
+pub use decode::{Arg, Args};
+pub use error::{AsProtocolError, ProtocolError};
+pub use handle::{ConnectionHandle, MessageSender, Outbox};
+pub use interface::{ArgInfo, ArgKind, InterfaceInfo, MessageInfo};
 pub use message::Message;
 pub use payload::PayloadBuilder;
 pub use socket::Socket;
@@ -1,10 +1,15 @@
-use std::{collections::HashMap, ffi::OsStr, fmt::Write as _, fs::OpenOptions, io::Write as _};
+use std::{collections::HashMap, ffi::OsStr, fs, process::Command, time::Instant};
 
 use anyhow::{Context, Result, anyhow, bail};
-use quote::quote;
+use quote::{format_ident, quote};
 use sap::{Argument, Parser};
 use walkdir::WalkDir;
-use waynest_gen::{ProtocolGenerator, parser::Protocol};
+use waynest_gen::{
+    ProtocolGenerator, file_header, format_code,
+    lint::{self, Severity},
+    parser::Protocol,
+    protocol_feature,
+};
 
 fn main() -> Result<()> {
     let mut parser = Parser::from_env().context("Failed to init sap")?;
@@ -22,7 +27,9 @@ fn main() -> Result<()> {
     }
 
     match command.ok_or(anyhow!("Please specify a command"))?.as_str() {
-        "generate" => generate(),
+        "generate" => generate(parser),
+        "lint" => lint(parser),
+        "bench-dispatch" => bench_dispatch(),
         command => bail!("Unknown command {command}"),
     }
 }
@@ -56,7 +63,7 @@ const SKIP: [(&str, &str); 7] = [
     ("mutter", "session-management-v1.xml"),
 ];
 
-fn generate() -> Result<()> {
+fn load_protocols() -> Result<HashMap<&'static str, Vec<Protocol>>> {
     let mut protocols = HashMap::new();
 
     for (module, protocol) in PROTOCOLS {
@@ -79,71 +86,355 @@ fn generate() -> Result<()> {
         protocols.insert(module, protos);
     }
 
-    let mut server_rs_content = "pub mod core;".to_string();
-    let mut client_rs_content = "pub mod core;".to_string();
+    Ok(protocols)
+}
+
+/// Lints the given protocol files, or every bundled protocol if none are given. Enum
+/// references are resolved against the bundled protocols.
+fn lint(mut parser: Parser<impl Iterator<Item = String>>) -> Result<()> {
+    let mut paths = Vec::new();
+
+    while let Some(arg) = parser.forward()? {
+        match arg {
+            Argument::Value(path) => paths.push(path.into_owned()),
+            arg => Err(arg.unexpected())?,
+        }
+    }
+
+    let protocols = load_protocols()?;
+    let known: Vec<&Protocol> = PROTOCOLS
+        .iter()
+        .flat_map(|(module, _)| &protocols[module])
+        .collect();
+
+    let targets = if paths.is_empty() {
+        known.iter().map(|protocol| (*protocol).clone()).collect()
+    } else {
+        paths
+            .iter()
+            .map(Protocol::from_path)
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    let mut errors = 0;
+    let mut warnings = 0;
+
+    for protocol in &targets {
+        for diagnostic in lint::lint(protocol, &known) {
+            match diagnostic.severity {
+                Severity::Error => errors += 1,
+                Severity::Warning => warnings += 1,
+            }
+
+            println!("{diagnostic}");
+        }
+    }
+
+    println!("{errors} errors, {warnings} warnings");
+
+    if errors > 0 {
+        bail!("Linting failed");
+    }
+
+    Ok(())
+}
+
+const FEATURES_START: &str = "# Protocol features, generated by `cargo xtask generate`";
+const FEATURES_END: &str = "# End of protocol features";
+
+/// Renders the manifest of `waynest-protocols` with one feature per protocol, depending on the
+/// features of the protocols it references, and one aggregate feature per family.
+fn features_manifest(protocols: &HashMap<&'static str, Vec<Protocol>>) -> Result<String> {
+    let path = "crates/protocols/Cargo.toml";
+    let manifest = fs::read_to_string(path).with_context(|| format!("Failed to read {path}"))?;
+
+    let (head, rest) = manifest
+        .split_once(FEATURES_START)
+        .with_context(|| format!("Missing `{FEATURES_START}` in {path}"))?;
+    let (_, tail) = rest
+        .split_once(FEATURES_END)
+        .with_context(|| format!("Missing `{FEATURES_END}` in {path}"))?;
+
+    // Feature names must not clash with each other, nor with the hand written ones
+    let mut defined: HashMap<String, String> = ["server", "client", "tracing"]
+        .into_iter()
+        .chain(PROTOCOLS.iter().map(|(family, _)| *family))
+        .map(|feature| (feature.to_string(), "the manifest".to_string()))
+        .collect();
+    let mut features = format!("{FEATURES_START}\n");
+
+    for (family, _) in PROTOCOLS.iter().filter(|(family, _)| *family != "core") {
+        let xml = &protocols[family];
+        let generator = ProtocolGenerator::new(xml, protocols);
+
+        features.push_str(&format!("\n{family} = [\n"));
+
+        for protocol in xml {
+            features.push_str(&format!("  {:?},\n", protocol_feature(protocol)));
+        }
+
+        features.push_str("]\n");
+
+        for protocol in xml {
+            let feature = protocol_feature(protocol);
+
+            if let Some(previous) = defined.insert(feature.clone(), family.to_string()) {
+                bail!("Feature `{feature}` from {family} is already defined by {previous}");
+            }
+
+            let dependencies: Vec<String> = generator
+                .dependencies(protocol)
+                .into_iter()
+                .filter(|dependency| {
+                    !protocols["core"]
+                        .iter()
+                        .any(|core| core.name == dependency.name)
+                })
+                .map(|dependency| format!("{:?}", protocol_feature(dependency)))
+                .collect();
+
+            features.push_str(&format!("{feature} = [{}]\n", dependencies.join(", ")));
+        }
+    }
+
+    features.push('\n');
+
+    Ok(format!("{head}{features}{FEATURES_END}{tail}"))
+}
+
+/// Collects generated files, either writing them out or, in check mode, recording the ones
+/// whose content differs from what is on disk.
+struct Output {
+    check: bool,
+    stale: Vec<String>,
+}
+
+impl Output {
+    fn write(&mut self, path: &str, content: String) -> Result<()> {
+        if self.check {
+            if fs::read_to_string(path).ok().as_ref() != Some(&content) {
+                self.stale.push(path.to_string());
+            }
+
+            return Ok(());
+        }
+
+        fs::write(path, content).with_context(|| format!("Failed to write {path}"))
+    }
+}
+
+fn generate(mut parser: Parser<impl Iterator<Item = String>>) -> Result<()> {
+    let mut check = false;
+
+    while let Some(arg) = parser.forward()? {
+        match arg {
+            Argument::Long("check") => check = true,
+            arg => Err(arg.unexpected())?,
+        }
+    }
+
+    let protocols = load_protocols()?;
+
+    let mut output = Output {
+        check,
+        stale: Vec::new(),
+    };
+
+    if !check {
+        fs::create_dir_all("crates/protocols/src/metadata")?;
+    }
+
+    let mut family_modules = Vec::new();
+    let mut family_interfaces = Vec::new();
+
+    for (module, _) in PROTOCOLS {
+        let xml = &protocols[module];
 
-    for (module, xml) in &protocols {
         println!("Generating {module} protocols...");
 
-        let server_modules =
-            ProtocolGenerator::new(xml, &protocols).generate_protocols(false, true)?;
-        let client_modules =
-            ProtocolGenerator::new(xml, &protocols).generate_protocols(true, false)?;
+        // Core is always available, every other protocol sits behind its own feature
+        let generator = || {
+            let generator = ProtocolGenerator::new(xml, &protocols);
 
-        let server_module_content = quote! {
-            #(#server_modules)*
+            if module == "core" {
+                generator
+            } else {
+                generator.with_features()
+            }
         };
 
-        let client_module_content = quote! {
-            #(#client_modules)*
-        };
+        let server_modules = generator().generate_protocols(false, true)?;
+        let client_modules = generator().generate_protocols(true, false)?;
+        let metadata_module = generator().generate_metadata(module)?;
+
+        let header = file_header(xml);
+
+        output.write(
+            &format!("crates/protocols/src/server/{module}.rs"),
+            header.clone() + &format_code(quote! { #(#server_modules)* })?,
+        )?;
+        output.write(
+            &format!("crates/protocols/src/client/{module}.rs"),
+            header.clone() + &format_code(quote! { #(#client_modules)* })?,
+        )?;
+        output.write(
+            &format!("crates/protocols/src/metadata/{module}.rs"),
+            header + &format_code(metadata_module)?,
+        )?;
+
+        let ident = format_ident!("{module}");
+
+        family_modules.push(quote! { pub mod #ident; });
+        family_interfaces.push(quote! { #ident::INTERFACES });
+    }
+
+    output.write(
+        "crates/protocols/Cargo.toml",
+        features_manifest(&protocols)?,
+    )?;
+
+    let header = file_header(&[]);
+
+    output.write(
+        "crates/protocols/src/server.rs",
+        header.clone() + &format_code(quote! { #(#family_modules)* })?,
+    )?;
+    output.write(
+        "crates/protocols/src/client.rs",
+        header.clone() + &format_code(quote! { #(#family_modules)* })?,
+    )?;
+    output.write(
+        "crates/protocols/src/metadata.rs",
+        header
+            + &format_code(quote! {
+                #(#family_modules)*
 
-        let mut server_module_file = OpenOptions::new()
-            .truncate(true)
-            .write(true)
-            .create(true)
-            .open(format!("crates/protocols/src/server/{module}.rs"))?;
-
-        let mut client_module_file = OpenOptions::new()
-            .truncate(true)
-            .write(true)
-            .create(true)
-            .open(format!("crates/protocols/src/client/{module}.rs"))?;
-
-        write!(&mut server_module_file, "{server_module_content}",)?;
-        write!(&mut client_module_file, "{client_module_content}",)?;
-
-        if *module != "core" {
-            writeln!(
-                &mut server_rs_content,
-                r#"#[cfg(feature = "{module}")]
-        #[cfg_attr(docsrs, doc(cfg(feature = "{module}")))]
-        pub mod {module};"#
-            )?;
-
-            writeln!(
-                &mut client_rs_content,
-                r#"#[cfg(feature = "{module}")]
-        #[cfg_attr(docsrs, doc(cfg(feature = "{module}")))]
-        pub mod {module};"#
-            )?;
+                static FAMILIES: &[&[&waynest::InterfaceInfo]] = &[#(#family_interfaces),*];
+
+                /// Iterates over the metadata of every interface in the enabled protocol families
+                pub fn interfaces() -> impl Iterator<Item = &'static waynest::InterfaceInfo> {
+                    FAMILIES.iter().flat_map(|family| family.iter().copied())
+                }
+
+                /// Looks up the metadata of an interface by name across all the enabled protocol families
+                pub fn lookup_interface(name: &str) -> Option<&'static waynest::InterfaceInfo> {
+                    interfaces().find(|interface| interface.name == name)
+                }
+            })?,
+    )?;
+
+    if !output.stale.is_empty() {
+        for path in &output.stale {
+            println!("{path} is out of date");
         }
+
+        bail!("Generated files are out of date, run `cargo xtask generate`");
     }
 
-    let mut server_rs = OpenOptions::new()
-        .truncate(true)
-        .write(true)
-        .create(true)
-        .open("crates/protocols/src/server.rs")?;
+    Ok(())
+}
+
+/// Measures the generated dispatch code on the core protocol, building a small benchmark crate
+/// and reporting its compile time, binary size and dispatch throughput.
+fn bench_dispatch() -> Result<()> {
+    let protocols = load_protocols()?;
+    let root = std::env::current_dir()?;
+    let bench_dir = root.join("target/dispatch-bench");
+    let package = "dispatch-bench";
+
+    println!("Benchmarking dispatch...");
+
+    fs::create_dir_all(bench_dir.join("src"))?;
+
+    let modules = ProtocolGenerator::new(&protocols["core"], &protocols)
+        .with_root(quote! { crate::protocols })
+        .generate_protocols(false, true)?;
+
+    fs::write(
+        bench_dir.join("src/protocols.rs"),
+        format_code(quote! { #(#modules)* })?,
+    )?;
+    fs::write(
+        bench_dir.join("src/main.rs"),
+        include_str!("../templates/dispatch_bench.rs"),
+    )?;
+    fs::write(
+        bench_dir.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "{package}"
+edition = "2024"
+publish = false
+
+[workspace]
+
+[features]
+tracing = []
+log = []
+
+[dependencies]
+bitflags = "2.13.0"
+futures-core = "0.3.32"
+futures-sink = "0.3.32"
+waynest = {{ path = "{waynest}" }}
+"#,
+            waynest = root.join("crates/waynest").display()
+        ),
+    )?;
+    // Use the dependency versions the workspace is tested with
+    fs::copy(root.join("Cargo.lock"), bench_dir.join("Cargo.lock"))?;
+
+    let cargo = |args: &[&str]| -> Result<()> {
+        let status = Command::new(env!("CARGO"))
+            .args(args)
+            .arg("--manifest-path")
+            .arg(bench_dir.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(bench_dir.join("target"))
+            .status()?;
+
+        if !status.success() {
+            bail!("cargo {} failed for {package}", args.join(" "));
+        }
+
+        Ok(())
+    };
+
+    // Build the dependencies first so only the generated code is timed
+    cargo(&["build", "--release", "--quiet"])?;
+    cargo(&["clean", "--release", "--quiet", "--package", package])?;
+
+    let start = Instant::now();
+    cargo(&["build", "--release", "--quiet"])?;
+    let compile_time = start.elapsed();
+
+    let binary = bench_dir.join("target/release").join(package);
+    let size = fs::metadata(&binary)?.len();
+
+    let output = Command::new(&binary).output()?;
+
+    if !output.status.success() {
+        bail!(
+            "{package} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
 
-    let mut client_rs = OpenOptions::new()
-        .truncate(true)
-        .write(true)
-        .create(true)
-        .open("crates/protocols/src/client.rs")?;
+    let throughput: u64 = String::from_utf8(output.stdout)?
+        .trim()
+        .parse()
+        .context("Failed to parse the dispatch throughput")?;
 
-    write!(&mut server_rs, "{server_rs_content}")?;
-    write!(&mut client_rs, "{client_rs_content}")?;
+    println!();
+    println!(
+        "{:>14} {:>14} {:>20}",
+        "compile time", "binary size", "messages/s"
+    );
+    println!(
+        "{:>13.2}s {:>11} KiB {throughput:>20}",
+        compile_time.as_secs_f64(),
+        size / 1024
+    );
 
     Ok(())
 }
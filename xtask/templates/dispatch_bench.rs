@@ -0,0 +1,240 @@
+// Dispatch benchmark built by `cargo xtask bench-dispatch`.
+
+#[allow(dead_code, unused)]
+mod protocols;
+
+use std::{
+    future::Future,
+    hint::black_box,
+    os::fd::OwnedFd,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+    time::Instant,
+};
+
+use futures_core::Stream;
+use futures_sink::Sink;
+use protocols::wayland::{
+    wl_region::WlRegion, wl_registry::WlRegistry, wl_subcompositor::WlSubcompositor,
+};
+use waynest::{Message, NewId, ObjectId, PayloadBuilder, ProtocolError};
+
+const ITERATIONS: u32 = 1_000_000;
+
+struct NullConnection;
+
+impl Stream for NullConnection {
+    type Item = Result<Message, ProtocolError>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(None)
+    }
+}
+
+impl Sink<Message> for NullConnection {
+    type Error = ProtocolError;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, _item: Message) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl waynest::Connection for NullConnection {
+    type Error = ProtocolError;
+
+    fn fd(&mut self) -> Result<OwnedFd, ProtocolError> {
+        Err(ProtocolError::MissingFd)
+    }
+
+    fn push_fd(&mut self, _fd: OwnedFd) {}
+}
+
+struct Object;
+
+impl WlRegion for Object {
+    type Connection = NullConnection;
+    type State = ();
+
+    async fn destroy(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+    ) -> Result<(), ProtocolError> {
+        Ok(())
+    }
+
+    async fn add(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> Result<(), ProtocolError> {
+        black_box((x, y, width, height));
+        Ok(())
+    }
+
+    async fn subtract(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> Result<(), ProtocolError> {
+        black_box((x, y, width, height));
+        Ok(())
+    }
+}
+
+impl WlRegistry for Object {
+    type Connection = NullConnection;
+    type State = ();
+
+    async fn bind(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+        name: u32,
+        id: NewId,
+    ) -> Result<(), ProtocolError> {
+        black_box((name, id));
+        Ok(())
+    }
+}
+
+impl WlSubcompositor for Object {
+    type Connection = NullConnection;
+    type State = ();
+
+    async fn destroy(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+    ) -> Result<(), ProtocolError> {
+        Ok(())
+    }
+
+    async fn get_subsurface(
+        &self,
+        _connection: &mut NullConnection,
+        _state: &mut (),
+        _sender_id: ObjectId,
+        id: ObjectId,
+        surface: ObjectId,
+        parent: ObjectId,
+    ) -> Result<(), ProtocolError> {
+        black_box((id, surface, parent));
+        Ok(())
+    }
+}
+
+/// Every handler completes immediately, so a single poll is enough.
+fn run<F: Future<Output = Result<(), ProtocolError>>>(future: F) {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    match pin!(future).poll(&mut cx) {
+        Poll::Ready(result) => result.expect("Failed to dispatch"),
+        Poll::Pending => unreachable!(),
+    }
+}
+
+fn main() {
+    let id = ObjectId::new(3).unwrap();
+    let new_id = ObjectId::new(4).unwrap();
+
+    let messages = [
+        Message::new(
+            id,
+            1,
+            PayloadBuilder::new()
+                .put_int(0)
+                .put_int(0)
+                .put_int(1920)
+                .put_int(1080)
+                .build(),
+        ),
+        Message::new(
+            id,
+            0,
+            PayloadBuilder::new()
+                .put_uint(1)
+                .put_new_id(NewId {
+                    interface: "wl_subcompositor".to_string(),
+                    version: 1,
+                    object_id: new_id,
+                })
+                .build(),
+        ),
+        Message::new(
+            id,
+            1,
+            PayloadBuilder::new()
+                .put_object(Some(new_id))
+                .put_object(Some(id))
+                .put_object(Some(id))
+                .build(),
+        ),
+    ];
+
+    let mut connection = NullConnection;
+    let object = Object;
+
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        let mut region = messages[0].clone();
+        run(WlRegion::handle_request(
+            &object,
+            &mut connection,
+            &mut (),
+            id,
+            &mut region,
+        ));
+
+        let mut registry = messages[1].clone();
+        run(WlRegistry::handle_request(
+            &object,
+            &mut connection,
+            &mut (),
+            id,
+            &mut registry,
+        ));
+
+        let mut subcompositor = messages[2].clone();
+        run(WlSubcompositor::handle_request(
+            &object,
+            &mut connection,
+            &mut (),
+            id,
+            &mut subcompositor,
+        ));
+    }
+
+    let elapsed = start.elapsed();
+
+    println!(
+        "{}",
+        (ITERATIONS as f64 * messages.len() as f64 / elapsed.as_secs_f64()) as u64
+    );
+}